@@ -0,0 +1,41 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use moveos::gas::table::{INSTRUCTION_TIERS_KEY, STACK_HEIGHT_TIERS_KEY, STACK_SIZE_TIERS_KEY};
+use rooch_types::framework::gas_schedule::GasEntry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The tiers of the cost table at genesis, loaded from a YAML file.
+/// Every tier maps the tier start to the cost, a tier overrides the tier of the initial cost table
+/// with the same start, or adds a new tier. The tiers are stored on-chain by the genesis.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostTableConfig {
+    #[serde(default)]
+    pub instruction_tiers: BTreeMap<u64, u64>,
+    #[serde(default)]
+    pub stack_height_tiers: BTreeMap<u64, u64>,
+    #[serde(default)]
+    pub stack_size_tiers: BTreeMap<u64, u64>,
+}
+
+impl CostTableConfig {
+    /// The gas schedule entries of the tiers, such as `instruction_tiers.3000`
+    pub fn gas_schedule_entries(&self) -> Vec<GasEntry> {
+        [
+            (INSTRUCTION_TIERS_KEY, &self.instruction_tiers),
+            (STACK_HEIGHT_TIERS_KEY, &self.stack_height_tiers),
+            (STACK_SIZE_TIERS_KEY, &self.stack_size_tiers),
+        ]
+        .into_iter()
+        .flat_map(|(name, tiers)| {
+            tiers
+                .iter()
+                .map(move |(start, cost)| GasEntry::new(format!("{}.{}", name, start), *cost))
+        })
+        .collect()
+    }
+}
+
+impl Config for CostTableConfig {}
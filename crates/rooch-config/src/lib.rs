@@ -11,18 +11,31 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
 use moveos_config::{temp_dir, DataDirPath};
+use moveos_types::gas_config::{GasMeterKind, GasTraceFormat};
 use rooch_types::bitcoin::network::Network;
 use rooch_types::chain_id::RoochChainID;
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::gas_schedule::GasEntry;
+use rooch_types::framework::transaction_fee::DEFAULT_TARGET_BLOCK_GAS;
 
+use crate::config::Config;
+use crate::cost_table_config::CostTableConfig;
 use crate::da_config::DAConfig;
+use crate::module_gas_quota_config::ModuleGasQuotaConfig;
+use crate::server_config::{
+    CorsConfig, LoadShedConfig, TlsConfig, DEFAULT_RPC_LATENCY_BUDGET_MS, DEFAULT_RPC_MAX_IN_FLIGHT,
+};
 use crate::store_config::StoreConfig;
+use crate::webhook_config::WebhookConfig;
 
 pub mod config;
+pub mod cost_table_config;
 pub mod da_config;
 pub mod indexer_config;
+pub mod module_gas_quota_config;
 pub mod server_config;
 pub mod store_config;
+pub mod webhook_config;
 
 pub const ROOCH_DIR: &str = ".rooch";
 pub const ROOCH_CONFIR_DIR: &str = "rooch_config";
@@ -85,6 +98,43 @@ pub struct RoochOpt {
     #[clap(long, short = 'p')]
     pub port: Option<u16>,
 
+    /// The max request body size in bytes of the rooch server, defaults to 10 MB.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub max_request_body_size: Option<u32>,
+
+    /// The PEM file of the TLS certificate chain, the rooch server serves HTTPS if it is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "tls_key")]
+    pub tls_cert: Option<PathBuf>,
+    /// The PEM file of the TLS private key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "tls_cert")]
+    pub tls_key: Option<PathBuf>,
+    /// The YAML file of the CORS config of the rooch server, with the allowed origins, methods, headers,
+    /// and the allowed origins per JSON-RPC namespace.
+    /// If not set, the allowed origins are read from the `ACCESS_CONTROL_ALLOW_ORIGIN` env, separated by comma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub cors_config: Option<PathBuf>,
+
+    /// Serve the explorer-lite page on `/explorer`, rendering the recent blocks, transactions and account lookups
+    /// from the node's own RPC. It is meant for local dev networks and devnet debugging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub explorer: Option<bool>,
+
+    /// Shed the cheapest-to-reject RPC calls, such as the simulations and the heavy queries, when the number of
+    /// calls in flight reaches the limit. The load shedding is disabled if neither it nor `rpc_latency_budget_ms` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub rpc_max_in_flight: Option<usize>,
+    /// The latency budget of an RPC method in milliseconds, the node is saturated when the average latency
+    /// of a method exceeds it, defaults to 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub rpc_latency_budget_ms: Option<u64>,
+
     /// The Ethereum RPC URL to connect to for relay L1 block and transaction to L2.
     /// If not set, the relayer service will not start.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,10 +179,170 @@ pub struct RoochOpt {
     /// The address of the relayer account
     #[clap(long)]
     pub relayer_account: Option<String>,
+    /// The address of the next sequencer account, set it to rotate the sequencer key.
+    /// The rotation is announced on-chain at startup, and the proposer signs the batches with both keys
+    /// until the node is restarted with the next sequencer account as the sequencer account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub next_sequencer_account: Option<String>,
+    /// The handover window of the sequencer key rotation in milliseconds, defaults to 1 hour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "next_sequencer_account")]
+    pub sequencer_handover_window_ms: Option<u64>,
+    /// The JSON key file of the sequencer committee generated by `rooch committee keygen`. The node serves the
+    /// partial signatures of the key shares in the file to the committee sequencer by `rooch_signBatchCommitment`.
+    /// If the key shares in the file and the `committee-operator`s reach the threshold, the proposer collects their
+    /// partial signatures, and appends the threshold signature to the batch signatures.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub committee_key_file: Option<PathBuf>,
+    /// The other operators of the sequencer committee in the format `<index>@<url>`, the proposer requests
+    /// their partial signatures over the RPC.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[clap(long = "committee-operator", requires = "committee_key_file")]
+    pub committee_operators: Vec<String>,
+    /// The sequencer account whose batches the node signs as a committee operator,
+    /// defaults to the sequencer account of the node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "committee_key_file")]
+    pub committee_sequencer_account: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub da: Option<DAConfig>,
+
+    /// The max number of concurrent view function calls and dry runs from the RPC, defaults to 4.
+    /// The view function calls are executed by a dedicated pool of executors with this size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub max_view_concurrency: Option<usize>,
+    /// The timeout in milliseconds of a view function call or dry run from the RPC, defaults to 5000 ms.
+    /// The view function execution is aborted when the timeout is exceeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub view_timeout_ms: Option<u64>,
+    /// The max gas amount of a view function call from the RPC, defaults to 100000000.
+    /// It is separate from the max gas amount of the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub view_max_gas_amount: Option<u64>,
+
+    /// The safety margin in percentage added to the gas estimated by `rooch_estimateGas`, defaults to 20.
+    /// The suggested max gas amount covers the state changes before the transaction is executed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub gas_estimate_margin_percentage: Option<u64>,
+    /// The max gas used by the transactions of a block, defaults to twice the default target block gas of the fee market.
+    /// The proposer defers the rest of the executed transactions to the next block once the limit is reached,
+    /// and the sequencer rejects the transactions while the deferred transactions use more gas than 8 blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub block_gas_limit: Option<u64>,
+    /// The gas meter of the transactions, `standard` or `flat-rate`, defaults to `standard`.
+    /// The `flat-rate` meter charges every instruction the same for the development, it is only allowed on the local chain,
+    /// the nodes of the shared chains must meter the transactions the same.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub gas_meter: Option<GasMeterKind>,
+    /// Write the gas call tree of every executed transaction to the dir, in the folded stack format consumed by
+    /// inferno and speedscope. It slows down the execution, it is for the profiling only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub gas_trace_dir: Option<PathBuf>,
+    /// The format of the gas traces, `folded` or `json`, defaults to `folded`.
+    /// The `json` format is the gas heatmap keyed by `module::function`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "gas_trace_dir")]
+    pub gas_trace_format: Option<GasTraceFormat>,
+    /// The YAML file of the cost table tiers at genesis, the instruction, stack height and stack size tiers
+    /// override the initial cost table. The tiers are a part of the genesis, so the node must be started with
+    /// the same config after the genesis, the later changes go through the on-chain gas schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub genesis_cost_table_config: Option<PathBuf>,
+
+    /// Only report the pending state migrations after a framework upgrade, without submitting them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub migration_dry_run: Option<bool>,
+    /// The number of the states migrated by one migration transaction, defaults to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub migration_batch_size: Option<usize>,
+
+    /// Opt in to report the anonymized node stats to the telemetry endpoint, such as the version,
+    /// the chain, the sync height and the tx throughput. The telemetry is disabled if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub telemetry_endpoint: Option<String>,
+    /// The interval in seconds of the telemetry reports, defaults to 600 seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "telemetry_endpoint")]
+    pub telemetry_interval_secs: Option<u64>,
+
+    /// Prune the events of the transactions older than the last N transactions.
+    /// The events are kept forever if neither `event_retention_txs` nor `event_retention_days` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub event_retention_txs: Option<u64>,
+    /// Prune the events of the transactions sequenced more than N days ago.
+    /// The sequencing time is observed by the pruner, so the window starts when the pruner first runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub event_retention_days: Option<u64>,
+    /// Archive the pruned events to the directory before pruning them, the archive files can be replayed
+    /// to rebuild an indexer. The pruned events are dropped if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub event_archive_dir: Option<PathBuf>,
+    /// The interval in seconds of the event pruning, defaults to 3600 seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub event_prune_interval_secs: Option<u64>,
+
+    /// Move the transactions and change sets older than the last N transactions to the cold store segments,
+    /// they are still readable through the store. Nothing is moved if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub cold_storage_keep_txs: Option<u64>,
+    /// The interval in seconds of moving the old transactions to the cold store, defaults to 3600 seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "cold_storage_keep_txs")]
+    pub cold_storage_interval_secs: Option<u64>,
+
+    /// The YAML file of the webhooks, the node posts the executed transactions and events matching the filters
+    /// of the webhooks to their urls. The webhooks are disabled if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub webhook_config: Option<PathBuf>,
+
+    /// The YAML file of the per-block gas quotas of the module addresses, the sequencer rejects the further calls
+    /// to a module address once its quota of the current block is exhausted. No quota is enforced if it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub module_gas_quota_config: Option<PathBuf>,
+
+    /// Record the changes of the executed transactions and serve them with `rooch_getChangeFeed`,
+    /// so the read replicas can follow this node.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub change_feed: Option<bool>,
+    /// Run as a read replica of the primary node at the RPC url, the node applies the change feed of the primary
+    /// without executing the transactions, and rejects the writes. It must be started with the primary's chain id
+    /// and sequencer account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub replica_of: Option<String>,
+    /// The interval in milliseconds of polling the change feed of the primary node, defaults to 1000 ms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "replica_of")]
+    pub replica_poll_interval_ms: Option<u64>,
+
+    /// Serve the writes even if the embedded framework mismatches the on-chain framework.
+    /// By default, the node is read only on mismatch, only override it when you know the mismatch is harmless.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub allow_framework_mismatch: Option<bool>,
 }
 
 impl std::fmt::Display for RoochOpt {
@@ -152,6 +362,13 @@ impl RoochOpt {
             chain_id: Some(RoochChainID::LOCAL),
             store: StoreConfig::default(),
             port: None,
+            max_request_body_size: None,
+            tls_cert: None,
+            tls_key: None,
+            cors_config: None,
+            explorer: None,
+            rpc_max_in_flight: None,
+            rpc_latency_budget_ms: None,
             eth_rpc_url: None,
             btc_rpc_url: None,
             btc_rpc_username: None,
@@ -161,7 +378,37 @@ impl RoochOpt {
             sequencer_account: None,
             proposer_account: None,
             relayer_account: None,
+            next_sequencer_account: None,
+            sequencer_handover_window_ms: None,
+            committee_key_file: None,
+            committee_operators: vec![],
+            committee_sequencer_account: None,
             da: None,
+            max_view_concurrency: None,
+            view_timeout_ms: None,
+            view_max_gas_amount: None,
+            gas_estimate_margin_percentage: None,
+            block_gas_limit: None,
+            gas_meter: None,
+            gas_trace_dir: None,
+            gas_trace_format: None,
+            genesis_cost_table_config: None,
+            migration_dry_run: None,
+            migration_batch_size: None,
+            telemetry_endpoint: None,
+            telemetry_interval_secs: None,
+            event_retention_txs: None,
+            event_retention_days: None,
+            event_archive_dir: None,
+            event_prune_interval_secs: None,
+            cold_storage_keep_txs: None,
+            cold_storage_interval_secs: None,
+            webhook_config: None,
+            module_gas_quota_config: None,
+            change_feed: None,
+            replica_of: None,
+            replica_poll_interval_ms: None,
+            allow_framework_mismatch: None,
         }
     }
 
@@ -182,6 +429,151 @@ impl RoochOpt {
             btc_start_block_height: self.btc_start_block_height,
         })
     }
+
+    pub fn view_execution_config(&self) -> ViewExecutionConfig {
+        let default = ViewExecutionConfig::default();
+        ViewExecutionConfig {
+            max_concurrency: self.max_view_concurrency.unwrap_or(default.max_concurrency),
+            timeout_ms: self.view_timeout_ms.unwrap_or(default.timeout_ms),
+            max_gas_amount: self.view_max_gas_amount.unwrap_or(default.max_gas_amount),
+            gas_meter_kind: self.gas_meter_kind(),
+        }
+    }
+
+    pub fn gas_meter_kind(&self) -> GasMeterKind {
+        self.gas_meter.unwrap_or_default()
+    }
+
+    pub fn gas_estimate_margin_percentage(&self) -> u64 {
+        self.gas_estimate_margin_percentage
+            .unwrap_or(DEFAULT_GAS_ESTIMATE_MARGIN_PERCENTAGE)
+    }
+
+    pub fn block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+            .unwrap_or(DEFAULT_TARGET_BLOCK_GAS.saturating_mul(BLOCK_GAS_LIMIT_TARGET_MULTIPLIER))
+    }
+
+    /// The max gas used by the executed transactions waiting to be proposed, the sequencer rejects the transactions beyond it
+    pub fn max_buffered_gas(&self) -> u64 {
+        self.block_gas_limit().saturating_mul(MAX_BUFFERED_BLOCKS)
+    }
+
+    pub fn tls_config(&self) -> Option<TlsConfig> {
+        match (&self.tls_cert, &self.tls_key) {
+            (Some(cert_path), Some(key_path)) => Some(TlsConfig {
+                cert_path: cert_path.clone(),
+                key_path: key_path.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn cors_config(&self) -> Result<CorsConfig> {
+        match &self.cors_config {
+            Some(path) => CorsConfig::load(path),
+            None => Ok(CorsConfig {
+                allow_origins: std::env::var("ACCESS_CONTROL_ALLOW_ORIGIN")
+                    .map(|value| value.split(',').map(ToOwned::to_owned).collect())
+                    .unwrap_or_default(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    pub fn load_shed_config(&self) -> Option<LoadShedConfig> {
+        if self.rpc_max_in_flight.is_none() && self.rpc_latency_budget_ms.is_none() {
+            return None;
+        }
+        Some(LoadShedConfig {
+            max_in_flight: self.rpc_max_in_flight.unwrap_or(DEFAULT_RPC_MAX_IN_FLIGHT),
+            latency_budget_ms: self
+                .rpc_latency_budget_ms
+                .unwrap_or(DEFAULT_RPC_LATENCY_BUDGET_MS),
+            ..Default::default()
+        })
+    }
+
+    pub fn telemetry_config(&self) -> Option<TelemetryConfig> {
+        self.telemetry_endpoint
+            .as_ref()
+            .map(|endpoint| TelemetryConfig {
+                endpoint: endpoint.clone(),
+                interval_secs: self
+                    .telemetry_interval_secs
+                    .unwrap_or(DEFAULT_TELEMETRY_INTERVAL_SECS),
+            })
+    }
+
+    /// The event retention policy, `None` if the events are kept forever.
+    /// An empty retention window would prune the events of every transaction as soon as it is executed,
+    /// so it is rejected.
+    pub fn event_retention_config(&self) -> Result<Option<EventRetentionConfig>> {
+        if self.event_retention_txs.is_none() && self.event_retention_days.is_none() {
+            return Ok(None);
+        }
+        anyhow::ensure!(
+            self.event_retention_txs != Some(0),
+            "The event retention txs must be greater than 0"
+        );
+        anyhow::ensure!(
+            self.event_retention_days != Some(0),
+            "The event retention days must be greater than 0"
+        );
+        Ok(Some(EventRetentionConfig {
+            keep_txs: self.event_retention_txs,
+            keep_days: self.event_retention_days,
+            archive_dir: self.event_archive_dir.clone(),
+            interval_secs: self
+                .event_prune_interval_secs
+                .unwrap_or(DEFAULT_EVENT_PRUNE_INTERVAL_SECS),
+        }))
+    }
+
+    /// The tiering policy of the cold store, `None` if all the transactions are kept in the hot store
+    pub fn cold_storage_config(&self) -> Option<ColdStorageConfig> {
+        self.cold_storage_keep_txs
+            .map(|keep_txs| ColdStorageConfig {
+                keep_txs,
+                interval_secs: self
+                    .cold_storage_interval_secs
+                    .unwrap_or(DEFAULT_COLD_STORAGE_INTERVAL_SECS),
+            })
+    }
+
+    pub fn webhook_config(&self) -> Result<Option<WebhookConfig>> {
+        self.webhook_config
+            .as_ref()
+            .map(WebhookConfig::load)
+            .transpose()
+    }
+
+    pub fn module_gas_quota_config(&self) -> Result<Option<ModuleGasQuotaConfig>> {
+        self.module_gas_quota_config
+            .as_ref()
+            .map(ModuleGasQuotaConfig::load)
+            .transpose()
+    }
+
+    /// The gas schedule entries of the genesis cost table config, empty if it is not set
+    pub fn genesis_gas_schedule_entries(&self) -> Result<Vec<GasEntry>> {
+        Ok(self
+            .genesis_cost_table_config
+            .as_ref()
+            .map(CostTableConfig::load)
+            .transpose()?
+            .map(|config| config.gas_schedule_entries())
+            .unwrap_or_default())
+    }
+
+    pub fn migration_config(&self) -> MigrationConfig {
+        MigrationConfig {
+            dry_run: self.migration_dry_run.unwrap_or(false),
+            batch_size: self
+                .migration_batch_size
+                .unwrap_or(DEFAULT_MIGRATION_BATCH_SIZE),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -197,6 +589,85 @@ pub struct BitcoinRelayerConfig {
     pub btc_start_block_height: Option<u64>,
 }
 
+pub const DEFAULT_MAX_VIEW_CONCURRENCY: usize = 4;
+pub const DEFAULT_VIEW_TIMEOUT_MS: u64 = 5000;
+pub const DEFAULT_VIEW_MAX_GAS_AMOUNT: u64 = 100_000_000;
+
+/// The limits of the view function calls and dry runs from the RPC,
+/// so expensive calls can not starve the transaction execution.
+#[derive(Debug, Clone)]
+pub struct ViewExecutionConfig {
+    pub max_concurrency: usize,
+    pub timeout_ms: u64,
+    pub max_gas_amount: u64,
+    /// The gas meter of the dry runs, the same as the meter of the transactions
+    pub gas_meter_kind: GasMeterKind,
+}
+
+impl Default for ViewExecutionConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: DEFAULT_MAX_VIEW_CONCURRENCY,
+            timeout_ms: DEFAULT_VIEW_TIMEOUT_MS,
+            max_gas_amount: DEFAULT_VIEW_MAX_GAS_AMOUNT,
+            gas_meter_kind: GasMeterKind::default(),
+        }
+    }
+}
+
+/// The default block gas limit is the multiple of the target block gas, so the base gas price rises under the load
+pub const BLOCK_GAS_LIMIT_TARGET_MULTIPLIER: u64 = 2;
+
+/// The sequencer rejects the transactions while the executed transactions waiting to be proposed
+/// use more gas than the blocks of the number
+pub const MAX_BUFFERED_BLOCKS: u64 = 8;
+
+pub const DEFAULT_GAS_ESTIMATE_MARGIN_PERCENTAGE: u64 = 20;
+
+pub const DEFAULT_MIGRATION_BATCH_SIZE: usize = 100;
+
+/// The config of the state migrations which run after a framework upgrade
+#[derive(Debug, Clone)]
+pub struct MigrationConfig {
+    pub dry_run: bool,
+    pub batch_size: usize,
+}
+
+pub const DEFAULT_TELEMETRY_INTERVAL_SECS: u64 = 600;
+
+/// The config of the opt-in telemetry reporter
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    pub endpoint: String,
+    pub interval_secs: u64,
+}
+
+pub const DEFAULT_EVENT_PRUNE_INTERVAL_SECS: u64 = 3600;
+
+/// The retention policy of the event store, the events of a transaction are pruned once they are out of
+/// every configured window.
+#[derive(Debug, Clone)]
+pub struct EventRetentionConfig {
+    /// Keep the events of the last N transactions
+    pub keep_txs: Option<u64>,
+    /// Keep the events of the transactions sequenced in the last N days
+    pub keep_days: Option<u64>,
+    /// The directory the pruned events are archived to
+    pub archive_dir: Option<PathBuf>,
+    pub interval_secs: u64,
+}
+
+pub const DEFAULT_COLD_STORAGE_INTERVAL_SECS: u64 = 3600;
+
+/// The tiering policy of the transactions and change sets, the old ones are moved from the RocksDB to the
+/// append-only segment files of the cold store.
+#[derive(Debug, Clone)]
+pub struct ColdStorageConfig {
+    /// Keep the last N transactions in the hot store
+    pub keep_txs: u64,
+    pub interval_secs: u64,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct BaseConfig {
     pub chain_id: RoochChainID,
@@ -250,6 +721,8 @@ pub struct ServerOpt {
     pub sequencer_keypair: Option<RoochKeyPair>,
     pub proposer_keypair: Option<RoochKeyPair>,
     pub relayer_keypair: Option<RoochKeyPair>,
+    /// The next sequencer keypair during the sequencer key rotation
+    pub next_sequencer_keypair: Option<RoochKeyPair>,
     pub active_env: Option<String>,
 }
 
@@ -269,6 +742,7 @@ impl ServerOpt {
             sequencer_keypair: None,
             proposer_keypair: None,
             relayer_keypair: None,
+            next_sequencer_keypair: None,
             active_env: None,
         }
     }
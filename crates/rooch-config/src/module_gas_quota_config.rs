@@ -0,0 +1,21 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use serde::{Deserialize, Serialize};
+
+/// The per-block gas quotas of the module addresses enforced by the sequencer, loaded from a YAML file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleGasQuotaConfig {
+    pub quotas: Vec<ModuleGasQuota>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleGasQuota {
+    /// The module address, such as `0x42`, the calls to the functions of all the modules under it share the quota
+    pub module_address: String,
+    /// The aggregate gas of the calls to the module address in a block
+    pub gas_per_block: u64,
+}
+
+impl Config for ModuleGasQuotaConfig {}
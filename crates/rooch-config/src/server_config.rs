@@ -4,13 +4,134 @@
 use crate::config::Config;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter, Result, Write};
+use std::path::PathBuf;
+
+/// The default max request body size of the RPC server, 10 MB.
+/// Large module publish transactions should fit into it.
+pub const DEFAULT_MAX_REQUEST_BODY_SIZE: u32 = 10 * 1024 * 1024;
+pub const DEFAULT_RPC_MAX_IN_FLIGHT: usize = 1024;
+pub const DEFAULT_RPC_LATENCY_BUDGET_MS: u64 = 1000;
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub block_propose_duration_in_seconds: u16,
+    /// The max request body size in bytes, the request exceeds the limit is rejected before deserialization
+    #[serde(default = "default_max_request_body_size")]
+    pub max_request_body_size: u32,
+    #[serde(default)]
+    pub cors: CorsConfig,
+    /// Serve HTTPS with the certificate if it is set, otherwise serve HTTP
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// Serve the explorer-lite page on `/explorer`, it is disabled by default
+    #[serde(default)]
+    pub explorer: bool,
+    /// Reject the cheapest-to-reject calls when the server is saturated, it is disabled by default
+    #[serde(default)]
+    pub load_shed: Option<LoadShedConfig>,
+}
+
+/// The CORS config of the RPC server
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct CorsConfig {
+    /// The allowed origins, any origin is allowed if it is empty
+    #[serde(default)]
+    pub allow_origins: Vec<String>,
+    /// The allowed HTTP methods, defaults to `POST`
+    #[serde(default)]
+    pub allow_methods: Vec<String>,
+    /// The allowed request headers besides `Content-Type` and the RPC authentication headers
+    #[serde(default)]
+    pub allow_headers: Vec<String>,
+    /// The allowed origins of the JSON-RPC namespaces, such as `rooch`, `eth` and `btc`,
+    /// they override the `allow_origins` for the methods in the namespace.
+    #[serde(default)]
+    pub namespace_origins: BTreeMap<String, Vec<String>>,
+}
+
+impl CorsConfig {
+    /// The allowed origins of the method, any origin is allowed if it is empty
+    pub fn method_origins(&self, method: &str) -> &[String] {
+        method
+            .split_once('_')
+            .and_then(|(namespace, _)| self.namespace_origins.get(namespace))
+            .unwrap_or(&self.allow_origins)
+    }
+
+    pub fn is_origin_allowed(&self, method: &str, origin: &str) -> bool {
+        let origins = self.method_origins(method);
+        origins.is_empty() || origins.iter().any(|o| o == "*" || o == origin)
+    }
+
+    /// The origins allowed by any namespace, any origin is allowed if it is empty
+    pub fn all_origins(&self) -> Vec<String> {
+        if self.allow_origins.is_empty()
+            || self
+                .namespace_origins
+                .values()
+                .any(|origins| origins.is_empty() || origins.iter().any(|o| o == "*"))
+        {
+            return vec![];
+        }
+        let mut origins = self.allow_origins.clone();
+        for origin in self.namespace_origins.values().flatten() {
+            if !origins.contains(origin) {
+                origins.push(origin.clone());
+            }
+        }
+        origins
+    }
+}
+
+impl Config for CorsConfig {}
+
+/// The load shedding config of the RPC server
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct LoadShedConfig {
+    /// The server is saturated when the calls in flight reach the limit
+    pub max_in_flight: usize,
+    /// The server is saturated when the average latency of a method exceeds its latency budget in milliseconds
+    pub latency_budget_ms: u64,
+    /// The latency budgets of the methods which override the `latency_budget_ms`, keyed by the method name
+    #[serde(default)]
+    pub method_latency_budgets_ms: BTreeMap<String, u64>,
+}
+
+impl LoadShedConfig {
+    pub fn method_latency_budget_ms(&self, method: &str) -> u64 {
+        self.method_latency_budgets_ms
+            .get(method)
+            .copied()
+            .unwrap_or(self.latency_budget_ms)
+    }
+}
+
+impl Default for LoadShedConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: DEFAULT_RPC_MAX_IN_FLIGHT,
+            latency_budget_ms: DEFAULT_RPC_LATENCY_BUDGET_MS,
+            method_latency_budgets_ms: BTreeMap::new(),
+        }
+    }
+}
+
+/// The TLS certificate of the RPC server.
+/// ACME is not built in, the certificate can be issued and renewed by an ACME client such as certbot.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct TlsConfig {
+    /// The PEM file of the certificate chain
+    pub cert_path: PathBuf,
+    /// The PEM file of the private key
+    pub key_path: PathBuf,
+}
+
+fn default_max_request_body_size() -> u32 {
+    DEFAULT_MAX_REQUEST_BODY_SIZE
 }
 
 impl ServerConfig {
@@ -36,6 +157,14 @@ impl Display for ServerConfig {
 
         writeln!(writer, "host : {}", self.host)?;
         writeln!(writer, "port : {}", self.port)?;
+        writeln!(
+            writer,
+            "max_request_body_size : {}",
+            self.max_request_body_size
+        )?;
+        writeln!(writer, "tls : {}", self.tls.is_some())?;
+        writeln!(writer, "explorer : {}", self.explorer)?;
+        writeln!(writer, "load_shed : {}", self.load_shed.is_some())?;
 
         write!(f, "{}", writer)
     }
@@ -47,6 +176,40 @@ impl Default for ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 50051,
             block_propose_duration_in_seconds: 5,
+            max_request_body_size: DEFAULT_MAX_REQUEST_BODY_SIZE,
+            cors: CorsConfig::default(),
+            tls: None,
+            explorer: false,
+            load_shed: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cors_namespace_origins() {
+        let mut config = CorsConfig {
+            allow_origins: vec!["https://a.com".to_owned()],
+            ..Default::default()
+        };
+        config.namespace_origins.insert(
+            "eth".to_owned(),
+            vec!["https://a.com".to_owned(), "https://b.com".to_owned()],
+        );
+        assert!(config.is_origin_allowed("rooch_getStates", "https://a.com"));
+        assert!(!config.is_origin_allowed("rooch_getStates", "https://b.com"));
+        assert!(config.is_origin_allowed("eth_chainId", "https://b.com"));
+        assert!(!config.is_origin_allowed("eth_chainId", "https://c.com"));
+        assert_eq!(
+            config.all_origins(),
+            vec!["https://a.com".to_owned(), "https://b.com".to_owned()]
+        );
+
+        config.namespace_origins.insert("btc".to_owned(), vec![]);
+        assert!(config.is_origin_allowed("btc_queryUTXOs", "https://c.com"));
+        assert!(config.all_origins().is_empty());
+    }
+}
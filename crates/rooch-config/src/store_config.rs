@@ -15,8 +15,9 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 pub static R_DEFAULT_DB_DIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("roochdb"));
-static R_DEFAULT_DB_MOVEOS_SUBDIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("moveos_store"));
+pub static R_DEFAULT_DB_MOVEOS_SUBDIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("moveos_store"));
 static R_DEFAULT_DB_ROOCH_SUBDIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("rooch_store"));
+static R_DEFAULT_DB_COLD_SUBDIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("cold_store"));
 
 pub const DEFAULT_CACHE_SIZE: usize = 20000;
 
@@ -54,6 +55,14 @@ pub struct StoreConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(name = "rocksdb-bytes-per-sync", long, help = "rocksdb bytes per sync")]
     pub bytes_per_sync: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "cold-storage-dir",
+        long,
+        help = "the dir of the cold store segments, such as a mounted cheaper disk"
+    )]
+    pub cold_storage_dir: Option<PathBuf>,
 }
 
 impl StoreConfig {
@@ -106,6 +115,14 @@ impl StoreConfig {
             .join(R_DEFAULT_DB_ROOCH_SUBDIR.as_path())
     }
 
+    pub fn get_cold_store_dir(&self) -> PathBuf {
+        self.cold_storage_dir.clone().unwrap_or_else(|| {
+            self.data_dir()
+                .join(R_DEFAULT_DB_DIR.as_path())
+                .join(R_DEFAULT_DB_COLD_SUBDIR.as_path())
+        })
+    }
+
     pub fn rocksdb_config(&self) -> RocksdbConfig {
         let default = RocksdbConfig::default();
         RocksdbConfig {
@@ -144,6 +161,9 @@ impl ConfigModule for StoreConfig {
         if store_config.wal_bytes_per_sync.is_some() {
             self.wal_bytes_per_sync = store_config.wal_bytes_per_sync;
         }
+        if store_config.cold_storage_dir.is_some() {
+            self.cold_storage_dir = store_config.cold_storage_dir;
+        }
 
         Ok(())
     }
@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_WEBHOOK_MAX_RETRIES: u32 = 5;
+pub const DEFAULT_WEBHOOK_INITIAL_BACKOFF_MS: u64 = 1000;
+pub const DEFAULT_WEBHOOK_QUEUE_SIZE: usize = 1000;
+
+/// The webhooks notified of the executed transactions, loaded from a YAML file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub webhooks: Vec<WebhookTarget>,
+    /// The max retries of a failed delivery, defaults to 5
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// The backoff before the first retry in milliseconds, it is doubled on every retry, defaults to 1000 ms
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    /// The max notifications waiting to be delivered to a webhook, the new notifications are dropped
+    /// while the queue is full, defaults to 1000
+    #[serde(default = "default_queue_size")]
+    pub queue_size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookTarget {
+    pub url: String,
+    /// The secret to sign the payloads, the receiver verifies the `X-Rooch-Signature` header with it
+    pub secret: String,
+    /// The event types to notify, such as `0x3::coin::DepositEvent`, a module or address prefix like `0x3::coin`
+    /// matches all the event types in it. Any event type matches if it is empty.
+    #[serde(default)]
+    pub event_types: Vec<String>,
+    /// The transaction senders to notify, any sender matches if it is empty
+    #[serde(default)]
+    pub senders: Vec<String>,
+    /// Notify the matched transactions even if none of their events matches
+    #[serde(default)]
+    pub include_transactions: bool,
+}
+
+fn default_max_retries() -> u32 {
+    DEFAULT_WEBHOOK_MAX_RETRIES
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    DEFAULT_WEBHOOK_INITIAL_BACKOFF_MS
+}
+
+fn default_queue_size() -> usize {
+    DEFAULT_WEBHOOK_QUEUE_SIZE
+}
+
+impl Config for WebhookConfig {}
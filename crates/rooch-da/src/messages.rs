@@ -4,17 +4,45 @@
 use coerce::actor::message::Message;
 use serde::{Deserialize, Serialize};
 
-use moveos_types::h256::H256;
+use moveos_types::h256::{self, H256};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BatchMeta {
     // each batch maps to a L2 block
     pub block_number: u128,
+    // tx order of the first transaction in the batch
+    pub first_tx_order: u64,
+    // hashes of the transactions in the batch, in the sequenced order
+    pub tx_hashes: Vec<H256>,
     // sha3_256 hash of the batch data
     pub batch_hash: H256,
-    // TODO use sequencer keypair to sign
-    // signature result of BatchMeta
-    pub signature: Vec<u8>,
+    // checksum of the execution results of the transactions in the batch,
+    // the follower nodes verify it after executing the batch to detect the divergence from the sequencer
+    pub execution_checksum: H256,
+    // tx accumulator roots before and after the batch
+    pub prev_tx_accumulator_root: H256,
+    pub tx_accumulator_root: H256,
+    // signatures of the BatchMeta signing hash by the sequencer keys,
+    // it contains the signatures of both the current and the next key during the sequencer key rotation handover window
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl BatchMeta {
+    /// The hash signed by the sequencer keys, it covers every field except the signatures,
+    /// `sha3_256(bcs(block_number, first_tx_order, tx_hashes, batch_hash, execution_checksum, prev_tx_accumulator_root, tx_accumulator_root))`
+    pub fn signing_hash(&self) -> H256 {
+        let bytes = bcs::to_bytes(&(
+            self.block_number,
+            self.first_tx_order,
+            &self.tx_hashes,
+            self.batch_hash,
+            self.execution_checksum,
+            self.prev_tx_accumulator_root,
+            self.tx_accumulator_root,
+        ))
+        .expect("Serialize BatchMeta should success");
+        h256::sha3_256_of(&bytes)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
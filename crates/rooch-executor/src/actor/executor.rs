@@ -1,11 +1,21 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::framework_version::{
+    check_framework_version, load_active_framework_address, may_change_framework_version,
+};
+use super::gas_schedule::GasScheduleSync;
+use super::gas_trace_writer::GasTraceWriter;
 use super::messages::{
-    ExecuteTransactionMessage, ExecuteTransactionResult, ResolveMessage, ValidateTransactionMessage,
+    ApplyChangeFeedEntryMessage, ExecuteTransactionMessage, ExecuteTransactionResult,
+    ResolveMessage, SaveSourceVerificationMessage, ValidateTransactionMessage,
+};
+use super::object_change_notifier::{
+    collect_object_changes, ObjectChangeNotifier, TxObjectChanges,
 };
+use crate::metrics::EXECUTOR_METRICS;
 use accumulator::inmemory::InMemoryAccumulator;
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use itertools::Itertools;
@@ -15,38 +25,65 @@ use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
 use move_core_types::value::MoveValue;
-use move_core_types::vm_status::{StatusCode, VMStatus};
-use moveos::gas::table::{initial_cost_schedule, MoveOSGasMeter};
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
+use moveos::gas::table::{CostTable, MoveOSGasMeter};
 use moveos::moveos::{GasPaymentAccount, MoveOS};
 use moveos::vm::vm_status_explainer::explain_vm_status;
+use moveos_store::state_store::statedb::StateDBStore;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
+use moveos_types::gas_config::{GasMeterKind, GasTraceFormat};
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::h256::H256;
-use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
 use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::event::Event;
 use moveos_types::moveos_std::tx_context::TxContext;
-use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
 use moveos_types::transaction::{
     FunctionCall, MoveAction, MoveOSTransaction, TransactionExecutionInfo, VerifiedMoveAction,
 };
+use moveos_types::transaction::{RawTransactionOutput, TransactionOutput};
 use moveos_verifier::metadata::load_module_metadata;
 use rooch_genesis::RoochGenesis;
+use rooch_store::change_feed_store::ChangeFeedStore;
+use rooch_store::gas_statistics_store::GasStatisticsStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::source_registry_store::SourceRegistryStore;
 use rooch_store::RoochStore;
 use rooch_types::address::MultiChainAddress;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::framework::address_mapping::AddressMapping;
 use rooch_types::framework::auth_validator::{AuthValidatorCaller, TxValidateResult};
+use rooch_types::framework::gas_sponsor::TxSponsor;
 use rooch_types::framework::genesis::GenesisContext;
+use rooch_types::framework::module_blocklist::{reachable_functions, ModuleBlocklistModule};
 use rooch_types::framework::transaction_validator::TransactionValidator;
-use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
-use rooch_types::transaction::{AbstractTransaction, AuthenticatorInfo};
+use rooch_types::framework::{
+    system_post_execute_functions, system_post_execute_functions_at, system_pre_execute_functions,
+    system_pre_execute_functions_at,
+};
+use rooch_types::replica::{ChangeFeedEntry, ReplicaChangeSet};
+use rooch_types::sequencer::SequencerOrder;
+use rooch_types::source_verification::SourceVerification;
+use rooch_types::transaction::{AbstractTransaction, AuthenticatorInfo, TypedTransaction};
+use std::path::PathBuf;
 
 pub struct ExecutorActor {
     genesis: RoochGenesis,
     moveos: MoveOS,
     rooch_store: RoochStore,
+    /// The address of the active framework version, the system functions are routed to it
+    framework_address: AccountAddress,
+    /// Record the changes of the transactions for the change feed of the read replicas
+    change_feed: bool,
+    /// Follow the on-chain gas schedule metering the transactions
+    gas_schedule: GasScheduleSync,
+    /// Write the gas traces of the executed transactions, the gas is not traced if it is `None`
+    gas_trace_writer: Option<GasTraceWriter>,
+    /// Publish the object changes of the executed transactions to the subscriptions
+    object_changes: Option<ObjectChangeNotifier>,
 }
 
 type ValidateAuthenticatorResult = Result<
@@ -68,20 +105,39 @@ impl ExecutorActor {
     ) -> Result<Self> {
         let genesis: RoochGenesis =
             rooch_genesis::RoochGenesis::build(genesis_ctx, bitcoin_genesis_ctx)?;
+        Self::new_with_genesis(genesis, moveos_store, rooch_store)
+    }
+
+    pub fn new_with_genesis(
+        genesis: RoochGenesis,
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+    ) -> Result<Self> {
         let moveos = MoveOS::new(
             moveos_store,
-            genesis.all_natives(),
+            genesis.all_natives()?,
             genesis.config.clone(),
             system_pre_execute_functions(),
             system_post_execute_functions(),
         )?;
 
+        // The cost table of the genesis config is replaced by the one stored on chain once the genesis is executed
+        let gas_schedule = GasScheduleSync::new(genesis.cost_table()?);
         let executor = Self {
             genesis,
             moveos,
             rooch_store,
+            framework_address: ROOCH_FRAMEWORK_ADDRESS,
+            change_feed: false,
+            gas_schedule,
+            gas_trace_writer: None,
+            object_changes: None,
         };
-        executor.init_or_check_genesis()
+        let mut executor = executor.init_or_check_genesis()?;
+        executor.gas_schedule = GasScheduleSync::load(executor.moveos())?;
+        executor.route_framework_version()?;
+        executor.sync_gas_schedule();
+        Ok(executor)
     }
 
     fn init_or_check_genesis(mut self) -> Result<Self> {
@@ -125,10 +181,56 @@ impl ExecutorActor {
         &self.moveos
     }
 
+    /// The cost table of the builtin gas schedule stored at genesis
+    pub fn genesis_cost_table(&self) -> &CostTable {
+        self.gas_schedule.genesis_cost_table()
+    }
+
+    /// The version of the on-chain gas schedule metering the transactions
+    pub fn gas_schedule_version(&self) -> Option<u64> {
+        self.gas_schedule.version()
+    }
+
+    pub fn moveos_mut(&mut self) -> &mut MoveOS {
+        &mut self.moveos
+    }
+
     pub fn genesis(&self) -> &RoochGenesis {
         &self.genesis
     }
 
+    /// Meter the transactions with the cost table instead of the builtin gas schedule
+    pub fn set_cost_table(&mut self, cost_table: CostTable) {
+        self.moveos.set_cost_table(cost_table);
+    }
+
+    pub fn set_gas_meter_kind(&mut self, gas_meter_kind: GasMeterKind) {
+        self.moveos.set_gas_meter_kind(gas_meter_kind);
+    }
+
+    /// Record the changes of the executed transactions, the read replicas pull them from the change feed
+    pub fn set_change_feed(&mut self, enabled: bool) {
+        self.change_feed = enabled;
+    }
+
+    /// Write the gas call tree of every executed transaction to the `dir` in the `format`,
+    /// the file is named by the transaction hash.
+    pub fn set_gas_trace_dir(
+        &mut self,
+        dir: Option<PathBuf>,
+        format: GasTraceFormat,
+    ) -> Result<()> {
+        self.gas_trace_writer = dir
+            .map(|dir| GasTraceWriter::new(dir, format))
+            .transpose()?;
+        Ok(())
+    }
+
+    /// Publish the object changes of the executed transactions to the `notifier`
+    pub fn set_object_change_notifier(&mut self, notifier: ObjectChangeNotifier) {
+        self.object_changes = Some(notifier);
+    }
+
     pub fn resolve_or_generate(
         &self,
         multi_chain_address_sender: MultiChainAddress,
@@ -143,8 +245,237 @@ impl ExecutorActor {
 
     pub fn execute(&mut self, tx: VerifiedMoveOSTransaction) -> Result<ExecuteTransactionResult> {
         let tx_hash = tx.ctx.tx_hash();
-        let (state_root, output) = self.moveos.execute_and_apply(tx)?;
-        self.handle_tx_output(tx_hash, state_root, output)
+        let function_id = match &tx.action {
+            VerifiedMoveAction::Function { call } => Some(call.function_id.clone()),
+            _ => None,
+        };
+        // The object states before the transaction are read at the state root before it
+        let pre_state_root = self.moveos.state().state_root();
+        let (state_root, output) = match &self.gas_trace_writer {
+            Some(writer) => {
+                let (state_root, output, gas_trace) =
+                    self.moveos.execute_and_apply_with_gas_trace(tx)?;
+                writer.write(tx_hash, gas_trace);
+                (state_root, output)
+            }
+            None => self.moveos.execute_and_apply(tx)?,
+        };
+        EXECUTOR_METRICS
+            .system_gas_used_total
+            .inc_by(output.gas_breakdown.system_gas);
+        if let Some(function_id) = function_id {
+            self.record_gas_statistics(function_id, output.gas_used)?;
+        }
+        let result = self.handle_tx_output(tx_hash, state_root, output)?;
+        self.record_change_set(tx_hash, &result.output)?;
+        self.notify_object_changes(tx_hash, pre_state_root, &result.output);
+        if may_change_framework_version(&result.output) {
+            self.route_framework_version()?;
+        }
+        self.sync_gas_schedule();
+        Ok(result)
+    }
+
+    /// Apply the transaction replicated from the primary node without executing it.
+    /// The changes are verified with the state root, and the rebuilt execution info must be the same as the primary's,
+    /// so the replica serves the same states, transactions and events as the primary.
+    pub fn apply_change_feed_entry(
+        &mut self,
+        entry: ChangeFeedEntry,
+    ) -> Result<ExecuteTransactionResult> {
+        let ChangeFeedEntry {
+            tx,
+            sequence_info,
+            execution_info,
+            change_set,
+        } = entry;
+        let tx_hash = tx.tx_hash();
+        ensure!(
+            tx_hash == execution_info.tx_hash,
+            "The transaction hash {:?} mismatches the execution info {:?}",
+            tx_hash,
+            execution_info.tx_hash
+        );
+        let is_upgrade = change_set.is_upgrade;
+        let (changeset, state_changeset, events) = change_set.into_change_sets()?;
+        let event_ids = self.moveos.apply_replicated_changes(
+            changeset.clone(),
+            state_changeset.clone(),
+            events.clone(),
+            execution_info.state_root,
+        )?;
+        let output = TransactionOutput {
+            status: execution_info.status.clone(),
+            changeset,
+            state_changeset,
+            events: events
+                .into_iter()
+                .zip(event_ids)
+                .map(|(event, event_id)| Event::new_with_event_id(event_id, event))
+                .collect(),
+            gas_used: execution_info.gas_used,
+            is_upgrade,
+            out_of_gas_instructions: execution_info.out_of_gas_instructions,
+            // The committed execution never times out
+            execution_timed_out: false,
+            // The dimension is not recorded in the execution info
+            out_of_gas_dimension: None,
+            // The replicated transactions are executed after the breakdown was recorded
+            gas_breakdown: execution_info.gas_breakdown.clone().unwrap_or_default(),
+        };
+        let result = self.handle_tx_output(tx_hash, execution_info.state_root, output)?;
+        ensure!(
+            result.transaction_info == execution_info,
+            "The replicated execution info {:?} mismatches the primary's {:?}",
+            result.transaction_info,
+            execution_info
+        );
+
+        let tx_order = sequence_info.tx_order;
+        self.rooch_store.transaction_store.save_transaction(tx)?;
+        self.rooch_store
+            .transaction_store
+            .save_tx_sequence_info_mapping(tx_order, tx_hash)?;
+        self.rooch_store
+            .transaction_store
+            .save_tx_sequence_info_reverse_mapping(tx_hash, tx_order)?;
+        self.rooch_store
+            .transaction_store
+            .save_tx_sequence_info(sequence_info)?;
+        self.rooch_store
+            .save_sequencer_order(SequencerOrder::new(tx_order))?;
+        self.record_change_set(tx_hash, &result.output)?;
+
+        if is_upgrade {
+            self.moveos
+                .refresh_state(result.transaction_info.state_root, is_upgrade)?;
+        }
+        if may_change_framework_version(&result.output) {
+            self.route_framework_version()?;
+        }
+        self.sync_gas_schedule();
+        Ok(result)
+    }
+
+    /// Execute the transaction on the state of `state_root` without applying the output, for replaying the
+    /// historical transactions in a sandbox. The executor must have its own state root, not shared with the node.
+    pub fn execute_at(
+        &mut self,
+        state_root: H256,
+        tx: TypedTransaction,
+    ) -> Result<RawTransactionOutput> {
+        // The modules may be different at the state root, so the loader cache is invalidated
+        self.moveos.refresh_state(state_root, true)?;
+        self.route_framework_version()?;
+        let verified_tx = self.validate(tx)?;
+        self.moveos.execute(verified_tx)
+    }
+
+    /// Publish the object changes of the sequenced transaction to the subscriptions, the transactions are executed
+    /// in the tx order, so the changes are published in the tx order. A failure does not fail the transaction.
+    fn notify_object_changes(
+        &self,
+        tx_hash: H256,
+        pre_state_root: H256,
+        output: &TransactionOutput,
+    ) {
+        let notifier = match &self.object_changes {
+            Some(notifier) if notifier.has_subscriptions() => notifier,
+            _ => return,
+        };
+        let tx_order = match self
+            .rooch_store
+            .transaction_store
+            .multi_get_tx_sequence_info_mapping_by_hash(vec![tx_hash])
+        {
+            Ok(mut mappings) => match mappings.pop().flatten() {
+                Some(mapping) => mapping.tx_order,
+                None => return,
+            },
+            Err(e) => {
+                log::error!("Get the tx order of the tx {:?} error: {:?}", tx_hash, e);
+                return;
+            }
+        };
+        let pre_state = StateDBStore::new_with_root(
+            self.moveos.state().node_store.clone(),
+            Some(pre_state_root),
+        );
+        match collect_object_changes(&pre_state, &output.state_changeset) {
+            Ok(changes) if changes.is_empty() => {}
+            Ok(changes) => notifier.notify(TxObjectChanges {
+                tx_order,
+                tx_hash,
+                changes,
+            }),
+            Err(e) => log::error!(
+                "Collect the object changes of the tx {:?} error: {:?}",
+                tx_hash,
+                e
+            ),
+        }
+    }
+
+    fn record_change_set(&self, tx_hash: H256, output: &TransactionOutput) -> Result<()> {
+        if !self.change_feed {
+            return Ok(());
+        }
+        self.rooch_store.save_replica_change_set(
+            tx_hash,
+            ReplicaChangeSet::new(
+                &output.changeset,
+                &output.state_changeset,
+                &output.events,
+                output.is_upgrade,
+            ),
+        )
+    }
+
+    /// Route the system functions to the framework version activated in the `framework_versions` module.
+    /// The packages built against other versions keep linking to the modules at their own addresses.
+    /// An unreadable or unusable version is an error, the transactions are not validated by a guessed framework.
+    fn route_framework_version(&mut self) -> Result<()> {
+        let framework_address = load_active_framework_address(self.moveos())?;
+        if framework_address != self.framework_address {
+            check_framework_version(self.moveos(), framework_address)?;
+            log::info!(
+                "Route the system functions from framework {} to {}",
+                self.framework_address.to_hex_literal(),
+                framework_address.to_hex_literal()
+            );
+            self.moveos.set_system_functions(
+                system_pre_execute_functions_at(framework_address),
+                system_post_execute_functions_at(framework_address),
+            );
+            self.framework_address = framework_address;
+        }
+        Ok(())
+    }
+
+    /// The address of the framework version the system functions are routed to
+    pub fn framework_address(&self) -> AccountAddress {
+        self.framework_address
+    }
+
+    /// Switch to the on-chain gas schedule when its version changes, such as at the upgrade epoch of a new schedule.
+    fn sync_gas_schedule(&mut self) {
+        self.gas_schedule.sync(&mut self.moveos, &self.genesis);
+    }
+
+    /// Accumulate the gas used per entry function, for finding out which contracts dominate the compute.
+    fn record_gas_statistics(&self, function_id: FunctionId, gas_used: u64) -> Result<()> {
+        let module_name = function_id.module_id.to_string();
+        let function_name = function_id.function_name.as_str();
+        EXECUTOR_METRICS
+            .function_gas_used_total
+            .with_label_values(&[module_name.as_str(), function_name])
+            .inc_by(gas_used);
+        EXECUTOR_METRICS
+            .function_call_total
+            .with_label_values(&[module_name.as_str(), function_name])
+            .inc();
+        self.rooch_store
+            .record_function_gas_used(function_id, gas_used)
     }
 
     fn handle_tx_output(
@@ -162,6 +493,8 @@ impl ExecutorActor {
             event_root,
             output.gas_used,
             output.status.clone(),
+            output.out_of_gas_instructions,
+            output.gas_breakdown.clone(),
         );
         self.moveos()
             .transaction_store()
@@ -173,6 +506,19 @@ impl ExecutorActor {
                     e
                 )
             })?;
+        // The event ids are saved in the same order as the event accumulator leaves,
+        // so the event proof can be rebuilt from the tx hash.
+        let event_ids = output.events.iter().map(|e| e.event_id).collect();
+        self.moveos()
+            .event_store()
+            .save_tx_event_ids(tx_hash, event_ids)
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "ExecuteTransactionMessage handler save tx event ids failed: {:?} {}",
+                    tx_hash,
+                    e
+                )
+            })?;
         Ok(ExecuteTransactionResult {
             output,
             transaction_info,
@@ -217,6 +563,13 @@ impl ExecutorActor {
             }
         }
 
+        // The gas of a sponsored transaction is paid by the sponsor, unless the module account pays it
+        if !pay_by_module_account {
+            if let Some(tx_sponsor) = moveos_tx.ctx.get::<TxSponsor>()? {
+                gas_payment_account = tx_sponsor.sponsor;
+            }
+        }
+
         moveos_tx
             .ctx
             .add(GasPaymentAccount {
@@ -244,6 +597,14 @@ impl ExecutorActor {
                     .add(tx_validate_result)
                     .expect("add tx_validate_result failed");
 
+                if let Err(e) = self.check_blocked_functions(&moveos_tx.action)? {
+                    log::warn!(
+                        "transaction calls a blocked function, tx_hash: {}",
+                        moveos_tx.ctx.tx_hash()
+                    );
+                    return Err(e.into());
+                }
+
                 moveos_tx.append_pre_execute_functions(pre_execute_functions);
                 moveos_tx.append_post_execute_functions(post_execute_functions);
                 Ok(self.moveos().verify(moveos_tx)?)
@@ -261,12 +622,36 @@ impl ExecutorActor {
         }
     }
 
+    /// Reject the transaction calling a blocked module or function from the entry function, the script or
+    /// the other modules, with the same abort as the `transaction_validator` which only checks the entry function.
+    /// The reachable functions are only resolved when the blocklist is not empty.
+    fn check_blocked_functions(&self, action: &MoveAction) -> Result<Result<(), VMStatus>> {
+        let blocklist = self.moveos().as_module_binding::<ModuleBlocklistModule>();
+        if blocklist.is_empty()? {
+            return Ok(Ok(()));
+        }
+        let functions = reachable_functions(self.moveos().moveos_resolver(), action)?;
+        if blocklist.is_any_function_blocked(&functions)? {
+            return Ok(Err(VMStatus::MoveAbort(
+                AbortLocation::Module(ModuleId::new(
+                    self.framework_address,
+                    TransactionValidator::MODULE_NAME.to_owned(),
+                )),
+                TransactionValidator::ERROR_VALIDATE_MODULE_BLOCKED,
+            )));
+        }
+        Ok(Ok(()))
+    }
+
     pub fn validate_authenticator(
         &self,
         ctx: &TxContext,
         authenticator: AuthenticatorInfo,
     ) -> Result<ValidateAuthenticatorResult> {
-        let tx_validator = self.moveos().as_module_binding::<TransactionValidator>();
+        let tx_validator = self
+            .moveos()
+            .as_module_binding::<TransactionValidator>()
+            .with_framework_address(self.framework_address);
         let tx_validate_function_result = tx_validator
             .validate(ctx, authenticator.clone())?
             .into_result();
@@ -319,7 +704,7 @@ impl ExecutorActor {
     pub fn validate_gas_function(&self, tx: &MoveOSTransaction) -> VMResult<Option<bool>> {
         let MoveOSTransaction { ctx, .. } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let cost_table = self.moveos().cost_table().clone();
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
 
@@ -437,7 +822,7 @@ impl ExecutorActor {
     pub fn get_account_balance(&self, tx: &MoveOSTransaction) -> VMResult<u128> {
         let MoveOSTransaction { ctx, .. } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let cost_table = self.moveos().cost_table().clone();
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
 
@@ -519,3 +904,38 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
         self.execute(msg.tx)
     }
 }
+
+#[async_trait]
+impl Handler<SaveSourceVerificationMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: SaveSourceVerificationMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<SourceVerification> {
+        let verification = msg.verification;
+        // A mismatched submission does not override a verified source, so nobody can remove the verified badge
+        if !verification.status.is_verified() {
+            if let Some(previous) = self
+                .rooch_store
+                .get_source_verification(verification.source.package_address)?
+                .filter(|previous| previous.status.is_verified())
+            {
+                return Ok(previous);
+            }
+        }
+        self.rooch_store
+            .save_source_verification(verification.clone())?;
+        Ok(verification)
+    }
+}
+
+#[async_trait]
+impl Handler<ApplyChangeFeedEntryMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ApplyChangeFeedEntryMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<ExecuteTransactionResult> {
+        self.apply_change_feed_entry(msg.entry)
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, ensure, Result};
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::resolver::ModuleResolver;
+use moveos::moveos::MoveOS;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::transaction::TransactionOutput;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::framework_versions::{self, FrameworkVersionsModule};
+use rooch_types::framework::gas_coin;
+use rooch_types::framework::transaction_validator::TransactionValidator;
+
+/// The framework_versions module does not exist before the framework is upgraded to include it
+pub fn framework_versions_module_exists(moveos: &MoveOS) -> Result<bool> {
+    Ok(moveos
+        .moveos_resolver()
+        .get_module(&FrameworkVersionsModule::module_id())?
+        .is_some())
+}
+
+/// The address of the active framework version, `@rooch_framework` if the framework_versions module does not exist.
+/// The other errors are returned, the system functions are never routed to a guessed framework.
+pub fn load_active_framework_address(moveos: &MoveOS) -> Result<AccountAddress> {
+    if !framework_versions_module_exists(moveos)? {
+        return Ok(ROOCH_FRAMEWORK_ADDRESS);
+    }
+    moveos
+        .as_module_binding::<FrameworkVersionsModule>()
+        .active_framework_address()
+}
+
+/// The transaction validator of a framework version must charge the gas with the gas coin at `@rooch_framework`.
+/// The gas coin of a framework version is another type, the accounts have no balance of it to pay the gas.
+pub fn check_framework_version(moveos: &MoveOS, framework_address: AccountAddress) -> Result<()> {
+    if framework_address == ROOCH_FRAMEWORK_ADDRESS {
+        return Ok(());
+    }
+    let validator_id = ModuleId::new(
+        framework_address,
+        TransactionValidator::MODULE_NAME.to_owned(),
+    );
+    let bytes = moveos
+        .moveos_resolver()
+        .get_module(&validator_id)?
+        .ok_or_else(|| {
+            anyhow!(
+                "The framework version at {} has no transaction validator",
+                framework_address.to_hex_literal()
+            )
+        })?;
+    let validator = CompiledModule::deserialize(&bytes)?;
+    let gas_coin_id = ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, gas_coin::MODULE_NAME.to_owned());
+    ensure!(
+        validator.immediate_dependencies().contains(&gas_coin_id),
+        "The transaction validator of the framework version at {} does not charge the gas with {}",
+        framework_address.to_hex_literal(),
+        gas_coin_id
+    );
+    Ok(())
+}
+
+/// The active framework version changes only if the framework is upgraded or a version is activated,
+/// the routing is not read again after the other transactions.
+pub fn may_change_framework_version(output: &TransactionOutput) -> bool {
+    output.is_upgrade
+        || output
+            .events
+            .iter()
+            .any(|event| framework_versions::is_activated_event(&event.event_type))
+}
@@ -0,0 +1,127 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use move_core_types::resolver::ModuleResolver;
+use moveos::gas::table::{initial_cost_schedule, CostTable};
+use moveos::moveos::MoveOS;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use rooch_framework::natives::gas_parameter::gas_schedule::{
+    load_cost_table, load_genesis_cost_table,
+};
+use rooch_genesis::RoochGenesis;
+use rooch_types::framework::gas_schedule::{GasScheduleConfig, GasScheduleModule};
+
+/// The gas_schedule module does not exist before the framework is upgraded to include it
+pub fn gas_schedule_module_exists(moveos: &MoveOS) -> Result<bool> {
+    Ok(moveos
+        .moveos_resolver()
+        .get_module(&GasScheduleModule::module_id())?
+        .is_some())
+}
+
+/// Load the cost table of the genesis from the builtin gas schedule stored on chain, so it is the same on every node
+/// whatever genesis config the node is started with. The chains without the gas_schedule module have no genesis
+/// cost table entries, they are metered by the initial cost table.
+pub fn load_chain_genesis_cost_table(moveos: &MoveOS) -> Result<CostTable> {
+    if !gas_schedule_module_exists(moveos)? {
+        return Ok(initial_cost_schedule());
+    }
+    let gas_schedule = moveos
+        .as_module_binding::<GasScheduleModule>()
+        .genesis_gas_schedule()?;
+    load_genesis_cost_table(&gas_schedule.entries)
+}
+
+/// The active on-chain gas schedule, the builtin gas schedule if the gas_schedule module does not exist.
+/// The other errors are returned, an unreadable schedule is not replaced by the builtin one.
+pub fn load_current_gas_schedule(moveos: &MoveOS) -> Result<GasScheduleConfig> {
+    if !gas_schedule_module_exists(moveos)? {
+        return Ok(GasScheduleConfig::builtin());
+    }
+    moveos
+        .as_module_binding::<GasScheduleModule>()
+        .current_gas_schedule()
+}
+
+/// Follow the on-chain gas schedule of a MoveOS, the cost table and the natives are switched when the version
+/// of the active schedule changes, such as at the upgrade epoch of a new schedule.
+/// A schedule which can not be read or loaded is skipped, the MoveOS keeps metering with the previous schedule,
+/// and the schedule is loaded again at the next sync. The state is the same on every node, so the gas used is
+/// still deterministic.
+pub struct GasScheduleSync {
+    /// The cost table of the builtin gas schedule stored at genesis, the on-chain gas schedules override it
+    genesis_cost_table: CostTable,
+    /// The version of the gas schedule metering the transactions, `None` before a schedule is loaded
+    version: Option<u64>,
+}
+
+impl GasScheduleSync {
+    pub fn new(genesis_cost_table: CostTable) -> Self {
+        Self {
+            genesis_cost_table,
+            version: None,
+        }
+    }
+
+    /// Load the genesis cost table from the chain state of the `moveos`
+    pub fn load(moveos: &MoveOS) -> Result<Self> {
+        Ok(Self::new(load_chain_genesis_cost_table(moveos)?))
+    }
+
+    pub fn genesis_cost_table(&self) -> &CostTable {
+        &self.genesis_cost_table
+    }
+
+    pub fn version(&self) -> Option<u64> {
+        self.version
+    }
+
+    /// Switch the `moveos` to the active on-chain gas schedule if its version changes
+    pub fn sync(&mut self, moveos: &mut MoveOS, genesis: &RoochGenesis) {
+        let gas_schedule = match load_current_gas_schedule(moveos) {
+            Ok(gas_schedule) => gas_schedule,
+            Err(e) => {
+                log::error!(
+                    "Read the on-chain gas schedule error, keep metering with the schedule version {:?}: {:?}",
+                    self.version,
+                    e
+                );
+                return;
+            }
+        };
+        if Some(gas_schedule.schedule_version) == self.version {
+            return;
+        }
+        match self.switch(moveos, genesis, &gas_schedule) {
+            Ok(()) => {
+                log::info!(
+                    "Switch the gas schedule from version {:?} to {}",
+                    self.version,
+                    gas_schedule.schedule_version
+                );
+                self.version = Some(gas_schedule.schedule_version);
+            }
+            Err(e) => log::error!(
+                "Skip the invalid gas schedule version {}, keep metering with the schedule version {:?}: {:?}",
+                gas_schedule.schedule_version,
+                self.version,
+                e
+            ),
+        }
+    }
+
+    fn switch(
+        &self,
+        moveos: &mut MoveOS,
+        genesis: &RoochGenesis,
+        gas_schedule: &GasScheduleConfig,
+    ) -> Result<()> {
+        let cost_table = load_cost_table(&self.genesis_cost_table, gas_schedule)?;
+        let natives =
+            genesis.all_natives_with_gas_schedule(gas_schedule, cost_table.feature_version())?;
+        moveos.set_natives(natives)?;
+        moveos.set_cost_table(cost_table);
+        Ok(())
+    }
+}
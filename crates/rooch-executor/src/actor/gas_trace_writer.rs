@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use moveos::gas::tracing::GasTrace;
+use moveos_types::gas_config::GasTraceFormat;
+use moveos_types::h256::H256;
+use std::path::PathBuf;
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+
+/// The traces waiting to be written, the traces exceeding it are dropped instead of blocking the execution
+const GAS_TRACE_QUEUE_SIZE: usize = 1024;
+
+/// Write the gas traces of the executed transactions to the files in a background thread,
+/// the rendering and the file io are not on the path of the transaction execution.
+/// The thread exits when the writer is dropped.
+pub struct GasTraceWriter {
+    dir: PathBuf,
+    format: GasTraceFormat,
+    sender: SyncSender<(PathBuf, GasTrace)>,
+}
+
+impl GasTraceWriter {
+    pub fn new(dir: PathBuf, format: GasTraceFormat) -> Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        let (sender, receiver) = sync_channel::<(PathBuf, GasTrace)>(GAS_TRACE_QUEUE_SIZE);
+        std::thread::Builder::new()
+            .name("gas-trace-writer".to_owned())
+            .spawn(move || {
+                for (path, trace) in receiver {
+                    if let Err(e) = std::fs::write(&path, trace.export(format)) {
+                        log::warn!("Write the gas trace to {:?} error: {:?}", path, e);
+                    }
+                }
+            })?;
+        Ok(Self {
+            dir,
+            format,
+            sender,
+        })
+    }
+
+    /// Queue the trace of the transaction, the file is named by the transaction hash
+    pub fn write(&self, tx_hash: H256, trace: GasTrace) {
+        let path = self
+            .dir
+            .join(format!("{:?}.{}", tx_hash, self.format.extension()));
+        match self.sender.try_send((path, trace)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                log::warn!(
+                    "The gas trace queue is full, drop the gas trace of the transaction {:?}",
+                    tx_hash
+                );
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                log::warn!(
+                    "The gas trace writer exited, drop the gas trace of the transaction {:?}",
+                    tx_hash
+                );
+            }
+        }
+    }
+}
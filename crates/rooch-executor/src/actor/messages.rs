@@ -5,18 +5,28 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use move_resource_viewer::AnnotatedMoveStruct;
+use moveos::gas::budget::GasPrices;
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
+use moveos_types::gas_config::ViewFunctionLimits;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
 use moveos_types::state::{AnnotatedState, State};
 use moveos_types::transaction::FunctionCall;
+use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
 use rooch_types::address::MultiChainAddress;
+use rooch_types::event_proof::EventProof;
+use rooch_types::gas_statistics::FunctionGasStatistics;
+use rooch_types::replica::{ChangeFeedEntry, ReplicaChangeSet};
+use rooch_types::source_verification::SourceVerification;
+use rooch_types::transaction::rooch::RoochTransactionData;
 use rooch_types::transaction::AbstractTransaction;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug)]
 pub struct ValidateTransactionMessage<T> {
@@ -45,9 +55,20 @@ impl Message for ExecuteTransactionMessage {
     type Result = Result<ExecuteTransactionResult>;
 }
 
+#[derive(Debug)]
+pub struct ApplyChangeFeedEntryMessage {
+    pub entry: ChangeFeedEntry,
+}
+
+impl Message for ApplyChangeFeedEntryMessage {
+    type Result = Result<ExecuteTransactionResult>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExecuteViewFunctionMessage {
     pub call: FunctionCall,
+    /// The limits of the view function calls from the RPC, the internal calls are not limited.
+    pub limits: Option<ViewFunctionLimits>,
 }
 
 impl Message for ExecuteViewFunctionMessage {
@@ -134,6 +155,49 @@ impl Message for GetEventsByEventIDsMessage {
     type Result = Result<Vec<Option<AnnotatedEvent>>>;
 }
 
+/// Execute the verified transaction on the latest state without applying the output
+#[derive(Debug)]
+pub struct DryRunTransactionMessage {
+    pub tx: VerifiedMoveOSTransaction,
+}
+
+impl Message for DryRunTransactionMessage {
+    type Result = Result<RawTransactionOutput>;
+}
+
+/// Simulate the unsigned transaction on the latest state to estimate its gas,
+/// the simulation is aborted as out of gas after the timeout.
+#[derive(Debug)]
+pub struct EstimateGasMessage {
+    pub tx_data: RoochTransactionData,
+    pub timeout_milliseconds: u64,
+}
+
+impl Message for EstimateGasMessage {
+    /// The output of the simulation and the gas prices it is metered with
+    type Result = Result<(RawTransactionOutput, GasPrices)>;
+}
+
+/// Decode the event data with the current layout of the event type, or the registered event schemas.
+/// The event which can not be decoded is returned as `None`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DecodeEventsMessage {
+    pub events: Vec<(StructTag, Vec<u8>)>,
+}
+
+impl Message for DecodeEventsMessage {
+    type Result = Result<Vec<Option<AnnotatedMoveStruct>>>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetEventProofMessage {
+    pub event_id: EventID,
+}
+
+impl Message for GetEventProofMessage {
+    type Result = Result<Option<(Event, EventProof)>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetTxExecutionInfosByHashMessage {
     pub tx_hashes: Vec<H256>,
@@ -161,3 +225,48 @@ pub struct RefreshStateMessage {
 impl Message for RefreshStateMessage {
     type Result = Result<()>;
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetGasStatisticsMessage {}
+
+impl Message for GetGasStatisticsMessage {
+    type Result = Result<Vec<FunctionGasStatistics>>;
+}
+
+/// The bytecode of the modules published at the package address, keyed by the module name
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPackageModulesMessage {
+    pub package_address: AccountAddress,
+}
+
+impl Message for GetPackageModulesMessage {
+    type Result = Result<BTreeMap<String, Vec<u8>>>;
+}
+
+/// Record the verification in the source registry, return the recorded verification of the package
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveSourceVerificationMessage {
+    pub verification: SourceVerification,
+}
+
+impl Message for SaveSourceVerificationMessage {
+    type Result = Result<SourceVerification>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetSourceVerificationMessage {
+    pub package_address: AccountAddress,
+}
+
+impl Message for GetSourceVerificationMessage {
+    type Result = Result<Option<SourceVerification>>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetReplicaChangeSetsMessage {
+    pub tx_hashes: Vec<H256>,
+}
+
+impl Message for GetReplicaChangeSetsMessage {
+    type Result = Result<Vec<Option<ReplicaChangeSet>>>;
+}
@@ -2,5 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod executor;
+pub mod framework_version;
+pub mod gas_schedule;
+pub mod gas_trace_writer;
 pub mod messages;
+pub mod object_change_notifier;
 pub mod reader_executor;
@@ -0,0 +1,98 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use move_core_types::effects::Op;
+use moveos_store::state_store::statedb::StateDBStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{State, StateChangeSet};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// The executed transactions buffered for the slow subscribers, a subscriber lagging behind skips the oldest ones
+pub const OBJECT_CHANGE_CHANNEL_CAPACITY: usize = 1024;
+
+/// A change of an object with its raw states before and after the transaction
+#[derive(Debug, Clone)]
+pub struct ObjectChange {
+    pub object_id: ObjectID,
+    pub before: Option<State>,
+    pub after: Option<State>,
+}
+
+/// The object changes of an executed transaction
+#[derive(Debug, Clone)]
+pub struct TxObjectChanges {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub changes: Vec<ObjectChange>,
+}
+
+/// Broadcast the object changes of the executed transactions to the subscriptions.
+/// The changes are published by the executor, so they are published in the order of the execution.
+#[derive(Debug, Clone)]
+pub struct ObjectChangeNotifier {
+    sender: broadcast::Sender<Arc<TxObjectChanges>>,
+}
+
+impl Default for ObjectChangeNotifier {
+    fn default() -> Self {
+        Self::new(OBJECT_CHANGE_CHANNEL_CAPACITY)
+    }
+}
+
+impl ObjectChangeNotifier {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// The object changes are only collected if there is a subscription
+    pub fn has_subscriptions(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    pub fn notify(&self, changes: TxObjectChanges) {
+        // The send fails only if all the subscriptions are closed
+        let _ = self.sender.send(Arc::new(changes));
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<TxObjectChanges>> {
+        self.sender.subscribe()
+    }
+}
+
+/// The objects changed by the change set in all the tables, with their states before the change read from
+/// the `pre_state` at the state root before the transaction. The entries which are not objects are skipped.
+pub fn collect_object_changes(
+    pre_state: &StateDBStore,
+    state_changeset: &StateChangeSet,
+) -> Result<Vec<ObjectChange>> {
+    let mut changes = vec![];
+    for (handle, table_change) in &state_changeset.changes {
+        for (key, op) in &table_change.entries {
+            let object_id = match ObjectID::from_bytes(key.as_slice()) {
+                Ok(object_id) => object_id,
+                Err(_) => continue,
+            };
+            let after = match op {
+                Op::New(state) | Op::Modify(state) => Some(state.clone()),
+                Op::Delete => None,
+            };
+            let before = pre_state.resolve_state(handle, key.as_slice())?;
+            let is_object = before
+                .iter()
+                .chain(after.iter())
+                .any(|state| state.get_object_struct_tag().is_some());
+            if is_object {
+                changes.push(ObjectChange {
+                    object_id,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    Ok(changes)
+}
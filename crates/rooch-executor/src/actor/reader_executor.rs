@@ -1,36 +1,80 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::gas_schedule::GasScheduleSync;
 use super::messages::{
     AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
     GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage, RefreshStateMessage,
     StatesMessage,
 };
 use crate::actor::messages::{
-    GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
-    ListStatesMessage,
+    DecodeEventsMessage, DryRunTransactionMessage, EstimateGasMessage, GetEventProofMessage,
+    GetEventsByEventIDsMessage, GetGasStatisticsMessage, GetPackageModulesMessage,
+    GetReplicaChangeSetsMessage, GetSourceVerificationMessage, GetTxExecutionInfosByHashMessage,
+    ListAnnotatedStatesMessage, ListStatesMessage,
 };
-use anyhow::Result;
+use anyhow::{anyhow, bail, ensure, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
-use move_resource_viewer::MoveValueAnnotator;
-use moveos::moveos::MoveOS;
+use move_binary_format::CompiledModule;
+use move_core_types::language_storage::StructTag;
+use move_core_types::vm_status::StatusCode;
+use move_resource_viewer::{AnnotatedMoveStruct, MoveValueAnnotator};
+use moveos::gas::budget::GasPrices;
+use moveos::gas::table::CostTable;
+use moveos::moveos::{GasPaymentAccount, MoveOS};
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
+use moveos_types::access_path::{AccessPath, Path};
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::function_return_value::AnnotatedFunctionReturnValue;
+use moveos_types::gas_config::{GasConfig, GasMeterKind};
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::ascii::MoveAsciiString;
+use moveos_types::move_std::option::MoveOption;
 use moveos_types::moveos_std::event::EventHandle;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event};
+use moveos_types::moveos_std::move_module::MoveModule;
 use moveos_types::state::{AnnotatedState, State};
 use moveos_types::state_resolver::{AnnotatedStateReader, StateReader};
-use moveos_types::transaction::TransactionExecutionInfo;
+use moveos_types::transaction::{
+    MoveOSTransaction, RawTransactionOutput, TransactionExecutionInfo,
+};
 use rooch_genesis::RoochGenesis;
+use rooch_store::change_feed_store::ChangeFeedStore;
+use rooch_store::gas_statistics_store::GasStatisticsStore;
+use rooch_store::source_registry_store::SourceRegistryStore;
 use rooch_store::RoochStore;
+use rooch_types::address::MultiChainAddress;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::crypto::{Ed25519RoochSignature, Signature};
+use rooch_types::event_proof::EventProof;
+use rooch_types::framework::auth_validator::{
+    AuthValidator, AuthValidatorCaller, BuiltinAuthValidator, TxValidateResult,
+};
+use rooch_types::framework::event_schema_registry::{
+    decode_with_schemas, EventSchemaRegistryModule,
+};
+use rooch_types::framework::native_validator::NativeValidatorModule;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use rooch_types::gas_statistics::FunctionGasStatistics;
+use rooch_types::replica::ReplicaChangeSet;
+use rooch_types::source_verification::SourceVerification;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+const LIST_MODULES_PAGE_SIZE: usize = 100;
 
 pub struct ReaderExecutorActor {
     moveos: MoveOS,
     rooch_store: RoochStore,
+    genesis: RoochGenesis,
+    /// Follow the on-chain gas schedule of the executor, the dry runs are metered by the same schedule
+    gas_schedule: GasScheduleSync,
 }
 
 // type ValidateAuthenticatorResult = Result<
@@ -49,20 +93,31 @@ impl ReaderExecutorActor {
         moveos_store: MoveOSStore,
         rooch_store: RoochStore,
     ) -> Result<Self> {
-        let moveos = MoveOS::new(
+        let mut moveos = MoveOS::new(
             moveos_store,
-            genesis.all_natives(),
+            genesis.all_natives()?,
             genesis.config.clone(),
             system_pre_execute_functions(),
             system_post_execute_functions(),
         )?;
+        // The genesis cost table is loaded once, the on-chain gas schedules override it
+        let mut gas_schedule = GasScheduleSync::load(&moveos)?;
+        // Estimate the gas of the dry runs with the gas schedule of the executor
+        gas_schedule.sync(&mut moveos, &genesis);
 
         Ok(Self {
             moveos,
             rooch_store,
+            genesis,
+            gas_schedule,
         })
     }
 
+    /// The gas meter of the simulated transactions, it should be the meter of the executed transactions
+    pub fn set_gas_meter_kind(&mut self, gas_meter_kind: GasMeterKind) {
+        self.moveos.set_gas_meter_kind(gas_meter_kind);
+    }
+
     // pub fn resolve_or_generate(
     //     &self,
     //     multi_chain_address_sender: MultiChainAddress,
@@ -384,6 +439,90 @@ impl ReaderExecutorActor {
     pub fn moveos(&self) -> &MoveOS {
         &self.moveos
     }
+
+    /// The cost table of the builtin gas schedule stored at genesis
+    pub fn genesis_cost_table(&self) -> &CostTable {
+        self.gas_schedule.genesis_cost_table()
+    }
+
+    /// Simulate the unsigned transaction on the latest state, with the same validator pre and post execute functions
+    /// as the executor, the simulation is aborted as out of gas after the timeout.
+    pub fn estimate_gas(
+        &self,
+        mut tx_data: RoochTransactionData,
+        timeout_milliseconds: u64,
+    ) -> Result<RawTransactionOutput> {
+        // The transaction is metered with its max gas amount, but at least the default one,
+        // so the wallet does not need to guess the max gas amount to get the estimate.
+        tx_data.max_gas_amount = tx_data
+            .max_gas_amount
+            .max(GasConfig::DEFAULT_MAX_GAS_AMOUNT);
+        // The compute and storage budgets are estimated too, so the simulation is not limited by them.
+        tx_data.gas_budget = None;
+        let sender = tx_data.sender;
+        let gas_payment_account = tx_data.sponsor.unwrap_or(sender).into();
+        // The transaction is unsigned, it is estimated as signed by the native validator with a placeholder signature,
+        // so it has the size of the signed transaction, and runs the same pre and post execute functions as the executor.
+        let authenticator = Authenticator::rooch(Signature::Ed25519RoochSignature(
+            Ed25519RoochSignature::default(),
+        ));
+        let mut moveos_tx: MoveOSTransaction = RoochTransaction::new(tx_data, authenticator).into();
+        let auth_validator = AuthValidator {
+            id: BuiltinAuthValidator::Rooch.flag().into(),
+            module_address: ROOCH_FRAMEWORK_ADDRESS,
+            module_name: MoveAsciiString::from_str(NativeValidatorModule::MODULE_NAME.as_str())?,
+        };
+        let auth_validator_caller = AuthValidatorCaller::new(self.moveos(), auth_validator.clone());
+        moveos_tx.ctx.add(GasPaymentAccount {
+            account: gas_payment_account,
+            pay_gas_by_module_account: false,
+        })?;
+        moveos_tx.ctx.add(MultiChainAddress::from(sender))?;
+        moveos_tx.ctx.add(TxValidateResult {
+            auth_validator_id: auth_validator.id,
+            auth_validator: MoveOption::some(auth_validator),
+            session_key: MoveOption::none(),
+        })?;
+        moveos_tx
+            .append_pre_execute_functions(vec![auth_validator_caller.pre_execute_function_call()]);
+        moveos_tx.append_post_execute_functions(vec![
+            auth_validator_caller.post_execute_function_call()
+        ]);
+        let tx = self.moveos().verify(moveos_tx)?;
+        self.moveos()
+            .simulate(tx, Duration::from_millis(timeout_milliseconds))
+    }
+
+    /// The version of the on-chain gas schedule metering the transactions
+    pub fn gas_schedule_version(&self) -> Option<u64> {
+        self.gas_schedule.version()
+    }
+
+    /// Annotate the event data with the current layout of the event type.
+    /// If the layout is changed by a module upgrade, the event emitted before the upgrade
+    /// is decoded with the schemas registered in the event schema registry.
+    fn annotate_event_data(
+        &self,
+        event_type: &StructTag,
+        event_data: &[u8],
+    ) -> Result<AnnotatedMoveStruct> {
+        let resolver = self.moveos().moveos_resolver();
+        match MoveValueAnnotator::new(resolver).view_resource(event_type, event_data) {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                let schemas = self
+                    .moveos()
+                    .as_module_binding::<EventSchemaRegistryModule>()
+                    .event_schemas(event_type)?;
+                if schemas.is_empty() {
+                    return Err(e);
+                }
+                let (_version, value) = decode_with_schemas(&schemas, event_data)
+                    .map_err(|_| anyhow!("Failed to decode the event {}: {:?}", event_type, e))?;
+                Ok(value)
+            }
+        }
+    }
 }
 
 impl Actor for ReaderExecutorActor {}
@@ -411,7 +550,23 @@ impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
     ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
         let resoler = self.moveos().moveos_resolver();
 
-        let function_result = self.moveos().execute_view_function(msg.call);
+        let ExecuteViewFunctionMessage { call, limits } = msg;
+        let function_result = match limits {
+            Some(limits) => {
+                let function_result = self
+                    .moveos()
+                    .execute_view_function_with_limits(call, limits);
+                if function_result.vm_status.status_code() == StatusCode::OUT_OF_GAS {
+                    bail!(
+                        "View limit exceeded, the view function call exceeds the max gas amount {} or the timeout {}ms",
+                        limits.max_gas_amount,
+                        limits.timeout_milliseconds
+                    );
+                }
+                function_result
+            }
+            None => self.moveos().execute_view_function(call),
+        };
         Ok(AnnotatedFunctionResult {
             vm_status: function_result.vm_status,
             return_values: match function_result.return_values {
@@ -505,7 +660,6 @@ impl Handler<GetAnnotatedEventsByEventHandleMessage> for ReaderExecutorActor {
             limit,
         } = msg;
         let event_store = self.moveos().event_store();
-        let resolver = self.moveos().moveos_resolver();
 
         let event_handle_id = EventHandle::derive_event_handle_id(&event_handle_type);
         let events = event_store.get_events_by_event_handle_id(&event_handle_id, cursor, limit)?;
@@ -513,8 +667,8 @@ impl Handler<GetAnnotatedEventsByEventHandleMessage> for ReaderExecutorActor {
         events
             .into_iter()
             .map(|event| {
-                let event_move_value = MoveValueAnnotator::new(resolver)
-                    .view_resource(&event_handle_type, event.event_data())?;
+                let event_move_value =
+                    self.annotate_event_data(&event_handle_type, event.event_data())?;
                 Ok(AnnotatedEvent::new(event, event_move_value))
             })
             .collect::<Result<Vec<_>>>()
@@ -549,15 +703,14 @@ impl Handler<GetEventsByEventIDsMessage> for ReaderExecutorActor {
     ) -> Result<Vec<Option<AnnotatedEvent>>> {
         let GetEventsByEventIDsMessage { event_ids } = msg;
         let event_store = self.moveos().event_store();
-        let resolver = self.moveos().moveos_resolver();
 
         event_store
             .multi_get_events(event_ids)?
             .into_iter()
             .map(|v| match v {
                 Some(event) => {
-                    let event_move_value = MoveValueAnnotator::new(resolver)
-                        .view_resource(event.event_type(), event.event_data())?;
+                    let event_move_value =
+                        self.annotate_event_data(event.event_type(), event.event_data())?;
                     Ok(Some(AnnotatedEvent::new(event, event_move_value)))
                 }
                 None => Ok(None),
@@ -566,6 +719,93 @@ impl Handler<GetEventsByEventIDsMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<DryRunTransactionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: DryRunTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<RawTransactionOutput> {
+        self.moveos().execute(msg.tx)
+    }
+}
+
+#[async_trait]
+impl Handler<EstimateGasMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: EstimateGasMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<(RawTransactionOutput, GasPrices)> {
+        let EstimateGasMessage {
+            tx_data,
+            timeout_milliseconds,
+        } = msg;
+        let output = self.estimate_gas(tx_data, timeout_milliseconds)?;
+        Ok((output, self.moveos().cost_table().gas_prices()))
+    }
+}
+
+#[async_trait]
+impl Handler<DecodeEventsMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: DecodeEventsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<AnnotatedMoveStruct>>> {
+        let DecodeEventsMessage { events } = msg;
+        Ok(events
+            .iter()
+            .map(|(event_type, event_data)| self.annotate_event_data(event_type, event_data).ok())
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Handler<GetEventProofMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetEventProofMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<(Event, EventProof)>> {
+        let GetEventProofMessage { event_id } = msg;
+        let event_store = self.moveos().event_store();
+
+        let tx_hash = match event_store.get_event_tx_hash(event_id)? {
+            Some(tx_hash) => tx_hash,
+            None => return Ok(None),
+        };
+        // The pruned events are not provable by the node, their proofs are built from the event archive
+        ensure!(
+            !event_store.is_event_pruned(event_id)?,
+            "The event {:?} of the tx {:?} is pruned by the event retention, build its proof from the event archive",
+            event_id,
+            tx_hash
+        );
+        let event_ids = event_store
+            .get_tx_event_ids(tx_hash)?
+            .ok_or_else(|| anyhow!("Can not find the event ids of the tx: {:?}", tx_hash))?;
+        let mut events = event_store
+            .multi_get_events(event_ids)?
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| anyhow!("Some events of the tx {:?} are missing", tx_hash))?;
+        let leaf_index = events
+            .iter()
+            .position(|event| event.event_id == event_id)
+            .ok_or_else(|| {
+                anyhow!(
+                    "The event {:?} is not in the events of the tx {:?}",
+                    event_id,
+                    tx_hash
+                )
+            })?;
+        let event_hashes = events.iter().map(|event| event.hash()).collect::<Vec<_>>();
+        let proof = EventProof::build(tx_hash, &event_hashes, leaf_index as u64)?;
+        Ok(Some((events.swap_remove(leaf_index), proof)))
+    }
+}
+
 #[async_trait]
 impl Handler<GetTxExecutionInfosByHashMessage> for ReaderExecutorActor {
     async fn handle(
@@ -608,6 +848,82 @@ impl Handler<RefreshStateMessage> for ReaderExecutorActor {
             new_state_root,
             is_upgrade,
         } = msg;
-        self.moveos.refresh_state(new_state_root, is_upgrade)
+        self.moveos.refresh_state(new_state_root, is_upgrade)?;
+        // Switch with the executor when the refreshed state activates a new gas schedule
+        self.gas_schedule.sync(&mut self.moveos, &self.genesis);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<GetGasStatisticsMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        _msg: GetGasStatisticsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<FunctionGasStatistics>> {
+        self.rooch_store.list_function_gas_statistics()
+    }
+}
+
+#[async_trait]
+impl Handler<GetPackageModulesMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetPackageModulesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<BTreeMap<String, Vec<u8>>> {
+        let package_address = msg.package_address;
+        let statedb = self.moveos().moveos_resolver();
+        let access_path = AccessPath(Path::Module {
+            account: package_address,
+            module_names: None,
+        });
+        let mut modules = BTreeMap::new();
+        let mut cursor = None;
+        loop {
+            let states =
+                statedb.list_states(access_path.clone(), cursor.clone(), LIST_MODULES_PAGE_SIZE)?;
+            let is_last_page = states.len() < LIST_MODULES_PAGE_SIZE;
+            for (key, state) in states {
+                let module = state.cast::<MoveModule>()?;
+                let compiled_module = CompiledModule::deserialize(&module.byte_codes)?;
+                modules.insert(
+                    compiled_module.self_id().name().to_string(),
+                    module.byte_codes,
+                );
+                cursor = Some(key);
+            }
+            if is_last_page {
+                break;
+            }
+        }
+        if modules.is_empty() {
+            bail!("No module is published at address {}", package_address);
+        }
+        Ok(modules)
+    }
+}
+
+#[async_trait]
+impl Handler<GetSourceVerificationMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetSourceVerificationMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<SourceVerification>> {
+        self.rooch_store
+            .get_source_verification(msg.package_address)
+    }
+}
+
+#[async_trait]
+impl Handler<GetReplicaChangeSetsMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetReplicaChangeSetsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<ReplicaChangeSet>>> {
+        self.rooch_store.get_replica_change_sets(msg.tx_hashes)
     }
 }
@@ -0,0 +1,54 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{register, Opts, PrometheusError, Registry, UIntCounter, UIntCounterVec};
+use once_cell::sync::Lazy;
+
+pub static EXECUTOR_METRICS: Lazy<ExecutorMetrics> = Lazy::new(|| {
+    ExecutorMetrics::register(metrics::default_registry())
+        .expect("Register executor metrics failed")
+});
+
+#[derive(Clone)]
+pub struct ExecutorMetrics {
+    pub function_gas_used_total: UIntCounterVec,
+    pub function_call_total: UIntCounterVec,
+    pub system_gas_used_total: UIntCounter,
+}
+
+impl ExecutorMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let function_gas_used_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "executor_function_gas_used_total",
+                    "Cumulative gas used of the entry function of the executed transactions",
+                ),
+                &["module", "function"],
+            )?,
+            registry,
+        )?;
+        let function_call_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "executor_function_call_total",
+                    "Counters of how many times the entry function is executed",
+                ),
+                &["module", "function"],
+            )?,
+            registry,
+        )?;
+        let system_gas_used_total = register(
+            UIntCounter::with_opts(Opts::new(
+                "executor_system_gas_used_total",
+                "Cumulative gas of the system functions executed without charging the users",
+            ))?,
+            registry,
+        )?;
+        Ok(Self {
+            function_gas_used_total,
+            function_call_total,
+            system_gas_used_total,
+        })
+    }
+}
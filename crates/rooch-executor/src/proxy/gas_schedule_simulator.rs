@@ -0,0 +1,147 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::executor::ExecutorActor;
+use anyhow::{anyhow, ensure, Result};
+use moveos_store::transaction_store::TransactionStore as MoveOSTransactionStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use rooch_genesis::RoochGenesis;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::gas_schedule_simulation::{GasScheduleSimulation, TransactionGasSimulation};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// The max number of the sample transactions of a simulation
+pub const MAX_GAS_SCHEDULE_SIMULATION_TXS: usize = 100;
+
+/// Re-price the historical transactions under a proposed gas schedule, so the governance can see the impact
+/// of a gas schedule change before voting.
+/// Every simulation runs in a sandbox executor with its own state root, each transaction is executed on the state
+/// before it, and the outputs are discarded. Only one simulation runs at a time, it never blocks the node executor.
+#[derive(Clone)]
+pub struct GasScheduleSimulator {
+    genesis: RoochGenesis,
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    permits: Arc<Semaphore>,
+}
+
+impl GasScheduleSimulator {
+    pub fn new(genesis: &RoochGenesis, moveos_store: MoveOSStore, rooch_store: RoochStore) -> Self {
+        Self {
+            genesis: genesis.clone(),
+            moveos_store,
+            rooch_store,
+            permits: Arc::new(Semaphore::new(1)),
+        }
+    }
+
+    /// Execute the sample transactions with the gas schedule `entries` overriding the genesis cost table
+    pub async fn simulate(
+        &self,
+        entries: BTreeMap<String, u64>,
+        tx_hashes: Vec<H256>,
+    ) -> Result<GasScheduleSimulation> {
+        ensure!(
+            tx_hashes.len() <= MAX_GAS_SCHEDULE_SIMULATION_TXS,
+            "The number of the sample transactions {} exceeds the max {}",
+            tx_hashes.len(),
+            MAX_GAS_SCHEDULE_SIMULATION_TXS
+        );
+        let permit = self.permits.clone().acquire_owned().await?;
+        let simulator = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let result = simulator.simulate_blocking(entries, tx_hashes);
+            drop(permit);
+            result
+        })
+        .await?
+    }
+
+    fn simulate_blocking(
+        &self,
+        entries: BTreeMap<String, u64>,
+        tx_hashes: Vec<H256>,
+    ) -> Result<GasScheduleSimulation> {
+        let mut sandbox = self.new_sandbox()?;
+        let cost_table = sandbox.genesis_cost_table().with_entries(&entries)?;
+        sandbox.set_cost_table(cost_table);
+        let mut transactions = Vec::with_capacity(tx_hashes.len());
+        for tx_hash in tx_hashes {
+            let tx = self
+                .rooch_store
+                .get_transaction_by_hash(tx_hash)?
+                .ok_or_else(|| anyhow!("The transaction {:?} does not exist", tx_hash))?;
+            let tx_order = self
+                .rooch_store
+                .multi_get_tx_sequence_info_mapping_by_hash(vec![tx_hash])?
+                .pop()
+                .flatten()
+                .ok_or_else(|| anyhow!("The transaction {:?} is not sequenced", tx_hash))?
+                .tx_order;
+            let execution_info = self
+                .moveos_store
+                .get_tx_execution_info(tx_hash)?
+                .ok_or_else(|| anyhow!("The transaction {:?} is not executed", tx_hash))?;
+            let pre_state_root = self.get_pre_state_root(tx_order)?;
+            let output = sandbox
+                .execute_at(pre_state_root, tx)
+                .map_err(|e| anyhow!("Failed to simulate the transaction {:?}: {}", tx_hash, e))?;
+            transactions.push(TransactionGasSimulation {
+                tx_hash,
+                tx_order,
+                original_status: execution_info.status,
+                original_gas_used: execution_info.gas_used,
+                simulated_status: output.status,
+                simulated_gas_used: output.gas_used,
+            });
+        }
+        Ok(GasScheduleSimulation::new(transactions))
+    }
+
+    /// The sandbox shares the stores with the node, but it does not share the state root with the node
+    fn new_sandbox(&self) -> Result<ExecutorActor> {
+        let genesis_state_root = self.get_pre_state_root(0)?;
+        let moveos_store = MoveOSStore::new_with_root(
+            self.moveos_store.moveosdb.clone(),
+            Some(genesis_state_root),
+        )?;
+        ExecutorActor::new_with_genesis(
+            self.genesis.clone(),
+            moveos_store,
+            self.rooch_store.clone(),
+        )
+    }
+
+    /// The state root before the transaction of `tx_order`, the first transaction is executed on the genesis state
+    fn get_pre_state_root(&self, tx_order: u64) -> Result<H256> {
+        if tx_order == 0 {
+            return Ok(self
+                .moveos_store
+                .get_config_store()
+                .get_genesis()?
+                .ok_or_else(|| anyhow!("The genesis info does not exist"))?
+                .state_root_hash);
+        }
+        let prev_tx_hash = self
+            .rooch_store
+            .get_tx_sequence_info_mapping_by_order(vec![tx_order - 1])?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow!(
+                    "The transaction of the order {} does not exist",
+                    tx_order - 1
+                )
+            })?
+            .tx_hash;
+        Ok(self
+            .moveos_store
+            .get_tx_execution_info(prev_tx_hash)?
+            .ok_or_else(|| anyhow!("The transaction {:?} is not executed", prev_tx_hash))?
+            .state_root)
+    }
+}
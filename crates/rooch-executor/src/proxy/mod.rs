@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage, GetEventsByEventIDsMessage,
-    GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
-    RefreshStateMessage,
+    ApplyChangeFeedEntryMessage, DecodeEventsMessage, DryRunTransactionMessage,
+    GetAnnotatedStatesByStateMessage, GetEventProofMessage, GetEventsByEventHandleMessage,
+    GetEventsByEventIDsMessage, GetGasStatisticsMessage, GetPackageModulesMessage,
+    GetReplicaChangeSetsMessage, GetSourceVerificationMessage, GetTxExecutionInfosByHashMessage,
+    ListAnnotatedStatesMessage, ListStatesMessage, RefreshStateMessage,
+    SaveSourceVerificationMessage,
 };
 use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
@@ -18,12 +21,14 @@ use anyhow::Result;
 use coerce::actor::ActorRef;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use move_resource_viewer::AnnotatedMoveStruct;
 use moveos_types::function_return_value::{AnnotatedFunctionResult, FunctionResult};
 use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::transaction::FunctionCall;
+use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::{access_path::AccessPath, transaction::VerifiedMoveOSTransaction};
@@ -32,9 +37,17 @@ use moveos_types::{
     state::{AnnotatedState, State},
 };
 use rooch_types::address::MultiChainAddress;
+use rooch_types::event_proof::EventProof;
+use rooch_types::gas_statistics::FunctionGasStatistics;
+use rooch_types::replica::{ChangeFeedEntry, ReplicaChangeSet};
+use rooch_types::source_verification::SourceVerification;
 use rooch_types::transaction::AbstractTransaction;
+use std::collections::BTreeMap;
 use tokio::runtime::Handle;
 
+pub mod gas_schedule_simulator;
+pub mod view_executor_pool;
+
 #[derive(Clone)]
 pub struct ExecutorProxy {
     pub actor: ActorRef<ExecutorActor>,
@@ -59,6 +72,16 @@ impl ExecutorProxy {
         self.actor.send(ValidateTransactionMessage { tx }).await?
     }
 
+    /// Execute the verified transaction on the reader executor without applying the output
+    pub async fn dry_run_transaction(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<RawTransactionOutput> {
+        self.reader_actor
+            .send(DryRunTransactionMessage { tx })
+            .await?
+    }
+
     //TODO ensure the execute result
     pub async fn execute_transaction(
         &self,
@@ -71,12 +94,24 @@ impl ExecutorProxy {
         Ok((result.output, result.transaction_info))
     }
 
+    /// Apply the transaction replicated from the primary node without executing it
+    pub async fn apply_change_feed_entry(
+        &self,
+        entry: ChangeFeedEntry,
+    ) -> Result<(TransactionOutput, TransactionExecutionInfo)> {
+        let result = self
+            .actor
+            .send(ApplyChangeFeedEntryMessage { entry })
+            .await??;
+        Ok((result.output, result.transaction_info))
+    }
+
     pub async fn execute_view_function(
         &self,
         call: FunctionCall,
     ) -> Result<AnnotatedFunctionResult> {
         self.reader_actor
-            .send(ExecuteViewFunctionMessage { call })
+            .send(ExecuteViewFunctionMessage { call, limits: None })
             .await?
     }
 
@@ -168,6 +203,21 @@ impl ExecutorProxy {
             .await?
     }
 
+    pub async fn decode_events(
+        &self,
+        events: Vec<(StructTag, Vec<u8>)>,
+    ) -> Result<Vec<Option<AnnotatedMoveStruct>>> {
+        self.reader_actor
+            .send(DecodeEventsMessage { events })
+            .await?
+    }
+
+    pub async fn get_event_proof(&self, event_id: EventID) -> Result<Option<(Event, EventProof)>> {
+        self.reader_actor
+            .send(GetEventProofMessage { event_id })
+            .await?
+    }
+
     pub async fn get_transaction_execution_infos_by_hash(
         &self,
         tx_hashes: Vec<H256>,
@@ -186,6 +236,46 @@ impl ExecutorProxy {
             .await?
     }
 
+    pub async fn get_gas_statistics(&self) -> Result<Vec<FunctionGasStatistics>> {
+        self.reader_actor.send(GetGasStatisticsMessage {}).await?
+    }
+
+    pub async fn get_package_modules(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<BTreeMap<String, Vec<u8>>> {
+        self.reader_actor
+            .send(GetPackageModulesMessage { package_address })
+            .await?
+    }
+
+    pub async fn save_source_verification(
+        &self,
+        verification: SourceVerification,
+    ) -> Result<SourceVerification> {
+        self.actor
+            .send(SaveSourceVerificationMessage { verification })
+            .await?
+    }
+
+    pub async fn get_source_verification(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<SourceVerification>> {
+        self.reader_actor
+            .send(GetSourceVerificationMessage { package_address })
+            .await?
+    }
+
+    pub async fn get_replica_change_sets(
+        &self,
+        tx_hashes: Vec<H256>,
+    ) -> Result<Vec<Option<ReplicaChangeSet>>> {
+        self.reader_actor
+            .send(GetReplicaChangeSetsMessage { tx_hashes })
+            .await?
+    }
+
     pub async fn refresh_state(&self, new_state_root: H256, is_upgrade: bool) -> Result<()> {
         self.reader_actor
             .send(RefreshStateMessage {
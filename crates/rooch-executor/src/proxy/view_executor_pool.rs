@@ -0,0 +1,164 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::messages::{EstimateGasMessage, ExecuteViewFunctionMessage, RefreshStateMessage};
+use crate::actor::reader_executor::ReaderExecutorActor;
+use anyhow::{anyhow, ensure, Result};
+use coerce::actor::{system::ActorSystem, ActorRef, IntoActor};
+use moveos::gas::budget::GasPrices;
+use moveos_store::MoveOSStore;
+use moveos_types::function_return_value::AnnotatedFunctionResult;
+use moveos_types::gas_config::ViewFunctionLimits;
+use moveos_types::h256::H256;
+use moveos_types::transaction::{FunctionCall, RawTransactionOutput};
+use parking_lot::Mutex;
+use rooch_config::ViewExecutionConfig;
+use rooch_genesis::RoochGenesis;
+use rooch_store::RoochStore;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::Instant;
+
+/// A dedicated bounded pool of reader executors for the view function calls and dry runs from the RPC.
+/// The number of concurrent calls is capped by the pool size, and every call has a timeout,
+/// so expensive calls from the public RPC can not starve the main execution loop.
+/// The view function calls are also limited by a separate gas budget, and aborted when the timeout is exceeded.
+#[derive(Clone)]
+pub struct ViewExecutorPool {
+    executors: Arc<Vec<ActorRef<ReaderExecutorActor>>>,
+    idle_executors: Arc<Mutex<Vec<ActorRef<ReaderExecutorActor>>>>,
+    permits: Arc<Semaphore>,
+    timeout: Duration,
+    limits: ViewFunctionLimits,
+}
+
+impl ViewExecutorPool {
+    pub async fn new(
+        genesis: &RoochGenesis,
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        config: &ViewExecutionConfig,
+        actor_system: &ActorSystem,
+    ) -> Result<Self> {
+        ensure!(
+            config.max_concurrency > 0,
+            "The max view concurrency should be greater than 0"
+        );
+        let mut executors = Vec::with_capacity(config.max_concurrency);
+        for i in 0..config.max_concurrency {
+            let mut executor = ReaderExecutorActor::new(
+                genesis.clone(),
+                moveos_store.clone(),
+                rooch_store.clone(),
+            )?;
+            executor.set_gas_meter_kind(config.gas_meter_kind);
+            let executor = executor
+                .into_actor(Some(format!("ViewExecutor{}", i)), actor_system)
+                .await?;
+            executors.push(ActorRef::from(executor));
+        }
+        Ok(Self {
+            idle_executors: Arc::new(Mutex::new(executors.clone())),
+            executors: Arc::new(executors),
+            permits: Arc::new(Semaphore::new(config.max_concurrency)),
+            timeout: Duration::from_millis(config.timeout_ms),
+            limits: ViewFunctionLimits {
+                max_gas_amount: config.max_gas_amount,
+                timeout_milliseconds: config.timeout_ms,
+            },
+        })
+    }
+
+    pub async fn execute_view_function(
+        &self,
+        call: FunctionCall,
+    ) -> Result<AnnotatedFunctionResult> {
+        let idle_executors = self.idle_executors.clone();
+        let limits = Some(self.limits);
+        self.run(async move {
+            let executor = idle_executors
+                .lock()
+                .pop()
+                .expect("The permit guarantees an idle view executor");
+            let result = executor
+                .send(ExecuteViewFunctionMessage { call, limits })
+                .await;
+            idle_executors.lock().push(executor);
+            result?
+        })
+        .await
+    }
+
+    /// Simulate the unsigned transaction to estimate its gas, the output is not applied.
+    /// The simulation is bounded by the timeout of the pool like the view function calls.
+    pub async fn estimate_gas(
+        &self,
+        tx_data: RoochTransactionData,
+    ) -> Result<(RawTransactionOutput, GasPrices)> {
+        let idle_executors = self.idle_executors.clone();
+        let timeout_milliseconds = self.limits.timeout_milliseconds;
+        self.run(async move {
+            let executor = idle_executors
+                .lock()
+                .pop()
+                .expect("The permit guarantees an idle view executor");
+            let result = executor
+                .send(EstimateGasMessage {
+                    tx_data,
+                    timeout_milliseconds,
+                })
+                .await;
+            idle_executors.lock().push(executor);
+            result?
+        })
+        .await
+    }
+
+    /// Run the task with a permit of the pool, and fail if the task does not finish before the timeout.
+    /// The permit is held until the task finishes, even if the caller times out,
+    /// so the timed out calls still count toward the concurrency cap.
+    pub async fn run<F, T>(&self, task: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let deadline = Instant::now() + self.timeout;
+        let permit = tokio::time::timeout_at(deadline, self.permits.clone().acquire_owned())
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "The view executors are busy, no executor is available in {}ms",
+                    self.timeout.as_millis()
+                )
+            })??;
+        let handle = tokio::spawn(async move {
+            let result = task.await;
+            drop(permit);
+            result
+        });
+        tokio::time::timeout_at(deadline, handle)
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "The view call timed out after {}ms",
+                    self.timeout.as_millis()
+                )
+            })?
+            .map_err(|e| anyhow!("The view call failed: {:?}", e))?
+    }
+
+    /// Sync the latest state root to all view executors.
+    /// The refresh is queued without waiting, so a busy view executor does not block the caller.
+    pub fn refresh_state(&self, new_state_root: H256, is_upgrade: bool) -> Result<()> {
+        for executor in self.executors.iter() {
+            executor.notify(RefreshStateMessage {
+                new_state_root,
+                is_upgrade,
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -29,12 +29,14 @@ impl RustBindingTest {
         let moveos_store = MoveOSStore::mock_moveos_store()?;
         let rooch_store = RoochStore::mock_rooch_store()?;
         let sequencer = RoochAddress::random();
-        let executor = ExecutorActor::new(
+        let mut executor = ExecutorActor::new(
             RoochChainID::LOCAL.genesis_ctx(sequencer),
             BitcoinGenesisContext::new(Network::default().to_num()),
             moveos_store.clone(),
             rooch_store.clone(),
         )?;
+        // Execute every transaction twice in the tests, to detect the nondeterministic execution
+        executor.moveos_mut().set_determinism_check(true);
         let reader_executor =
             ReaderExecutorActor::new(executor.genesis().clone(), moveos_store, rooch_store)?;
         Ok(Self {
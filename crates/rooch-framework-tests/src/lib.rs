@@ -2,5 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod binding_test;
+pub mod test_scenario;
 #[cfg(test)]
 mod tests;
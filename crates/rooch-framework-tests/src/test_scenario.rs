@@ -0,0 +1,162 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test::RustBindingTest;
+use anyhow::{bail, Result};
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::access_path::AccessPath;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::Event;
+use moveos_types::state::{MoveStructType, State};
+use moveos_types::state_resolver::StateReader;
+use moveos_types::transaction::{MoveAction, TransactionOutput};
+use rooch_executor::actor::messages::ExecuteTransactionResult;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::account::Account;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::rooch::RoochTransactionData;
+
+/// A snapshot of the scenario state, the scenario can be rolled back to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScenarioSnapshot {
+    pub state_root: H256,
+}
+
+/// A multi-sender transaction scenario for the integration tests.
+/// The scenario creates the senders, executes the transactions in sequence,
+/// and keeps the output of every step, so the change sets and events can be inspected between steps.
+pub struct TestScenario {
+    binding_test: RustBindingTest,
+    keystore: InMemKeystore,
+    state_root: H256,
+    outputs: Vec<TransactionOutput>,
+}
+
+impl TestScenario {
+    pub fn new() -> Result<Self> {
+        let binding_test = RustBindingTest::new()?;
+        let state_root = binding_test.executor().genesis().genesis_state_root();
+        Ok(Self {
+            binding_test,
+            keystore: InMemKeystore::new_insecure_for_tests(0),
+            state_root,
+            outputs: vec![],
+        })
+    }
+
+    pub fn binding_test(&self) -> &RustBindingTest {
+        &self.binding_test
+    }
+
+    /// Create a new sender with a random key pair
+    pub fn create_sender(&mut self) -> Result<RoochAddress> {
+        let result = self
+            .keystore
+            .generate_and_add_new_key(None, None, None, None)?;
+        Ok(result.address)
+    }
+
+    /// Create `count` new senders
+    pub fn create_senders(&mut self, count: usize) -> Result<Vec<RoochAddress>> {
+        (0..count).map(|_| self.create_sender()).collect()
+    }
+
+    /// Get the on-chain sequence number of the sender, return 0 if the account does not exist
+    pub fn sequence_number(&self, sender: RoochAddress) -> Result<u64> {
+        Ok(self
+            .binding_test
+            .executor()
+            .moveos()
+            .state()
+            .get_states(AccessPath::resource(sender.into(), Account::struct_tag()))?
+            .pop()
+            .flatten()
+            .map(|state| state.cast::<Account>())
+            .transpose()?
+            .map_or(0, |account| account.sequence_number))
+    }
+
+    /// Sign and execute the action as the `sender`, the sequence number is resolved from the current state.
+    /// The output is kept whether the transaction is executed successfully or not.
+    pub fn execute_as_result(
+        &mut self,
+        sender: RoochAddress,
+        action: MoveAction,
+    ) -> Result<ExecuteTransactionResult> {
+        let sequence_number = self.sequence_number(sender)?;
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+        let tx = self.keystore.sign_transaction(&sender, tx_data, None)?;
+        let result = self.binding_test.execute_as_result(tx)?;
+        self.state_root = result.transaction_info.state_root;
+        self.binding_test
+            .reader_executor
+            .moveos()
+            .refresh_state(self.state_root, result.output.is_upgrade)?;
+        self.outputs.push(result.output.clone());
+        Ok(result)
+    }
+
+    /// Execute the action as the `sender`, and require the transaction is executed successfully
+    pub fn execute(
+        &mut self,
+        sender: RoochAddress,
+        action: MoveAction,
+    ) -> Result<TransactionOutput> {
+        let result = self.execute_as_result(sender, action)?;
+        if result.transaction_info.status != KeptVMStatus::Executed {
+            bail!(
+                "tx should success, error: {:?}",
+                result.transaction_info.status
+            );
+        }
+        Ok(result.output)
+    }
+
+    /// The outputs of the executed steps, in execution order
+    pub fn outputs(&self) -> &[TransactionOutput] {
+        &self.outputs
+    }
+
+    /// The output of the last executed step
+    pub fn last_output(&self) -> Option<&TransactionOutput> {
+        self.outputs.last()
+    }
+
+    /// The events emitted by the last executed step
+    pub fn last_events(&self) -> &[Event] {
+        self.last_output()
+            .map(|output| output.events.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Get the states by the access path from the current state
+    pub fn get_states(&self, path: AccessPath) -> Result<Vec<Option<State>>> {
+        self.binding_test
+            .executor()
+            .moveos()
+            .state()
+            .get_states(path)
+    }
+
+    pub fn snapshot(&self) -> ScenarioSnapshot {
+        ScenarioSnapshot {
+            state_root: self.state_root,
+        }
+    }
+
+    /// Roll back the state to the snapshot, the outputs of the steps after the snapshot are kept.
+    /// The loader cache is invalidated, so the modules published after the snapshot are rolled back too.
+    pub fn rollback(&mut self, snapshot: ScenarioSnapshot) -> Result<()> {
+        self.state_root = snapshot.state_root;
+        self.binding_test
+            .executor()
+            .moveos()
+            .refresh_state(self.state_root, true)?;
+        self.binding_test
+            .reader_executor
+            .moveos()
+            .refresh_state(self.state_root, true)?;
+        Ok(())
+    }
+}
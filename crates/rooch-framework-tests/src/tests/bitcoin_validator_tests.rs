@@ -0,0 +1,105 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::key::XOnlyPublicKey;
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::{Address, Network, PublicKey, ScriptBuf};
+use fastcrypto::hash::Sha256;
+use fastcrypto::secp256k1::recoverable::{
+    Secp256k1RecoverableKeyPair, Secp256k1RecoverablePrivateKey,
+};
+use fastcrypto::traits::{KeyPair, ToFromBytes};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::tx_context::TxContext;
+use rooch_types::address::{BitcoinAddress, MultiChainAddress};
+use rooch_types::framework::address_mapping::AddressMapping;
+use rooch_types::framework::bitcoin_validator::BitcoinValidatorModule;
+
+use crate::binding_test;
+
+fn new_keypair(seed: u8) -> Secp256k1RecoverableKeyPair {
+    Secp256k1RecoverablePrivateKey::from_bytes(&[seed; 32])
+        .unwrap()
+        .into()
+}
+
+/// The Bitcoin addresses controlled by the key alone, every type the wallets use for a single key
+fn single_key_addresses(keypair: &Secp256k1RecoverableKeyPair) -> Vec<BitcoinAddress> {
+    let network = Network::Bitcoin;
+    let public_key = PublicKey::from_slice(keypair.public().as_bytes()).unwrap();
+    let p2pk_script = ScriptBuf::new_p2pk(&public_key);
+    vec![
+        Address::p2pkh(&public_key, network),
+        Address::p2wpkh(&public_key, network).unwrap(),
+        Address::p2shwpkh(&public_key, network).unwrap(),
+        Address::p2tr(
+            &Secp256k1::verification_only(),
+            XOnlyPublicKey::from(public_key.inner),
+            None,
+            network,
+        ),
+        Address::p2wsh(&p2pk_script, network),
+        Address::p2sh(&p2pk_script, network).unwrap(),
+    ]
+    .into_iter()
+    .map(BitcoinAddress::from)
+    .collect()
+}
+
+fn sign_payload(
+    keypair: &Secp256k1RecoverableKeyPair,
+    tx_hash: H256,
+    address: MultiChainAddress,
+) -> Vec<u8> {
+    let signature = keypair.sign_recoverable_with_hash::<Sha256>(tx_hash.as_bytes());
+    let mut payload = signature.as_bytes().to_vec();
+    payload.extend(address.to_bytes());
+    payload
+}
+
+#[test]
+fn test_validate() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let bitcoin_validator = binding_test.as_module_binding::<BitcoinValidatorModule>();
+    let address_mapping = binding_test.as_module_binding::<AddressMapping>();
+
+    let keypair = new_keypair(1);
+    for bitcoin_address in single_key_addresses(&keypair) {
+        let multichain_address = MultiChainAddress::from(bitcoin_address.clone());
+        let sender = address_mapping
+            .resolve_or_generate(multichain_address.clone())
+            .unwrap();
+        let tx_hash = H256::random();
+        let ctx = TxContext::new(sender, 0, 10_000_000, tx_hash, 0);
+        let payload = sign_payload(&keypair, tx_hash, multichain_address.clone());
+
+        let validated_address = bitcoin_validator
+            .validate(&ctx, payload)
+            .unwrap_or_else(|e| panic!("validate {} failed: {:?}", bitcoin_address, e));
+        assert_eq!(validated_address, multichain_address);
+    }
+}
+
+#[test]
+fn test_validate_address_of_other_key() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let bitcoin_validator = binding_test.as_module_binding::<BitcoinValidatorModule>();
+    let address_mapping = binding_test.as_module_binding::<AddressMapping>();
+
+    let keypair = new_keypair(1);
+    for bitcoin_address in single_key_addresses(&new_keypair(2)) {
+        let multichain_address = MultiChainAddress::from(bitcoin_address.clone());
+        let sender = address_mapping
+            .resolve_or_generate(multichain_address.clone())
+            .unwrap();
+        let tx_hash = H256::random();
+        let ctx = TxContext::new(sender, 0, 10_000_000, tx_hash, 0);
+        let payload = sign_payload(&keypair, tx_hash, multichain_address);
+
+        assert!(
+            bitcoin_validator.validate(&ctx, payload).is_err(),
+            "the address {} of the other key must not be validated",
+            bitcoin_address
+        );
+    }
+}
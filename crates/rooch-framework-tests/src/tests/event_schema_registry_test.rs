@@ -0,0 +1,20 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::language_storage::StructTag;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_types::framework::event_schema_registry::EventSchemaRegistryModule;
+use std::str::FromStr;
+
+#[test]
+fn test_event_schemas_of_unregistered_event() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let event_type =
+        StructTag::from_str("0x3::sequencer_key_registry::SequencerKeyRotatedEvent").unwrap();
+    let schemas = binding_test
+        .as_module_binding::<EventSchemaRegistryModule>()
+        .event_schemas(&event_type)
+        .unwrap();
+    assert!(schemas.is_empty());
+}
@@ -0,0 +1,70 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_binary_format::CompiledModule;
+use moveos_types::h256::H256;
+use moveos_types::transaction::MoveAction;
+use rooch_genesis::{framework_hash, FrameworkCheckResult};
+use std::collections::BTreeSet;
+
+#[test]
+fn test_framework_check() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let genesis = binding_test.executor.genesis();
+    let result = genesis
+        .check_framework(binding_test.executor.moveos())
+        .unwrap();
+    assert!(result.is_matched(), "{}", result);
+    assert!(!result.is_upgradable());
+    assert_eq!(result.onchain_version, 0);
+    assert!(result.mismatched_modules.is_empty());
+
+    let mut modules = genesis.framework_modules().unwrap();
+    assert_eq!(framework_hash(&modules).unwrap(), result.embedded_hash);
+    let (_, bytes) = modules.iter_mut().next().unwrap();
+    bytes.push(0);
+    assert_ne!(framework_hash(&modules).unwrap(), result.embedded_hash);
+}
+
+#[test]
+fn test_framework_upgradable() {
+    let result = |onchain_version: u64, onchain_hash: H256| FrameworkCheckResult {
+        embedded_version: 2,
+        onchain_version,
+        embedded_hash: H256::zero(),
+        onchain_hash,
+        mismatched_modules: vec![],
+    };
+    // The on-chain framework of an older node is upgraded to the embedded one
+    assert!(result(1, H256::repeat_byte(1)).is_upgradable());
+    // The same modules are matched whatever the versions
+    assert!(result(1, H256::zero()).is_matched());
+    assert!(!result(1, H256::zero()).is_upgradable());
+    // The embedded framework is never downgraded
+    assert!(!result(2, H256::repeat_byte(1)).is_upgradable());
+    assert!(!result(3, H256::repeat_byte(1)).is_upgradable());
+}
+
+#[test]
+fn test_framework_upgrade_action() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let genesis = binding_test.executor.genesis();
+    let call = match genesis.framework_upgrade_action().unwrap() {
+        MoveAction::Function(call) => call,
+        action => panic!("unexpected action {:?}", action),
+    };
+    // The UpgradeCap and the bundles of the std, moveos_std, rooch_framework and bitcoin_move
+    assert_eq!(call.args.len(), 5);
+    let upgraded_modules = call.args[1..]
+        .iter()
+        .flat_map(|arg| bcs::from_bytes::<Vec<Vec<u8>>>(arg).unwrap())
+        .map(|bytes| CompiledModule::deserialize(&bytes).unwrap().self_id())
+        .collect::<BTreeSet<_>>();
+    let embedded_modules = genesis
+        .framework_modules()
+        .unwrap()
+        .into_keys()
+        .collect::<BTreeSet<_>>();
+    assert_eq!(upgraded_modules, embedded_modules);
+}
@@ -0,0 +1,31 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use rooch_executor::actor::framework_version::{
+    check_framework_version, framework_versions_module_exists, load_active_framework_address,
+};
+use rooch_types::addresses::{ROOCH_FRAMEWORK_ADDRESS, ROOCH_FRAMEWORK_VERSION_ADDRESSES};
+
+#[test]
+fn test_route_framework_version() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos = binding_test.executor().moveos();
+
+    // No version is activated at genesis, the system functions are called at `@rooch_framework`
+    assert!(framework_versions_module_exists(moveos).unwrap());
+    assert_eq!(
+        load_active_framework_address(moveos).unwrap(),
+        ROOCH_FRAMEWORK_ADDRESS
+    );
+    assert_eq!(
+        binding_test.executor().framework_address(),
+        ROOCH_FRAMEWORK_ADDRESS
+    );
+
+    check_framework_version(moveos, ROOCH_FRAMEWORK_ADDRESS).unwrap();
+    // Nothing is published at the framework version addresses, the routing to them is refused
+    for framework_address in ROOCH_FRAMEWORK_VERSION_ADDRESSES {
+        assert!(check_framework_version(moveos, framework_address).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_store::MoveOSStore;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_genesis::RoochGenesis;
+use rooch_store::RoochStore;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::framework::gas_schedule::{GasEntry, GasScheduleModule};
+
+#[test]
+fn test_genesis_cost_table_is_loaded_from_chain() {
+    let moveos_store = MoveOSStore::mock_moveos_store().unwrap();
+    let rooch_store = RoochStore::mock_rooch_store().unwrap();
+    let entries = vec![GasEntry::new("instruction_tiers.0".to_owned(), 3)];
+    let genesis_ctx = RoochChainID::LOCAL
+        .genesis_ctx(RoochAddress::random())
+        .with_gas_schedule_entries(entries.clone());
+    let executor = ExecutorActor::new(
+        genesis_ctx,
+        BitcoinGenesisContext::new(Network::default().to_num()),
+        moveos_store.clone(),
+        rooch_store.clone(),
+    )
+    .unwrap();
+
+    // The entries of the genesis config are stored on chain as the builtin gas schedule
+    let genesis_gas_schedule = executor
+        .moveos()
+        .as_module_binding::<GasScheduleModule>()
+        .genesis_gas_schedule()
+        .unwrap();
+    assert!(genesis_gas_schedule.is_builtin());
+    assert_eq!(genesis_gas_schedule.entries, entries);
+    assert_eq!(executor.genesis_cost_table().instruction_tier(0).0, 3);
+    assert_eq!(
+        executor.moveos().cost_table(),
+        executor.genesis_cost_table()
+    );
+
+    // A reader executor started with another genesis config meters by the genesis cost table of the chain
+    let other_genesis = RoochGenesis::build(
+        RoochChainID::LOCAL.genesis_ctx(RoochAddress::random()),
+        BitcoinGenesisContext::new(Network::default().to_num()),
+    )
+    .unwrap();
+    assert_ne!(
+        other_genesis.cost_table().unwrap(),
+        *executor.genesis_cost_table()
+    );
+    let reader_executor =
+        ReaderExecutorActor::new(other_genesis, moveos_store, rooch_store).unwrap();
+    assert_eq!(
+        reader_executor.genesis_cost_table(),
+        executor.genesis_cost_table()
+    );
+    assert_eq!(
+        reader_executor.moveos().cost_table(),
+        executor.moveos().cost_table()
+    );
+    // Both are metered by the builtin gas schedule until a new schedule is activated
+    assert_eq!(executor.gas_schedule_version(), Some(0));
+    assert_eq!(
+        reader_executor.gas_schedule_version(),
+        executor.gas_schedule_version()
+    );
+}
@@ -2,10 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod bitcoin_light_client_test;
+mod bitcoin_validator_tests;
 mod brc20_test;
 mod empty_tests;
 mod ethereum_light_client_test;
 mod ethereum_validator_tests;
+mod framework_check_test;
+mod framework_version_test;
+mod event_schema_registry_test;
+mod gas_schedule_test;
+mod module_blocklist_test;
+mod native_gas_test;
+mod native_memory_quota_test;
 mod native_validator_tests;
+mod object_change_test;
 mod ord_test;
+mod sequencer_key_registry_test;
+mod test_scenario_test;
 mod transaction_validator_tests;
+mod view_function_limits_test;
@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::language_storage::ModuleId;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveStructType;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::module_blocklist::{reachable_functions, ModuleBlocklistModule};
+use rooch_types::framework::transfer::TransferModule;
+use std::str::FromStr;
+
+#[test]
+fn test_reachable_functions() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let resolver = binding_test.executor().moveos().moveos_resolver();
+    let action = TransferModule::create_transfer_coin_action(
+        GasCoin::struct_tag(),
+        ROOCH_FRAMEWORK_ADDRESS,
+        1u64.into(),
+    );
+    let functions = reachable_functions(resolver, &action).unwrap();
+    // The functions called by the entry function through the other modules are reachable
+    for function in [
+        "0x3::transfer::transfer_coin",
+        "0x3::account::create_account",
+        "0x3::account_coin_store::transfer",
+    ] {
+        assert!(
+            functions.contains(&FunctionId::from_str(function).unwrap()),
+            "{} should be reachable",
+            function
+        );
+    }
+    assert!(!functions.contains(&FunctionId::new(
+        ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, "transfer".parse().unwrap()),
+        "transfer_object".parse().unwrap(),
+    )));
+
+    let blocklist = binding_test.as_module_binding::<ModuleBlocklistModule>();
+    assert!(blocklist.is_empty().unwrap());
+    assert!(!blocklist.is_any_function_blocked(&functions).unwrap());
+}
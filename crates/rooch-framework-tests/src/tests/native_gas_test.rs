@@ -0,0 +1,337 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_core_types::vm_status::VMStatus;
+use moveos_stdlib::natives::instrumentation;
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::FunctionCall;
+use rooch_framework::natives::gas_parameter::gas_member::InitialGasSchedule;
+use rooch_framework::natives::{all_natives, GasParameters};
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use std::collections::BTreeSet;
+
+/// The modules whose natives are not metered by the size of their input, their gas is checked by the tests
+/// of the modules. A native out of these modules must be called by the `native_calls`.
+const UNCOVERED_MODULES: &[&str] = &[
+    "0x2::type_info",
+    "0x2::rlp",
+    "0x2::event",
+    "0x2::test_helper",
+    "0x2::signer",
+    "0x2::move_module",
+    "0x2::object",
+    "0x2::dispatch",
+    "0x2::math",
+    "0x2::fixed_point64",
+    "0x2::string_utils",
+    "0x2::vector_utils",
+    "0x2::address",
+    "0x2::raw_table",
+    "0x3::account",
+];
+
+/// The natives which can not fail, or reject a malformed input without an abort,
+/// their failure path is covered by the calls without an abort.
+const NON_ABORTING_NATIVES: &[&str] = &[
+    "0x3::ed25519::verify",
+    "0x3::encoding::base58",
+    "0x3::encoding::base58check",
+    "0x2::json::native_from_json",
+];
+
+const KECCAK256: u8 = 0;
+const SHA256: u8 = 1;
+
+/// A call of a Move function which calls the native `native`
+struct NativeCall {
+    address: AccountAddress,
+    module: &'static str,
+    function: &'static str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<Vec<u8>>,
+    native: &'static str,
+    /// The native aborts with the malformed input
+    aborts: bool,
+}
+
+impl NativeCall {
+    fn new(address: AccountAddress, module: &'static str, function: &'static str) -> Self {
+        Self {
+            address,
+            module,
+            function,
+            ty_args: vec![],
+            args: vec![],
+            native: function,
+            aborts: false,
+        }
+    }
+
+    fn bytes(mut self, hex: &str) -> Self {
+        self.args
+            .push(bcs::to_bytes(&hex::decode(hex).unwrap()).unwrap());
+        self
+    }
+
+    fn u8(mut self, value: u8) -> Self {
+        self.args.push(bcs::to_bytes(&value).unwrap());
+        self
+    }
+
+    fn native(mut self, native: &'static str) -> Self {
+        self.native = native;
+        self
+    }
+
+    fn aborts(mut self) -> Self {
+        self.aborts = true;
+        self
+    }
+
+    fn native_name(&self) -> String {
+        format!(
+            "{}::{}::{}",
+            self.address.short_str_lossless(),
+            self.module,
+            self.native
+        )
+    }
+
+    fn function_call(&self) -> FunctionCall {
+        FunctionCall::new(
+            FunctionId::new(
+                ModuleId::new(self.address, Identifier::new(self.module).unwrap()),
+                Identifier::new(self.function).unwrap(),
+            ),
+            self.ty_args.clone(),
+            self.args.clone(),
+        )
+    }
+}
+
+fn framework(module: &'static str, function: &'static str) -> NativeCall {
+    NativeCall::new(ROOCH_FRAMEWORK_ADDRESS, module, function)
+}
+
+fn moveos_std(module: &'static str, function: &'static str) -> NativeCall {
+    NativeCall::new(MOVEOS_STD_ADDRESS, module, function)
+}
+
+fn native_calls() -> Vec<NativeCall> {
+    let msg = "00010203";
+    let k1_pubkey = "033e99a541db69bd32040dfe5037fbf5210dafa8151a71e21c5204b05d95ce0a62";
+    let k1_sig = "416a21d50b3c838328d4f03213f8ef0c3776389a972ba1ecd37b56243734eba208ea6aaa6fc076ad7accd71d355f693a6fe54fe69b3c168eace9803827bc9046";
+    let recoverable_msg = hex::encode(b"Hello, world!");
+    let recoverable_sig = "7e4237ebfbc36613e166bfc5f6229360a9c1949242da97ca04867e4de57b2df30c8340bcb320328cf46d71bda51fcb519e3ce53b348eec62de852e350edbd88600";
+    let schnorr_pubkey = "3e99a541db69bd32040dfe5037fbf5210dafa8151a71e21c5204b05d95ce0a62";
+    let schnorr_sig = "bf4395f2f5a75dcfc82f7f4dd9ff032c450b5caed39bdd7b09df4cfa1b15ecd0c9f1d124916903b5291623bd06f2bc005ad8e92c74ec6d962f2d41f3ea2600e7";
+    let ed25519_msg = "315f5bdb76d078c43b8ac0064e4a0164612b1fce77c869345bfc94c75894edd3";
+    let ed25519_pubkey = "cc62332e34bb2d5cd69f60efbb2a36cb916c7eb458301ea36636c4dbb012bd88";
+    let ed25519_sig = "cce72947906dbae4c166fc01fd096432784032be43db540909bc901dbc057992b4d655ca4f4355cf0868e1266baacf6919902969f063e74162f8f04bc4056105";
+    let witness_program = "751e76e8199196d454941c45d1b3a323f1433bd6";
+
+    vec![
+        framework("hash", "keccak256").bytes(msg),
+        framework("hash", "blake2b256").bytes(msg),
+        framework("hash", "ripemd160").bytes(msg),
+        framework("ecdsa_k1", "verify")
+            .bytes(k1_sig)
+            .bytes(k1_pubkey)
+            .bytes(msg)
+            .u8(SHA256),
+        framework("ecdsa_k1", "verify")
+            .bytes("")
+            .bytes(k1_pubkey)
+            .bytes(msg)
+            .u8(SHA256)
+            .aborts(),
+        framework("ecdsa_k1", "verify")
+            .bytes(k1_sig)
+            .bytes("")
+            .bytes(msg)
+            .u8(SHA256)
+            .aborts(),
+        framework("ecdsa_k1_recoverable", "ecrecover")
+            .bytes(recoverable_sig)
+            .bytes(&recoverable_msg)
+            .u8(KECCAK256),
+        framework("ecdsa_k1_recoverable", "ecrecover")
+            .bytes(&recoverable_sig[..128])
+            .bytes(&recoverable_msg)
+            .u8(KECCAK256)
+            .aborts(),
+        framework("ecdsa_k1_recoverable", "ecrecover")
+            .bytes(&"00".repeat(65))
+            .bytes("00")
+            .u8(KECCAK256)
+            .aborts(),
+        framework("ecdsa_k1_recoverable", "verify")
+            .bytes(recoverable_sig)
+            .bytes(&recoverable_msg)
+            .u8(KECCAK256),
+        framework("ecdsa_k1_recoverable", "verify")
+            .bytes("")
+            .bytes(&recoverable_msg)
+            .u8(KECCAK256)
+            .aborts(),
+        framework("ecdsa_k1_recoverable", "decompress_pubkey").bytes(k1_pubkey),
+        framework("ecdsa_k1_recoverable", "decompress_pubkey")
+            .bytes(&format!("01{}", &k1_pubkey[2..]))
+            .aborts(),
+        framework("schnorr", "verify")
+            .bytes(schnorr_sig)
+            .bytes(schnorr_pubkey)
+            .bytes(msg)
+            .u8(SHA256),
+        framework("schnorr", "verify")
+            .bytes("")
+            .bytes(schnorr_pubkey)
+            .bytes(msg)
+            .u8(SHA256)
+            .aborts(),
+        framework("schnorr", "verify")
+            .bytes(schnorr_sig)
+            .bytes("")
+            .bytes(msg)
+            .u8(SHA256)
+            .aborts(),
+        framework("ed25519", "verify")
+            .bytes(ed25519_sig)
+            .bytes(ed25519_pubkey)
+            .bytes(ed25519_msg),
+        // The malformed signature is rejected without an abort
+        framework("ed25519", "verify")
+            .bytes("")
+            .bytes(ed25519_pubkey)
+            .bytes(ed25519_msg),
+        framework("encoding", "base58").bytes(msg),
+        framework("encoding", "base58check").bytes(msg).u8(0),
+        framework("encoding", "bech32").bytes(witness_program).u8(0),
+        framework("encoding", "bech32")
+            .bytes(witness_program)
+            .u8(32)
+            .aborts(),
+        framework("encoding", "p2pkh").bytes(k1_pubkey),
+        framework("encoding", "p2pkh").bytes("").aborts(),
+        framework("encoding", "p2sh").bytes(k1_pubkey),
+        framework("encoding", "p2sh").bytes("").aborts(),
+        framework("decoding", "base58").bytes(&hex::encode(b"2NEpo7TZRRrLZSi2U")),
+        framework("decoding", "base58")
+            .bytes(&hex::encode(b"0OIl"))
+            .aborts(),
+        framework("decoding", "base58check")
+            .bytes(&hex::encode(b"0OIl"))
+            .u8(0)
+            .aborts(),
+        moveos_std("bcs", "to_u64")
+            .bytes("0100000000000000")
+            .native("native_from_bytes"),
+        moveos_std("bcs", "to_u64")
+            .bytes("01")
+            .native("native_from_bytes")
+            .aborts(),
+        moveos_std("json", "to_map")
+            .bytes(&hex::encode(b"{\"a\":\"b\"}"))
+            .native("native_from_json"),
+        // The malformed json is parsed to an empty map without an abort
+        moveos_std("json", "to_map")
+            .bytes(&hex::encode(b"{\"a\""))
+            .native("native_from_json"),
+    ]
+}
+
+/// Every native charges gas on both the success and the failure paths,
+/// so a malformed input can not be used to consume the computation for free.
+#[test]
+fn test_natives_charge_gas_on_success_and_failure() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+    instrumentation::enable();
+    for call in native_calls() {
+        let name = call.native_name();
+        let before = instrumentation::snapshot()
+            .remove(&name)
+            .unwrap_or_default();
+        let result = binding_test
+            .call_function(&ctx, call.function_call())
+            .unwrap();
+        let after = instrumentation::snapshot()
+            .remove(&name)
+            .unwrap_or_default();
+        assert!(after.calls > before.calls, "{} is not called", name);
+        assert!(
+            after.total_gas > before.total_gas,
+            "{} charges no gas, vm status: {:?}",
+            name,
+            result.vm_status
+        );
+        if call.aborts {
+            assert!(
+                matches!(result.vm_status, VMStatus::MoveAbort(..)),
+                "{} should abort, vm status: {:?}",
+                name,
+                result.vm_status
+            );
+            assert!(after.failures > before.failures);
+        } else {
+            assert_eq!(
+                result.vm_status,
+                VMStatus::Executed,
+                "{} should succeed",
+                name
+            );
+        }
+    }
+    instrumentation::disable();
+}
+
+/// Every native of the framework is called by the `native_calls` on both the success and the failure paths,
+/// except the natives of the move stdlib and the `UNCOVERED_MODULES`, so a new native can not skip the check.
+#[test]
+fn test_natives_are_covered() {
+    let calls = native_calls();
+    let covered: BTreeSet<String> = calls.iter().map(|call| call.native_name()).collect();
+    let uncovered: Vec<String> = all_natives(GasParameters::initial())
+        .unwrap()
+        .into_iter()
+        .filter(|(address, _, _, _)| *address != AccountAddress::ONE)
+        .map(|(address, module, function, _)| {
+            (
+                format!("{}::{}", address.short_str_lossless(), module),
+                function,
+            )
+        })
+        .filter(|(module, _)| !UNCOVERED_MODULES.contains(&module.as_str()))
+        .map(|(module, function)| format!("{}::{}", module, function))
+        .filter(|name| !covered.contains(name))
+        .collect();
+    assert!(
+        uncovered.is_empty(),
+        "the natives are not covered by the native gas test: {:?}",
+        uncovered
+    );
+
+    // The natives charging on the failure paths are called with a malformed input
+    let failures: BTreeSet<String> = calls
+        .iter()
+        .filter(|call| call.aborts)
+        .map(|call| call.native_name())
+        .collect();
+    for name in covered.iter() {
+        let charges_failure = !name.starts_with("0x3::hash::");
+        assert!(
+            !charges_failure
+                || failures.contains(name)
+                || NON_ABORTING_NATIVES.contains(&name.as_str()),
+            "the failure path of {} is not covered",
+            name
+        );
+    }
+}
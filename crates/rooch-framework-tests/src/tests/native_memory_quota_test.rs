@@ -0,0 +1,37 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::KeptVMStatus;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::timestamp::TimestampModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_native_memory_quota_exceeded() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let cost_table = binding_test.executor().moveos().cost_table().clone();
+    // Any state loaded by the natives of the user action exceeds the quota
+    let mut entries = BTreeMap::new();
+    entries.insert("extra_gas_parameter.native_memory_quota".to_owned(), 1);
+    binding_test
+        .executor
+        .set_cost_table(cost_table.with_entries(&entries).unwrap());
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = TimestampModule::create_fast_forward_seconds_for_local_action(1);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action.clone());
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    // The system functions are exempted from the quota, so the transaction is kept as out of gas
+    let result = binding_test.execute_as_result(tx).unwrap();
+    assert_eq!(result.transaction_info.status, KeptVMStatus::OutOfGas);
+
+    // The node keeps executing, and the sequence number of the sender is increased by the failed transaction
+    binding_test.executor.set_cost_table(cost_table);
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+}
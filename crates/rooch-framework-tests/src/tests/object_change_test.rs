@@ -0,0 +1,69 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::MoveAction;
+use rooch_executor::actor::object_change_notifier::ObjectChangeNotifier;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::AbstractTransaction;
+
+#[test]
+fn test_object_changes() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let notifier = ObjectChangeNotifier::default();
+    binding_test
+        .executor
+        .set_object_change_notifier(notifier.clone());
+    let mut receiver = notifier.subscribe();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let receiver_address = keystore.addresses()[1];
+
+    // The changes of the transaction without a tx order are not published
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+    assert!(receiver.try_recv().is_err());
+
+    let action = TransferModule::create_transfer_coin_action(
+        GasCoin::struct_tag(),
+        receiver_address.into(),
+        U256::from(100u64),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let tx_hash = tx.tx_hash();
+    let tx_order = 1;
+    let rooch_store = binding_test.executor.get_rooch_store();
+    rooch_store
+        .save_tx_sequence_info_mapping(tx_order, tx_hash)
+        .unwrap();
+    rooch_store
+        .save_tx_sequence_info_reverse_mapping(tx_hash, tx_order)
+        .unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let tx_changes = receiver.try_recv().unwrap();
+    assert_eq!(tx_changes.tx_order, tx_order);
+    assert_eq!(tx_changes.tx_hash, tx_hash);
+    // The coin store of the sender is modified, the state before it is read at the state root before the transaction
+    assert!(tx_changes.changes.iter().any(|change| matches!(
+        (&change.before, &change.after),
+        (Some(before), Some(after)) if before != after
+    )));
+    // The coin store of the receiver is created by the transfer
+    assert!(tx_changes
+        .changes
+        .iter()
+        .any(|change| change.before.is_none() && change.after.is_some()));
+}
@@ -0,0 +1,85 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos_types::h256::H256;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::sequencer_key_registry::{
+    PendingRotation, SequencerKeyRegistryModule, SequencerKeys,
+};
+use rooch_types::sequencer::SequencerKeyTracker;
+use rooch_types::transaction::TransactionSequenceInfo;
+
+#[test]
+fn test_sequencer_keys_from_registry() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let keys = binding_test
+        .as_module_binding::<SequencerKeyRegistryModule>()
+        .sequencer_keys()
+        .unwrap();
+    assert_eq!(keys.key_version, 0);
+    assert!(keys.pending_rotation.is_none());
+    assert!(keys.is_valid_sequencer(keys.sequencer));
+    assert!(!keys.in_handover());
+
+    let mut tracker = SequencerKeyTracker::new(&binding_test).unwrap();
+    assert_eq!(tracker.keys(), &keys);
+    assert!(!tracker.refresh(&binding_test).unwrap());
+}
+
+#[test]
+fn test_verify_tx_order_signature_during_handover() {
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let addresses = keystore.addresses();
+    let (sequencer, next_sequencer) = (addresses[0], addresses[1]);
+
+    let tx_hash = H256::random();
+    let tx_order = 1;
+    let witness_hash = TransactionSequenceInfo::tx_order_witness_hash(tx_hash, tx_order);
+    let sequence_info = |signer: RoochAddress| {
+        let signature = keystore
+            .sign_hashed(&signer, witness_hash.as_bytes(), None)
+            .unwrap();
+        TransactionSequenceInfo::new(tx_order, signature.into(), H256::random())
+    };
+
+    let mut keys = SequencerKeys {
+        sequencer: sequencer.into(),
+        key_version: 0,
+        pending_rotation: Some(PendingRotation {
+            next_sequencer: next_sequencer.into(),
+            next_public_key: vec![],
+            announced_at: 0,
+            handover_end: 1000,
+        }),
+        timestamp_milliseconds: 500,
+    };
+    assert!(keys.in_handover());
+    assert_eq!(
+        keys.verify_tx_order_signature(tx_hash, &sequence_info(sequencer))
+            .unwrap(),
+        sequencer
+    );
+    assert_eq!(
+        keys.verify_tx_order_signature(tx_hash, &sequence_info(next_sequencer))
+            .unwrap(),
+        next_sequencer
+    );
+    // the signature of another tx order is rejected
+    assert!(keys
+        .verify_tx_order_signature(H256::random(), &sequence_info(sequencer))
+        .is_err());
+
+    // only the next key is valid after the handover window
+    keys.timestamp_milliseconds = 1000;
+    assert!(!keys.in_handover());
+    assert!(keys
+        .verify_tx_order_signature(tx_hash, &sequence_info(sequencer))
+        .is_err());
+    assert!(keys
+        .verify_tx_order_signature(tx_hash, &sequence_info(next_sequencer))
+        .is_ok());
+}
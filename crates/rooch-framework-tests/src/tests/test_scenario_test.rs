@@ -0,0 +1,44 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::test_scenario::TestScenario;
+use moveos_types::transaction::MoveAction;
+use rooch_types::framework::empty::Empty;
+
+#[test]
+fn test_multi_sender_scenario() {
+    let mut scenario = TestScenario::new().unwrap();
+    let senders = scenario.create_senders(2).unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+
+    scenario.execute(senders[0], action.clone()).unwrap();
+    scenario.execute(senders[1], action.clone()).unwrap();
+    scenario.execute(senders[0], action.clone()).unwrap();
+    assert_eq!(scenario.outputs().len(), 3);
+    assert!(!scenario
+        .last_output()
+        .unwrap()
+        .state_changeset
+        .changes
+        .is_empty());
+    assert_eq!(scenario.sequence_number(senders[0]).unwrap(), 2);
+    assert_eq!(scenario.sequence_number(senders[1]).unwrap(), 1);
+}
+
+#[test]
+fn test_scenario_rollback() {
+    let mut scenario = TestScenario::new().unwrap();
+    let sender = scenario.create_sender().unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+
+    scenario.execute(sender, action.clone()).unwrap();
+    let snapshot = scenario.snapshot();
+    scenario.execute(sender, action.clone()).unwrap();
+    assert_eq!(scenario.sequence_number(sender).unwrap(), 2);
+
+    scenario.rollback(snapshot).unwrap();
+    assert_eq!(scenario.snapshot(), snapshot);
+    assert_eq!(scenario.sequence_number(sender).unwrap(), 1);
+    scenario.execute(sender, action).unwrap();
+    assert_eq!(scenario.sequence_number(sender).unwrap(), 2);
+}
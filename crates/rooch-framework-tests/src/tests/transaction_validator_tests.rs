@@ -6,15 +6,24 @@ use ethers::types::{Bytes, U256};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
 use move_core_types::language_storage::ModuleId;
+use move_core_types::u256::U256 as MoveU256;
 use move_core_types::value::MoveValue;
-use move_core_types::vm_status::{AbortLocation, VMStatus};
+use move_core_types::vm_status::{AbortLocation, KeptVMStatus, VMStatus};
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::FunctionCall;
 use moveos_types::{module_binding::ModuleBinding, transaction::MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::free_gas_quota::{FreeGasQuotaModule, DEFAULT_FREE_TX_MAX_GAS_AMOUNT};
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::gas_sponsor::GasSponsorModule;
 use rooch_types::framework::session_key::SessionKeyModule;
 use rooch_types::framework::timestamp::TimestampModule;
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::authenticator::Authenticator;
 use rooch_types::transaction::ethereum::EthereumTransaction;
 use rooch_types::{addresses::ROOCH_FRAMEWORK_ADDRESS, framework::empty::Empty};
 use rooch_types::{
@@ -199,3 +208,238 @@ fn test_session_key_rooch() {
         }
     }
 }
+
+fn gas_balance(binding_test: &binding_test::RustBindingTest, addr: AccountAddress) -> MoveU256 {
+    let call = FunctionCall::new(
+        FunctionId::new(
+            ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, ident_str!("gas_coin").to_owned()),
+            ident_str!("balance").to_owned(),
+        ),
+        vec![],
+        vec![addr.to_vec()],
+    );
+    let result = binding_test
+        .call_function(&TxContext::zero(), call)
+        .unwrap()
+        .into_result()
+        .unwrap();
+    bcs::from_bytes::<MoveU256>(&result[0].value).unwrap()
+}
+
+#[test]
+fn test_failed_tx_pay_gas() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let balance_before = gas_balance(&binding_test, sender.into());
+    // Transfer more than the balance, the transaction aborts after the gas is consumed.
+    let action = TransferModule::create_transfer_coin_action(
+        GasCoin::struct_tag(),
+        AccountAddress::random(),
+        balance_before + MoveU256::one(),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let execute_result = binding_test.execute_as_result(tx).unwrap();
+    let transaction_info = execute_result.transaction_info;
+    assert!(matches!(
+        transaction_info.status,
+        KeptVMStatus::MoveAbort(_, _)
+    ));
+    assert!(transaction_info.gas_used > 0);
+
+    // The transfer is discarded, but the fee of the gas used is deducted.
+    let balance_after = gas_balance(&binding_test, sender.into());
+    assert_eq!(
+        balance_before - balance_after,
+        MoveU256::from(transaction_info.gas_used)
+    );
+    assert!(execute_result
+        .output
+        .events
+        .iter()
+        .any(|event| event.event_type.name.as_str() == "FailedTransactionFeeEvent"));
+}
+
+#[test]
+fn test_validate_sponsored_rooch() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let transaction_validator = binding_test
+        .as_module_binding::<rooch_types::framework::transaction_validator::TransactionValidator>(
+    );
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let sponsor = keystore.addresses()[1];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action).with_sponsor(sponsor);
+    let sponsor_signature = keystore
+        .sign_hashed(&sponsor, tx_data.hash().as_bytes(), None)
+        .unwrap();
+
+    // The sponsor does not co-sign the transaction
+    let tx = keystore
+        .sign_transaction(&sender, tx_data.clone(), None)
+        .unwrap();
+    let auth_info = tx.authenticator_info().unwrap();
+    let move_tx = tx.construct_moveos_transaction(sender.into()).unwrap();
+    let result = transaction_validator
+        .validate(&move_tx.ctx, auth_info)
+        .unwrap()
+        .into_result();
+    assert!(
+        result.is_err(),
+        "The sponsor should co-sign the transaction"
+    );
+
+    // The sponsor co-signs the transaction, but does not grant an allowance to the sender
+    let tx = keystore
+        .sign_transaction(&sender, tx_data, None)
+        .unwrap()
+        .with_sponsor_authenticator(Authenticator::rooch(sponsor_signature));
+    let auth_info = tx.authenticator_info().unwrap();
+    let move_tx = tx.construct_moveos_transaction(sender.into()).unwrap();
+    let result = transaction_validator
+        .validate(&move_tx.ctx, auth_info)
+        .unwrap()
+        .into_result();
+    match result {
+        Err(VMStatus::MoveAbort(AbortLocation::Module(module_id), _)) => {
+            assert_eq!(module_id.name().as_str(), "gas_sponsor");
+        }
+        other => panic!(
+            "The sponsored transaction without allowance should abort, {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn test_execute_sponsored_rooch() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let sponsor = keystore.addresses()[1];
+
+    // The sponsor grants the sender an allowance, and the sender's account is created by its first transaction
+    let allowance = MoveU256::from(10_000_000_000_000_000_000u128);
+    let action = GasSponsorModule::create_grant_allowance_action(sender.into(), allowance);
+    let tx_data = RoochTransactionData::new_for_test(sponsor, 0, action);
+    let tx = keystore.sign_transaction(&sponsor, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let sender_balance = gas_balance(&binding_test, sender.into());
+    let sponsor_balance = gas_balance(&binding_test, sponsor.into());
+    assert_eq!(
+        binding_test
+            .as_module_binding::<GasSponsorModule>()
+            .allowance(sponsor.into(), sender.into())
+            .unwrap(),
+        allowance
+    );
+
+    // The gas of the sponsored transaction is paid by the sponsor, and consumes the allowance of the sender
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action).with_sponsor(sponsor);
+    let sponsor_signature = keystore
+        .sign_hashed(&sponsor, tx_data.hash().as_bytes(), None)
+        .unwrap();
+    let tx = keystore
+        .sign_transaction(&sender, tx_data, None)
+        .unwrap()
+        .with_sponsor_authenticator(Authenticator::rooch(sponsor_signature));
+    binding_test.execute(tx).unwrap();
+
+    assert_eq!(gas_balance(&binding_test, sender.into()), sender_balance);
+    let sponsor_balance_after = gas_balance(&binding_test, sponsor.into());
+    assert!(sponsor_balance_after < sponsor_balance);
+    let allowance_after = binding_test
+        .as_module_binding::<GasSponsorModule>()
+        .allowance(sponsor.into(), sender.into())
+        .unwrap();
+    assert_eq!(
+        allowance - allowance_after,
+        sponsor_balance - sponsor_balance_after
+    );
+}
+
+#[test]
+fn test_free_gas_quota() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    // The max gas amount of the test transaction exceeds the max gas amount of the quota, it pays the gas
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+    let quota = binding_test
+        .as_module_binding::<FreeGasQuotaModule>()
+        .remaining_quota(sender.into())
+        .unwrap();
+    assert!(
+        quota > 0,
+        "The free gas quota should be enabled on the local chain"
+    );
+    let balance_before = gas_balance(&binding_test, sender.into());
+
+    // The transaction within the max gas amount of the quota is free
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let mut tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    tx_data.max_gas_amount = DEFAULT_FREE_TX_MAX_GAS_AMOUNT;
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+    assert_eq!(gas_balance(&binding_test, sender.into()), balance_before);
+    let remaining_quota = binding_test
+        .as_module_binding::<FreeGasQuotaModule>()
+        .remaining_quota(sender.into())
+        .unwrap();
+    assert_eq!(remaining_quota, quota - 1);
+}
+
+#[test]
+fn test_estimate_gas() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let receiver = keystore.addresses()[1];
+
+    // The first transaction of the sender creates its account in the pre execute functions,
+    // the second one transfers the gas coin, the estimates are the gas used by the executed transactions
+    let actions = vec![
+        MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]),
+        TransferModule::create_transfer_coin_action(
+            GasCoin::struct_tag(),
+            receiver.into(),
+            MoveU256::from(100u64),
+        ),
+    ];
+    for (sequence_number, action) in actions.into_iter().enumerate() {
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number as u64, action);
+        let estimate = binding_test
+            .reader_executor
+            .estimate_gas(tx_data.clone(), 10_000)
+            .unwrap();
+        assert_eq!(estimate.status, KeptVMStatus::Executed);
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        let execute_result = binding_test.execute_as_result(tx).unwrap();
+        assert_eq!(
+            execute_result.transaction_info.status,
+            KeptVMStatus::Executed
+        );
+        assert_eq!(estimate.gas_used, execute_result.transaction_info.gas_used);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::{StatusCode, VMStatus};
+use moveos_types::gas_config::ViewFunctionLimits;
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::transaction::FunctionCall;
+use rooch_types::framework::timestamp::TimestampModule;
+
+fn now_microseconds_call() -> FunctionCall {
+    FunctionCall::new(
+        TimestampModule::function_id(TimestampModule::NOW_MICROSECONDS_FUNCTION_NAME),
+        vec![],
+        vec![],
+    )
+}
+
+#[test]
+fn test_view_function_limits() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let moveos = binding_test.reader_executor.moveos();
+
+    let result = moveos.execute_view_function_with_limits(
+        now_microseconds_call(),
+        ViewFunctionLimits {
+            max_gas_amount: 100_000_000,
+            timeout_milliseconds: 5000,
+        },
+    );
+    assert_eq!(result.vm_status, VMStatus::Executed);
+
+    // exceed the gas budget
+    let result = moveos.execute_view_function_with_limits(
+        now_microseconds_call(),
+        ViewFunctionLimits {
+            max_gas_amount: 1,
+            timeout_milliseconds: 5000,
+        },
+    );
+    assert_eq!(result.vm_status.status_code(), StatusCode::OUT_OF_GAS);
+
+    // exceed the timeout
+    let result = moveos.execute_view_function_with_limits(
+        now_microseconds_call(),
+        ViewFunctionLimits {
+            max_gas_amount: 100_000_000,
+            timeout_milliseconds: 0,
+        },
+    );
+    assert_eq!(result.vm_status.status_code(), StatusCode::OUT_OF_GAS);
+}
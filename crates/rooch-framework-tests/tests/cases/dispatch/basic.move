@@ -0,0 +1,46 @@
+//# init --addresses test=0x42
+
+//# publish
+module test::plugin {
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+
+    struct Counter has key {
+        value: u64,
+    }
+
+    entry fun on_trigger(ctx: &mut Context, value: u64) {
+        let obj = context::new_named_object(ctx, Counter { value });
+        object::transfer_extend(obj, @test);
+    }
+
+    public fun value(ctx: &Context): u64 {
+        let obj = context::borrow_object<Counter>(ctx, object::named_object_id<Counter>());
+        object::borrow(obj).value
+    }
+}
+
+//# publish
+module test::host {
+    use std::string;
+    use moveos_std::bcs;
+    use moveos_std::dispatch;
+
+    struct HostWitness has drop {}
+
+    public entry fun trigger(value: u64) {
+        dispatch::dispatch<HostWitness>(@test, string::utf8(b"plugin"), string::utf8(b"on_trigger"), vector[bcs::to_bytes(&value)]);
+    }
+}
+
+//# run test::host::trigger --signers test --args 42u64
+
+//# run --signers test
+script {
+    use moveos_std::context::Context;
+    use test::plugin;
+
+    fun main(ctx: &Context) {
+        assert!(plugin::value(ctx) == 42, 1);
+    }
+}
@@ -14,6 +14,7 @@ module rooch_framework::account {
    friend rooch_framework::transfer;
    friend rooch_framework::genesis;
    friend rooch_framework::upgrade;
+   friend rooch_framework::storage_rent;
 
    /// Resource representing an account.
    struct Account has key, store {
@@ -230,6 +231,20 @@ module rooch_framework::account {
       create_account_unchecked(ctx, new_address)
    }
 
+   #[test_only]
+   /// Create the accounts for the multi-sender test scenarios, return the signers in the order of `addresses`.
+   public fun create_accounts_for_test(ctx: &mut Context, addresses: vector<address>): vector<signer> {
+      let signers = vector::empty<signer>();
+      let i = 0;
+      let len = vector::length(&addresses);
+      while (i < len) {
+         let addr = *vector::borrow(&addresses, i);
+         vector::push_back(&mut signers, create_account_unchecked(ctx, addr));
+         i = i + 1;
+      };
+      signers
+   }
+
    #[test]
    /// Assert correct signer creation.
    fun test_create_signer_for_test() {
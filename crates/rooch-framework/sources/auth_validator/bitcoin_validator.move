@@ -10,11 +10,27 @@ module rooch_framework::bitcoin_validator {
     use rooch_framework::ecdsa_k1_recoverable;
     use rooch_framework::auth_validator;
     use rooch_framework::multichain_address;
+    use rooch_framework::address_mapping;
+    use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
+    use rooch_framework::hash;
+    use rooch_framework::encoding;
     use moveos_std::bcs;
 
     /// there defines auth validator id for each blockchain
     const BITCOIN_AUTH_VALIDATOR_ID: u64 = 2;
 
+    /// The payload types and the witness versions of the Bitcoin address, keep consistent with the rust BitcoinAddress
+    const SCRIPT_HASH_PAYLOAD_TYPE: u8 = 1;
+    const WITNESS_PROGRAM_PAYLOAD_TYPE: u8 = 2;
+    const WITNESS_VERSION_0: u8 = 0;
+    const WITNESS_VERSION_1: u8 = 1;
+
+    /// The opcodes of the scripts controlled by a single public key
+    const OP_0: u8 = 0x00;
+    const OP_PUSHBYTES_20: u8 = 0x14;
+    const OP_PUSHBYTES_33: u8 = 0x21;
+    const OP_CHECKSIG: u8 = 0xac;
+
     // error code
     const ErrorInvalidPublicKeyLength: u64 = 1;
 
@@ -24,11 +40,12 @@ module rooch_framework::bitcoin_validator {
         BITCOIN_AUTH_VALIDATOR_ID
     }
 
+    /// The BCS bytes of the Bitcoin `MultiChainAddress` follow the signature, the length depends on the address type.
     public fun get_address_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
         let address = vector::empty<u8>();
         let i = ecdsa_k1_recoverable::signature_length();
-        let address_position = ecdsa_k1_recoverable::signature_length() + multichain_address::get_length();
-        while (i < address_position) {
+        let payload_length = vector::length(authenticator_payload);
+        while (i < payload_length) {
             let value = vector::borrow(authenticator_payload, i);
             vector::push_back(&mut address, *value);
             i = i + 1;
@@ -60,11 +77,82 @@ module rooch_framework::bitcoin_validator {
         );
     }
 
+    /// The Bitcoin addresses controlled by the compressed public key alone:
+    /// the P2PKH and the P2WPKH addresses, the P2SH address wrapping the P2WPKH script,
+    /// the key path only P2TR address of BIP86, and the P2WSH and the P2SH addresses of the `<public_key> OP_CHECKSIG` script.
+    /// The script addresses which require other keys or conditions can not be authenticated by a single signature.
+    public fun public_key_to_addresses(public_key: vector<u8>): vector<BitcoinAddress> {
+        assert!(
+            vector::length(&public_key) == ecdsa_k1_recoverable::public_key_length(),
+            ErrorInvalidPublicKeyLength
+        );
+        let pubkey_hash = hash160(public_key);
+
+        let p2wpkh_script = vector[OP_0, OP_PUSHBYTES_20];
+        vector::append(&mut p2wpkh_script, pubkey_hash);
+
+        let p2pk_script = vector[OP_PUSHBYTES_33];
+        vector::append(&mut p2pk_script, public_key);
+        vector::push_back(&mut p2pk_script, OP_CHECKSIG);
+
+        vector[
+            bitcoin_address::new_p2pkh(pubkey_hash),
+            new_witness_program(WITNESS_VERSION_0, pubkey_hash),
+            new_script_hash(hash160(p2wpkh_script)),
+            new_witness_program(WITNESS_VERSION_1, encoding::taproot_output_key(&public_key)),
+            new_witness_program(WITNESS_VERSION_0, hash::sha2_256(p2pk_script)),
+            new_script_hash(hash160(p2pk_script)),
+        ]
+    }
+
+    fun hash160(data: vector<u8>): vector<u8> {
+        hash::ripemd160(&hash::sha2_256(data))
+    }
+
+    fun new_script_hash(script_hash: vector<u8>): BitcoinAddress {
+        let bytes = vector[SCRIPT_HASH_PAYLOAD_TYPE];
+        vector::append(&mut bytes, script_hash);
+        bitcoin_address::from_bytes(bytes)
+    }
+
+    fun new_witness_program(version: u8, program: vector<u8>): BitcoinAddress {
+        let bytes = vector[WITNESS_PROGRAM_PAYLOAD_TYPE, version];
+        vector::append(&mut bytes, program);
+        bitcoin_address::new_witness_program(bytes)
+    }
+
+    /// Validate the signature, and the Bitcoin address in the payload must be derived from the public key recovered from the signature.
+    /// The sender must be the Rooch address the Bitcoin address is bound to, or the address generated from it if it is not bound yet,
+    /// so a new Bitcoin user sends the first transaction from the generated address, without creating the account first,
+    /// the account and the address mapping are created in the `transaction_validator::pre_execute`.
     public fun validate(ctx: &Context, authenticator_payload: vector<u8>): MultiChainAddress {
+        validate_account(ctx, context::sender(ctx), authenticator_payload)
+    }
+
+    /// Validate the authenticator is the `account`'s signature of the transaction hash, such as the signature of the sender or the gas sponsor.
+    public fun validate_account(ctx: &Context, account: address, authenticator_payload: vector<u8>): MultiChainAddress {
         let tx_hash = context::tx_hash(ctx);
         validate_signature(&authenticator_payload, &tx_hash);
 
-        bcs::from_bytes<MultiChainAddress>(get_address_from_authenticator_payload(&authenticator_payload))
+        let maddress = bcs::from_bytes<MultiChainAddress>(get_address_from_authenticator_payload(&authenticator_payload));
+        assert!(
+            multichain_address::is_bitcoin_address(&maddress),
+            auth_validator::error_invalid_authenticator()
+        );
+        let public_key = ecdsa_k1_recoverable::ecrecover(
+            &get_signature_from_authenticator_payload(&authenticator_payload),
+            &tx_hash,
+            ecdsa_k1_recoverable::sha256()
+        );
+        assert!(
+            vector::contains(&public_key_to_addresses(public_key), &multichain_address::into_bitcoin_address(maddress)),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            address_mapping::resolve_or_generate(ctx, maddress) == account,
+            auth_validator::error_invalid_account_auth_key()
+        );
+        maddress
     }
 
     fun pre_execute(
@@ -74,4 +162,25 @@ module rooch_framework::bitcoin_validator {
     fun post_execute(
         _ctx: &mut Context,
     ) {}
+
+    #[test]
+    fun test_public_key_to_addresses() {
+        let public_key = x"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let addresses = public_key_to_addresses(public_key);
+        // 1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH
+        assert!(*vector::borrow(&addresses, 0) == bitcoin_address::new_p2pkh(x"751e76e8199196d454941c45d1b3a323f1433bd6"), 1000);
+        // bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4
+        assert!(*vector::borrow(&addresses, 1) == bitcoin_address::new_witness_program(x"0200751e76e8199196d454941c45d1b3a323f1433bd6"), 1001);
+        // 3JvL6Ymt8MVWiCNHC7oWU6nLeHNJKLZGLN
+        assert!(*vector::borrow(&addresses, 2) == bitcoin_address::from_bytes(x"01bcfeb728b584253d5f3f70bcb780e9ef218a68f4"), 1002);
+        assert!(*vector::borrow(&addresses, 3) == bitcoin_address::new_witness_program(x"0201da4710964f7852695de2da025290e24af6d8c281de5a0b902b7135fd9fd74d21"), 1003);
+        assert!(*vector::borrow(&addresses, 4) == bitcoin_address::new_witness_program(x"02001863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262"), 1004);
+        assert!(*vector::borrow(&addresses, 5) == bitcoin_address::from_bytes(x"0123b0ad3477f2178bc0b3eed26e4e6316f4e83aa1"), 1005);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPublicKeyLength)]
+    fun test_public_key_to_addresses_invalid_public_key() {
+        public_key_to_addresses(x"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f8");
+    }
 }
@@ -127,6 +127,24 @@ module rooch_framework::ethereum_validator {
         // TODO compare the auth_key from lsthe payload with the auth_key from the account
     }
 
+    /// Validate the authenticator is the `account`'s signature of the transaction hash, such as the signature of the gas sponsor.
+    /// The authentication key of the public key recovered from the signature must be the Ethereum authentication key of the account.
+    public fun validate_account(ctx: &Context, account: address, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        validate_signature(&authenticator_payload, &tx_hash);
+
+        let public_key = ecdsa_k1_recoverable::ecrecover(
+            &get_signature_from_authenticator_payload(&authenticator_payload),
+            &tx_hash,
+            ecdsa_k1_recoverable::keccak256()
+        );
+        let auth_key_in_account = get_authentication_key_option_from_account(ctx, account);
+        assert!(
+            auth_key_in_account == option::some(public_key_to_authentication_key(public_key)),
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
     fun pre_execute(
         _ctx: &mut Context,
     ) {}
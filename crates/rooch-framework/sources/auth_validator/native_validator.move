@@ -121,11 +121,17 @@ module rooch_framework::native_validator {
     }
 
     public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        validate_account(ctx, context::sender(ctx), authenticator_payload);
+    }
+
+    /// Validate the authenticator is the `account`'s signature of the transaction hash,
+    /// such as the signature of the sender or the gas sponsor.
+    public fun validate_account(ctx: &Context, account: address, authenticator_payload: vector<u8>) {
         let tx_hash = context::tx_hash(ctx);
         validate_signature(&authenticator_payload, &tx_hash);
 
         let auth_key_from_authenticator_payload = get_authentication_key_from_authenticator_payload(&authenticator_payload);
-        let auth_key_in_account = get_authentication_key_with_default(ctx, context::sender(ctx));
+        let auth_key_in_account = get_authentication_key_with_default(ctx, account);
         assert!(
             auth_key_in_account == auth_key_from_authenticator_payload,
             auth_validator::error_invalid_account_auth_key()
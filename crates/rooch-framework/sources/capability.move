@@ -0,0 +1,79 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The capabilities for the privileged operations of the framework.
+/// The capability objects are minted at genesis or publish, and the privileged entry functions
+/// require a `&mut Object<Cap>` argument, so only the owner of the capability can call them.
+module rooch_framework::capability {
+
+    use std::signer;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object, ObjectID};
+    use rooch_framework::core_addresses;
+
+    friend rooch_framework::genesis;
+
+    const ErrorPublisherCapAlreadyExists: u64 = 1;
+    const ErrorPublisherCapMismatch: u64 = 2;
+    const ErrorFrameworkReservedAddress: u64 = 3;
+
+    const BitcoinMoveAccount: address = @0x4;
+
+    /// The capability to upgrade the framework packages
+    struct UpgradeCap has key, store {}
+
+    /// The capability to mint and burn the gas coin
+    struct TreasuryCap has key, store {}
+
+    /// The capability of a package publisher
+    struct PublisherCap has key, store {
+        package: address,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer, upgrade_cap_owner: address, treasury_cap_owner: address, publisher_cap_owner: address) {
+        let upgrade_cap = context::new_named_object(ctx, UpgradeCap{});
+        object::transfer(upgrade_cap, upgrade_cap_owner);
+        let treasury_cap = context::new_named_object(ctx, TreasuryCap{});
+        object::transfer(treasury_cap, treasury_cap_owner);
+        mint_publisher_cap(ctx, @std, publisher_cap_owner);
+        mint_publisher_cap(ctx, @moveos_std, publisher_cap_owner);
+        mint_publisher_cap(ctx, @rooch_framework, publisher_cap_owner);
+        mint_publisher_cap(ctx, BitcoinMoveAccount, publisher_cap_owner);
+    }
+
+    fun mint_publisher_cap(ctx: &mut Context, package: address, owner: address) {
+        let object_id = publisher_cap_id(package);
+        assert!(!context::exists_object<PublisherCap>(ctx, object_id), ErrorPublisherCapAlreadyExists);
+        let cap = context::new_account_named_object(ctx, package, PublisherCap{ package });
+        object::transfer(cap, owner);
+    }
+
+    /// Claim the PublisherCap of the sender's package after publishing modules to the sender address.
+    /// The PublisherCap of the framework reserved addresses are minted at genesis.
+    public entry fun claim_publisher_cap(ctx: &mut Context, account: &signer) {
+        let package = signer::address_of(account);
+        assert!(!core_addresses::is_framework_reserved_address(package), ErrorFrameworkReservedAddress);
+        mint_publisher_cap(ctx, package, package);
+    }
+
+    public fun upgrade_cap_id(): ObjectID {
+        object::named_object_id<UpgradeCap>()
+    }
+
+    public fun treasury_cap_id(): ObjectID {
+        object::named_object_id<TreasuryCap>()
+    }
+
+    public fun publisher_cap_id(package: address): ObjectID {
+        object::account_named_object_id<PublisherCap>(package)
+    }
+
+    public fun publisher_cap_package(cap: &Object<PublisherCap>): address {
+        object::borrow(cap).package
+    }
+
+    /// Assert the PublisherCap is the capability of the `package`
+    public fun assert_publisher(cap: &Object<PublisherCap>, package: address) {
+        assert!(publisher_cap_package(cap) == package, ErrorPublisherCapMismatch);
+    }
+}
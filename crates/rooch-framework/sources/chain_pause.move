@@ -0,0 +1,152 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The emergency pause switch of the chain, for the incident response.
+/// When the chain is paused, the transactions of the users are rejected by the mempool, the sequencer and the executor,
+/// only the system transactions sent by the sequencer or the registered relayers are accepted, and the states can still be read.
+module rooch_framework::chain_pause {
+
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::event;
+    use moveos_std::signer;
+    use rooch_framework::capability::UpgradeCap;
+    use rooch_framework::onchain_config;
+    use rooch_framework::timestamp;
+
+    friend rooch_framework::genesis;
+
+    const ErrorNotSequencer: u64 = 1;
+    const ErrorAlreadyPaused: u64 = 2;
+    const ErrorNotPaused: u64 = 3;
+    const ErrorRelayerAlreadyRegistered: u64 = 4;
+    const ErrorRelayerNotRegistered: u64 = 5;
+
+    struct ChainPause has key {
+        paused: bool,
+        /// The time in milliseconds when the chain is paused
+        paused_at: u64,
+        reason: vector<u8>,
+        /// The relayers are the system senders besides the sequencer, they keep relaying when the chain is paused
+        relayers: vector<address>,
+    }
+
+    struct ChainPausedEvent has drop {
+        paused_by: address,
+        reason: vector<u8>,
+    }
+
+    struct ChainUnpausedEvent has drop {
+        unpaused_by: address,
+    }
+
+    struct RelayersUpdatedEvent has drop {
+        relayers: vector<address>,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let obj = context::new_named_object(ctx, ChainPause{
+            paused: false,
+            paused_at: 0,
+            reason: vector[],
+            relayers: vector[],
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow(ctx: &Context): &ChainPause {
+        let object_id = object::named_object_id<ChainPause>();
+        object::borrow(context::borrow_object<ChainPause>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut ChainPause {
+        let object_id = object::named_object_id<ChainPause>();
+        object::borrow_mut(context::borrow_mut_object_extend<ChainPause>(ctx, object_id))
+    }
+
+    public fun is_paused(ctx: &Context): bool {
+        borrow(ctx).paused
+    }
+
+    public fun paused_at(ctx: &Context): u64 {
+        borrow(ctx).paused_at
+    }
+
+    public fun reason(ctx: &Context): vector<u8> {
+        borrow(ctx).reason
+    }
+
+    public fun relayers(ctx: &Context): vector<address> {
+        borrow(ctx).relayers
+    }
+
+    /// The system senders are the sequencer and the registered relayers
+    public fun is_system_sender(ctx: &Context, sender: address): bool {
+        sender == onchain_config::sequencer(ctx) || vector::contains(&borrow(ctx).relayers, &sender)
+    }
+
+    /// Check the transaction of the `sender` is allowed, only the system senders can send transactions when the chain is paused
+    public fun is_tx_allowed(ctx: &Context, sender: address): bool {
+        !is_paused(ctx) || is_system_sender(ctx, sender)
+    }
+
+    fun do_pause(ctx: &mut Context, paused_by: address, reason: vector<u8>) {
+        let now = timestamp::now_milliseconds(ctx);
+        let chain_pause = borrow_mut(ctx);
+        assert!(!chain_pause.paused, ErrorAlreadyPaused);
+        chain_pause.paused = true;
+        chain_pause.paused_at = now;
+        chain_pause.reason = reason;
+        event::emit(ChainPausedEvent{ paused_by, reason });
+    }
+
+    fun do_unpause(ctx: &mut Context, unpaused_by: address) {
+        let chain_pause = borrow_mut(ctx);
+        assert!(chain_pause.paused, ErrorNotPaused);
+        chain_pause.paused = false;
+        chain_pause.paused_at = 0;
+        chain_pause.reason = vector[];
+        event::emit(ChainUnpausedEvent{ unpaused_by });
+    }
+
+    /// The sequencer pauses the chain
+    public entry fun pause(ctx: &mut Context, account: &signer, reason: vector<u8>) {
+        let sender = signer::address_of(account);
+        assert!(sender == onchain_config::sequencer(ctx), ErrorNotSequencer);
+        do_pause(ctx, sender, reason);
+    }
+
+    /// The sequencer unpauses the chain
+    public entry fun unpause(ctx: &mut Context, account: &signer) {
+        let sender = signer::address_of(account);
+        assert!(sender == onchain_config::sequencer(ctx), ErrorNotSequencer);
+        do_unpause(ctx, sender);
+    }
+
+    /// The owner of the UpgradeCap pauses the chain.
+    /// The chain can only be unpaused by the sequencer, because the transactions of the others are rejected when paused.
+    public entry fun pause_with_cap(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, reason: vector<u8>) {
+        let sender = context::sender(ctx);
+        do_pause(ctx, sender, reason);
+    }
+
+    /// The sequencer registers a relayer as a system sender
+    public entry fun add_relayer(ctx: &mut Context, account: &signer, relayer: address) {
+        assert!(signer::address_of(account) == onchain_config::sequencer(ctx), ErrorNotSequencer);
+        let chain_pause = borrow_mut(ctx);
+        assert!(!vector::contains(&chain_pause.relayers, &relayer), ErrorRelayerAlreadyRegistered);
+        vector::push_back(&mut chain_pause.relayers, relayer);
+        event::emit(RelayersUpdatedEvent{ relayers: chain_pause.relayers });
+    }
+
+    /// The sequencer unregisters a relayer
+    public entry fun remove_relayer(ctx: &mut Context, account: &signer, relayer: address) {
+        assert!(signer::address_of(account) == onchain_config::sequencer(ctx), ErrorNotSequencer);
+        let chain_pause = borrow_mut(ctx);
+        let (found, index) = vector::index_of(&chain_pause.relayers, &relayer);
+        assert!(found, ErrorRelayerNotRegistered);
+        vector::remove(&mut chain_pause.relayers, index);
+        event::emit(RelayersUpdatedEvent{ relayers: chain_pause.relayers });
+    }
+}
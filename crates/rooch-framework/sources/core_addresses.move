@@ -60,6 +60,16 @@ module rooch_framework::core_addresses {
             addr == @0xa
     }
 
+    /// Return true if `addr` is reserved for the framework versions deployed side by side with `@rooch_framework`.
+    public fun is_framework_version_address(addr: address): bool {
+        addr == @0x5 ||
+            addr == @0x6 ||
+            addr == @0x7 ||
+            addr == @0x8 ||
+            addr == @0x9 ||
+            addr == @0xa
+    }
+
     /// Return true if `addr` is 0x3.
     public fun is_rooch_framework_address(addr: address): bool {
         addr == @rooch_framework
@@ -38,6 +38,10 @@ module rooch_framework::encoding {
    /// Creates a pay to (compressed) public key hash address from a public key.
    native public fun p2pkh(public_key: &vector<u8>): vector<u8>;
 
+   /// @param public_key: 33 bytes compressed public key
+   /// Creates the 32 bytes output key of a key path only taproot (P2TR) output from an internal public key, as defined in BIP86.
+   native public fun taproot_output_key(public_key: &vector<u8>): vector<u8>;
+
    #[test]
    /// This test can be verified at http://lenschulwitz.com/base58.
    fun test_base58_encoding() {
@@ -127,4 +131,22 @@ module rooch_framework::encoding {
         
         assert!(p2pkh_address == expected_p2pkh_address, 1006);
     }
+
+    // Test function for the taproot output key generation
+    #[test]
+    /// This test is verified with the test vectors of BIP86.
+    fun test_taproot_output_key() {
+        let public_key = x"02cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115"; // 33-byte compressed internal key
+        let output_key = taproot_output_key(&public_key);
+
+        let expected_output_key = x"a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c"; // bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr
+
+        assert!(output_key == expected_output_key, 1007);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidPubkey, location = Self)]
+    fun test_taproot_output_key_invalid_public_key() {
+        taproot_output_key(&x"cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115");
+    }
 }
@@ -0,0 +1,96 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The registry of the event schemas, it maps an event type to the versioned layouts of the event struct.
+/// Before a module upgrade changes the layout of an event struct, the module owner registers the layout,
+/// so the historical events can still be decoded by the indexer and the RPC after the upgrade.
+/// The layout is the BCS bytes of the annotated `MoveStructLayout` of the event struct.
+module rooch_framework::event_schema_registry {
+
+    use std::string::String;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+    use moveos_std::event;
+    use moveos_std::signer;
+    use moveos_std::type_info;
+    use rooch_framework::timestamp;
+
+    const ErrorNotEventTypeOwner: u64 = 1;
+    const ErrorEmptyLayout: u64 = 2;
+
+    /// A version of the layout of an event struct
+    struct EventSchema has store, copy, drop {
+        /// The version of the schema, starts from 0 and increased by one on every registration
+        version: u64,
+        /// The BCS bytes of the annotated `MoveStructLayout`
+        layout: vector<u8>,
+        /// The time in milliseconds when the schema is registered
+        registered_at: u64,
+    }
+
+    /// The registered schemas of the event type `T`, ordered by version
+    struct EventSchemas<phantom T> has key {
+        schemas: vector<EventSchema>,
+    }
+
+    struct EventSchemaRegisteredEvent has drop {
+        event_type: String,
+        version: u64,
+    }
+
+    /// Register a new version of the layout of the event type `T`.
+    /// Only the account of the module which defines `T` can register the schema.
+    public entry fun register_event_schema<T: drop>(ctx: &mut Context, account: &signer, layout: vector<u8>) {
+        let type_info = type_info::type_of<T>();
+        assert!(type_info::account_address(&type_info) == signer::address_of(account), ErrorNotEventTypeOwner);
+        assert!(!vector::is_empty(&layout), ErrorEmptyLayout);
+
+        let object_id = object::named_object_id<EventSchemas<T>>();
+        if (!context::exists_object<EventSchemas<T>>(ctx, object_id)) {
+            let schemas_obj = context::new_named_object(ctx, EventSchemas<T> {
+                schemas: vector::empty(),
+            });
+            object::transfer_extend(schemas_obj, @rooch_framework);
+        };
+        let registered_at = timestamp::now_milliseconds(ctx);
+        let schemas_obj = context::borrow_mut_object_extend<EventSchemas<T>>(ctx, object_id);
+        let schemas = &mut object::borrow_mut(schemas_obj).schemas;
+        let version = vector::length(schemas);
+        vector::push_back(schemas, EventSchema {
+            version,
+            layout,
+            registered_at,
+        });
+        event::emit(EventSchemaRegisteredEvent {
+            event_type: type_info::type_name<T>(),
+            version,
+        });
+    }
+
+    /// Return all the registered schemas of the event type `T`, ordered by version
+    public fun event_schemas<T>(ctx: &Context): vector<EventSchema> {
+        let object_id = object::named_object_id<EventSchemas<T>>();
+        if (!context::exists_object<EventSchemas<T>>(ctx, object_id)) {
+            return vector::empty()
+        };
+        object::borrow(context::borrow_object<EventSchemas<T>>(ctx, object_id)).schemas
+    }
+
+    /// Return the count of the registered schemas of the event type `T`
+    public fun schema_count<T>(ctx: &Context): u64 {
+        vector::length(&event_schemas<T>(ctx))
+    }
+
+    public fun version(self: &EventSchema): u64 {
+        self.version
+    }
+
+    public fun layout(self: &EventSchema): vector<u8> {
+        self.layout
+    }
+
+    public fun registered_at(self: &EventSchema): u64 {
+        self.registered_at
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The registry of the framework versions deployed side by side.
+/// A breaking version of the framework is published at a framework version address (0x5 ~ 0xa) instead of
+/// replacing the modules at `@rooch_framework`, the packages built against the old version keep linking to it.
+/// The executor routes the system functions, such as the transaction validator, to the active version.
+/// The transaction validator of a version must charge the gas with the gas coin at `@rooch_framework`, the types of
+/// a version are its own, the balances are not shared. The executor refuses to route to a version which does not.
+/// The version 0 is the framework at `@rooch_framework`, it is active if no other version is activated.
+module rooch_framework::framework_versions {
+
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+    use moveos_std::event;
+    use moveos_std::simple_map::{Self, SimpleMap};
+    use rooch_framework::core_addresses;
+
+    friend rooch_framework::upgrade;
+
+    const ErrorNotFrameworkVersionAddress: u64 = 1;
+    const ErrorFrameworkVersionAlreadyRegistered: u64 = 2;
+    const ErrorFrameworkVersionAddressInUse: u64 = 3;
+    const ErrorFrameworkVersionNotRegistered: u64 = 4;
+
+    struct FrameworkVersions has key {
+        active_version: u64,
+        /// The framework version -> the address of the framework
+        addresses: SimpleMap<u64, address>,
+    }
+
+    struct FrameworkVersionRegisteredEvent has drop, store {
+        version: u64,
+        framework_address: address,
+    }
+
+    struct FrameworkVersionActivatedEvent has drop, store {
+        version: u64,
+        framework_address: address,
+    }
+
+    /// Register the framework published at `framework_address` as `version`, it is not activated.
+    public(friend) fun register_version(ctx: &mut Context, version: u64, framework_address: address) {
+        assert!(core_addresses::is_framework_version_address(framework_address), ErrorNotFrameworkVersionAddress);
+        let versions = borrow_mut_or_create(ctx);
+        assert!(version != 0 && !simple_map::contains_key(&versions.addresses, &version), ErrorFrameworkVersionAlreadyRegistered);
+        let registered = simple_map::values(&versions.addresses);
+        assert!(!vector::contains(&registered, &framework_address), ErrorFrameworkVersionAddressInUse);
+        simple_map::add(&mut versions.addresses, version, framework_address);
+        event::emit(FrameworkVersionRegisteredEvent { version, framework_address });
+    }
+
+    /// Route the system functions to the framework of `version`, the version 0 rolls back to `@rooch_framework`.
+    public(friend) fun activate_version(ctx: &mut Context, version: u64) {
+        let versions = borrow_mut_or_create(ctx);
+        assert!(version == 0 || simple_map::contains_key(&versions.addresses, &version), ErrorFrameworkVersionNotRegistered);
+        versions.active_version = version;
+        event::emit(FrameworkVersionActivatedEvent { version, framework_address: active_framework_address(ctx) });
+    }
+
+    public fun active_version(ctx: &Context): u64 {
+        let object_id = object::named_object_id<FrameworkVersions>();
+        if (!context::exists_object<FrameworkVersions>(ctx, object_id)) {
+            return 0
+        };
+        let obj = context::borrow_object<FrameworkVersions>(ctx, object_id);
+        object::borrow(obj).active_version
+    }
+
+    /// Return the address of the framework of `version`
+    public fun framework_address(ctx: &Context, version: u64): address {
+        if (version == 0) {
+            return @rooch_framework
+        };
+        let object_id = object::named_object_id<FrameworkVersions>();
+        assert!(context::exists_object<FrameworkVersions>(ctx, object_id), ErrorFrameworkVersionNotRegistered);
+        let obj = context::borrow_object<FrameworkVersions>(ctx, object_id);
+        let addresses = &object::borrow(obj).addresses;
+        assert!(simple_map::contains_key(addresses, &version), ErrorFrameworkVersionNotRegistered);
+        *simple_map::borrow(addresses, &version)
+    }
+
+    /// Return the address of the active framework, the executor calls the system functions at this address.
+    public fun active_framework_address(ctx: &Context): address {
+        framework_address(ctx, active_version(ctx))
+    }
+
+    fun borrow_mut_or_create(ctx: &mut Context): &mut FrameworkVersions {
+        let object_id = object::named_object_id<FrameworkVersions>();
+        if (!context::exists_object<FrameworkVersions>(ctx, object_id)) {
+            let obj = context::new_named_object(ctx, FrameworkVersions {
+                active_version: 0,
+                addresses: simple_map::new(),
+            });
+            object::transfer_extend(obj, @rooch_framework);
+        };
+        let obj = context::borrow_mut_object_extend<FrameworkVersions>(ctx, object_id);
+        object::borrow_mut(obj)
+    }
+
+    #[test_only]
+    public fun register_version_for_test(ctx: &mut Context, version: u64, framework_address: address) {
+        register_version(ctx, version, framework_address);
+    }
+
+    #[test_only]
+    public fun activate_version_for_test(ctx: &mut Context, version: u64) {
+        activate_version(ctx, version);
+    }
+}
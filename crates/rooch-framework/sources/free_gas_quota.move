@@ -0,0 +1,146 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The free gas quota of the accounts on the local, dev and test chains.
+/// Every account can send its first `tx_quota` transactions without the gas coin, so the new accounts
+/// can try the chain without funding. A transaction is free only if its max gas amount does not exceed
+/// the `max_gas_amount` of the quota, and it consumes the quota instead of paying the gas.
+module rooch_framework::free_gas_quota {
+
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::table::{Self, Table};
+    use rooch_framework::capability::UpgradeCap;
+    use rooch_framework::chain_id;
+
+    friend rooch_framework::genesis;
+    friend rooch_framework::transaction_validator;
+
+    /// The free gas quota is not allowed on the main chain
+    const ErrorFreeGasQuotaOnMainChain: u64 = 1;
+
+    /// The free transactions of an account on the local, dev and test chains
+    const DEFAULT_TX_QUOTA: u64 = 10;
+    /// The max gas amount of a free transaction, 1% of the default max gas amount of a transaction.
+    /// It covers the common function calls, the heavy transactions such as the module publishing pay the gas.
+    const DEFAULT_MAX_GAS_AMOUNT: u64 = 10000000;
+
+    struct FreeGasQuotaConfig has store, copy, drop {
+        /// The free transactions of an account, 0 disables the free gas quota
+        tx_quota: u64,
+        /// The max gas amount of a free transaction
+        max_gas_amount: u64,
+    }
+
+    struct FreeGasQuota has key {
+        config: FreeGasQuotaConfig,
+        /// The free transactions used by the accounts
+        used: Table<address, u64>,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        init_quota(ctx);
+    }
+
+    /// Create the free gas quota with the default config, the quota is disabled on the main chain
+    fun init_quota(ctx: &mut Context) {
+        let tx_quota = if (chain_id::is_main(ctx)) { 0 } else { DEFAULT_TX_QUOTA };
+        let used = context::new_table<address, u64>(ctx);
+        let obj = context::new_named_object(ctx, FreeGasQuota{
+            config: FreeGasQuotaConfig{
+                tx_quota,
+                max_gas_amount: DEFAULT_MAX_GAS_AMOUNT,
+            },
+            used,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    /// Create the free gas quota on the chain upgraded from a framework without it, only the owner of the UpgradeCap
+    /// can call this function. It does nothing if the free gas quota exists.
+    public entry fun init_after_upgrade(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>) {
+        if (!exists_quota(ctx)) {
+            init_quota(ctx);
+        };
+    }
+
+    fun exists_quota(ctx: &Context): bool {
+        context::exists_object<FreeGasQuota>(ctx, object::named_object_id<FreeGasQuota>())
+    }
+
+    fun borrow(ctx: &Context): &FreeGasQuota {
+        let object_id = object::named_object_id<FreeGasQuota>();
+        object::borrow(context::borrow_object<FreeGasQuota>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut FreeGasQuota {
+        let object_id = object::named_object_id<FreeGasQuota>();
+        object::borrow_mut(context::borrow_mut_object_extend<FreeGasQuota>(ctx, object_id))
+    }
+
+    public fun config(ctx: &Context): FreeGasQuotaConfig {
+        borrow(ctx).config
+    }
+
+    public fun config_tx_quota(self: &FreeGasQuotaConfig): u64 {
+        self.tx_quota
+    }
+
+    public fun config_max_gas_amount(self: &FreeGasQuotaConfig): u64 {
+        self.max_gas_amount
+    }
+
+    /// The free transactions the `account` can still send
+    public fun remaining_quota(ctx: &Context, account: address): u64 {
+        // The free gas quota does not exist before the framework is upgraded to include it
+        if (!exists_quota(ctx)) {
+            return 0
+        };
+        let quota = borrow(ctx);
+        let used = *table::borrow_with_default(&quota.used, account, &0);
+        if (used >= quota.config.tx_quota) {
+            0
+        } else {
+            quota.config.tx_quota - used
+        }
+    }
+
+    /// Whether the current transaction is free for the sender
+    public fun is_free_tx(ctx: &Context): bool {
+        remaining_quota(ctx, context::sender(ctx)) > 0
+            && context::max_gas_amount(ctx) <= borrow(ctx).config.max_gas_amount
+    }
+
+    /// The free transaction of the `account` consumes a quota
+    public(friend) fun consume(ctx: &mut Context, account: address) {
+        let quota = borrow_mut(ctx);
+        let used = table::borrow_mut_with_default(&mut quota.used, account, 0);
+        *used = *used + 1;
+    }
+
+    /// Update the free gas quota, only the owner of the UpgradeCap can call this function, the free gas quota is created
+    /// if the chain is upgraded from a framework without it. The used quota of the accounts is kept, the accounts can send more free transactions if the quota grows.
+    public entry fun update_config(
+        ctx: &mut Context,
+        _upgrade_cap: &mut Object<UpgradeCap>,
+        tx_quota: u64,
+        max_gas_amount: u64,
+    ) {
+        assert!(tx_quota == 0 || !chain_id::is_main(ctx), ErrorFreeGasQuotaOnMainChain);
+        if (!exists_quota(ctx)) {
+            init_quota(ctx);
+        };
+        borrow_mut(ctx).config = FreeGasQuotaConfig{
+            tx_quota,
+            max_gas_amount,
+        };
+    }
+
+    #[test_only]
+    /// Remove the free gas quota, as the chain before the framework upgrade
+    public fun remove_for_test(ctx: &mut Context) {
+        let (_, obj) = context::take_object_extend<FreeGasQuota>(ctx, object::named_object_id<FreeGasQuota>());
+        let FreeGasQuota{ config: _, used } = object::remove(obj);
+        table::drop(used);
+    }
+}
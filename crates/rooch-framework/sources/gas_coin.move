@@ -9,9 +9,11 @@ module rooch_framework::gas_coin {
     use moveos_std::object::{Self, Object};
     use rooch_framework::coin::{Self, Coin, CoinInfo};
     use rooch_framework::account_coin_store;
+    use rooch_framework::capability::TreasuryCap;
 
     friend rooch_framework::genesis;
     friend rooch_framework::transaction_validator;
+    friend rooch_framework::transaction_fee;
 
     //TODO should we allow user to transfer gas coin?
     //If not, we can remove `store` ability from GasCoin.
@@ -47,6 +49,18 @@ module rooch_framework::gas_coin {
         account_coin_store::withdraw_extend<GasCoin>(ctx, addr, amount)
     }
 
+    /// Deduct at most `amount` gas coin from the given account, the deducted amount is capped by the balance.
+    /// It never aborts, an account without an available gas coin store pays nothing.
+    public(friend) fun deduct_gas_at_most(ctx: &mut Context, addr: address, amount: u256): Coin<GasCoin> {
+        if (!account_coin_store::exist_account_coin_store<GasCoin>(ctx, addr)
+            || account_coin_store::is_account_coin_store_frozen<GasCoin>(ctx, addr)) {
+            return coin::zero<GasCoin>()
+        };
+        let balance = account_coin_store::balance<GasCoin>(ctx, addr);
+        let amount = if (amount > balance) { balance } else { amount };
+        account_coin_store::withdraw_extend<GasCoin>(ctx, addr, amount)
+    }
+
     /// Mint gas coin to the given account.
     public(friend) fun faucet(ctx: &mut Context, addr: address, amount: u256) {
         let coin = mint(ctx, amount);
@@ -66,6 +80,11 @@ module rooch_framework::gas_coin {
         faucet(ctx, addr, amount);
     }
 
+    /// Mint gas coin to the given account, only the owner of the TreasuryCap can call this function.
+    public entry fun mint_entry(ctx: &mut Context, _treasury_cap: &mut Object<TreasuryCap>, to: address, amount: u256) {
+        faucet(ctx, to, amount);
+    }
+
     /// Can only be called during genesis to initialize the Rooch coin.
     public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer){
         let coin_info_obj = coin::register_extend<GasCoin>(
@@ -0,0 +1,207 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The versioned on-chain gas schedule. The executor meters the transactions with the schedule of the active version,
+/// the version 0 is the builtin gas schedule of the node, its entries are the cost table entries of the genesis config.
+/// The entries of a schedule override the initial gas parameters of the natives and the genesis cost table,
+/// the keys are the same as the on-chain gas schedule keys, such as `move_stdlib.bcs.to_bytes.per_byte_serialized`
+/// and `instruction_tiers.3000`.
+/// A new schedule is proposed by the owner of the UpgradeCap with an upgrade epoch, the time in milliseconds
+/// when it is activated, so all the nodes switch to it at the same point of the chain.
+/// The executor and the reader executors check the active version after every transaction and switch the cost table
+/// and the natives together. A schedule whose entries can not be loaded is skipped, the node keeps metering with the
+/// previous version and logs an error, so a bad proposal is visible but does not stop the chain.
+module rooch_framework::gas_schedule {
+
+    use std::option::{Self, Option};
+    use std::string::String;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::event;
+    use rooch_framework::capability::UpgradeCap;
+    use rooch_framework::timestamp;
+
+    friend rooch_framework::genesis;
+
+    const ErrorInvalidScheduleVersion: u64 = 1;
+    const ErrorEntriesLengthMismatch: u64 = 2;
+    const ErrorInvalidActivationTime: u64 = 3;
+    const ErrorNoPendingSchedule: u64 = 4;
+
+    struct GasEntry has store, copy, drop {
+        key: String,
+        val: u64,
+    }
+
+    struct GasScheduleConfig has store, copy, drop {
+        schedule_version: u64,
+        entries: vector<GasEntry>,
+    }
+
+    /// The gas schedule waiting for its upgrade epoch
+    struct PendingGasSchedule has store, copy, drop {
+        config: GasScheduleConfig,
+        /// The time in milliseconds when the schedule is activated
+        activation_time: u64,
+    }
+
+    struct GasSchedule has key {
+        current: GasScheduleConfig,
+        pending: Option<PendingGasSchedule>,
+        /// The entries of the builtin gas schedule, the later schedules override the cost table built from them
+        genesis_entries: vector<GasEntry>,
+    }
+
+    struct GasScheduleProposedEvent has drop {
+        schedule_version: u64,
+        activation_time: u64,
+    }
+
+    struct GasScheduleCancelledEvent has drop {
+        schedule_version: u64,
+    }
+
+    /// The builtin gas schedule carries the cost table `entries` of the genesis config
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer, entries: vector<GasEntry>) {
+        let obj = context::new_named_object(ctx, GasSchedule{
+            current: GasScheduleConfig{
+                schedule_version: 0,
+                entries,
+            },
+            pending: option::none(),
+            genesis_entries: entries,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow(ctx: &Context): &GasSchedule {
+        let object_id = object::named_object_id<GasSchedule>();
+        object::borrow(context::borrow_object<GasSchedule>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut GasSchedule {
+        let object_id = object::named_object_id<GasSchedule>();
+        object::borrow_mut(context::borrow_mut_object_extend<GasSchedule>(ctx, object_id))
+    }
+
+    fun is_activated(ctx: &Context, pending: &PendingGasSchedule): bool {
+        timestamp::now_milliseconds(ctx) >= pending.activation_time
+    }
+
+    /// The active gas schedule, the pending schedule is active once its upgrade epoch is reached
+    public fun current_gas_schedule(ctx: &Context): GasScheduleConfig {
+        let gas_schedule = borrow(ctx);
+        if (option::is_some(&gas_schedule.pending)) {
+            let pending = option::borrow(&gas_schedule.pending);
+            if (is_activated(ctx, pending)) {
+                return pending.config
+            };
+        };
+        gas_schedule.current
+    }
+
+    /// The builtin gas schedule of the version 0, its entries are the cost table entries of the genesis config
+    public fun genesis_gas_schedule(ctx: &Context): GasScheduleConfig {
+        GasScheduleConfig{
+            schedule_version: 0,
+            entries: borrow(ctx).genesis_entries,
+        }
+    }
+
+    public fun schedule_version(ctx: &Context): u64 {
+        current_gas_schedule(ctx).schedule_version
+    }
+
+    /// The pending gas schedule which is not activated yet
+    public fun pending_gas_schedule(ctx: &Context): Option<PendingGasSchedule> {
+        let pending = borrow(ctx).pending;
+        if (option::is_some(&pending) && is_activated(ctx, option::borrow(&pending))) {
+            return option::none()
+        };
+        pending
+    }
+
+    public fun config_schedule_version(self: &GasScheduleConfig): u64 {
+        self.schedule_version
+    }
+
+    public fun config_entries(self: &GasScheduleConfig): &vector<GasEntry> {
+        &self.entries
+    }
+
+    public fun entry_key(self: &GasEntry): &String {
+        &self.key
+    }
+
+    public fun entry_val(self: &GasEntry): u64 {
+        self.val
+    }
+
+    public fun pending_config(self: &PendingGasSchedule): &GasScheduleConfig {
+        &self.config
+    }
+
+    public fun pending_activation_time(self: &PendingGasSchedule): u64 {
+        self.activation_time
+    }
+
+    /// Move the activated pending schedule to the current
+    fun settle_activated(ctx: &mut Context) {
+        let pending = borrow(ctx).pending;
+        if (option::is_some(&pending) && is_activated(ctx, option::borrow(&pending))) {
+            let gas_schedule = borrow_mut(ctx);
+            gas_schedule.current = option::destroy_some(pending).config;
+            gas_schedule.pending = option::none();
+        };
+    }
+
+    /// Propose the gas schedule of a new version, it is activated at the `activation_time` in milliseconds.
+    /// The proposal replaces the pending schedule which is not activated yet.
+    public entry fun propose_gas_schedule(
+        ctx: &mut Context,
+        _upgrade_cap: &mut Object<UpgradeCap>,
+        schedule_version: u64,
+        keys: vector<String>,
+        vals: vector<u64>,
+        activation_time: u64,
+    ) {
+        settle_activated(ctx);
+        assert!(schedule_version > borrow(ctx).current.schedule_version, ErrorInvalidScheduleVersion);
+        assert!(vector::length(&keys) == vector::length(&vals), ErrorEntriesLengthMismatch);
+        assert!(activation_time >= timestamp::now_milliseconds(ctx), ErrorInvalidActivationTime);
+
+        let entries = vector[];
+        let i = 0;
+        let len = vector::length(&keys);
+        while (i < len) {
+            vector::push_back(&mut entries, GasEntry{
+                key: *vector::borrow(&keys, i),
+                val: *vector::borrow(&vals, i),
+            });
+            i = i + 1;
+        };
+        borrow_mut(ctx).pending = option::some(PendingGasSchedule{
+            config: GasScheduleConfig{
+                schedule_version,
+                entries,
+            },
+            activation_time,
+        });
+        event::emit(GasScheduleProposedEvent{
+            schedule_version,
+            activation_time,
+        });
+    }
+
+    /// Cancel the pending gas schedule before its upgrade epoch
+    public entry fun cancel_gas_schedule(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>) {
+        settle_activated(ctx);
+        let pending = borrow(ctx).pending;
+        assert!(option::is_some(&pending), ErrorNoPendingSchedule);
+        borrow_mut(ctx).pending = option::none();
+        event::emit(GasScheduleCancelledEvent{
+            schedule_version: option::destroy_some(pending).config.schedule_version,
+        });
+    }
+}
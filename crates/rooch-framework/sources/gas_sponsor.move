@@ -0,0 +1,136 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The gas sponsorship of the transactions.
+/// A sponsor grants gas allowances to the accounts it sponsors, a transaction designating the sponsor
+/// is co-signed by the sponsor, and its gas is paid by the sponsor instead of the sender.
+/// It allows the users with zero balance to send transactions.
+module rooch_framework::gas_sponsor {
+    use std::option::{Self, Option};
+    use std::signer;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::event;
+    use moveos_std::table::{Self, Table};
+    use rooch_framework::gas_coin;
+    use rooch_framework::native_validator;
+    use rooch_framework::ethereum_validator;
+    use rooch_framework::bitcoin_validator;
+
+    friend rooch_framework::transaction_validator;
+
+    /// The sponsor does not grant an allowance to the sender
+    const ErrorNoGasAllowance: u64 = 1;
+    /// The allowance of the sender is not enough to pay the gas of the transaction
+    const ErrorGasAllowanceNotEnough: u64 = 2;
+    /// The balance of the sponsor is not enough to pay the gas of the transaction
+    const ErrorSponsorCantPayGas: u64 = 3;
+    /// The sponsor's authenticator is not of a builtin auth validator
+    const ErrorUnsupportedSponsorAuthValidator: u64 = 4;
+
+    /// The sponsor designated by the transaction, the transaction adds it to the tx context.
+    /// The authenticator payload is the sponsor's signature of the transaction hash, validated by the auth validator of the id.
+    struct TxSponsor has copy, store, drop {
+        sponsor: address,
+        auth_validator_id: u64,
+        authenticator_payload: vector<u8>,
+    }
+
+    /// The gas allowances granted by a sponsor, keyed by the sponsored account
+    struct GasAllowances has key {
+        allowances: Table<address, u256>,
+    }
+
+    /// Emitted when the gas of a transaction is paid by the sponsor
+    struct GasSponsoredEvent has drop, store, copy {
+        sponsor: address,
+        account: address,
+        gas: u256,
+    }
+
+    /// Grant the `account` an allowance to spend at most `amount` gas coin of the sponsor,
+    /// it replaces the previous allowance of the account.
+    public entry fun grant_allowance(ctx: &mut Context, sponsor: &signer, account: address, amount: u256) {
+        let sponsor_addr = signer::address_of(sponsor);
+        if (!context::exists_resource<GasAllowances>(ctx, sponsor_addr)) {
+            let allowances = context::new_table<address, u256>(ctx);
+            context::move_resource_to<GasAllowances>(ctx, sponsor, GasAllowances { allowances });
+        };
+        let gas_allowances = context::borrow_mut_resource<GasAllowances>(ctx, sponsor_addr);
+        table::upsert(&mut gas_allowances.allowances, account, amount);
+    }
+
+    public entry fun revoke_allowance(ctx: &mut Context, sponsor: &signer, account: address) {
+        let sponsor_addr = signer::address_of(sponsor);
+        if (!context::exists_resource<GasAllowances>(ctx, sponsor_addr)) {
+            return
+        };
+        let gas_allowances = context::borrow_mut_resource<GasAllowances>(ctx, sponsor_addr);
+        if (table::contains(&gas_allowances.allowances, account)) {
+            table::remove(&mut gas_allowances.allowances, account);
+        };
+    }
+
+    /// The gas coin the `sponsor` allows the `account` to spend, 0 if there is no allowance
+    public fun allowance(ctx: &Context, sponsor: address, account: address): u256 {
+        if (has_allowance(ctx, sponsor, account)) {
+            *table::borrow(&context::borrow_resource<GasAllowances>(ctx, sponsor).allowances, account)
+        } else {
+            0
+        }
+    }
+
+    /// The sponsor of the current transaction
+    public fun tx_sponsor(ctx: &Context): Option<address> {
+        let tx_sponsor = context::get<TxSponsor>(ctx);
+        if (option::is_some(&tx_sponsor)) {
+            option::some(option::extract(&mut tx_sponsor).sponsor)
+        } else {
+            option::none()
+        }
+    }
+
+    /// Validate the sponsor of the transaction can pay the `gas`, it verifies the sponsor's signature,
+    /// the allowance of the sender and the balance of the sponsor.
+    public(friend) fun validate(ctx: &Context, gas: u256) {
+        let tx_sponsor = context::get<TxSponsor>(ctx);
+        assert!(option::is_some(&tx_sponsor), ErrorNoGasAllowance);
+        let TxSponsor { sponsor, auth_validator_id, authenticator_payload } = option::extract(&mut tx_sponsor);
+        validate_sponsor_authenticator(ctx, sponsor, auth_validator_id, authenticator_payload);
+        let sender = context::sender(ctx);
+        assert!(has_allowance(ctx, sponsor, sender), ErrorNoGasAllowance);
+        assert!(allowance(ctx, sponsor, sender) >= gas, ErrorGasAllowanceNotEnough);
+        assert!(gas_coin::balance(ctx, sponsor) >= gas, ErrorSponsorCantPayGas);
+    }
+
+    /// The sponsor signs the transaction by any of the builtin auth validators
+    fun validate_sponsor_authenticator(ctx: &Context, sponsor: address, auth_validator_id: u64, authenticator_payload: vector<u8>) {
+        if (auth_validator_id == native_validator::auth_validator_id()) {
+            native_validator::validate_account(ctx, sponsor, authenticator_payload);
+        } else if (auth_validator_id == ethereum_validator::auth_validator_id()) {
+            ethereum_validator::validate_account(ctx, sponsor, authenticator_payload);
+        } else if (auth_validator_id == bitcoin_validator::auth_validator_id()) {
+            bitcoin_validator::validate_account(ctx, sponsor, authenticator_payload);
+        } else {
+            abort ErrorUnsupportedSponsorAuthValidator
+        }
+    }
+
+    fun has_allowance(ctx: &Context, sponsor: address, account: address): bool {
+        context::exists_resource<GasAllowances>(ctx, sponsor)
+            && table::contains(&context::borrow_resource<GasAllowances>(ctx, sponsor).allowances, account)
+    }
+
+    /// The sponsored transaction of the sender spends the `gas` paid by the sponsor,
+    /// it is called in the system post_execute, so it never aborts.
+    public(friend) fun consume_allowance(ctx: &mut Context, sponsor: address, gas: u256) {
+        let sender = context::sender(ctx);
+        if (!has_allowance(ctx, sponsor, sender)) {
+            return
+        };
+        let gas_allowances = context::borrow_mut_resource<GasAllowances>(ctx, sponsor);
+        let allowance = table::borrow_mut(&mut gas_allowances.allowances, sender);
+        *allowance = if (*allowance > gas) { *allowance - gas } else { 0 };
+        event::emit(GasSponsoredEvent { sponsor, account: sender, gas });
+    }
+
+}
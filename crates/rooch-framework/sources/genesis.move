@@ -17,6 +17,15 @@ module rooch_framework::genesis {
     use rooch_framework::address_mapping;
     use rooch_framework::ethereum_light_client;
     use rooch_framework::onchain_config;
+    use rooch_framework::name_service;
+    use rooch_framework::capability;
+    use rooch_framework::storage_rent;
+    use rooch_framework::sequencer_key_registry;
+    use rooch_framework::chain_pause;
+    use rooch_framework::module_publish_policy;
+    use rooch_framework::gas_schedule::{Self, GasEntry};
+    use rooch_framework::treasury;
+    use rooch_framework::free_gas_quota;
 
     const ErrorGenesisInit: u64 = 1;
 
@@ -27,6 +36,14 @@ module rooch_framework::genesis {
         timestamp: u64,
         /// Sequencer account
         sequencer: address,
+        /// The owner of the UpgradeCap
+        upgrade_cap_owner: address,
+        /// The owner of the TreasuryCap
+        treasury_cap_owner: address,
+        /// The owner of the framework packages PublisherCap
+        publisher_cap_owner: address,
+        /// The cost table entries of the genesis gas schedule, such as the instruction tiers
+        gas_schedule_entries: vector<GasEntry>,
     }
 
     fun init(ctx: &mut Context){
@@ -46,6 +63,15 @@ module rooch_framework::genesis {
         address_mapping::genesis_init(ctx, genesis_account);
         ethereum_light_client::genesis_init(ctx, genesis_account);
         onchain_config::genesis_init(ctx, genesis_account, genesis_context.sequencer);
+        name_service::genesis_init(ctx, genesis_account);
+        capability::genesis_init(ctx, genesis_account, genesis_context.upgrade_cap_owner, genesis_context.treasury_cap_owner, genesis_context.publisher_cap_owner);
+        storage_rent::genesis_init(ctx, genesis_account);
+        sequencer_key_registry::genesis_init(ctx, genesis_account);
+        chain_pause::genesis_init(ctx, genesis_account);
+        module_publish_policy::genesis_init(ctx, genesis_account);
+        gas_schedule::genesis_init(ctx, genesis_account, genesis_context.gas_schedule_entries);
+        treasury::genesis_init(ctx, genesis_account);
+        free_gas_quota::genesis_init(ctx, genesis_account);
     }
 
 
@@ -53,7 +79,7 @@ module rooch_framework::genesis {
     /// init the genesis context for test, and return the Context with @rooch_framework genesis account
     public fun init_for_test(): Context{
         let ctx = moveos_std::context::new_test_context(@rooch_framework);
-        context::add(&mut ctx, GenesisContext{chain_id: 20230103, timestamp: 0, sequencer: @rooch_framework});
+        context::add(&mut ctx, GenesisContext{chain_id: 20230103, timestamp: 0, sequencer: @rooch_framework, upgrade_cap_owner: @rooch_framework, treasury_cap_owner: @rooch_framework, publisher_cap_owner: @rooch_framework, gas_schedule_entries: vector[]});
         init(&mut ctx);
         ctx
     }
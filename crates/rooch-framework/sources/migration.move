@@ -0,0 +1,83 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The on-chain record of the state migrations.
+/// When a framework upgrade changes the struct layouts, the sequencer runs the registered migrations
+/// over the affected accounts and objects in batches after the upgrade, and completes the migration on-chain.
+/// A Move migration function should be an entry function with the signature
+/// `fun migrate(ctx: &mut Context, account: &signer, object_ids: vector<ObjectID>)`,
+/// and call `assert_migrator` to ensure it is only called by the migration.
+module rooch_framework::migration {
+
+    use std::string::String;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+    use moveos_std::event;
+    use moveos_std::signer;
+    use moveos_std::simple_map::{Self, SimpleMap};
+    use rooch_framework::onchain_config;
+    use rooch_framework::timestamp;
+
+    const ErrorNotMigrator: u64 = 1;
+    const ErrorMigrationCompleted: u64 = 2;
+
+    struct MigrationRecord has store, copy, drop {
+        /// The framework version when the migration is completed
+        framework_version: u64,
+        /// The number of the migrated states
+        migrated: u64,
+        /// The time in milliseconds when the migration is completed
+        completed_at: u64,
+    }
+
+    struct Migrations has key {
+        records: SimpleMap<String, MigrationRecord>,
+    }
+
+    struct MigrationCompletedEvent has drop {
+        name: String,
+        framework_version: u64,
+        migrated: u64,
+    }
+
+    /// Only the sequencer can run the migrations
+    public fun assert_migrator(ctx: &Context, account: &signer) {
+        assert!(signer::address_of(account) == onchain_config::sequencer(ctx), ErrorNotMigrator);
+    }
+
+    public fun is_completed(ctx: &Context, name: String): bool {
+        let object_id = object::named_object_id<Migrations>();
+        if (!context::exists_object<Migrations>(ctx, object_id)) {
+            return false
+        };
+        let migrations = object::borrow(context::borrow_object<Migrations>(ctx, object_id));
+        simple_map::contains_key(&migrations.records, &name)
+    }
+
+    /// Record the migration is completed, a migration can only be completed once.
+    public entry fun complete_migration(ctx: &mut Context, account: &signer, name: String, migrated: u64) {
+        assert_migrator(ctx, account);
+        assert!(!is_completed(ctx, name), ErrorMigrationCompleted);
+
+        let object_id = object::named_object_id<Migrations>();
+        if (!context::exists_object<Migrations>(ctx, object_id)) {
+            let migrations_obj = context::new_named_object(ctx, Migrations {
+                records: simple_map::create(),
+            });
+            object::transfer_extend(migrations_obj, @rooch_framework);
+        };
+        let framework_version = onchain_config::framework_version(ctx);
+        let completed_at = timestamp::now_milliseconds(ctx);
+        let migrations = object::borrow_mut(context::borrow_mut_object_extend<Migrations>(ctx, object_id));
+        simple_map::add(&mut migrations.records, name, MigrationRecord {
+            framework_version,
+            migrated,
+            completed_at,
+        });
+        event::emit(MigrationCompletedEvent {
+            name,
+            framework_version,
+            migrated,
+        });
+    }
+}
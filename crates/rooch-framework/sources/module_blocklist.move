@@ -0,0 +1,241 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The blocklist of the modules and the functions, for the emergency response.
+/// When an exploit of a module is live, the owner of the UpgradeCap blocks the module or some of its functions,
+/// and the transactions calling them are rejected, before the module is fixed by an upgrade.
+/// The `transaction_validator` checks the entry function called by the transaction, and the executor checks all the
+/// functions reachable from the entry function or the script by `is_any_function_blocked`, so the calls through
+/// the scripts and the wrapper modules are rejected as well. The framework modules can not be blocked.
+module rooch_framework::module_blocklist {
+
+    use std::ascii::{Self, String};
+    use std::option;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::table::{Self, Table};
+    use moveos_std::tx_meta::{Self, TxMeta};
+    use moveos_std::event;
+    use rooch_framework::capability::UpgradeCap;
+    use rooch_framework::core_addresses;
+
+    const ErrorModuleAlreadyBlocked: u64 = 1;
+    const ErrorModuleNotBlocked: u64 = 2;
+    const ErrorFunctionAlreadyBlocked: u64 = 3;
+    const ErrorFunctionNotBlocked: u64 = 4;
+    /// The framework modules can not be blocked, otherwise the transactions can not be validated,
+    /// and the blocklist itself can not be unblocked
+    const ErrorCannotBlockFrameworkModule: u64 = 5;
+    const ErrorFunctionsLengthMismatch: u64 = 6;
+
+    struct BlockedModule has copy, drop, store {
+        module_address: address,
+        module_name: String,
+    }
+
+    struct BlockedFunction has copy, drop, store {
+        module_address: address,
+        module_name: String,
+        function_name: String,
+    }
+
+    /// The blocklist is created when a module or a function is blocked for the first time
+    struct ModuleBlocklist has key {
+        /// The blocked modules and the reasons
+        modules: Table<BlockedModule, vector<u8>>,
+        /// The blocked functions and the reasons
+        functions: Table<BlockedFunction, vector<u8>>,
+    }
+
+    struct ModuleBlockedEvent has drop {
+        module_address: address,
+        module_name: String,
+        reason: vector<u8>,
+    }
+
+    struct ModuleUnblockedEvent has drop {
+        module_address: address,
+        module_name: String,
+    }
+
+    struct FunctionBlockedEvent has drop {
+        module_address: address,
+        module_name: String,
+        function_name: String,
+        reason: vector<u8>,
+    }
+
+    struct FunctionUnblockedEvent has drop {
+        module_address: address,
+        module_name: String,
+        function_name: String,
+    }
+
+    fun exists_blocklist(ctx: &Context): bool {
+        context::exists_object<ModuleBlocklist>(ctx, object::named_object_id<ModuleBlocklist>())
+    }
+
+    fun borrow(ctx: &Context): &ModuleBlocklist {
+        let object_id = object::named_object_id<ModuleBlocklist>();
+        object::borrow(context::borrow_object<ModuleBlocklist>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut ModuleBlocklist {
+        let object_id = object::named_object_id<ModuleBlocklist>();
+        object::borrow_mut(context::borrow_mut_object_extend<ModuleBlocklist>(ctx, object_id))
+    }
+
+    fun borrow_mut_or_create(ctx: &mut Context): &mut ModuleBlocklist {
+        let object_id = object::named_object_id<ModuleBlocklist>();
+        if (!context::exists_object<ModuleBlocklist>(ctx, object_id)) {
+            let modules = context::new_table<BlockedModule, vector<u8>>(ctx);
+            let functions = context::new_table<BlockedFunction, vector<u8>>(ctx);
+            let obj = context::new_named_object(ctx, ModuleBlocklist {
+                modules,
+                functions,
+            });
+            object::transfer_extend(obj, @rooch_framework);
+        };
+        object::borrow_mut(context::borrow_mut_object_extend<ModuleBlocklist>(ctx, object_id))
+    }
+
+    public fun is_module_blocked(ctx: &Context, module_address: address, module_name: String): bool {
+        if (!exists_blocklist(ctx)) {
+            return false
+        };
+        table::contains(&borrow(ctx).modules, BlockedModule { module_address, module_name })
+    }
+
+    /// Check the function is blocked, by itself or by its module
+    public fun is_function_blocked(ctx: &Context, module_address: address, module_name: String, function_name: String): bool {
+        if (!exists_blocklist(ctx)) {
+            return false
+        };
+        let blocklist = borrow(ctx);
+        table::contains(&blocklist.modules, BlockedModule { module_address, module_name })
+            || table::contains(&blocklist.functions, BlockedFunction { module_address, module_name, function_name })
+    }
+
+    /// Return true if no module or function is blocked, the executor skips checking the reachable functions
+    public fun is_empty(ctx: &Context): bool {
+        if (!exists_blocklist(ctx)) {
+            return true
+        };
+        let blocklist = borrow(ctx);
+        table::length(&blocklist.modules) == 0 && table::length(&blocklist.functions) == 0
+    }
+
+    /// Check any of the functions is blocked, the function `i` is `module_addresses[i]::module_names[i]::function_names[i]`.
+    /// The executor calls it with all the functions reachable from the entry function or the script of the transaction.
+    public fun is_any_function_blocked(
+        ctx: &Context,
+        module_addresses: vector<address>,
+        module_names: vector<vector<u8>>,
+        function_names: vector<vector<u8>>,
+    ): bool {
+        let len = vector::length(&module_addresses);
+        assert!(
+            vector::length(&module_names) == len && vector::length(&function_names) == len,
+            ErrorFunctionsLengthMismatch
+        );
+        if (is_empty(ctx)) {
+            return false
+        };
+        let i = 0;
+        while (i < len) {
+            if (is_function_blocked(
+                ctx,
+                *vector::borrow(&module_addresses, i),
+                ascii::string(*vector::borrow(&module_names, i)),
+                ascii::string(*vector::borrow(&function_names, i)),
+            )) {
+                return true
+            };
+            i = i + 1;
+        };
+        false
+    }
+
+    /// Check the entry function called by the transaction is blocked
+    public fun is_tx_blocked(ctx: &Context, meta: &TxMeta): bool {
+        let function_meta = tx_meta::function_meta(meta);
+        if (option::is_none(&function_meta)) {
+            return false
+        };
+        let function_meta = option::destroy_some(function_meta);
+        is_function_blocked(
+            ctx,
+            *tx_meta::function_meta_module_address(&function_meta),
+            *tx_meta::function_meta_module_name(&function_meta),
+            *tx_meta::function_meta_function_name(&function_meta),
+        )
+    }
+
+    fun assert_not_framework(module_address: address) {
+        assert!(
+            module_address != @std && !core_addresses::is_framework_reserved_address(module_address),
+            ErrorCannotBlockFrameworkModule
+        );
+    }
+
+    public entry fun block_module(
+        ctx: &mut Context,
+        _upgrade_cap: &mut Object<UpgradeCap>,
+        module_address: address,
+        module_name: String,
+        reason: vector<u8>,
+    ) {
+        assert_not_framework(module_address);
+        let modules = &mut borrow_mut_or_create(ctx).modules;
+        let key = BlockedModule { module_address, module_name };
+        assert!(!table::contains(modules, key), ErrorModuleAlreadyBlocked);
+        table::add(modules, key, reason);
+        event::emit(ModuleBlockedEvent { module_address, module_name, reason });
+    }
+
+    public entry fun unblock_module(
+        ctx: &mut Context,
+        _upgrade_cap: &mut Object<UpgradeCap>,
+        module_address: address,
+        module_name: String,
+    ) {
+        assert!(exists_blocklist(ctx), ErrorModuleNotBlocked);
+        let modules = &mut borrow_mut(ctx).modules;
+        let key = BlockedModule { module_address, module_name };
+        assert!(table::contains(modules, key), ErrorModuleNotBlocked);
+        table::remove(modules, key);
+        event::emit(ModuleUnblockedEvent { module_address, module_name });
+    }
+
+    public entry fun block_function(
+        ctx: &mut Context,
+        _upgrade_cap: &mut Object<UpgradeCap>,
+        module_address: address,
+        module_name: String,
+        function_name: String,
+        reason: vector<u8>,
+    ) {
+        assert_not_framework(module_address);
+        let functions = &mut borrow_mut_or_create(ctx).functions;
+        let key = BlockedFunction { module_address, module_name, function_name };
+        assert!(!table::contains(functions, key), ErrorFunctionAlreadyBlocked);
+        table::add(functions, key, reason);
+        event::emit(FunctionBlockedEvent { module_address, module_name, function_name, reason });
+    }
+
+    public entry fun unblock_function(
+        ctx: &mut Context,
+        _upgrade_cap: &mut Object<UpgradeCap>,
+        module_address: address,
+        module_name: String,
+        function_name: String,
+    ) {
+        assert!(exists_blocklist(ctx), ErrorFunctionNotBlocked);
+        let functions = &mut borrow_mut(ctx).functions;
+        let key = BlockedFunction { module_address, module_name, function_name };
+        assert!(table::contains(functions, key), ErrorFunctionNotBlocked);
+        table::remove(functions, key);
+        event::emit(FunctionUnblockedEvent { module_address, module_name, function_name });
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The module publishing policy, for the permissioned networks.
+/// The policy is open by default, everyone can publish modules except the denied addresses.
+/// If the policy is the allowlist, only the allowed addresses can publish modules.
+/// The policy is controlled by the owner of the UpgradeCap. The `transaction_validator` checks the module bundle
+/// transactions, and adds the publish permission of the sender to the context before every transaction, which is
+/// checked by `context::publish_modules`, so the policy applies to the modules published by any module or script.
+module rooch_framework::module_publish_policy {
+
+    use std::ascii;
+    use std::option;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::table::{Self, Table};
+    use moveos_std::tx_meta::{Self, TxMeta};
+    use moveos_std::event;
+    use rooch_framework::capability::UpgradeCap;
+
+    friend rooch_framework::genesis;
+    friend rooch_framework::transaction_validator;
+
+    const PolicyOpen: u8 = 0;
+    const PolicyAllowlist: u8 = 1;
+
+    const ErrorInvalidPolicy: u64 = 1;
+
+    struct ModulePublishPolicy has key {
+        policy: u8,
+        allowlist: Table<address, bool>,
+        denylist: Table<address, bool>,
+    }
+
+    struct ModulePublishPolicyUpdatedEvent has drop {
+        policy: u8,
+    }
+
+    public fun policy_open(): u8 { PolicyOpen }
+
+    public fun policy_allowlist(): u8 { PolicyAllowlist }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let allowlist = context::new_table<address, bool>(ctx);
+        let denylist = context::new_table<address, bool>(ctx);
+        let obj = context::new_named_object(ctx, ModulePublishPolicy{
+            policy: PolicyOpen,
+            allowlist,
+            denylist,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow(ctx: &Context): &ModulePublishPolicy {
+        let object_id = object::named_object_id<ModulePublishPolicy>();
+        object::borrow(context::borrow_object<ModulePublishPolicy>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut ModulePublishPolicy {
+        let object_id = object::named_object_id<ModulePublishPolicy>();
+        object::borrow_mut(context::borrow_mut_object_extend<ModulePublishPolicy>(ctx, object_id))
+    }
+
+    public fun policy(ctx: &Context): u8 {
+        borrow(ctx).policy
+    }
+
+    public fun is_allowed(ctx: &Context, publisher: address): bool {
+        let policy = borrow(ctx);
+        if (table::contains(&policy.denylist, publisher)) {
+            return false
+        };
+        policy.policy == PolicyOpen || table::contains(&policy.allowlist, publisher)
+    }
+
+    /// Check the transaction publishes modules, via the module bundle action or the `context::publish_modules_entry` function
+    public fun is_module_publish(meta: &TxMeta): bool {
+        if (tx_meta::is_module_publish(meta)) {
+            return true
+        };
+        let function_meta = tx_meta::function_meta(meta);
+        if (option::is_none(&function_meta)) {
+            return false
+        };
+        let function_meta = option::destroy_some(function_meta);
+        *tx_meta::function_meta_module_address(&function_meta) == @moveos_std
+            && *tx_meta::function_meta_module_name(&function_meta) == ascii::string(b"context")
+            && *tx_meta::function_meta_function_name(&function_meta) == ascii::string(b"publish_modules_entry")
+    }
+
+    /// Check the transaction calls the `upgrade` module, the framework upgrade is authorized by the UpgradeCap
+    fun is_framework_upgrade(meta: &TxMeta): bool {
+        let function_meta = tx_meta::function_meta(meta);
+        if (option::is_none(&function_meta)) {
+            return false
+        };
+        let function_meta = option::destroy_some(function_meta);
+        *tx_meta::function_meta_module_address(&function_meta) == @rooch_framework
+            && *tx_meta::function_meta_module_name(&function_meta) == ascii::string(b"upgrade")
+    }
+
+    /// Add the publish permission of the transaction sender to the context, before the transaction is executed
+    public(friend) fun add_tx_permission(ctx: &mut Context) {
+        let sender = context::sender(ctx);
+        let allowed = is_framework_upgrade(&context::tx_meta(ctx)) || is_allowed(ctx, sender);
+        context::add(ctx, context::new_module_publish_permission(allowed));
+    }
+
+    #[test_only]
+    public fun add_tx_permission_for_test(ctx: &mut Context) {
+        add_tx_permission(ctx)
+    }
+
+    public entry fun set_policy(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, policy: u8) {
+        assert!(policy == PolicyOpen || policy == PolicyAllowlist, ErrorInvalidPolicy);
+        borrow_mut(ctx).policy = policy;
+        event::emit(ModulePublishPolicyUpdatedEvent{ policy });
+    }
+
+    public entry fun allow(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, publisher: address) {
+        table::upsert(&mut borrow_mut(ctx).allowlist, publisher, true);
+    }
+
+    public entry fun remove_allowed(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, publisher: address) {
+        let allowlist = &mut borrow_mut(ctx).allowlist;
+        if (table::contains(allowlist, publisher)) {
+            table::remove(allowlist, publisher);
+        };
+    }
+
+    public entry fun deny(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, publisher: address) {
+        table::upsert(&mut borrow_mut(ctx).denylist, publisher, true);
+    }
+
+    public entry fun remove_denied(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, publisher: address) {
+        let denylist = &mut borrow_mut(ctx).denylist;
+        if (table::contains(denylist, publisher)) {
+            table::remove(denylist, publisher);
+        };
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// A simple on-chain name service, maps a human readable name to an address.
+/// The target address can be an account address or the address of an ObjectID.
+module rooch_framework::name_service {
+
+    use std::option::{Self, Option};
+    use std::string::{Self, String};
+    use std::vector;
+    use std::signer;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::table::{Self, Table};
+    use moveos_std::event;
+    use rooch_framework::timestamp;
+
+    friend rooch_framework::genesis;
+
+    const MIN_NAME_LENGTH: u64 = 3;
+    const MAX_NAME_LENGTH: u64 = 63;
+    /// The max registration duration is 10 years
+    const MAX_REGISTRATION_DURATION_SECONDS: u64 = 315360000;
+
+    const ErrorInvalidName: u64 = 1;
+    const ErrorNameAlreadyRegistered: u64 = 2;
+    const ErrorNameNotRegistered: u64 = 3;
+    const ErrorNotNameOwner: u64 = 4;
+    const ErrorNameExpired: u64 = 5;
+    const ErrorInvalidDuration: u64 = 6;
+
+    struct NameRecord has store, drop {
+        owner: address,
+        target: address,
+        /// The expiration time in seconds
+        expiration: u64,
+    }
+
+    struct NameRegistry has key {
+        records: Table<String, NameRecord>,
+    }
+
+    struct NameRegisteredEvent has drop {
+        name: String,
+        owner: address,
+        target: address,
+        expiration: u64,
+    }
+
+    struct NameRenewedEvent has drop {
+        name: String,
+        expiration: u64,
+    }
+
+    struct NameTransferredEvent has drop {
+        name: String,
+        from: address,
+        to: address,
+    }
+
+    struct NameTargetChangedEvent has drop {
+        name: String,
+        target: address,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let records = context::new_table<String, NameRecord>(ctx);
+        let obj = context::new_named_object(ctx, NameRegistry{
+            records,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    /// Borrow the name registry object
+    public fun borrow(ctx: &Context): &Object<NameRegistry> {
+        let object_id = object::named_object_id<NameRegistry>();
+        context::borrow_object<NameRegistry>(ctx, object_id)
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut Object<NameRegistry> {
+        let object_id = object::named_object_id<NameRegistry>();
+        context::borrow_mut_object_extend<NameRegistry>(ctx, object_id)
+    }
+
+    /// A valid name is 3 to 63 characters, starts with a lowercase letter,
+    /// and only contains lowercase letters, digits and `_`.
+    /// So the name can be used as a named address in the Move and CLI.
+    public fun is_valid_name(name: &String): bool {
+        let bytes = string::bytes(name);
+        let len = vector::length(bytes);
+        if (len < MIN_NAME_LENGTH || len > MAX_NAME_LENGTH) {
+            return false
+        };
+        let first = *vector::borrow(bytes, 0);
+        if (!is_lowercase_letter(first)) {
+            return false
+        };
+        let i = 1;
+        while (i < len) {
+            let c = *vector::borrow(bytes, i);
+            if (!(is_lowercase_letter(c) || is_digit(c) || c == 95 /* _ */)) {
+                return false
+            };
+            i = i + 1;
+        };
+        true
+    }
+
+    fun is_lowercase_letter(c: u8): bool {
+        c >= 97 && c <= 122
+    }
+
+    fun is_digit(c: u8): bool {
+        c >= 48 && c <= 57
+    }
+
+    fun is_expired(ctx: &Context, record: &NameRecord): bool {
+        record.expiration <= timestamp::now_seconds(ctx)
+    }
+
+    /// Check the name is registered and not expired
+    public fun is_registered(ctx: &Context, name: String): bool {
+        let registry = object::borrow(borrow(ctx));
+        if (!table::contains(&registry.records, name)) {
+            return false
+        };
+        let record = table::borrow(&registry.records, name);
+        !is_expired(ctx, record)
+    }
+
+    /// Resolve the name to the target address, return none if the name is not registered or expired
+    public fun resolve(ctx: &Context, name: String): Option<address> {
+        let registry = object::borrow(borrow(ctx));
+        if (!table::contains(&registry.records, name)) {
+            return option::none()
+        };
+        let record = table::borrow(&registry.records, name);
+        if (is_expired(ctx, record)) {
+            option::none()
+        } else {
+            option::some(record.target)
+        }
+    }
+
+    /// Return the owner of the name, return none if the name is not registered or expired
+    public fun owner_of(ctx: &Context, name: String): Option<address> {
+        let registry = object::borrow(borrow(ctx));
+        if (!table::contains(&registry.records, name)) {
+            return option::none()
+        };
+        let record = table::borrow(&registry.records, name);
+        if (is_expired(ctx, record)) {
+            option::none()
+        } else {
+            option::some(record.owner)
+        }
+    }
+
+    /// Return the expiration time in seconds of the name, return none if the name is not registered
+    public fun expiration_of(ctx: &Context, name: String): Option<u64> {
+        let registry = object::borrow(borrow(ctx));
+        if (!table::contains(&registry.records, name)) {
+            return option::none()
+        };
+        option::some(table::borrow(&registry.records, name).expiration)
+    }
+
+    /// Register a name for `target`, the sender becomes the owner of the name.
+    /// An expired name can be registered again by anyone.
+    public fun register(ctx: &mut Context, account: &signer, name: String, target: address, duration_seconds: u64) {
+        assert!(is_valid_name(&name), ErrorInvalidName);
+        assert!(duration_seconds > 0 && duration_seconds <= MAX_REGISTRATION_DURATION_SECONDS, ErrorInvalidDuration);
+        let now = timestamp::now_seconds(ctx);
+        let owner = signer::address_of(account);
+        let expiration = now + duration_seconds;
+        let registry = object::borrow_mut(borrow_mut(ctx));
+        if (table::contains(&registry.records, name)) {
+            let record = table::borrow(&registry.records, name);
+            assert!(record.expiration <= now, ErrorNameAlreadyRegistered);
+        };
+        table::upsert(&mut registry.records, name, NameRecord{
+            owner,
+            target,
+            expiration,
+        });
+        event::emit(NameRegisteredEvent{
+            name,
+            owner,
+            target,
+            expiration,
+        });
+    }
+
+    /// Renew the name, extend the expiration time by `duration_seconds`
+    public fun renew(ctx: &mut Context, account: &signer, name: String, duration_seconds: u64) {
+        let now = timestamp::now_seconds(ctx);
+        let record = borrow_mut_record_by_owner(ctx, account, name, now);
+        let expiration = record.expiration + duration_seconds;
+        assert!(duration_seconds > 0 && expiration - now <= MAX_REGISTRATION_DURATION_SECONDS, ErrorInvalidDuration);
+        record.expiration = expiration;
+        event::emit(NameRenewedEvent{
+            name,
+            expiration,
+        });
+    }
+
+    /// Transfer the ownership of the name to `new_owner`, the target of the name is not changed
+    public fun transfer(ctx: &mut Context, account: &signer, name: String, new_owner: address) {
+        let now = timestamp::now_seconds(ctx);
+        let record = borrow_mut_record_by_owner(ctx, account, name, now);
+        let from = record.owner;
+        record.owner = new_owner;
+        event::emit(NameTransferredEvent{
+            name,
+            from,
+            to: new_owner,
+        });
+    }
+
+    /// Change the target address of the name
+    public fun set_target(ctx: &mut Context, account: &signer, name: String, target: address) {
+        let now = timestamp::now_seconds(ctx);
+        let record = borrow_mut_record_by_owner(ctx, account, name, now);
+        record.target = target;
+        event::emit(NameTargetChangedEvent{
+            name,
+            target,
+        });
+    }
+
+    fun borrow_mut_record_by_owner(ctx: &mut Context, account: &signer, name: String, now: u64): &mut NameRecord {
+        let registry = object::borrow_mut(borrow_mut(ctx));
+        assert!(table::contains(&registry.records, name), ErrorNameNotRegistered);
+        let record = table::borrow_mut(&mut registry.records, name);
+        assert!(record.expiration > now, ErrorNameExpired);
+        assert!(record.owner == signer::address_of(account), ErrorNotNameOwner);
+        record
+    }
+
+    public entry fun register_entry(ctx: &mut Context, account: &signer, name: String, target: address, duration_seconds: u64) {
+        register(ctx, account, name, target, duration_seconds);
+    }
+
+    public entry fun renew_entry(ctx: &mut Context, account: &signer, name: String, duration_seconds: u64) {
+        renew(ctx, account, name, duration_seconds);
+    }
+
+    public entry fun transfer_entry(ctx: &mut Context, account: &signer, name: String, new_owner: address) {
+        transfer(ctx, account, name, new_owner);
+    }
+
+    public entry fun set_target_entry(ctx: &mut Context, account: &signer, name: String, target: address) {
+        set_target(ctx, account, name, target);
+    }
+}
@@ -8,6 +8,7 @@ module rooch_framework::onchain_config {
 
     friend rooch_framework::upgrade;
     friend rooch_framework::genesis;
+    friend rooch_framework::sequencer_key_registry;
 
     /// OnchainConfig is framework configurations stored on chain.
     struct OnchainConfig has key {
@@ -28,6 +29,10 @@ module rooch_framework::onchain_config {
         onchain_config(ctx).sequencer
     }
 
+    public(friend) fun update_sequencer(ctx: &mut Context, sequencer: address) {
+        onchain_config_mut(ctx).sequencer = sequencer;
+    }
+
     public(friend) fun update_framework_version(ctx: &mut Context) {
         let config = onchain_config_mut(ctx);
         config.framework_version = config.framework_version + 1;
@@ -0,0 +1,185 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The registry of the sequencer key, it supports rotating the sequencer key without stopping the chain.
+/// The current sequencer announces the next key via a system transaction, and then both keys are valid
+/// during the handover window, so the proposer can dual-sign and the followers can switch to the next key smoothly.
+/// After the handover window, only the next key is valid, and the next sequencer completes the rotation on-chain.
+module rooch_framework::sequencer_key_registry {
+
+    use std::option::{Self, Option};
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+    use moveos_std::event;
+    use moveos_std::signer;
+    use rooch_framework::onchain_config;
+    use rooch_framework::native_validator;
+    use rooch_framework::timestamp;
+
+    friend rooch_framework::genesis;
+
+    /// The max handover window is 7 days
+    const MAX_HANDOVER_WINDOW_MILLISECONDS: u64 = 604800000;
+
+    const ErrorNotSequencer: u64 = 1;
+    const ErrorRotationInProgress: u64 = 2;
+    const ErrorNoPendingRotation: u64 = 3;
+    const ErrorHandoverNotFinished: u64 = 4;
+    const ErrorSameSequencer: u64 = 5;
+    const ErrorInvalidHandoverWindow: u64 = 6;
+    const ErrorNotNextSequencer: u64 = 7;
+    const ErrorHandoverFinished: u64 = 8;
+
+    /// The key rotation announced by the current sequencer
+    struct PendingRotation has store, copy, drop {
+        next_sequencer: address,
+        next_public_key: vector<u8>,
+        /// The time in milliseconds when the rotation is announced
+        announced_at: u64,
+        /// The time in milliseconds when the handover window ends, after it only the next key is valid
+        handover_end: u64,
+    }
+
+    struct SequencerKeyRegistry has key {
+        /// The version of the sequencer key, increased by one on every completed rotation
+        key_version: u64,
+        pending_rotation: Option<PendingRotation>,
+    }
+
+    struct SequencerKeyRotationAnnouncedEvent has drop {
+        current_sequencer: address,
+        next_sequencer: address,
+        next_public_key: vector<u8>,
+        handover_end: u64,
+    }
+
+    struct SequencerKeyRotatedEvent has drop {
+        previous_sequencer: address,
+        sequencer: address,
+        key_version: u64,
+    }
+
+    struct SequencerKeyRotationCancelledEvent has drop {
+        sequencer: address,
+        next_sequencer: address,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let obj = context::new_named_object(ctx, SequencerKeyRegistry{
+            key_version: 0,
+            pending_rotation: option::none(),
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow(ctx: &Context): &SequencerKeyRegistry {
+        let object_id = object::named_object_id<SequencerKeyRegistry>();
+        object::borrow(context::borrow_object<SequencerKeyRegistry>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut SequencerKeyRegistry {
+        let object_id = object::named_object_id<SequencerKeyRegistry>();
+        object::borrow_mut(context::borrow_mut_object_extend<SequencerKeyRegistry>(ctx, object_id))
+    }
+
+    public fun key_version(ctx: &Context): u64 {
+        borrow(ctx).key_version
+    }
+
+    public fun pending_rotation(ctx: &Context): Option<PendingRotation> {
+        borrow(ctx).pending_rotation
+    }
+
+    public fun next_sequencer(self: &PendingRotation): address {
+        self.next_sequencer
+    }
+
+    public fun next_public_key(self: &PendingRotation): vector<u8> {
+        self.next_public_key
+    }
+
+    public fun announced_at(self: &PendingRotation): u64 {
+        self.announced_at
+    }
+
+    public fun handover_end(self: &PendingRotation): u64 {
+        self.handover_end
+    }
+
+    /// Check the sequencer key of the `addr` is valid now.
+    /// During the handover window both the current and the next key are valid,
+    /// after the handover window only the next key is valid even if the rotation is not completed yet.
+    public fun is_valid_sequencer(ctx: &Context, addr: address): bool {
+        let current_sequencer = onchain_config::sequencer(ctx);
+        let registry = borrow(ctx);
+        if (option::is_none(&registry.pending_rotation)) {
+            return addr == current_sequencer
+        };
+        let pending = option::borrow(&registry.pending_rotation);
+        if (addr == pending.next_sequencer) {
+            return true
+        };
+        addr == current_sequencer && timestamp::now_milliseconds(ctx) < pending.handover_end
+    }
+
+    /// The current sequencer announces the next sequencer key, the key is valid from now on,
+    /// and the current key is still valid until the handover window ends.
+    public entry fun announce_key_rotation(ctx: &mut Context, account: &signer, next_public_key: vector<u8>, handover_window_milliseconds: u64) {
+        let current_sequencer = onchain_config::sequencer(ctx);
+        assert!(signer::address_of(account) == current_sequencer, ErrorNotSequencer);
+        assert!(option::is_none(&borrow(ctx).pending_rotation), ErrorRotationInProgress);
+        assert!(handover_window_milliseconds <= MAX_HANDOVER_WINDOW_MILLISECONDS, ErrorInvalidHandoverWindow);
+        let next_sequencer = native_validator::public_key_to_address(next_public_key);
+        assert!(next_sequencer != current_sequencer, ErrorSameSequencer);
+
+        let now = timestamp::now_milliseconds(ctx);
+        let handover_end = now + handover_window_milliseconds;
+        borrow_mut(ctx).pending_rotation = option::some(PendingRotation{
+            next_sequencer,
+            next_public_key,
+            announced_at: now,
+            handover_end,
+        });
+        event::emit(SequencerKeyRotationAnnouncedEvent{
+            current_sequencer,
+            next_sequencer,
+            next_public_key,
+            handover_end,
+        });
+    }
+
+    /// The next sequencer completes the rotation after the handover window, it proves the possession of the next key.
+    public entry fun complete_key_rotation(ctx: &mut Context, account: &signer) {
+        let pending_opt = borrow(ctx).pending_rotation;
+        assert!(option::is_some(&pending_opt), ErrorNoPendingRotation);
+        let pending = option::destroy_some(pending_opt);
+        assert!(signer::address_of(account) == pending.next_sequencer, ErrorNotNextSequencer);
+        assert!(timestamp::now_milliseconds(ctx) >= pending.handover_end, ErrorHandoverNotFinished);
+
+        let previous_sequencer = onchain_config::sequencer(ctx);
+        onchain_config::update_sequencer(ctx, pending.next_sequencer);
+        let registry = borrow_mut(ctx);
+        registry.key_version = registry.key_version + 1;
+        registry.pending_rotation = option::none();
+        event::emit(SequencerKeyRotatedEvent{
+            previous_sequencer,
+            sequencer: pending.next_sequencer,
+            key_version: registry.key_version,
+        });
+    }
+
+    /// The current sequencer cancels the pending rotation before the handover window ends.
+    public entry fun cancel_key_rotation(ctx: &mut Context, account: &signer) {
+        let sequencer = onchain_config::sequencer(ctx);
+        assert!(signer::address_of(account) == sequencer, ErrorNotSequencer);
+        let pending_opt = borrow(ctx).pending_rotation;
+        assert!(option::is_some(&pending_opt), ErrorNoPendingRotation);
+        let pending = option::destroy_some(pending_opt);
+        assert!(timestamp::now_milliseconds(ctx) < pending.handover_end, ErrorHandoverFinished);
+        borrow_mut(ctx).pending_rotation = option::none();
+        event::emit(SequencerKeyRotationCancelledEvent{
+            sequencer,
+            next_sequencer: pending.next_sequencer,
+        });
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The storage rent of the objects.
+/// The storage deposit of an object is charged via the change set gas when the object is created,
+/// and refunded as the storage rebate when the object is deleted.
+/// This module tracks the last active time of the accounts, and if the reclamation is enabled,
+/// the system transaction can reclaim the objects of the accounts which are inactive longer than the grace period.
+module rooch_framework::storage_rent {
+
+    use std::option::{Self, Option};
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, ObjectID};
+    use moveos_std::table::{Self, Table};
+    use moveos_std::event;
+    use rooch_framework::account;
+    use rooch_framework::core_addresses;
+    use rooch_framework::timestamp;
+
+    friend rooch_framework::genesis;
+    friend rooch_framework::transaction_validator;
+
+    /// The default grace period is 2 years
+    const DEFAULT_GRACE_PERIOD_SECONDS: u64 = 63072000;
+    /// The min grace period is 1 year
+    const MIN_GRACE_PERIOD_SECONDS: u64 = 31536000;
+
+    const ErrorReclamationDisabled: u64 = 1;
+    const ErrorAccountNotInactive: u64 = 2;
+    const ErrorInvalidGracePeriod: u64 = 3;
+
+    struct StorageRent has key {
+        reclamation_enabled: bool,
+        grace_period_seconds: u64,
+        /// The last active time in seconds of the accounts
+        last_active: Table<address, u64>,
+    }
+
+    struct ObjectReclaimedEvent has drop {
+        object_id: ObjectID,
+        owner: address,
+        last_active: u64,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let last_active = context::new_table<address, u64>(ctx);
+        let obj = context::new_named_object(ctx, StorageRent{
+            reclamation_enabled: false,
+            grace_period_seconds: DEFAULT_GRACE_PERIOD_SECONDS,
+            last_active,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow(ctx: &Context): &StorageRent {
+        let object_id = object::named_object_id<StorageRent>();
+        object::borrow(context::borrow_object<StorageRent>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut StorageRent {
+        let object_id = object::named_object_id<StorageRent>();
+        object::borrow_mut(context::borrow_mut_object_extend<StorageRent>(ctx, object_id))
+    }
+
+    /// Record the account is active now, called by the transaction_validator after every transaction.
+    public(friend) fun record_activity(ctx: &mut Context, account: address) {
+        let now = timestamp::now_seconds(ctx);
+        let rent = borrow_mut(ctx);
+        table::upsert(&mut rent.last_active, account, now);
+    }
+
+    public fun reclamation_enabled(ctx: &Context): bool {
+        borrow(ctx).reclamation_enabled
+    }
+
+    public fun grace_period_seconds(ctx: &Context): u64 {
+        borrow(ctx).grace_period_seconds
+    }
+
+    /// Return the last active time in seconds of the account, return none if the account has never been active
+    public fun last_active(ctx: &Context, account: address): Option<u64> {
+        let rent = borrow(ctx);
+        if (table::contains(&rent.last_active, account)) {
+            option::some(*table::borrow(&rent.last_active, account))
+        } else {
+            option::none()
+        }
+    }
+
+    /// Check the account is inactive longer than the grace period.
+    /// The account which has never been active is not treated as inactive.
+    public fun is_inactive(ctx: &Context, account: address): bool {
+        let last_active_opt = last_active(ctx, account);
+        if (option::is_none(&last_active_opt)) {
+            return false
+        };
+        let last_active = option::destroy_some(last_active_opt);
+        timestamp::now_seconds(ctx) - last_active > grace_period_seconds(ctx)
+    }
+
+    public entry fun set_reclamation_enabled(ctx: &mut Context, account: &signer, enabled: bool) {
+        core_addresses::assert_rooch_framework(account);
+        borrow_mut(ctx).reclamation_enabled = enabled;
+    }
+
+    public entry fun set_grace_period_seconds(ctx: &mut Context, account: &signer, grace_period_seconds: u64) {
+        core_addresses::assert_rooch_framework(account);
+        assert!(grace_period_seconds >= MIN_GRACE_PERIOD_SECONDS, ErrorInvalidGracePeriod);
+        borrow_mut(ctx).grace_period_seconds = grace_period_seconds;
+    }
+
+    /// Reclaim the object of an inactive account, the object is transferred to the rooch_framework.
+    /// This function is called by the system transaction, and only works when the reclamation is enabled.
+    public entry fun reclaim_object<T: key + store>(ctx: &mut Context, account: &signer, object_id: ObjectID) {
+        core_addresses::assert_rooch_framework(account);
+        assert!(reclamation_enabled(ctx), ErrorReclamationDisabled);
+        let owner = object::owner(context::borrow_object<T>(ctx, object_id));
+        assert!(is_inactive(ctx, owner), ErrorAccountNotInactive);
+        let last_active = option::destroy_some(last_active(ctx, owner));
+        let owner_signer = account::create_signer(owner);
+        let obj = context::take_object<T>(ctx, &owner_signer, object_id);
+        object::transfer(obj, @rooch_framework);
+        event::emit(ObjectReclaimedEvent{
+            object_id,
+            owner,
+            last_active,
+        });
+    }
+
+    #[test_only]
+    public fun set_last_active_for_test(ctx: &mut Context, account: address, last_active: u64) {
+        let rent = borrow_mut(ctx);
+        table::upsert(&mut rent.last_active, account, last_active);
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the framework capabilities
+module rooch_framework::capability_test{
+
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, UpgradeCap, TreasuryCap, PublisherCap};
+
+    #[test]
+    fun test_genesis_capabilities(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let upgrade_cap = context::borrow_object<UpgradeCap>(&genesis_ctx, capability::upgrade_cap_id());
+        assert!(object::owner(upgrade_cap) == @rooch_framework, 1000);
+        let treasury_cap = context::borrow_object<TreasuryCap>(&genesis_ctx, capability::treasury_cap_id());
+        assert!(object::owner(treasury_cap) == @rooch_framework, 1001);
+        let publisher_cap = context::borrow_object<PublisherCap>(&genesis_ctx, capability::publisher_cap_id(@rooch_framework));
+        capability::assert_publisher(publisher_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(publisher = @0x42)]
+    fun test_claim_publisher_cap(publisher: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let publisher_signer = account::create_account_for_test(&mut genesis_ctx, publisher);
+        capability::claim_publisher_cap(&mut genesis_ctx, &publisher_signer);
+        let publisher_cap = context::borrow_object<PublisherCap>(&genesis_ctx, capability::publisher_cap_id(publisher));
+        assert!(object::owner(publisher_cap) == publisher, 1000);
+        assert!(capability::publisher_cap_package(publisher_cap) == publisher, 1001);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(publisher = @0x42)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::capability)]
+    fun test_claim_publisher_cap_twice(publisher: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let publisher_signer = account::create_account_for_test(&mut genesis_ctx, publisher);
+        capability::claim_publisher_cap(&mut genesis_ctx, &publisher_signer);
+        capability::claim_publisher_cap(&mut genesis_ctx, &publisher_signer);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the chain pause switch
+module rooch_framework::chain_pause_test{
+
+    use rooch_framework::account;
+    use rooch_framework::chain_pause;
+
+    #[test(user = @0x42)]
+    fun test_pause_and_unpause(user: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        assert!(!chain_pause::is_paused(&genesis_ctx), 1000);
+        assert!(chain_pause::is_tx_allowed(&genesis_ctx, user), 1001);
+
+        chain_pause::pause(&mut genesis_ctx, &sequencer, b"incident");
+        assert!(chain_pause::is_paused(&genesis_ctx), 1002);
+        assert!(chain_pause::reason(&genesis_ctx) == b"incident", 1003);
+        assert!(!chain_pause::is_tx_allowed(&genesis_ctx, user), 1004);
+        assert!(chain_pause::is_tx_allowed(&genesis_ctx, @rooch_framework), 1005);
+
+        chain_pause::unpause(&mut genesis_ctx, &sequencer);
+        assert!(!chain_pause::is_paused(&genesis_ctx), 1006);
+        assert!(chain_pause::is_tx_allowed(&genesis_ctx, user), 1007);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(user = @0x42, relayer = @0x43)]
+    fun test_relayer_allowed_when_paused(user: address, relayer: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        assert!(!chain_pause::is_system_sender(&genesis_ctx, relayer), 1000);
+
+        chain_pause::add_relayer(&mut genesis_ctx, &sequencer, relayer);
+        assert!(chain_pause::is_system_sender(&genesis_ctx, relayer), 1001);
+        assert!(chain_pause::relayers(&genesis_ctx) == vector[relayer], 1002);
+
+        chain_pause::pause(&mut genesis_ctx, &sequencer, b"incident");
+        assert!(chain_pause::is_tx_allowed(&genesis_ctx, relayer), 1003);
+        assert!(!chain_pause::is_tx_allowed(&genesis_ctx, user), 1004);
+
+        chain_pause::remove_relayer(&mut genesis_ctx, &sequencer, relayer);
+        assert!(!chain_pause::is_tx_allowed(&genesis_ctx, relayer), 1005);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(user = @0x42, relayer = @0x43)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::chain_pause)]
+    fun test_add_relayer_not_sequencer(user: address, relayer: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let user_signer = account::create_signer_for_test(user);
+        chain_pause::add_relayer(&mut genesis_ctx, &user_signer, relayer);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(user = @0x42)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::chain_pause)]
+    fun test_pause_not_sequencer(user: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let user_signer = account::create_signer_for_test(user);
+        chain_pause::pause(&mut genesis_ctx, &user_signer, b"incident");
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3, location = rooch_framework::chain_pause)]
+    fun test_unpause_not_paused(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        chain_pause::unpause(&mut genesis_ctx, &sequencer);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the event schema registry
+module rooch_framework::event_schema_registry_test{
+
+    use std::vector;
+    use rooch_framework::account;
+    use rooch_framework::event_schema_registry;
+
+    struct TestEvent has drop {
+        value: u64,
+    }
+
+    #[test]
+    fun test_register_event_schema(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let owner = account::create_signer_for_test(@rooch_framework);
+        assert!(event_schema_registry::schema_count<TestEvent>(&genesis_ctx) == 0, 1000);
+
+        event_schema_registry::register_event_schema<TestEvent>(&mut genesis_ctx, &owner, x"01");
+        event_schema_registry::register_event_schema<TestEvent>(&mut genesis_ctx, &owner, x"02");
+        let schemas = event_schema_registry::event_schemas<TestEvent>(&genesis_ctx);
+        assert!(vector::length(&schemas) == 2, 1001);
+        let latest = vector::borrow(&schemas, 1);
+        assert!(event_schema_registry::version(latest) == 1, 1002);
+        assert!(event_schema_registry::layout(latest) == x"02", 1003);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(other = @0x42)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::event_schema_registry)]
+    fun test_register_by_non_owner(other: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let other_signer = account::create_signer_for_test(other);
+        event_schema_registry::register_event_schema<TestEvent>(&mut genesis_ctx, &other_signer, x"01");
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
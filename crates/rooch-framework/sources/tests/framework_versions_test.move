@@ -0,0 +1,51 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the framework versions registry
+module rooch_framework::framework_versions_test{
+
+    use rooch_framework::framework_versions;
+
+    #[test]
+    fun test_activate_framework_version(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        assert!(framework_versions::active_version(&genesis_ctx) == 0, 1000);
+        assert!(framework_versions::active_framework_address(&genesis_ctx) == @rooch_framework, 1001);
+
+        framework_versions::register_version_for_test(&mut genesis_ctx, 1, @0x5);
+        assert!(framework_versions::active_framework_address(&genesis_ctx) == @rooch_framework, 1002);
+        framework_versions::activate_version_for_test(&mut genesis_ctx, 1);
+        assert!(framework_versions::active_version(&genesis_ctx) == 1, 1003);
+        assert!(framework_versions::active_framework_address(&genesis_ctx) == @0x5, 1004);
+
+        framework_versions::activate_version_for_test(&mut genesis_ctx, 0);
+        assert!(framework_versions::active_framework_address(&genesis_ctx) == @rooch_framework, 1005);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::framework_versions)]
+    fun test_register_non_version_address(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        framework_versions::register_version_for_test(&mut genesis_ctx, 1, @0x42);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3, location = rooch_framework::framework_versions)]
+    fun test_register_address_in_use(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        framework_versions::register_version_for_test(&mut genesis_ctx, 1, @0x5);
+        framework_versions::register_version_for_test(&mut genesis_ctx, 2, @0x5);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = rooch_framework::framework_versions)]
+    fun test_activate_unregistered_version(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        framework_versions::activate_version_for_test(&mut genesis_ctx, 1);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
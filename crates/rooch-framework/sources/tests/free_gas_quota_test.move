@@ -0,0 +1,61 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the free gas quota
+module rooch_framework::free_gas_quota_test{
+
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, UpgradeCap};
+    use rooch_framework::free_gas_quota;
+
+    #[test]
+    fun test_update_config(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        // The test genesis is the dev chain, the free gas quota is enabled
+        assert!(free_gas_quota::remaining_quota(&genesis_ctx, @0x42) == 10, 1000);
+
+        free_gas_quota::update_config(&mut genesis_ctx, &mut upgrade_cap, 3, 1000);
+        let config = free_gas_quota::config(&genesis_ctx);
+        assert!(free_gas_quota::config_tx_quota(&config) == 3, 1001);
+        assert!(free_gas_quota::config_max_gas_amount(&config) == 1000, 1002);
+        assert!(free_gas_quota::remaining_quota(&genesis_ctx, @0x42) == 3, 1003);
+
+        free_gas_quota::update_config(&mut genesis_ctx, &mut upgrade_cap, 0, 1000);
+        assert!(free_gas_quota::remaining_quota(&genesis_ctx, @0x42) == 0, 1004);
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_init_after_upgrade(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        free_gas_quota::remove_for_test(&mut genesis_ctx);
+        assert!(free_gas_quota::remaining_quota(&genesis_ctx, @0x42) == 0, 1000);
+
+        // The chain upgraded from a framework without the free gas quota creates it with the default config
+        free_gas_quota::init_after_upgrade(&mut genesis_ctx, &mut upgrade_cap);
+        let config = free_gas_quota::config(&genesis_ctx);
+        assert!(free_gas_quota::config_tx_quota(&config) == 10, 1001);
+        assert!(free_gas_quota::config_max_gas_amount(&config) == 10000000, 1002);
+        assert!(free_gas_quota::remaining_quota(&genesis_ctx, @0x42) == 10, 1003);
+
+        // The existing free gas quota is kept
+        free_gas_quota::update_config(&mut genesis_ctx, &mut upgrade_cap, 3, 1000);
+        free_gas_quota::init_after_upgrade(&mut genesis_ctx, &mut upgrade_cap);
+        assert!(free_gas_quota::config_tx_quota(&free_gas_quota::config(&genesis_ctx)) == 3, 1004);
+
+        // The config is updated on the chain without the free gas quota
+        free_gas_quota::remove_for_test(&mut genesis_ctx);
+        free_gas_quota::update_config(&mut genesis_ctx, &mut upgrade_cap, 5, 1000);
+        assert!(free_gas_quota::remaining_quota(&genesis_ctx, @0x42) == 5, 1005);
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the versioned gas schedule
+module rooch_framework::gas_schedule_test{
+
+    use std::option;
+    use std::string;
+    use std::vector;
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, UpgradeCap};
+    use rooch_framework::gas_schedule;
+    use rooch_framework::timestamp;
+
+    #[test]
+    fun test_propose_and_activate(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        assert!(gas_schedule::schedule_version(&genesis_ctx) == 0, 1000);
+
+        gas_schedule::propose_gas_schedule(&mut genesis_ctx, &mut upgrade_cap, 1, vector[string::utf8(b"instruction_tiers.0")], vector[2], 1000);
+        assert!(gas_schedule::schedule_version(&genesis_ctx) == 0, 1001);
+        let pending = gas_schedule::pending_gas_schedule(&genesis_ctx);
+        assert!(option::is_some(&pending), 1002);
+        assert!(gas_schedule::pending_activation_time(option::borrow(&pending)) == 1000, 1003);
+
+        // The schedule is switched at the upgrade epoch
+        timestamp::update_global_time_for_test(&mut genesis_ctx, 1000);
+        assert!(gas_schedule::schedule_version(&genesis_ctx) == 1, 1004);
+        assert!(option::is_none(&gas_schedule::pending_gas_schedule(&genesis_ctx)), 1005);
+        let config = gas_schedule::current_gas_schedule(&genesis_ctx);
+        let entries = gas_schedule::config_entries(&config);
+        assert!(vector::length(entries) == 1, 1006);
+        assert!(gas_schedule::entry_val(vector::borrow(entries, 0)) == 2, 1007);
+
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_cancel_gas_schedule(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        gas_schedule::propose_gas_schedule(&mut genesis_ctx, &mut upgrade_cap, 1, vector[], vector[], 1000);
+        gas_schedule::cancel_gas_schedule(&mut genesis_ctx, &mut upgrade_cap);
+        timestamp::update_global_time_for_test(&mut genesis_ctx, 1000);
+        assert!(gas_schedule::schedule_version(&genesis_ctx) == 0, 1000);
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::gas_schedule)]
+    fun test_propose_old_version(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        gas_schedule::propose_gas_schedule(&mut genesis_ctx, &mut upgrade_cap, 0, vector[], vector[], 0);
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
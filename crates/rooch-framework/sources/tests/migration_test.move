@@ -0,0 +1,42 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the migration record
+module rooch_framework::migration_test{
+
+    use std::string;
+    use rooch_framework::account;
+    use rooch_framework::migration;
+
+    #[test]
+    fun test_complete_migration(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        let name = string::utf8(b"test_migration");
+        assert!(!migration::is_completed(&genesis_ctx, name), 1000);
+        migration::complete_migration(&mut genesis_ctx, &sequencer, name, 10);
+        assert!(migration::is_completed(&genesis_ctx, name), 1001);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2, location = rooch_framework::migration)]
+    fun test_complete_migration_twice(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        let name = string::utf8(b"test_migration");
+        migration::complete_migration(&mut genesis_ctx, &sequencer, name, 10);
+        migration::complete_migration(&mut genesis_ctx, &sequencer, name, 10);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(other = @0x42)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::migration)]
+    fun test_complete_migration_by_non_sequencer(other: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let other_signer = account::create_signer_for_test(other);
+        migration::complete_migration(&mut genesis_ctx, &other_signer, string::utf8(b"test_migration"), 10);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
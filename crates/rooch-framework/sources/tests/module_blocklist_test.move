@@ -0,0 +1,95 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the module blocklist
+module rooch_framework::module_blocklist_test{
+
+    use std::ascii;
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, UpgradeCap};
+    use rooch_framework::module_blocklist;
+
+    #[test]
+    fun test_block_and_unblock(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        let module_name = ascii::string(b"exploited");
+        let function_name = ascii::string(b"withdraw");
+        let other_function_name = ascii::string(b"deposit");
+        assert!(!module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, function_name), 1000);
+        assert!(module_blocklist::is_empty(&genesis_ctx), 1010);
+
+        module_blocklist::block_function(&mut genesis_ctx, &mut upgrade_cap, @0x42, module_name, function_name, b"exploit");
+        assert!(module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, function_name), 1001);
+        assert!(!module_blocklist::is_empty(&genesis_ctx), 1011);
+        // The blocked function is reached through another function
+        assert!(module_blocklist::is_any_function_blocked(&genesis_ctx, vector[@0x43, @0x42], vector[b"wrapper", b"exploited"], vector[b"call", b"withdraw"]), 1012);
+        assert!(!module_blocklist::is_any_function_blocked(&genesis_ctx, vector[@0x43, @0x42], vector[b"wrapper", b"exploited"], vector[b"call", b"deposit"]), 1013);
+        assert!(!module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, other_function_name), 1002);
+        assert!(!module_blocklist::is_module_blocked(&genesis_ctx, @0x42, module_name), 1003);
+
+        module_blocklist::block_module(&mut genesis_ctx, &mut upgrade_cap, @0x42, module_name, b"exploit");
+        assert!(module_blocklist::is_module_blocked(&genesis_ctx, @0x42, module_name), 1004);
+        assert!(module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, other_function_name), 1005);
+        assert!(!module_blocklist::is_module_blocked(&genesis_ctx, @0x43, module_name), 1006);
+
+        module_blocklist::unblock_module(&mut genesis_ctx, &mut upgrade_cap, @0x42, module_name);
+        assert!(!module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, other_function_name), 1007);
+        assert!(module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, function_name), 1008);
+
+        module_blocklist::unblock_function(&mut genesis_ctx, &mut upgrade_cap, @0x42, module_name, function_name);
+        assert!(!module_blocklist::is_function_blocked(&genesis_ctx, @0x42, module_name, function_name), 1009);
+        assert!(module_blocklist::is_empty(&genesis_ctx), 1014);
+
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = rooch_framework::module_blocklist)]
+    fun test_block_blocklist(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        module_blocklist::block_module(&mut genesis_ctx, &mut upgrade_cap, @rooch_framework, ascii::string(b"module_blocklist"), b"");
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = rooch_framework::module_blocklist)]
+    fun test_block_framework_function(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        module_blocklist::block_function(&mut genesis_ctx, &mut upgrade_cap, @rooch_framework, ascii::string(b"transaction_validator"), ascii::string(b"validate"), b"");
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 5, location = rooch_framework::module_blocklist)]
+    fun test_block_std_module(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        module_blocklist::block_module(&mut genesis_ctx, &mut upgrade_cap, @std, ascii::string(b"vector"), b"");
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2, location = rooch_framework::module_blocklist)]
+    fun test_unblock_not_blocked(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        module_blocklist::unblock_module(&mut genesis_ctx, &mut upgrade_cap, @0x42, ascii::string(b"exploited"));
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
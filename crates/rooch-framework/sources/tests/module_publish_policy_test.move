@@ -0,0 +1,76 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the module publishing policy
+module rooch_framework::module_publish_policy_test{
+
+    use std::vector;
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, UpgradeCap};
+    use rooch_framework::module_publish_policy;
+
+    #[test(alice = @0x42, bob = @0x43)]
+    fun test_publish_policy(alice: address, bob: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        assert!(module_publish_policy::policy(&genesis_ctx) == module_publish_policy::policy_open(), 1000);
+        assert!(module_publish_policy::is_allowed(&genesis_ctx, alice), 1001);
+
+        module_publish_policy::deny(&mut genesis_ctx, &mut upgrade_cap, alice);
+        assert!(!module_publish_policy::is_allowed(&genesis_ctx, alice), 1002);
+        assert!(module_publish_policy::is_allowed(&genesis_ctx, bob), 1003);
+        module_publish_policy::remove_denied(&mut genesis_ctx, &mut upgrade_cap, alice);
+        assert!(module_publish_policy::is_allowed(&genesis_ctx, alice), 1004);
+
+        module_publish_policy::set_policy(&mut genesis_ctx, &mut upgrade_cap, module_publish_policy::policy_allowlist());
+        module_publish_policy::allow(&mut genesis_ctx, &mut upgrade_cap, alice);
+        assert!(module_publish_policy::is_allowed(&genesis_ctx, alice), 1005);
+        assert!(!module_publish_policy::is_allowed(&genesis_ctx, bob), 1006);
+
+        // The denylist takes precedence over the allowlist
+        module_publish_policy::deny(&mut genesis_ctx, &mut upgrade_cap, alice);
+        assert!(!module_publish_policy::is_allowed(&genesis_ctx, alice), 1007);
+
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_tx_permission(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        module_publish_policy::add_tx_permission_for_test(&mut genesis_ctx);
+        assert!(context::is_module_publish_allowed(&genesis_ctx), 1000);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = moveos_std::context)]
+    fun test_denied_tx_permission(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        // The sender of the test context is the framework
+        module_publish_policy::deny(&mut genesis_ctx, &mut upgrade_cap, @rooch_framework);
+        object::transfer(upgrade_cap, @rooch_framework);
+        module_publish_policy::add_tx_permission_for_test(&mut genesis_ctx);
+        assert!(!context::is_module_publish_allowed(&genesis_ctx), 1000);
+        // The modules published by any module are checked
+        context::publish_modules(&mut genesis_ctx, &framework, vector::empty());
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::module_publish_policy)]
+    fun test_set_invalid_policy(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let upgrade_cap = context::take_object<UpgradeCap>(&mut genesis_ctx, &framework, capability::upgrade_cap_id());
+        module_publish_policy::set_policy(&mut genesis_ctx, &mut upgrade_cap, 2);
+        object::transfer(upgrade_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
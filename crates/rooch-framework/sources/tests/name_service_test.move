@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the name service
+module rooch_framework::name_service_test{
+
+    use std::option;
+    use std::string;
+    use rooch_framework::account;
+    use rooch_framework::name_service;
+    use rooch_framework::timestamp;
+
+    #[test(alice = @0x42, bob = @0x43)]
+    fun test_register_and_resolve(alice: address, bob: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let alice_signer = account::create_account_for_test(&mut genesis_ctx, alice);
+        let name = string::utf8(b"alice");
+        assert!(option::is_none(&name_service::resolve(&genesis_ctx, name)), 1000);
+
+        name_service::register(&mut genesis_ctx, &alice_signer, name, alice, 100);
+        assert!(name_service::resolve(&genesis_ctx, name) == option::some(alice), 1001);
+        assert!(name_service::owner_of(&genesis_ctx, name) == option::some(alice), 1002);
+
+        name_service::set_target(&mut genesis_ctx, &alice_signer, name, bob);
+        assert!(name_service::resolve(&genesis_ctx, name) == option::some(bob), 1003);
+
+        name_service::transfer(&mut genesis_ctx, &alice_signer, name, bob);
+        assert!(name_service::owner_of(&genesis_ctx, name) == option::some(bob), 1004);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(alice = @0x42, bob = @0x43)]
+    fun test_expired_name(alice: address, bob: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let alice_signer = account::create_account_for_test(&mut genesis_ctx, alice);
+        let bob_signer = account::create_account_for_test(&mut genesis_ctx, bob);
+        let name = string::utf8(b"alice");
+        name_service::register(&mut genesis_ctx, &alice_signer, name, alice, 100);
+        name_service::renew(&mut genesis_ctx, &alice_signer, name, 100);
+        assert!(name_service::expiration_of(&genesis_ctx, name) == option::some(200), 1000);
+
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, 200);
+        assert!(option::is_none(&name_service::resolve(&genesis_ctx, name)), 1001);
+
+        // An expired name can be registered by others
+        name_service::register(&mut genesis_ctx, &bob_signer, name, bob, 100);
+        assert!(name_service::resolve(&genesis_ctx, name) == option::some(bob), 1002);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(alice = @0x42, bob = @0x43)]
+    #[expected_failure(abort_code = 2, location = rooch_framework::name_service)]
+    fun test_register_duplicate_name(alice: address, bob: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let alice_signer = account::create_account_for_test(&mut genesis_ctx, alice);
+        let bob_signer = account::create_account_for_test(&mut genesis_ctx, bob);
+        let name = string::utf8(b"alice");
+        name_service::register(&mut genesis_ctx, &alice_signer, name, alice, 100);
+        name_service::register(&mut genesis_ctx, &bob_signer, name, bob, 100);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(alice = @0x42, bob = @0x43)]
+    #[expected_failure(abort_code = 4, location = rooch_framework::name_service)]
+    fun test_set_target_not_owner(alice: address, bob: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let alice_signer = account::create_account_for_test(&mut genesis_ctx, alice);
+        let bob_signer = account::create_account_for_test(&mut genesis_ctx, bob);
+        let name = string::utf8(b"alice");
+        name_service::register(&mut genesis_ctx, &alice_signer, name, alice, 100);
+        name_service::set_target(&mut genesis_ctx, &bob_signer, name, bob);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_is_valid_name(){
+        assert!(name_service::is_valid_name(&string::utf8(b"alice_01")), 1000);
+        assert!(!name_service::is_valid_name(&string::utf8(b"ab")), 1001);
+        assert!(!name_service::is_valid_name(&string::utf8(b"1alice")), 1002);
+        assert!(!name_service::is_valid_name(&string::utf8(b"Alice")), 1003);
+        assert!(!name_service::is_valid_name(&string::utf8(b"alice.rooch")), 1004);
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the sequencer key rotation
+module rooch_framework::sequencer_key_registry_test{
+
+    use std::option;
+    use rooch_framework::account;
+    use rooch_framework::native_validator;
+    use rooch_framework::onchain_config;
+    use rooch_framework::sequencer_key_registry;
+    use rooch_framework::timestamp;
+
+    const NEXT_PUBLIC_KEY: vector<u8> = x"3ab059d7ae6e8e1b6e8a5a4a3e5b6b0dbc4c5a1b0e0b05e9b4c5d6a8f0e1f2a3";
+    const HANDOVER_WINDOW_MILLISECONDS: u64 = 3600000;
+
+    #[test]
+    fun test_rotate_sequencer_key(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        let next_sequencer_addr = native_validator::public_key_to_address(NEXT_PUBLIC_KEY);
+
+        sequencer_key_registry::announce_key_rotation(&mut genesis_ctx, &sequencer, NEXT_PUBLIC_KEY, HANDOVER_WINDOW_MILLISECONDS);
+        let pending = option::destroy_some(sequencer_key_registry::pending_rotation(&genesis_ctx));
+        assert!(sequencer_key_registry::next_sequencer(&pending) == next_sequencer_addr, 1000);
+        // both keys are valid during the handover window
+        assert!(sequencer_key_registry::is_valid_sequencer(&genesis_ctx, @rooch_framework), 1001);
+        assert!(sequencer_key_registry::is_valid_sequencer(&genesis_ctx, next_sequencer_addr), 1002);
+
+        timestamp::update_global_time_for_test(&mut genesis_ctx, HANDOVER_WINDOW_MILLISECONDS);
+        assert!(!sequencer_key_registry::is_valid_sequencer(&genesis_ctx, @rooch_framework), 1003);
+
+        let next_sequencer = account::create_signer_for_test(next_sequencer_addr);
+        sequencer_key_registry::complete_key_rotation(&mut genesis_ctx, &next_sequencer);
+        assert!(onchain_config::sequencer(&genesis_ctx) == next_sequencer_addr, 1004);
+        assert!(sequencer_key_registry::key_version(&genesis_ctx) == 1, 1005);
+        assert!(option::is_none(&sequencer_key_registry::pending_rotation(&genesis_ctx)), 1006);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 4, location = rooch_framework::sequencer_key_registry)]
+    fun test_complete_rotation_before_handover_end(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        sequencer_key_registry::announce_key_rotation(&mut genesis_ctx, &sequencer, NEXT_PUBLIC_KEY, HANDOVER_WINDOW_MILLISECONDS);
+        let next_sequencer = account::create_signer_for_test(native_validator::public_key_to_address(NEXT_PUBLIC_KEY));
+        sequencer_key_registry::complete_key_rotation(&mut genesis_ctx, &next_sequencer);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(other = @0x42)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::sequencer_key_registry)]
+    fun test_announce_by_non_sequencer(other: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let other_signer = account::create_signer_for_test(other);
+        sequencer_key_registry::announce_key_rotation(&mut genesis_ctx, &other_signer, NEXT_PUBLIC_KEY, HANDOVER_WINDOW_MILLISECONDS);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_cancel_rotation(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sequencer = account::create_signer_for_test(@rooch_framework);
+        sequencer_key_registry::announce_key_rotation(&mut genesis_ctx, &sequencer, NEXT_PUBLIC_KEY, HANDOVER_WINDOW_MILLISECONDS);
+        sequencer_key_registry::cancel_key_rotation(&mut genesis_ctx, &sequencer);
+        assert!(option::is_none(&sequencer_key_registry::pending_rotation(&genesis_ctx)), 1000);
+        assert!(!sequencer_key_registry::is_valid_sequencer(&genesis_ctx, native_validator::public_key_to_address(NEXT_PUBLIC_KEY)), 1001);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
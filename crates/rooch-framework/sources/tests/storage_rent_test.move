@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the storage rent reclamation
+module rooch_framework::storage_rent_test{
+
+    use std::option;
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::storage_rent;
+    use rooch_framework::timestamp;
+
+    const TWO_YEARS_SECONDS: u64 = 63072000;
+
+    struct TestObject has key, store {}
+
+    #[test(owner = @0x42)]
+    fun test_reclaim_object(owner: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let _owner_signer = account::create_account_for_test(&mut genesis_ctx, owner);
+        let obj = context::new_object(&mut genesis_ctx, TestObject{});
+        let object_id = object::id(&obj);
+        object::transfer(obj, owner);
+
+        storage_rent::set_last_active_for_test(&mut genesis_ctx, owner, 0);
+        assert!(!storage_rent::is_inactive(&genesis_ctx, owner), 1000);
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, TWO_YEARS_SECONDS + 1);
+        assert!(storage_rent::is_inactive(&genesis_ctx, owner), 1001);
+
+        storage_rent::set_reclamation_enabled(&mut genesis_ctx, &framework, true);
+        storage_rent::reclaim_object<TestObject>(&mut genesis_ctx, &framework, object_id);
+        let obj = context::borrow_object<TestObject>(&genesis_ctx, object_id);
+        assert!(object::owner(obj) == @rooch_framework, 1002);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(owner = @0x42)]
+    #[expected_failure(abort_code = 1, location = rooch_framework::storage_rent)]
+    fun test_reclaim_object_disabled(owner: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let obj = context::new_object(&mut genesis_ctx, TestObject{});
+        let object_id = object::id(&obj);
+        object::transfer(obj, owner);
+        storage_rent::set_last_active_for_test(&mut genesis_ctx, owner, 0);
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, TWO_YEARS_SECONDS + 1);
+        storage_rent::reclaim_object<TestObject>(&mut genesis_ctx, &framework, object_id);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test(owner = @0x42)]
+    #[expected_failure(abort_code = 2, location = rooch_framework::storage_rent)]
+    fun test_reclaim_object_of_active_account(owner: address){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let obj = context::new_object(&mut genesis_ctx, TestObject{});
+        let object_id = object::id(&obj);
+        object::transfer(obj, owner);
+        storage_rent::set_last_active_for_test(&mut genesis_ctx, owner, 0);
+        assert!(option::is_some(&storage_rent::last_active(&genesis_ctx, owner)), 1000);
+        storage_rent::set_reclamation_enabled(&mut genesis_ctx, &framework, true);
+        storage_rent::reclaim_object<TestObject>(&mut genesis_ctx, &framework, object_id);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
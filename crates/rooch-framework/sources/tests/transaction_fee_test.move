@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the base gas price of the fee market
+module rooch_framework::transaction_fee_test{
+
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, TreasuryCap};
+    use rooch_framework::timestamp;
+    use rooch_framework::transaction_fee;
+
+    #[test]
+    fun test_next_base_gas_price(){
+        assert!(transaction_fee::next_base_gas_price(800, 1000, 1000, 1) == 800, 1000);
+        // A full block of twice the target raises the price by 1/8
+        assert!(transaction_fee::next_base_gas_price(800, 2000, 1000, 1) == 900, 1001);
+        // An empty block lowers the price by 1/8
+        assert!(transaction_fee::next_base_gas_price(800, 0, 1000, 1) == 700, 1002);
+        assert!(transaction_fee::next_base_gas_price(1, 1001, 1000, 1) == 2, 1003);
+        assert!(transaction_fee::next_base_gas_price(2, 0, 1000, 1) == 1, 1004);
+        assert!(transaction_fee::next_base_gas_price(1, 0, 1000, 1) == 1, 1005);
+    }
+
+    #[test]
+    fun test_fee_block(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        assert!(transaction_fee::base_gas_price(&genesis_ctx) == 1, 1000);
+
+        // The gas used in the fee block adjusts the base gas price when the block interval elapses
+        transaction_fee::on_gas_used_for_test(&mut genesis_ctx, 3000000000);
+        assert!(transaction_fee::base_gas_price(&genesis_ctx) == 1, 1001);
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, 1);
+        transaction_fee::on_gas_used_for_test(&mut genesis_ctx, 0);
+        assert!(transaction_fee::base_gas_price(&genesis_ctx) == 2, 1002);
+        assert!(transaction_fee::get_gas_factor(&genesis_ctx) == 2, 1003);
+
+        // The idle fee blocks lower the base gas price to the minimum
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, 10);
+        transaction_fee::on_gas_used_for_test(&mut genesis_ctx, 0);
+        assert!(transaction_fee::base_gas_price(&genesis_ctx) == 1, 1004);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_max_gas_price(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let treasury_cap = context::take_object<TreasuryCap>(&mut genesis_ctx, &framework, capability::treasury_cap_id());
+        transaction_fee::update_fee_market_config(&mut genesis_ctx, &mut treasury_cap, 10, 1000000000, 1000);
+        assert!(transaction_fee::base_gas_price(&genesis_ctx) == 10, 1000);
+
+        // The gas is never priced higher than the max gas price signed by the sender
+        transaction_fee::set_max_gas_price_for_test(&mut genesis_ctx, 5);
+        assert!(!transaction_fee::is_gas_price_acceptable(&genesis_ctx), 1001);
+        assert!(transaction_fee::get_gas_factor(&genesis_ctx) == 5, 1002);
+        assert!(transaction_fee::calculate_gas(&genesis_ctx, 100) == 500, 1003);
+        object::transfer(treasury_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::transaction_fee)]
+    fun test_invalid_fee_market_config(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let treasury_cap = context::take_object<TreasuryCap>(&mut genesis_ctx, &framework, capability::treasury_cap_id());
+        transaction_fee::update_fee_market_config(&mut genesis_ctx, &mut treasury_cap, 1, 1000000000, 0);
+        object::transfer(treasury_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
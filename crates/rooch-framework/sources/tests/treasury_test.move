@@ -0,0 +1,106 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the fee distribution of the treasury
+module rooch_framework::treasury_test{
+
+    use moveos_std::context;
+    use moveos_std::object;
+    use rooch_framework::account;
+    use rooch_framework::capability::{Self, TreasuryCap};
+    use rooch_framework::gas_coin;
+    use rooch_framework::timestamp;
+    use rooch_framework::transaction_fee;
+    use rooch_framework::treasury;
+
+    #[test]
+    fun test_epoch_transition(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        // The sequencer of the test genesis is @rooch_framework
+        let sequencer_balance = gas_coin::balance(&genesis_ctx, @rooch_framework);
+        let fee = gas_coin::mint_for_test(&mut genesis_ctx, 10000u256);
+        transaction_fee::deposit_fee_for_test(&mut genesis_ctx, fee);
+        assert!(transaction_fee::fee_pool_balance(&genesis_ctx) == 10000, 1000);
+
+        treasury::epoch_transition_for_test(&mut genesis_ctx);
+        assert!(transaction_fee::fee_pool_balance(&genesis_ctx) == 0, 1001);
+        assert!(gas_coin::balance(&genesis_ctx, @rooch_framework) == sequencer_balance + 5000, 1002);
+        assert!(treasury::community_balance(&genesis_ctx) == 3000, 1003);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_epoch_not_ended(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let fee = gas_coin::mint_for_test(&mut genesis_ctx, 10000u256);
+        transaction_fee::deposit_fee_for_test(&mut genesis_ctx, fee);
+
+        // The fees are kept in the fee pool until the epoch interval elapses
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, 1);
+        treasury::on_epoch_transition_for_test(&mut genesis_ctx);
+        assert!(transaction_fee::fee_pool_balance(&genesis_ctx) == 10000, 1000);
+        assert!(treasury::community_balance(&genesis_ctx) == 0, 1001);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_update_epoch_interval(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let treasury_cap = context::take_object<TreasuryCap>(&mut genesis_ctx, &framework, capability::treasury_cap_id());
+        treasury::update_epoch_interval(&mut genesis_ctx, &mut treasury_cap, 1000);
+        let fee = gas_coin::mint_for_test(&mut genesis_ctx, 10000u256);
+        transaction_fee::deposit_fee_for_test(&mut genesis_ctx, fee);
+
+        timestamp::fast_forward_seconds_for_test(&mut genesis_ctx, 1);
+        treasury::on_epoch_transition_for_test(&mut genesis_ctx);
+        assert!(transaction_fee::fee_pool_balance(&genesis_ctx) == 0, 1000);
+        assert!(treasury::community_balance(&genesis_ctx) == 3000, 1001);
+        object::transfer(treasury_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2, location = rooch_framework::treasury)]
+    fun test_invalid_epoch_interval(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let treasury_cap = context::take_object<TreasuryCap>(&mut genesis_ctx, &framework, capability::treasury_cap_id());
+        treasury::update_epoch_interval(&mut genesis_ctx, &mut treasury_cap, 0);
+        object::transfer(treasury_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    fun test_update_distribution_config(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let treasury_cap = context::take_object<TreasuryCap>(&mut genesis_ctx, &framework, capability::treasury_cap_id());
+        treasury::update_distribution_config(&mut genesis_ctx, &mut treasury_cap, 0, 10000);
+        let fee = gas_coin::mint_for_test(&mut genesis_ctx, 10000u256);
+        transaction_fee::deposit_fee_for_test(&mut genesis_ctx, fee);
+
+        treasury::epoch_transition_for_test(&mut genesis_ctx);
+        assert!(treasury::community_balance(&genesis_ctx) == 10000, 1000);
+        let config = treasury::distribution_config(&genesis_ctx);
+        assert!(treasury::config_burn_bps(&config) == 0, 1001);
+
+        treasury::withdraw_community(&mut genesis_ctx, &mut treasury_cap, @0x42, 4000);
+        assert!(treasury::community_balance(&genesis_ctx) == 6000, 1002);
+        assert!(gas_coin::balance(&genesis_ctx, @0x42) == 4000, 1003);
+        object::transfer(treasury_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::treasury)]
+    fun test_invalid_distribution_config(){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let framework = account::create_signer_for_test(@rooch_framework);
+        let treasury_cap = context::take_object<TreasuryCap>(&mut genesis_ctx, &framework, capability::treasury_cap_id());
+        treasury::update_distribution_config(&mut genesis_ctx, &mut treasury_cap, 6000, 5000);
+        object::transfer(treasury_cap, @rooch_framework);
+        moveos_std::context::drop_test_context(genesis_ctx);
+    }
+}
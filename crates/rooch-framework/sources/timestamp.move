@@ -108,6 +108,12 @@ module rooch_framework::timestamp {
         fast_forward_seconds(ctx, timestamp_seconds)
     }
 
+    #[test_only]
+    /// Update the global time to the timestamp of the test environment, see `moveos_std::test_helper`
+    public fun update_global_time_from_test_env(ctx: &mut Context) {
+        update_global_time(ctx, moveos_std::test_helper::timestamp_ms());
+    }
+
     fun fast_forward_seconds(ctx: &mut Context, timestamp_seconds: u64) {
         let now_milliseconds = now_milliseconds(ctx);
         update_global_time(ctx, now_milliseconds + (timestamp_seconds * MILLI_CONVERSION_FACTOR));
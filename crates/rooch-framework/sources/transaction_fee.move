@@ -3,31 +3,282 @@
 
 module rooch_framework::transaction_fee {
 
+    use std::option;
     use moveos_std::context::{Self, Context};
     use moveos_std::object::{Self, Object};
     use rooch_framework::coin_store::{Self, CoinStore};
-    use rooch_framework::coin::Coin;
-    use rooch_framework::gas_coin::{GasCoin};
+    use moveos_std::event;
+    use rooch_framework::capability::TreasuryCap;
+    use rooch_framework::coin::{Self, Coin};
+    use rooch_framework::gas_coin::{Self, GasCoin};
+    use rooch_framework::timestamp;
 
     friend rooch_framework::genesis;
     friend rooch_framework::transaction_validator;
+    friend rooch_framework::treasury;
+
+    const ErrorInvalidFeeMarketConfig: u64 = 1;
+
+    /// The gas used by a fee block at which the base gas price stays unchanged
+    const DEFAULT_TARGET_BLOCK_GAS: u64 = 1000000000;
+    /// The base gas price never drops below it, the gas is priced 1:1 as before the fee market
+    const DEFAULT_MIN_BASE_GAS_PRICE: u64 = 1;
+    /// The duration of a fee block in milliseconds
+    const DEFAULT_BLOCK_INTERVAL: u64 = 1000;
+    /// The base gas price changes at most 1/8 per fee block, as EIP-1559
+    const BASE_GAS_PRICE_CHANGE_DENOMINATOR: u64 = 8;
+    /// The empty fee blocks lowering the base gas price at most when the transactions resume after an idle period
+    const MAX_EMPTY_BLOCKS: u64 = 64;
 
     struct TransactionFeePool has key {
         fee: Object<CoinStore<GasCoin>>,
     }
 
+    /// The EIP-1559 style fee market. The fee blocks are derived from the on-chain timestamp, the first transaction
+    /// executed after the block interval elapses ends the fee block and adjusts the base gas price by the gas used of
+    /// the block in its system post_execute, so every node prices the transactions by the same base gas price.
+    struct FeeMarket has key {
+        base_gas_price: u64,
+        min_base_gas_price: u64,
+        target_block_gas: u64,
+        block_interval: u64,
+        /// The on-chain time in milliseconds when the current fee block starts
+        block_start_time: u64,
+        /// The gas used by the transactions of the current fee block
+        block_gas_used: u64,
+        last_block_gas_used: u64,
+        /// The number of the ended fee blocks
+        blocks: u64,
+    }
+
+    /// The status of the fee market, for the RPC queries
+    struct FeeMarketStatus has copy, drop {
+        base_gas_price: u64,
+        min_base_gas_price: u64,
+        target_block_gas: u64,
+        block_interval: u64,
+        block_start_time: u64,
+        block_gas_used: u64,
+        last_block_gas_used: u64,
+        blocks: u64,
+    }
+
+    /// The max gas price signed by the sender of the transaction, the transaction is rejected if the base gas price
+    /// is higher at the validation, and the gas is never priced higher than it at the execution.
+    struct MaxGasPrice has copy, store, drop {
+        max_gas_price: u64,
+    }
+
+    struct FeeMarketConfigUpdatedEvent has copy, drop {
+        min_base_gas_price: u64,
+        target_block_gas: u64,
+        block_interval: u64,
+    }
+
+    /// Emitted when a failed transaction pays the fee, the changes of the transaction are discarded.
+    struct FailedTransactionFeeEvent has copy, drop {
+        /// The account that pays the gas
+        account: address,
+        gas_used: u64,
+        /// The fee actually charged, it may be less than the fee of the gas used if the balance is insufficient
+        fee: u256,
+    }
+
     public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer)  {
         let fee_store = coin_store::create_coin_store<GasCoin>(ctx);
         let obj = context::new_named_object(ctx, TransactionFeePool{
             fee: fee_store,
         });
         object::transfer_extend(obj, @rooch_framework);
+        // The timestamp is not initialized yet, the first transaction ends the genesis fee block
+        create_fee_market(ctx, 0);
     }
 
-    /// Returns the gas factor of gas.
-    public fun get_gas_factor(_ctx: &Context): u64 {
-        //TODO we should provide a algorithm to cordanate the gas factor based on the network throughput
-        return 1
+    fun create_fee_market(ctx: &mut Context, block_start_time: u64) {
+        let obj = context::new_named_object(ctx, FeeMarket{
+            base_gas_price: DEFAULT_MIN_BASE_GAS_PRICE,
+            min_base_gas_price: DEFAULT_MIN_BASE_GAS_PRICE,
+            target_block_gas: DEFAULT_TARGET_BLOCK_GAS,
+            block_interval: DEFAULT_BLOCK_INTERVAL,
+            block_start_time,
+            block_gas_used: 0,
+            last_block_gas_used: 0,
+            blocks: 0,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun exists_fee_market(ctx: &Context): bool {
+        context::exists_object<FeeMarket>(ctx, object::named_object_id<FeeMarket>())
+    }
+
+    fun borrow_fee_market(ctx: &Context): &FeeMarket {
+        let object_id = object::named_object_id<FeeMarket>();
+        object::borrow(context::borrow_object<FeeMarket>(ctx, object_id))
+    }
+
+    fun borrow_mut_fee_market(ctx: &mut Context): &mut FeeMarket {
+        let object_id = object::named_object_id<FeeMarket>();
+        object::borrow_mut(context::borrow_mut_object_extend<FeeMarket>(ctx, object_id))
+    }
+
+    /// The base gas price of the current fee block, or 1 before the framework is upgraded to include the fee market
+    public fun base_gas_price(ctx: &Context): u64 {
+        if (exists_fee_market(ctx)) {
+            borrow_fee_market(ctx).base_gas_price
+        } else {
+            DEFAULT_MIN_BASE_GAS_PRICE
+        }
+    }
+
+    /// The max gas price signed by the sender of the transaction, none if the sender does not limit it
+    public fun max_gas_price(ctx: &Context): option::Option<u64> {
+        let max_gas_price = context::get<MaxGasPrice>(ctx);
+        if (option::is_some(&max_gas_price)) {
+            option::some(option::extract(&mut max_gas_price).max_gas_price)
+        } else {
+            option::none()
+        }
+    }
+
+    /// Whether the base gas price does not exceed the max gas price signed by the sender
+    public fun is_gas_price_acceptable(ctx: &Context): bool {
+        let max_gas_price = max_gas_price(ctx);
+        option::is_none(&max_gas_price) || base_gas_price(ctx) <= option::extract(&mut max_gas_price)
+    }
+
+    /// Returns the gas factor of gas, it is the base gas price of the fee market capped by the max gas price
+    /// signed by the sender of the transaction.
+    public fun get_gas_factor(ctx: &Context): u64 {
+        let base_gas_price = base_gas_price(ctx);
+        let max_gas_price = max_gas_price(ctx);
+        if (option::is_some(&max_gas_price)) {
+            let max_gas_price = option::extract(&mut max_gas_price);
+            if (max_gas_price < base_gas_price) {
+                return max_gas_price
+            }
+        };
+        base_gas_price
+    }
+
+    public fun fee_market_status(ctx: &Context): FeeMarketStatus {
+        if (!exists_fee_market(ctx)) {
+            return FeeMarketStatus{
+                base_gas_price: DEFAULT_MIN_BASE_GAS_PRICE,
+                min_base_gas_price: DEFAULT_MIN_BASE_GAS_PRICE,
+                target_block_gas: DEFAULT_TARGET_BLOCK_GAS,
+                block_interval: DEFAULT_BLOCK_INTERVAL,
+                block_start_time: 0,
+                block_gas_used: 0,
+                last_block_gas_used: 0,
+                blocks: 0,
+            }
+        };
+        let fee_market = borrow_fee_market(ctx);
+        FeeMarketStatus{
+            base_gas_price: fee_market.base_gas_price,
+            min_base_gas_price: fee_market.min_base_gas_price,
+            target_block_gas: fee_market.target_block_gas,
+            block_interval: fee_market.block_interval,
+            block_start_time: fee_market.block_start_time,
+            block_gas_used: fee_market.block_gas_used,
+            last_block_gas_used: fee_market.last_block_gas_used,
+            blocks: fee_market.blocks,
+        }
+    }
+
+    /// The base gas price of the next fee block, it rises when the block uses more gas than the target
+    /// and falls when the block uses less, proportional to the deviation from the target.
+    public fun next_base_gas_price(base_gas_price: u64, block_gas_used: u64, target_block_gas: u64, min_base_gas_price: u64): u64 {
+        let target = (target_block_gas as u128);
+        let base = (base_gas_price as u128);
+        let used = (block_gas_used as u128);
+        let next = if (used > target) {
+            let delta = base * (used - target) / target / (BASE_GAS_PRICE_CHANGE_DENOMINATOR as u128);
+            // The base gas price rises at least 1 so it can leave the minimum price of 1
+            if (delta == 0) {
+                delta = 1;
+            };
+            let max_u64 = 18446744073709551615u128;
+            if (base + delta > max_u64) { max_u64 } else { base + delta }
+        } else if (used < target) {
+            let delta = base * (target - used) / target / (BASE_GAS_PRICE_CHANGE_DENOMINATOR as u128);
+            // The base gas price falls at least 1 so it can return to the minimum price
+            if (delta == 0) {
+                delta = 1;
+            };
+            if (delta >= base) { 0 } else { base - delta }
+        } else {
+            base
+        };
+        if (next < (min_base_gas_price as u128)) {
+            min_base_gas_price
+        } else {
+            (next as u64)
+        }
+    }
+
+    /// Record the gas used by the transaction in the current fee block, the fee block ends if the block interval
+    /// elapses at the on-chain time. It is called in the system post_execute after the gas is charged, so it never aborts.
+    public(friend) fun on_gas_used(ctx: &mut Context, gas_used: u64) {
+        let now = timestamp::now_milliseconds(ctx);
+        // The fee market is created by the first transaction after the framework is upgraded to include it
+        if (!exists_fee_market(ctx)) {
+            create_fee_market(ctx, now);
+        };
+        let fee_market = borrow_mut_fee_market(ctx);
+        if (now >= fee_market.block_start_time + fee_market.block_interval) {
+            let elapsed_blocks = (now - fee_market.block_start_time) / fee_market.block_interval;
+            let base_gas_price = next_base_gas_price(fee_market.base_gas_price, fee_market.block_gas_used, fee_market.target_block_gas, fee_market.min_base_gas_price);
+            // The fee blocks without transactions lower the base gas price too, the network is idle
+            let empty_blocks = elapsed_blocks - 1;
+            if (empty_blocks > MAX_EMPTY_BLOCKS) {
+                empty_blocks = MAX_EMPTY_BLOCKS;
+            };
+            while (empty_blocks > 0 && base_gas_price > fee_market.min_base_gas_price) {
+                base_gas_price = next_base_gas_price(base_gas_price, 0, fee_market.target_block_gas, fee_market.min_base_gas_price);
+                empty_blocks = empty_blocks - 1;
+            };
+            fee_market.base_gas_price = base_gas_price;
+            fee_market.last_block_gas_used = fee_market.block_gas_used;
+            fee_market.block_gas_used = 0;
+            fee_market.block_start_time = fee_market.block_start_time + elapsed_blocks * fee_market.block_interval;
+            fee_market.blocks = fee_market.blocks + elapsed_blocks;
+        };
+        let block_gas_used = (fee_market.block_gas_used as u128) + (gas_used as u128);
+        fee_market.block_gas_used = if (block_gas_used > 18446744073709551615u128) {
+            18446744073709551615
+        } else {
+            (block_gas_used as u64)
+        };
+    }
+
+    /// Update the parameters of the fee market, they take effect at the end of the current fee block.
+    /// Only the owner of the TreasuryCap can call this function.
+    public entry fun update_fee_market_config(
+        ctx: &mut Context,
+        _treasury_cap: &mut Object<TreasuryCap>,
+        min_base_gas_price: u64,
+        target_block_gas: u64,
+        block_interval: u64,
+    ) {
+        assert!(min_base_gas_price > 0 && target_block_gas > 0 && block_interval > 0, ErrorInvalidFeeMarketConfig);
+        if (!exists_fee_market(ctx)) {
+            let now = timestamp::now_milliseconds(ctx);
+            create_fee_market(ctx, now);
+        };
+        let fee_market = borrow_mut_fee_market(ctx);
+        fee_market.min_base_gas_price = min_base_gas_price;
+        fee_market.target_block_gas = target_block_gas;
+        fee_market.block_interval = block_interval;
+        if (fee_market.base_gas_price < min_base_gas_price) {
+            fee_market.base_gas_price = min_base_gas_price;
+        };
+        event::emit(FeeMarketConfigUpdatedEvent{
+            min_base_gas_price,
+            target_block_gas,
+            block_interval,
+        });
     }
 
     public fun calculate_gas(ctx: &Context, gas_amount: u64): u256{
@@ -40,4 +291,46 @@ module rooch_framework::transaction_fee {
         let pool = object::borrow_mut(pool_object);
         coin_store::deposit<GasCoin>(&mut pool.fee, gas_coin);
     }
+
+    /// The fees accumulated in the pool since the last distribution
+    public fun fee_pool_balance(ctx: &Context): u256 {
+        let object_id = object::named_object_id<TransactionFeePool>();
+        let pool_object = context::borrow_object<TransactionFeePool>(ctx, object_id);
+        coin_store::balance(&object::borrow(pool_object).fee)
+    }
+
+    /// Withdraw all the fees of the pool for the distribution
+    public(friend) fun withdraw_fee(ctx: &mut Context): Coin<GasCoin> {
+        let object_id = object::named_object_id<TransactionFeePool>();
+        let pool_object = context::borrow_mut_object_extend<TransactionFeePool>(ctx, object_id);
+        let pool = object::borrow_mut(pool_object);
+        let balance = coin_store::balance(&pool.fee);
+        coin_store::withdraw(&mut pool.fee, balance)
+    }
+
+    #[test_only]
+    public fun deposit_fee_for_test(ctx: &mut Context, gas_coin: Coin<GasCoin>) {
+        deposit_fee(ctx, gas_coin);
+    }
+
+    #[test_only]
+    public fun on_gas_used_for_test(ctx: &mut Context, gas_used: u64) {
+        on_gas_used(ctx, gas_used);
+    }
+
+    #[test_only]
+    public fun set_max_gas_price_for_test(ctx: &mut Context, max_gas_price: u64) {
+        context::add(ctx, MaxGasPrice{ max_gas_price });
+    }
+
+    /// Charge the fee of a failed transaction, called in the system post_execute after the changes are discarded.
+    /// The failed transactions still consume the sequencer and executor capacity, so they should not be free.
+    /// The system post_execute must not abort, so the fee is capped by the balance of the gas payment account.
+    public(friend) fun charge_failed_tx_fee(ctx: &mut Context, account: address, gas_used: u64) {
+        let gas = calculate_gas(ctx, gas_used);
+        let gas_coin = gas_coin::deduct_gas_at_most(ctx, account, gas);
+        let fee = coin::value(&gas_coin);
+        deposit_fee(ctx, gas_coin);
+        event::emit(FailedTransactionFeeEvent { account, gas_used, fee });
+    }
 }
@@ -15,6 +15,13 @@ module rooch_framework::transaction_validator {
     use rooch_framework::chain_id;
     use rooch_framework::transaction_fee;
     use rooch_framework::gas_coin;
+    use rooch_framework::gas_sponsor;
+    use rooch_framework::free_gas_quota;
+    use rooch_framework::storage_rent;
+    use rooch_framework::treasury;
+    use rooch_framework::chain_pause;
+    use rooch_framework::module_publish_policy;
+    use rooch_framework::module_blocklist;
 
     const MAX_U64: u128 = 18446744073709551615;
 
@@ -33,9 +40,17 @@ module rooch_framework::transaction_validator {
     const ErrorValidateTransactionExpired: u64 = 1005;
     const ErrorValidateBadChainId: u64 = 1006;
     const ErrorValidateSequenceNumberTooBig: u64 = 1007;
+    /// The chain is paused, only the system transactions are accepted
+    const ErrorValidateChainPaused: u64 = 1008;
+    /// The sender is not allowed to publish modules by the module publishing policy
+    const ErrorValidateModulePublishNotAllowed: u64 = 1009;
 
     /// The authenticator's auth validator id is not installed to the sender's account
     const ErrorValidateNotInstalledAuthValidator: u64 = 1010;
+    /// The function called by the transaction, or a function reachable from it, is blocked by the module blocklist
+    const ErrorValidateModuleBlocked: u64 = 1011;
+    /// The base gas price of the fee market exceeds the max gas price signed by the sender
+    const ErrorValidateGasPriceTooHigh: u64 = 1012;
 
 
     /// This function is for Rooch to validate the transaction sender's authenticator.
@@ -53,6 +68,12 @@ module rooch_framework::transaction_validator {
             ErrorValidateBadChainId
         );
 
+        // === validate the chain is not paused ===
+        assert!(
+            chain_pause::is_tx_allowed(ctx, context::sender(ctx)),
+            ErrorValidateChainPaused
+        );
+
         // === validate the sequence number ===
         let tx_sequence_number = context::sequence_number(ctx);
         assert!(
@@ -78,13 +99,35 @@ module rooch_framework::transaction_validator {
 
         let sender = context::sender(ctx);
 
+        // === validate the module publishing policy ===
+        if (module_publish_policy::is_module_publish(&context::tx_meta(ctx))) {
+            assert!(
+                module_publish_policy::is_allowed(ctx, sender),
+                ErrorValidateModulePublishNotAllowed
+            );
+        };
+
+        // === validate the called function is not blocked ===
+        assert!(
+            !module_blocklist::is_tx_blocked(ctx, &context::tx_meta(ctx)),
+            ErrorValidateModuleBlocked
+        );
+
         // === validate gas ===
+        assert!(
+            transaction_fee::is_gas_price_acceptable(ctx),
+            ErrorValidateGasPriceTooHigh
+        );
+        // The max gas amount is priced by the base gas price of the fee market
         let max_gas_amount = context::max_gas_amount(ctx);
         let gas = transaction_fee::calculate_gas(ctx, max_gas_amount);
 
-        // We skip the gas check for the new account, for avoid break the current testcase
-        // TODO remove the skip afater we provide the gas faucet and update all testcase
-        if(account::exists_at(ctx, sender)){
+        // The gas of a sponsored transaction is paid by the sponsor, and a free transaction pays no gas
+        if (option::is_some(&gas_sponsor::tx_sponsor(ctx))) {
+            gas_sponsor::validate(ctx, gas);
+        } else if(!free_gas_quota::is_free_tx(ctx) && account::exists_at(ctx, sender)){
+            // We skip the gas check for the new account, for avoid break the current testcase
+            // TODO remove the skip afater we provide the gas faucet and update all testcase
             let gas_balance = gas_coin::balance(ctx, sender);
             assert!(
                 gas_balance >= gas,
@@ -121,6 +164,10 @@ module rooch_framework::transaction_validator {
         ctx: &mut Context,
     ) {
         let sender = context::sender(ctx);
+        // The chain may be paused after the transaction is validated
+        assert!(chain_pause::is_tx_allowed(ctx, sender), ErrorValidateChainPaused);
+        // The publish policy of the modules published by the modules or the scripts of the transaction
+        module_publish_policy::add_tx_permission(ctx);
         //Auto create account if not exist
         if (!account::exists_at(ctx, sender)) {
             account::create_account(ctx, sender);
@@ -160,11 +207,35 @@ module rooch_framework::transaction_validator {
         // Increment sequence number
         account::increment_sequence_number(ctx);
 
+        // Record the sender is active, for the storage rent reclamation
+        let sender = context::sender(ctx);
+        storage_rent::record_activity(ctx, sender);
+
         let tx_result = context::tx_result(ctx);
         let gas_payment_account = context::tx_gas_payment_account(ctx);
         let gas_used = tx_result::gas_used(&tx_result);
-        let gas = transaction_fee::calculate_gas(ctx, gas_used);
-        let gas_coin = gas_coin::deduct_gas(ctx, gas_payment_account, gas);
-        transaction_fee::deposit_fee(ctx, gas_coin);
+        let sponsor = gas_sponsor::tx_sponsor(ctx);
+        if (option::is_none(&sponsor) && gas_payment_account == sender && free_gas_quota::is_free_tx(ctx)) {
+            // The free transaction consumes the free gas quota of the sender instead of paying the gas
+            free_gas_quota::consume(ctx, sender);
+        } else if (tx_result::is_executed(&tx_result)) {
+            let gas = transaction_fee::calculate_gas(ctx, gas_used);
+            let gas_coin = gas_coin::deduct_gas(ctx, gas_payment_account, gas);
+            transaction_fee::deposit_fee(ctx, gas_coin);
+        } else {
+            transaction_fee::charge_failed_tx_fee(ctx, gas_payment_account, gas_used);
+        };
+
+        // The sponsor pays the gas, the allowance of the sender is consumed
+        if (option::is_some(&sponsor) && option::some(gas_payment_account) == sponsor) {
+            let gas = transaction_fee::calculate_gas(ctx, gas_used);
+            gas_sponsor::consume_allowance(ctx, gas_payment_account, gas);
+        };
+
+        // The gas used by every transaction, including the free ones, adjusts the base gas price of the next fee block
+        transaction_fee::on_gas_used(ctx, gas_used);
+
+        // Distribute the fees of the ended epoch, including the fee of this transaction
+        treasury::on_epoch_transition(ctx);
     }
 }
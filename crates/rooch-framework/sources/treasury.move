@@ -0,0 +1,248 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The treasury distributes the transaction fees accumulated in the fee pool at every epoch transition.
+/// The fees are split by the configured percentages in basis points to the sequencer, the community treasury,
+/// and the rest is burned. The epoch is derived from the on-chain timestamp, the first transaction executed after
+/// the epoch interval elapses ends the epoch and distributes the fees in its system post_execute, so every node
+/// distributes the fees at the same transaction.
+module rooch_framework::treasury {
+
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object};
+    use moveos_std::event;
+    use rooch_framework::coin;
+    use rooch_framework::coin_store::{Self, CoinStore};
+    use rooch_framework::account_coin_store;
+    use rooch_framework::capability::TreasuryCap;
+    use rooch_framework::gas_coin::{Self, GasCoin};
+    use rooch_framework::onchain_config;
+    use rooch_framework::timestamp;
+    use rooch_framework::transaction_fee;
+
+    friend rooch_framework::genesis;
+    friend rooch_framework::transaction_validator;
+
+    const ErrorInvalidDistributionConfig: u64 = 1;
+    const ErrorInvalidEpochInterval: u64 = 2;
+
+    const BASIS_POINTS_DENOMINATOR: u64 = 10000;
+    const DEFAULT_SEQUENCER_BPS: u64 = 5000;
+    const DEFAULT_COMMUNITY_BPS: u64 = 3000;
+    /// One day in milliseconds
+    const DEFAULT_EPOCH_INTERVAL: u64 = 86400000;
+
+    /// The percentages of the fees in basis points, the rest of the fees is burned
+    struct DistributionConfig has store, copy, drop {
+        sequencer_bps: u64,
+        community_bps: u64,
+    }
+
+    struct Treasury has key {
+        community: Object<CoinStore<GasCoin>>,
+        config: DistributionConfig,
+        /// The number of the epochs whose fees are distributed
+        epoch: u64,
+        /// The duration of an epoch in milliseconds
+        epoch_interval: u64,
+        /// The on-chain time in milliseconds when the current epoch starts
+        epoch_start_time: u64,
+        total_to_sequencer: u256,
+        total_to_community: u256,
+        total_burned: u256,
+    }
+
+    /// The accounting of the treasury, for the RPC queries
+    struct TreasuryStatus has copy, drop {
+        epoch: u64,
+        epoch_interval: u64,
+        epoch_start_time: u64,
+        config: DistributionConfig,
+        /// The fees accumulated in the current epoch, they are distributed at the next epoch transition
+        pending_fee: u256,
+        community_balance: u256,
+        total_to_sequencer: u256,
+        total_to_community: u256,
+        total_burned: u256,
+    }
+
+    struct FeeDistributedEvent has copy, drop {
+        epoch: u64,
+        fee: u256,
+        sequencer: address,
+        sequencer_amount: u256,
+        community_amount: u256,
+        burned_amount: u256,
+    }
+
+    struct DistributionConfigUpdatedEvent has copy, drop {
+        sequencer_bps: u64,
+        community_bps: u64,
+    }
+
+    struct EpochIntervalUpdatedEvent has copy, drop {
+        epoch_interval: u64,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let community = coin_store::create_coin_store<GasCoin>(ctx);
+        let obj = context::new_named_object(ctx, Treasury{
+            community,
+            config: DistributionConfig{
+                sequencer_bps: DEFAULT_SEQUENCER_BPS,
+                community_bps: DEFAULT_COMMUNITY_BPS,
+            },
+            epoch: 0,
+            epoch_interval: DEFAULT_EPOCH_INTERVAL,
+            epoch_start_time: timestamp::now_milliseconds(ctx),
+            total_to_sequencer: 0,
+            total_to_community: 0,
+            total_burned: 0,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow(ctx: &Context): &Treasury {
+        let object_id = object::named_object_id<Treasury>();
+        object::borrow(context::borrow_object<Treasury>(ctx, object_id))
+    }
+
+    fun borrow_mut(ctx: &mut Context): &mut Treasury {
+        let object_id = object::named_object_id<Treasury>();
+        object::borrow_mut(context::borrow_mut_object_extend<Treasury>(ctx, object_id))
+    }
+
+    public fun distribution_config(ctx: &Context): DistributionConfig {
+        borrow(ctx).config
+    }
+
+    public fun config_sequencer_bps(self: &DistributionConfig): u64 {
+        self.sequencer_bps
+    }
+
+    public fun config_community_bps(self: &DistributionConfig): u64 {
+        self.community_bps
+    }
+
+    public fun config_burn_bps(self: &DistributionConfig): u64 {
+        BASIS_POINTS_DENOMINATOR - self.sequencer_bps - self.community_bps
+    }
+
+    public fun community_balance(ctx: &Context): u256 {
+        coin_store::balance(&borrow(ctx).community)
+    }
+
+    public fun status(ctx: &Context): TreasuryStatus {
+        let treasury = borrow(ctx);
+        TreasuryStatus{
+            epoch: treasury.epoch,
+            epoch_interval: treasury.epoch_interval,
+            epoch_start_time: treasury.epoch_start_time,
+            config: treasury.config,
+            pending_fee: transaction_fee::fee_pool_balance(ctx),
+            community_balance: coin_store::balance(&treasury.community),
+            total_to_sequencer: treasury.total_to_sequencer,
+            total_to_community: treasury.total_to_community,
+            total_burned: treasury.total_burned,
+        }
+    }
+
+    /// Update the percentages of the fee distribution, it takes effect at the next epoch transition.
+    /// Only the owner of the TreasuryCap can call this function.
+    public entry fun update_distribution_config(
+        ctx: &mut Context,
+        _treasury_cap: &mut Object<TreasuryCap>,
+        sequencer_bps: u64,
+        community_bps: u64,
+    ) {
+        assert!(sequencer_bps + community_bps <= BASIS_POINTS_DENOMINATOR, ErrorInvalidDistributionConfig);
+        borrow_mut(ctx).config = DistributionConfig{
+            sequencer_bps,
+            community_bps,
+        };
+        event::emit(DistributionConfigUpdatedEvent{
+            sequencer_bps,
+            community_bps,
+        });
+    }
+
+    /// Update the duration of the epochs in milliseconds, the current epoch ends when the new interval elapses.
+    /// Only the owner of the TreasuryCap can call this function.
+    public entry fun update_epoch_interval(
+        ctx: &mut Context,
+        _treasury_cap: &mut Object<TreasuryCap>,
+        epoch_interval: u64,
+    ) {
+        assert!(epoch_interval > 0, ErrorInvalidEpochInterval);
+        borrow_mut(ctx).epoch_interval = epoch_interval;
+        event::emit(EpochIntervalUpdatedEvent{
+            epoch_interval,
+        });
+    }
+
+    /// Withdraw the gas coin from the community treasury, only the owner of the TreasuryCap can call this function.
+    public entry fun withdraw_community(
+        ctx: &mut Context,
+        _treasury_cap: &mut Object<TreasuryCap>,
+        to: address,
+        amount: u256,
+    ) {
+        let gas_coin = coin_store::withdraw(&mut borrow_mut(ctx).community, amount);
+        account_coin_store::deposit(ctx, to, gas_coin);
+    }
+
+    /// Distribute the fees of the epoch if the epoch interval elapses at the on-chain time,
+    /// it is called in the system post_execute, so it never aborts.
+    public(friend) fun on_epoch_transition(ctx: &mut Context) {
+        // The treasury does not exist before the framework is upgraded to include it
+        if (!context::exists_object<Treasury>(ctx, object::named_object_id<Treasury>())) {
+            return
+        };
+        let now = timestamp::now_milliseconds(ctx);
+        let treasury = borrow(ctx);
+        if (now < treasury.epoch_start_time + treasury.epoch_interval) {
+            return
+        };
+        let fee_coin = transaction_fee::withdraw_fee(ctx);
+        let fee = coin::value(&fee_coin);
+        let config = borrow(ctx).config;
+        let sequencer = onchain_config::sequencer(ctx);
+
+        let sequencer_amount = fee * (config.sequencer_bps as u256) / (BASIS_POINTS_DENOMINATOR as u256);
+        let community_amount = fee * (config.community_bps as u256) / (BASIS_POINTS_DENOMINATOR as u256);
+        let burned_amount = fee - sequencer_amount - community_amount;
+
+        account_coin_store::deposit(ctx, sequencer, coin::extract(&mut fee_coin, sequencer_amount));
+        let community_coin = coin::extract(&mut fee_coin, community_amount);
+        let treasury = borrow_mut(ctx);
+        coin_store::deposit(&mut treasury.community, community_coin);
+        treasury.epoch = treasury.epoch + 1;
+        treasury.epoch_start_time = now;
+        treasury.total_to_sequencer = treasury.total_to_sequencer + sequencer_amount;
+        treasury.total_to_community = treasury.total_to_community + community_amount;
+        treasury.total_burned = treasury.total_burned + burned_amount;
+        let epoch = treasury.epoch;
+        gas_coin::burn(ctx, fee_coin);
+
+        event::emit(FeeDistributedEvent{
+            epoch,
+            fee,
+            sequencer,
+            sequencer_amount,
+            community_amount,
+            burned_amount,
+        });
+    }
+
+    #[test_only]
+    public fun on_epoch_transition_for_test(ctx: &mut Context) {
+        on_epoch_transition(ctx);
+    }
+
+    #[test_only]
+    public fun epoch_transition_for_test(ctx: &mut Context) {
+        let epoch_interval = borrow(ctx).epoch_interval;
+        timestamp::fast_forward_seconds_for_test(ctx, epoch_interval / 1000);
+        on_epoch_transition(ctx);
+    }
+}
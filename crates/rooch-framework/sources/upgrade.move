@@ -3,14 +3,14 @@
 
 module rooch_framework::upgrade {
 
-    use moveos_std::signer;
     use moveos_std::event;
     use moveos_std::context::{Self, Context};
+    use moveos_std::object::Object;
     use rooch_framework::onchain_config;
+    use rooch_framework::framework_versions;
+    use rooch_framework::capability::UpgradeCap;
     use rooch_framework::account::create_signer;
 
-    const ErrorNotSequencer: u64 = 1;
-
     const MoveStdAccount: address = @0x1;
     const MoveosStdAccount: address = @0x2;
     const RoochFrameworkAccount: address = @rooch_framework;
@@ -21,15 +21,13 @@ module rooch_framework::upgrade {
         version: u64,
     }
 
-    entry fun upgrade_entry(ctx: &mut Context, account: &signer, 
+    /// Upgrade the framework packages, only the owner of the UpgradeCap can call this function.
+    entry fun upgrade_entry(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>,
         move_std_bundles: vector<vector<u8>>,
         moveos_std_bundles: vector<vector<u8>>,
         rooch_framework_bundles: vector<vector<u8>>,
         bitcoin_move_bundles: vector<vector<u8>>,
     ) {
-        let sender_address = signer::address_of(account);
-        assert!(sender_address == onchain_config::sequencer(ctx), ErrorNotSequencer);
-
         let std_signer = create_signer(MoveStdAccount);
         context::publish_modules_entry(ctx, &std_signer, move_std_bundles);
 
@@ -45,4 +43,21 @@ module rooch_framework::upgrade {
         onchain_config::update_framework_version(ctx);
         event::emit<FrameworkUpgradeEvent>(FrameworkUpgradeEvent { version: onchain_config::framework_version(ctx) });
     }
+
+    /// Publish a new version of the framework at a framework version address, the framework at `@rooch_framework`
+    /// is kept, so the packages built against it still work. The new version is not activated.
+    entry fun publish_framework_version_entry(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>,
+        version: u64,
+        framework_address: address,
+        rooch_framework_bundles: vector<vector<u8>>,
+    ) {
+        framework_versions::register_version(ctx, version, framework_address);
+        let framework_signer = create_signer(framework_address);
+        context::publish_modules_entry(ctx, &framework_signer, rooch_framework_bundles);
+    }
+
+    /// Route the system functions of the executor to the framework of `version`.
+    entry fun activate_framework_version_entry(ctx: &mut Context, _upgrade_cap: &mut Object<UpgradeCap>, version: u64) {
+        framework_versions::activate_version(ctx, version);
+    }
 }
@@ -1,6 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+// The gas schedule derive refers to the traits by the crate name, so it works in this crate too
+extern crate self as rooch_framework;
+
 pub mod natives;
 
 pub use rooch_types::addresses::*;
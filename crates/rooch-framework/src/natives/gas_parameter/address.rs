@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use moveos_stdlib::natives::moveos_stdlib::address::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "address", [
+    [.from_bytes.base, optional "from_bytes.base", 4 * MUL],
+    [.to_u256.base, optional "to_u256.base", 4 * MUL],
+    [.from_u256.base, optional "from_u256.base", 4 * MUL],
+]);
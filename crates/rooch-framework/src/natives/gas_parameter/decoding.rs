@@ -6,5 +6,9 @@ use crate::natives::rooch_framework::crypto::decoding::GasParameters;
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "decoding", [
     [.base58.base, "base58.base", (5 + 1) * MUL],
+    [.base58.per_byte, "base58.per_byte", MUL],
+    [.base58.failure, "base58.failure", (5 + 1) * MUL],
     [.base58check.base, "base58check.base", (5 + 1) * MUL],
+    [.base58check.per_byte, "base58check.per_byte", MUL],
+    [.base58check.failure, "base58check.failure", (5 + 1) * MUL],
 ]);
@@ -0,0 +1,10 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use moveos_stdlib::natives::moveos_stdlib::dispatch::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "dispatch", [
+    [.request_dispatch.base, optional "request_dispatch.base", (5 + 1) * MUL],
+    [.request_dispatch.per_byte, optional "request_dispatch.per_byte", (5 + 1) * MUL],
+]);
@@ -6,6 +6,12 @@ use crate::natives::rooch_framework::crypto::ecdsa_k1_recoverable::GasParameters
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "ecdsa_k1_recoverable", [
     [.verify.base, "verify.base", (5 + 1) * MUL],
+    [.verify.per_byte, "verify.per_byte", MUL],
+    [.verify.failure, "verify.failure", (5 + 1) * MUL],
     [.ecrecover.base, "ecrecover.base", (5 + 1) * MUL],
+    [.ecrecover.per_byte, "ecrecover.per_byte", MUL],
+    [.ecrecover.failure, "ecrecover.failure", (5 + 1) * MUL],
     [.decompress_pubkey.base, "decompress_pubkey.base", (5 + 1) * MUL],
+    [.decompress_pubkey.per_byte, "decompress_pubkey.per_byte", MUL],
+    [.decompress_pubkey.failure, "decompress_pubkey.failure", (5 + 1) * MUL],
 ]);
@@ -6,8 +6,19 @@ use crate::natives::rooch_framework::crypto::encoding::GasParameters;
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "encoding", [
     [.base58.base, "base58.base", (5 + 1) * MUL],
+    [.base58.per_byte, "base58.per_byte", MUL],
     [.base58check.base, "base58check.base", (5 + 1) * MUL],
+    [.base58check.per_byte, "base58check.per_byte", MUL],
     [.bech32.base, "bech32.base", (5 + 1) * MUL],
+    [.bech32.per_byte, "bech32.per_byte", MUL],
+    [.bech32.failure, "bech32.failure", (5 + 1) * MUL],
     [.p2pkh.base, "p2pkh.base", (5 + 1) * MUL],
+    [.p2pkh.per_byte, "p2pkh.per_byte", MUL],
+    [.p2pkh.failure, "p2pkh.failure", (5 + 1) * MUL],
     [.p2sh.base, "p2sh.base", (5 + 1) * MUL],
+    [.p2sh.per_byte, "p2sh.per_byte", MUL],
+    [.p2sh.failure, "p2sh.failure", (5 + 1) * MUL],
+    [.taproot_output_key.base, "taproot_output_key.base", (5 + 1) * MUL],
+    [.taproot_output_key.per_byte, "taproot_output_key.per_byte", MUL],
+    [.taproot_output_key.failure, "taproot_output_key.failure", (5 + 1) * MUL],
 ]);
@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use moveos_stdlib::natives::moveos_stdlib::fixed_point64::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "fixed_point64", [
+    [.sqrt_raw.base, optional "sqrt_raw.base", 12 * MUL],
+    [.pow_raw.base, optional "pow_raw.base", 16 * MUL],
+    [.log2_plus_64_raw.base, optional "log2_plus_64_raw.base", 24 * MUL],
+]);
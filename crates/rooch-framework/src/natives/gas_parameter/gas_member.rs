@@ -1,14 +1,97 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The entries of an on-chain gas schedule which do not match the gas parameters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasScheduleError {
+    /// The required entries missing from the gas schedule
+    pub missing: Vec<String>,
+    /// The entries of the gas schedule which are not used by the gas parameters
+    pub unknown: Vec<String>,
+    /// The keys which appear more than once with the same value
+    pub duplicate: Vec<String>,
+    /// The keys which appear more than once with different values, the gas schedule is ambiguous
+    pub conflicting: Vec<GasScheduleConflict>,
+}
+
+/// A key of the gas schedule with different values, the values are in the order of the entries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasScheduleConflict {
+    pub key: String,
+    pub values: Vec<u64>,
+}
+
+impl fmt::Display for GasScheduleConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            self.key,
+            self.values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(" vs ")
+        )
+    }
+}
+
+impl GasScheduleError {
+    pub fn missing(missing: Vec<String>) -> Self {
+        Self {
+            missing,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty()
+            && self.unknown.is_empty()
+            && self.duplicate.is_empty()
+            && self.conflicting.is_empty()
+    }
+}
+
+impl fmt::Display for GasScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid gas schedule")?;
+        if !self.missing.is_empty() {
+            write!(f, ", missing entries: [{}]", self.missing.join(", "))?;
+        }
+        if !self.unknown.is_empty() {
+            write!(f, ", unknown entries: [{}]", self.unknown.join(", "))?;
+        }
+        if !self.duplicate.is_empty() {
+            write!(f, ", duplicate entries: [{}]", self.duplicate.join(", "))?;
+        }
+        if !self.conflicting.is_empty() {
+            write!(
+                f,
+                ", conflicting entries: [{}]",
+                self.conflicting
+                    .iter()
+                    .map(|conflict| conflict.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for GasScheduleError {}
 
 /// A trait for converting from a map representation of the on-chain gas schedule.
 pub trait FromOnChainGasSchedule: Sized {
     /// Constructs a value of this type from a map representation of the on-chain gas schedule.
-    /// `None` should be returned when the gas schedule is missing some required entries.
-    /// Unused entries should be safely ignored.
-    fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self>;
+    /// An error listing all the missing entries should be returned when the gas schedule is missing
+    /// some required entries. Unused entries should be safely ignored.
+    fn from_on_chain_gas_schedule(
+        gas_schedule: &BTreeMap<String, u64>,
+    ) -> Result<Self, GasScheduleError>;
 }
 
 /// A trait for converting to a list of entries of the on-chain gas schedule.
@@ -24,3 +107,158 @@ pub trait InitialGasSchedule: Sized {
     /// Returns the initial value of this type, which is used in the genesis.
     fn initial() -> Self;
 }
+
+/// Construct the gas parameters of an aggregate from the on-chain gas schedule.
+/// The missing entries are appended to `missing` and the initial value is returned instead,
+/// so the missing entries of all the gas parameters of the aggregate are reported at once.
+pub fn from_on_chain_gas_schedule_or_initial<P>(
+    gas_schedule: &BTreeMap<String, u64>,
+    missing: &mut Vec<String>,
+) -> P
+where
+    P: FromOnChainGasSchedule + InitialGasSchedule,
+{
+    P::from_on_chain_gas_schedule(gas_schedule).unwrap_or_else(|error| {
+        missing.extend(error.missing);
+        P::initial()
+    })
+}
+
+/// Collect the entries of the gas schedule into a map, the keys which appear more than once are reported,
+/// so two gas parameters sharing a key can not write an ambiguous gas schedule.
+pub fn collect_gas_schedule(
+    entries: impl IntoIterator<Item = (String, u64)>,
+) -> Result<BTreeMap<String, u64>, GasScheduleError> {
+    let mut values: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    for (key, value) in entries {
+        values.entry(key).or_default().push(value);
+    }
+    let mut error = GasScheduleError::default();
+    let mut gas_schedule = BTreeMap::new();
+    for (key, values) in values {
+        if values.len() > 1 {
+            if values.iter().all(|value| *value == values[0]) {
+                error.duplicate.push(key.clone());
+            } else {
+                error.conflicting.push(GasScheduleConflict {
+                    key: key.clone(),
+                    values: values.clone(),
+                });
+            }
+        }
+        gas_schedule.insert(key, values[0]);
+    }
+    if error.is_empty() {
+        Ok(gas_schedule)
+    } else {
+        Err(error)
+    }
+}
+
+/// The on-chain gas schedule of the gas parameters, the duplicate keys are reported instead of overriding each other.
+pub fn to_on_chain_gas_schedule_map<P>(
+    params: &P,
+) -> Result<BTreeMap<String, u64>, GasScheduleError>
+where
+    P: ToOnChainGasSchedule,
+{
+    collect_gas_schedule(params.to_on_chain_gas_schedule())
+}
+
+/// Validate the on-chain gas schedule against the entries of the initial gas parameters,
+/// the entries missing from the gas schedule and the entries unknown to the gas parameters are reported.
+pub fn validate_gas_schedule<P>(
+    gas_schedule: &BTreeMap<String, u64>,
+) -> Result<(), GasScheduleError>
+where
+    P: InitialGasSchedule + ToOnChainGasSchedule,
+{
+    let expected: BTreeSet<String> = to_on_chain_gas_schedule_map(&P::initial())?
+        .into_keys()
+        .collect();
+    let error = GasScheduleError {
+        missing: expected
+            .iter()
+            .filter(|key| !gas_schedule.contains_key(*key))
+            .cloned()
+            .collect(),
+        unknown: gas_schedule
+            .keys()
+            .filter(|key| !expected.contains(*key))
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+    if error.is_empty() {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::natives::GasParameters;
+
+    #[test]
+    fn test_gas_schedule_diagnostics() {
+        let mut gas_schedule: BTreeMap<String, u64> = GasParameters::initial()
+            .to_on_chain_gas_schedule()
+            .into_iter()
+            .collect();
+        validate_gas_schedule::<GasParameters>(&gas_schedule).unwrap();
+        assert!(GasParameters::from_on_chain_gas_schedule(&gas_schedule).is_ok());
+
+        let removed = [
+            "account.create_signer.base".to_owned(),
+            "hash.keccak256.base".to_owned(),
+        ];
+        for key in &removed {
+            assert!(gas_schedule.remove(key).is_some());
+        }
+        gas_schedule.insert("unknown.base".to_owned(), 1);
+
+        let error = GasParameters::from_on_chain_gas_schedule(&gas_schedule).unwrap_err();
+        assert_eq!(error.missing, removed.to_vec());
+        let error = validate_gas_schedule::<GasParameters>(&gas_schedule).unwrap_err();
+        assert_eq!(error.missing, removed.to_vec());
+        assert_eq!(error.unknown, vec!["unknown.base".to_owned()]);
+    }
+
+    #[test]
+    fn test_gas_schedule_key_collisions() {
+        let entries = GasParameters::initial().to_on_chain_gas_schedule();
+        let gas_schedule = to_on_chain_gas_schedule_map(&GasParameters::initial()).unwrap();
+        assert_eq!(gas_schedule.len(), entries.len());
+
+        let (key, value) = entries[0].clone();
+        let (other_key, other_value) = entries[1].clone();
+        let mut colliding = entries;
+        colliding.push((key.clone(), value));
+        colliding.push((other_key.clone(), other_value + 1));
+        let error = collect_gas_schedule(colliding).unwrap_err();
+        assert_eq!(error.duplicate, vec![key]);
+        assert_eq!(
+            error.conflicting,
+            vec![GasScheduleConflict {
+                key: other_key,
+                values: vec![other_value, other_value + 1],
+            }]
+        );
+        assert!(error.missing.is_empty());
+    }
+
+    #[test]
+    fn test_failure_costs_are_charged() {
+        let failures: Vec<(String, u64)> = GasParameters::initial()
+            .to_on_chain_gas_schedule()
+            .into_iter()
+            .filter(|(key, _)| key.ends_with(".failure"))
+            .collect();
+        assert!(!failures.is_empty());
+        for (key, value) in failures {
+            assert!(value > 0, "the failure cost {} is zero", key);
+        }
+    }
+}
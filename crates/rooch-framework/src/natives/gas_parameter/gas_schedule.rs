@@ -0,0 +1,258 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::gas_member::{
+    collect_gas_schedule, to_on_chain_gas_schedule_map, FromOnChainGasSchedule, InitialGasSchedule,
+    ToOnChainGasSchedule,
+};
+use anyhow::{anyhow, bail, Result};
+use moveos::gas::table::{initial_cost_schedule, CostTable, GAS_FEATURE_VERSION_NATIVE_INPUT_COST};
+use rooch_types::framework::gas_schedule::{GasEntry, GasScheduleConfig};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The native gas entries introduced by `GAS_FEATURE_VERSION_NATIVE_INPUT_COST`,
+/// the natives of the earlier gas feature versions are metered without them.
+pub const NATIVE_INPUT_COST_ENTRIES: &[&str] = &[
+    "bcs.from_bytes.per_byte",
+    "bcs.from_bytes.failure",
+    "json.from_bytes.per_byte",
+    "json.from_bytes.failure",
+    "hash.keccak256.per_byte",
+    "hash.blake2b256.per_byte",
+    "hash.ripemd160.per_byte",
+    "ed25519.verify.per_byte",
+    "ed25519.verify.failure",
+    "ecdsa_k1.verify.per_byte",
+    "ecdsa_k1.verify.failure",
+    "ecdsa_k1_recoverable.verify.per_byte",
+    "ecdsa_k1_recoverable.verify.failure",
+    "ecdsa_k1_recoverable.ecrecover.per_byte",
+    "ecdsa_k1_recoverable.ecrecover.failure",
+    "ecdsa_k1_recoverable.decompress_pubkey.per_byte",
+    "ecdsa_k1_recoverable.decompress_pubkey.failure",
+    "schnorr.verify.per_byte",
+    "schnorr.verify.failure",
+    "encoding.base58.per_byte",
+    "encoding.base58check.per_byte",
+    "encoding.bech32.per_byte",
+    "encoding.bech32.failure",
+    "encoding.p2pkh.per_byte",
+    "encoding.p2pkh.failure",
+    "encoding.p2sh.per_byte",
+    "encoding.p2sh.failure",
+    "decoding.base58.per_byte",
+    "decoding.base58.failure",
+    "decoding.base58check.per_byte",
+    "decoding.base58check.failure",
+];
+
+/// Zero the native gas entries introduced after the gas `feature_version`,
+/// so the natives charge the same gas as the nodes before the version.
+pub fn gate_native_gas_schedule(entries: &mut BTreeMap<String, u64>, feature_version: u64) {
+    if feature_version < GAS_FEATURE_VERSION_NATIVE_INPUT_COST {
+        for key in NATIVE_INPUT_COST_ENTRIES {
+            if let Some(entry) = entries.get_mut(*key) {
+                *entry = 0;
+            }
+        }
+    }
+}
+
+/// Load the native gas parameters of the on-chain gas schedule version metered by the gas `feature_version`
+/// of its cost table, the entries of the schedule override the initial gas parameters.
+pub fn load_native_gas_parameters<P>(
+    gas_schedule: &GasScheduleConfig,
+    feature_version: u64,
+) -> Result<P>
+where
+    P: FromOnChainGasSchedule + ToOnChainGasSchedule + InitialGasSchedule,
+{
+    let mut entries = to_on_chain_gas_schedule_map(&P::initial())
+        .map_err(|e| anyhow!("The initial native gas parameters are ambiguous: {}", e))?;
+    for (key, val) in gas_schedule.entries_map() {
+        if let Some(entry) = entries.get_mut(&key) {
+            *entry = val;
+        }
+    }
+    gate_native_gas_schedule(&mut entries, feature_version);
+    P::from_on_chain_gas_schedule(&entries).map_err(|e| {
+        anyhow!(
+            "The native gas parameters of the gas schedule version {} are incomplete: {}",
+            gas_schedule.schedule_version,
+            e
+        )
+    })
+}
+
+/// Load the cost table of the on-chain gas schedule version,
+/// the cost table entries of the schedule override the cost table of the genesis.
+pub fn load_cost_table(
+    genesis_cost_table: &CostTable,
+    gas_schedule: &GasScheduleConfig,
+) -> Result<CostTable> {
+    let entries = gas_schedule
+        .entries_map()
+        .into_iter()
+        .filter(|(key, _)| CostTable::is_entry_key(key))
+        .collect();
+    genesis_cost_table.with_entries(&entries)
+}
+
+/// Build the cost table of the genesis from the gas schedule entries of the genesis config.
+/// Only the cost table entries are allowed, the natives are metered by the initial gas parameters at genesis.
+pub fn load_genesis_cost_table(entries: &[GasEntry]) -> Result<CostTable> {
+    let entries = collect_gas_schedule(
+        entries
+            .iter()
+            .map(|entry| (entry.key.to_string(), entry.val)),
+    )
+    .map_err(|e| anyhow!("The genesis gas schedule is ambiguous: {}", e))?;
+    if let Some(key) = entries.keys().find(|key| !CostTable::is_entry_key(key)) {
+        bail!(
+            "The genesis gas schedule entry {} is not a cost table entry",
+            key
+        );
+    }
+    initial_cost_schedule().with_entries(&entries)
+}
+
+/// Check every entry of the gas schedule is an entry of the cost table or one of the `native_keys`,
+/// so a misspelled key is not ignored silently, and no key appears more than once.
+pub fn check_gas_schedule_keys(
+    gas_schedule: &GasScheduleConfig,
+    native_keys: &BTreeSet<String>,
+) -> Result<()> {
+    collect_gas_schedule(
+        gas_schedule
+            .entries
+            .iter()
+            .map(|entry| (entry.key.to_string(), entry.val)),
+    )
+    .map_err(|e| {
+        anyhow!(
+            "The gas schedule version {} is ambiguous: {}",
+            gas_schedule.schedule_version,
+            e
+        )
+    })?;
+    for entry in &gas_schedule.entries {
+        let key = entry.key.to_string();
+        if !CostTable::is_entry_key(&key) && !native_keys.contains(&key) {
+            bail!(
+                "Unknown entry {} in the gas schedule version {}",
+                key,
+                gas_schedule.schedule_version
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::natives::GasParameters;
+    use moveos::gas::table::{GAS_FEATURE_VERSION_STORAGE_FEE, LATEST_GAS_FEATURE_VERSION};
+
+    fn gas_schedule(entries: Vec<(&str, u64)>) -> GasScheduleConfig {
+        GasScheduleConfig {
+            schedule_version: 1,
+            entries: entries
+                .into_iter()
+                .map(|(key, val)| GasEntry {
+                    key: key.to_owned().into(),
+                    val,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_load_gas_schedule() {
+        let initial_entries: BTreeMap<String, u64> = GasParameters::initial()
+            .to_on_chain_gas_schedule()
+            .into_iter()
+            .collect();
+        let (native_key, native_val) = initial_entries.iter().next().unwrap();
+        let schedule = gas_schedule(vec![
+            (native_key.as_str(), native_val + 1),
+            ("instruction_tiers.0", 7),
+        ]);
+        let native_keys = initial_entries.keys().cloned().collect();
+        check_gas_schedule_keys(&schedule, &native_keys).unwrap();
+
+        let params: GasParameters =
+            load_native_gas_parameters(&schedule, LATEST_GAS_FEATURE_VERSION).unwrap();
+        let loaded_entries: BTreeMap<String, u64> =
+            params.to_on_chain_gas_schedule().into_iter().collect();
+        assert_eq!(loaded_entries.get(native_key), Some(&(native_val + 1)));
+        assert_eq!(loaded_entries.len(), initial_entries.len());
+
+        let cost_table = load_cost_table(&initial_cost_schedule(), &schedule).unwrap();
+        assert_eq!(cost_table.instruction_tier(0).0, 7);
+
+        let genesis_cost_table = load_genesis_cost_table(&[
+            GasEntry::new("instruction_tiers.0".to_owned(), 3),
+            GasEntry::new("stack_size_tiers.100000".to_owned(), 200),
+        ])
+        .unwrap();
+        assert_eq!(genesis_cost_table.instruction_tier(0).0, 3);
+        assert_eq!(genesis_cost_table.stack_size_tier(100000).0, 200);
+        assert_eq!(
+            genesis_cost_table.stack_height_tiers,
+            initial_cost_schedule().stack_height_tiers
+        );
+        // The later schedule overrides the genesis cost table, the other genesis tiers are kept
+        let cost_table = load_cost_table(&genesis_cost_table, &schedule).unwrap();
+        assert_eq!(cost_table.instruction_tier(0).0, 7);
+        assert_eq!(cost_table.stack_size_tier(100000).0, 200);
+        assert!(load_genesis_cost_table(&[GasEntry::new(native_key.clone(), 1)]).is_err());
+
+        let unknown = gas_schedule(vec![("unknown.key", 1)]);
+        assert!(check_gas_schedule_keys(&unknown, &native_keys).is_err());
+
+        let conflicting = gas_schedule(vec![
+            (native_key.as_str(), native_val + 1),
+            (native_key.as_str(), native_val + 2),
+        ]);
+        assert!(check_gas_schedule_keys(&conflicting, &native_keys).is_err());
+    }
+
+    #[test]
+    fn test_gate_native_input_cost() {
+        let initial_entries: BTreeMap<String, u64> = GasParameters::initial()
+            .to_on_chain_gas_schedule()
+            .into_iter()
+            .collect();
+        for key in NATIVE_INPUT_COST_ENTRIES {
+            assert!(
+                initial_entries.get(*key).copied().unwrap_or_default() > 0,
+                "the native input cost entry {} is not charged",
+                key
+            );
+        }
+
+        let schedule = gas_schedule(vec![]);
+        let latest: BTreeMap<String, u64> =
+            load_native_gas_parameters::<GasParameters>(&schedule, LATEST_GAS_FEATURE_VERSION)
+                .unwrap()
+                .to_on_chain_gas_schedule()
+                .into_iter()
+                .collect();
+        assert_eq!(latest, initial_entries);
+
+        let legacy: BTreeMap<String, u64> =
+            load_native_gas_parameters::<GasParameters>(&schedule, GAS_FEATURE_VERSION_STORAGE_FEE)
+                .unwrap()
+                .to_on_chain_gas_schedule()
+                .into_iter()
+                .collect();
+        for (key, val) in legacy {
+            if NATIVE_INPUT_COST_ENTRIES.contains(&key.as_str()) {
+                assert_eq!(val, 0, "{} is charged before its feature version", key);
+            } else {
+                assert_eq!(Some(&val), initial_entries.get(&key));
+            }
+        }
+    }
+}
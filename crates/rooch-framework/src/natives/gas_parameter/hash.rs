@@ -6,6 +6,9 @@ use crate::natives::rooch_framework::crypto::hash::GasParameters;
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "hash", [
     [.keccak256.base, "keccak256.base", (5 + 1) * MUL],
+    [.keccak256.per_byte, "keccak256.per_byte", MUL],
     [.blake2b256.base, "blake2b256.base", (5 + 1) * MUL],
+    [.blake2b256.per_byte, "blake2b256.per_byte", MUL],
     [.ripemd160.base, "ripemd160.base", (5 + 1) * MUL],
+    [.ripemd160.per_byte, "ripemd160.per_byte", MUL],
 ]);
@@ -6,4 +6,6 @@ use moveos_stdlib::natives::moveos_stdlib::json::GasParameters;
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "json", [
     [.from_bytes.base, "from_bytes.base", (5 + 1) * MUL],
+    [.from_bytes.per_byte, "from_bytes.per_byte", MUL],
+    [.from_bytes.failure, "from_bytes.failure", (5 + 1) * MUL],
 ]);
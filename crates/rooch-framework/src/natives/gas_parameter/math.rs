@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use moveos_stdlib::natives::moveos_stdlib::math::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "math", [
+    [.mul_div_u128.base, optional "mul_div_u128.base", 4 * MUL],
+    [.sqrt_u128.base, optional "sqrt_u128.base", 10 * MUL],
+    [.pow_u128.base, optional "pow_u128.base", 8 * MUL],
+]);
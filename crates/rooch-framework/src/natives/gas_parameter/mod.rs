@@ -1,16 +1,21 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 mod account;
+mod address;
 mod bcd;
 mod decoding;
+mod dispatch;
 mod ecdsa_k1;
 mod ecdsa_k1_recoverable;
 mod ed25519;
 mod encoding;
 mod events;
+mod fixed_point64;
 pub mod gas_member;
+pub mod gas_schedule;
 mod hash;
 mod json;
+mod math;
 mod move_module;
 pub mod move_std;
 pub mod native;
@@ -20,6 +25,10 @@ mod object;
 mod rlp;
 mod schnorr;
 mod signer;
+mod string_utils;
 mod table_extension;
 mod test_helper;
 mod type_info;
+mod vector_utils;
+
+pub use rooch_gas_parameter_macros::{impl_gas_schedule, GasSchedule};
@@ -7,38 +7,40 @@ pub const MUL: u64 = 1;
 
 #[macro_export]
 macro_rules! expand_get_impl_for_native_gas_params {
-    ($params: ident $(.$field: ident)+, $map: ident, $prefix: literal, optional $key: literal) => {
+    ($params: ident $(.$field: ident)+, $map: ident, $missing: ident, $prefix: literal, optional $key: literal) => {
         if let Some(val) = $map.get(&format!("{}.{}", $prefix, $key)) {
             $params $(.$field)+ = (*val).into();
         }
     };
-    ($params: ident $(.$field: ident)+, $map: ident, $prefix: literal, $key: literal) => {
-        $params $(.$field)+ = $map.get(&format!("{}.{}", $prefix, $key)).cloned()?.into();
+    ($params: ident $(.$field: ident)+, $map: ident, $missing: ident, $prefix: literal, $key: literal) => {
+        let key = format!("{}.{}", $prefix, $key);
+        match $map.get(&key) {
+            Some(val) => $params $(.$field)+ = (*val).into(),
+            None => $missing.push(key),
+        }
     };
 }
 
 #[macro_export]
 macro_rules! expand_get_for_native_gas_params {
-    (test_only $(.$field: ident)+, $(optional $($dummy: ident)?)? $key: literal, $initial_val: expr, $param_ty: ty, $package_name: literal, $params: ident, $gas_schedule: ident) => {
+    (test_only $(.$field: ident)+, $(optional $($dummy: ident)?)? $key: literal, $initial_val: expr, $param_ty: ty, $package_name: literal, $params: ident, $gas_schedule: ident, $missing: ident) => {
         // TODO(Gas): this is a hack to work-around issue
         // https://github.com/rust-lang/rust/issues/15701
         {
             #[cfg(feature = "testing")]
-            fn assign(params: &mut $param_ty, gas_schedule: &std::collections::BTreeMap<String, u64>) -> Option<()> {
-                $crate::natives::gas_parameter::expand_get_impl_for_native_gas_params!(params $(.$field)+, gas_schedule, $package_name, $(optional $($dummy)?)? $key);
-                Some(())
+            fn assign(params: &mut $param_ty, gas_schedule: &std::collections::BTreeMap<String, u64>, missing: &mut Vec<String>) {
+                $crate::natives::gas_parameter::native::expand_get_impl_for_native_gas_params!(params $(.$field)+, gas_schedule, missing, $package_name, $(optional $($dummy)?)? $key);
             }
 
             #[cfg(not(feature = "testing"))]
-            fn assign(_params: &mut $param_ty, _gas_schedule: &std::collections::BTreeMap<String, u64>) -> Option<()> {
-                Some(())
+            fn assign(_params: &mut $param_ty, _gas_schedule: &std::collections::BTreeMap<String, u64>, _missing: &mut Vec<String>) {
             }
 
-            assign(&mut $params, &$gas_schedule)?;
+            assign(&mut $params, &$gas_schedule, &mut $missing);
         }
     };
-    ($(.$field: ident)+, $(optional $($dummy: ident)?)? $key: literal, $initial_val: expr, $param_ty: ty, $package_name: literal, $params: ident, $gas_schedule: ident) => {
-        $crate::natives::gas_parameter::native::expand_get_impl_for_native_gas_params!($params $(.$field)+, $gas_schedule, $package_name, $(optional $($dummy)?)? $key);
+    ($(.$field: ident)+, $(optional $($dummy: ident)?)? $key: literal, $initial_val: expr, $param_ty: ty, $package_name: literal, $params: ident, $gas_schedule: ident, $missing: ident) => {
+        $crate::natives::gas_parameter::native::expand_get_impl_for_native_gas_params!($params $(.$field)+, $gas_schedule, $missing, $package_name, $(optional $($dummy)?)? $key);
     }
 }
 
@@ -100,14 +102,22 @@ macro_rules! extract_path_for_native_gas_params {
 macro_rules! define_gas_parameters_for_natives {
     ($param_ty: ty, $package_name: literal, [$([$($t: tt)*]),* $(,)?] $(, allow_unmapped = $allow_unmapped: expr)?) => {
         impl $crate::natives::gas_parameter::gas_member::FromOnChainGasSchedule for $param_ty {
-            fn from_on_chain_gas_schedule(gas_schedule: &std::collections::BTreeMap<String, u64>) -> Option<Self> {
+            fn from_on_chain_gas_schedule(
+                gas_schedule: &std::collections::BTreeMap<String, u64>,
+            ) -> Result<Self, $crate::natives::gas_parameter::gas_member::GasScheduleError> {
                 let mut params = <$param_ty>::zeros();
+                #[allow(unused_mut)]
+                let mut missing: Vec<String> = vec![];
 
                 $(
-                    $crate::natives::gas_parameter::native::expand_get_for_native_gas_params!($($t)*, $param_ty, $package_name, params, gas_schedule);
+                    $crate::natives::gas_parameter::native::expand_get_for_native_gas_params!($($t)*, $param_ty, $package_name, params, gas_schedule, missing);
                 )*
 
-                Some(params)
+                if missing.is_empty() {
+                    Ok(params)
+                } else {
+                    Err($crate::natives::gas_parameter::gas_member::GasScheduleError::missing(missing))
+                }
             }
         }
 
@@ -7,4 +7,6 @@ use moveos_stdlib::natives::moveos_stdlib::object::GasParameters;
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "object", [
     [.as_ref_inner.base, "as_ref_inner.base", (5 + 1) * MUL],
     [.as_mut_ref_inner.base, "as_mut_ref_inner.base", (5 + 1) * MUL],
+    [.derive_object_id.base, optional "derive_object_id.base", (5 + 1) * MUL],
+    [.derive_object_id.per_byte, optional "derive_object_id.per_byte", MUL],
 ]);
@@ -6,4 +6,6 @@ use crate::natives::rooch_framework::crypto::schnorr::GasParameters;
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "schnorr", [
     [.verify.base, "verify.base", (5 + 1) * MUL],
+    [.verify.per_byte, "verify.per_byte", MUL],
+    [.verify.failure, "verify.failure", (5 + 1) * MUL],
 ]);
@@ -0,0 +1,18 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use moveos_stdlib::natives::moveos_stdlib::string_utils::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "string_utils", [
+    [.check_utf8_inner.base, optional "check_utf8_inner.base", 4 * MUL],
+    [.check_utf8_inner.per_byte, optional "check_utf8_inner.per_byte", MUL],
+    [.index_of_inner.base, optional "index_of_inner.base", 4 * MUL],
+    [.index_of_inner.per_byte, optional "index_of_inner.per_byte", MUL],
+    [.split_inner.base, optional "split_inner.base", 6 * MUL],
+    [.split_inner.per_byte, optional "split_inner.per_byte", MUL],
+    [.parse_u256_inner.base, optional "parse_u256_inner.base", 4 * MUL],
+    [.parse_u256_inner.per_byte, optional "parse_u256_inner.per_byte", MUL],
+    [.u256_to_string_inner.base, optional "u256_to_string_inner.base", 4 * MUL],
+    [.u256_to_string_inner.per_byte, optional "u256_to_string_inner.per_byte", MUL],
+]);
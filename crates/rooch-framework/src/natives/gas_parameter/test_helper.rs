@@ -6,4 +6,5 @@ use moveos_stdlib::natives::moveos_stdlib::test_helper::GasParameters;
 
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "test_helper", [
     [.destroy.base, "destroy.base", (5 + 1) * MUL],
+    [.test_env.base, optional "test_env.base", (5 + 1) * MUL],
 ]);
@@ -0,0 +1,14 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use moveos_stdlib::natives::moveos_stdlib::vector_utils::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "vector_utils", [
+    [.move_all_inner.base, optional "move_all_inner.base", 4 * MUL],
+    [.move_all_inner.per_element, optional "move_all_inner.per_element", 2 * MUL],
+    [.reverse.base, optional "reverse.base", 4 * MUL],
+    [.reverse.per_element, optional "reverse.per_element", MUL],
+    [.slice.base, optional "slice.base", 4 * MUL],
+    [.slice.per_element, optional "slice.per_element", 2 * MUL],
+]);
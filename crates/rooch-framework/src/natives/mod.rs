@@ -1,19 +1,23 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::gas_parameter::gas_member::{FromOnChainGasSchedule, InitialGasSchedule};
+use crate::natives::gas_parameter::gas_member::InitialGasSchedule;
+use crate::natives::gas_parameter::{impl_gas_schedule, GasSchedule};
 use crate::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
 use move_vm_runtime::native_functions::{make_table_from_iter, NativeFunctionTable};
+use moveos::builder::MoveOSBuilder;
+use moveos_stdlib::natives::instrumentation::instrument_natives;
 use moveos_stdlib::natives::GasParameters as MoveOSGasParameters;
-use std::collections::BTreeMap;
 
 pub mod helpers {
     pub use moveos_stdlib::natives::helpers::*;
 }
 pub mod gas_parameter;
+pub mod registry;
 pub mod rooch_framework;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, GasSchedule)]
 pub struct GasParameters {
     moveos_stdlib: moveos_stdlib::natives::GasParameters,
     account: rooch_framework::account::GasParameters,
@@ -24,83 +28,30 @@ pub struct GasParameters {
     schnorr: rooch_framework::crypto::schnorr::GasParameters,
     encoding: rooch_framework::crypto::encoding::GasParameters,
     decoding: rooch_framework::crypto::decoding::GasParameters,
-    bcs: rooch_framework::bcs::GasParameters,
+    /// The gas parameters of the natives registered by the extension crates
+    extensions: registry::ExtensionGasParameters,
 }
 
-impl FromOnChainGasSchedule for GasParameters {
-    fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
-        Some(Self {
-            moveos_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            account: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            hash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            ecdsa_k1_recoverable: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            schnorr: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            encoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            decoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            bcs: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-        })
-    }
-}
-
-impl InitialGasSchedule for GasParameters {
-    fn initial() -> Self {
-        Self {
-            moveos_stdlib: InitialGasSchedule::initial(),
-            account: InitialGasSchedule::initial(),
-            hash: InitialGasSchedule::initial(),
-            ed25519: InitialGasSchedule::initial(),
-            ecdsa_k1: InitialGasSchedule::initial(),
-            ecdsa_k1_recoverable: InitialGasSchedule::initial(),
-            schnorr: InitialGasSchedule::initial(),
-            encoding: InitialGasSchedule::initial(),
-            decoding: InitialGasSchedule::initial(),
-            bcs: InitialGasSchedule::initial(),
-        }
-    }
-}
-
-impl FromOnChainGasSchedule for MoveOSGasParameters {
-    fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
-        Some(Self {
-            move_stdlib: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            move_nursery: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            table_extension: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)
-                .unwrap(),
-            type_info: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            rlp: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            bcd: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            events: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            test_helper: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            signer: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            move_module: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            object: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-            json: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-        })
-    }
-}
-
-impl InitialGasSchedule for MoveOSGasParameters {
-    fn initial() -> Self {
-        Self {
-            move_stdlib: InitialGasSchedule::initial(),
-            move_nursery: InitialGasSchedule::initial(),
-            table_extension: InitialGasSchedule::initial(),
-            type_info: InitialGasSchedule::initial(),
-            rlp: InitialGasSchedule::initial(),
-            bcd: InitialGasSchedule::initial(),
-            events: InitialGasSchedule::initial(),
-            test_helper: InitialGasSchedule::initial(),
-            signer: InitialGasSchedule::initial(),
-            move_module: InitialGasSchedule::initial(),
-            object: InitialGasSchedule::initial(),
-            json: InitialGasSchedule::initial(),
-        }
-    }
-}
+impl_gas_schedule!(MoveOSGasParameters {
+    move_stdlib,
+    move_nursery,
+    table_extension,
+    type_info,
+    rlp,
+    bcd,
+    events,
+    test_helper,
+    signer,
+    move_module,
+    object,
+    json,
+    dispatch,
+    math,
+    fixed_point64,
+    string_utils,
+    vector_utils,
+    address,
+});
 
 pub fn get_global_gas_parameter() {
     let gas_parameter = GasParameters::initial();
@@ -120,12 +71,31 @@ impl GasParameters {
             schnorr: rooch_framework::crypto::schnorr::GasParameters::zeros(),
             encoding: rooch_framework::crypto::encoding::GasParameters::zeros(),
             decoding: rooch_framework::crypto::decoding::GasParameters::zeros(),
-            bcs: rooch_framework::bcs::GasParameters::zeros(),
+            extensions: registry::ExtensionGasParameters::zeros(),
+        }
+    }
+
+    /// The initial gas parameters of the framework natives, without the natives registered by the extension crates
+    pub fn initial_without_extensions() -> Self {
+        Self {
+            moveos_stdlib: moveos_stdlib::natives::GasParameters::initial(),
+            account: rooch_framework::account::GasParameters::initial(),
+            hash: rooch_framework::crypto::hash::GasParameters::initial(),
+            ed25519: rooch_framework::crypto::ed25519::GasParameters::initial(),
+            ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters::initial(),
+            ecdsa_k1_recoverable:
+                rooch_framework::crypto::ecdsa_k1_recoverable::GasParameters::initial(),
+            schnorr: rooch_framework::crypto::schnorr::GasParameters::initial(),
+            encoding: rooch_framework::crypto::encoding::GasParameters::initial(),
+            decoding: rooch_framework::crypto::decoding::GasParameters::initial(),
+            extensions: registry::ExtensionGasParameters::default(),
         }
     }
 }
 
-pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
+/// All the natives of the framework and of the extension crates, the gas parameters of the extension natives
+/// must match the natives registered.
+pub fn all_natives(gas_params: GasParameters) -> Result<NativeFunctionTable> {
     let mut native_fun_table = moveos_stdlib::natives::all_natives(gas_params.moveos_stdlib);
 
     let mut natives = vec![];
@@ -171,10 +141,30 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
         "decoding",
         rooch_framework::crypto::decoding::make_all(gas_params.decoding)
     );
-    add_natives!("bcs", rooch_framework::bcs::make_all(gas_params.bcs));
 
     let rooch_native_fun_table = make_table_from_iter(ROOCH_FRAMEWORK_ADDRESS, natives);
-    native_fun_table.extend(rooch_native_fun_table);
+    native_fun_table.extend(instrument_natives(rooch_native_fun_table));
+
+    // the natives registered by the extension crates
+    let extension_native_fun_table =
+        registry::registered_natives().natives(gas_params.extensions.gas_schedule())?;
+    native_fun_table.extend(instrument_natives(extension_native_fun_table));
 
-    native_fun_table
+    Ok(native_fun_table)
+}
+
+/// The builder of the MoveOS embedded without the node, all the natives are metered by the initial gas schedule
+pub fn moveos_builder() -> Result<MoveOSBuilder> {
+    Ok(MoveOSBuilder::new().natives(all_natives(GasParameters::initial())?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moveos_builder() {
+        let moveos = moveos_builder().unwrap().build().unwrap();
+        assert!(moveos.state().is_genesis());
+    }
 }
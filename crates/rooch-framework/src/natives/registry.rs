@@ -0,0 +1,358 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The registry of the natives defined by the extension crates.
+//! A chain built on this workspace registers the natives of its own Move modules at startup,
+//! before the natives are built by the genesis or the executor, instead of forking the framework:
+//!
+//! ```ignore
+//! rooch_framework::natives::registry::register_natives(
+//!     APP_ADDRESS,
+//!     "app_hash",
+//!     app_hash::make_all,
+//! )?;
+//! ```
+//!
+//! The gas parameters of the extension natives are a part of the native gas schedule, they are stored on-chain
+//! by the genesis and can be updated by the gas schedule like the framework natives. Their keys are prefixed
+//! by the address of the module, such as `0x42::app_hash.keccak256.base`, so they never collide with the keys
+//! of the framework natives or of the extensions at other addresses.
+
+use crate::natives::gas_parameter::gas_member::{
+    FromOnChainGasSchedule, GasScheduleError, InitialGasSchedule, ToOnChainGasSchedule,
+};
+use crate::natives::GasParameters;
+use crate::{BITCOIN_MOVE_ADDRESS, ROOCH_FRAMEWORK_ADDRESS, ROOCH_FRAMEWORK_VERSION_ADDRESSES};
+use anyhow::{anyhow, bail, ensure, Result};
+use move_core_types::account_address::AccountAddress;
+use move_vm_runtime::native_functions::{
+    make_table_from_iter, NativeFunction, NativeFunctionTable,
+};
+use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
+use once_cell::sync::Lazy;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+
+static NATIVE_REGISTRY: Lazy<RwLock<NativeRegistry>> =
+    Lazy::new(|| RwLock::new(NativeRegistry::default()));
+
+type MakeNatives = dyn Fn(&BTreeMap<String, u64>) -> Result<Vec<(String, NativeFunction)>, GasScheduleError>
+    + Send
+    + Sync;
+type ValidateGasSchedule =
+    dyn Fn(&BTreeMap<String, u64>) -> Result<(), GasScheduleError> + Send + Sync;
+
+/// The natives of a Move module of an extension crate
+#[derive(Clone)]
+struct NativeExtension {
+    address: AccountAddress,
+    module: String,
+    /// The initial gas schedule of the natives, the keys are prefixed by the address
+    initial_gas_schedule: Vec<(String, u64)>,
+    make_natives: Arc<MakeNatives>,
+    validate: Arc<ValidateGasSchedule>,
+}
+
+/// The key of the gas schedule entry `key` of the natives at `address`
+fn extension_gas_key(address: &AccountAddress, key: &str) -> String {
+    format!("{}::{}", address.to_hex_literal(), key)
+}
+
+/// Load the gas parameters of the natives at `address` from the entries of the gas schedule prefixed by the address,
+/// the keys of the error are prefixed as the keys of the gas schedule.
+fn load_extension_gas_params<G: FromOnChainGasSchedule>(
+    address: &AccountAddress,
+    gas_schedule: &BTreeMap<String, u64>,
+) -> Result<G, GasScheduleError> {
+    let prefix = extension_gas_key(address, "");
+    let extension_gas_schedule = gas_schedule
+        .iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(prefix.as_str())
+                .map(|key| (key.to_string(), *value))
+        })
+        .collect();
+    G::from_on_chain_gas_schedule(&extension_gas_schedule).map_err(|error| GasScheduleError {
+        missing: error
+            .missing
+            .iter()
+            .map(|key| extension_gas_key(address, key))
+            .collect(),
+        unknown: error
+            .unknown
+            .iter()
+            .map(|key| extension_gas_key(address, key))
+            .collect(),
+        ..error
+    })
+}
+
+/// The natives registered by the extension crates, keyed by the address and the module name
+#[derive(Clone, Default)]
+pub struct NativeRegistry {
+    extensions: BTreeMap<(AccountAddress, String), NativeExtension>,
+}
+
+impl NativeRegistry {
+    /// Register the natives of the Move module `module` at `address`, the natives are made by `make_all`
+    /// from the gas parameters `G`, such as the `make_all` of a framework native module.
+    /// A module can only be registered once, and the natives can not be registered at the framework addresses.
+    pub fn register<G, F, I>(
+        &mut self,
+        address: AccountAddress,
+        module: &str,
+        make_all: F,
+    ) -> Result<()>
+    where
+        G: FromOnChainGasSchedule + ToOnChainGasSchedule + InitialGasSchedule + 'static,
+        F: Fn(G) -> I + Send + Sync + 'static,
+        I: IntoIterator<Item = (String, NativeFunction)>,
+    {
+        ensure!(
+            !is_framework_address(&address),
+            "The natives of {}::{} can not be registered at a framework address",
+            address.short_str_lossless(),
+            module
+        );
+        let key = (address, module.to_string());
+        ensure!(
+            !self.extensions.contains_key(&key),
+            "The natives of {}::{} are already registered",
+            address.short_str_lossless(),
+            module
+        );
+        let initial_gas_schedule: Vec<(String, u64)> = G::initial()
+            .to_on_chain_gas_schedule()
+            .into_iter()
+            .map(|(key, value)| (extension_gas_key(&address, &key), value))
+            .collect();
+        // The registered keys are checked against the full native gas schedule, the framework natives
+        // and the natives of the other extensions
+        let registered_keys: BTreeSet<String> = framework_initial_gas_schedule()
+            .into_iter()
+            .chain(self.initial_gas_schedule())
+            .map(|(key, _)| key)
+            .collect();
+        if let Some((key, _)) = initial_gas_schedule
+            .iter()
+            .find(|(key, _)| registered_keys.contains(key))
+        {
+            bail!(
+                "The gas schedule key {} of {}::{} is already registered",
+                key,
+                address.short_str_lossless(),
+                module
+            );
+        }
+        let make_natives = move |gas_schedule: &BTreeMap<String, u64>| {
+            let gas_params = load_extension_gas_params::<G>(&address, gas_schedule)?;
+            Ok(make_all(gas_params).into_iter().collect())
+        };
+        self.extensions.insert(
+            key,
+            NativeExtension {
+                address,
+                module: module.to_string(),
+                initial_gas_schedule,
+                make_natives: Arc::new(make_natives),
+                validate: Arc::new(move |gas_schedule| {
+                    load_extension_gas_params::<G>(&address, gas_schedule).map(|_| ())
+                }),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+
+    /// The initial gas schedule of all the registered natives
+    pub fn initial_gas_schedule(&self) -> Vec<(String, u64)> {
+        self.extensions
+            .values()
+            .flat_map(|extension| extension.initial_gas_schedule.clone())
+            .collect()
+    }
+
+    /// The native function table of all the registered natives metered by the `gas_schedule`,
+    /// the `gas_schedule` must have the entries of all the registered natives.
+    pub fn natives(&self, gas_schedule: &BTreeMap<String, u64>) -> Result<NativeFunctionTable> {
+        let mut native_fun_table = vec![];
+        for extension in self.extensions.values() {
+            let natives = (extension.make_natives)(gas_schedule).map_err(|e| {
+                anyhow!(
+                    "Load the gas parameters of the natives of {}::{} error: {}",
+                    extension.address.short_str_lossless(),
+                    extension.module,
+                    e
+                )
+            })?;
+            native_fun_table.extend(make_table_from_iter(
+                extension.address,
+                natives
+                    .into_iter()
+                    .map(|(func_name, func)| (extension.module.clone(), func_name, func)),
+            ));
+        }
+        Ok(native_fun_table)
+    }
+
+    fn validate(&self, gas_schedule: &BTreeMap<String, u64>) -> Result<(), GasScheduleError> {
+        let mut missing = vec![];
+        for extension in self.extensions.values() {
+            if let Err(error) = (extension.validate)(gas_schedule) {
+                missing.extend(error.missing);
+            }
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(GasScheduleError::missing(missing))
+        }
+    }
+}
+
+fn is_framework_address(address: &AccountAddress) -> bool {
+    [
+        MOVE_STD_ADDRESS,
+        MOVEOS_STD_ADDRESS,
+        ROOCH_FRAMEWORK_ADDRESS,
+        BITCOIN_MOVE_ADDRESS,
+    ]
+    .contains(address)
+        || ROOCH_FRAMEWORK_VERSION_ADDRESSES.contains(address)
+}
+
+/// The initial gas schedule of the framework natives, without the natives of the extension crates,
+/// the registry is not read so it can be called while the registry is locked.
+fn framework_initial_gas_schedule() -> Vec<(String, u64)> {
+    GasParameters::initial_without_extensions().to_on_chain_gas_schedule()
+}
+
+/// Register the natives of an extension crate in the global registry, see `NativeRegistry::register`.
+pub fn register_natives<G, F, I>(address: AccountAddress, module: &str, make_all: F) -> Result<()>
+where
+    G: FromOnChainGasSchedule + ToOnChainGasSchedule + InitialGasSchedule + 'static,
+    F: Fn(G) -> I + Send + Sync + 'static,
+    I: IntoIterator<Item = (String, NativeFunction)>,
+{
+    NATIVE_REGISTRY
+        .write()
+        .expect("native registry lock should not be poisoned")
+        .register(address, module, make_all)
+}
+
+/// A snapshot of the global registry
+pub fn registered_natives() -> NativeRegistry {
+    NATIVE_REGISTRY
+        .read()
+        .expect("native registry lock should not be poisoned")
+        .clone()
+}
+
+/// The gas parameters of the natives registered by the extension crates, they are the entries of
+/// the native gas schedule used by the registered natives.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionGasParameters {
+    gas_schedule: BTreeMap<String, u64>,
+}
+
+impl ExtensionGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            gas_schedule: registered_natives()
+                .initial_gas_schedule()
+                .into_iter()
+                .map(|(key, _)| (key, 0))
+                .collect(),
+        }
+    }
+
+    pub fn gas_schedule(&self) -> &BTreeMap<String, u64> {
+        &self.gas_schedule
+    }
+}
+
+impl FromOnChainGasSchedule for ExtensionGasParameters {
+    fn from_on_chain_gas_schedule(
+        gas_schedule: &BTreeMap<String, u64>,
+    ) -> Result<Self, GasScheduleError> {
+        let registry = registered_natives();
+        registry.validate(gas_schedule)?;
+        Ok(Self {
+            gas_schedule: registry
+                .initial_gas_schedule()
+                .into_iter()
+                .filter_map(|(key, _)| gas_schedule.get(&key).map(|value| (key, *value)))
+                .collect(),
+        })
+    }
+}
+
+impl ToOnChainGasSchedule for ExtensionGasParameters {
+    fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
+        self.gas_schedule
+            .iter()
+            .map(|(key, value)| (key.clone(), *value))
+            .collect()
+    }
+}
+
+impl InitialGasSchedule for ExtensionGasParameters {
+    fn initial() -> Self {
+        Self {
+            gas_schedule: registered_natives()
+                .initial_gas_schedule()
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::natives::rooch_framework::crypto::hash;
+
+    #[test]
+    fn test_register_natives() {
+        let address = AccountAddress::from_hex_literal("0x42").unwrap();
+        let mut registry = NativeRegistry::default();
+        // The module shares the name of a framework module, the gas keys are prefixed by the address
+        registry.register(address, "hash", hash::make_all).unwrap();
+        assert!(registry.register(address, "hash", hash::make_all).is_err());
+        assert!(registry
+            .register(ROOCH_FRAMEWORK_ADDRESS, "app_hash", hash::make_all)
+            .is_err());
+
+        let initial_gas_schedule: BTreeMap<String, u64> =
+            registry.initial_gas_schedule().into_iter().collect();
+        assert!(initial_gas_schedule.contains_key("0x42::hash.keccak256.base"));
+        let framework_keys: BTreeSet<String> = framework_initial_gas_schedule()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        assert!(framework_keys.contains("hash.keccak256.base"));
+        assert!(initial_gas_schedule
+            .keys()
+            .all(|key| !framework_keys.contains(key)));
+        let natives = registry.natives(&initial_gas_schedule).unwrap();
+        assert_eq!(natives.len(), 3);
+        assert!(natives
+            .iter()
+            .all(|(native_address, module, _, _)| *native_address == address
+                && module.as_str() == "hash"));
+
+        let mut missing_gas_schedule = initial_gas_schedule.clone();
+        missing_gas_schedule.remove("0x42::hash.keccak256.base");
+        assert_eq!(
+            registry
+                .validate(&missing_gas_schedule)
+                .unwrap_err()
+                .missing,
+            vec!["0x42::hash.keccak256.base".to_string()]
+        );
+        assert!(registry.natives(&missing_gas_schedule).is_err());
+    }
+}
@@ -1,9 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -32,15 +31,15 @@ pub fn native_base58(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 1);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let encoded_address_bytes = pop_arg!(args, VectorRef);
+    let cost = gas_params.cost(encoded_address_bytes.as_bytes_ref().len());
 
     let Ok(bs58_raw_bytes) = bs58::decode(encoded_address_bytes.as_bytes_ref().to_vec()).into_vec()
     else {
-        return Ok(NativeResult::err(cost, E_DECODE_FAILED));
+        return Ok(NativeResult::err(
+            cost + gas_params.failure,
+            E_DECODE_FAILED,
+        ));
     };
 
     Ok(NativeResult::ok(
@@ -65,19 +64,19 @@ pub fn native_base58check(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 2);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let version_byte = pop_arg!(args, u8);
     let encoded_address_bytes = pop_arg!(args, VectorRef);
+    let cost = gas_params.cost(encoded_address_bytes.as_bytes_ref().len());
 
     let Ok(bs58_raw_bytes_without_checksum) =
         bs58::decode(encoded_address_bytes.as_bytes_ref().to_vec())
             .with_check(Some(version_byte))
             .into_vec()
     else {
-        return Ok(NativeResult::err(cost, E_DECODE_FAILED));
+        return Ok(NativeResult::err(
+            cost + gas_params.failure,
+            E_DECODE_FAILED,
+        ));
     };
 
     Ok(NativeResult::ok(
@@ -86,16 +85,7 @@ pub fn native_base58check(
     ))
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
-
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /***************************************************************************************************
  * module
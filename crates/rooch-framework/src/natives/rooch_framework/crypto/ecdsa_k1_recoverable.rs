@@ -1,14 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
 use fastcrypto::{
     hash::{Keccak256, Sha256},
     secp256k1::{recoverable::Secp256k1RecoverableSignature, Secp256k1PublicKey},
     traits::{RecoverableSignature, ToFromBytes},
 };
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -42,17 +41,17 @@ pub fn native_ecrecover(
     let msg_ref = msg.as_bytes_ref();
     let signature_ref = signature.as_bytes_ref();
 
-    // TODO(Gas): Charge the arg size dependent costs
-    let cost = gas_params.base;
+    let cost = gas_params.cost(msg_ref.len() + signature_ref.len());
+    let failure_cost = cost + gas_params.failure;
 
     let Ok(sig) = <Secp256k1RecoverableSignature as ToFromBytes>::from_bytes(&signature_ref) else {
-        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+        return Ok(NativeResult::err(failure_cost, E_INVALID_SIGNATURE));
     };
 
     let pk = match hash {
         KECCAK256 => sig.recover_with_hash::<Keccak256>(&msg_ref),
         SHA256 => sig.recover_with_hash::<Sha256>(&msg_ref),
-        _ => return Ok(NativeResult::err(cost, E_INVALID_HASH_TYPE)), // We should never reach here
+        _ => return Ok(NativeResult::err(failure_cost, E_INVALID_HASH_TYPE)), // We should never reach here
     };
 
     match pk {
@@ -60,7 +59,7 @@ pub fn native_ecrecover(
             cost,
             smallvec![Value::vector_u8(pk.as_bytes().to_vec())],
         )),
-        Err(_) => Ok(NativeResult::err(cost, E_FAIL_TO_RECOVER_PUBKEY)),
+        Err(_) => Ok(NativeResult::err(failure_cost, E_FAIL_TO_RECOVER_PUBKEY)),
     }
 }
 
@@ -76,8 +75,7 @@ pub fn native_decompress_pubkey(
     let pubkey = pop_arg!(args, VectorRef);
     let pubkey_ref = pubkey.as_bytes_ref();
 
-    // TODO(Gas): Charge the arg size dependent costs
-    let cost = gas_params.base;
+    let cost = gas_params.cost(pubkey_ref.len());
 
     match Secp256k1PublicKey::from_bytes(&pubkey_ref) {
         Ok(pubkey) => {
@@ -87,7 +85,10 @@ pub fn native_decompress_pubkey(
                 smallvec![Value::vector_u8(uncompressed.to_vec())],
             ))
         }
-        Err(_) => Ok(NativeResult::err(cost, E_INVALID_PUBKEY)),
+        Err(_) => Ok(NativeResult::err(
+            cost + gas_params.failure,
+            E_INVALID_PUBKEY,
+        )),
     }
 }
 
@@ -108,30 +109,38 @@ pub fn native_verify(
     let msg_ref = msg.as_bytes_ref();
     let signature_bytes_ref = signature_bytes.as_bytes_ref();
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
+    let cost = gas_params.cost(msg_ref.len() + signature_bytes_ref.len());
+    let failure_cost = cost + gas_params.failure;
 
     let Ok(sig) = <Secp256k1RecoverableSignature as ToFromBytes>::from_bytes(&signature_bytes_ref)
     else {
-        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+        return Ok(NativeResult::err(failure_cost, E_INVALID_SIGNATURE));
     };
 
     let pk = match hash {
         KECCAK256 => match sig.recover_with_hash::<Keccak256>(&msg_ref) {
             Ok(pk) => pk,
             Err(_) => {
-                return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+                return Ok(NativeResult::ok(
+                    failure_cost,
+                    smallvec![Value::bool(false)],
+                ));
             }
         },
         SHA256 => match sig.recover_with_hash::<Sha256>(&msg_ref) {
             Ok(pk) => pk,
             Err(_) => {
-                return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+                return Ok(NativeResult::ok(
+                    failure_cost,
+                    smallvec![Value::bool(false)],
+                ));
             }
         },
         _ => {
-            return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+            return Ok(NativeResult::ok(
+                failure_cost,
+                smallvec![Value::bool(false)],
+            ));
         }
     };
 
@@ -148,16 +157,7 @@ pub fn native_verify(
     Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
-
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /***************************************************************************************************
  * module
@@ -13,10 +13,9 @@ use move_vm_types::{
     values::{Value, VectorRef},
 };
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 
-use move_core_types::gas_algebra::InternalGas;
 use smallvec::smallvec;
 use std::collections::VecDeque;
 
@@ -36,10 +35,6 @@ pub fn native_verify(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 3);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let msg = pop_arg!(args, VectorRef);
     let msg_ref = msg.as_bytes_ref();
     let public_key_bytes = pop_arg!(args, VectorRef);
@@ -47,13 +42,22 @@ pub fn native_verify(
     let signature_bytes = pop_arg!(args, VectorRef);
     let signature_bytes_ref = signature_bytes.as_bytes_ref();
 
+    let cost =
+        gas_params.cost(msg_ref.len() + public_key_bytes_ref.len() + signature_bytes_ref.len());
+
     let Ok(signature) = <Ed25519Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
-        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+        return Ok(NativeResult::ok(
+            cost + gas_params.failure,
+            smallvec![Value::bool(false)],
+        ));
     };
 
     let Ok(public_key) = <Ed25519PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
     else {
-        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+        return Ok(NativeResult::ok(
+            cost + gas_params.failure,
+            smallvec![Value::bool(false)],
+        ));
     };
 
     Ok(NativeResult::ok(
@@ -62,16 +66,7 @@ pub fn native_verify(
     ))
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
-
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /***************************************************************************************************
  * module
@@ -1,12 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
+use bitcoin::key::{TapTweak, XOnlyPublicKey};
+use bitcoin::secp256k1::Secp256k1;
 use bitcoin::Network;
 use bitcoin::{Address, PublicKey};
 use bitcoin_bech32::{constants::Network as Bech32Network, u5, WitnessProgram};
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -38,11 +39,8 @@ pub fn native_base58(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 1);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let address_bytes = pop_arg!(args, VectorRef);
+    let cost = gas_params.cost(address_bytes.as_bytes_ref().len());
 
     let bs58_bytes = bs58::encode(address_bytes.as_bytes_ref().to_vec()).into_vec();
 
@@ -68,12 +66,9 @@ pub fn native_base58check(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 2);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let version_byte = pop_arg!(args, u8);
     let address_bytes = pop_arg!(args, VectorRef);
+    let cost = gas_params.cost(address_bytes.as_bytes_ref().len());
 
     let bs58_checksum_bytes = bs58::encode(address_bytes.as_bytes_ref().to_vec())
         .with_check_version(version_byte)
@@ -101,16 +96,14 @@ pub fn native_bech32(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 2);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let version = pop_arg!(args, u8);
     let public_key = pop_arg!(args, VectorRef);
+    let cost = gas_params.cost(public_key.as_bytes_ref().len());
+    let failure_cost = cost + gas_params.failure;
 
     // Version 0 for bech32 encoding and 1-16 are for bech32m encoding
     let Ok(version) = u5::try_from_u8(version) else {
-        return Ok(NativeResult::err(cost, E_INVALID_DATA));
+        return Ok(NativeResult::err(failure_cost, E_INVALID_DATA));
     };
 
     let Ok(witness_program) = WitnessProgram::new(
@@ -118,7 +111,7 @@ pub fn native_bech32(
         public_key.as_bytes_ref().to_vec(),
         Bech32Network::Bitcoin, // TODO network selection
     ) else {
-        return Ok(NativeResult::err(cost, E_INVALID_SCRIPT_VERSION));
+        return Ok(NativeResult::err(failure_cost, E_INVALID_SCRIPT_VERSION));
     };
 
     let address = witness_program.to_address();
@@ -146,15 +139,13 @@ pub fn native_p2pkh(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 1);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let public_key = pop_arg!(args, VectorRef);
     let public_key_bytes_ref = public_key.as_bytes_ref();
+    let cost = gas_params.cost(public_key_bytes_ref.len());
+    let failure_cost = cost + gas_params.failure;
 
     let Ok(bitcoin_public_key) = PublicKey::from_slice(&public_key_bytes_ref) else {
-        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+        return Ok(NativeResult::err(failure_cost, E_INVALID_PUBKEY));
     };
 
     // Generate the P2PKH address from the bitcoin public key
@@ -183,15 +174,13 @@ pub fn native_p2sh(
     debug_assert!(ty_args.is_empty());
     debug_assert!(args.len() == 1);
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
-
     let public_key = pop_arg!(args, VectorRef);
     let public_key_bytes_ref = public_key.as_bytes_ref();
+    let cost = gas_params.cost(public_key_bytes_ref.len());
+    let failure_cost = cost + gas_params.failure;
 
     let Ok(bitcoin_public_key) = PublicKey::from_slice(&public_key_bytes_ref) else {
-        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+        return Ok(NativeResult::err(failure_cost, E_INVALID_PUBKEY));
     };
 
     // Create a redeem script (e.g., P2PKH)
@@ -202,7 +191,7 @@ pub fn native_p2sh(
         redeem_script,
         Network::Bitcoin, // TODO network selection
     ) else {
-        return Ok(NativeResult::err(cost, E_EXCESSIVE_SCRIPT_SIZE));
+        return Ok(NativeResult::err(failure_cost, E_EXCESSIVE_SCRIPT_SIZE));
     };
     let p2sh_address_bytes = p2sh_address.to_string().as_bytes().to_vec();
 
@@ -212,17 +201,42 @@ pub fn native_p2sh(
     ))
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
+/***************************************************************************************************
+ * native fun taproot_output_key
+ * Implementation of the Move native function `encoding::taproot_output_key(public_key: &vector<u8>): vector<u8>`
+ *   gas cost: encoding_taproot_output_key_cost_base                               | base cost for function call and fixed opers
+ *              + encoding_taproot_output_key_data_cost_per_byte * msg.len()       | cost depends on length of message
+ **************************************************************************************************/
+pub fn native_taproot_output_key(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
 
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
+    let public_key = pop_arg!(args, VectorRef);
+    let public_key_bytes_ref = public_key.as_bytes_ref();
+    let cost = gas_params.cost(public_key_bytes_ref.len());
+    let failure_cost = cost + gas_params.failure;
+
+    let Ok(bitcoin_public_key) = PublicKey::from_slice(&public_key_bytes_ref) else {
+        return Ok(NativeResult::err(failure_cost, E_INVALID_PUBKEY));
+    };
+
+    // Tweak the internal key without a script tree, the key path only output of BIP86
+    let internal_key = XOnlyPublicKey::from(bitcoin_public_key.inner);
+    let (output_key, _parity) = internal_key.tap_tweak(&Secp256k1::verification_only(), None);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(output_key.to_inner().serialize())],
+    ))
 }
 
+pub type FromBytesGasParameters = InputGasParameters;
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -234,6 +248,7 @@ pub struct GasParameters {
     pub bech32: FromBytesGasParameters,
     pub p2pkh: FromBytesGasParameters,
     pub p2sh: FromBytesGasParameters,
+    pub taproot_output_key: FromBytesGasParameters,
 }
 
 impl GasParameters {
@@ -244,6 +259,7 @@ impl GasParameters {
             bech32: FromBytesGasParameters::zeros(),
             p2pkh: FromBytesGasParameters::zeros(),
             p2sh: FromBytesGasParameters::zeros(),
+            taproot_output_key: FromBytesGasParameters::zeros(),
         }
     }
 }
@@ -258,6 +274,10 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
         ("bech32", make_native(gas_params.bech32, native_bech32)),
         ("p2pkh", make_native(gas_params.p2pkh, native_p2pkh)),
         ("p2sh", make_native(gas_params.p2sh, native_p2sh)),
+        (
+            "taproot_output_key",
+            make_native(gas_params.taproot_output_key, native_taproot_output_key),
+        ),
     ];
 
     make_module_natives(natives)
@@ -1,10 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
 use fastcrypto::hash::{Blake2b256, HashFunction, Keccak256, Ripemd160};
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -16,7 +15,7 @@ use smallvec::smallvec;
 use std::collections::VecDeque;
 
 fn hash<H: HashFunction<DIGEST_SIZE>, const DIGEST_SIZE: usize>(
-    _: &mut NativeContext,
+    gas_params: &FromBytesGasParameters,
     ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -24,14 +23,12 @@ fn hash<H: HashFunction<DIGEST_SIZE>, const DIGEST_SIZE: usize>(
     debug_assert!(args.len() == 1);
 
     let msg = pop_arg!(args, VectorRef);
-
-    // TODO(Gas): Charge the arg size dependent costs
+    let msg_ref = msg.as_bytes_ref();
+    let cost = gas_params.cost(msg_ref.len());
 
     Ok(NativeResult::ok(
-        0.into(),
-        smallvec![Value::vector_u8(
-            H::digest(msg.as_bytes_ref().as_slice()).digest
-        )],
+        cost,
+        smallvec![Value::vector_u8(H::digest(msg_ref.as_slice()).digest)],
     ))
 }
 
@@ -43,12 +40,12 @@ fn hash<H: HashFunction<DIGEST_SIZE>, const DIGEST_SIZE: usize>(
  *              + hash_keccak256_data_cost_per_block * num_blocks     | cost depends on number of blocks in message
  **************************************************************************************************/
 pub fn native_keccak256(
-    _gas_params: &FromBytesGasParameters,
-    context: &mut NativeContext,
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
     ty_args: Vec<Type>,
     args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
-    hash::<Keccak256, 32>(context, ty_args, args)
+    hash::<Keccak256, 32>(gas_params, ty_args, args)
 }
 
 /***************************************************************************************************
@@ -59,12 +56,12 @@ pub fn native_keccak256(
  *              + hash_blake2b256_data_cost_per_block * num_blocks     | cost depends on number of blocks in message
  **************************************************************************************************/
 pub fn native_blake2b256(
-    _gas_params: &FromBytesGasParameters,
-    context: &mut NativeContext,
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
     ty_args: Vec<Type>,
     args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
-    hash::<Blake2b256, 32>(context, ty_args, args)
+    hash::<Blake2b256, 32>(gas_params, ty_args, args)
 }
 
 /***************************************************************************************************
@@ -75,24 +72,15 @@ pub fn native_blake2b256(
  *              + hash_ripemd160_data_cost_per_block * num_blocks     | cost depends on number of blocks in message
  **************************************************************************************************/
 pub fn native_ripemd160(
-    _gas_params: &FromBytesGasParameters,
-    context: &mut NativeContext,
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
     ty_args: Vec<Type>,
     args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
-    hash::<Ripemd160, 20>(context, ty_args, args)
-}
-
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
+    hash::<Ripemd160, 20>(gas_params, ty_args, args)
 }
 
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /***************************************************************************************************
  * module
@@ -1,14 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
 use fastcrypto::{
     hash::{Keccak256, Sha256},
     secp256k1::schnorr::{SchnorrPublicKey, SchnorrSignature},
     traits::ToFromBytes,
 };
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -43,17 +42,22 @@ pub fn native_verify(
     let signature_bytes_ref = signature_bytes.as_bytes_ref();
     let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
 
-    // TODO(Gas): Charge the arg size dependent costs
-
-    let cost = gas_params.base;
+    let cost =
+        gas_params.cost(msg_ref.len() + public_key_bytes_ref.len() + signature_bytes_ref.len());
 
     let Ok(sign) = SchnorrSignature::from_bytes(&signature_bytes_ref) else {
-        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+        return Ok(NativeResult::err(
+            cost + gas_params.failure,
+            E_INVALID_SIGNATURE,
+        ));
     };
 
     let Ok(public_key) = <SchnorrPublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
     else {
-        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+        return Ok(NativeResult::err(
+            cost + gas_params.failure,
+            E_INVALID_PUBKEY,
+        ));
     };
 
     let result = match hash {
@@ -69,16 +73,7 @@ pub fn native_verify(
     Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
-
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /***************************************************************************************************
  * module
@@ -2,6 +2,4 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod account;
-//TODO remove bcs;
-pub mod bcs;
 pub mod crypto;
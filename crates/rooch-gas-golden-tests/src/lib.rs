@@ -0,0 +1,235 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The golden-file gas regression tests.
+//! The gas used by a transaction is a part of the consensus, a change of the cost table, the native gas parameters
+//! or a gas formula changes the state root of every node replaying the transaction. The harness executes a fixed
+//! corpus of transactions and compares the gas statements and the gas schedule with the checked-in golden files,
+//! so such a change fails the tests with a diff instead of landing silently.
+//!
+//! An intended gas change is accepted by regenerating the golden files with `UB=1 cargo test -p rooch-gas-golden-tests`
+//! and reviewing their diff.
+
+use anyhow::{anyhow, bail, Result};
+use move_binary_format::file_format::empty_module;
+use move_command_line_common::testing::{
+    add_update_baseline_fix, format_diff, read_env_update_baseline,
+};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::ident_str;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::value::MoveValue;
+use moveos_types::module_binding::ModuleBinding;
+use moveos_types::move_std::ascii::MoveAsciiString;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveState;
+use moveos_types::transaction::{MoveAction, TransactionOutput};
+use rooch_framework_tests::binding_test::RustBindingTest;
+use rooch_genesis::RoochGenesis;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::ethereum_validator::EthereumValidatorModule;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::framework::timestamp::TimestampModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The golden file of the gas used by the corpus
+pub const CORPUS_GOLDEN_FILE: &str = "corpus_gas.exp";
+/// The golden file of the cost table and the native gas schedule
+pub const GAS_SCHEDULE_GOLDEN_FILE: &str = "gas_schedule.exp";
+
+/// A transaction of the corpus, the transactions are executed in order by the same sender
+pub struct GasCase {
+    pub name: &'static str,
+    pub action: MoveAction,
+}
+
+impl GasCase {
+    pub fn new(name: &'static str, action: MoveAction) -> Self {
+        Self { name, action }
+    }
+}
+
+fn create_account_action(address: AccountAddress) -> MoveAction {
+    MoveAction::new_function_call(
+        FunctionId::new(
+            ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, ident_str!("account").to_owned()),
+            ident_str!("create_account_entry").to_owned(),
+        ),
+        vec![],
+        vec![MoveValue::Address(address).simple_serialize().unwrap()],
+    )
+}
+
+/// Publish a module without any function at the address of the sender
+fn publish_empty_module_action(sender: AccountAddress) -> MoveAction {
+    let mut module = empty_module();
+    module.address_identifiers[0] = sender;
+    module.identifiers[0] = Identifier::new("golden_empty").unwrap();
+    let mut bytes = vec![];
+    module.serialize(&mut bytes).unwrap();
+    MoveAction::new_module_bundle(vec![bytes])
+}
+
+/// Create a session key with many scopes, the session key is stored with all the scopes
+fn create_session_key_with_scopes_action(scopes: usize) -> MoveAction {
+    let module_name = MoveAsciiString::from_str(Empty::MODULE_NAME.as_str()).unwrap();
+    let function_name = MoveAsciiString::from_str(Empty::EMPTY_FUNCTION_NAME.as_str()).unwrap();
+    MoveAction::new_function_call(
+        FunctionId::new(
+            ModuleId::new(
+                ROOCH_FRAMEWORK_ADDRESS,
+                ident_str!("session_key").to_owned(),
+            ),
+            ident_str!("create_session_key_with_multi_scope_entry").to_owned(),
+        ),
+        vec![],
+        vec![
+            MoveValue::vector_u8(vec![1u8; 32])
+                .simple_serialize()
+                .unwrap(),
+            MoveValue::Vector(vec![MoveValue::Address(ROOCH_FRAMEWORK_ADDRESS); scopes])
+                .simple_serialize()
+                .unwrap(),
+            MoveValue::Vector(vec![module_name.to_move_value(); scopes])
+                .simple_serialize()
+                .unwrap(),
+            MoveValue::Vector(vec![function_name.to_move_value(); scopes])
+                .simple_serialize()
+                .unwrap(),
+            MoveValue::U64(100).simple_serialize().unwrap(),
+        ],
+    )
+}
+
+/// The compressed secp256k1 public key of the generator point
+const SECP256K1_GENERATOR_PUBLIC_KEY: &str =
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+/// The corpus of the transactions, a new transaction should be appended to the end,
+/// so the gas used of the existing transactions does not change.
+pub fn gas_corpus(sender: AccountAddress) -> Vec<GasCase> {
+    let account = AccountAddress::from_hex_literal("0x42").unwrap();
+    vec![
+        GasCase::new(
+            "empty",
+            MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]),
+        ),
+        GasCase::new("create_account", create_account_action(account)),
+        // The account is already created, the transaction aborts
+        GasCase::new("create_account_abort", create_account_action(account)),
+        GasCase::new(
+            "fast_forward_timestamp",
+            TimestampModule::create_fast_forward_seconds_for_local_action(1),
+        ),
+        GasCase::new(
+            "create_session_key",
+            SessionKeyModule::create_session_key_action(
+                vec![0u8; 32],
+                SessionScope::new(
+                    ROOCH_FRAMEWORK_ADDRESS,
+                    Empty::MODULE_NAME.as_str(),
+                    Empty::EMPTY_FUNCTION_NAME.as_str(),
+                ),
+                100,
+            ),
+        ),
+        GasCase::new("publish_module", publish_empty_module_action(sender)),
+        // The public key is decompressed and hashed by the natives, the native validator of the sender is kept
+        GasCase::new(
+            "rotate_ethereum_authentication_key",
+            EthereumValidatorModule::rotate_authentication_key_action(
+                hex::decode(SECP256K1_GENERATOR_PUBLIC_KEY).unwrap(),
+            ),
+        ),
+        GasCase::new(
+            "create_session_key_with_scopes",
+            create_session_key_with_scopes_action(32),
+        ),
+    ]
+}
+
+fn write_gas_output(output: &mut String, name: &str, tx_output: &TransactionOutput) {
+    let gas_breakdown = &tx_output.gas_breakdown;
+    writeln!(output, "== {}", name).unwrap();
+    writeln!(output, "status: {:?}", tx_output.status).unwrap();
+    writeln!(output, "gas_used: {}", tx_output.gas_used).unwrap();
+    writeln!(output, "instruction_gas: {}", gas_breakdown.instruction_gas).unwrap();
+    writeln!(output, "stack_gas: {}", gas_breakdown.stack_gas).unwrap();
+    writeln!(output, "native_gas: {}", gas_breakdown.native_gas).unwrap();
+    writeln!(output, "storage_gas: {}", gas_breakdown.storage_gas).unwrap();
+    writeln!(output, "publish_gas: {}", gas_breakdown.publish_gas).unwrap();
+    writeln!(output, "storage_rebate: {}", gas_breakdown.storage_rebate).unwrap();
+}
+
+/// Execute the corpus of the sender of the test keystore on a new chain, and render the gas used by every transaction
+pub fn run_gas_corpus(corpus: impl FnOnce(AccountAddress) -> Vec<GasCase>) -> Result<String> {
+    let mut binding_test = RustBindingTest::new()?;
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let mut sequence_number = 0;
+    let mut output = String::new();
+    for case in corpus(sender.into()) {
+        let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, case.action);
+        let tx = keystore.sign_transaction(&sender, tx_data, None)?;
+        let result = binding_test.execute_as_result(tx)?;
+        write_gas_output(&mut output, case.name, &result.output);
+        sequence_number += 1;
+    }
+    Ok(output)
+}
+
+/// Render the entries of the genesis cost table metering the corpus, and of the initial native gas schedule
+pub fn render_gas_schedule() -> Result<String> {
+    let binding_test = RustBindingTest::new()?;
+    let mut output = String::new();
+    writeln!(output, "== cost_table").unwrap();
+    for (key, value) in binding_test.executor().genesis_cost_table().to_entries() {
+        writeln!(output, "{} = {}", key, value).unwrap();
+    }
+    writeln!(output, "== native_gas_schedule").unwrap();
+    for (key, value) in RoochGenesis::native_gas_schedule()? {
+        writeln!(output, "{} = {}", key, value).unwrap();
+    }
+    Ok(output)
+}
+
+/// The directory of the golden files
+pub fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("golden")
+}
+
+/// Compare the `output` with the golden file, the golden file is regenerated if the update baseline env is set.
+pub fn check_golden(golden_path: &Path, output: &str) -> Result<()> {
+    if read_env_update_baseline() {
+        if let Some(dir) = golden_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(golden_path, output)?;
+        return Ok(());
+    }
+    if !golden_path.exists() {
+        bail!(
+            "missing golden {}, run with UB=1 to generate it",
+            golden_path.display()
+        );
+    }
+    let expected = std::fs::read_to_string(golden_path)
+        .map_err(|e| anyhow!("Read the golden {} error: {}", golden_path.display(), e))?
+        .replace("\r\n", "\n");
+    if output != expected {
+        let msg = format!(
+            "The gas differs from the golden file {}, a gas change breaks the consensus:\n{}",
+            golden_path.display(),
+            format_diff(expected, output),
+        );
+        bail!(add_update_baseline_fix(msg))
+    }
+    Ok(())
+}
@@ -0,0 +1,19 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use rooch_gas_golden_tests::{
+    check_golden, gas_corpus, golden_dir, render_gas_schedule, run_gas_corpus, CORPUS_GOLDEN_FILE,
+    GAS_SCHEDULE_GOLDEN_FILE,
+};
+
+#[test]
+fn test_corpus_gas_golden() {
+    let output = run_gas_corpus(gas_corpus).unwrap();
+    check_golden(&golden_dir().join(CORPUS_GOLDEN_FILE), &output).unwrap();
+}
+
+#[test]
+fn test_gas_schedule_golden() {
+    let output = render_gas_schedule().unwrap();
+    check_golden(&golden_dir().join(GAS_SCHEDULE_GOLDEN_FILE), &output).unwrap();
+}
@@ -0,0 +1,180 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, parse_macro_input, Data, DeriveInput, Error, Fields, Meta, NestedMeta, Path, Token,
+};
+
+/// Derive the `FromOnChainGasSchedule`, `ToOnChainGasSchedule` and `InitialGasSchedule` impls of an aggregate
+/// of the native gas parameters, every field is the gas parameters of a native module.
+///
+/// The field marked with `#[gas_schedule(skip)]` is not a part of the gas schedule, it is initialized with
+/// `Default::default()`.
+///
+/// ```ignore
+/// #[derive(Debug, Clone, GasSchedule)]
+/// pub struct GasParameters {
+///     account: account::GasParameters,
+///     hash: hash::GasParameters,
+/// }
+/// ```
+#[proc_macro_derive(GasSchedule, attributes(gas_schedule))]
+pub fn derive_gas_schedule(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implement the gas schedule traits for an aggregate of the native gas parameters defined in another crate,
+/// the fields are listed in the braces, and all of them are the gas parameters of the native modules.
+///
+/// ```ignore
+/// impl_gas_schedule!(MoveOSGasParameters { move_stdlib, move_nursery });
+/// ```
+#[proc_macro]
+pub fn impl_gas_schedule(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as GasScheduleFields);
+    expand_impls(&input.ty, &input.fields, &[]).into()
+}
+
+struct GasScheduleFields {
+    ty: Path,
+    fields: Vec<Ident>,
+}
+
+impl Parse for GasScheduleFields {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty: Path = input.parse()?;
+        let content;
+        braced!(content in input);
+        let fields = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+        Ok(Self {
+            ty,
+            fields: fields.into_iter().collect(),
+        })
+    }
+}
+
+fn expand_derive(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "GasSchedule can only be derived for the structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "GasSchedule can only be derived for the structs",
+            ))
+        }
+    };
+    let mut fields = vec![];
+    let mut skipped = vec![];
+    for field in &named.named {
+        let ident = field
+            .ident
+            .clone()
+            .expect("named field should have an ident");
+        if is_skipped(&field.attrs)? {
+            skipped.push(ident);
+        } else {
+            fields.push(ident);
+        }
+    }
+    let ident = &input.ident;
+    Ok(expand_impls(&syn::parse_quote!(#ident), &fields, &skipped))
+}
+
+fn is_skipped(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in attrs {
+        if !attr.path.is_ident("gas_schedule") {
+            continue;
+        }
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                            skipped = true;
+                        }
+                        other => {
+                            return Err(Error::new_spanned(
+                                other,
+                                "unknown gas_schedule attribute, expected `skip`",
+                            ))
+                        }
+                    }
+                }
+            }
+            other => {
+                return Err(Error::new_spanned(
+                    other,
+                    "expected the attribute as `#[gas_schedule(skip)]`",
+                ))
+            }
+        }
+    }
+    Ok(skipped)
+}
+
+fn expand_impls(ty: &Path, fields: &[Ident], skipped: &[Ident]) -> TokenStream2 {
+    let gas_member = quote!(::rooch_framework::natives::gas_parameter::gas_member);
+    let from_fields = fields.iter().map(|field| {
+        quote!(#field: #gas_member::from_on_chain_gas_schedule_or_initial(gas_schedule, &mut missing))
+    });
+    let to_fields = fields.iter().map(|field| {
+        quote!(entries.extend(#gas_member::ToOnChainGasSchedule::to_on_chain_gas_schedule(&self.#field)))
+    });
+    let initial_fields = fields
+        .iter()
+        .map(|field| quote!(#field: #gas_member::InitialGasSchedule::initial()));
+    quote! {
+        impl #gas_member::FromOnChainGasSchedule for #ty {
+            fn from_on_chain_gas_schedule(
+                gas_schedule: &::std::collections::BTreeMap<String, u64>,
+            ) -> ::std::result::Result<Self, #gas_member::GasScheduleError> {
+                #[allow(unused_mut)]
+                let mut missing: Vec<String> = vec![];
+                let params = Self {
+                    #(#from_fields,)*
+                    #(#skipped: ::std::default::Default::default(),)*
+                };
+                if missing.is_empty() {
+                    Ok(params)
+                } else {
+                    Err(#gas_member::GasScheduleError::missing(missing))
+                }
+            }
+        }
+
+        impl #gas_member::ToOnChainGasSchedule for #ty {
+            fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
+                #[allow(unused_mut)]
+                let mut entries = vec![];
+                #(#to_fields;)*
+                entries
+            }
+        }
+
+        impl #gas_member::InitialGasSchedule for #ty {
+            fn initial() -> Self {
+                Self {
+                    #(#initial_fields,)*
+                    #(#skipped: ::std::default::Default::default(),)*
+                }
+            }
+        }
+    }
+}
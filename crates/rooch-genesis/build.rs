@@ -2,8 +2,31 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::env::current_dir;
+use std::path::{Path, PathBuf};
+
+/// The max version of the framework packages released by `rooch-framework-release`, 0 if none is released
+fn released_framework_version(release_dir: &Path) -> u64 {
+    std::fs::read_dir(release_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u64>().ok())
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0)
+}
 
 fn main() {
+    let release_crate_dir = PathBuf::from(
+        std::env::var("CARGO_MANIFEST_DIR").expect("Should be able to get the manifest dir"),
+    )
+    .join("../rooch-framework-release");
+    println!("cargo:rerun-if-changed={}", release_crate_dir.display());
+    println!(
+        "cargo:rustc-env=ROOCH_FRAMEWORK_RELEASE_VERSION={}",
+        released_framework_version(&release_crate_dir.join("compiled"))
+    );
+
     if std::env::var("SKIP_STDLIB_BUILD").is_err() {
         let current_dir = current_dir().expect("Should be able to get current dir");
         // Get the project root directory
@@ -3,24 +3,45 @@
 
 use anyhow::Result;
 use move_binary_format::{errors::Location, CompiledModule};
-use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+    resolver::ModuleResolver,
+};
 use move_vm_runtime::{config::VMConfig, native_functions::NativeFunction};
+use moveos::gas::table::CostTable;
 use moveos::moveos::{MoveOS, MoveOSConfig};
 use moveos_stdlib_builder::Stdlib;
 use moveos_store::{config_store::ConfigDBStore, MoveOSStore};
+use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::h256;
 use moveos_types::h256::H256;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state_resolver::MoveOSResolverProxy;
 use moveos_types::transaction::MoveAction;
 use once_cell::sync::Lazy;
-use rooch_framework::natives::gas_parameter::gas_member::InitialGasSchedule;
+use rooch_framework::natives::gas_parameter::gas_member::{
+    collect_gas_schedule, InitialGasSchedule, ToOnChainGasSchedule,
+};
+use rooch_framework::natives::gas_parameter::gas_schedule::{
+    check_gas_schedule_keys, gate_native_gas_schedule, load_cost_table, load_genesis_cost_table,
+    load_native_gas_parameters,
+};
+use rooch_types::addresses::{
+    BITCOIN_MOVE_ADDRESS, ROOCH_FRAMEWORK_ADDRESS, ROOCH_FRAMEWORK_VERSION_ADDRESSES,
+};
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::bitcoin::network::Network;
 use rooch_types::error::GenesisError;
+use rooch_types::framework::capability::UpgradeCap;
+use rooch_types::framework::gas_schedule::GasScheduleConfig;
 use rooch_types::framework::genesis::GenesisContext;
+use rooch_types::framework::migration::MigrationModule;
 use rooch_types::transaction::rooch::RoochTransaction;
 use rooch_types::{address::RoochAddress, chain_id::RoochChainID};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 use std::{
     fs::File,
@@ -28,6 +49,16 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// The on-chain framework version of the embedded stdlib, derived from the framework packages released in
+/// `rooch-framework-release/compiled`. The release 1 is the genesis framework and every later release upgrades it,
+/// the framework version is 0 at genesis and it is increased by every framework upgrade.
+pub static EMBEDDED_FRAMEWORK_VERSION: Lazy<u64> = Lazy::new(|| {
+    env!("ROOCH_FRAMEWORK_RELEASE_VERSION")
+        .parse::<u64>()
+        .expect("The released framework version should be a number")
+        .saturating_sub(1)
+});
+
 pub static ROOCH_LOCAL_GENESIS: Lazy<RoochGenesis> = Lazy::new(|| {
     // TODO: For now, ROOCH_LOCAL_GENESIS in only used in integration-test.
     // There is no need to upgrade framework, so we set sequencer to 0x0.
@@ -64,6 +95,48 @@ pub struct RoochGenesis {
     pub genesis_package: GenesisPackage,
 }
 
+/// The result of the check of the embedded framework against the on-chain framework
+#[derive(Clone, Debug)]
+pub struct FrameworkCheckResult {
+    pub embedded_version: u64,
+    pub onchain_version: u64,
+    pub embedded_hash: H256,
+    pub onchain_hash: H256,
+    /// The embedded modules which are missing on-chain or have different bytecode
+    pub mismatched_modules: Vec<ModuleId>,
+}
+
+impl FrameworkCheckResult {
+    /// The framework modules are the same, the versions only tell which framework is newer
+    pub fn is_matched(&self) -> bool {
+        self.embedded_hash == self.onchain_hash
+    }
+
+    /// The on-chain framework is older than the embedded one, such as the state of an older node,
+    /// it is upgraded to the embedded framework instead of refusing the writes.
+    pub fn is_upgradable(&self) -> bool {
+        !self.is_matched() && self.onchain_version < self.embedded_version
+    }
+}
+
+impl std::fmt::Display for FrameworkCheckResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "embedded framework version {} hash {:?}, on-chain framework version {} hash {:?}, mismatched modules: [{}]",
+            self.embedded_version,
+            self.embedded_hash,
+            self.onchain_version,
+            self.onchain_hash,
+            self.mismatched_modules
+                .iter()
+                .map(|module_id| module_id.short_str_lossless())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
 pub enum BuildOption {
     Fresh,
     Release,
@@ -90,6 +163,10 @@ impl RoochGenesis {
             vm_config: VMConfig::default(),
         };
 
+        // The keys of the native gas schedule must be unique, or the on-chain gas schedule is ambiguous
+        Self::native_gas_schedule()?;
+        // The genesis cost table is stored on-chain by the genesis, reject the invalid entries before the genesis
+        load_genesis_cost_table(&genesis_ctx.gas_schedule_entries)?;
         let rooch_framework_gas_params = rooch_framework::natives::GasParameters::zeros();
         let bitcoin_move_gas_params = bitcoin_move::natives::GasParameters::zeros();
         let genesis_package = GenesisPackage::build(genesis_ctx, bitcoin_genesis_ctx, option)?;
@@ -119,13 +196,111 @@ impl RoochGenesis {
         self.genesis_package.bitcoin_genesis_ctx.clone()
     }
 
-    pub fn all_natives(&self) -> Vec<(AccountAddress, Identifier, Identifier, NativeFunction)> {
+    /// The cost table of the genesis, the initial cost schedule overridden by the cost table entries
+    /// of the genesis gas schedule.
+    pub fn cost_table(&self) -> Result<CostTable> {
+        load_genesis_cost_table(&self.genesis_package.genesis_ctx.gas_schedule_entries)
+    }
+
+    pub fn all_natives(
+        &self,
+    ) -> Result<Vec<(AccountAddress, Identifier, Identifier, NativeFunction)>> {
+        Self::all_natives_with_gas_params(
+            self.rooch_framework_gas_params.clone(),
+            self.bitcoin_move_gas_params.clone(),
+        )
+    }
+
+    /// The natives metered by the on-chain gas schedule version, the builtin version keeps the gas parameters
+    /// of the genesis, the other versions override the initial gas parameters with the schedule entries.
+    /// The native gas entries introduced after the gas `feature_version` of the schedule are not charged.
+    pub fn all_natives_with_gas_schedule(
+        &self,
+        gas_schedule: &GasScheduleConfig,
+        feature_version: u64,
+    ) -> Result<Vec<(AccountAddress, Identifier, Identifier, NativeFunction)>> {
+        if gas_schedule.is_builtin() {
+            return self.all_natives();
+        }
+        let native_keys = Self::native_gas_schedule()?.into_keys().collect();
+        check_gas_schedule_keys(gas_schedule, &native_keys)?;
+        Self::all_natives_with_gas_params(
+            load_native_gas_parameters(gas_schedule, feature_version)?,
+            load_native_gas_parameters(gas_schedule, feature_version)?,
+        )
+    }
+
+    /// The initial native gas schedule of all the frameworks, the keys shared by the gas parameters are reported.
+    pub fn native_gas_schedule() -> Result<BTreeMap<String, u64>> {
+        collect_gas_schedule(
+            rooch_framework::natives::GasParameters::initial()
+                .to_on_chain_gas_schedule()
+                .into_iter()
+                .chain(bitcoin_move::natives::GasParameters::initial().to_on_chain_gas_schedule()),
+        )
+        .map_err(|e| anyhow::anyhow!("The native gas schedule is ambiguous: {}", e))
+    }
+
+    /// The gas schedule metering the transactions by the on-chain gas schedule version, the entries of its cost table
+    /// and of its natives. The builtin version is the genesis cost table and the natives of the genesis, which charge
+    /// no gas, the other versions are overridden by the schedule entries as they are loaded by the executor.
+    pub fn effective_gas_schedule(
+        genesis_cost_table: &CostTable,
+        gas_schedule: &GasScheduleConfig,
+    ) -> Result<BTreeMap<String, u64>> {
+        let cost_table = load_cost_table(genesis_cost_table, gas_schedule)?;
+        let native_entries = if gas_schedule.is_builtin() {
+            collect_gas_schedule(
+                rooch_framework::natives::GasParameters::zeros()
+                    .to_on_chain_gas_schedule()
+                    .into_iter()
+                    .chain(
+                        bitcoin_move::natives::GasParameters::zeros().to_on_chain_gas_schedule(),
+                    ),
+            )
+            .map_err(|e| anyhow::anyhow!("The native gas schedule is ambiguous: {}", e))?
+        } else {
+            let mut native_entries = Self::native_gas_schedule()?;
+            for (key, val) in gas_schedule.entries_map() {
+                if let Some(entry) = native_entries.get_mut(&key) {
+                    *entry = val;
+                }
+            }
+            gate_native_gas_schedule(&mut native_entries, cost_table.feature_version());
+            native_entries
+        };
+        let mut entries = cost_table.to_entries();
+        entries.extend(native_entries);
+        Ok(entries)
+    }
+
+    fn all_natives_with_gas_params(
+        rooch_framework_gas_params: rooch_framework::natives::GasParameters,
+        bitcoin_move_gas_params: bitcoin_move::natives::GasParameters,
+    ) -> Result<Vec<(AccountAddress, Identifier, Identifier, NativeFunction)>> {
         let mut rooch_framework_native_tables =
-            rooch_framework::natives::all_natives(self.rooch_framework_gas_params.clone());
-        let bitcoin_move_native_table =
-            bitcoin_move::natives::all_natives(self.bitcoin_move_gas_params.clone());
+            rooch_framework::natives::all_natives(rooch_framework_gas_params)?;
+        let bitcoin_move_native_table = bitcoin_move::natives::all_natives(bitcoin_move_gas_params);
+        // The framework versions deployed side by side declare the same natives at their own addresses
+        let framework_version_native_tables = rooch_framework_native_tables
+            .iter()
+            .filter(|(address, _, _, _)| *address == ROOCH_FRAMEWORK_ADDRESS)
+            .flat_map(|(_, module_name, func_name, func)| {
+                ROOCH_FRAMEWORK_VERSION_ADDRESSES
+                    .iter()
+                    .map(move |address| {
+                        (
+                            *address,
+                            module_name.clone(),
+                            func_name.clone(),
+                            func.clone(),
+                        )
+                    })
+            })
+            .collect::<Vec<_>>();
+        rooch_framework_native_tables.extend(framework_version_native_tables);
         rooch_framework_native_tables.extend(bitcoin_move_native_table);
-        rooch_framework_native_tables
+        Ok(rooch_framework_native_tables)
     }
 
     pub fn genesis_package_hash(&self) -> H256 {
@@ -173,6 +348,86 @@ impl RoochGenesis {
         }
         Ok(())
     }
+
+    /// The bytecode of the embedded framework modules, keyed by the module id
+    pub fn framework_modules(&self) -> Result<BTreeMap<ModuleId, Vec<u8>>> {
+        self.genesis_package
+            .module_bundle()
+            .into_iter()
+            .map(|bytes| {
+                let module = CompiledModule::deserialize(&bytes)
+                    .map_err(|e| e.finish(Location::Undefined))?;
+                Ok((module.self_id(), bytes))
+            })
+            .collect()
+    }
+
+    /// The action upgrading the on-chain framework to the embedded framework, it is sent by the owner of the UpgradeCap
+    pub fn framework_upgrade_action(&self) -> Result<MoveAction> {
+        let bundles = self
+            .genesis_package
+            .genesis_txs
+            .iter()
+            .filter_map(|tx| match tx.action() {
+                MoveAction::ModuleBundle(bundle) => Some((tx.sender().into(), bundle.clone())),
+                _ => None,
+            })
+            .collect::<BTreeMap<AccountAddress, Vec<Vec<u8>>>>();
+        let mut args = vec![bcs::to_bytes(&UpgradeCap::object_id())?];
+        for address in [
+            MOVE_STD_ADDRESS,
+            MOVEOS_STD_ADDRESS,
+            ROOCH_FRAMEWORK_ADDRESS,
+            BITCOIN_MOVE_ADDRESS,
+        ] {
+            args.push(bcs::to_bytes(
+                bundles.get(&address).map(Vec::as_slice).unwrap_or_default(),
+            )?);
+        }
+        Ok(MoveAction::new_function_call(
+            FunctionId::new(
+                ModuleId::new(
+                    ROOCH_FRAMEWORK_ADDRESS,
+                    Identifier::new("upgrade".to_owned())?,
+                ),
+                Identifier::new("upgrade_entry".to_owned())?,
+            ),
+            vec![],
+            args,
+        ))
+    }
+
+    /// Check the embedded framework against the framework modules in the state of the `moveos`.
+    /// The node should not execute the transactions with a mismatched framework,
+    /// because the natives and the Rust bindings in the binary are built with the embedded framework.
+    pub fn check_framework(&self, moveos: &MoveOS) -> Result<FrameworkCheckResult> {
+        let onchain_version = moveos
+            .as_module_binding::<MigrationModule>()
+            .framework_version()?;
+        let resolver = MoveOSResolverProxy(moveos.state().clone());
+        let embedded_modules = self.framework_modules()?;
+        let mut onchain_modules = BTreeMap::new();
+        let mut mismatched_modules = vec![];
+        for (module_id, bytes) in embedded_modules.iter() {
+            let onchain_bytes = resolver.get_module(module_id)?.unwrap_or_default();
+            if &onchain_bytes != bytes {
+                mismatched_modules.push(module_id.clone());
+            }
+            onchain_modules.insert(module_id.clone(), onchain_bytes);
+        }
+        Ok(FrameworkCheckResult {
+            embedded_version: *EMBEDDED_FRAMEWORK_VERSION,
+            onchain_version,
+            embedded_hash: framework_hash(&embedded_modules)?,
+            onchain_hash: framework_hash(&onchain_modules)?,
+            mismatched_modules,
+        })
+    }
+}
+
+/// The hash of the framework modules, the modules are ordered by the module id
+pub fn framework_hash(modules: &BTreeMap<ModuleId, Vec<u8>>) -> Result<H256> {
+    h256::sha3_256_of_bcs(modules)
 }
 
 static GENESIS_STDLIB_BYTES: &[u8] = include_bytes!("../generated/stdlib");
@@ -207,7 +462,7 @@ impl GenesisPackage {
         };
         let mut moveos = MoveOS::new(
             MoveOSStore::mock_moveos_store()?,
-            rooch_framework::natives::all_natives(gas_parameters),
+            rooch_framework::natives::all_natives(gas_parameters)?,
             vm_config,
             vec![],
             vec![],
@@ -250,17 +505,24 @@ impl GenesisPackage {
         Ok(())
     }
 
-    pub fn modules(&self) -> Result<Vec<CompiledModule>> {
+    /// The bytecode of all the modules in the genesis transactions
+    pub fn module_bundle(&self) -> Vec<Vec<u8>> {
         self.genesis_txs
             .iter()
             .filter_map(|tx| {
                 if let MoveAction::ModuleBundle(bundle) = &tx.action() {
-                    Some(bundle)
+                    Some(bundle.clone())
                 } else {
                     None
                 }
             })
             .flatten()
+            .collect()
+    }
+
+    pub fn modules(&self) -> Result<Vec<CompiledModule>> {
+        self.module_bundle()
+            .iter()
             .map(|module| {
                 let compiled_module = CompiledModule::deserialize(module)
                     .map_err(|e| e.finish(Location::Undefined))?;
@@ -319,7 +581,7 @@ mod tests {
         let moveos_store = MoveOSStore::mock_moveos_store().unwrap();
         let mut moveos = MoveOS::new(
             moveos_store,
-            all_natives(genesis.rooch_framework_gas_params),
+            all_natives(genesis.rooch_framework_gas_params).unwrap(),
             genesis.config,
             vec![],
             vec![],
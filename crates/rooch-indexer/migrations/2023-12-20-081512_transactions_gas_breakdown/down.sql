@@ -0,0 +1,2 @@
+ALTER TABLE transactions DROP COLUMN out_of_gas_instructions;
+ALTER TABLE transactions DROP COLUMN gas_breakdown;
@@ -0,0 +1,3 @@
+-- The transactions indexed before the columns were added have no out of gas instructions and no gas breakdown recorded
+ALTER TABLE transactions ADD COLUMN out_of_gas_instructions BIGINT;
+ALTER TABLE transactions ADD COLUMN gas_breakdown VARCHAR;
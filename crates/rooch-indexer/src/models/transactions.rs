@@ -9,7 +9,7 @@ use std::str::FromStr;
 use crate::schema::transactions;
 use crate::types::IndexedTransaction;
 
-use moveos_types::transaction::TransactionExecutionInfo;
+use moveos_types::transaction::{GasBreakdown, TransactionExecutionInfo};
 use rooch_types::transaction::authenticator::Authenticator;
 use rooch_types::transaction::{RawTransaction, TransactionType, TransactionWithInfo};
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
@@ -72,6 +72,13 @@ pub struct StoredTransaction {
 
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub created_at: i64,
+
+    /// The instruction count reached when the transaction ran out of gas.
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::BigInt>)]
+    pub out_of_gas_instructions: Option<i64>,
+    /// The gas used of the transaction by category, in json.
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub gas_breakdown: Option<String>,
 }
 
 impl From<IndexedTransaction> for StoredTransaction {
@@ -102,6 +109,11 @@ impl From<IndexedTransaction> for StoredTransaction {
             tx_order_authenticator_payload: transaction.tx_order_authenticator_payload,
 
             created_at: transaction.created_at as i64,
+
+            out_of_gas_instructions: transaction
+                .out_of_gas_instructions
+                .map(|instructions| instructions as i64),
+            gas_breakdown: transaction.gas_breakdown,
         }
     }
 }
@@ -123,12 +135,20 @@ impl StoredTransaction {
         };
 
         let status: KeptVMStatus = serde_json::from_str(self.status.as_str())?;
+        let gas_breakdown: Option<GasBreakdown> = self
+            .gas_breakdown
+            .map(|gas_breakdown| serde_json::from_str(gas_breakdown.as_str()))
+            .transpose()?;
         let execution_info = TransactionExecutionInfo {
             tx_hash: H256::from_str(self.tx_hash.as_str())?,
             state_root: H256::from_str(self.state_root.as_str())?,
-            event_root: H256::from_str(self.state_root.as_str())?,
+            event_root: H256::from_str(self.event_root.as_str())?,
             gas_used: self.gas_used as u64,
             status,
+            out_of_gas_instructions: self
+                .out_of_gas_instructions
+                .map(|instructions| instructions as u64),
+            gas_breakdown,
         };
         Ok(TransactionWithInfo {
             transaction,
@@ -80,6 +80,8 @@ diesel::table! {
         tx_order_auth_validator_id -> BigInt,
         tx_order_authenticator_payload -> Binary,
         created_at -> BigInt,
+        out_of_gas_instructions -> Nullable<BigInt>,
+        gas_breakdown -> Nullable<Text>,
     }
 }
 
@@ -299,7 +299,9 @@ impl SqliteIndexerStore {
             .map(StoredEvent::from)
             .collect::<Vec<_>>();
 
-        diesel::insert_into(events::table)
+        // The events are deduplicated by the primary key `(event_index, tx_order)`,
+        // so indexing the same transaction again is idempotent.
+        diesel::insert_or_ignore_into(events::table)
             .values(events.as_slice())
             .execute(&mut connection)
             .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
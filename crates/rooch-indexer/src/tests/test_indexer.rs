@@ -25,8 +25,8 @@ use moveos_types::state::{
     MoveStructType, SplitStateChangeSet, State, StateChangeSet, TableChange, TableTypeInfo,
 };
 use moveos_types::transaction::{
-    FunctionCall, MoveAction, ScriptCall, TransactionExecutionInfo, VerifiedMoveAction,
-    VerifiedMoveOSTransaction,
+    FunctionCall, GasBreakdown, MoveAction, ScriptCall, TransactionExecutionInfo,
+    VerifiedMoveAction, VerifiedMoveOSTransaction,
 };
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
@@ -451,7 +451,13 @@ fn test_transaction_store() -> Result<()> {
         H256::random(),
         H256::random(),
         rand::random(),
-        KeptVMStatus::Executed,
+        KeptVMStatus::OutOfGas,
+        Some(rand::random()),
+        GasBreakdown {
+            instruction_gas: rand::random(),
+            storage_gas: rand::random(),
+            ..Default::default()
+        },
     );
 
     let tx_context = TxContext::new_readonly_ctx(AccountAddress::random());
@@ -461,12 +467,14 @@ fn test_transaction_store() -> Result<()> {
         action: move_action,
         pre_execute_functions: random_function_calls(),
         post_execute_functions: random_function_calls(),
+        dependency_hints: None,
+        gas_budget: None,
     };
 
     let indexed_transaction = IndexedTransaction::new(
         random_transaction,
         random_sequence_info,
-        random_execution_info,
+        random_execution_info.clone(),
         random_moveos_tx.clone(),
     )?;
     let transactions = vec![indexed_transaction];
@@ -476,6 +484,17 @@ fn test_transaction_store() -> Result<()> {
     let query_transactions =
         indexer_reader.query_transactions_with_filter(filter, None, 1, true)?;
     assert_eq!(query_transactions.len(), 1);
+    let execution_info = &query_transactions[0].execution_info;
+    assert_eq!(execution_info.state_root, random_execution_info.state_root);
+    assert_eq!(execution_info.event_root, random_execution_info.event_root);
+    assert_eq!(
+        execution_info.out_of_gas_instructions,
+        random_execution_info.out_of_gas_instructions
+    );
+    assert_eq!(
+        execution_info.gas_breakdown,
+        random_execution_info.gas_breakdown
+    );
     Ok(())
 }
 
@@ -508,6 +527,8 @@ fn test_event_store() -> Result<()> {
         action: move_action,
         pre_execute_functions: random_function_calls(),
         post_execute_functions: random_function_calls(),
+        dependency_hints: None,
+        gas_budget: None,
     };
 
     let indexed_event = IndexedEvent::new(
@@ -55,6 +55,11 @@ pub struct IndexedTransaction {
     pub tx_order_authenticator_payload: Vec<u8>,
 
     pub created_at: u64,
+
+    // The instruction count reached when the transaction ran out of gas.
+    pub out_of_gas_instructions: Option<u64>,
+    // The gas used of the transaction by category, in json.
+    pub gas_breakdown: Option<String>,
 }
 
 impl IndexedTransaction {
@@ -68,6 +73,11 @@ impl IndexedTransaction {
         let action_raw = move_action.encode()?;
         let transaction_authenticator_info = transaction.authenticator_info()?;
         let status = serde_json::to_string(&execution_info.status)?;
+        let gas_breakdown = execution_info
+            .gas_breakdown
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
 
         let indexed_transaction = IndexedTransaction {
             tx_hash: transaction.tx_hash(),
@@ -104,6 +114,9 @@ impl IndexedTransaction {
 
             //TODO record transaction timestamp
             created_at: 0,
+
+            out_of_gas_instructions: execution_info.out_of_gas_instructions,
+            gas_breakdown,
         };
         Ok(indexed_transaction)
     }
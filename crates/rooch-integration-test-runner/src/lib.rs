@@ -100,7 +100,7 @@ impl<'a> MoveOSTestAdapter<'a> for MoveOSTestRunner<'a> {
         let genesis: &RoochGenesis = &rooch_genesis::ROOCH_LOCAL_GENESIS;
         let mut moveos = MoveOS::new(
             moveos_store,
-            genesis.all_natives(),
+            genesis.all_natives().unwrap(),
             genesis.config_for_test.clone(),
             rooch_types::framework::system_pre_execute_functions(),
             vec![],
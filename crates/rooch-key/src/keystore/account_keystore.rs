@@ -6,6 +6,7 @@ use crate::key_derive::{
     derive_address_from_private_key, derive_private_key_from_path, encrypt_key,
     generate_derivation_path, generate_new_key_pair, hash_password,
 };
+use crate::keystore::remote_signer::RemoteSignerConfig;
 use crate::keystore::ImportedMnemonic;
 use bip32::DerivationPath;
 use bip39::{Language, Mnemonic, Seed};
@@ -64,6 +65,12 @@ pub trait AccountKeystore {
     ) -> Result<(), anyhow::Error>;
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error>;
 
+    /// Add an account which key is managed by an external signing service, return the address of the account
+    fn add_remote_signer(
+        &mut self,
+        config: RemoteSignerConfig,
+    ) -> Result<RoochAddress, anyhow::Error>;
+
     fn sign_hashed(
         &self,
         address: &RoochAddress,
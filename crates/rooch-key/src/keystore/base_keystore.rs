@@ -3,6 +3,7 @@
 
 use std::collections::BTreeMap;
 
+use super::remote_signer::{RemoteSigner, RemoteSignerConfig};
 use super::types::{AddressMapping, LocalAccount, LocalSessionKey};
 use crate::key_derive::{decrypt_key, generate_new_key_pair, retrieve_key_pair};
 use crate::keystore::account_keystore::AccountKeystore;
@@ -40,6 +41,9 @@ pub(crate) struct BaseKeyStore {
     pub(crate) is_password_empty: bool,
     #[serde(default)]
     pub(crate) address_mapping: AddressMapping,
+    /// The accounts which keys are managed by the external signing services
+    #[serde(default)]
+    pub(crate) remote_signers: BTreeMap<RoochAddress, RemoteSignerConfig>,
 }
 
 impl BaseKeyStore {
@@ -51,8 +55,16 @@ impl BaseKeyStore {
             password_hash: None,
             is_password_empty: true,
             address_mapping: AddressMapping::default(),
+            remote_signers: BTreeMap::new(),
         }
     }
+
+    fn remote_signer(&self, address: &RoochAddress) -> Result<Option<RemoteSigner>, anyhow::Error> {
+        self.remote_signers
+            .get(address)
+            .map(RemoteSigner::from_config)
+            .transpose()
+    }
 }
 
 impl AccountKeystore for BaseKeyStore {
@@ -75,6 +87,21 @@ impl AccountKeystore for BaseKeyStore {
             };
             accounts.insert(*address, local_account);
         }
+        for (address, config) in &self.remote_signers {
+            let multichain_address = self
+                .address_mapping
+                .rooch_to_multichain
+                .get(address)
+                .cloned();
+            let has_session_key = self.session_keys.get(address).is_some();
+            let local_account = LocalAccount {
+                address: *address,
+                multichain_address,
+                public_key: Some(config.public_key.clone()),
+                has_session_key,
+            };
+            accounts.insert(*address, local_account);
+        }
         for address in self.session_keys.keys() {
             if accounts.contains_key(address) {
                 continue;
@@ -118,6 +145,9 @@ impl AccountKeystore for BaseKeyStore {
         msg: &[u8],
         password: Option<String>,
     ) -> Result<Signature, anyhow::Error> {
+        if let Some(remote_signer) = self.remote_signer(address)? {
+            return remote_signer.sign_hashed(msg);
+        }
         Ok(Signature::new_hashed(
             msg,
             &self.get_key_pair_with_password(address, password)?,
@@ -133,6 +163,9 @@ impl AccountKeystore for BaseKeyStore {
     where
         T: Serialize,
     {
+        if let Some(remote_signer) = self.remote_signer(address)? {
+            return remote_signer.sign_hashed(&Signature::secure_digest(msg));
+        }
         Ok(Signature::new_secure(
             msg,
             &self.get_key_pair_with_password(address, password)?,
@@ -145,6 +178,11 @@ impl AccountKeystore for BaseKeyStore {
         msg: RoochTransactionData,
         password: Option<String>,
     ) -> Result<RoochTransaction, anyhow::Error> {
+        if let Some(remote_signer) = self.remote_signer(address)? {
+            let signature = remote_signer.sign_hashed(msg.hash().as_bytes())?;
+            let auth = authenticator::Authenticator::rooch(signature);
+            return Ok(RoochTransaction::new(msg, auth));
+        }
         let kp = self
             .get_key_pair_with_password(address, password)
             .ok()
@@ -188,6 +226,9 @@ impl AccountKeystore for BaseKeyStore {
             let public_key = keypair.public();
             result.push((*address, public_key));
         }
+        for (address, config) in &self.remote_signers {
+            result.push((*address, config.public_key.clone()));
+        }
         Ok(result)
     }
 
@@ -216,9 +257,25 @@ impl AccountKeystore for BaseKeyStore {
 
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keys.remove(address);
+        self.remote_signers.remove(address);
         Ok(())
     }
 
+    fn add_remote_signer(
+        &mut self,
+        config: RemoteSignerConfig,
+    ) -> Result<RoochAddress, anyhow::Error> {
+        let address = config.address();
+        if self.keys.contains_key(&address) {
+            return Err(anyhow!(
+                "The address {} is already managed by a local key",
+                address
+            ));
+        }
+        self.remote_signers.insert(address, config);
+        Ok(address)
+    }
+
     fn generate_session_key(
         &mut self,
         address: &RoochAddress,
@@ -301,6 +358,10 @@ impl AccountKeystore for BaseKeyStore {
             addresses.push(*key);
         }
 
+        for key in self.remote_signers.keys() {
+            addresses.push(*key);
+        }
+
         for key in self.session_keys.keys() {
             addresses.push(*key);
         }
@@ -5,6 +5,7 @@ use super::types::LocalAccount;
 use crate::key_derive::retrieve_key_pair;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
+use crate::keystore::remote_signer::RemoteSignerConfig;
 use anyhow::anyhow;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
@@ -81,6 +82,15 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(())
     }
 
+    fn add_remote_signer(
+        &mut self,
+        config: RemoteSignerConfig,
+    ) -> Result<RoochAddress, anyhow::Error> {
+        let address = self.keystore.add_remote_signer(config)?;
+        self.save()?;
+        Ok(address)
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keystore.nullify(address)?;
         self.save()?;
@@ -126,6 +136,10 @@ impl AccountKeystore for FileBasedKeystore {
             addresses.push(*key);
         }
 
+        for key in self.keystore.remote_signers.keys() {
+            addresses.push(*key);
+        }
+
         for key in self.keystore.session_keys.keys() {
             addresses.push(*key);
         }
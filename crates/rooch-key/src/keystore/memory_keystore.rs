@@ -5,6 +5,7 @@ use super::types::LocalAccount;
 use crate::key_derive::get_key_pair_from_red;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
+use crate::keystore::remote_signer::RemoteSignerConfig;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -71,6 +72,13 @@ impl AccountKeystore for InMemKeystore {
             .update_address_encryption_data(address, encryption)
     }
 
+    fn add_remote_signer(
+        &mut self,
+        config: RemoteSignerConfig,
+    ) -> Result<RoochAddress, anyhow::Error> {
+        self.keystore.add_remote_signer(config)
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keystore.nullify(address)
     }
@@ -114,6 +122,10 @@ impl AccountKeystore for InMemKeystore {
             addresses.push(*key);
         }
 
+        for key in self.keystore.remote_signers.keys() {
+            addresses.push(*key);
+        }
+
         for key in self.keystore.session_keys.keys() {
             addresses.push(*key);
         }
@@ -5,6 +5,7 @@ use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::file_keystore::FileBasedKeystore;
 use enum_dispatch::enum_dispatch;
 use memory_keystore::InMemKeystore;
+use remote_signer::RemoteSignerConfig;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -21,6 +22,7 @@ pub mod account_keystore;
 pub mod base_keystore;
 pub mod file_keystore;
 pub mod memory_keystore;
+pub mod remote_signer;
 pub mod types;
 
 pub struct ImportedMnemonic {
@@ -149,6 +151,16 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn add_remote_signer(
+        &mut self,
+        config: RemoteSignerConfig,
+    ) -> Result<RoochAddress, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.add_remote_signer(config),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.add_remote_signer(config),
+        }
+    }
+
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         // Implement this method to nullify the key pair by coin ID for the appropriate variant (File or InMem)
         match self {
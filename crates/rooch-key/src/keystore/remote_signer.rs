@@ -0,0 +1,292 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The remote signer delegates the signing to an external signing service, such as a KMS,
+//! so the private key of the account never leaves the custody of the service.
+//! The keystore only keeps the public key and the backend config of the remote account.
+//!
+//! The built-in transport only speaks plain HTTP, it is expected to talk to a signing sidecar
+//! or an authenticating proxy (e.g. the AWS SigV4 proxy) which runs next to the node.
+
+use anyhow::{anyhow, bail, ensure, Result};
+use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use fastcrypto::encoding::{Base64, Encoding};
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::{PublicKey, Signature, ToFromBytes, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+
+/// The config of a remote signer account, stored in the keystore
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteSignerConfig {
+    pub public_key: PublicKey,
+    pub backend: RemoteSignerBackend,
+    /// The name of the environment variable which holds the bearer token of the signing service
+    #[serde(default)]
+    pub auth_token_env: Option<String>,
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+}
+
+impl RemoteSignerConfig {
+    pub fn new(public_key: PublicKey, backend: RemoteSignerBackend) -> Self {
+        Self {
+            public_key,
+            backend,
+            auth_token_env: None,
+            timeout_seconds: None,
+        }
+    }
+
+    pub fn address(&self) -> RoochAddress {
+        self.public_key.address()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteSignerBackend {
+    /// A generic signing service, the request is `{"key_id": "...", "message": "<base64>"}`,
+    /// and the response is `{"signature": "<base64>"}`
+    Http { endpoint: String, key_id: String },
+    /// The AWS KMS `Sign` API with the `ED25519_SHA_512` signing algorithm
+    AwsKms { endpoint: String, key_id: String },
+    /// The GCP Cloud KMS `asymmetricSign` API, the `key_name` is the full resource name of the key version
+    GcpKms { endpoint: String, key_name: String },
+}
+
+/// The external signing service, return the raw signature of the message
+pub trait SigningService: Send + Sync {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// The signer which delegates the signing to a `SigningService`
+pub struct RemoteSigner {
+    public_key: PublicKey,
+    service: Box<dyn SigningService>,
+}
+
+impl RemoteSigner {
+    pub fn new(public_key: PublicKey, service: Box<dyn SigningService>) -> Self {
+        Self {
+            public_key,
+            service,
+        }
+    }
+
+    pub fn from_config(config: &RemoteSignerConfig) -> Result<Self> {
+        let auth_token =
+            match &config.auth_token_env {
+                Some(env) => Some(std::env::var(env).map_err(|_| {
+                    anyhow!("The auth token env {} of remote signer is not set", env)
+                })?),
+                None => None,
+            };
+        let transport = HttpTransport {
+            auth_token,
+            timeout: Duration::from_secs(config.timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_SECONDS)),
+        };
+        let service: Box<dyn SigningService> = match &config.backend {
+            RemoteSignerBackend::Http { endpoint, key_id } => Box::new(HttpSigningService {
+                transport,
+                endpoint: endpoint.clone(),
+                key_id: key_id.clone(),
+            }),
+            RemoteSignerBackend::AwsKms { endpoint, key_id } => Box::new(AwsKmsSigningService {
+                transport,
+                endpoint: endpoint.clone(),
+                key_id: key_id.clone(),
+            }),
+            RemoteSignerBackend::GcpKms { endpoint, key_name } => Box::new(GcpKmsSigningService {
+                transport,
+                endpoint: endpoint.clone(),
+                key_name: key_name.clone(),
+            }),
+        };
+        Ok(Self::new(config.public_key.clone(), service))
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn address(&self) -> RoochAddress {
+        self.public_key.address()
+    }
+
+    /// Sign the hashed message via the signing service.
+    /// The returned signature is verified with the public key, so a misconfigured key is caught before sending the transaction.
+    pub fn sign_hashed(&self, msg: &[u8]) -> Result<Signature> {
+        let signature_bytes = self.service.sign(msg)?;
+        let public_key = Ed25519PublicKey::from_bytes(self.public_key.as_ref())
+            .map_err(|e| anyhow!("Invalid public key of remote signer: {:?}", e))?;
+        let signature = Ed25519Signature::from_bytes(&signature_bytes)
+            .map_err(|e| anyhow!("Invalid signature from remote signer: {:?}", e))?;
+        public_key.verify(msg, &signature).map_err(|e| {
+            anyhow!(
+                "The signature from remote signer does not match the public key: {:?}",
+                e
+            )
+        })?;
+
+        let mut bytes =
+            Vec::with_capacity(1 + signature_bytes.len() + self.public_key.as_ref().len());
+        bytes.push(self.public_key.flag());
+        bytes.extend_from_slice(&signature_bytes);
+        bytes.extend_from_slice(self.public_key.as_ref());
+        Signature::from_bytes(&bytes).map_err(|e| anyhow!("Invalid signature bytes: {:?}", e))
+    }
+}
+
+struct HttpSigningService {
+    transport: HttpTransport,
+    endpoint: String,
+    key_id: String,
+}
+
+impl SigningService for HttpSigningService {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let request = json!({
+            "key_id": self.key_id,
+            "message": Base64::encode(message),
+        });
+        let response = self.transport.post_json(&self.endpoint, &[], &request)?;
+        decode_base64_field(&response, "signature")
+    }
+}
+
+struct AwsKmsSigningService {
+    transport: HttpTransport,
+    endpoint: String,
+    key_id: String,
+}
+
+impl SigningService for AwsKmsSigningService {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let request = json!({
+            "KeyId": self.key_id,
+            "Message": Base64::encode(message),
+            "MessageType": "RAW",
+            "SigningAlgorithm": "ED25519_SHA_512",
+        });
+        let headers = [
+            ("Content-Type", "application/x-amz-json-1.1"),
+            ("X-Amz-Target", "TrentService.Sign"),
+        ];
+        let response = self
+            .transport
+            .post_json(&self.endpoint, &headers, &request)?;
+        decode_base64_field(&response, "Signature")
+    }
+}
+
+struct GcpKmsSigningService {
+    transport: HttpTransport,
+    endpoint: String,
+    key_name: String,
+}
+
+impl SigningService for GcpKmsSigningService {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let url = format!(
+            "{}/v1/{}:asymmetricSign",
+            self.endpoint.trim_end_matches('/'),
+            self.key_name
+        );
+        let request = json!({
+            "data": Base64::encode(message),
+        });
+        let response = self.transport.post_json(&url, &[], &request)?;
+        decode_base64_field(&response, "signature")
+    }
+}
+
+fn decode_base64_field(response: &Value, field: &str) -> Result<Vec<u8>> {
+    let value = response
+        .get(field)
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Missing field {} in the remote signer response", field))?;
+    Base64::decode(value).map_err(|e| anyhow!("Invalid base64 field {}: {:?}", field, e))
+}
+
+/// A minimal blocking HTTP/1.0 client for the signing sidecar
+struct HttpTransport {
+    auth_token: Option<String>,
+    timeout: Duration,
+}
+
+impl HttpTransport {
+    fn post_json(&self, url: &str, headers: &[(&str, &str)], body: &Value) -> Result<Value> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            anyhow!(
+                "Only the http endpoint is supported by the remote signer, got: {}",
+                url
+            )
+        })?;
+        let (host, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+        ensure!(!host.is_empty(), "Invalid remote signer endpoint: {}", url);
+        let addr = if host.contains(':') {
+            host.to_owned()
+        } else {
+            format!("{}:80", host)
+        };
+
+        let body = serde_json::to_vec(body)?;
+        let mut request = format!(
+            "POST {} HTTP/1.0\r\nHost: {}\r\nContent-Length: {}\r\n",
+            path,
+            host,
+            body.len()
+        );
+        if !headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("Content-Type"))
+        {
+            request.push_str("Content-Type: application/json\r\n");
+        }
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        if let Some(token) = &self.auth_token {
+            request.push_str(&format!("Authorization: Bearer {}\r\n", token));
+        }
+        request.push_str("\r\n");
+
+        let mut stream = TcpStream::connect(&addr)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        stream.write_all(request.as_bytes())?;
+        stream.write_all(&body)?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+
+        let header_end = response
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .ok_or_else(|| anyhow!("Invalid http response from remote signer"))?;
+        let head = String::from_utf8_lossy(&response[..header_end]);
+        let status = head
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|code| code.parse::<u16>().ok())
+            .ok_or_else(|| anyhow!("Invalid http status line from remote signer"))?;
+        let body = &response[header_end + 4..];
+        if !(200..300).contains(&status) {
+            bail!(
+                "Remote signer returned status {}: {}",
+                status,
+                String::from_utf8_lossy(body)
+            );
+        }
+        Ok(serde_json::from_slice(body)?)
+    }
+}
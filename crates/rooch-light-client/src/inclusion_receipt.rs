@@ -0,0 +1,131 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verify an inclusion receipt returned by `rooch_sendRawTransactionWithReceipt`.
+//! The sequencer signs `sha3_256(bcs(tx_hash, tx_order, timestamp_ms))` when it sequences the transaction,
+//! before the transaction is executed. A verified receipt with the verified batch covering the tx order
+//! proves the sequencer censored or reordered the transaction if the batch has another transaction at the order.
+
+use anyhow::{bail, ensure, Result};
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::{Signature, ToFromBytes};
+use rooch_types::sequencer::{SequencerBatch, TxInclusionReceipt};
+
+/// Verify the receipt is signed by the `sequencer`
+pub fn verify_inclusion_receipt(
+    receipt: &TxInclusionReceipt,
+    sequencer: RoochAddress,
+) -> Result<()> {
+    ensure!(
+        receipt.sequencer == sequencer,
+        "The receipt of the transaction {:?} is issued by {}, but the sequencer is {}",
+        receipt.tx_hash,
+        receipt.sequencer,
+        sequencer
+    );
+    let signature = match Signature::from_bytes(&receipt.signature) {
+        Ok(signature) => signature,
+        Err(e) => bail!("Invalid signature of the receipt: {:?}", e),
+    };
+    if signature
+        .verify_hashed(receipt.signing_hash().as_bytes(), sequencer)
+        .is_err()
+    {
+        bail!(
+            "The receipt of the transaction {:?} is not signed by the sequencer {}",
+            receipt.tx_hash,
+            sequencer
+        )
+    }
+    Ok(())
+}
+
+/// Check the receipt is honored by the verified `batches`, return the tx order of the transaction if it is sequenced,
+/// or `None` if the batches do not cover the tx order yet.
+/// It fails if the batch covering the tx order has another transaction at the order.
+pub fn check_receipt_honored(
+    receipt: &TxInclusionReceipt,
+    batches: &[SequencerBatch],
+) -> Result<Option<u64>> {
+    let batch = match batches.iter().find(|batch| {
+        batch.first_tx_order <= receipt.tx_order
+            && receipt.tx_order < batch.first_tx_order + batch.batch_size()
+    }) {
+        Some(batch) => batch,
+        None => return Ok(None),
+    };
+    let tx_hash = batch.tx_hashes[(receipt.tx_order - batch.first_tx_order) as usize];
+    ensure!(
+        tx_hash == receipt.tx_hash,
+        "The sequencer promised the transaction {:?} at the tx order {}, but the batch {} has the transaction {:?} at it",
+        receipt.tx_hash,
+        receipt.tx_order,
+        batch.batch_id,
+        tx_hash
+    );
+    Ok(Some(receipt.tx_order))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use moveos_types::h256::H256;
+    use rooch_types::crypto::RoochKeyPair;
+
+    fn batch(first_tx_order: u64, tx_hashes: Vec<H256>) -> SequencerBatch {
+        SequencerBatch {
+            batch_id: 1,
+            first_tx_order,
+            tx_hashes,
+            batch_hash: H256::random(),
+            execution_checksum: H256::random(),
+            prev_tx_accumulator_root: H256::random(),
+            tx_accumulator_root: H256::random(),
+            signatures: vec![],
+        }
+    }
+
+    #[test]
+    fn test_verify_inclusion_receipt() {
+        let keypair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer: RoochAddress = (&keypair.public()).into();
+        let receipt = TxInclusionReceipt::new(H256::random(), 10, 1000, &keypair);
+        verify_inclusion_receipt(&receipt, sequencer).unwrap();
+
+        // issued by another key
+        assert!(verify_inclusion_receipt(&receipt, RoochAddress::random()).is_err());
+
+        // the tx order is tampered
+        let mut tampered = receipt.clone();
+        tampered.tx_order = 20;
+        assert!(verify_inclusion_receipt(&tampered, sequencer).is_err());
+    }
+
+    #[test]
+    fn test_check_receipt_honored() {
+        let keypair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let tx_hash = H256::random();
+        let receipt = TxInclusionReceipt::new(tx_hash, 11, 1000, &keypair);
+
+        // sequenced at the tx order
+        let honored = batch(10, vec![H256::random(), tx_hash]);
+        assert_eq!(
+            check_receipt_honored(&receipt, &[honored]).unwrap(),
+            Some(11)
+        );
+
+        // the batches do not reach the tx order yet
+        let pending = batch(10, vec![H256::random()]);
+        assert_eq!(check_receipt_honored(&receipt, &[pending]).unwrap(), None);
+
+        // sequenced before the tx order
+        let reordered = batch(10, vec![tx_hash, H256::random()]);
+        assert!(check_receipt_honored(&receipt, &[reordered]).is_err());
+
+        // the batch covering the tx order does not have the transaction
+        let censored = batch(10, vec![H256::random(), H256::random()]);
+        assert!(check_receipt_honored(&receipt, &[censored]).is_err());
+    }
+}
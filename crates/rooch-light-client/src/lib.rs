@@ -0,0 +1,7 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The light client verifies the data served by the full nodes without executing the transactions.
+
+pub mod inclusion_receipt;
+pub mod sequencer_batch;
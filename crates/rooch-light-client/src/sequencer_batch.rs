@@ -0,0 +1,260 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verify a sequencer batch returned by `rooch_getSequencerBatch`, so a third party can audit the sequencing honesty.
+//! The sequencer signs every field of the batch except the signatures, including the tx orders and the tx accumulator roots,
+//! and the batch hash is the DA commitment, so a verified batch proves the sequencer committed to these transactions
+//! at these tx orders. The tx accumulator roots are also checked to be consistent between the batches.
+
+use anyhow::{bail, ensure, Result};
+use moveos_types::h256::{self, H256};
+use rooch_types::address::RoochAddress;
+use rooch_types::committee::{CommitteePublicKey, ThresholdSignature};
+use rooch_types::crypto::{Signature, ToFromBytes};
+use rooch_types::sequencer::SequencerBatch;
+use rooch_types::transaction::ethereum::EthereumTransaction;
+use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::AbstractTransaction;
+
+/// Verify the batch is signed by the `sequencer`, and the encoded transactions are the batch content.
+pub fn verify_sequencer_batch(
+    batch: &SequencerBatch,
+    transactions: &[Vec<u8>],
+    sequencer: RoochAddress,
+) -> Result<()> {
+    ensure!(
+        transactions.len() == batch.tx_hashes.len(),
+        "The batch {} has {} tx hashes, but got {} transactions",
+        batch.batch_id,
+        batch.tx_hashes.len(),
+        transactions.len()
+    );
+    for (index, (tx, expected_hash)) in transactions.iter().zip(batch.tx_hashes.iter()).enumerate()
+    {
+        let tx_hash = decode_tx_hash(tx)?;
+        ensure!(
+            &tx_hash == expected_hash,
+            "The hash of the transaction {} is {:?}, but the batch records {:?}",
+            index,
+            tx_hash,
+            expected_hash
+        );
+    }
+
+    let batch_hash = h256::sha3_256_of(&transactions.concat());
+    ensure!(
+        batch_hash == batch.batch_hash,
+        "The hash of the transactions is {:?}, but the batch hash is {:?}",
+        batch_hash,
+        batch.batch_hash
+    );
+
+    let signing_hash = batch.signing_hash();
+    for signature in batch.signatures.iter() {
+        let signature = match Signature::from_bytes(signature) {
+            Ok(signature) => signature,
+            Err(_) => continue,
+        };
+        if signature
+            .verify_hashed(signing_hash.as_bytes(), sequencer)
+            .is_ok()
+        {
+            return Ok(());
+        }
+    }
+    bail!(
+        "The batch {} is not signed by the sequencer {}",
+        batch.batch_id,
+        sequencer
+    )
+}
+
+/// Verify the batch commitment is threshold-signed by the sequencer committee of the `committee_public_key`,
+/// the threshold signature is one of the batch signatures besides the sequencer key signatures.
+pub fn verify_committee_signature(
+    batch: &SequencerBatch,
+    committee_public_key: &CommitteePublicKey,
+) -> Result<()> {
+    let signing_hash = batch.signing_hash();
+    let signed = batch
+        .signatures
+        .iter()
+        .filter_map(|signature| ThresholdSignature::from_bytes(signature).ok())
+        .any(|signature| {
+            committee_public_key
+                .verify(signing_hash.as_bytes(), &signature)
+                .is_ok()
+        });
+    ensure!(
+        signed,
+        "The batch {} is not signed by the sequencer committee",
+        batch.batch_id
+    );
+    Ok(())
+}
+
+/// Verify the batch follows the previous batch, the batch ids and the tx orders are continuous,
+/// and the accumulator root of the previous batch is the start of the batch.
+pub fn verify_batch_continuity(prev: &SequencerBatch, batch: &SequencerBatch) -> Result<()> {
+    ensure!(
+        batch.batch_id == prev.batch_id + 1,
+        "The batch {} does not follow the batch {}",
+        batch.batch_id,
+        prev.batch_id
+    );
+    ensure!(
+        batch.first_tx_order == prev.first_tx_order + prev.batch_size(),
+        "The first tx order of the batch {} is {}, expect {}",
+        batch.batch_id,
+        batch.first_tx_order,
+        prev.first_tx_order + prev.batch_size()
+    );
+    ensure!(
+        batch.prev_tx_accumulator_root == prev.tx_accumulator_root,
+        "The previous tx accumulator root of the batch {} is {:?}, but the accumulator root of the batch {} is {:?}",
+        batch.batch_id,
+        batch.prev_tx_accumulator_root,
+        prev.batch_id,
+        prev.tx_accumulator_root
+    );
+    Ok(())
+}
+
+/// The batch contains both the Rooch and the Ethereum transactions, and they are hashed differently
+fn decode_tx_hash(tx: &[u8]) -> Result<H256> {
+    if let Ok(rooch_tx) = RoochTransaction::decode(tx) {
+        if rooch_tx.encode() == tx {
+            return Ok(rooch_tx.tx_hash());
+        }
+    }
+    match EthereumTransaction::decode(tx) {
+        Ok(eth_tx) => Ok(eth_tx.tx_hash()),
+        Err(e) => bail!("Invalid transaction in the batch: {:?}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::ModuleId;
+    use moveos_types::move_types::FunctionId;
+    use moveos_types::transaction::MoveAction;
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::committee::generate_committee_keys;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::transaction::rooch::RoochTransactionData;
+
+    fn signed_batch(keypair: &RoochKeyPair) -> (SequencerBatch, Vec<Vec<u8>>) {
+        let sender: RoochAddress = (&keypair.public()).into();
+        let action = MoveAction::new_function_call(
+            FunctionId::new(
+                ModuleId::new(AccountAddress::random(), Identifier::new("test").unwrap()),
+                Identifier::new("test").unwrap(),
+            ),
+            vec![],
+            vec![],
+        );
+        let transactions = (0..3)
+            .map(|seq| {
+                RoochTransactionData::new_for_test(sender, seq, action.clone()).sign(keypair)
+            })
+            .collect::<Vec<_>>();
+        let encoded = transactions
+            .iter()
+            .map(|tx| tx.encode())
+            .collect::<Vec<_>>();
+        let mut batch = SequencerBatch {
+            batch_id: 1,
+            first_tx_order: 10,
+            tx_hashes: transactions.iter().map(|tx| tx.tx_hash()).collect(),
+            batch_hash: h256::sha3_256_of(&encoded.concat()),
+            execution_checksum: H256::random(),
+            prev_tx_accumulator_root: H256::random(),
+            tx_accumulator_root: H256::random(),
+            signatures: vec![],
+        };
+        let signature = Signature::new_hashed(batch.signing_hash().as_bytes(), keypair);
+        batch.signatures.push(signature.as_ref().to_vec());
+        (batch, encoded)
+    }
+
+    #[test]
+    fn test_verify_sequencer_batch() {
+        let keypair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer: RoochAddress = (&keypair.public()).into();
+        let (batch, transactions) = signed_batch(&keypair);
+        verify_sequencer_batch(&batch, &transactions, sequencer).unwrap();
+
+        // signed by another key
+        assert!(verify_sequencer_batch(&batch, &transactions, RoochAddress::random()).is_err());
+
+        // the transactions are reordered
+        let mut reordered = transactions.clone();
+        reordered.swap(0, 1);
+        assert!(verify_sequencer_batch(&batch, &reordered, sequencer).is_err());
+
+        // a transaction is dropped
+        assert!(verify_sequencer_batch(&batch, &transactions[1..], sequencer).is_err());
+
+        // the execution checksum is tampered
+        let mut tampered = batch.clone();
+        tampered.execution_checksum = H256::random();
+        assert!(verify_sequencer_batch(&tampered, &transactions, sequencer).is_err());
+
+        // the tx orders are tampered
+        let mut tampered = batch.clone();
+        tampered.first_tx_order += 1;
+        assert!(verify_sequencer_batch(&tampered, &transactions, sequencer).is_err());
+
+        // the tx accumulator roots are tampered
+        let mut tampered = batch.clone();
+        tampered.prev_tx_accumulator_root = H256::random();
+        assert!(verify_sequencer_batch(&tampered, &transactions, sequencer).is_err());
+        let mut tampered = batch.clone();
+        tampered.tx_accumulator_root = H256::random();
+        assert!(verify_sequencer_batch(&tampered, &transactions, sequencer).is_err());
+    }
+
+    #[test]
+    fn test_verify_committee_signature() {
+        let keypair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer: RoochAddress = (&keypair.public()).into();
+        let (public_key, shares) = generate_committee_keys(2, 3, &mut rand::thread_rng()).unwrap();
+        let (mut batch, transactions) = signed_batch(&keypair);
+        assert!(verify_committee_signature(&batch, &public_key).is_err());
+
+        let signing_hash = batch.signing_hash();
+        let partials: Vec<_> = shares[1..]
+            .iter()
+            .map(|share| share.sign(signing_hash.as_bytes()))
+            .collect();
+        let signature = public_key
+            .aggregate(signing_hash.as_bytes(), &partials)
+            .unwrap();
+        batch.signatures.push(signature.to_bytes());
+        verify_committee_signature(&batch, &public_key).unwrap();
+        // The sequencer key signature is still verified along with the threshold signature
+        verify_sequencer_batch(&batch, &transactions, sequencer).unwrap();
+
+        let (other_public_key, _) = generate_committee_keys(2, 3, &mut rand::thread_rng()).unwrap();
+        assert!(verify_committee_signature(&batch, &other_public_key).is_err());
+    }
+
+    #[test]
+    fn test_verify_batch_continuity() {
+        let keypair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let (prev, _) = signed_batch(&keypair);
+        let mut batch = prev.clone();
+        batch.batch_id = prev.batch_id + 1;
+        batch.first_tx_order = prev.first_tx_order + prev.batch_size();
+        batch.prev_tx_accumulator_root = prev.tx_accumulator_root;
+        verify_batch_continuity(&prev, &batch).unwrap();
+
+        batch.prev_tx_accumulator_root = H256::random();
+        assert!(verify_batch_continuity(&prev, &batch).is_err());
+    }
+}
@@ -8,6 +8,8 @@ use anyhow::Result;
 use clap::clap_derive::ValueEnum;
 use clap::Parser;
 use rooch_open_rpc::Project;
+use rooch_rpc_api::api::btc_api::BtcAPIOpenRpc;
+use rooch_rpc_api::api::eth_api::{EthAPIOpenRpc, EthNetAPIOpenRpc};
 use rooch_rpc_api::api::rooch_api::RoochAPIOpenRpc;
 use std::fs::File;
 use std::io::Write;
@@ -47,6 +49,15 @@ pub fn build_rooch_rpc_spec() -> Project {
     open_rpc
 }
 
+/// Build the spec of all the RPC namespaces served by the rooch server, the `rooch`, `btc`, `eth` and `net` namespaces.
+pub fn build_full_rooch_rpc_spec() -> Project {
+    let mut open_rpc = build_rooch_rpc_spec();
+    open_rpc.add_module(BtcAPIOpenRpc::module_doc());
+    open_rpc.add_module(EthAPIOpenRpc::module_doc());
+    open_rpc.add_module(EthNetAPIOpenRpc::module_doc());
+    open_rpc
+}
+
 pub fn build_and_save_rooch_rpc_spec() -> Result<()> {
     let open_rpc = build_rooch_rpc_spec();
     let content = serde_json::to_string_pretty(&open_rpc)?;
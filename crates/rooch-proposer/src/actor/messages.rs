@@ -4,6 +4,7 @@
 use anyhow::Result;
 use coerce::actor::{message::Message, scheduler::timer::TimerTick};
 use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::sequencer::SequencerBatch;
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 
 /// Transaction Sequence Message
@@ -37,3 +38,13 @@ impl Message for ProposeBlock {
 }
 
 impl TimerTick for ProposeBlock {}
+
+/// Get the sequencer batch by the batch id
+#[derive(Debug)]
+pub struct GetSequencerBatchMessage {
+    pub batch_id: u128,
+}
+
+impl Message for GetSequencerBatchMessage {
+    type Result = Result<Option<SequencerBatch>>;
+}
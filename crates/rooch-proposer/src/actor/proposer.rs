@@ -6,23 +6,65 @@ use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 
 use rooch_da::proxy::DAProxy;
+use rooch_store::RoochStore;
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::sequencer::{ProposerBacklog, SequencerBatch};
 
+use crate::committee::CommitteeSigner;
 use crate::scc::StateCommitmentChain;
 
-use super::messages::{ProposeBlock, TransactionProposeMessage, TransactionProposeResult};
+use super::messages::{
+    GetSequencerBatchMessage, ProposeBlock, TransactionProposeMessage, TransactionProposeResult,
+};
 
 pub struct ProposerActor {
     proposer_key: RoochKeyPair,
+    sequencer_key: RoochKeyPair,
+    /// The next sequencer key during the sequencer key rotation handover window,
+    /// the batches are signed by both the current and the next sequencer key when it is set.
+    next_sequencer_key: Option<RoochKeyPair>,
+    /// The sequencer committee threshold-signing the batches besides the sequencer key
+    committee_signer: Option<CommitteeSigner>,
     scc: StateCommitmentChain,
 }
 
 impl ProposerActor {
-    pub fn new(proposer_key: RoochKeyPair, da_proxy: DAProxy) -> Self {
-        Self {
+    pub fn new(
+        proposer_key: RoochKeyPair,
+        sequencer_key: RoochKeyPair,
+        da_proxy: DAProxy,
+        rooch_store: RoochStore,
+    ) -> Result<Self> {
+        Ok(Self {
             proposer_key,
-            scc: StateCommitmentChain::new(da_proxy),
-        }
+            sequencer_key,
+            next_sequencer_key: None,
+            committee_signer: None,
+            scc: StateCommitmentChain::new(da_proxy, rooch_store)?,
+        })
+    }
+
+    /// Enable the dual-signing with the next sequencer key
+    pub fn with_next_sequencer_key(mut self, next_sequencer_key: Option<RoochKeyPair>) -> Self {
+        self.next_sequencer_key = next_sequencer_key;
+        self
+    }
+
+    pub fn with_committee_signer(mut self, committee_signer: Option<CommitteeSigner>) -> Self {
+        self.committee_signer = committee_signer;
+        self
+    }
+
+    /// Limit the gas used by the transactions of a block, `None` packs all the buffered transactions
+    pub fn with_block_gas_limit(mut self, block_gas_limit: Option<u64>) -> Self {
+        self.scc.set_block_gas_limit(block_gas_limit);
+        self
+    }
+
+    /// Share the gas used by the buffered transactions with the sequencer for the backpressure
+    pub fn with_backlog(mut self, backlog: ProposerBacklog) -> Self {
+        self.scc.set_backlog(backlog);
+        self
     }
 }
 
@@ -40,21 +82,47 @@ impl Handler<TransactionProposeMessage> for ProposerActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetSequencerBatchMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetSequencerBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<SequencerBatch>> {
+        self.scc.get_batch(msg.batch_id)
+    }
+}
+
 #[async_trait]
 impl Handler<ProposeBlock> for ProposerActor {
     async fn handle(&mut self, _message: ProposeBlock, _ctx: &mut ActorContext) {
-        let block = self.scc.propose_block().await;
+        let mut batch_signers = vec![&self.sequencer_key];
+        if let Some(next_sequencer_key) = &self.next_sequencer_key {
+            batch_signers.push(next_sequencer_key);
+        }
+        let block_gas_used = self.scc.next_block_gas_used();
+        let block = self
+            .scc
+            .propose_block(&batch_signers, self.committee_signer.as_ref())
+            .await;
         match block {
-            Some(block) => {
+            Ok(Some(block)) => {
                 log::info!(
-                    "[ProposeBlock] block_number: {}, batch_size: {:?}",
+                    "[ProposeBlock] block_number: {}, batch_size: {:?}, block_gas_used: {}",
                     block.block_number,
-                    block.batch_size
+                    block.batch_size,
+                    block_gas_used
                 );
             }
-            None => {
+            Ok(None) => {
                 log::debug!("[ProposeBlock] no transaction to propose block");
             }
+            Err(e) => {
+                log::error!(
+                    "[ProposeBlock] failed to propose block, retry later: {:?}",
+                    e
+                );
+            }
         };
         //TODO submit to the on-chain SCC contract use the proposer key
         let _proposer_key = &self.proposer_key;
@@ -0,0 +1,426 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use async_trait::async_trait;
+use futures::future::join_all;
+use jsonrpsee::http_client::{HeaderMap, HeaderValue, HttpClientBuilder};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::{self, H256};
+use rooch_rpc_api::api::rooch_api::RoochAPIClient;
+use rooch_rpc_api::auth::RpcAuthHeaders;
+use rooch_rpc_api::jsonrpc_types::sequencer_batch_view::SequencerBatchView;
+use rooch_rpc_api::jsonrpc_types::AccountAddressView;
+use rooch_types::address::RoochAddress;
+use rooch_types::committee::{
+    CommitteeKeyFile, CommitteePublicKey, KeyShare, OperatorIndex, PartialSignature,
+    ThresholdSignature,
+};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::sequencer::SequencerBatch;
+
+/// The timeout of a partial signature request to a remote operator, it is shorter than the block propose interval
+pub const REMOTE_SIGN_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// The number of the latest signed batches an operator remembers to refuse the conflicting batches of the same id
+const MAX_SIGNED_BATCHES: usize = 1024;
+
+/// An operator of the sequencer committee, it signs the batch commitments with its key share.
+/// The operators run by other nodes are reached by `RemotePartialSigner`.
+#[async_trait]
+pub trait PartialSigner: Send + Sync {
+    fn index(&self) -> OperatorIndex;
+
+    /// Sign the batch, the encoded transactions are passed for the operator to check the DA commitment
+    async fn sign_partial(
+        &self,
+        batch: &SequencerBatch,
+        transactions: &[Vec<u8>],
+    ) -> Result<PartialSignature>;
+}
+
+/// An operator whose key share is held by this node
+pub struct LocalPartialSigner {
+    share: KeyShare,
+}
+
+impl LocalPartialSigner {
+    pub fn new(share: KeyShare) -> Self {
+        Self { share }
+    }
+}
+
+#[async_trait]
+impl PartialSigner for LocalPartialSigner {
+    fn index(&self) -> OperatorIndex {
+        self.share.index
+    }
+
+    async fn sign_partial(
+        &self,
+        batch: &SequencerBatch,
+        _transactions: &[Vec<u8>],
+    ) -> Result<PartialSignature> {
+        Ok(self.share.sign(batch.signing_hash().as_bytes()))
+    }
+}
+
+/// An operator run by another node, the partial signature is requested by `rooch_signBatchCommitment`
+/// authenticated by the sequencer key.
+pub struct RemotePartialSigner {
+    index: OperatorIndex,
+    url: String,
+    sequencer_key: RoochKeyPair,
+}
+
+impl RemotePartialSigner {
+    pub fn new(index: OperatorIndex, url: String, sequencer_key: RoochKeyPair) -> Self {
+        Self {
+            index,
+            url,
+            sequencer_key,
+        }
+    }
+
+    /// Parse the operator endpoint in the format `<index>@<url>`
+    pub fn parse_endpoint(endpoint: &str) -> Result<(OperatorIndex, String)> {
+        let (index, url) = endpoint.split_once('@').ok_or_else(|| {
+            anyhow!(
+                "Invalid committee operator {}, the format is <index>@<url>",
+                endpoint
+            )
+        })?;
+        let index = index
+            .parse()
+            .map_err(|e| anyhow!("Invalid committee operator index {}: {}", index, e))?;
+        ensure!(
+            !url.is_empty(),
+            "The url of the committee operator {} is empty",
+            index
+        );
+        Ok((index, url.to_owned()))
+    }
+}
+
+#[async_trait]
+impl PartialSigner for RemotePartialSigner {
+    fn index(&self) -> OperatorIndex {
+        self.index
+    }
+
+    async fn sign_partial(
+        &self,
+        batch: &SequencerBatch,
+        transactions: &[Vec<u8>],
+    ) -> Result<PartialSignature> {
+        let sequencer =
+            AccountAddressView::from(AccountAddress::from(self.sequencer_key.public().address()));
+        let batch_view = SequencerBatchView::new(batch.clone(), transactions.to_vec());
+        let params = serde_json::json!([sequencer, batch_view]);
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let mut headers = HeaderMap::new();
+        for (name, value) in RpcAuthHeaders::sign(
+            &self.sequencer_key,
+            "rooch_signBatchCommitment",
+            &params,
+            timestamp,
+        )
+        .to_headers()
+        {
+            headers.insert(name, HeaderValue::from_str(&value)?);
+        }
+        let http = HttpClientBuilder::default()
+            .request_timeout(REMOTE_SIGN_TIMEOUT)
+            .set_headers(headers)
+            .build(&self.url)?;
+        let partials = http.sign_batch_commitment(sequencer, batch_view).await?;
+        match partials.into_iter().find(|partial| partial.index == self.index) {
+            Some(partial) => partial.try_into(),
+            None => bail!(
+                "The committee operator at {} does not return the partial signature of the operator {}",
+                self.url,
+                self.index
+            ),
+        }
+    }
+}
+
+/// The committee operator run by this node, it signs the batches of the sequencer with the local key shares on request.
+pub struct CommitteeOperator {
+    shares: Vec<KeyShare>,
+    sequencer: RoochAddress,
+    /// The signing hashes of the latest signed batches by the batch id
+    signed: Mutex<BTreeMap<u128, H256>>,
+}
+
+impl CommitteeOperator {
+    pub fn new(key_file: CommitteeKeyFile, sequencer: RoochAddress) -> Result<Self> {
+        ensure!(
+            !key_file.shares.is_empty(),
+            "The committee key file has no key share"
+        );
+        Ok(Self {
+            shares: key_file.shares,
+            sequencer,
+            signed: Mutex::new(BTreeMap::new()),
+        })
+    }
+
+    /// The sequencer account whose batches the operator signs
+    pub fn sequencer(&self) -> RoochAddress {
+        self.sequencer
+    }
+
+    /// Sign the batch with all the local key shares. The batch is refused if its DA commitment mismatches the
+    /// transactions, or another batch of the same id is already signed, so the sequencer can not equivocate.
+    pub fn sign_batch(
+        &self,
+        batch: &SequencerBatch,
+        transactions: &[Vec<u8>],
+    ) -> Result<Vec<PartialSignature>> {
+        ensure!(
+            !transactions.is_empty() && transactions.len() == batch.tx_hashes.len(),
+            "The batch {} has {} transactions and {} tx hashes",
+            batch.batch_id,
+            transactions.len(),
+            batch.tx_hashes.len()
+        );
+        let batch_hash = h256::sha3_256_of(&transactions.concat());
+        ensure!(
+            batch_hash == batch.batch_hash,
+            "The DA commitment {:?} of the batch {} mismatches the transactions {:?}",
+            batch.batch_hash,
+            batch.batch_id,
+            batch_hash
+        );
+        let signing_hash = batch.signing_hash();
+        let mut signed = self
+            .signed
+            .lock()
+            .expect("committee operator lock should not be poisoned");
+        match signed.get(&batch.batch_id) {
+            Some(signed_hash) if *signed_hash != signing_hash => bail!(
+                "Another batch {} is already signed with the signing hash {:?}",
+                batch.batch_id,
+                signed_hash
+            ),
+            Some(_) => {}
+            None => {
+                signed.insert(batch.batch_id, signing_hash);
+                if signed.len() > MAX_SIGNED_BATCHES {
+                    signed.pop_first();
+                }
+            }
+        }
+        Ok(self
+            .shares
+            .iter()
+            .map(|share| share.sign(signing_hash.as_bytes()))
+            .collect())
+    }
+}
+
+/// Collect the partial signatures of a batch commitment from the committee operators,
+/// and aggregate them once the threshold is reached.
+#[derive(Clone)]
+pub struct CommitteeSigner {
+    public_key: CommitteePublicKey,
+    signers: Vec<Arc<dyn PartialSigner>>,
+}
+
+impl CommitteeSigner {
+    pub fn new(
+        public_key: CommitteePublicKey,
+        signers: Vec<Arc<dyn PartialSigner>>,
+    ) -> Result<Self> {
+        ensure!(
+            signers.len() >= public_key.threshold as usize,
+            "The committee signer has {} operators, the threshold is {}",
+            signers.len(),
+            public_key.threshold
+        );
+        let mut indexes = BTreeSet::new();
+        for signer in &signers {
+            public_key.public_share(signer.index())?;
+            ensure!(
+                indexes.insert(signer.index()),
+                "The committee operator {} is duplicated",
+                signer.index()
+            );
+        }
+        Ok(Self {
+            public_key,
+            signers,
+        })
+    }
+
+    /// The committee signer with the local key shares of the key file and the remote operators
+    pub fn from_key_file(
+        key_file: CommitteeKeyFile,
+        remote_signers: Vec<RemotePartialSigner>,
+    ) -> Result<Self> {
+        let signers = key_file
+            .shares
+            .into_iter()
+            .map(|share| Arc::new(LocalPartialSigner::new(share)) as Arc<dyn PartialSigner>)
+            .chain(
+                remote_signers
+                    .into_iter()
+                    .map(|signer| Arc::new(signer) as Arc<dyn PartialSigner>),
+            )
+            .collect();
+        Self::new(key_file.public_key, signers)
+    }
+
+    pub fn public_key(&self) -> &CommitteePublicKey {
+        &self.public_key
+    }
+
+    /// Request the partial signatures from all the operators, the failed operators are skipped
+    pub async fn sign(
+        &self,
+        batch: &SequencerBatch,
+        transactions: &[Vec<u8>],
+    ) -> Result<ThresholdSignature> {
+        let signing_hash = batch.signing_hash();
+        let results = join_all(
+            self.signers
+                .iter()
+                .map(|signer| signer.sign_partial(batch, transactions)),
+        )
+        .await;
+        let mut partials = Vec::with_capacity(results.len());
+        for (signer, result) in self.signers.iter().zip(results) {
+            match result {
+                Ok(partial) => partials.push(partial),
+                Err(e) => log::warn!(
+                    "The committee operator {} failed to sign the batch {}: {:?}",
+                    signer.index(),
+                    batch.batch_id,
+                    e
+                ),
+            }
+        }
+        self.public_key
+            .aggregate(signing_hash.as_bytes(), &partials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::committee::generate_committee_keys;
+
+    struct OfflineSigner(OperatorIndex);
+
+    #[async_trait]
+    impl PartialSigner for OfflineSigner {
+        fn index(&self) -> OperatorIndex {
+            self.0
+        }
+
+        async fn sign_partial(
+            &self,
+            _batch: &SequencerBatch,
+            _transactions: &[Vec<u8>],
+        ) -> Result<PartialSignature> {
+            bail!("The operator is offline")
+        }
+    }
+
+    fn test_batch(batch_id: u128, transactions: &[Vec<u8>]) -> SequencerBatch {
+        SequencerBatch {
+            batch_id,
+            first_tx_order: 0,
+            tx_hashes: transactions
+                .iter()
+                .map(|tx| h256::sha3_256_of(tx))
+                .collect(),
+            batch_hash: h256::sha3_256_of(&transactions.concat()),
+            execution_checksum: H256::random(),
+            prev_tx_accumulator_root: H256::random(),
+            tx_accumulator_root: H256::random(),
+            signatures: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_committee_signer() {
+        let (public_key, mut shares) =
+            generate_committee_keys(2, 3, &mut rand::thread_rng()).unwrap();
+        let transactions = vec![vec![1u8, 2, 3]];
+        let batch = test_batch(1, &transactions);
+        // One operator is offline, the other two reach the threshold
+        shares.truncate(2);
+        let mut signers: Vec<Arc<dyn PartialSigner>> = shares
+            .into_iter()
+            .map(|share| Arc::new(LocalPartialSigner::new(share)) as Arc<dyn PartialSigner>)
+            .collect();
+        signers.push(Arc::new(OfflineSigner(3)));
+        let committee_signer = CommitteeSigner::new(public_key.clone(), signers.clone()).unwrap();
+        let signature = committee_signer.sign(&batch, &transactions).await.unwrap();
+        public_key
+            .verify(batch.signing_hash().as_bytes(), &signature)
+            .unwrap();
+
+        signers.remove(0);
+        let committee_signer = CommitteeSigner::new(public_key.clone(), signers.clone()).unwrap();
+        assert!(committee_signer.sign(&batch, &transactions).await.is_err());
+
+        // The duplicated operators do not count to the threshold
+        signers[0] = Arc::new(OfflineSigner(3));
+        assert!(CommitteeSigner::new(public_key, signers).is_err());
+    }
+
+    #[test]
+    fn test_committee_operator() {
+        let (public_key, shares) = generate_committee_keys(2, 3, &mut rand::thread_rng()).unwrap();
+        let sequencer = RoochAddress::random();
+        let operator = CommitteeOperator::new(
+            CommitteeKeyFile {
+                public_key: public_key.clone(),
+                shares: vec![shares[1].clone()],
+            },
+            sequencer,
+        )
+        .unwrap();
+        let transactions = vec![vec![1u8, 2, 3], vec![4u8, 5]];
+        let batch = test_batch(1, &transactions);
+        let partials = operator.sign_batch(&batch, &transactions).unwrap();
+        assert_eq!(partials.len(), 1);
+        public_key
+            .verify_partial(batch.signing_hash().as_bytes(), &partials[0])
+            .unwrap();
+        // Signing the same batch again is allowed
+        assert_eq!(
+            operator.sign_batch(&batch, &transactions).unwrap(),
+            partials
+        );
+
+        // The DA commitment mismatches the transactions
+        let mut forged = test_batch(2, &transactions);
+        forged.batch_hash = H256::random();
+        assert!(operator.sign_batch(&forged, &transactions).is_err());
+
+        // Another batch of the same id is refused
+        let other_transactions = vec![vec![6u8]];
+        let other = test_batch(1, &other_transactions);
+        assert!(operator.sign_batch(&other, &other_transactions).is_err());
+    }
+
+    #[test]
+    fn test_parse_operator_endpoint() {
+        assert_eq!(
+            RemotePartialSigner::parse_endpoint("2@http://127.0.0.1:6767").unwrap(),
+            (2, "http://127.0.0.1:6767".to_owned())
+        );
+        assert!(RemotePartialSigner::parse_endpoint("http://127.0.0.1:6767").is_err());
+        assert!(RemotePartialSigner::parse_endpoint("x@http://127.0.0.1:6767").is_err());
+        assert!(RemotePartialSigner::parse_endpoint("2@").is_err());
+    }
+}
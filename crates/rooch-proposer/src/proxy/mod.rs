@@ -5,10 +5,11 @@ use anyhow::Result;
 use coerce::actor::ActorRef;
 
 use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::sequencer::SequencerBatch;
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 
 use crate::actor::{
-    messages::{TransactionProposeMessage, TransactionProposeResult},
+    messages::{GetSequencerBatchMessage, TransactionProposeMessage, TransactionProposeResult},
     proposer::ProposerActor,
 };
 
@@ -36,4 +37,10 @@ impl ProposerProxy {
             })
             .await?
     }
+
+    pub async fn get_sequencer_batch(&self, batch_id: u128) -> Result<Option<SequencerBatch>> {
+        self.actor
+            .send(GetSequencerBatchMessage { batch_id })
+            .await?
+    }
 }
@@ -3,14 +3,20 @@
 
 use std::collections::BTreeMap;
 
+use anyhow::Result;
 use moveos_types::h256;
 use moveos_types::h256::H256;
 use rooch_da::messages::{Batch, BatchMeta};
 use rooch_da::proxy::DAProxy;
-use rooch_types::block::Block;
+use rooch_store::batch_store::BatchStore;
+use rooch_store::RoochStore;
+use rooch_types::block::{execution_checksum, Block};
+use rooch_types::crypto::{RoochKeyPair, Signature};
+use rooch_types::sequencer::{ProposerBacklog, SequencerBatch};
 use rooch_types::transaction::AbstractTransaction;
 
 use crate::actor::messages::TransactionProposeMessage;
+use crate::committee::CommitteeSigner;
 
 /// State Commitment Chain(SCC) is a chain of transaction state root
 /// This SCC is a mirror of the on-chain SCC
@@ -19,20 +25,67 @@ pub struct StateCommitmentChain {
     blocks: BTreeMap<u128, Block>,
     buffer: Vec<TransactionProposeMessage>,
     da: DAProxy,
+    rooch_store: RoochStore,
+    /// The last batch saved in the storage, the block number continues from it after the node restarts
+    last_batch: Option<SequencerBatch>,
+    /// The max gas used by the transactions of a block, the rest of the buffered transactions are deferred to the next block
+    block_gas_limit: Option<u64>,
+    /// The gas used by the buffered transactions, it is shared with the sequencer for the backpressure
+    backlog: ProposerBacklog,
 }
 
 impl StateCommitmentChain {
     /// Create a new SCC
-    pub fn new(da_proxy: DAProxy) -> Self {
-        Self {
+    pub fn new(da_proxy: DAProxy, rooch_store: RoochStore) -> Result<Self> {
+        let last_batch = rooch_store.get_last_batch()?;
+        Ok(Self {
             blocks: BTreeMap::new(),
             buffer: Vec::new(),
             da: da_proxy,
-        }
+            rooch_store,
+            last_batch,
+            block_gas_limit: None,
+            backlog: ProposerBacklog::default(),
+        })
+    }
+
+    pub fn set_block_gas_limit(&mut self, block_gas_limit: Option<u64>) {
+        self.block_gas_limit = block_gas_limit;
+    }
+
+    /// Share the gas used by the buffered transactions with the sequencer
+    pub fn set_backlog(&mut self, backlog: ProposerBacklog) {
+        backlog.set_buffered_gas_used(self.buffered_gas_used());
+        self.backlog = backlog;
     }
 
     pub fn append_transaction(&mut self, tx: TransactionProposeMessage) {
+        let buffered_gas_used = self
+            .buffered_gas_used()
+            .saturating_add(tx.tx_execution_info.gas_used);
         self.buffer.push(tx);
+        self.backlog.set_buffered_gas_used(buffered_gas_used);
+    }
+
+    /// The gas used by all the buffered transactions
+    pub fn buffered_gas_used(&self) -> u64 {
+        self.backlog.buffered_gas_used()
+    }
+
+    /// The gas used by the transactions of the next block
+    pub fn next_block_gas_used(&self) -> u64 {
+        self.buffer[..self.next_block_size()]
+            .iter()
+            .map(|tx| tx.tx_execution_info.gas_used)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// How many buffered transactions are packed into the next block
+    fn next_block_size(&self) -> usize {
+        block_size(
+            self.buffer.iter().map(|tx| tx.tx_execution_info.gas_used),
+            self.block_gas_limit,
+        )
     }
 
     /// Append a new block to the SCC
@@ -50,26 +103,34 @@ impl StateCommitmentChain {
         self.blocks.keys().last().copied()
     }
 
-    /// Trigger the proposer to propose a new block
-    pub async fn propose_block(&mut self) -> Option<&Block> {
+    /// Trigger the proposer to propose a new block, the batch is signed by every key of the `batch_signers`,
+    /// and the threshold signature of the `committee_signer` is appended to the signatures if it is set.
+    /// If the committee fails to sign the batch, the proposal fails and the transactions stay in the buffer
+    /// to be proposed again, a batch is never published without the committee signature.
+    pub async fn propose_block(
+        &mut self,
+        batch_signers: &[&RoochKeyPair],
+        committee_signer: Option<&CommitteeSigner>,
+    ) -> Result<Option<&Block>> {
         if self.buffer.is_empty() {
-            return None;
+            return Ok(None);
         }
-        // construct a new block from buffer
-        let latest_transaction = self.buffer.last().expect("buffer must not empty");
+        // construct a new block from buffer, the transactions exceeding the block gas limit stay in the buffer
+        let block_size = self.next_block_size();
+        let txs = &self.buffer[..block_size];
+        let latest_transaction = txs.last().expect("block must not empty");
         let tx_accumulator_root = latest_transaction.tx_sequence_info.tx_accumulator_root;
-        let state_roots = self
-            .buffer
+        let state_roots = txs
             .iter()
             .map(|tx| tx.tx_execution_info.state_root)
             .collect();
 
-        let batch_size = self.buffer.len() as u64;
-        let last_block = self.last_block();
-        let (block_number, prev_tx_accumulator_root) = match last_block {
-            Some(block) => {
-                let block_number = block.block_number + 1;
-                let prev_tx_accumulator_root = block.tx_accumulator_root;
+        let execution_checksum = execution_checksum(txs.iter().map(|tx| &tx.tx_execution_info));
+        let batch_size = txs.len() as u64;
+        let (block_number, prev_tx_accumulator_root) = match &self.last_batch {
+            Some(batch) => {
+                let block_number = batch.batch_id + 1;
+                let prev_tx_accumulator_root = batch.tx_accumulator_root;
                 (block_number, prev_tx_accumulator_root)
             }
             None => {
@@ -81,17 +142,70 @@ impl StateCommitmentChain {
 
         // submit batch to DA server
         // TODO move batch submit out of proposer
-        let batch_data: Vec<u8> = self.buffer.iter().flat_map(|tx| tx.tx.encode()).collect();
+        let encoded_txs: Vec<Vec<u8>> = txs.iter().map(|tx| tx.tx.encode()).collect();
         // regard batch(tx list) as a blob: easy to check integrity
+        let batch_data = encoded_txs.concat();
         let batch_hash = h256::sha3_256_of(&batch_data);
+        let mut batch = SequencerBatch {
+            batch_id: block_number,
+            first_tx_order: txs[0].tx_sequence_info.tx_order,
+            tx_hashes: txs.iter().map(|tx| tx.tx.tx_hash()).collect(),
+            batch_hash,
+            execution_checksum,
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            signatures: vec![],
+        };
+        let signing_hash = batch.signing_hash();
+        batch.signatures = batch_signers
+            .iter()
+            .map(|signer| {
+                Signature::new_hashed(signing_hash.as_bytes(), *signer)
+                    .as_ref()
+                    .to_vec()
+            })
+            .collect();
+        if let Some(committee_signer) = committee_signer {
+            let signature = committee_signer
+                .sign(&batch, &encoded_txs)
+                .await
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        "Failed to sign the batch {} by the sequencer committee: {:?}",
+                        block_number,
+                        e
+                    )
+                })?;
+            batch.signatures.push(signature.to_bytes());
+        }
+        // The batch is saved before the transactions leave the buffer, if it fails to be saved,
+        // the proposal fails and the transactions are proposed again
+        self.rooch_store.save_batch(batch.clone()).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to save the sequencer batch {}: {:?}",
+                block_number,
+                e
+            )
+        })?;
+        let block_gas_used = self.next_block_gas_used();
+        self.buffer.drain(..block_size);
+        self.backlog
+            .set_buffered_gas_used(self.buffered_gas_used().saturating_sub(block_gas_used));
+        let meta = BatchMeta {
+            block_number,
+            first_tx_order: batch.first_tx_order,
+            tx_hashes: batch.tx_hashes.clone(),
+            batch_hash,
+            execution_checksum,
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            signatures: batch.signatures.clone(),
+        };
+        self.last_batch = Some(batch);
         let _ = self
             .da
             .submit_batch(Batch {
-                meta: BatchMeta {
-                    block_number,
-                    batch_hash,
-                    signature: vec![],
-                },
+                meta,
                 data: batch_data,
             })
             .await;
@@ -102,9 +216,59 @@ impl StateCommitmentChain {
             prev_tx_accumulator_root,
             tx_accumulator_root,
             state_roots,
+            execution_checksum,
         );
         self.append_block(new_block);
-        self.buffer.clear();
-        self.last_block()
+        Ok(self.last_block())
+    }
+
+    /// Get the sequencer batch by the batch id, the batch id is the block number
+    pub fn get_batch(&self, batch_id: u128) -> Result<Option<SequencerBatch>> {
+        self.rooch_store.get_batch(batch_id)
+    }
+}
+
+/// How many transactions are packed into a block by the gas used of the transactions in the sequenced order,
+/// the transactions are packed until the gas used reaches the block gas limit, and all of them are packed without a limit.
+/// A transaction using more gas than the limit still makes a block alone.
+fn block_size(gas_used: impl ExactSizeIterator<Item = u64>, block_gas_limit: Option<u64>) -> usize {
+    let len = gas_used.len();
+    let Some(block_gas_limit) = block_gas_limit else {
+        return len;
+    };
+    let mut block_gas_used = 0u64;
+    for (i, gas_used) in gas_used.enumerate() {
+        block_gas_used = block_gas_used.saturating_add(gas_used);
+        if block_gas_used > block_gas_limit {
+            return i.max(1);
+        }
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_size() {
+        // All the transactions are packed without the limit
+        assert_eq!(block_size([10, 20, 30].into_iter(), None), 3);
+        assert_eq!(block_size([].into_iter(), Some(100)), 0);
+        // The transactions are packed until the limit is reached
+        assert_eq!(block_size([10, 20, 30].into_iter(), Some(100)), 3);
+        assert_eq!(block_size([10, 20, 30].into_iter(), Some(60)), 3);
+        assert_eq!(block_size([10, 20, 30].into_iter(), Some(59)), 2);
+        assert_eq!(block_size([10, 20, 30].into_iter(), Some(30)), 2);
+        assert_eq!(block_size([10, 20, 30].into_iter(), Some(29)), 1);
+        // A transaction exceeding the limit makes a block alone
+        assert_eq!(block_size([200, 20, 30].into_iter(), Some(100)), 1);
+        assert_eq!(block_size([10, 200, 30].into_iter(), Some(100)), 1);
+        // The gas used saturates instead of overflowing
+        assert_eq!(block_size([1, u64::MAX, 1].into_iter(), Some(u64::MAX)), 3);
+        assert_eq!(
+            block_size([1, u64::MAX, 1].into_iter(), Some(u64::MAX - 1)),
+            1
+        );
     }
 }
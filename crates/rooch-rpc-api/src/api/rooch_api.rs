@@ -1,22 +1,35 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::account_view::BalanceInfoView;
-use crate::jsonrpc_types::event_view::EventFilterView;
+use crate::jsonrpc_types::account_view::{BalanceInfoView, SequenceNumberView};
+use crate::jsonrpc_types::event_view::{EventFilterView, EventProofView};
+use crate::jsonrpc_types::fee_market_view::BaseGasPriceView;
+use crate::jsonrpc_types::gas_estimate_view::GasEstimateView;
+use crate::jsonrpc_types::gas_schedule_simulation_view::GasScheduleSimulationView;
+use crate::jsonrpc_types::gas_statistics_view::FunctionGasStatisticsView;
+use crate::jsonrpc_types::inclusion_receipt_view::TxInclusionReceiptView;
+use crate::jsonrpc_types::module_abi_view::ModuleABIView;
+use crate::jsonrpc_types::object_change_view::{ObjectChangeFilterView, ObjectChangeView};
+use crate::jsonrpc_types::sequencer_batch_view::{PartialSignatureView, SequencerBatchView};
+use crate::jsonrpc_types::source_verification_view::{PackageSourceView, SourceVerificationView};
+use crate::jsonrpc_types::transaction_validation_view::TransactionValidationResultView;
 use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::treasury_view::TreasuryStatusView;
 use crate::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    BytesView, EventOptions, EventPageView, ExecuteTransactionResponseView, FunctionCallView,
-    GlobalStateFilterView, H256View, IndexerEventPageView, IndexerGlobalStatePageView,
-    IndexerTableChangeSetPageView, IndexerTableStatePageView, StateOptions, StateSyncFilterView,
-    StateView, StatesPageView, StrView, StructTagView, TableStateFilterView,
-    TransactionWithInfoPageView,
+    BytesView, ChangeFeedPageView, EventOptions, EventPageView, ExecuteTransactionResponseView,
+    FunctionCallView, GlobalStateFilterView, H256View, IndexerEventPageView,
+    IndexerGlobalStatePageView, IndexerTableChangeSetPageView, IndexerTableStatePageView,
+    ModuleIdView, StateOptions, StateSyncFilterView, StateView, StatesPageView, StrView,
+    StructTagView, TableStateFilterView, TransactionWithInfoPageView,
 };
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
+use moveos_types::moveos_std::event::EventID;
 use rooch_open_rpc_macros::open_rpc;
 use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
+use std::collections::BTreeMap;
 
 #[open_rpc(namespace = "rooch")]
 #[rpc(server, client, namespace = "rooch")]
@@ -30,6 +43,15 @@ pub trait RoochAPI {
     #[method(name = "sendRawTransaction")]
     async fn send_raw_transaction(&self, tx_bcs_hex: BytesView) -> RpcResult<H256View>;
 
+    /// Send the signed transaction in bcs hex format, and return the inclusion receipt signed by the sequencer.
+    /// The receipt is returned once the transaction is sequenced, before it is executed. It carries the tx order
+    /// of the transaction, and is the evidence if the transaction is censored or reordered by the sequencer.
+    #[method(name = "sendRawTransactionWithReceipt")]
+    async fn send_raw_transaction_with_receipt(
+        &self,
+        tx_bcs_hex: BytesView,
+    ) -> RpcResult<TxInclusionReceiptView>;
+
     /// Send the signed transaction in bcs hex format
     /// This method blocks waiting for the transaction to be executed.
     #[method(name = "executeRawTransaction")]
@@ -38,6 +60,35 @@ pub trait RoochAPI {
         tx_bcs_hex: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView>;
 
+    /// Validate the signed transaction in bcs hex format
+    /// The transaction is validated against the latest state but not sequenced or executed,
+    /// the reject reason is returned if the transaction is invalid.
+    #[method(name = "validateTransaction")]
+    async fn validate_transaction(
+        &self,
+        tx_bcs_hex: BytesView,
+    ) -> RpcResult<TransactionValidationResultView>;
+
+    /// Estimate the gas of the unsigned transaction data in bcs hex format
+    /// The transaction is simulated on the latest state without being applied, return the gas used
+    /// and the suggested max gas amount with the `margin_percentage`, defaults to the margin of the node.
+    #[method(name = "estimateGas")]
+    async fn estimate_gas(
+        &self,
+        tx_data_bcs_hex: BytesView,
+        margin_percentage: Option<StrView<u64>>,
+    ) -> RpcResult<GasEstimateView>;
+
+    /// Get the current base gas price, the gas fee of a transaction is its gas used multiplied by the base gas price.
+    /// The base gas price is adjusted on chain at the end of every fee block by the gas used of the block against the target block gas.
+    #[method(name = "getBaseGasPrice")]
+    async fn get_base_gas_price(&self) -> RpcResult<BaseGasPriceView>;
+
+    /// Get the accounting of the treasury, the transaction fees are distributed to the sequencer,
+    /// the community treasury and burned by the configured percentages when the on-chain fee epoch ends.
+    #[method(name = "getTreasuryStatus")]
+    async fn get_treasury_status(&self) -> RpcResult<TreasuryStatusView>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
     #[method(name = "executeViewFunction")]
@@ -76,6 +127,12 @@ pub trait RoochAPI {
         event_options: Option<EventOptions>,
     ) -> RpcResult<EventPageView>;
 
+    /// Get the event with the proof of its inclusion in the event accumulator of the transaction,
+    /// the proof can be verified against the `event_root` of the trusted transaction execution info.
+    /// Return None if the event does not exist.
+    #[method(name = "getEventProof")]
+    async fn get_event_proof(&self, event_id: EventID) -> RpcResult<Option<EventProofView>>;
+
     #[method(name = "getTransactionsByHash")]
     async fn get_transactions_by_hash(
         &self,
@@ -89,6 +146,16 @@ pub trait RoochAPI {
         limit: Option<StrView<u64>>,
     ) -> RpcResult<TransactionWithInfoPageView>;
 
+    /// Get the change feed of the committed transactions in the tx order, every entry is a BCS encoded `ChangeFeedEntry`
+    /// with the transaction, the execution info and the changes. The read replicas apply the changes without executing
+    /// the transactions. The changes are only recorded if the node is started with the change feed enabled.
+    #[method(name = "getChangeFeed")]
+    async fn get_change_feed(
+        &self,
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<u64>>,
+    ) -> RpcResult<ChangeFeedPageView>;
+
     /// get account balance by AccountAddress and CoinType
     #[method(name = "getBalance")]
     async fn get_balance(
@@ -106,6 +173,95 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
     ) -> RpcResult<BalanceInfoPageView>;
 
+    /// Get the sequence number of the account
+    /// The `committed` is the sequence number on chain, if `include_pending` is true,
+    /// the `next` considers the pending transactions which are not executed yet.
+    #[method(name = "getSequenceNumber")]
+    async fn get_sequence_number(
+        &self,
+        address: AccountAddressView,
+        include_pending: Option<bool>,
+    ) -> RpcResult<SequenceNumberView>;
+
+    /// Get the free transactions the account can still send without the gas coin, on the local, dev and test chains
+    #[method(name = "getFreeGasQuota")]
+    async fn get_free_gas_quota(&self, address: AccountAddressView) -> RpcResult<StrView<u64>>;
+
+    /// Get the sequence numbers of the pending transactions of the account, which are not executed yet
+    /// This is a private method, the request must be signed by the account with the authentication headers.
+    #[method(name = "getPendingSequenceNumbers")]
+    async fn get_pending_sequence_numbers(
+        &self,
+        address: AccountAddressView,
+    ) -> RpcResult<Vec<StrView<u64>>>;
+
+    /// Resolve the name to the address by the on-chain name service
+    /// Return None if the name is not registered or expired
+    #[method(name = "resolveName")]
+    async fn resolve_name(&self, name: String) -> RpcResult<Option<AccountAddressView>>;
+
+    /// Get the cumulative gas statistics of the entry functions of all executed transactions,
+    /// sorted by the total gas used in descending order.
+    #[method(name = "getGasStatistics")]
+    async fn get_gas_statistics(
+        &self,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<Vec<FunctionGasStatisticsView>>;
+
+    /// Re-price the sample transactions under the gas schedule with the `new_entries` overridden, in a sandbox.
+    /// Every transaction is executed on the state before it, return the gas used of every transaction
+    /// and the aggregate cost changes, for evaluating a gas schedule change before voting.
+    /// The entry keys are the same as the `CostTable` entries, such as `instruction_tiers.3000`.
+    #[method(name = "simulateGasScheduleChange")]
+    async fn simulate_gas_schedule_change(
+        &self,
+        new_entries: BTreeMap<String, StrView<u64>>,
+        sample_tx_hashes: Vec<H256View>,
+    ) -> RpcResult<GasScheduleSimulationView>;
+
+    /// Get the ABI of the module, including the visibility of the functions and
+    /// whether the functions can be called by transactions directly.
+    /// Return None if the module does not exist.
+    #[method(name = "getModuleABI")]
+    async fn get_module_abi(&self, module_id: ModuleIdView) -> RpcResult<Option<ModuleABIView>>;
+
+    /// Get the sequencer batch by the batch id, with the encoded transactions, the DA commitment,
+    /// the tx accumulator root and the sequencer signatures, the third parties audit the sequencing with it.
+    /// Return None if the batch does not exist.
+    #[method(name = "getSequencerBatch")]
+    async fn get_sequencer_batch(
+        &self,
+        batch_id: StrView<u128>,
+    ) -> RpcResult<Option<SequencerBatchView>>;
+
+    /// Sign the sequencer batch with the key shares of the committee operator run by the node.
+    /// The operator checks the DA commitment of the batch against its transactions, and never signs two different
+    /// batches of the same batch id. This is a private method, the request must be signed by the sequencer account.
+    #[method(name = "signBatchCommitment")]
+    async fn sign_batch_commitment(
+        &self,
+        sequencer: AccountAddressView,
+        batch: SequencerBatchView,
+    ) -> RpcResult<Vec<PartialSignatureView>>;
+
+    /// Submit the source and the build metadata of a published package to the source registry.
+    /// The node rebuilds the source with its own compiler and the frameworks it is built with,
+    /// compares the bytecode hashes of the rebuilt modules with the on-chain modules, and records the verification status.
+    /// The package may only depend on the frameworks, and the rebuilds are rate limited. The latest submission overrides the previous one,
+    /// except that a mismatched submission never overrides a verified one.
+    #[method(name = "submitPackageSource")]
+    async fn submit_package_source(
+        &self,
+        source: PackageSourceView,
+    ) -> RpcResult<SourceVerificationView>;
+
+    /// Get the source verification of the package at the address, return None if no source is submitted.
+    #[method(name = "getSourceVerification")]
+    async fn get_source_verification(
+        &self,
+        package_address: AccountAddressView,
+    ) -> RpcResult<Option<SourceVerificationView>>;
+
     /// Query the transactions indexer by transaction filter
     #[method(name = "queryTransactions")]
     async fn query_transactions(
@@ -160,4 +316,9 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerTableChangeSetPageView>;
+
+    /// Subscribe the changes of the objects by the object ids or the object type over the WebSocket,
+    /// every change made by the executed transactions is notified with the decoded states before and after it.
+    #[subscription(name = "subscribeObjectChanges", item = ObjectChangeView)]
+    fn subscribe_object_changes(&self, filter: ObjectChangeFilterView);
 }
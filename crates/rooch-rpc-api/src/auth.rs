@@ -0,0 +1,161 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, ensure, Result};
+use fastcrypto::traits::ToFromBytes;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::{RoochKeyPair, Signature};
+use serde::Serialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+pub const AUTH_ADDRESS_HEADER: &str = "x-rooch-auth-address";
+pub const AUTH_SIGNATURE_HEADER: &str = "x-rooch-auth-signature";
+pub const AUTH_TIMESTAMP_HEADER: &str = "x-rooch-auth-timestamp";
+
+/// The max difference in milliseconds between the signed timestamp and the server time
+pub const MAX_AUTH_TIMESTAMP_SKEW_MS: u64 = 60_000;
+
+/// The private methods only serve the data of the caller, they require the authentication headers,
+/// and the first param must be the address of the caller.
+pub const PRIVATE_METHODS: &[&str] = &[
+    "rooch_getPendingSequenceNumbers",
+    "rooch_signBatchCommitment",
+];
+
+pub fn is_private_method(method: &str) -> bool {
+    PRIVATE_METHODS.contains(&method)
+}
+
+/// The message signed by the caller of a private method.
+/// The params are the compact JSON of the request params, `null` if the request has no params.
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcAuthMessage {
+    pub method: String,
+    pub params: String,
+    pub timestamp: u64,
+}
+
+impl RpcAuthMessage {
+    pub fn new(method: &str, params: &Value, timestamp: u64) -> Self {
+        Self {
+            method: method.to_owned(),
+            params: params.to_string(),
+            timestamp,
+        }
+    }
+
+    pub fn sign(&self, keypair: &RoochKeyPair) -> Signature {
+        Signature::new_secure(self, keypair)
+    }
+
+    pub fn verify(&self, signature: &Signature, address: RoochAddress) -> Result<()> {
+        signature
+            .verify_hashed(&Signature::secure_digest(self), address)
+            .map_err(|e| anyhow!("Invalid authentication signature: {}", e))
+    }
+}
+
+/// The authentication headers of a request to a private method
+#[derive(Debug, Clone)]
+pub struct RpcAuthHeaders {
+    pub address: RoochAddress,
+    pub signature: Signature,
+    /// The milliseconds since the unix epoch
+    pub timestamp: u64,
+}
+
+impl RpcAuthHeaders {
+    pub fn sign(keypair: &RoochKeyPair, method: &str, params: &Value, timestamp: u64) -> Self {
+        Self {
+            address: keypair.public().address(),
+            signature: RpcAuthMessage::new(method, params, timestamp).sign(keypair),
+            timestamp,
+        }
+    }
+
+    pub fn parse(address: &str, signature: &str, timestamp: &str) -> Result<Self> {
+        let signature = hex::decode(signature.trim_start_matches("0x"))?;
+        Ok(Self {
+            address: RoochAddress::from_str(address)?,
+            signature: Signature::from_bytes(&signature)
+                .map_err(|e| anyhow!("Invalid authentication signature: {}", e))?,
+            timestamp: timestamp.parse()?,
+        })
+    }
+
+    /// The header name and value pairs
+    pub fn to_headers(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (AUTH_ADDRESS_HEADER, self.address.to_string()),
+            (
+                AUTH_SIGNATURE_HEADER,
+                format!("0x{}", hex::encode(self.signature.as_ref())),
+            ),
+            (AUTH_TIMESTAMP_HEADER, self.timestamp.to_string()),
+        ]
+    }
+
+    /// Verify the signature over the request, the timestamp must be within the skew of the server time,
+    /// and the first param must be the address of the caller.
+    pub fn verify(&self, method: &str, params: &Value, now: u64) -> Result<()> {
+        ensure!(
+            self.timestamp.abs_diff(now) <= MAX_AUTH_TIMESTAMP_SKEW_MS,
+            "The authentication timestamp {} is expired, the server time is {}",
+            self.timestamp,
+            now
+        );
+        RpcAuthMessage::new(method, params, self.timestamp)
+            .verify(&self.signature, self.address)?;
+        let owner = match params {
+            Value::Array(params) => params.first(),
+            Value::Object(params) => params.values().next(),
+            _ => None,
+        }
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("The first param of {} must be the account address", method))?;
+        ensure!(
+            RoochAddress::from_str(owner)? == self.address,
+            "The caller {} can not access the data of {}",
+            self.address,
+            owner
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use rooch_types::address::RoochSupportedAddress;
+    use serde_json::json;
+
+    #[test]
+    fn test_rpc_auth_headers() {
+        let keypair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let address = keypair.public().address();
+        let method = PRIVATE_METHODS[0];
+        let params = json!([address.to_string()]);
+        let headers = RpcAuthHeaders::sign(&keypair, method, &params, 1000);
+
+        let pairs = headers.to_headers();
+        let parsed = RpcAuthHeaders::parse(&pairs[0].1, &pairs[1].1, &pairs[2].1).unwrap();
+        parsed.verify(method, &params, 2000).unwrap();
+
+        // Expired timestamp
+        assert!(parsed
+            .verify(method, &params, 1000 + MAX_AUTH_TIMESTAMP_SKEW_MS + 1)
+            .is_err());
+        // Tampered params
+        let other = RoochAddress::random();
+        assert!(parsed
+            .verify(method, &json!([other.to_string()]), 1000)
+            .is_err());
+        // The data of another account
+        let other_params = json!([other.to_string()]);
+        let headers = RpcAuthHeaders::sign(&keypair, method, &other_params, 1000);
+        assert!(headers.verify(method, &other_params, 1000).is_err());
+    }
+}
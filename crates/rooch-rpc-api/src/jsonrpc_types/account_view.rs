@@ -32,3 +32,20 @@ impl BalanceInfoView {
         balance.to_string()
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SequenceNumberView {
+    /// The sequence number of the account on chain
+    pub committed: StrView<u64>,
+    /// The next usable sequence number, considering the pending transactions if required
+    pub next: StrView<u64>,
+}
+
+impl SequenceNumberView {
+    pub fn new(committed: u64, next: u64) -> Self {
+        Self {
+            committed: StrView(committed),
+            next: StrView(next),
+        }
+    }
+}
@@ -4,7 +4,9 @@
 use crate::jsonrpc_types::{
     AccountAddressView, AnnotatedMoveStructView, H256View, StrView, StructTagView,
 };
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID, TransactionEvent};
+use rooch_types::event_proof::EventProof;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -72,12 +74,53 @@ impl From<AnnotatedEvent> for EventView {
     }
 }
 
+/// The event with the proof of its inclusion in the event accumulator of the transaction
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct EventProofView {
+    pub event: EventView,
+    pub tx_hash: H256View,
+    /// The root of the event accumulator, equal to the `event_root` of the transaction execution info
+    pub event_root: H256View,
+    /// The index of the event leaf in the event accumulator
+    pub leaf_index: u64,
+    /// The siblings from the event leaf to the root
+    pub siblings: Vec<H256View>,
+}
+
+impl EventProofView {
+    pub fn new(event: Event, proof: EventProof, event_root: H256) -> Self {
+        EventProofView {
+            event: event.into(),
+            tx_hash: proof.tx_hash.into(),
+            event_root: event_root.into(),
+            leaf_index: proof.leaf_index,
+            siblings: proof
+                .siblings()
+                .iter()
+                .map(|sibling| (*sibling).into())
+                .collect(),
+        }
+    }
+}
+
+impl From<EventProofView> for EventProof {
+    fn from(view: EventProofView) -> Self {
+        EventProof::new(
+            view.tx_hash.into(),
+            view.leaf_index,
+            view.siblings.into_iter().map(Into::into).collect(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct IndexerEventView {
     pub indexer_event_id: IndexerEventID,
     pub event_id: EventID,
     pub event_type: StructTagView,
     pub event_data: StrView<Vec<u8>>,
+    /// event index in the transaction events, the events are ordered by `(tx_order, event_index)`
+    pub event_index: u64,
     pub tx_hash: H256View,
     pub sender: AccountAddressView,
     pub created_at: u64,
@@ -92,6 +135,7 @@ impl From<IndexerEvent> for IndexerEventView {
             event_id: event.event_id,
             event_type: event.event_type.into(),
             event_data: StrView(event.event_data),
+            event_index: event.indexer_event_id.event_index,
             tx_hash: event.tx_hash.into(),
             sender: event.sender.into(),
             created_at: event.created_at,
@@ -4,10 +4,11 @@
 use super::BytesView;
 use super::{ModuleIdView, StateChangeSetView, StrView};
 use crate::jsonrpc_types::event_view::EventView;
+use crate::jsonrpc_types::static_analysis_view::StaticAnalysisWarningView;
 use crate::jsonrpc_types::H256View;
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
-use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
+use moveos_types::transaction::{GasBreakdown, GasDimension, TransactionExecutionInfo};
 use rooch_types::transaction::{authenticator::Authenticator, TransactionSequenceInfo};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -120,6 +121,10 @@ pub struct TransactionExecutionInfoView {
     pub event_root: H256View,
     pub gas_used: u64,
     pub status: KeptVMStatusView,
+    /// The instruction count reached when the transaction ran out of gas
+    pub out_of_gas_instructions: Option<StrView<u64>>,
+    /// The gas used of the transaction by category, it is not recorded for the transactions executed before the breakdown was recorded
+    pub gas_breakdown: Option<GasBreakdownView>,
 }
 
 impl From<TransactionExecutionInfo> for TransactionExecutionInfoView {
@@ -130,6 +135,61 @@ impl From<TransactionExecutionInfo> for TransactionExecutionInfoView {
             event_root: transaction_execution_info.event_root.into(),
             gas_used: transaction_execution_info.gas_used,
             status: KeptVMStatusView::from(transaction_execution_info.status),
+            out_of_gas_instructions: transaction_execution_info
+                .out_of_gas_instructions
+                .map(StrView),
+            gas_breakdown: transaction_execution_info
+                .gas_breakdown
+                .map(GasBreakdownView::from),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GasDimensionView {
+    Compute,
+    Storage,
+}
+
+impl From<GasDimension> for GasDimensionView {
+    fn from(dimension: GasDimension) -> Self {
+        match dimension {
+            GasDimension::Compute => Self::Compute,
+            GasDimension::Storage => Self::Storage,
+        }
+    }
+}
+
+/// The gas used of the transaction by category
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GasBreakdownView {
+    /// The gas charged per bytecode instruction
+    pub instruction_gas: StrView<u64>,
+    /// The gas charged by the stack height and the stack size
+    pub stack_gas: StrView<u64>,
+    /// The gas charged by the native functions
+    pub native_gas: StrView<u64>,
+    /// The gas charged by writing the transaction, the states and the events
+    pub storage_gas: StrView<u64>,
+    /// The gas charged by publishing the modules
+    pub publish_gas: StrView<u64>,
+    /// The gas refunded by deleting the states, it is deducted from the gas used
+    pub storage_rebate: StrView<u64>,
+    /// The gas of the system code, it is not charged
+    pub system_gas: StrView<u64>,
+}
+
+impl From<GasBreakdown> for GasBreakdownView {
+    fn from(gas_breakdown: GasBreakdown) -> Self {
+        Self {
+            instruction_gas: StrView(gas_breakdown.instruction_gas),
+            stack_gas: StrView(gas_breakdown.stack_gas),
+            native_gas: StrView(gas_breakdown.native_gas),
+            storage_gas: StrView(gas_breakdown.storage_gas),
+            publish_gas: StrView(gas_breakdown.publish_gas),
+            storage_rebate: StrView(gas_breakdown.storage_rebate),
+            system_gas: StrView(gas_breakdown.system_gas),
         }
     }
 }
@@ -143,6 +203,8 @@ pub struct TransactionOutputView {
     pub events: Vec<EventView>,
     pub gas_used: u64,
     pub is_upgrade: bool,
+    /// The dimension of the charge exhausting the budget when the transaction runs out of gas
+    pub out_of_gas_dimension: Option<GasDimensionView>,
 }
 
 impl From<TransactionOutput> for TransactionOutputView {
@@ -157,6 +219,7 @@ impl From<TransactionOutput> for TransactionOutputView {
                 .collect(),
             gas_used: tx_output.gas_used,
             is_upgrade: tx_output.is_upgrade,
+            out_of_gas_dimension: tx_output.out_of_gas_dimension.map(Into::into),
         }
     }
 }
@@ -173,6 +236,16 @@ pub struct ExecuteTransactionResponseView {
     pub sequence_info: TransactionSequenceInfoView,
     pub execution_info: TransactionExecutionInfoView,
     pub output: TransactionOutputView,
+    /// The warnings of the static analysis if the transaction publishes modules
+    #[serde(default)]
+    pub warnings: Vec<StaticAnalysisWarningView>,
+}
+
+impl ExecuteTransactionResponseView {
+    pub fn with_warnings(mut self, warnings: Vec<StaticAnalysisWarningView>) -> Self {
+        self.warnings = warnings;
+        self
+    }
 }
 
 impl From<ExecuteTransactionResponse> for ExecuteTransactionResponseView {
@@ -181,6 +254,7 @@ impl From<ExecuteTransactionResponse> for ExecuteTransactionResponseView {
             sequence_info: response.sequence_info.into(),
             execution_info: response.execution_info.into(),
             output: response.output.into(),
+            warnings: vec![],
         }
     }
 }
@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::StrView;
+use rooch_types::framework::transaction_fee::FeeMarketStatus;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BaseGasPriceView {
+    /// The price of a unit of gas of the next transactions, the gas fee is the gas used multiplied by it
+    pub base_gas_price: StrView<u64>,
+    pub min_base_gas_price: StrView<u64>,
+    pub target_block_gas: StrView<u64>,
+    /// The duration of a fee block in milliseconds
+    pub block_interval: StrView<u64>,
+    /// The on-chain time in milliseconds when the current fee block starts
+    pub block_start_time: StrView<u64>,
+    /// The gas used by the transactions of the current fee block
+    pub block_gas_used: StrView<u64>,
+    pub last_block_gas_used: StrView<u64>,
+    /// The number of the ended fee blocks
+    pub blocks: StrView<u64>,
+}
+
+impl From<FeeMarketStatus> for BaseGasPriceView {
+    fn from(status: FeeMarketStatus) -> Self {
+        Self {
+            base_gas_price: StrView(status.base_gas_price),
+            min_base_gas_price: StrView(status.min_base_gas_price),
+            target_block_gas: StrView(status.target_block_gas),
+            block_interval: StrView(status.block_interval),
+            block_start_time: StrView(status.block_start_time),
+            block_gas_used: StrView(status.block_gas_used),
+            last_block_gas_used: StrView(status.last_block_gas_used),
+            blocks: StrView(status.blocks),
+        }
+    }
+}
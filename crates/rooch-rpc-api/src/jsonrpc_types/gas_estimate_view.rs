@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::static_analysis_view::StaticAnalysisWarningView;
+use crate::jsonrpc_types::{GasDimensionView, KeptVMStatusView, StrView};
+use rooch_types::gas_estimate::GasEstimate;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasEstimateView {
+    /// The status of the simulated transaction, the estimate is only reliable if it is executed
+    pub status: KeptVMStatusView,
+    pub gas_used: StrView<u64>,
+    pub execution_gas_used: StrView<u64>,
+    pub storage_gas_used: StrView<u64>,
+    pub publish_gas_used: StrView<u64>,
+    pub storage_rebate: StrView<u64>,
+    /// The compute gas charged, the execution and the module publishing
+    pub compute_gas_used: StrView<u64>,
+    /// The dimension of the charge exhausting the budget if the simulated transaction runs out of gas
+    pub out_of_gas_dimension: Option<GasDimensionView>,
+    pub margin_percentage: StrView<u64>,
+    /// The max gas amount suggested for the transaction, the gas charged with the safety margin
+    pub suggested_max_gas_amount: StrView<u64>,
+    /// The compute budget suggested for the transaction
+    pub suggested_compute_budget: StrView<u64>,
+    /// The storage budget suggested for the transaction
+    pub suggested_storage_budget: StrView<u64>,
+    /// The warnings of the static analysis if the transaction publishes modules
+    #[serde(default)]
+    pub warnings: Vec<StaticAnalysisWarningView>,
+}
+
+impl GasEstimateView {
+    pub fn with_warnings(mut self, warnings: Vec<StaticAnalysisWarningView>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+}
+
+impl From<GasEstimate> for GasEstimateView {
+    fn from(estimate: GasEstimate) -> Self {
+        Self {
+            status: estimate.status.into(),
+            gas_used: StrView(estimate.gas_used),
+            execution_gas_used: StrView(estimate.execution_gas_used),
+            storage_gas_used: StrView(estimate.storage_gas_used),
+            publish_gas_used: StrView(estimate.publish_gas_used),
+            storage_rebate: StrView(estimate.storage_rebate),
+            compute_gas_used: StrView(estimate.compute_gas_used),
+            out_of_gas_dimension: estimate.out_of_gas_dimension.map(Into::into),
+            margin_percentage: StrView(estimate.margin_percentage),
+            suggested_max_gas_amount: StrView(estimate.suggested_max_gas_amount),
+            suggested_compute_budget: StrView(estimate.suggested_compute_budget),
+            suggested_storage_budget: StrView(estimate.suggested_storage_budget),
+            warnings: vec![],
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, KeptVMStatusView, StrView};
+use rooch_types::gas_schedule_simulation::{
+    GasScheduleSimulation, GasScheduleSimulationStatistics, TransactionGasSimulation,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionGasSimulationView {
+    pub tx_hash: H256View,
+    pub tx_order: StrView<u64>,
+    pub original_status: KeptVMStatusView,
+    pub original_gas_used: StrView<u64>,
+    pub simulated_status: KeptVMStatusView,
+    pub simulated_gas_used: StrView<u64>,
+    /// The gas change relative to the original gas used, in basis points
+    pub gas_change_bps: StrView<i64>,
+}
+
+impl From<TransactionGasSimulation> for TransactionGasSimulationView {
+    fn from(simulation: TransactionGasSimulation) -> Self {
+        Self {
+            gas_change_bps: StrView(simulation.gas_change_bps()),
+            tx_hash: simulation.tx_hash.into(),
+            tx_order: StrView(simulation.tx_order),
+            original_status: simulation.original_status.into(),
+            original_gas_used: StrView(simulation.original_gas_used),
+            simulated_status: simulation.simulated_status.into(),
+            simulated_gas_used: StrView(simulation.simulated_gas_used),
+        }
+    }
+}
+
+/// The changes are in basis points, 10000 basis points is 100%, the negative changes mean cheaper
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasScheduleSimulationStatisticsView {
+    pub sample_count: StrView<u64>,
+    pub total_original_gas_used: StrView<u64>,
+    pub total_simulated_gas_used: StrView<u64>,
+    pub total_change_bps: StrView<i64>,
+    pub median_change_bps: StrView<i64>,
+    pub max_increase_bps: StrView<i64>,
+    pub max_decrease_bps: StrView<i64>,
+    pub increased_count: StrView<u64>,
+    pub decreased_count: StrView<u64>,
+    pub unchanged_count: StrView<u64>,
+    pub status_changed_count: StrView<u64>,
+}
+
+impl From<GasScheduleSimulationStatistics> for GasScheduleSimulationStatisticsView {
+    fn from(statistics: GasScheduleSimulationStatistics) -> Self {
+        Self {
+            sample_count: StrView(statistics.sample_count),
+            total_original_gas_used: StrView(statistics.total_original_gas_used),
+            total_simulated_gas_used: StrView(statistics.total_simulated_gas_used),
+            total_change_bps: StrView(statistics.total_change_bps),
+            median_change_bps: StrView(statistics.median_change_bps),
+            max_increase_bps: StrView(statistics.max_increase_bps),
+            max_decrease_bps: StrView(statistics.max_decrease_bps),
+            increased_count: StrView(statistics.increased_count),
+            decreased_count: StrView(statistics.decreased_count),
+            unchanged_count: StrView(statistics.unchanged_count),
+            status_changed_count: StrView(statistics.status_changed_count),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasScheduleSimulationView {
+    pub statistics: GasScheduleSimulationStatisticsView,
+    pub transactions: Vec<TransactionGasSimulationView>,
+}
+
+impl From<GasScheduleSimulation> for GasScheduleSimulationView {
+    fn from(simulation: GasScheduleSimulation) -> Self {
+        Self {
+            statistics: simulation.statistics.into(),
+            transactions: simulation
+                .transactions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{FunctionIdView, StrView};
+use rooch_types::gas_statistics::FunctionGasStatistics;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionGasStatisticsView {
+    pub function_id: FunctionIdView,
+    pub call_count: StrView<u64>,
+    pub total_gas_used: StrView<u64>,
+    pub max_gas_used: StrView<u64>,
+    pub avg_gas_used: StrView<u64>,
+}
+
+impl From<FunctionGasStatistics> for FunctionGasStatisticsView {
+    fn from(statistics: FunctionGasStatistics) -> Self {
+        Self {
+            avg_gas_used: StrView(statistics.avg_gas_used()),
+            function_id: StrView(statistics.function_id),
+            call_count: StrView(statistics.call_count),
+            total_gas_used: StrView(statistics.total_gas_used),
+            max_gas_used: StrView(statistics.max_gas_used),
+        }
+    }
+}
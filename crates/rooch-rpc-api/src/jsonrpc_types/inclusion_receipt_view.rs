@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{AccountAddressView, BytesView, H256View, StrView};
+use rooch_types::sequencer::TxInclusionReceipt;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The inclusion receipt signed by the sequencer when it sequences the transaction, the users verify it with the light client.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TxInclusionReceiptView {
+    pub tx_hash: H256View,
+    /// The tx order the transaction is sequenced at
+    pub tx_order: StrView<u64>,
+    /// The timestamp of the sequencing in milliseconds
+    pub timestamp_ms: StrView<u64>,
+    pub sequencer: AccountAddressView,
+    pub signing_hash: H256View,
+    pub signature: BytesView,
+}
+
+impl From<TxInclusionReceipt> for TxInclusionReceiptView {
+    fn from(receipt: TxInclusionReceipt) -> Self {
+        Self {
+            signing_hash: receipt.signing_hash().into(),
+            tx_hash: receipt.tx_hash.into(),
+            tx_order: StrView(receipt.tx_order),
+            timestamp_ms: StrView(receipt.timestamp_ms),
+            sequencer: StrView(receipt.sequencer.into()),
+            signature: StrView(receipt.signature),
+        }
+    }
+}
+
+impl From<TxInclusionReceiptView> for TxInclusionReceipt {
+    fn from(view: TxInclusionReceiptView) -> Self {
+        Self {
+            tx_hash: view.tx_hash.0,
+            tx_order: view.tx_order.0,
+            timestamp_ms: view.timestamp_ms.0,
+            sequencer: view.sequencer.0.into(),
+            signature: view.signature.0,
+        }
+    }
+}
@@ -17,7 +17,19 @@ mod transaction_argument_view;
 pub mod account_view;
 pub mod eth;
 pub mod event_view;
+pub mod fee_market_view;
+pub mod gas_estimate_view;
+pub mod gas_schedule_simulation_view;
+pub mod gas_statistics_view;
+pub mod inclusion_receipt_view;
+pub mod module_abi_view;
+pub mod object_change_view;
+pub mod sequencer_batch_view;
+pub mod source_verification_view;
+pub mod static_analysis_view;
+pub mod transaction_validation_view;
 pub mod transaction_view;
+pub mod treasury_view;
 
 pub mod address;
 pub mod btc;
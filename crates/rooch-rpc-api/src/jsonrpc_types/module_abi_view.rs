@@ -0,0 +1,83 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{ModuleIdView, StrView};
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::{FunctionDefinition, Visibility},
+    normalized, CompiledModule,
+};
+use moveos_types::move_types::EntryFunctionVisibility;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionABIView {
+    pub name: String,
+    /// The Move visibility of the function, `public`, `friend` or `private`
+    pub visibility: String,
+    pub is_entry: bool,
+    /// The entry visibility of the function, `public entry`, `entry`, `public(friend) entry` or `non-entry`
+    pub entry_visibility: String,
+    /// Whether the function can be called by transactions directly
+    pub externally_callable: bool,
+    pub type_params_count: StrView<u64>,
+    pub params: Vec<String>,
+    pub returns: Vec<String>,
+}
+
+impl FunctionABIView {
+    fn new(module: &CompiledModule, function_def: &FunctionDefinition) -> Self {
+        let handle = module.function_handle_at(function_def.function);
+        let visibility = match function_def.visibility {
+            Visibility::Public => "public",
+            Visibility::Friend => "friend",
+            Visibility::Private => "private",
+        };
+        let entry_visibility =
+            EntryFunctionVisibility::new(function_def.visibility, function_def.is_entry);
+        let signature_to_strings = |signature_index| {
+            module
+                .signature_at(signature_index)
+                .0
+                .iter()
+                .map(|token| normalized::Type::new(module, token).to_string())
+                .collect::<Vec<_>>()
+        };
+        Self {
+            name: module.identifier_at(handle.name).to_string(),
+            visibility: visibility.to_owned(),
+            is_entry: function_def.is_entry,
+            entry_visibility: entry_visibility.to_string(),
+            externally_callable: entry_visibility.is_externally_callable(),
+            type_params_count: StrView(handle.type_parameters.len() as u64),
+            params: signature_to_strings(handle.parameters),
+            returns: signature_to_strings(handle.return_),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ModuleABIView {
+    pub module_id: ModuleIdView,
+    pub friends: Vec<ModuleIdView>,
+    pub functions: Vec<FunctionABIView>,
+}
+
+impl From<&CompiledModule> for ModuleABIView {
+    fn from(module: &CompiledModule) -> Self {
+        Self {
+            module_id: StrView(module.self_id()),
+            friends: module
+                .immediate_friends()
+                .into_iter()
+                .map(StrView)
+                .collect(),
+            functions: module
+                .function_defs()
+                .iter()
+                .map(|function_def| FunctionABIView::new(module, function_def))
+                .collect(),
+        }
+    }
+}
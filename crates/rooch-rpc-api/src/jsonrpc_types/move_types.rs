@@ -189,7 +189,7 @@ impl From<AnnotatedMoveValue> for AnnotatedMoveValueView {
 //     }
 // }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct AnnotatedObjectView {
     pub id: ObjectID,
     pub owner: AccountAddressView,
@@ -338,8 +338,9 @@ impl FromStr for StrView<ModuleId> {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct TypeInfoView {
+    #[schemars(with = "AccountAddressView")]
     pub account_address: AccountAddress,
     pub module_name: BytesView,
     pub struct_name: BytesView,
@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StateView, StrView, StructTagView};
+use moveos_types::moveos_std::object::ObjectID;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectChangeFilterView {
+    /// Subscribe the changes of the given objects.
+    ObjectIds(Vec<ObjectID>),
+    /// Subscribe the changes of the objects of the value type,
+    /// all the instantiations of a generic type are matched if the type params are omitted.
+    ObjectType(StructTagView),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectChangeTypeView {
+    New,
+    Modify,
+    Delete,
+}
+
+/// A change of an object made by an executed transaction
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ObjectChangeView {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256View,
+    pub object_id: ObjectID,
+    pub change_type: ObjectChangeTypeView,
+    /// The object state before the transaction, `None` if the object is created by the transaction
+    pub before: Option<StateView>,
+    /// The object state after the transaction, `None` if the object is deleted by the transaction
+    pub after: Option<StateView>,
+}
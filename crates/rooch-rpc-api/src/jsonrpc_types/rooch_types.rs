@@ -22,6 +22,8 @@ use std::string::String;
 
 pub type EventPageView = PageView<EventView, u64>;
 pub type TransactionWithInfoPageView = PageView<TransactionWithInfoView, u64>;
+/// The BCS encoded `ChangeFeedEntry`s in the tx order
+pub type ChangeFeedPageView = PageView<BytesView, u64>;
 pub type StatesPageView = PageView<KeyStateKVView, BytesView>;
 pub type BalanceInfoPageView = PageView<BalanceInfoView, BytesView>;
 pub type IndexerEventPageView = PageView<IndexerEventView, IndexerEventID>;
@@ -36,6 +38,8 @@ pub type InscriptionPageView = PageView<InscriptionStateView, IndexerStateID>;
 /// `next_cursor` points to the last item in the page;
 /// Reading with `next_cursor` will start from the next item after `next_cursor` if
 /// `next_cursor` is `Some`, otherwise it will start from the first item.
+/// The `next_cursor` is a continuation token, the client should pass it back as is.
+/// The page size is capped by `MAX_RESULT_LIMIT`, use `has_next_page` to check if there are more items.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct PageView<T, C> {
     pub data: Vec<T>,
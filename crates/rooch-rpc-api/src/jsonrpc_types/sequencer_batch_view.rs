@@ -0,0 +1,87 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{BytesView, H256View, StrView};
+use anyhow::{bail, Result};
+use rooch_types::committee::{OperatorIndex, PartialSignature};
+use rooch_types::sequencer::SequencerBatch;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The sequencer batch with its transactions, the auditors verify it with the light client.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SequencerBatchView {
+    pub batch_id: StrView<u128>,
+    pub tx_orders: Vec<StrView<u64>>,
+    pub tx_hashes: Vec<H256View>,
+    /// The encoded transactions in the batch, the DA commitment is the hash of the concatenated transactions
+    pub transactions: Vec<BytesView>,
+    /// The DA commitment of the batch
+    pub batch_hash: H256View,
+    pub execution_checksum: H256View,
+    pub prev_tx_accumulator_root: H256View,
+    pub tx_accumulator_root: H256View,
+    pub signing_hash: H256View,
+    pub signatures: Vec<BytesView>,
+}
+
+impl SequencerBatchView {
+    pub fn new(batch: SequencerBatch, transactions: Vec<Vec<u8>>) -> Self {
+        Self {
+            batch_id: StrView(batch.batch_id),
+            tx_orders: batch.tx_orders().into_iter().map(StrView).collect(),
+            signing_hash: batch.signing_hash().into(),
+            tx_hashes: batch.tx_hashes.into_iter().map(Into::into).collect(),
+            transactions: transactions.into_iter().map(StrView).collect(),
+            batch_hash: batch.batch_hash.into(),
+            execution_checksum: batch.execution_checksum.into(),
+            prev_tx_accumulator_root: batch.prev_tx_accumulator_root.into(),
+            tx_accumulator_root: batch.tx_accumulator_root.into(),
+            signatures: batch.signatures.into_iter().map(StrView).collect(),
+        }
+    }
+
+    /// Return the batch and the encoded transactions, to verify them with the light client
+    pub fn into_batch(self) -> Result<(SequencerBatch, Vec<Vec<u8>>)> {
+        let first_tx_order = match self.tx_orders.first() {
+            Some(tx_order) => tx_order.0,
+            None => bail!("The batch {} has no transaction", self.batch_id),
+        };
+        let batch = SequencerBatch {
+            batch_id: self.batch_id.0,
+            first_tx_order,
+            tx_hashes: self.tx_hashes.into_iter().map(|h| h.0).collect(),
+            batch_hash: self.batch_hash.0,
+            execution_checksum: self.execution_checksum.0,
+            prev_tx_accumulator_root: self.prev_tx_accumulator_root.0,
+            tx_accumulator_root: self.tx_accumulator_root.0,
+            signatures: self.signatures.into_iter().map(|s| s.0).collect(),
+        };
+        let transactions = self.transactions.into_iter().map(|tx| tx.0).collect();
+        Ok((batch, transactions))
+    }
+}
+
+/// The partial signature of a batch by the key share of a committee operator
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PartialSignatureView {
+    pub index: OperatorIndex,
+    pub signature: BytesView,
+}
+
+impl From<PartialSignature> for PartialSignatureView {
+    fn from(partial: PartialSignature) -> Self {
+        Self {
+            index: partial.index,
+            signature: StrView(partial.signature_bytes()),
+        }
+    }
+}
+
+impl TryFrom<PartialSignatureView> for PartialSignature {
+    type Error = anyhow::Error;
+
+    fn try_from(view: PartialSignatureView) -> Result<Self> {
+        PartialSignature::from_bytes(view.index, &view.signature.0)
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{AccountAddressView, BytesView, H256View, StrView};
+use rooch_types::source_verification::{
+    BuildMetadata, PackageSource, SourceVerification, SourceVerificationStatus,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BuildMetadataView {
+    pub compiler_version: String,
+    pub named_addresses: BTreeMap<String, AccountAddressView>,
+    /// The bytecode of the modules built from the source, keyed by the module name
+    pub modules: BTreeMap<String, BytesView>,
+}
+
+impl From<BuildMetadata> for BuildMetadataView {
+    fn from(metadata: BuildMetadata) -> Self {
+        Self {
+            compiler_version: metadata.compiler_version,
+            named_addresses: metadata
+                .named_addresses
+                .into_iter()
+                .map(|(name, address)| (name, address.into()))
+                .collect(),
+            modules: metadata
+                .modules
+                .into_iter()
+                .map(|(name, bytes)| (name, bytes.into()))
+                .collect(),
+        }
+    }
+}
+
+impl From<BuildMetadataView> for BuildMetadata {
+    fn from(metadata: BuildMetadataView) -> Self {
+        Self {
+            compiler_version: metadata.compiler_version,
+            named_addresses: metadata
+                .named_addresses
+                .into_iter()
+                .map(|(name, address)| (name, address.0))
+                .collect(),
+            modules: metadata
+                .modules
+                .into_iter()
+                .map(|(name, bytes)| (name, bytes.0))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PackageSourceView {
+    pub package_address: AccountAddressView,
+    pub package_name: String,
+    /// The Move.toml and the source files, keyed by the path relative to the package root
+    pub files: BTreeMap<String, String>,
+    pub build_metadata: BuildMetadataView,
+}
+
+impl From<PackageSource> for PackageSourceView {
+    fn from(source: PackageSource) -> Self {
+        Self {
+            package_address: source.package_address.into(),
+            package_name: source.package_name,
+            files: source.files,
+            build_metadata: source.build_metadata.into(),
+        }
+    }
+}
+
+impl From<PackageSourceView> for PackageSource {
+    fn from(source: PackageSourceView) -> Self {
+        Self {
+            package_address: source.package_address.0,
+            package_name: source.package_name,
+            files: source.files,
+            build_metadata: source.build_metadata.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase", tag = "type")]
+pub enum SourceVerificationStatusView {
+    Verified,
+    Mismatched { modules: Vec<String> },
+}
+
+impl From<SourceVerificationStatus> for SourceVerificationStatusView {
+    fn from(status: SourceVerificationStatus) -> Self {
+        match status {
+            SourceVerificationStatus::Verified => SourceVerificationStatusView::Verified,
+            SourceVerificationStatus::Mismatched { modules } => {
+                SourceVerificationStatusView::Mismatched { modules }
+            }
+        }
+    }
+}
+
+/// The verification record of a package, the explorers show the verified badge with it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SourceVerificationView {
+    pub verified: bool,
+    pub status: SourceVerificationStatusView,
+    pub source_hash: H256View,
+    pub verified_at: StrView<u64>,
+    pub source: PackageSourceView,
+}
+
+impl From<SourceVerification> for SourceVerificationView {
+    fn from(verification: SourceVerification) -> Self {
+        Self {
+            verified: verification.status.is_verified(),
+            status: verification.status.into(),
+            source_hash: verification.source_hash.into(),
+            verified_at: StrView(verification.verified_at),
+            source: verification.source.into(),
+        }
+    }
+}
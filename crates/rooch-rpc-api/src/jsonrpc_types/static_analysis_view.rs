@@ -0,0 +1,27 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_verifier::static_analysis::StaticAnalysisWarning;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A warning of the static analysis on the published modules, it never rejects the transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct StaticAnalysisWarningView {
+    pub module: String,
+    pub function: String,
+    /// One of `unbounded_loop`, `unchecked_arithmetic` and `mutable_object_parameter`
+    pub kind: String,
+    pub message: String,
+}
+
+impl From<StaticAnalysisWarning> for StaticAnalysisWarningView {
+    fn from(warning: StaticAnalysisWarning) -> Self {
+        Self {
+            module: warning.module,
+            function: warning.function,
+            kind: warning.kind.as_str().to_owned(),
+            message: warning.message,
+        }
+    }
+}
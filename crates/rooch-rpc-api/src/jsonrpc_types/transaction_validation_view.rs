@@ -0,0 +1,156 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StrView};
+use move_binary_format::errors::VMError;
+use move_core_types::vm_status::{AbortLocation, VMStatus};
+use moveos_types::h256::H256;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The abort codes of the `rooch_framework::transaction_validator` module
+const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_OLD: u64 = 1001;
+const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW: u64 = 1002;
+const ERROR_VALIDATE_ACCOUNT_DOES_NOT_EXIST: u64 = 1003;
+const ERROR_VALIDATE_CANT_PAY_GAS_DEPOSIT: u64 = 1004;
+const ERROR_VALIDATE_TRANSACTION_EXPIRED: u64 = 1005;
+const ERROR_VALIDATE_BAD_CHAIN_ID: u64 = 1006;
+const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_BIG: u64 = 1007;
+const ERROR_VALIDATE_CHAIN_PAUSED: u64 = 1008;
+const ERROR_VALIDATE_MODULE_PUBLISH_NOT_ALLOWED: u64 = 1009;
+const ERROR_VALIDATE_NOT_INSTALLED_AUTH_VALIDATOR: u64 = 1010;
+const ERROR_VALIDATE_MODULE_BLOCKED: u64 = 1011;
+const ERROR_VALIDATE_GAS_PRICE_TOO_HIGH: u64 = 1012;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionRejectReasonView {
+    BadChainId,
+    SequenceNumberTooOld,
+    SequenceNumberTooNew,
+    SequenceNumberTooBig,
+    AccountDoesNotExist,
+    CantPayGasDeposit,
+    TransactionExpired,
+    AuthValidatorNotInstalled,
+    /// The chain is paused, only the system transactions are accepted
+    ChainPaused,
+    /// The sender is not allowed to publish modules by the module publishing policy
+    ModulePublishNotAllowed,
+    /// The called function or its module is blocked by the module blocklist
+    ModuleBlocked,
+    /// The base gas price of the fee market exceeds the max gas price signed by the sender
+    GasPriceTooHigh,
+    /// The authenticator is rejected by the auth validator or the session key
+    InvalidAuthenticator,
+    /// The transaction is rejected by the MoveOS verifier, e.g. the entry function does not exist
+    VerificationFailed,
+    Other,
+}
+
+impl TransactionRejectReasonView {
+    fn from_vm_status(vm_status: &VMStatus) -> Self {
+        match vm_status {
+            VMStatus::MoveAbort(AbortLocation::Module(module_id), code) => {
+                match (module_id.name().as_str(), *code) {
+                    ("transaction_validator", ERROR_VALIDATE_BAD_CHAIN_ID) => Self::BadChainId,
+                    ("transaction_validator", ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_OLD) => {
+                        Self::SequenceNumberTooOld
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW) => {
+                        Self::SequenceNumberTooNew
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_BIG) => {
+                        Self::SequenceNumberTooBig
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_ACCOUNT_DOES_NOT_EXIST) => {
+                        Self::AccountDoesNotExist
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_CANT_PAY_GAS_DEPOSIT) => {
+                        Self::CantPayGasDeposit
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_TRANSACTION_EXPIRED) => {
+                        Self::TransactionExpired
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_NOT_INSTALLED_AUTH_VALIDATOR) => {
+                        Self::AuthValidatorNotInstalled
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_CHAIN_PAUSED) => Self::ChainPaused,
+                    ("transaction_validator", ERROR_VALIDATE_MODULE_PUBLISH_NOT_ALLOWED) => {
+                        Self::ModulePublishNotAllowed
+                    }
+                    ("transaction_validator", ERROR_VALIDATE_MODULE_BLOCKED) => Self::ModuleBlocked,
+                    ("transaction_validator", ERROR_VALIDATE_GAS_PRICE_TOO_HIGH) => {
+                        Self::GasPriceTooHigh
+                    }
+                    (name, _) if name == "session_key" || name.ends_with("_validator") => {
+                        Self::InvalidAuthenticator
+                    }
+                    _ => Self::Other,
+                }
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The result of the transaction pre-flight validation.
+/// The transaction is validated against the latest state, but not sequenced or executed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionValidationResultView {
+    pub tx_hash: H256View,
+    pub accepted: bool,
+    pub reject_reason: Option<TransactionRejectReasonView>,
+    /// The abort location of the rejection, if the transaction is rejected by a Move abort
+    pub abort_location: Option<String>,
+    /// The abort code of the rejection, if the transaction is rejected by a Move abort
+    pub abort_code: Option<StrView<u64>>,
+    pub message: Option<String>,
+}
+
+impl TransactionValidationResultView {
+    pub fn accepted(tx_hash: H256) -> Self {
+        Self {
+            tx_hash: tx_hash.into(),
+            accepted: true,
+            reject_reason: None,
+            abort_location: None,
+            abort_code: None,
+            message: None,
+        }
+    }
+
+    pub fn rejected(tx_hash: H256, error: &anyhow::Error) -> Self {
+        let (reject_reason, vm_status) = if let Some(vm_status) = error.downcast_ref::<VMStatus>() {
+            (
+                TransactionRejectReasonView::from_vm_status(vm_status),
+                Some(vm_status.clone()),
+            )
+        } else if let Some(vm_error) = error.downcast_ref::<VMError>() {
+            (
+                TransactionRejectReasonView::VerificationFailed,
+                Some(vm_error.clone().into_vm_status()),
+            )
+        } else {
+            (TransactionRejectReasonView::Other, None)
+        };
+        let (abort_location, abort_code) = match vm_status {
+            Some(VMStatus::MoveAbort(location, code)) => {
+                let location = match location {
+                    AbortLocation::Module(module_id) => module_id.short_str_lossless(),
+                    AbortLocation::Script => "script".to_owned(),
+                };
+                (Some(location), Some(StrView(code)))
+            }
+            _ => (None, None),
+        };
+        Self {
+            tx_hash: tx_hash.into(),
+            accepted: false,
+            reject_reason: Some(reject_reason),
+            abort_location,
+            abort_code,
+            message: Some(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::StrView;
+use move_core_types::u256::U256;
+use rooch_types::framework::treasury::TreasuryStatus;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TreasuryStatusView {
+    /// The number of the epochs whose fees are distributed
+    pub epoch: StrView<u64>,
+    /// The duration of an epoch in milliseconds
+    pub epoch_interval: StrView<u64>,
+    /// The on-chain time in milliseconds when the current epoch starts
+    pub epoch_start_time: StrView<u64>,
+    /// The percentages of the fees in basis points distributed to the sequencer, the community treasury and burned
+    pub sequencer_bps: StrView<u64>,
+    pub community_bps: StrView<u64>,
+    pub burn_bps: StrView<u64>,
+    /// The fees accumulated in the current epoch, they are distributed at the next epoch transition
+    pub pending_fee: StrView<U256>,
+    pub community_balance: StrView<U256>,
+    pub total_to_sequencer: StrView<U256>,
+    pub total_to_community: StrView<U256>,
+    pub total_burned: StrView<U256>,
+}
+
+impl From<TreasuryStatus> for TreasuryStatusView {
+    fn from(status: TreasuryStatus) -> Self {
+        Self {
+            epoch: StrView(status.epoch),
+            epoch_interval: StrView(status.epoch_interval),
+            epoch_start_time: StrView(status.epoch_start_time),
+            sequencer_bps: StrView(status.config.sequencer_bps),
+            community_bps: StrView(status.config.community_bps),
+            burn_bps: StrView(status.config.burn_bps()),
+            pending_fee: StrView(status.pending_fee),
+            community_balance: StrView(status.community_balance),
+            total_to_sequencer: StrView(status.total_to_sequencer),
+            total_to_community: StrView(status.total_to_community),
+            total_burned: StrView(status.total_burned),
+        }
+    }
+}
@@ -4,4 +4,5 @@
 #![allow(clippy::non_canonical_clone_impl)]
 
 pub mod api;
+pub mod auth;
 pub mod jsonrpc_types;
@@ -4,7 +4,8 @@
 use anyhow::Result;
 use eth_client::EthRpcClient;
 use jsonrpsee::core::client::ClientT;
-use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use jsonrpsee::http_client::{HeaderMap, HeaderValue, HttpClient, HttpClientBuilder};
+use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::metadata::Metadata;
 use move_core_types::resolver::ModuleResolver;
@@ -16,8 +17,12 @@ use moveos_types::{
     moveos_std::tx_context::TxContext, transaction::FunctionCall,
 };
 use rooch_client::RoochRpcClient;
+use rooch_rpc_api::api::rooch_api::RoochAPIClient;
+use rooch_rpc_api::auth::RpcAuthHeaders;
+use rooch_rpc_api::jsonrpc_types::AccountAddressView;
+use rooch_types::crypto::RoochKeyPair;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub mod client_config;
 pub mod eth_client;
@@ -58,6 +63,8 @@ impl ClientBuilder {
         );
 
         Ok(Client {
+            http_url: http.as_ref().to_owned(),
+            request_timeout: self.request_timeout,
             http: http_client.clone(),
             rooch: RoochRpcClient::new(http_client.clone()),
             eth: EthRpcClient::new(http_client),
@@ -77,6 +84,8 @@ impl Default for ClientBuilder {
 
 #[derive(Clone)]
 pub struct Client {
+    http_url: String,
+    request_timeout: Duration,
     http: Arc<HttpClient>,
     pub rooch: RoochRpcClient,
     pub eth: EthRpcClient,
@@ -96,6 +105,39 @@ impl Client {
     ) -> Result<serde_json::Value> {
         Ok(self.http.request(method, params).await?)
     }
+
+    /// Build a client which sends the authentication headers signed by the keypair over the method and params,
+    /// the client is only valid for the call of the same method and params.
+    fn signed_http_client(
+        &self,
+        keypair: &RoochKeyPair,
+        method: &str,
+        params: &serde_json::Value,
+    ) -> Result<HttpClient> {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let mut headers = HeaderMap::new();
+        for (name, value) in RpcAuthHeaders::sign(keypair, method, params, timestamp).to_headers() {
+            headers.insert(name, HeaderValue::from_str(&value)?);
+        }
+        Ok(HttpClientBuilder::default()
+            .request_timeout(self.request_timeout)
+            .set_headers(headers)
+            .build(&self.http_url)?)
+    }
+
+    /// Get the sequence numbers of the pending transactions of the keypair's account,
+    /// the private method requires the request signed by the account.
+    pub async fn get_pending_sequence_numbers(&self, keypair: &RoochKeyPair) -> Result<Vec<u64>> {
+        let address = AccountAddressView::from(AccountAddress::from(keypair.public().address()));
+        let params = serde_json::json!([address]);
+        let http = self.signed_http_client(keypair, "rooch_getPendingSequenceNumbers", &params)?;
+        Ok(http
+            .get_pending_sequence_numbers(address)
+            .await?
+            .into_iter()
+            .map(|sequence_number| sequence_number.0)
+            .collect())
+    }
 }
 
 impl MoveFunctionCaller for Client {
@@ -112,7 +154,7 @@ impl MoveFunctionCaller for Client {
 
 impl ModuleResolver for &Client {
     fn get_module_metadata(&self, _module_id: &ModuleId) -> Vec<Metadata> {
-        todo!()
+        vec![]
     }
 
     fn get_module(&self, id: &ModuleId) -> Result<Option<Vec<u8>>> {
@@ -3,7 +3,10 @@
 
 use anyhow::Result;
 use jsonrpsee::http_client::HttpClient;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::ModuleId;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::EventID;
 use moveos_types::{
     access_path::AccessPath,
     state::{MoveStructType, State},
@@ -11,7 +14,17 @@ use moveos_types::{
 };
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
 use rooch_rpc_api::jsonrpc_types::{
-    account_view::BalanceInfoView, transaction_view::TransactionWithInfoView,
+    account_view::{BalanceInfoView, SequenceNumberView},
+    event_view::EventProofView,
+    fee_market_view::BaseGasPriceView,
+    gas_estimate_view::GasEstimateView,
+    gas_statistics_view::FunctionGasStatisticsView,
+    inclusion_receipt_view::TxInclusionReceiptView,
+    module_abi_view::ModuleABIView,
+    source_verification_view::{PackageSourceView, SourceVerificationView},
+    transaction_validation_view::TransactionValidationResultView,
+    transaction_view::TransactionWithInfoView,
+    treasury_view::TreasuryStatusView,
 };
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
@@ -19,7 +32,11 @@ use rooch_rpc_api::jsonrpc_types::{
 };
 use rooch_rpc_api::jsonrpc_types::{BytesView, TransactionWithInfoPageView};
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, StateView};
-use rooch_types::{account::Account, address::RoochAddress, transaction::rooch::RoochTransaction};
+use rooch_types::{
+    account::Account,
+    address::RoochAddress,
+    transaction::rooch::{RoochTransaction, RoochTransactionData},
+};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -47,6 +64,55 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Send the transaction and return the inclusion receipt signed by the sequencer
+    pub async fn send_tx_with_receipt(
+        &self,
+        tx: RoochTransaction,
+    ) -> Result<TxInclusionReceiptView> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        self.http
+            .send_raw_transaction_with_receipt(tx_payload.into())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn validate_tx(
+        &self,
+        tx: RoochTransaction,
+    ) -> Result<TransactionValidationResultView> {
+        let tx_payload = bcs::to_bytes(&tx)?;
+        self.http
+            .validate_transaction(tx_payload.into())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn estimate_gas(
+        &self,
+        tx_data: RoochTransactionData,
+        margin_percentage: Option<u64>,
+    ) -> Result<GasEstimateView> {
+        let tx_data_payload = bcs::to_bytes(&tx_data)?;
+        self.http
+            .estimate_gas(tx_data_payload.into(), margin_percentage.map(Into::into))
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn get_base_gas_price(&self) -> Result<BaseGasPriceView> {
+        self.http
+            .get_base_gas_price()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn get_treasury_status(&self) -> Result<TreasuryStatusView> {
+        self.http
+            .get_treasury_status()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -109,6 +175,17 @@ impl RoochRpcClient {
             .map_or(0, |account| account.sequence_number))
     }
 
+    /// Get the committed and the next usable sequence number, considering the pending transactions
+    pub async fn get_sequence_number_with_pending(
+        &self,
+        sender: RoochAddress,
+    ) -> Result<SequenceNumberView> {
+        Ok(self
+            .http
+            .get_sequence_number(AccountAddress::from(sender).into(), Some(true))
+            .await?)
+    }
+
     pub async fn get_events_by_event_handle(
         &self,
         event_handle_type: StructTagView,
@@ -128,6 +205,10 @@ impl RoochRpcClient {
         Ok(s)
     }
 
+    pub async fn get_event_proof(&self, event_id: EventID) -> Result<Option<EventProofView>> {
+        Ok(self.http.get_event_proof(event_id).await?)
+    }
+
     pub async fn list_states(
         &self,
         access_path: AccessPathView,
@@ -176,4 +257,36 @@ impl RoochRpcClient {
             .get_balances(account_addr, cursor, limit.map(Into::into))
             .await?)
     }
+
+    pub async fn resolve_name(&self, name: String) -> Result<Option<AccountAddress>> {
+        Ok(self.http.resolve_name(name).await?.map(Into::into))
+    }
+
+    pub async fn get_gas_statistics(
+        &self,
+        limit: Option<usize>,
+    ) -> Result<Vec<FunctionGasStatisticsView>> {
+        Ok(self.http.get_gas_statistics(limit.map(Into::into)).await?)
+    }
+
+    pub async fn get_module_abi(&self, module_id: ModuleId) -> Result<Option<ModuleABIView>> {
+        Ok(self.http.get_module_abi(module_id.into()).await?)
+    }
+
+    pub async fn submit_package_source(
+        &self,
+        source: PackageSourceView,
+    ) -> Result<SourceVerificationView> {
+        Ok(self.http.submit_package_source(source).await?)
+    }
+
+    pub async fn get_source_verification(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<SourceVerificationView>> {
+        Ok(self
+            .http
+            .get_source_verification(package_address.into())
+            .await?)
+    }
 }
@@ -103,6 +103,46 @@ impl WalletContext {
         }
     }
 
+    /// Resolve the address, the named address is resolved from the local address mapping first,
+    /// and then from the on-chain name service.
+    pub async fn resolve_address_with_name_service(
+        &self,
+        parsed_address: ParsedAddress,
+    ) -> RoochResult<AccountAddress> {
+        match parsed_address {
+            ParsedAddress::Numerical(address) => Ok(address.into_inner()),
+            ParsedAddress::Named(name) => {
+                if let Some(address) = self.address_mapping.get(&name) {
+                    return Ok(*address);
+                }
+                let client = self.get_client().await?;
+                client
+                    .rooch
+                    .resolve_name(name.clone())
+                    .await?
+                    .ok_or_else(|| {
+                        RoochError::CommandArgumentError(format!(
+                            "Unknown named address: {}",
+                            name
+                        ))
+                    })
+            }
+        }
+    }
+
+    /// Like `address_mapping`, but fallback to the on-chain name service if the name is not in the local address mapping.
+    pub async fn address_mapping_with_name_service(&self) -> Result<AddressMappingFn> {
+        let address_mapping = self.address_mapping.clone();
+        let client = self.get_client().await?;
+        Ok(Box::new(move |name| {
+            address_mapping.get(name).cloned().or_else(|| {
+                futures::executor::block_on(client.rooch.resolve_name(name.to_owned()))
+                    .ok()
+                    .flatten()
+            })
+        }))
+    }
+
     /// Parse and resolve addresses from a map of name to address string    
     pub fn parse_and_resolve_addresses(
         &self,
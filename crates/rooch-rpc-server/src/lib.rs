@@ -5,21 +5,24 @@ use crate::server::btc_server::BtcServer;
 use anyhow::{Error, Result};
 use coerce::actor::scheduler::timer::Timer;
 use coerce::actor::{system::ActorSystem, IntoActor};
-use hyper::header::HeaderValue;
-use hyper::Method;
 use jsonrpsee::server::ServerBuilder;
 use jsonrpsee::RpcModule;
 use serde_json::json;
-use std::env;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::net::SocketAddr;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
-use tower_http::cors::{AllowOrigin, CorsLayer};
+use tokio::task::JoinHandle;
+use tower_http::compression::CompressionLayer;
 use tower_http::trace::TraceLayer;
-use tracing::info;
+use tracing::{error, info, warn};
 
+use move_core_types::account_address::AccountAddress;
 use moveos_store::{MoveOSDB, MoveOSStore};
+use moveos_types::gas_config::GasMeterKind;
+use moveos_types::module_binding::MoveFunctionCaller;
 use raw_store::errors::RawStoreError;
 use raw_store::rocks::RocksDB;
 use raw_store::StoreInstance;
@@ -35,7 +38,10 @@ use rooch_da::server::celestia::proxy::DAServerCelestiaProxy;
 use rooch_da::server::serverproxy::DAServerNopProxy;
 use rooch_da::server::serverproxy::DAServerProxy;
 use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::object_change_notifier::ObjectChangeNotifier;
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::proxy::gas_schedule_simulator::GasScheduleSimulator;
+use rooch_executor::proxy::view_executor_pool::ViewExecutorPool;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
@@ -45,24 +51,47 @@ use rooch_indexer::IndexerStore;
 use rooch_key::key_derive::{generate_new_key_pair, retrieve_key_pair};
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
+use rooch_proposer::committee::{CommitteeOperator, CommitteeSigner, RemotePartialSigner};
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_relayer::actor::messages::RelayTick;
 use rooch_relayer::actor::relayer::RelayerActor;
 use rooch_rpc_api::api::RoochRpcModule;
 use rooch_sequencer::actor::sequencer::SequencerActor;
+use rooch_sequencer::priority::TxPriorityClassifier;
 use rooch_sequencer::proxy::SequencerProxy;
+use rooch_sequencer::quota::ModuleGasQuotas;
 use rooch_store::RoochStore;
 use rooch_types::address::RoochAddress;
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::bitcoin::network::Network;
+use rooch_types::committee::CommitteeKeyFile;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::{GenesisError, RoochError};
+use rooch_types::framework::chain_pause::ChainPauseModule;
+use rooch_types::framework::sequencer_key_registry::DEFAULT_HANDOVER_WINDOW_MILLISECONDS;
+use rooch_types::migration::MigrationRegistry;
+use rooch_types::sequencer::ProposerBacklog;
 
 use crate::server::eth_server::{EthNetServer, EthServer};
 use crate::server::rooch_server::RoochServer;
 use crate::service::aggregate_service::AggregateService;
+use crate::service::cold_storage::{ColdStorageActor, ColdStorageTick};
+use crate::service::event_pruner::{EventPruneTick, EventPrunerActor};
+use crate::service::explorer::{ExplorerLayer, EXPLORER_PATH};
+use crate::service::load_shed::LoadShedLayer;
+use crate::service::migration::{register_relayer, run_migrations, upgrade_framework_to};
+use crate::service::pending_tx_recovery::{
+    recover_pending_transactions, run_pending_tx_recovery, PENDING_TX_RECOVERY_INTERVAL,
+};
+use crate::service::replica::{ReplicaSyncer, DEFAULT_REPLICA_POLL_INTERVAL_MILLISECONDS};
+use crate::service::rpc_auth::RpcAuthLayer;
+use crate::service::rpc_cors::{build_cors_layer, RpcNamespaceCorsLayer};
 use crate::service::rpc_logger::RpcLogger;
 use crate::service::rpc_service::RpcService;
+use crate::service::sequencer_key_rotation::sync_sequencer_key_rotation;
+use crate::service::telemetry::{TelemetryActor, TelemetryTick};
+use crate::service::tls_proxy::{load_tls_acceptor, start_tls_proxy};
+use crate::service::webhook::WebhookDispatcher;
 
 pub mod server;
 pub mod service;
@@ -73,6 +102,7 @@ static R_EXIT_CODE_NEED_HELP: i32 = 120;
 pub struct ServerHandle {
     handle: jsonrpsee::server::ServerHandle,
     timers: Vec<Timer>,
+    tls_proxy: Option<JoinHandle<()>>,
     _store_config: StoreConfig,
     _index_config: IndexerConfig,
 }
@@ -83,6 +113,9 @@ impl ServerHandle {
         for timer in self.timers {
             timer.stop();
         }
+        if let Some(tls_proxy) = self.tls_proxy {
+            tls_proxy.abort();
+        }
         Ok(())
     }
 }
@@ -179,9 +212,16 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     // tracing_subscriber can only be inited once.
     let _ = tracing_subscriber::fmt::try_init();
 
-    let config = opt.port.map_or(ServerConfig::default(), |port| {
+    let mut config = opt.port.map_or(ServerConfig::default(), |port| {
         ServerConfig::new_with_port(port)
     });
+    if let Some(max_request_body_size) = opt.max_request_body_size {
+        config.max_request_body_size = max_request_body_size;
+    }
+    config.cors = opt.cors_config()?;
+    config.tls = opt.tls_config();
+    config.explorer = opt.explorer.unwrap_or(false);
+    config.load_shed = opt.load_shed_config();
     let chain_id_opt = opt.chain_id.clone().unwrap_or_default();
 
     let addr: SocketAddr = format!("{}:{}", config.host, config.port).parse()?;
@@ -189,6 +229,7 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
 
     //Init store
     let base_config = BaseConfig::load_with_opt(opt)?;
+    let data_dir = base_config.data_dir.clone();
     let mut store_config = StoreConfig::default();
     store_config.merge_with_opt_with_init(opt, Arc::new(base_config.clone()), true)?;
     let (moveos_store, rooch_store) = init_storage(&store_config)?;
@@ -224,12 +265,50 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     // Init executor
     let is_genesis = moveos_store.statedb.is_genesis();
     let btc_network = opt.btc_network.unwrap_or(Network::default().to_num());
-    let executor_actor = ExecutorActor::new(
-        chain_id_opt.genesis_ctx(sequencer_account),
+    let mut executor_actor = ExecutorActor::new(
+        chain_id_opt
+            .genesis_ctx(sequencer_account)
+            .with_gas_schedule_entries(opt.genesis_gas_schedule_entries()?),
         BitcoinGenesisContext::new(btc_network),
         moveos_store.clone(),
         rooch_store.clone(),
     )?;
+    executor_actor.set_change_feed(opt.change_feed.unwrap_or(false));
+    let gas_meter_kind = opt.gas_meter_kind();
+    if gas_meter_kind != GasMeterKind::Standard {
+        // The gas used depends on the meter, the nodes of a shared chain must meter the transactions the same
+        if !chain_id_opt.is_local() {
+            return Err(anyhow::anyhow!(
+                "The {} gas meter is only allowed on the local chain",
+                gas_meter_kind
+            ));
+        }
+        warn!(
+            "Meter the transactions with the {} gas meter",
+            gas_meter_kind
+        );
+    }
+    executor_actor.set_gas_meter_kind(gas_meter_kind);
+    if let Some(gas_trace_dir) = &opt.gas_trace_dir {
+        warn!(
+            "Write the gas traces of the transactions to {:?}",
+            gas_trace_dir
+        );
+    }
+    executor_actor.set_gas_trace_dir(
+        opt.gas_trace_dir.clone(),
+        opt.gas_trace_format.unwrap_or_default(),
+    )?;
+    // The object changes are published by the executor in the tx order
+    let object_changes = ObjectChangeNotifier::default();
+    executor_actor.set_object_change_notifier(object_changes.clone());
+    // The natives and the Rust bindings are built with the embedded framework,
+    // so the node does not serve the writes if the embedded framework mismatches the on-chain framework.
+    // A framework which can not be checked does not stop the node, the node does not serve the writes.
+    let framework_check = executor_actor
+        .genesis()
+        .check_framework(executor_actor.moveos());
+    let framework_upgrade_action = executor_actor.genesis().framework_upgrade_action()?;
     let reader_executor = ReaderExecutorActor::new(
         executor_actor.genesis().clone(),
         moveos_store.clone(),
@@ -237,6 +316,19 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     )?
     .into_actor(Some("ReaderExecutor"), &actor_system)
     .await?;
+    let view_executors = ViewExecutorPool::new(
+        executor_actor.genesis(),
+        moveos_store.clone(),
+        rooch_store.clone(),
+        &opt.view_execution_config(),
+        &actor_system,
+    )
+    .await?;
+    let gas_schedule_simulator = GasScheduleSimulator::new(
+        executor_actor.genesis(),
+        moveos_store.clone(),
+        rooch_store.clone(),
+    );
     let executor = executor_actor
         .into_actor(Some("Executor"), &actor_system)
         .await?;
@@ -244,10 +336,87 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
 
     // Init sequencer
     info!("RPC Server sequencer address: {:?}", sequencer_account);
-    let sequencer = SequencerActor::new(sequencer_keypair, rooch_store, is_genesis)?
+    let mut module_gas_quotas = BTreeMap::new();
+    if let Some(module_gas_quota_config) = opt.module_gas_quota_config()? {
+        for quota in module_gas_quota_config.quotas {
+            let module_address =
+                AccountAddress::from_hex_literal(&quota.module_address).map_err(|e| {
+                    anyhow::anyhow!(
+                        "Invalid module address {} of the gas quota: {}",
+                        quota.module_address,
+                        e
+                    )
+                })?;
+            info!(
+                "Enforce the gas quota {} per block of the module address {}",
+                quota.gas_per_block, quota.module_address
+            );
+            module_gas_quotas.insert(module_address, quota.gas_per_block);
+        }
+    }
+    // The gas used by the executed transactions waiting to be proposed, the sequencer rejects the transactions
+    // while the proposer lags behind
+    let proposer_backlog = ProposerBacklog::default();
+    let sequencer = SequencerActor::new(sequencer_keypair.copy(), rooch_store.clone(), is_genesis)?
+        .with_module_gas_quotas(ModuleGasQuotas::new(module_gas_quotas))
+        .with_proposer_backlog(proposer_backlog.clone(), Some(opt.max_buffered_gas()))
         .into_actor(Some("Sequencer"), &actor_system)
         .await?;
     let sequencer_proxy = SequencerProxy::new(sequencer.into());
+    // Load the chain pause state, the chain may be paused before the node restarts
+    match executor_proxy
+        .as_module_binding::<ChainPauseModule>()
+        .is_paused()
+    {
+        Ok(paused) => sequencer_proxy.update_chain_pause(paused).await?,
+        Err(e) => warn!("Failed to load the chain pause state: {:?}", e),
+    }
+    let relayers = match executor_proxy
+        .as_module_binding::<ChainPauseModule>()
+        .relayers()
+    {
+        Ok(relayers) => relayers,
+        Err(e) => {
+            warn!("Failed to load the relayers: {:?}", e);
+            vec![]
+        }
+    };
+    sequencer_proxy.update_relayers(relayers.clone()).await?;
+
+    // The on-chain framework older than the embedded one is upgraded by the sequencer before the writes are served,
+    // the read replica applies the upgrade of the primary.
+    let mut upgrade_framework = false;
+    match &framework_check {
+        Ok(framework_check) if framework_check.is_matched() => {}
+        Ok(framework_check) if framework_check.is_upgradable() && opt.replica_of.is_none() => {
+            warn!(
+                "The on-chain framework is older than the embedded framework, upgrade it: {}",
+                framework_check
+            );
+            upgrade_framework = true;
+        }
+        Ok(framework_check) if opt.allow_framework_mismatch.unwrap_or(false) => {
+            warn!(
+                "The framework mismatch is allowed, the node serves the writes: {}",
+                framework_check
+            );
+        }
+        Ok(framework_check) => {
+            error!("The framework mismatches: {}", framework_check);
+            sequencer_proxy
+                .set_read_only(Some(format!(
+                    "the embedded framework mismatches the on-chain framework, {}",
+                    framework_check
+                )))
+                .await?;
+        }
+        Err(e) => {
+            error!("Failed to check the framework: {:?}", e);
+            sequencer_proxy
+                .set_read_only(Some(format!("failed to check the framework, {}", e)))
+                .await?;
+        }
+    }
 
     // Init DA
     let mut da_config = DAConfig::default(); // TODO use opt
@@ -281,9 +450,76 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let proposer_keypair = server_opt.proposer_keypair.unwrap();
     let proposer_account: RoochAddress = (&proposer_keypair.public()).into();
     info!("RPC Server proposer address: {:?}", proposer_account);
-    let proposer = ProposerActor::new(proposer_keypair, da_proxy)
-        .into_actor(Some("Proposer"), &actor_system)
-        .await?;
+    let next_sequencer_keypair = server_opt.next_sequencer_keypair;
+    if let Some(next_sequencer_keypair) = &next_sequencer_keypair {
+        let next_sequencer_account: RoochAddress = (&next_sequencer_keypair.public()).into();
+        info!(
+            "RPC Server next sequencer address: {:?}, the proposer signs the batches with both sequencer keys",
+            next_sequencer_account
+        );
+    }
+    let committee_key_file = opt
+        .committee_key_file
+        .as_ref()
+        .map(|path| CommitteeKeyFile::load(path))
+        .transpose()?;
+    let mut committee_signer = None;
+    let mut committee_operator = None;
+    if let Some(key_file) = committee_key_file {
+        let committee_sequencer = match &opt.committee_sequencer_account {
+            Some(account) => RoochAddress::from_str(account)?,
+            None => sequencer_account,
+        };
+        committee_operator = Some(Arc::new(CommitteeOperator::new(
+            key_file.clone(),
+            committee_sequencer,
+        )?));
+        info!(
+            "RPC Server sequencer committee operators: {:?}, signing the batches of the sequencer {:?}",
+            key_file
+                .shares
+                .iter()
+                .map(|share| share.index)
+                .collect::<Vec<_>>(),
+            committee_sequencer
+        );
+        // The node threshold-signs its own batches if the local key shares and the remote operators reach the threshold,
+        // the signer fails at startup if they are configured but can not reach it.
+        let threshold = key_file.public_key.threshold as usize;
+        if !opt.committee_operators.is_empty() || key_file.shares.len() >= threshold {
+            let remote_signers = opt
+                .committee_operators
+                .iter()
+                .map(|endpoint| {
+                    let (index, url) = RemotePartialSigner::parse_endpoint(endpoint)?;
+                    Ok(RemotePartialSigner::new(
+                        index,
+                        url,
+                        sequencer_keypair.copy(),
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let signer = CommitteeSigner::from_key_file(key_file, remote_signers)?;
+            info!(
+                "RPC Server sequencer committee: {}-of-{}, the proposer threshold-signs the batches",
+                signer.public_key().threshold,
+                signer.public_key().committee_size()
+            );
+            committee_signer = Some(signer);
+        }
+    }
+    let proposer = ProposerActor::new(
+        proposer_keypair,
+        sequencer_keypair.copy(),
+        da_proxy,
+        rooch_store.clone(),
+    )?
+    .with_next_sequencer_key(next_sequencer_keypair.as_ref().map(|kp| kp.copy()))
+    .with_committee_signer(committee_signer)
+    .with_block_gas_limit(Some(opt.block_gas_limit()))
+    .with_backlog(proposer_backlog)
+    .into_actor(Some("Proposer"), &actor_system)
+    .await?;
     let proposer_proxy = ProposerProxy::new(proposer.clone().into());
     //TODO load from config
     let block_propose_duration_in_seconds: u64 = 5;
@@ -296,7 +532,7 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     timers.push(proposer_timer);
 
     // Init indexer
-    let indexer_executor = IndexerActor::new(indexer_store, moveos_store)?
+    let indexer_executor = IndexerActor::new(indexer_store, moveos_store.clone())?
         .into_actor(Some("Indexer"), &actor_system)
         .await?;
     let indexer_reader_executor = IndexerReaderActor::new(indexer_reader)?
@@ -304,15 +540,170 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         .await?;
     let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
 
-    let rpc_service = RpcService::new(
+    // Init the telemetry reporter if the node opts in
+    if let Some(telemetry_config) = opt.telemetry_config() {
+        info!(
+            "Report the node telemetry to {} every {} seconds",
+            telemetry_config.endpoint, telemetry_config.interval_secs
+        );
+        let interval = Duration::from_secs(telemetry_config.interval_secs);
+        let telemetry = TelemetryActor::new(
+            telemetry_config,
+            chain_id_opt.chain_id().id(),
+            rooch_store.clone(),
+            &data_dir,
+        )?
+        .into_actor(Some("Telemetry"), &actor_system)
+        .await?;
+        timers.push(Timer::start(telemetry, interval, TelemetryTick {}));
+    }
+
+    // Init the event pruner if a retention policy is configured
+    if let Some(event_retention_config) = opt.event_retention_config()? {
+        info!(
+            "Prune the events out of the retention windows, keep txs: {:?}, keep days: {:?}, archive dir: {:?}",
+            event_retention_config.keep_txs,
+            event_retention_config.keep_days,
+            event_retention_config.archive_dir
+        );
+        let interval = Duration::from_secs(event_retention_config.interval_secs);
+        let event_pruner = EventPrunerActor::new(
+            event_retention_config,
+            rooch_store.clone(),
+            moveos_store,
+            &data_dir,
+        )?
+        .into_actor(Some("EventPruner"), &actor_system)
+        .await?;
+        timers.push(Timer::start(event_pruner, interval, EventPruneTick {}));
+    }
+
+    // Init the cold storage tiering if a hot window is configured
+    if let Some(cold_storage_config) = opt.cold_storage_config() {
+        info!(
+            "Move the transactions older than the last {} transactions to the cold store {:?}",
+            cold_storage_config.keep_txs,
+            rooch_store.get_cold_store().segment_dir()
+        );
+        let interval = Duration::from_secs(cold_storage_config.interval_secs);
+        let cold_storage = ColdStorageActor::new(cold_storage_config, rooch_store.clone())
+            .into_actor(Some("ColdStorage"), &actor_system)
+            .await?;
+        timers.push(Timer::start(cold_storage, interval, ColdStorageTick {}));
+    }
+
+    // Only the protocol-critical transactions sent by the sequencer or the relayer are sequenced ahead
+    let mut tx_classifier = TxPriorityClassifier::new(sequencer_account.clone().into());
+    if let Some(relayer_keypair) = &server_opt.relayer_keypair {
+        let relayer_account: RoochAddress = (&relayer_keypair.public()).into();
+        tx_classifier = tx_classifier.with_sender(relayer_account.into());
+    }
+    let mut rpc_service = RpcService::new(
         chain_id_opt.chain_id().id(),
         executor_proxy.clone(),
         sequencer_proxy,
         proposer_proxy,
         indexer_proxy,
-    );
+        view_executors,
+        gas_schedule_simulator,
+        tx_classifier,
+    )
+    .with_gas_estimate_margin_percentage(opt.gas_estimate_margin_percentage())
+    .with_object_change_notifier(object_changes);
+    if let Some(webhook_config) = opt.webhook_config()? {
+        info!(
+            "Notify the executed transactions to {} webhooks",
+            webhook_config.webhooks.len()
+        );
+        rpc_service = rpc_service.with_webhooks(WebhookDispatcher::new(&webhook_config)?);
+    }
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
+    if let Some(primary_url) = &opt.replica_of {
+        // The replica applies the transactions of the primary, it never sequences the transactions itself
+        rpc_service
+            .sequencer
+            .set_read_only(Some(format!(
+                "the node is a read replica of {}",
+                primary_url
+            )))
+            .await?;
+        let replica_syncer = ReplicaSyncer::new(
+            primary_url.clone(),
+            rpc_service.clone(),
+            rooch_store.clone(),
+            Duration::from_millis(
+                opt.replica_poll_interval_ms
+                    .unwrap_or(DEFAULT_REPLICA_POLL_INTERVAL_MILLISECONDS),
+            ),
+        )?;
+        replica_syncer.check_primary().await?;
+        tokio::spawn(replica_syncer.run());
+    } else {
+        sync_sequencer_key_rotation(
+            &rpc_service,
+            &executor_proxy,
+            &sequencer_keypair,
+            next_sequencer_keypair.as_ref(),
+            opt.sequencer_handover_window_ms
+                .unwrap_or(DEFAULT_HANDOVER_WINDOW_MILLISECONDS),
+        )
+        .await?;
+
+        if upgrade_framework {
+            if let Err(e) =
+                upgrade_framework_to(&rpc_service, &sequencer_keypair, framework_upgrade_action)
+                    .await
+            {
+                error!("Failed to upgrade the framework: {:?}", e);
+                rpc_service
+                    .sequencer
+                    .set_read_only(Some(format!(
+                        "failed to upgrade the on-chain framework to the embedded framework, {}",
+                        e
+                    )))
+                    .await?;
+            }
+        }
+
+        // The relayer of the node is a system sender as the sequencer, both on chain and in the sequencer
+        if let Some(relayer_keypair) = &server_opt.relayer_keypair {
+            let relayer_account: AccountAddress =
+                RoochAddress::from(&relayer_keypair.public()).into();
+            if relayer_account != AccountAddress::from(sequencer_account)
+                && !relayers.contains(&relayer_account)
+            {
+                if let Err(e) =
+                    register_relayer(&rpc_service, &sequencer_keypair, relayer_account).await
+                {
+                    warn!(
+                        "Failed to register the relayer {}: {:?}",
+                        relayer_account, e
+                    );
+                }
+            }
+        }
+
+        run_migrations(
+            &rpc_service,
+            &executor_proxy,
+            &rooch_store,
+            &sequencer_keypair,
+            &MigrationRegistry::builtin(),
+            &opt.migration_config(),
+        )
+        .await?;
+
+        // Recover the accepted transactions before serving the new ones, so the transactions keep the accepted order.
+        // The recovery is deferred until the sequencer is writable, the read only node or the paused chain rejects them.
+        if !recover_pending_transactions(&rpc_service).await? {
+            tokio::spawn(run_pending_tx_recovery(
+                rpc_service.clone(),
+                PENDING_TX_RECOVERY_INTERVAL,
+            ));
+        }
+    }
+
     let ethereum_relayer_config = opt.ethereum_relayer_config();
     let bitcoin_relayer_config = opt.bitcoin_relayer_config();
 
@@ -339,41 +730,53 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         timers.push(relayer_timer);
     }
 
-    let acl = match env::var("ACCESS_CONTROL_ALLOW_ORIGIN") {
-        Ok(value) => {
-            let allow_hosts = value
-                .split(',')
-                .map(HeaderValue::from_str)
-                .collect::<Result<Vec<_>, _>>()?;
-            AllowOrigin::list(allow_hosts)
-        }
-        _ => AllowOrigin::any(),
-    };
-    info!(?acl);
-
-    let cors: CorsLayer = CorsLayer::new()
-        // Allow `POST` when accessing the resource
-        .allow_methods([Method::POST])
-        // Allow requests from any origin
-        .allow_origin(acl)
-        .allow_headers([hyper::header::CONTENT_TYPE]);
+    info!(cors = ?config.cors);
+    let cors = build_cors_layer(&config.cors)?;
 
+    // Compress the response by the `Accept-Encoding` of the request, such as gzip and brotli
     let middleware = tower::ServiceBuilder::new()
         .layer(TraceLayer::new_for_http())
-        .layer(cors);
+        .layer(ExplorerLayer::new(config.explorer))
+        .layer(cors)
+        .layer(CompressionLayer::new())
+        // Shed the simulations and the heavy queries before the body is checked by the next layers
+        .layer(LoadShedLayer::new(
+            config.load_shed.clone(),
+            config.max_request_body_size,
+        ))
+        .layer(RpcNamespaceCorsLayer::new(
+            config.cors.clone(),
+            config.max_request_body_size,
+        ))
+        // Verify the authentication headers of the private methods, the layer is next to the server
+        // so the authenticated caller is visible to the method calls.
+        .layer(RpcAuthLayer::new(config.max_request_body_size));
+
+    // With TLS, the server listens on a local port behind the TLS proxy
+    let tls_acceptor = config.tls.as_ref().map(load_tls_acceptor).transpose()?;
+    let server_addr: SocketAddr = if tls_acceptor.is_some() {
+        "127.0.0.1:0".parse()?
+    } else {
+        addr
+    };
 
     // Build server
     let server = ServerBuilder::default()
+        .max_request_body_size(config.max_request_body_size)
         .set_logger(RpcLogger)
         .set_middleware(middleware)
-        .build(&addr)
+        .build(&server_addr)
         .await?;
+    let tls_proxy = match tls_acceptor {
+        Some(acceptor) => Some(start_tls_proxy(addr, server.local_addr()?, acceptor).await?),
+        None => None,
+    };
 
     let mut rpc_module_builder = RpcModuleBuilder::new();
-    rpc_module_builder.register_module(RoochServer::new(
-        rpc_service.clone(),
-        aggregate_service.clone(),
-    ))?;
+    rpc_module_builder.register_module(
+        RoochServer::new(rpc_service.clone(), aggregate_service.clone())
+            .with_committee_operator(committee_operator),
+    )?;
     rpc_module_builder.register_module(EthNetServer::new(chain_id_opt.chain_id()))?;
     rpc_module_builder.register_module(EthServer::new(
         chain_id_opt.chain_id(),
@@ -390,12 +793,20 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     let methods_names = rpc_module_builder.module.method_names().collect::<Vec<_>>();
     let handle = server.start(rpc_module_builder.module)?;
 
-    info!("JSON-RPC HTTP Server start listening {:?}", addr);
+    info!(
+        "JSON-RPC {} Server start listening {:?}",
+        if tls_proxy.is_some() { "HTTPS" } else { "HTTP" },
+        addr
+    );
     info!("Available JSON-RPC methods : {:?}", methods_names);
+    if config.explorer {
+        info!("Explorer-lite is served on {}", EXPLORER_PATH);
+    }
 
     Ok(ServerHandle {
         handle,
         timers,
+        tls_proxy,
         _store_config: store_config,
         _index_config: indexer_config,
     })
@@ -416,7 +827,7 @@ fn _build_rpc_api<M: Send + Sync + 'static>(mut rpc_module: RpcModule<M>) -> Rpc
     rpc_module
 }
 
-fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
+pub fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
     let (rooch_db_path, moveos_db_path) = (
         store_config.get_rooch_store_dir(),
         store_config.get_moveos_store_dir(),
@@ -444,7 +855,8 @@ fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)>
         rooch_store::StoreMeta::get_column_family_names().to_vec(),
         store_config.rocksdb_config(),
         None,
-    )?))?;
+    )?))?
+    .with_cold_store_dir(store_config.get_cold_store_dir())?;
     Ok((moveos_store, rooch_store))
 }
 
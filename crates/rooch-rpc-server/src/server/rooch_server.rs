@@ -2,27 +2,54 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::service::aggregate_service::AggregateService;
+use crate::service::object_subscription::object_change_stream;
+use crate::service::rpc_auth::ensure_authenticated;
 use crate::service::rpc_service::RpcService;
 use anyhow::Result;
 use jsonrpsee::{
     core::{async_trait, Error as JsonRpcError, RpcResult},
-    RpcModule,
+    types::SubscriptionResult,
+    RpcModule, SubscriptionSink,
 };
 use move_core_types::account_address::AccountAddress;
 use moveos_types::h256::H256;
-use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, EventView, IndexerEventView};
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::event::EventID;
+use moveos_types::transaction::MoveAction;
+use moveos_verifier::static_analysis::analyze_action;
+use rooch_proposer::committee::CommitteeOperator;
+use rooch_rpc_api::jsonrpc_types::event_view::{
+    EventFilterView, EventProofView, EventView, IndexerEventView,
+};
+use rooch_rpc_api::jsonrpc_types::fee_market_view::BaseGasPriceView;
+use rooch_rpc_api::jsonrpc_types::gas_estimate_view::GasEstimateView;
+use rooch_rpc_api::jsonrpc_types::gas_schedule_simulation_view::GasScheduleSimulationView;
+use rooch_rpc_api::jsonrpc_types::gas_statistics_view::FunctionGasStatisticsView;
+use rooch_rpc_api::jsonrpc_types::inclusion_receipt_view::TxInclusionReceiptView;
+use rooch_rpc_api::jsonrpc_types::module_abi_view::ModuleABIView;
+use rooch_rpc_api::jsonrpc_types::object_change_view::ObjectChangeFilterView;
+use rooch_rpc_api::jsonrpc_types::sequencer_batch_view::{
+    PartialSignatureView, SequencerBatchView,
+};
+use rooch_rpc_api::jsonrpc_types::source_verification_view::{
+    PackageSourceView, SourceVerificationView,
+};
+use rooch_rpc_api::jsonrpc_types::static_analysis_view::StaticAnalysisWarningView;
+use rooch_rpc_api::jsonrpc_types::transaction_validation_view::TransactionValidationResultView;
 use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::treasury_view::TreasuryStatusView;
 use rooch_rpc_api::jsonrpc_types::{
-    account_view::BalanceInfoView, GlobalStateFilterView, IndexerEventPageView,
-    IndexerGlobalStatePageView, IndexerGlobalStateView, IndexerTableChangeSetPageView,
-    IndexerTableChangeSetView, IndexerTableStatePageView, IndexerTableStateView, KeyStateKVView,
-    KeyStateView, StateOptions, StateSyncFilterView, TableStateFilterView,
+    account_view::{BalanceInfoView, SequenceNumberView},
+    GlobalStateFilterView, IndexerEventPageView, IndexerGlobalStatePageView,
+    IndexerGlobalStateView, IndexerTableChangeSetPageView, IndexerTableChangeSetView,
+    IndexerTableStatePageView, IndexerTableStateView, KeyStateKVView, KeyStateView, StateOptions,
+    StateSyncFilterView, TableStateFilterView,
 };
 use rooch_rpc_api::jsonrpc_types::{transaction_view::TransactionWithInfoView, EventOptions};
 use rooch_rpc_api::jsonrpc_types::{
-    AccessPathView, AccountAddressView, BalanceInfoPageView, EventPageView,
-    ExecuteTransactionResponseView, FunctionCallView, H256View, StateView, StatesPageView, StrView,
-    StructTagView, TransactionWithInfoPageView,
+    AccessPathView, AccountAddressView, AnnotatedMoveStructView, BalanceInfoPageView,
+    ChangeFeedPageView, EventPageView, ExecuteTransactionResponseView, FunctionCallView, H256View,
+    ModuleIdView, StateView, StatesPageView, StrView, StructTagView, TransactionWithInfoPageView,
 };
 use rooch_rpc_api::{api::rooch_api::RoochAPIServer, api::DEFAULT_RESULT_LIMIT};
 use rooch_rpc_api::{
@@ -33,17 +60,24 @@ use rooch_rpc_api::{
     api::{MAX_RESULT_LIMIT, MAX_RESULT_LIMIT_USIZE},
     jsonrpc_types::BytesView,
 };
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::transaction_fee::TransactionFeeModule;
+use rooch_types::framework::treasury::TreasuryModule;
 use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
 use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
 use rooch_types::{address::MultiChainAddress, multichain_id::RoochMultiChainID};
 use std::cmp::min;
-use tracing::info;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{debug, info};
 
 pub struct RoochServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    /// The committee operator run by the node, it signs the batches of the committee sequencer
+    committee_operator: Option<Arc<CommitteeOperator>>,
 }
 
 impl RoochServer {
@@ -51,8 +85,22 @@ impl RoochServer {
         Self {
             rpc_service,
             aggregate_service,
+            committee_operator: None,
         }
     }
+
+    pub fn with_committee_operator(
+        mut self,
+        committee_operator: Option<Arc<CommitteeOperator>>,
+    ) -> Self {
+        self.committee_operator = committee_operator;
+        self
+    }
+}
+
+/// The static analysis warnings of the modules published by the `action`
+fn analysis_warnings(action: &MoveAction) -> Vec<StaticAnalysisWarningView> {
+    analyze_action(action).into_iter().map(Into::into).collect()
 }
 
 #[async_trait]
@@ -63,27 +111,97 @@ impl RoochAPIServer for RoochServer {
     }
 
     async fn send_raw_transaction(&self, payload: BytesView) -> RpcResult<H256View> {
-        info!("send_raw_transaction payload: {:?}", payload);
+        info!("send_raw_transaction payload size: {}", payload.0.len());
         let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
-        info!("send_raw_transaction tx: {:?}", tx);
-
+        // Do not log the whole transaction, the payload of module publish transaction may be large.
         let hash = tx.tx_hash();
+        info!("send_raw_transaction tx hash: {:?}", hash);
         self.rpc_service
             .quene_tx(TypedTransaction::Rooch(tx))
             .await?;
         Ok(hash.into())
     }
 
+    async fn send_raw_transaction_with_receipt(
+        &self,
+        payload: BytesView,
+    ) -> RpcResult<TxInclusionReceiptView> {
+        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
+        info!(
+            "send_raw_transaction_with_receipt tx hash: {:?}",
+            tx.tx_hash()
+        );
+        let receipt = self
+            .rpc_service
+            .quene_tx(TypedTransaction::Rooch(tx))
+            .await?;
+        Ok(receipt.into())
+    }
+
     async fn execute_raw_transaction(
         &self,
         payload: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView> {
         let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
-        Ok(self
+        let warnings = analysis_warnings(tx.action());
+        Ok(ExecuteTransactionResponseView::from(
+            self.rpc_service
+                .execute_tx(TypedTransaction::Rooch(tx))
+                .await?,
+        )
+        .with_warnings(warnings))
+    }
+
+    async fn validate_transaction(
+        &self,
+        payload: BytesView,
+    ) -> RpcResult<TransactionValidationResultView> {
+        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
+        let hash = tx.tx_hash();
+        let result = match self
             .rpc_service
-            .execute_tx(TypedTransaction::Rooch(tx))
-            .await?
-            .into())
+            .validate_tx(TypedTransaction::Rooch(tx))
+            .await
+        {
+            Ok(()) => TransactionValidationResultView::accepted(hash),
+            Err(e) => {
+                info!("validate_transaction tx hash: {:?} rejected: {:?}", hash, e);
+                TransactionValidationResultView::rejected(hash, &e)
+            }
+        };
+        Ok(result)
+    }
+
+    async fn estimate_gas(
+        &self,
+        tx_data_bcs_hex: BytesView,
+        margin_percentage: Option<StrView<u64>>,
+    ) -> RpcResult<GasEstimateView> {
+        let tx_data = bcs::from_bytes::<RoochTransactionData>(&tx_data_bcs_hex.0)
+            .map_err(anyhow::Error::from)?;
+        let warnings = analysis_warnings(&tx_data.action);
+        Ok(GasEstimateView::from(
+            self.rpc_service
+                .estimate_gas(tx_data, margin_percentage.map(|margin| margin.0))
+                .await?,
+        )
+        .with_warnings(warnings))
+    }
+
+    async fn get_base_gas_price(&self) -> RpcResult<BaseGasPriceView> {
+        let transaction_fee_module = self
+            .rpc_service
+            .executor
+            .as_module_binding::<TransactionFeeModule>();
+        Ok(transaction_fee_module.fee_market_status()?.into())
+    }
+
+    async fn get_treasury_status(&self) -> RpcResult<TreasuryStatusView> {
+        let treasury_module = self
+            .rpc_service
+            .executor
+            .as_module_binding::<TreasuryModule>();
+        Ok(treasury_module.status()?.into())
     }
 
     async fn execute_view_function(
@@ -212,6 +330,30 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_event_proof(&self, event_id: EventID) -> RpcResult<Option<EventProofView>> {
+        let (event, proof) = match self.rpc_service.get_event_proof(event_id).await? {
+            Some(event_with_proof) => event_with_proof,
+            None => return Ok(None),
+        };
+        let execution_info = self
+            .rpc_service
+            .get_transaction_execution_infos_by_hash(vec![proof.tx_hash])
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                JsonRpcError::Custom(format!(
+                    "The execution info of the tx {:?} does not exist",
+                    proof.tx_hash
+                ))
+            })?;
+        Ok(Some(EventProofView::new(
+            event,
+            proof,
+            execution_info.event_root,
+        )))
+    }
+
     async fn get_transactions_by_hash(
         &self,
         tx_hashes: Vec<H256View>,
@@ -245,7 +387,10 @@ impl RoochAPIServer for RoochServer {
             .await?
             .map_or(0, |v| v.last_order);
 
-        let limit_of = limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT);
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT),
+            MAX_RESULT_LIMIT,
+        );
         let cursor = cursor.map(|v| v.0);
         let start = cursor.unwrap_or(0);
         let end = min(start + (limit_of + 1), last_sequencer_order + 1);
@@ -296,6 +441,47 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_change_feed(
+        &self,
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<u64>>,
+    ) -> RpcResult<ChangeFeedPageView> {
+        let last_sequencer_order = match self.rpc_service.get_sequencer_order().await? {
+            Some(order) => order.last_order,
+            None => {
+                return Ok(ChangeFeedPageView {
+                    data: vec![],
+                    next_cursor: cursor.map(|v| v.0),
+                    has_next_page: false,
+                })
+            }
+        };
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT),
+            MAX_RESULT_LIMIT,
+        );
+        let cursor = cursor.map(|v| v.0);
+        let start = cursor.map_or(0, |cursor| cursor + 1);
+        let end = min(start.saturating_add(limit_of), last_sequencer_order + 1);
+        let tx_orders: Vec<_> = (start..end).collect();
+        let has_next_page = end <= last_sequencer_order;
+        let next_cursor = tx_orders.last().copied().or(cursor);
+
+        let data = self
+            .rpc_service
+            .get_change_feed_entries(tx_orders)
+            .await?
+            .into_iter()
+            .map(|entry| Ok(StrView(bcs::to_bytes(&entry)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ChangeFeedPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn get_balance(
         &self,
         account_addr: AccountAddressView,
@@ -344,6 +530,159 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_sequence_number(
+        &self,
+        address: AccountAddressView,
+        include_pending: Option<bool>,
+    ) -> RpcResult<SequenceNumberView> {
+        let (committed, next) = self
+            .rpc_service
+            .get_sequence_number_with_pending(address.into(), include_pending.unwrap_or(false))
+            .await?;
+        Ok(SequenceNumberView::new(committed, next))
+    }
+
+    async fn get_free_gas_quota(&self, address: AccountAddressView) -> RpcResult<StrView<u64>> {
+        Ok(StrView(
+            self.rpc_service.get_free_gas_quota(address.into()).await?,
+        ))
+    }
+
+    async fn get_pending_sequence_numbers(
+        &self,
+        address: AccountAddressView,
+    ) -> RpcResult<Vec<StrView<u64>>> {
+        ensure_authenticated(address.0.into())?;
+        Ok(self
+            .rpc_service
+            .get_pending_sequence_numbers(address.into())
+            .into_iter()
+            .map(StrView)
+            .collect())
+    }
+
+    async fn resolve_name(&self, name: String) -> RpcResult<Option<AccountAddressView>> {
+        Ok(self
+            .aggregate_service
+            .resolve_name(name)
+            .await?
+            .map(Into::into))
+    }
+
+    async fn get_gas_statistics(
+        &self,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<Vec<FunctionGasStatisticsView>> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            MAX_RESULT_LIMIT_USIZE,
+        );
+        let mut data = self.rpc_service.get_gas_statistics().await?;
+        data.sort_by(|a, b| b.total_gas_used.cmp(&a.total_gas_used));
+        data.truncate(limit_of);
+        Ok(data.into_iter().map(Into::into).collect())
+    }
+
+    async fn simulate_gas_schedule_change(
+        &self,
+        new_entries: BTreeMap<String, StrView<u64>>,
+        sample_tx_hashes: Vec<H256View>,
+    ) -> RpcResult<GasScheduleSimulationView> {
+        let entries = new_entries
+            .into_iter()
+            .map(|(key, value)| (key, value.0))
+            .collect();
+        let tx_hashes = sample_tx_hashes.into_iter().map(Into::into).collect();
+        Ok(self
+            .rpc_service
+            .simulate_gas_schedule_change(entries, tx_hashes)
+            .await?
+            .into())
+    }
+
+    async fn get_module_abi(&self, module_id: ModuleIdView) -> RpcResult<Option<ModuleABIView>> {
+        Ok(self
+            .rpc_service
+            .get_module(module_id.0)
+            .await?
+            .map(|module| ModuleABIView::from(&module)))
+    }
+
+    async fn get_sequencer_batch(
+        &self,
+        batch_id: StrView<u128>,
+    ) -> RpcResult<Option<SequencerBatchView>> {
+        let batch = match self.rpc_service.get_sequencer_batch(batch_id.0).await? {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+        let transactions = self
+            .rpc_service
+            .get_transactions_by_hash(batch.tx_hashes.clone())
+            .await?
+            .into_iter()
+            .zip(batch.tx_hashes.iter())
+            .map(|(tx, tx_hash)| {
+                tx.map(|tx| tx.encode()).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The transaction {:?} of batch {} is not found",
+                        tx_hash,
+                        batch.batch_id
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(SequencerBatchView::new(batch, transactions)))
+    }
+
+    async fn sign_batch_commitment(
+        &self,
+        sequencer: AccountAddressView,
+        batch: SequencerBatchView,
+    ) -> RpcResult<Vec<PartialSignatureView>> {
+        let sequencer: RoochAddress = sequencer.0.into();
+        ensure_authenticated(sequencer)?;
+        let committee_operator = self.committee_operator.as_ref().ok_or_else(|| {
+            JsonRpcError::Custom("The node is not a sequencer committee operator".to_owned())
+        })?;
+        if sequencer != committee_operator.sequencer() {
+            return Err(JsonRpcError::Custom(format!(
+                "The account {} is not the committee sequencer",
+                sequencer
+            )));
+        }
+        let (batch, transactions) = batch.into_batch()?;
+        let partials = committee_operator.sign_batch(&batch, &transactions)?;
+        info!(
+            "sign_batch_commitment batch: {}, signing hash: {:?}",
+            batch.batch_id,
+            batch.signing_hash()
+        );
+        Ok(partials.into_iter().map(Into::into).collect())
+    }
+
+    async fn submit_package_source(
+        &self,
+        source: PackageSourceView,
+    ) -> RpcResult<SourceVerificationView> {
+        let verification = self
+            .rpc_service
+            .verify_package_source(source.into())
+            .await?;
+        Ok(verification.into())
+    }
+
+    async fn get_source_verification(
+        &self,
+        package_address: AccountAddressView,
+    ) -> RpcResult<Option<SourceVerificationView>> {
+        Ok(self
+            .rpc_service
+            .get_source_verification(package_address.0)
+            .await?
+            .map(Into::into))
+    }
+
     async fn query_transactions(
         &self,
         filter: TransactionFilterView,
@@ -405,6 +744,17 @@ impl RoochAPIServer for RoochServer {
 
         let has_next_page = data.len() > limit_of;
         data.truncate(limit_of);
+        let decoded_event_data = self
+            .rpc_service
+            .decode_events(
+                data.iter()
+                    .map(|e| (e.event_type.0.clone(), e.event_data.0.clone()))
+                    .collect(),
+            )
+            .await?;
+        for (event, decoded) in data.iter_mut().zip(decoded_event_data) {
+            event.decoded_event_data = decoded.map(AnnotatedMoveStructView::from);
+        }
         let next_cursor = data
             .last()
             .cloned()
@@ -545,6 +895,19 @@ impl RoochAPIServer for RoochServer {
             has_next_page,
         })
     }
+
+    fn subscribe_object_changes(
+        &self,
+        mut sink: SubscriptionSink,
+        filter: ObjectChangeFilterView,
+    ) -> SubscriptionResult {
+        let stream = object_change_stream(self.rpc_service.clone(), filter.into());
+        tokio::spawn(async move {
+            let closed = sink.pipe_from_stream(Box::pin(stream)).await;
+            debug!("The object change subscription is closed: {:?}", closed);
+        });
+        Ok(())
+    }
 }
 
 impl RoochRpcModule for RoochServer {
@@ -21,6 +21,7 @@ use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::framework::address_mapping::AddressMapping;
 use rooch_types::framework::coin::{CoinInfo, CoinModule};
 use rooch_types::framework::coin_store::CoinStore;
+use rooch_types::framework::name_service::NameService;
 use rooch_types::indexer::state::IndexerGlobalState;
 use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::{TransactionSequenceInfoMapping, TransactionWithInfo};
@@ -184,6 +185,14 @@ impl AggregateService {
         }
     }
 
+    pub async fn resolve_name(&self, name: String) -> Result<Option<AccountAddress>> {
+        let name_service_module = self
+            .rpc_service
+            .executor
+            .as_module_binding::<NameService>();
+        name_service_module.resolve(name.as_str())
+    }
+
     pub async fn get_transaction_with_info(
         &self,
         tx_hashes: Vec<H256>,
@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::scheduler::timer::TimerTick;
+use coerce::actor::Actor;
+use rooch_config::ColdStorageConfig;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::RoochStore;
+use tracing::{info, warn};
+
+/// The transactions moved to the cold store in a tick, the rest are moved in the next ticks
+pub const MAX_TIERED_TXS_PER_TICK: u64 = 100_000;
+
+/// The transactions of a cold store segment
+const SEGMENT_TXS: u64 = 10_000;
+
+#[derive(Clone)]
+pub struct ColdStorageTick {}
+
+impl Message for ColdStorageTick {
+    type Result = ();
+}
+
+impl TimerTick for ColdStorageTick {}
+
+/// Move the transactions and change sets out of the hot window to the cold store periodically,
+/// so the RocksDB of a long-lived node stays small. The progress is persisted in the store with every segment.
+pub struct ColdStorageActor {
+    config: ColdStorageConfig,
+    rooch_store: RoochStore,
+}
+
+impl ColdStorageActor {
+    pub fn new(config: ColdStorageConfig, rooch_store: RoochStore) -> Self {
+        Self {
+            config,
+            rooch_store,
+        }
+    }
+
+    fn tier(&self) -> Result<()> {
+        let Some(target) = self
+            .rooch_store
+            .get_sequencer_order()?
+            .and_then(|order| order.last_order.checked_sub(self.config.keep_txs))
+        else {
+            return Ok(());
+        };
+        let from_order = self.rooch_store.get_cold_store().get_next_order()?;
+        let end = target.min(from_order.saturating_add(MAX_TIERED_TXS_PER_TICK - 1));
+        let mut start = from_order;
+        let mut tiered = 0;
+        while start <= end {
+            let segment_end = end.min(start.saturating_add(SEGMENT_TXS - 1));
+            tiered += self.rooch_store.tier_transactions(start, segment_end)?;
+            start = segment_end + 1;
+        }
+        if start > from_order {
+            info!(
+                "Moved {} transactions from tx order {} to {} to the cold store, the tier target is {}",
+                tiered,
+                from_order,
+                start - 1,
+                target
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Actor for ColdStorageActor {}
+
+#[async_trait]
+impl Handler<ColdStorageTick> for ColdStorageActor {
+    async fn handle(&mut self, _message: ColdStorageTick, _ctx: &mut ActorContext) {
+        if let Err(err) = self.tier() {
+            warn!("Cold storage tick task error: {:?}", err);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::scheduler::timer::TimerTick;
+use coerce::actor::Actor;
+use moveos_store::event_store::EventStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::Event;
+use rooch_config::EventRetentionConfig;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::event_proof::EventProof;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// The transactions whose events are pruned in a tick, the rest are pruned in the next ticks
+pub const MAX_PRUNED_TXS_PER_TICK: u64 = 10_000;
+
+const PRUNE_BATCH_SIZE: u64 = 1_000;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const EVENT_PRUNER_STATE_FILE: &str = "event_pruner_state.json";
+
+/// The events of a transaction in the archive, the archive file is in the JSON lines format,
+/// one transaction per line in the tx order, so it can be replayed to rebuild an indexer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedTxEvents {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub events: Vec<Event>,
+}
+
+impl ArchivedTxEvents {
+    /// Build the proof of the archived event at `leaf_index` against the `event_root` of the transaction,
+    /// the archive keeps all the events of the transaction, so the pruned events are still provable.
+    pub fn event_proof(&self, leaf_index: u64) -> Result<EventProof> {
+        let event_hashes = self
+            .events
+            .iter()
+            .map(|event| event.hash())
+            .collect::<Vec<_>>();
+        EventProof::build(self.tx_hash, &event_hashes, leaf_index)
+    }
+}
+
+/// The storage of the pruned events, such as a local directory or an object storage bucket
+pub trait EventArchive: Send + Sync {
+    /// Archive the events of the transactions from `from_order` to `to_order`, the events are pruned
+    /// only after they are archived.
+    fn archive(&self, from_order: u64, to_order: u64, records: &[ArchivedTxEvents]) -> Result<()>;
+}
+
+/// Archive the pruned events to the files of a local directory, one file per pruned range
+pub struct LocalEventArchive {
+    dir: PathBuf,
+}
+
+impl LocalEventArchive {
+    pub fn new(dir: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    pub fn file_path(&self, from_order: u64, to_order: u64) -> PathBuf {
+        self.dir
+            .join(format!("events_{:020}_{:020}.jsonl", from_order, to_order))
+    }
+}
+
+impl EventArchive for LocalEventArchive {
+    fn archive(&self, from_order: u64, to_order: u64, records: &[ArchivedTxEvents]) -> Result<()> {
+        let path = self.file_path(from_order, to_order);
+        // Write to a temporary file first, so a crash never leaves a partial archive file
+        let tmp_path = path.with_extension("jsonl.tmp");
+        let mut writer = BufWriter::new(std::fs::File::create(&tmp_path)?);
+        for record in records {
+            serde_json::to_writer(&mut writer, record)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        std::fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+}
+
+/// Read the events of an archive file in the tx order
+pub fn read_event_archive(path: &Path) -> Result<Vec<ArchivedTxEvents>> {
+    let reader = BufReader::new(std::fs::File::open(path)?);
+    reader
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
+
+/// The progress of the pruner, it is persisted in the data dir
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct EventPrunerState {
+    /// The tx order of the first transaction whose events are not pruned
+    next_order: u64,
+    /// The last tx order observed at the seconds since the unix epoch, in the time order.
+    /// The transactions up to the order of a mark are sequenced before the time of the mark.
+    marks: VecDeque<(u64, u64)>,
+}
+
+/// The last tx order whose events are out of the retention windows, `None` if no event should be pruned.
+/// A transaction is kept if it is in any of the windows.
+fn prune_target(
+    last_order: u64,
+    now: u64,
+    keep_txs: Option<u64>,
+    keep_days: Option<u64>,
+    marks: &VecDeque<(u64, u64)>,
+) -> Option<u64> {
+    let by_txs = keep_txs.map(|keep_txs| last_order.checked_sub(keep_txs));
+    let by_days = keep_days.map(|keep_days| {
+        let cutoff = now.saturating_sub(keep_days.saturating_mul(SECONDS_PER_DAY));
+        marks
+            .iter()
+            .rev()
+            .find(|(timestamp, _)| *timestamp <= cutoff)
+            .map(|(_, tx_order)| *tx_order)
+    });
+    match (by_txs, by_days) {
+        (Some(by_txs), Some(by_days)) => by_txs.zip(by_days).map(|(a, b)| a.min(b)),
+        (Some(target), None) | (None, Some(target)) => target,
+        (None, None) => None,
+    }
+}
+
+/// Write the state to a temporary file and rename it, so a crash never leaves a partial state file
+fn save_state(path: &Path, state: &EventPrunerState) -> Result<()> {
+    let tmp_path = path.with_extension("json.tmp");
+    let mut file = std::fs::File::create(&tmp_path)?;
+    file.write_all(&serde_json::to_vec(state)?)?;
+    file.sync_all()?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct EventPruneTick {}
+
+impl Message for EventPruneTick {
+    type Result = ();
+}
+
+impl TimerTick for EventPruneTick {}
+
+/// Prune the events out of the retention windows periodically, the pruned events are archived first if an
+/// archive is configured. The events are the bulk of the event store, the event handles and the mappings
+/// between the transactions and the event ids are kept.
+pub struct EventPrunerActor {
+    config: EventRetentionConfig,
+    rooch_store: RoochStore,
+    moveos_store: MoveOSStore,
+    archive: Option<Box<dyn EventArchive>>,
+    state_path: PathBuf,
+    state: EventPrunerState,
+}
+
+impl EventPrunerActor {
+    pub fn new(
+        config: EventRetentionConfig,
+        rooch_store: RoochStore,
+        moveos_store: MoveOSStore,
+        data_dir: &Path,
+    ) -> Result<Self> {
+        let archive = match &config.archive_dir {
+            Some(dir) => {
+                Some(Box::new(LocalEventArchive::new(dir.clone())?) as Box<dyn EventArchive>)
+            }
+            None => None,
+        };
+        let state_path = data_dir.join(EVENT_PRUNER_STATE_FILE);
+        let state = match std::fs::read(&state_path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(_) => EventPrunerState::default(),
+        };
+        Ok(Self {
+            config,
+            rooch_store,
+            moveos_store,
+            archive,
+            state_path,
+            state,
+        })
+    }
+
+    /// Replace the archive of the pruned events, such as an object storage bucket
+    pub fn with_archive(mut self, archive: Box<dyn EventArchive>) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
+    fn record_mark(&mut self, last_order: u64, now: u64) {
+        self.state.marks.push_back((now, last_order));
+        match self.config.keep_days {
+            Some(keep_days) => {
+                // Only the newest mark out of the window is needed
+                let cutoff = now.saturating_sub(keep_days.saturating_mul(SECONDS_PER_DAY));
+                while self.state.marks.len() > 1 && self.state.marks[1].0 <= cutoff {
+                    self.state.marks.pop_front();
+                }
+            }
+            None => self.state.marks.clear(),
+        }
+    }
+
+    fn prune(&mut self) -> Result<()> {
+        let Some(last_order) = self
+            .rooch_store
+            .get_sequencer_order()?
+            .map(|order| order.last_order)
+        else {
+            return Ok(());
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        self.record_mark(last_order, now);
+        let target = prune_target(
+            last_order,
+            now,
+            self.config.keep_txs,
+            self.config.keep_days,
+            &self.state.marks,
+        );
+        if let Some(target) = target {
+            let from_order = self.state.next_order;
+            let end = target.min(from_order.saturating_add(MAX_PRUNED_TXS_PER_TICK - 1));
+            let mut start = from_order;
+            while start <= end {
+                let batch_end = end.min(start.saturating_add(PRUNE_BATCH_SIZE - 1));
+                self.prune_range(start, batch_end)?;
+                self.state.next_order = batch_end + 1;
+                start = batch_end + 1;
+            }
+            if self.state.next_order > from_order {
+                info!(
+                    "Pruned the events from tx order {} to {}, the prune target is {}",
+                    from_order,
+                    self.state.next_order - 1,
+                    target
+                );
+            }
+        }
+        save_state(&self.state_path, &self.state)
+    }
+
+    fn prune_range(&self, from_order: u64, to_order: u64) -> Result<()> {
+        let mappings = self
+            .rooch_store
+            .get_tx_sequence_info_mapping_by_order((from_order..=to_order).collect())?;
+        let mut records = vec![];
+        let mut pruned_event_ids = vec![];
+        for mapping in mappings.into_iter().flatten() {
+            let event_ids = self
+                .moveos_store
+                .get_tx_event_ids(mapping.tx_hash)?
+                .unwrap_or_default();
+            if event_ids.is_empty() {
+                continue;
+            }
+            let events: Vec<Event> = self
+                .moveos_store
+                .multi_get_events(event_ids.clone())?
+                .into_iter()
+                .flatten()
+                .collect();
+            if events.is_empty() {
+                continue;
+            }
+            pruned_event_ids.extend(event_ids);
+            records.push(ArchivedTxEvents {
+                tx_order: mapping.tx_order,
+                tx_hash: mapping.tx_hash,
+                events,
+            });
+        }
+        if records.is_empty() {
+            return Ok(());
+        }
+        if let Some(archive) = &self.archive {
+            archive.archive(from_order, to_order, &records)?;
+        }
+        self.moveos_store.delete_events(pruned_event_ids)
+    }
+}
+
+impl Actor for EventPrunerActor {}
+
+#[async_trait]
+impl Handler<EventPruneTick> for EventPrunerActor {
+    async fn handle(&mut self, _message: EventPruneTick, _ctx: &mut ActorContext) {
+        if let Err(err) = self.prune() {
+            warn!("Event prune tick task error: {:?}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::inmemory::InMemoryAccumulator;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::ident_str;
+    use move_core_types::language_storage::StructTag;
+    use moveos_types::moveos_std::event::EventID;
+    use moveos_types::moveos_std::object::ObjectID;
+
+    #[test]
+    fn test_prune_target() {
+        let t0 = 100 * SECONDS_PER_DAY;
+        let marks: VecDeque<(u64, u64)> = vec![
+            (t0, 10),
+            (t0 + SECONDS_PER_DAY, 20),
+            (t0 + 2 * SECONDS_PER_DAY, 30),
+        ]
+        .into();
+        let now = t0 + 2 * SECONDS_PER_DAY;
+
+        assert_eq!(prune_target(30, now, Some(5), None, &marks), Some(25));
+        assert_eq!(prune_target(30, now, Some(31), None, &marks), None);
+        assert_eq!(prune_target(30, now, Some(0), None, &marks), Some(30));
+        assert_eq!(prune_target(30, now, None, Some(1), &marks), Some(20));
+        assert_eq!(prune_target(30, now, None, Some(3), &marks), None);
+        // The transaction in any window is kept
+        assert_eq!(prune_target(30, now, Some(5), Some(1), &marks), Some(20));
+        assert_eq!(prune_target(30, now, Some(15), Some(1), &marks), Some(15));
+        assert_eq!(prune_target(30, now, Some(31), Some(1), &marks), None);
+    }
+
+    #[test]
+    fn test_save_state() {
+        let dir = moveos_config::temp_dir();
+        let path = dir.path().join(EVENT_PRUNER_STATE_FILE);
+        let state = EventPrunerState {
+            next_order: 10,
+            marks: vec![(100, 9)].into(),
+        };
+        save_state(&path, &state).unwrap();
+        let loaded: EventPrunerState =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(loaded, state);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn test_archived_event_proof() {
+        let event_type = StructTag {
+            address: AccountAddress::ONE,
+            module: ident_str!("test").to_owned(),
+            name: ident_str!("TestEvent").to_owned(),
+            type_params: vec![],
+        };
+        let events = (0..3u64)
+            .map(|i| {
+                Event::new(
+                    EventID::new(ObjectID::from(AccountAddress::ONE), i),
+                    event_type.clone(),
+                    vec![i as u8; 4],
+                    i,
+                )
+            })
+            .collect::<Vec<_>>();
+        let event_hashes = events.iter().map(|event| event.hash()).collect::<Vec<_>>();
+        let event_root = InMemoryAccumulator::from_leaves(&event_hashes).root_hash();
+        let record = ArchivedTxEvents {
+            tx_order: 1,
+            tx_hash: H256::random(),
+            events: events.clone(),
+        };
+        for (index, event) in events.iter().enumerate() {
+            let proof = record.event_proof(index as u64).unwrap();
+            proof.verify(event_root, event).unwrap();
+        }
+        assert!(record.event_proof(3).is_err());
+    }
+
+    #[test]
+    fn test_local_event_archive() {
+        let dir = moveos_config::temp_dir();
+        let archive = LocalEventArchive::new(dir.path().to_path_buf()).unwrap();
+        let records = vec![ArchivedTxEvents {
+            tx_order: 1,
+            tx_hash: H256::random(),
+            events: vec![],
+        }];
+        archive.archive(0, 1, &records).unwrap();
+        assert_eq!(
+            read_event_archive(&archive.file_path(0, 1)).unwrap(),
+            records
+        );
+    }
+}
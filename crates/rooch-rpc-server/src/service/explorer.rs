@@ -0,0 +1,102 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::future::BoxFuture;
+use hyper::header::{CACHE_CONTROL, CONTENT_TYPE};
+use hyper::{Body, Method, Request, Response};
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// The path of the explorer page served by the node
+pub const EXPLORER_PATH: &str = "/explorer";
+
+/// The embedded explorer page, it renders the recent blocks, transactions and account lookups
+/// with the node's own JSON-RPC methods.
+const EXPLORER_HTML: &str = include_str!("explorer.html");
+
+/// Serve the explorer-lite page on `GET /explorer`, for local dev networks and devnet debugging
+/// without deploying a separate explorer. The other requests are passed to the JSON-RPC server.
+#[derive(Debug, Clone)]
+pub struct ExplorerLayer {
+    enabled: bool,
+}
+
+impl ExplorerLayer {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<S> Layer<S> for ExplorerLayer {
+    type Service = ExplorerService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ExplorerService {
+            inner,
+            enabled: self.enabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplorerService<S> {
+    inner: S,
+    enabled: bool,
+}
+
+impl<S> Service<Request<Body>> for ExplorerService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        if self.enabled && is_explorer_request(&request) {
+            return Box::pin(async move { Ok(explorer_response()) });
+        }
+        // The inner service is ready, take it and leave the clone for the next call
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(inner.call(request))
+    }
+}
+
+fn is_explorer_request(request: &Request<Body>) -> bool {
+    request.method() == Method::GET && request.uri().path().trim_end_matches('/') == EXPLORER_PATH
+}
+
+fn explorer_response() -> Response<Body> {
+    Response::builder()
+        .header(CONTENT_TYPE, "text/html; charset=utf-8")
+        .header(CACHE_CONTROL, "no-cache")
+        .body(Body::from(EXPLORER_HTML))
+        .expect("The explorer response should be valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_explorer_request() {
+        let request = |method: Method, uri: &str| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap()
+        };
+        assert!(is_explorer_request(&request(Method::GET, "/explorer")));
+        assert!(is_explorer_request(&request(Method::GET, "/explorer/")));
+        assert!(!is_explorer_request(&request(Method::POST, "/explorer")));
+        assert!(!is_explorer_request(&request(Method::GET, "/")));
+    }
+}
@@ -0,0 +1,446 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_auth::{call_method, error_response, read_body};
+use futures::future::BoxFuture;
+use hyper::header::{HeaderValue, RETRY_AFTER};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use rooch_config::server_config::LoadShedConfig;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tower::{Layer, Service};
+
+/// The JSON-RPC error code of the call rejected because the server is overloaded
+pub const OVERLOADED_ERROR_CODE: i32 = -32005;
+
+/// The JSON-RPC error code of the request whose body can not be read
+const PARSE_ERROR_CODE: i32 = -32700;
+
+/// The latency of a method is stale if the method is not called in the window, a stale latency does not saturate the server
+const LATENCY_WINDOW: Duration = Duration::from_secs(10);
+
+/// The methods submitting the transactions, they are never rejected
+const CRITICAL_METHODS: [&str; 5] = [
+    "rooch_sendRawTransaction",
+    "rooch_sendRawTransactionWithReceipt",
+    "rooch_executeRawTransaction",
+    "eth_sendRawTransaction",
+    "eth_sendTransaction",
+];
+
+/// The simulations and the heavy queries, they are rejected first when the server is saturated
+const SHEDDABLE_METHODS: [&str; 17] = [
+    "rooch_executeViewFunction",
+    "rooch_estimateGas",
+    "rooch_validateTransaction",
+    "rooch_simulateGasScheduleChange",
+    "rooch_listStates",
+    "rooch_getChangeFeed",
+    "rooch_getGasStatistics",
+    "rooch_queryTransactions",
+    "rooch_queryEvents",
+    "rooch_queryGlobalStates",
+    "rooch_queryTableStates",
+    "rooch_syncStates",
+    "rooch_submitPackageSource",
+    "eth_estimateGas",
+    "eth_feeHistory",
+    "btc_queryUTXOs",
+    "btc_queryInscriptions",
+];
+
+/// The priority of a call when the server is saturated, in the ascending order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CallPriority {
+    /// Rejected when the server is saturated
+    Sheddable,
+    /// Rejected when the calls in flight reach the limit
+    Normal,
+    /// Never rejected
+    Critical,
+}
+
+pub fn method_priority(method: &str) -> CallPriority {
+    if CRITICAL_METHODS.contains(&method) {
+        CallPriority::Critical
+    } else if SHEDDABLE_METHODS.contains(&method) {
+        CallPriority::Sheddable
+    } else {
+        CallPriority::Normal
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct MethodLatency {
+    /// The moving average of the latency in milliseconds
+    average_ms: u64,
+    updated_at: Instant,
+}
+
+/// The calls in flight and the latency of the methods
+#[derive(Debug, Default)]
+struct LoadTracker {
+    in_flight: usize,
+    latencies: HashMap<String, MethodLatency>,
+}
+
+impl LoadTracker {
+    fn is_saturated(&self, config: &LoadShedConfig, now: Instant) -> bool {
+        self.in_flight >= config.max_in_flight
+            || self.latencies.iter().any(|(method, latency)| {
+                now.duration_since(latency.updated_at) < LATENCY_WINDOW
+                    && latency.average_ms > config.method_latency_budget_ms(method)
+            })
+    }
+
+    /// Admit the call of the `priority`, or return false if it should be rejected
+    fn admit(&mut self, config: &LoadShedConfig, priority: CallPriority, now: Instant) -> bool {
+        let admitted = match priority {
+            CallPriority::Critical => true,
+            CallPriority::Normal => self.in_flight < config.max_in_flight,
+            CallPriority::Sheddable => !self.is_saturated(config, now),
+        };
+        if admitted {
+            self.in_flight += 1;
+        }
+        admitted
+    }
+
+    /// The latency of the critical methods is not tracked, they wait for the sequencing and the execution,
+    /// so a slow submission does not mean the server is saturated by the queries
+    fn complete(&mut self, method: Option<&str>, elapsed: Duration, now: Instant) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        if let Some(method) =
+            method.filter(|method| method_priority(method) != CallPriority::Critical)
+        {
+            let elapsed_ms = elapsed.as_millis() as u64;
+            let latency = self
+                .latencies
+                .entry(method.to_owned())
+                .or_insert(MethodLatency {
+                    average_ms: elapsed_ms,
+                    updated_at: now,
+                });
+            latency.average_ms = (latency.average_ms * 7 + elapsed_ms) / 8;
+            latency.updated_at = now;
+        }
+    }
+}
+
+/// Record the completion of an admitted call when it is dropped, including the call cancelled by the client
+struct InFlightGuard {
+    tracker: Arc<Mutex<LoadTracker>>,
+    method: Option<String>,
+    started_at: Instant,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let now = Instant::now();
+        self.tracker
+            .lock()
+            .expect("load tracker lock should not be poisoned")
+            .complete(self.method.as_deref(), now - self.started_at, now);
+    }
+}
+
+/// Reject the cheapest-to-reject calls with the HTTP status 429 when the server is saturated, to protect
+/// the transaction submission and the block production from the simulations and the heavy queries.
+/// All the calls are passed through if the config is not set.
+#[derive(Debug, Clone)]
+pub struct LoadShedLayer {
+    config: Option<Arc<LoadShedConfig>>,
+    tracker: Arc<Mutex<LoadTracker>>,
+    max_request_body_size: u32,
+}
+
+impl LoadShedLayer {
+    pub fn new(config: Option<LoadShedConfig>, max_request_body_size: u32) -> Self {
+        Self {
+            config: config.map(Arc::new),
+            tracker: Arc::new(Mutex::new(LoadTracker::default())),
+            max_request_body_size,
+        }
+    }
+}
+
+impl<S> Layer<S> for LoadShedLayer {
+    type Service = LoadShedService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LoadShedService {
+            inner,
+            config: self.config.clone(),
+            tracker: self.tracker.clone(),
+            max_request_body_size: self.max_request_body_size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadShedService<S> {
+    inner: S,
+    config: Option<Arc<LoadShedConfig>>,
+    tracker: Arc<Mutex<LoadTracker>>,
+    max_request_body_size: u32,
+}
+
+impl<S> Service<Request<Body>> for LoadShedService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let config = match &self.config {
+            Some(config) if request.method() == Method::POST => config.clone(),
+            _ => return Box::pin(inner.call(request)),
+        };
+        let tracker = self.tracker.clone();
+        let max_request_body_size = self.max_request_body_size;
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let bytes = match read_body(body, max_request_body_size).await {
+                Ok(bytes) => bytes,
+                Err(e) => return Ok(error_response(Value::Null, PARSE_ERROR_CODE, e.to_string())),
+            };
+            // The latency is tracked by the method of a single call, a batch only counts in flight.
+            // A batch takes the lowest priority of its calls, so a submission can not shield the heavy queries.
+            let (id, method, priority) = match serde_json::from_slice::<Value>(&bytes) {
+                Ok(Value::Array(batch)) => {
+                    let priority = batch
+                        .iter()
+                        .filter_map(call_method)
+                        .map(method_priority)
+                        .min()
+                        .unwrap_or(CallPriority::Normal);
+                    (Value::Null, None, priority)
+                }
+                Ok(call) => {
+                    let method = call_method(&call).map(ToOwned::to_owned);
+                    let priority = method
+                        .as_deref()
+                        .map_or(CallPriority::Normal, method_priority);
+                    let id = call.get("id").cloned().unwrap_or(Value::Null);
+                    (id, method, priority)
+                }
+                Err(_) => (Value::Null, None, CallPriority::Normal),
+            };
+            let started_at = Instant::now();
+            let admitted = tracker
+                .lock()
+                .expect("load tracker lock should not be poisoned")
+                .admit(&config, priority, started_at);
+            if !admitted {
+                return Ok(overloaded_response(id, method.as_deref()));
+            }
+            let _guard = InFlightGuard {
+                tracker,
+                method,
+                started_at,
+            };
+            inner
+                .call(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        })
+    }
+}
+
+fn overloaded_response(id: Value, method: Option<&str>) -> Response<Body> {
+    let mut response = error_response(
+        id,
+        OVERLOADED_ERROR_CODE,
+        format!(
+            "The server is overloaded, the call {} is rejected, please retry later",
+            method.unwrap_or("batch")
+        ),
+    );
+    *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from_static("1"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::Notify;
+    use tower::{service_fn, ServiceExt};
+
+    #[test]
+    fn test_load_shed_admit() {
+        let config = LoadShedConfig {
+            max_in_flight: 2,
+            latency_budget_ms: 100,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        let mut tracker = LoadTracker::default();
+        assert!(tracker.admit(&config, CallPriority::Sheddable, now));
+        assert!(tracker.admit(&config, CallPriority::Normal, now));
+        // The calls in flight reach the limit
+        assert!(!tracker.admit(&config, CallPriority::Sheddable, now));
+        assert!(!tracker.admit(&config, CallPriority::Normal, now));
+        assert!(tracker.admit(&config, CallPriority::Critical, now));
+
+        tracker.complete(None, Duration::ZERO, now);
+        tracker.complete(None, Duration::ZERO, now);
+        tracker.complete(Some("rooch_getStates"), Duration::from_millis(1000), now);
+        assert_eq!(tracker.in_flight, 0);
+        // A method exceeds its latency budget, only the sheddable calls are rejected
+        assert!(!tracker.admit(&config, CallPriority::Sheddable, now));
+        assert!(tracker.admit(&config, CallPriority::Normal, now));
+        // The latency is stale out of the window
+        assert!(tracker.admit(&config, CallPriority::Sheddable, now + LATENCY_WINDOW));
+    }
+
+    #[test]
+    fn test_load_shed_critical_latency_not_tracked() {
+        let config = LoadShedConfig {
+            max_in_flight: 2,
+            latency_budget_ms: 100,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        let mut tracker = LoadTracker::default();
+        assert!(tracker.admit(&config, CallPriority::Critical, now));
+        tracker.complete(
+            Some("rooch_executeRawTransaction"),
+            Duration::from_millis(5000),
+            now,
+        );
+        assert!(tracker.latencies.is_empty());
+        assert!(tracker.admit(&config, CallPriority::Sheddable, now));
+    }
+
+    fn post(body: impl Into<Body>) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .body(body.into())
+            .unwrap()
+    }
+
+    async fn call_json<S>(service: &S, body: impl Into<Body>) -> (StatusCode, Value)
+    where
+        S: Service<Request<Body>, Response = Response<Body>> + Clone,
+        S::Error: std::fmt::Debug,
+    {
+        let response = service.clone().oneshot(post(body)).await.unwrap();
+        let status = response.status();
+        let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let value = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap()
+        };
+        (status, value)
+    }
+
+    #[tokio::test]
+    async fn test_load_shed_service() {
+        let config = LoadShedConfig {
+            max_in_flight: 1,
+            latency_budget_ms: 100,
+            ..Default::default()
+        };
+        let layer = LoadShedLayer::new(Some(config), 128);
+        // The inner service holds the `rooch_getStates` calls until they are released
+        let release = Arc::new(Notify::new());
+        let inner = service_fn({
+            let release = release.clone();
+            move |request: Request<Body>| {
+                let release = release.clone();
+                async move {
+                    let bytes = hyper::body::to_bytes(request.into_body()).await?;
+                    if String::from_utf8_lossy(&bytes).contains("rooch_getStates") {
+                        release.notified().await;
+                    }
+                    Ok::<_, hyper::Error>(Response::new(Body::empty()))
+                }
+            }
+        });
+        let service = layer.layer(inner);
+
+        let held = {
+            let service = service.clone();
+            tokio::spawn(async move {
+                call_json(
+                    &service,
+                    r#"{"jsonrpc":"2.0","id":1,"method":"rooch_getStates","params":[]}"#,
+                )
+                .await
+            })
+        };
+        while layer.tracker.lock().unwrap().in_flight == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        // The calls in flight reach the limit, the sheddable call is rejected
+        let (status, value) = call_json(
+            &service,
+            r#"{"jsonrpc":"2.0","id":2,"method":"rooch_queryEvents","params":[]}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(value["error"]["code"], OVERLOADED_ERROR_CODE);
+        assert_eq!(value["id"], 2);
+
+        // A submission does not shield the heavy query in the same batch
+        let (status, _) = call_json(
+            &service,
+            r#"[{"jsonrpc":"2.0","id":3,"method":"rooch_sendRawTransaction","params":[]},{"jsonrpc":"2.0","id":4,"method":"rooch_queryEvents","params":[]}]"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+        // The submission alone is never rejected
+        let (status, _) = call_json(
+            &service,
+            r#"{"jsonrpc":"2.0","id":5,"method":"rooch_sendRawTransaction","params":[]}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        // The body exceeding the max size is a parse error, not an overload
+        let (status, value) = call_json(&service, vec![b' '; 256]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(value["error"]["code"], PARSE_ERROR_CODE);
+
+        release.notify_one();
+        held.await.unwrap();
+        assert_eq!(layer.tracker.lock().unwrap().in_flight, 0);
+        let (status, _) = call_json(
+            &service,
+            r#"{"jsonrpc":"2.0","id":6,"method":"rooch_queryEvents","params":[]}"#,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[test]
+    fn test_method_priority() {
+        assert_eq!(
+            method_priority("rooch_sendRawTransaction"),
+            CallPriority::Critical
+        );
+        assert_eq!(
+            method_priority("rooch_queryEvents"),
+            CallPriority::Sheddable
+        );
+        assert_eq!(method_priority("rooch_getStates"), CallPriority::Normal);
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use anyhow::{bail, Result};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::access_path::AccessPath;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::account_storage::AccountStorage;
+use moveos_types::moveos_std::context::GLOBAL_OBJECT_STORAGE_HANDLE;
+use moveos_types::state::{MoveStructType, State};
+use moveos_types::transaction::MoveAction;
+use rooch_config::MigrationConfig;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_relayer::TxSubmiter;
+use rooch_store::migration_store::MigrationStore;
+use rooch_store::RoochStore;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::chain_pause::ChainPauseModule;
+use rooch_types::framework::migration::MigrationModule;
+use rooch_types::migration::{
+    Migration, MigrationCheckpoint, MigrationItem, MigrationRegistry, MigrationReport,
+    MigrationTarget,
+};
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::TypedTransaction;
+use tracing::{info, warn};
+
+/// Run the registered state migrations whose framework version is reached, after a framework upgrade.
+/// The global objects are scanned in batches, the states of the migration target in every batch are migrated
+/// by a transaction signed by the sequencer, and the progress is checkpointed after every batch,
+/// so an interrupted migration resumes from the checkpoint. The completed migration is recorded on-chain.
+/// In the dry run, the migration transactions are executed without applying, and only the reports are returned.
+pub async fn run_migrations(
+    rpc_service: &RpcService,
+    executor: &ExecutorProxy,
+    rooch_store: &RoochStore,
+    sequencer_keypair: &RoochKeyPair,
+    registry: &MigrationRegistry,
+    config: &MigrationConfig,
+) -> Result<Vec<MigrationReport>> {
+    if registry.is_empty() {
+        return Ok(vec![]);
+    }
+    let framework_version = executor
+        .as_module_binding::<MigrationModule>()
+        .framework_version()?;
+    let mut reports = vec![];
+    for migration in registry.migrations_at(framework_version) {
+        if executor
+            .as_module_binding::<MigrationModule>()
+            .is_completed(&migration.name)?
+        {
+            continue;
+        }
+        let report = run_migration(
+            rpc_service,
+            executor,
+            rooch_store,
+            sequencer_keypair,
+            migration,
+            config,
+        )
+        .await?;
+        info!("The state migration report: {:?}", report);
+        reports.push(report);
+    }
+    Ok(reports)
+}
+
+/// Upgrade the on-chain framework by the `action` signed by the sequencer, the sequencer must own the UpgradeCap.
+/// The state migrations of the new framework version run after it.
+pub async fn upgrade_framework_to(
+    rpc_service: &RpcService,
+    sequencer_keypair: &RoochKeyPair,
+    action: MoveAction,
+) -> Result<()> {
+    let tx = sign_system_tx(rpc_service, sequencer_keypair, action).await?;
+    let result = rpc_service.execute_tx(TypedTransaction::Rooch(tx)).await?;
+    if result.execution_info.status != KeptVMStatus::Executed {
+        bail!(
+            "The framework upgrade failed, status: {:?}",
+            result.execution_info.status
+        );
+    }
+    info!(
+        "The framework is upgraded by the tx {:?}",
+        result.execution_info.tx_hash
+    );
+    Ok(())
+}
+
+/// Register the relayer of the node as a system sender, so its transactions are accepted when the chain is paused
+pub async fn register_relayer(
+    rpc_service: &RpcService,
+    sequencer_keypair: &RoochKeyPair,
+    relayer: AccountAddress,
+) -> Result<()> {
+    let action = ChainPauseModule::create_add_relayer_action(relayer);
+    let tx = sign_system_tx(rpc_service, sequencer_keypair, action).await?;
+    let result = rpc_service.execute_tx(TypedTransaction::Rooch(tx)).await?;
+    if result.execution_info.status != KeptVMStatus::Executed {
+        bail!(
+            "The relayer registration failed, status: {:?}",
+            result.execution_info.status
+        );
+    }
+    info!("The relayer {} is registered as a system sender", relayer);
+    Ok(())
+}
+
+async fn run_migration(
+    rpc_service: &RpcService,
+    executor: &ExecutorProxy,
+    rooch_store: &RoochStore,
+    sequencer_keypair: &RoochKeyPair,
+    migration: &Migration,
+    config: &MigrationConfig,
+) -> Result<MigrationReport> {
+    let mut checkpoint = if config.dry_run {
+        MigrationCheckpoint::default()
+    } else {
+        rooch_store
+            .get_migration_checkpoint(&migration.name)?
+            .unwrap_or_default()
+    };
+    let mut report = MigrationReport {
+        name: migration.name.clone(),
+        dry_run: config.dry_run,
+        ..Default::default()
+    };
+    info!(
+        "Run the state migration {} of the framework version {}, checkpoint: {:?}",
+        migration.name, migration.framework_version, checkpoint
+    );
+
+    while !checkpoint.completed {
+        let states = rpc_service
+            .list_states(
+                AccessPath::table_without_keys(GLOBAL_OBJECT_STORAGE_HANDLE),
+                checkpoint.cursor.clone(),
+                config.batch_size,
+            )
+            .await?;
+        let scanned = states.len();
+        let next_cursor = states.last().map(|(key, _state)| key.clone());
+        let items = collect_migration_items(
+            rpc_service,
+            &migration.target,
+            states.into_iter().map(|(_key, state)| state),
+        )
+        .await?;
+
+        if let Some(action) = migration.function.migrate(executor, &items)? {
+            let tx = sign_system_tx(rpc_service, sequencer_keypair, action).await?;
+            if config.dry_run {
+                let verified_tx = executor
+                    .validate_transaction(TypedTransaction::Rooch(tx))
+                    .await?;
+                let output = executor.dry_run_transaction(verified_tx).await?;
+                report.gas_used += output.gas_used;
+                if output.status != KeptVMStatus::Executed {
+                    report.failed_batches.push(format!(
+                        "The batch after cursor {:?} failed, status: {:?}",
+                        checkpoint.cursor.as_ref().map(hex::encode),
+                        output.status
+                    ));
+                }
+            } else {
+                let result = rpc_service.execute_tx(TypedTransaction::Rooch(tx)).await?;
+                if result.execution_info.status != KeptVMStatus::Executed {
+                    bail!(
+                        "The state migration {} failed at the checkpoint {:?}, status: {:?}",
+                        migration.name,
+                        checkpoint,
+                        result.execution_info.status
+                    );
+                }
+                report.gas_used += result.execution_info.gas_used;
+            }
+            report.batches += 1;
+            report.migrated += items.len() as u64;
+            checkpoint.migrated += items.len() as u64;
+        }
+        report.scanned += scanned as u64;
+        checkpoint.scanned += scanned as u64;
+        checkpoint.completed = scanned < config.batch_size;
+        if next_cursor.is_some() {
+            checkpoint.cursor = next_cursor;
+        }
+        if !config.dry_run && !checkpoint.completed {
+            rooch_store.save_migration_checkpoint(&migration.name, checkpoint.clone())?;
+        }
+    }
+
+    if !config.dry_run {
+        let action =
+            MigrationModule::create_complete_migration_action(&migration.name, checkpoint.migrated);
+        let tx = sign_system_tx(rpc_service, sequencer_keypair, action).await?;
+        let result = rpc_service.execute_tx(TypedTransaction::Rooch(tx)).await?;
+        if result.execution_info.status != KeptVMStatus::Executed {
+            warn!(
+                "Failed to record the completed state migration {} on-chain, status: {:?}",
+                migration.name, result.execution_info.status
+            );
+        }
+        rooch_store.save_migration_checkpoint(&migration.name, checkpoint)?;
+    }
+    Ok(report)
+}
+
+/// Collect the states of the migration target in the scanned global objects
+async fn collect_migration_items(
+    rpc_service: &RpcService,
+    target: &MigrationTarget,
+    states: impl Iterator<Item = State>,
+) -> Result<Vec<MigrationItem>> {
+    let mut items = vec![];
+    for state in states {
+        let raw_object = match state.as_raw_object() {
+            Ok(raw_object) => raw_object,
+            Err(_) => continue,
+        };
+        match target {
+            MigrationTarget::Object(_) => {
+                if target.matches(&raw_object.value.struct_tag) {
+                    items.push(MigrationItem {
+                        id: raw_object.id,
+                        state,
+                    });
+                }
+            }
+            MigrationTarget::Resource(resource_type) => {
+                if raw_object.value.struct_tag != AccountStorage::struct_tag() {
+                    continue;
+                }
+                let account = raw_object.id.into();
+                let resource = rpc_service
+                    .get_states(AccessPath::resource(account, resource_type.clone()))
+                    .await?
+                    .pop()
+                    .flatten();
+                if let Some(resource) = resource {
+                    items.push(MigrationItem {
+                        id: raw_object.id,
+                        state: resource,
+                    });
+                }
+            }
+        }
+    }
+    Ok(items)
+}
+
+async fn sign_system_tx(
+    rpc_service: &RpcService,
+    keypair: &RoochKeyPair,
+    action: MoveAction,
+) -> Result<RoochTransaction> {
+    let sender = keypair.public().address();
+    let sequence_number = rpc_service.get_sequence_number(sender).await?;
+    let tx_data = RoochTransactionData::new(
+        sender,
+        sequence_number,
+        rpc_service.get_chain_id(),
+        GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+        action,
+    );
+    Ok(tx_data.sign(keypair))
+}
@@ -2,5 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod aggregate_service;
+pub mod cold_storage;
+pub mod event_pruner;
+pub mod explorer;
+pub mod load_shed;
+pub mod migration;
+pub mod object_subscription;
+pub mod pending_tx_recovery;
+pub mod pending_tx_tracker;
+pub mod replica;
+pub mod rpc_auth;
+pub mod rpc_cors;
 pub mod rpc_logger;
 pub mod rpc_service;
+pub mod sequencer_key_rotation;
+pub mod source_verifier;
+pub mod telemetry;
+pub mod tls_proxy;
+pub mod webhook;
@@ -0,0 +1,199 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use futures::stream::{self, Stream, StreamExt};
+use move_core_types::language_storage::StructTag;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::State;
+use rooch_executor::actor::object_change_notifier::{ObjectChange, TxObjectChanges};
+use rooch_rpc_api::jsonrpc_types::object_change_view::{
+    ObjectChangeFilterView, ObjectChangeTypeView, ObjectChangeView,
+};
+use rooch_rpc_api::jsonrpc_types::{StateView, StrView};
+use std::collections::BTreeSet;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+
+/// The filter of an object change subscription
+#[derive(Debug, Clone)]
+pub enum ObjectChangeFilter {
+    ObjectIds(BTreeSet<ObjectID>),
+    /// The value type of the objects, the type params are ignored if the filter has no type params
+    ObjectType(StructTag),
+}
+
+impl From<ObjectChangeFilterView> for ObjectChangeFilter {
+    fn from(filter: ObjectChangeFilterView) -> Self {
+        match filter {
+            ObjectChangeFilterView::ObjectIds(object_ids) => {
+                Self::ObjectIds(object_ids.into_iter().collect())
+            }
+            ObjectChangeFilterView::ObjectType(object_type) => Self::ObjectType(object_type.0),
+        }
+    }
+}
+
+impl ObjectChangeFilter {
+    pub fn matches(&self, change: &ObjectChange) -> bool {
+        match self {
+            Self::ObjectIds(object_ids) => object_ids.contains(&change.object_id),
+            Self::ObjectType(object_type) => change
+                .before
+                .iter()
+                .chain(change.after.iter())
+                .filter_map(State::get_object_struct_tag)
+                .any(|value_type| Self::match_type(object_type, &value_type)),
+        }
+    }
+
+    fn match_type(filter_type: &StructTag, value_type: &StructTag) -> bool {
+        if filter_type.type_params.is_empty() {
+            filter_type.address == value_type.address
+                && filter_type.module == value_type.module
+                && filter_type.name == value_type.name
+        } else {
+            filter_type == value_type
+        }
+    }
+}
+
+fn change_type(change: &ObjectChange) -> ObjectChangeTypeView {
+    match (&change.before, &change.after) {
+        (_, None) => ObjectChangeTypeView::Delete,
+        (None, Some(_)) => ObjectChangeTypeView::New,
+        (Some(_), Some(_)) => ObjectChangeTypeView::Modify,
+    }
+}
+
+/// The stream of the changes matching the `filter`, the states are decoded when the changes are notified
+pub fn object_change_stream(
+    rpc_service: RpcService,
+    filter: ObjectChangeFilter,
+) -> impl Stream<Item = ObjectChangeView> {
+    let receiver = rpc_service.object_changes.subscribe();
+    stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(changes) => return Some((changes, receiver)),
+                Err(RecvError::Lagged(skipped)) => warn!(
+                    "The object change subscription lags behind, skip {} transactions",
+                    skipped
+                ),
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+    .then(move |changes| {
+        let rpc_service = rpc_service.clone();
+        let filter = filter.clone();
+        async move { decode_object_changes(&rpc_service, &changes, &filter).await }
+    })
+    .flat_map(stream::iter)
+}
+
+async fn decode_object_changes(
+    rpc_service: &RpcService,
+    tx_changes: &TxObjectChanges,
+    filter: &ObjectChangeFilter,
+) -> Vec<ObjectChangeView> {
+    let changes: Vec<&ObjectChange> = tx_changes
+        .changes
+        .iter()
+        .filter(|change| filter.matches(change))
+        .collect();
+    if changes.is_empty() {
+        return vec![];
+    }
+    let states: Vec<State> = changes
+        .iter()
+        .flat_map(|change| change.before.iter().chain(change.after.iter()).cloned())
+        .collect();
+    // The changes are notified without the decoded values if the states can not be decoded
+    let mut state_views: Vec<StateView> = match rpc_service
+        .get_annotated_states_by_state(states.clone())
+        .await
+    {
+        Ok(annotated_states) => annotated_states.into_iter().map(Into::into).collect(),
+        Err(e) => {
+            warn!(
+                "Decode the object changes of the tx {:?} error: {:?}",
+                tx_changes.tx_hash, e
+            );
+            states.into_iter().map(Into::into).collect()
+        }
+    };
+    // The states are popped in the order of the changes
+    state_views.reverse();
+    changes
+        .into_iter()
+        .map(|change| {
+            let before = change.before.as_ref().and_then(|_| state_views.pop());
+            let after = change.after.as_ref().and_then(|_| state_views.pop());
+            ObjectChangeView {
+                tx_order: StrView(tx_changes.tx_order),
+                tx_hash: tx_changes.tx_hash.into(),
+                object_id: change.object_id,
+                change_type: change_type(change),
+                before,
+                after,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::TypeTag;
+    use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+
+    fn new_struct_tag(name: &str, type_params: Vec<TypeTag>) -> StructTag {
+        StructTag {
+            address: AccountAddress::from_hex_literal("0x42").unwrap(),
+            module: Identifier::new("order_book").unwrap(),
+            name: Identifier::new(name).unwrap(),
+            type_params,
+        }
+    }
+
+    fn new_object_state(value_type: StructTag) -> State {
+        let object_type = StructTag {
+            address: MOVEOS_STD_ADDRESS,
+            module: Identifier::new("object").unwrap(),
+            name: Identifier::new("ObjectEntity").unwrap(),
+            type_params: vec![TypeTag::Struct(Box::new(value_type))],
+        };
+        State::new(vec![], TypeTag::Struct(Box::new(object_type)))
+    }
+
+    #[test]
+    fn test_object_change_filter() {
+        let object_id = ObjectID::from(AccountAddress::ONE);
+        let book_type = new_struct_tag("Book", vec![TypeTag::U64]);
+        let change = ObjectChange {
+            object_id,
+            before: Some(new_object_state(book_type.clone())),
+            after: None,
+        };
+        assert_eq!(change_type(&change), ObjectChangeTypeView::Delete);
+
+        let filter = ObjectChangeFilter::ObjectIds(BTreeSet::from([object_id]));
+        assert!(filter.matches(&change));
+        let filter =
+            ObjectChangeFilter::ObjectIds(BTreeSet::from([ObjectID::from(AccountAddress::TWO)]));
+        assert!(!filter.matches(&change));
+
+        // The deleted object is matched by the type before the change
+        assert!(ObjectChangeFilter::ObjectType(book_type).matches(&change));
+        // All the instantiations are matched if the type params are omitted
+        assert!(ObjectChangeFilter::ObjectType(new_struct_tag("Book", vec![])).matches(&change));
+        assert!(
+            !ObjectChangeFilter::ObjectType(new_struct_tag("Book", vec![TypeTag::U8]))
+                .matches(&change)
+        );
+        assert!(!ObjectChangeFilter::ObjectType(new_struct_tag("Order", vec![])).matches(&change));
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use anyhow::Result;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
+use std::future::Future;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// The interval of retrying the recovery when the sequencer is read only or the chain is paused
+pub const PENDING_TX_RECOVERY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Sequence the transactions which were accepted but not sequenced before the node stopped.
+/// The transactions are revalidated against the latest state in the accepted order,
+/// the transactions which fail the revalidation, such as the expired sequence numbers, are dropped.
+/// The recovery only runs when the sequencer is writable, it returns false if the recovery is deferred,
+/// the pending transactions are kept until the node is writable and the chain is unpaused.
+pub async fn recover_pending_transactions(rpc_service: &RpcService) -> Result<bool> {
+    recover_pending_transactions_with(&rpc_service.sequencer, |tx| async move {
+        rpc_service.execute_tx(tx).await.map(|_| ())
+    })
+    .await
+}
+
+/// Retry the deferred recovery at the `interval` until the pending transactions are recovered
+pub async fn run_pending_tx_recovery(rpc_service: RpcService, interval: Duration) {
+    loop {
+        tokio::time::sleep(interval).await;
+        match recover_pending_transactions(&rpc_service).await {
+            Ok(true) => break,
+            Ok(false) => {}
+            Err(e) => warn!("Failed to recover the pending transactions: {:?}", e),
+        }
+    }
+}
+
+async fn recover_pending_transactions_with<F, Fut>(
+    sequencer: &SequencerProxy,
+    execute_tx: F,
+) -> Result<bool>
+where
+    F: Fn(TypedTransaction) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    if !sequencer.is_writable().await? {
+        info!("The sequencer is not writable, defer the recovery of the pending transactions");
+        return Ok(false);
+    }
+    let pending_txs = sequencer.get_pending_transactions().await?;
+    if pending_txs.is_empty() {
+        return Ok(true);
+    }
+    info!("Recover {} pending transactions", pending_txs.len());
+    let mut dropped = 0;
+    for pending_tx in pending_txs {
+        if !sequencer.is_writable().await? {
+            info!("The sequencer is not writable, defer the recovery of the remaining pending transactions");
+            return Ok(false);
+        }
+        let tx_hash = pending_tx.tx.tx_hash();
+        if let Err(e) = execute_tx(pending_tx.tx.clone()).await {
+            if !sequencer.is_writable().await? {
+                // The sequencer rejects the transaction because it turns read only or the chain is paused,
+                // the transaction is kept pending for the deferred recovery
+                sequencer.restore_pending_transaction(pending_tx).await?;
+                info!("The sequencer is not writable, defer the recovery of the remaining pending transactions");
+                return Ok(false);
+            }
+            warn!(
+                "Drop the pending transaction {:?}, it fails to be recovered: {:?}",
+                tx_hash, e
+            );
+            sequencer.remove_pending_transaction(tx_hash).await?;
+            dropped += 1;
+        }
+    }
+    if dropped > 0 {
+        warn!("{} pending transactions are dropped", dropped);
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use coerce::actor::{system::ActorSystem, IntoActor};
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use moveos_types::h256::H256;
+    use moveos_types::transaction::MoveAction;
+    use rooch_sequencer::actor::sequencer::SequencerActor;
+    use rooch_store::RoochStore;
+    use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::framework::empty::Empty;
+    use rooch_types::transaction::rooch::RoochTransactionData;
+    use std::sync::{Arc, Mutex};
+
+    fn random_tx(kp: &RoochKeyPair) -> TypedTransaction {
+        let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+        TypedTransaction::Rooch(
+            RoochTransactionData::new_for_test(RoochAddress::random(), 0, action).sign(kp),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_recover_pending_transactions_when_writable() {
+        let actor_system = ActorSystem::global_system();
+        let sequencer_key =
+            RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(
+                sequencer_key.copy(),
+                RoochStore::mock_rooch_store().unwrap(),
+                true,
+            )
+            .unwrap()
+            .into_actor(Some("PendingTxRecoveryTest"), &actor_system)
+            .await
+            .unwrap()
+            .into(),
+        );
+        let txs = (0..4)
+            .map(|_| random_tx(&sequencer_key))
+            .collect::<Vec<_>>();
+        for tx in &txs {
+            sequencer.accept_transaction(tx.clone()).await.unwrap();
+        }
+
+        // The executed transactions are sequenced, the transactions in `invalid` fail the revalidation,
+        // and the sequencer turns read only while sequencing the transactions in `read_only_at`
+        let executed = Arc::new(Mutex::new(Vec::<H256>::new()));
+        let execute_tx = |invalid: Vec<H256>, read_only_at: Vec<H256>| {
+            let sequencer = sequencer.clone();
+            let executed = executed.clone();
+            move |tx: TypedTransaction| {
+                let sequencer = sequencer.clone();
+                let executed = executed.clone();
+                let invalid = invalid.contains(&tx.tx_hash());
+                let read_only = read_only_at.contains(&tx.tx_hash());
+                async move {
+                    if invalid {
+                        anyhow::bail!("The transaction is invalid");
+                    }
+                    if read_only {
+                        sequencer.set_read_only(Some("test".to_owned())).await?;
+                    }
+                    let tx_hash = tx.tx_hash();
+                    sequencer.sequence_transaction(tx, None).await?;
+                    executed.lock().unwrap().push(tx_hash);
+                    Ok(())
+                }
+            }
+        };
+
+        // The recovery is deferred while the node is read only, the pending transactions are kept
+        sequencer
+            .set_read_only(Some("test".to_owned()))
+            .await
+            .unwrap();
+        assert!(
+            !recover_pending_transactions_with(&sequencer, execute_tx(vec![], vec![]))
+                .await
+                .unwrap()
+        );
+        assert!(executed.lock().unwrap().is_empty());
+        assert_eq!(
+            sequencer.get_pending_transactions().await.unwrap().len(),
+            txs.len()
+        );
+
+        // The sequencer turns read only while sequencing the second transaction, the remaining ones are kept
+        sequencer.set_read_only(None).await.unwrap();
+        assert!(!recover_pending_transactions_with(
+            &sequencer,
+            execute_tx(vec![], vec![txs[1].tx_hash()])
+        )
+        .await
+        .unwrap());
+        assert_eq!(*executed.lock().unwrap(), vec![txs[0].tx_hash()]);
+        let pending_hashes = sequencer
+            .get_pending_transactions()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|pending_tx| pending_tx.tx.tx_hash())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pending_hashes,
+            txs[1..].iter().map(|tx| tx.tx_hash()).collect::<Vec<_>>()
+        );
+
+        // The writable sequencer recovers the remaining transactions in the accepted order and drops the invalid one
+        sequencer.set_read_only(None).await.unwrap();
+        assert!(recover_pending_transactions_with(
+            &sequencer,
+            execute_tx(vec![txs[2].tx_hash()], vec![])
+        )
+        .await
+        .unwrap());
+        assert_eq!(
+            *executed.lock().unwrap(),
+            vec![txs[0].tx_hash(), txs[1].tx_hash(), txs[3].tx_hash()]
+        );
+        assert!(sequencer
+            .get_pending_transactions()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, RwLock};
+
+/// Track the transactions which are validated but not executed yet.
+/// The pending sequence numbers are used to calculate the next usable sequence number of the sender,
+/// so the clients submitting bursts of transactions don't collide with themselves.
+#[derive(Clone, Debug, Default)]
+pub struct PendingTxTracker {
+    pending: Arc<RwLock<BTreeMap<AccountAddress, BTreeSet<u64>>>>,
+}
+
+impl PendingTxTracker {
+    /// Add a pending transaction, it is removed when the returned guard is dropped.
+    pub fn track(&self, sender: AccountAddress, sequence_number: u64) -> PendingTxGuard {
+        self.pending
+            .write()
+            .expect("pending tx lock should not be poisoned")
+            .entry(sender)
+            .or_default()
+            .insert(sequence_number);
+        PendingTxGuard {
+            tracker: self.clone(),
+            sender,
+            sequence_number,
+        }
+    }
+
+    fn remove(&self, sender: AccountAddress, sequence_number: u64) {
+        let mut pending = self
+            .pending
+            .write()
+            .expect("pending tx lock should not be poisoned");
+        if let Some(sequence_numbers) = pending.get_mut(&sender) {
+            sequence_numbers.remove(&sequence_number);
+            if sequence_numbers.is_empty() {
+                pending.remove(&sender);
+            }
+        }
+    }
+
+    /// Get the max sequence number of the pending transactions of the sender
+    pub fn max_pending_sequence_number(&self, sender: AccountAddress) -> Option<u64> {
+        self.pending
+            .read()
+            .expect("pending tx lock should not be poisoned")
+            .get(&sender)
+            .and_then(|sequence_numbers| sequence_numbers.last().copied())
+    }
+
+    /// Get the sequence numbers of the pending transactions of the sender, in ascending order
+    pub fn pending_sequence_numbers(&self, sender: AccountAddress) -> Vec<u64> {
+        self.pending
+            .read()
+            .expect("pending tx lock should not be poisoned")
+            .get(&sender)
+            .map(|sequence_numbers| sequence_numbers.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the next usable sequence number of the sender, considering the pending transactions
+    pub fn next_sequence_number(&self, sender: AccountAddress, committed: u64) -> u64 {
+        match self.max_pending_sequence_number(sender) {
+            Some(max_pending) => committed.max(max_pending.saturating_add(1)),
+            None => committed,
+        }
+    }
+}
+
+pub struct PendingTxGuard {
+    tracker: PendingTxTracker,
+    sender: AccountAddress,
+    sequence_number: u64,
+}
+
+impl Drop for PendingTxGuard {
+    fn drop(&mut self) {
+        self.tracker.remove(self.sender, self.sequence_number);
+    }
+}
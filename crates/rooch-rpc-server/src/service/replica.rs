@@ -0,0 +1,244 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! A read replica follows a primary node with the change feed: it pulls the committed transactions with their changes,
+//! and applies the changes without executing the transactions, so the read RPCs scale horizontally.
+//! The state root and the execution info of every applied transaction are verified against the primary's,
+//! the replica stops following if they mismatch. The sequencer batches are copied from the primary as well,
+//! the execution checksum of a batch is verified against the local execution infos of its transactions before it is saved.
+//! The replica initializes the same genesis as the primary, so it must be started with the same chain id and sequencer account.
+//! The indexer is not fed by the replica, the indexer queries are served by the primary.
+
+use crate::service::rpc_service::RpcService;
+use anyhow::{bail, Result};
+use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_rpc_api::api::rooch_api::RoochAPIClient;
+use rooch_rpc_api::jsonrpc_types::StrView;
+use rooch_store::batch_store::BatchStore;
+use rooch_store::RoochStore;
+use rooch_types::block::Block;
+use rooch_types::replica::ChangeFeedEntry;
+use rooch_types::sequencer::SequencerBatch;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+pub const DEFAULT_REPLICA_POLL_INTERVAL_MILLISECONDS: u64 = 1000;
+pub const REPLICA_SYNC_BATCH_SIZE: u64 = 100;
+
+pub struct ReplicaSyncer {
+    primary_url: String,
+    client: HttpClient,
+    rpc_service: RpcService,
+    rooch_store: RoochStore,
+    poll_interval: Duration,
+}
+
+impl ReplicaSyncer {
+    pub fn new(
+        primary_url: String,
+        rpc_service: RpcService,
+        rooch_store: RoochStore,
+        poll_interval: Duration,
+    ) -> Result<Self> {
+        let client = HttpClientBuilder::default()
+            .max_request_body_size(2 << 30)
+            .request_timeout(Duration::from_secs(60))
+            .build(&primary_url)?;
+        Ok(Self {
+            primary_url,
+            client,
+            rpc_service,
+            rooch_store,
+            poll_interval,
+        })
+    }
+
+    /// Check the replica follows the primary of the same chain
+    pub async fn check_primary(&self) -> Result<()> {
+        let primary_chain_id = self.client.get_chain_id().await?.0;
+        let chain_id = self.rpc_service.get_chain_id();
+        if primary_chain_id != chain_id {
+            bail!(
+                "The chain id {} of the primary node {} mismatches the local chain id {}",
+                primary_chain_id,
+                self.primary_url,
+                chain_id
+            );
+        }
+        Ok(())
+    }
+
+    /// Follow the primary until the replicated changes mismatch the local states
+    pub async fn run(self) {
+        info!(
+            "Follow the change feed of the primary node {}",
+            self.primary_url
+        );
+        loop {
+            match self.sync_once().await {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(e) if self.is_diverged(&e) => {
+                    error!(
+                        "Stop following the primary node {}, the replica diverges: {:?}",
+                        self.primary_url, e
+                    );
+                    return;
+                }
+                Err(e) => warn!(
+                    "Failed to sync from the primary node {}: {:?}",
+                    self.primary_url, e
+                ),
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Apply a page of the change feed and copy the new batches, return true if the primary has more transactions
+    pub async fn sync_once(&self) -> Result<bool> {
+        let cursor = self
+            .rpc_service
+            .get_sequencer_order()
+            .await?
+            .map(|order| StrView(order.last_order));
+        let page = self
+            .client
+            .get_change_feed(cursor, Some(StrView(REPLICA_SYNC_BATCH_SIZE)))
+            .await?;
+        for data in page.data {
+            let entry = bcs::from_bytes::<ChangeFeedEntry>(&data.0)?;
+            self.apply_entry(entry).await?;
+        }
+        self.sync_batches().await?;
+        Ok(page.has_next_page)
+    }
+
+    async fn apply_entry(&self, entry: ChangeFeedEntry) -> Result<()> {
+        let (output, execution_info) = self
+            .rpc_service
+            .executor
+            .apply_change_feed_entry(entry)
+            .await
+            .map_err(ReplicaDivergence::wrap)?;
+        self.rpc_service
+            .executor
+            .refresh_state(execution_info.state_root, output.is_upgrade)
+            .await?;
+        self.rpc_service
+            .view_executors
+            .refresh_state(execution_info.state_root, output.is_upgrade)?;
+        Ok(())
+    }
+
+    /// Copy the batches whose transactions are all applied, the batch is verified by its execution checksum,
+    /// the replica diverges from the primary if the checksum mismatches the local execution results.
+    async fn sync_batches(&self) -> Result<()> {
+        let mut next_batch_id = self
+            .rooch_store
+            .get_last_batch()?
+            .map_or(0, |batch| batch.batch_id + 1);
+        while let Some(batch) = self
+            .client
+            .get_sequencer_batch(StrView(next_batch_id))
+            .await?
+        {
+            let (batch, _transactions) = batch.into_batch()?;
+            let execution_infos = self
+                .rpc_service
+                .get_transaction_execution_infos_by_hash(batch.tx_hashes.clone())
+                .await?;
+            // The batch is copied after the change feed applies all its transactions
+            let Some(execution_infos) = execution_infos.into_iter().collect::<Option<Vec<_>>>()
+            else {
+                break;
+            };
+            verify_batch_execution(&batch, &execution_infos).map_err(ReplicaDivergence::wrap)?;
+            self.rooch_store.save_batch(batch)?;
+            next_batch_id += 1;
+        }
+        Ok(())
+    }
+
+    fn is_diverged(&self, error: &anyhow::Error) -> bool {
+        error.downcast_ref::<ReplicaDivergence>().is_some()
+    }
+}
+
+/// Verify the execution checksum of the block of the `batch` against the local `execution_infos` of its transactions,
+/// the error names the block whose execution diverges.
+fn verify_batch_execution(
+    batch: &SequencerBatch,
+    execution_infos: &[TransactionExecutionInfo],
+) -> Result<()> {
+    let block = Block::new(
+        batch.batch_id,
+        batch.batch_size(),
+        batch.prev_tx_accumulator_root,
+        batch.tx_accumulator_root,
+        execution_infos.iter().map(|info| info.state_root).collect(),
+        batch.execution_checksum,
+    );
+    block.verify_execution_checksum(execution_infos)
+}
+
+/// The replicated changes can not be applied to the local states
+#[derive(Debug)]
+struct ReplicaDivergence(anyhow::Error);
+
+impl ReplicaDivergence {
+    fn wrap(error: anyhow::Error) -> anyhow::Error {
+        anyhow::Error::new(ReplicaDivergence(error))
+    }
+}
+
+impl std::fmt::Display for ReplicaDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ReplicaDivergence {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::h256::H256;
+    use moveos_types::transaction::GasBreakdown;
+    use rooch_types::block::execution_checksum;
+
+    #[test]
+    fn test_verify_batch_execution() {
+        let execution_infos = (0..2)
+            .map(|_| {
+                TransactionExecutionInfo::new(
+                    H256::random(),
+                    H256::random(),
+                    H256::random(),
+                    100,
+                    KeptVMStatus::Executed,
+                    None,
+                    GasBreakdown::default(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let batch = SequencerBatch {
+            batch_id: 7,
+            first_tx_order: 1,
+            tx_hashes: execution_infos.iter().map(|info| info.tx_hash).collect(),
+            batch_hash: H256::random(),
+            execution_checksum: execution_checksum(&execution_infos),
+            prev_tx_accumulator_root: H256::random(),
+            tx_accumulator_root: H256::random(),
+            signatures: vec![],
+        };
+        verify_batch_execution(&batch, &execution_infos).unwrap();
+
+        // The local execution diverges from the primary, the error names the block
+        let mut diverged = execution_infos;
+        diverged[1].gas_used += 1;
+        let err = verify_batch_execution(&batch, &diverged).unwrap_err();
+        assert!(err.to_string().contains("block 7"));
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, bail, Result};
+use futures::future::BoxFuture;
+use hyper::body::HttpBody;
+use hyper::header::CONTENT_TYPE;
+use hyper::{Body, HeaderMap, Method, Request, Response};
+use jsonrpsee::core::Error as JsonRpcError;
+use rooch_rpc_api::auth::{
+    is_private_method, RpcAuthHeaders, AUTH_ADDRESS_HEADER, AUTH_SIGNATURE_HEADER,
+    AUTH_TIMESTAMP_HEADER,
+};
+use rooch_types::address::RoochAddress;
+use serde_json::{json, Value};
+use std::task::{Context, Poll};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tower::{Layer, Service};
+
+/// The JSON-RPC error code of the unauthorized call to a private method
+pub const UNAUTHORIZED_ERROR_CODE: i32 = -32001;
+
+tokio::task_local! {
+    /// The caller authenticated by the headers of the HTTP request, it is only set for the private methods.
+    static AUTHENTICATED_CALLER: RoochAddress;
+}
+
+/// Ensure the call is authenticated by the `account`, the private methods should call it before serving the data.
+/// The calls without the verified authentication headers, including the calls over the websocket, are rejected.
+pub fn ensure_authenticated(account: RoochAddress) -> Result<(), JsonRpcError> {
+    let caller = AUTHENTICATED_CALLER.try_with(|caller| *caller).ok();
+    match caller {
+        Some(caller) if caller == account => Ok(()),
+        _ => Err(JsonRpcError::Custom(format!(
+            "The call is not authenticated by the account {}",
+            account
+        ))),
+    }
+}
+
+/// Verify the authentication headers of the HTTP requests to the private methods
+#[derive(Debug, Clone)]
+pub struct RpcAuthLayer {
+    max_request_body_size: u32,
+}
+
+impl RpcAuthLayer {
+    pub fn new(max_request_body_size: u32) -> Self {
+        Self {
+            max_request_body_size,
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcAuthLayer {
+    type Service = RpcAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcAuthService {
+            inner,
+            max_request_body_size: self.max_request_body_size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcAuthService<S> {
+    inner: S,
+    max_request_body_size: u32,
+}
+
+impl<S> Service<Request<Body>> for RpcAuthService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // The inner service is ready, take it and leave the clone for the next call
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        if request.method() != Method::POST {
+            return Box::pin(inner.call(request));
+        }
+        let max_request_body_size = self.max_request_body_size;
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let bytes = match read_body(body, max_request_body_size).await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    return Ok(error_response(
+                        Value::Null,
+                        UNAUTHORIZED_ERROR_CODE,
+                        e.to_string(),
+                    ))
+                }
+            };
+            let caller = match authenticate(&parts.headers, &bytes) {
+                Ok(caller) => caller,
+                Err((id, e)) => {
+                    return Ok(error_response(id, UNAUTHORIZED_ERROR_CODE, e.to_string()))
+                }
+            };
+            let request = Request::from_parts(parts, Body::from(bytes));
+            match caller {
+                Some(caller) => {
+                    AUTHENTICATED_CALLER
+                        .scope(caller, inner.call(request))
+                        .await
+                }
+                None => inner.call(request).await,
+            }
+        })
+    }
+}
+
+pub(crate) async fn read_body(mut body: Body, max_request_body_size: u32) -> Result<Vec<u8>> {
+    let mut bytes = vec![];
+    while let Some(chunk) = body.data().await {
+        bytes.extend_from_slice(&chunk?);
+        if bytes.len() > max_request_body_size as usize {
+            bail!(
+                "The request body exceeds the max size {}",
+                max_request_body_size
+            );
+        }
+    }
+    Ok(bytes)
+}
+
+/// Return the authenticated caller if the request calls a private method
+fn authenticate(
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<Option<RoochAddress>, (Value, anyhow::Error)> {
+    // The invalid JSON-RPC requests are left to the server to reject
+    let request = match serde_json::from_slice::<Value>(body) {
+        Ok(request) => request,
+        Err(_) => return Ok(None),
+    };
+    match &request {
+        Value::Array(batch) => {
+            if batch
+                .iter()
+                .any(|call| call_method(call).map_or(false, is_private_method))
+            {
+                return Err((
+                    Value::Null,
+                    anyhow!("The private methods can not be called in a batch"),
+                ));
+            }
+            Ok(None)
+        }
+        Value::Object(call) => {
+            let method = match call_method(&request) {
+                Some(method) if is_private_method(method) => method,
+                _ => return Ok(None),
+            };
+            let id = call.get("id").cloned().unwrap_or(Value::Null);
+            let params = call.get("params").cloned().unwrap_or(Value::Null);
+            verify_headers(headers, method, &params)
+                .map(Some)
+                .map_err(|e| (id, e))
+        }
+        _ => Ok(None),
+    }
+}
+
+pub(crate) fn call_method(call: &Value) -> Option<&str> {
+    call.get("method").and_then(Value::as_str)
+}
+
+fn verify_headers(headers: &HeaderMap, method: &str, params: &Value) -> Result<RoochAddress> {
+    let auth_headers = RpcAuthHeaders::parse(
+        header_value(headers, method, AUTH_ADDRESS_HEADER)?,
+        header_value(headers, method, AUTH_SIGNATURE_HEADER)?,
+        header_value(headers, method, AUTH_TIMESTAMP_HEADER)?,
+    )?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    auth_headers.verify(method, params, now)?;
+    Ok(auth_headers.address)
+}
+
+fn header_value<'a>(headers: &'a HeaderMap, method: &str, name: &str) -> Result<&'a str> {
+    headers
+        .get(name)
+        .ok_or_else(|| anyhow!("The private method {} requires the header {}", method, name))?
+        .to_str()
+        .map_err(|e| anyhow!("Invalid header {}: {}", name, e))
+}
+
+/// The JSON-RPC error response of the request rejected by the middleware
+pub(crate) fn error_response(id: Value, code: i32, message: String) -> Response<Body> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "error": {
+            "code": code,
+            "message": message,
+        },
+        "id": id,
+    });
+    Response::builder()
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("The error response should be valid")
+}
@@ -0,0 +1,163 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_auth::{call_method, error_response, read_body};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use hyper::header::{HeaderName, HeaderValue, ORIGIN};
+use hyper::{Body, Method, Request, Response};
+use rooch_config::server_config::CorsConfig;
+use rooch_rpc_api::auth::{AUTH_ADDRESS_HEADER, AUTH_SIGNATURE_HEADER, AUTH_TIMESTAMP_HEADER};
+use serde_json::Value;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// The JSON-RPC error code of the method call which is not allowed from the origin
+pub const ORIGIN_NOT_ALLOWED_ERROR_CODE: i32 = -32002;
+
+/// Build the CORS layer which allows the origins of all the namespaces,
+/// the origins are checked per namespace by the `RpcNamespaceCorsLayer`.
+pub fn build_cors_layer(config: &CorsConfig) -> Result<CorsLayer> {
+    let origins = config.all_origins();
+    let allow_origin = if origins.is_empty() {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            origins
+                .iter()
+                .map(|origin| HeaderValue::from_str(origin))
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    };
+    let allow_methods = if config.allow_methods.is_empty() {
+        vec![Method::POST]
+    } else {
+        config
+            .allow_methods
+            .iter()
+            .map(|method| Method::from_bytes(method.to_uppercase().as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    let mut allow_headers = vec![
+        hyper::header::CONTENT_TYPE,
+        HeaderName::from_static(AUTH_ADDRESS_HEADER),
+        HeaderName::from_static(AUTH_SIGNATURE_HEADER),
+        HeaderName::from_static(AUTH_TIMESTAMP_HEADER),
+    ];
+    for header in &config.allow_headers {
+        allow_headers.push(HeaderName::from_bytes(header.as_bytes())?);
+    }
+    Ok(CorsLayer::new()
+        .allow_methods(allow_methods)
+        .allow_origin(allow_origin)
+        .allow_headers(allow_headers))
+}
+
+/// Reject the JSON-RPC calls from the origins which are not allowed by the namespace of the method
+#[derive(Debug, Clone)]
+pub struct RpcNamespaceCorsLayer {
+    config: Arc<CorsConfig>,
+    max_request_body_size: u32,
+}
+
+impl RpcNamespaceCorsLayer {
+    pub fn new(config: CorsConfig, max_request_body_size: u32) -> Self {
+        Self {
+            config: Arc::new(config),
+            max_request_body_size,
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcNamespaceCorsLayer {
+    type Service = RpcNamespaceCorsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcNamespaceCorsService {
+            inner,
+            config: self.config.clone(),
+            max_request_body_size: self.max_request_body_size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcNamespaceCorsService<S> {
+    inner: S,
+    config: Arc<CorsConfig>,
+    max_request_body_size: u32,
+}
+
+impl<S> Service<Request<Body>> for RpcNamespaceCorsService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        // The requests without the origin are not from the browsers
+        let origin = request
+            .headers()
+            .get(ORIGIN)
+            .and_then(|origin| origin.to_str().ok())
+            .map(ToOwned::to_owned);
+        let origin = match origin {
+            Some(origin)
+                if request.method() == Method::POST
+                    && !self.config.namespace_origins.is_empty() =>
+            {
+                origin
+            }
+            _ => return Box::pin(inner.call(request)),
+        };
+        let config = self.config.clone();
+        let max_request_body_size = self.max_request_body_size;
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let bytes = match read_body(body, max_request_body_size).await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    return Ok(error_response(
+                        Value::Null,
+                        ORIGIN_NOT_ALLOWED_ERROR_CODE,
+                        e.to_string(),
+                    ))
+                }
+            };
+            let calls = match serde_json::from_slice::<Value>(&bytes) {
+                Ok(Value::Array(batch)) => batch,
+                Ok(call) => vec![call],
+                Err(_) => vec![],
+            };
+            for call in &calls {
+                if let Some(method) = call_method(call) {
+                    if !config.is_origin_allowed(method, &origin) {
+                        return Ok(error_response(
+                            call.get("id").cloned().unwrap_or(Value::Null),
+                            ORIGIN_NOT_ALLOWED_ERROR_CODE,
+                            format!(
+                                "The method {} is not allowed from the origin {}",
+                                method, origin
+                            ),
+                        ));
+                    }
+                }
+            }
+            inner
+                .call(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        })
+    }
+}
@@ -1,34 +1,63 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
+use move_binary_format::CompiledModule;
 use move_core_types::account_address::AccountAddress;
-use move_core_types::language_storage::StructTag;
-
+use move_core_types::language_storage::{ModuleId, StructTag};
+use move_resource_viewer::AnnotatedMoveStruct;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::oneshot;
+
+use crate::service::pending_tx_tracker::PendingTxTracker;
+use crate::service::source_verifier::SourceVerifier;
+use crate::service::webhook::WebhookDispatcher;
 use moveos_types::access_path::AccessPath;
-use moveos_types::function_return_value::AnnotatedFunctionResult;
+use moveos_types::function_return_value::{AnnotatedFunctionResult, FunctionResult};
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
+use moveos_types::moveos_std::move_module::MoveModule;
 use moveos_types::state::{AnnotatedState, MoveStructType, State};
-use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
+use moveos_types::transaction::{
+    FunctionCall, MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction,
+};
+use rooch_config::DEFAULT_GAS_ESTIMATE_MARGIN_PERCENTAGE;
+use rooch_executor::actor::object_change_notifier::ObjectChangeNotifier;
+use rooch_executor::proxy::gas_schedule_simulator::GasScheduleSimulator;
+use rooch_executor::proxy::view_executor_pool::ViewExecutorPool;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_relayer::TxSubmiter;
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponse, ExecuteTransactionResponseView};
+use rooch_sequencer::priority::{TxPriorityClassifier, TxScheduler, TxSchedulerStats};
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::account::Account;
 use rooch_types::address::{MultiChainAddress, RoochAddress};
+use rooch_types::event_proof::EventProof;
+use rooch_types::framework::chain_pause::{
+    ChainPausedEvent, ChainUnpausedEvent, RelayersUpdatedEvent,
+};
+use rooch_types::framework::free_gas_quota::FreeGasQuotaModule;
+use rooch_types::gas_estimate::GasEstimate;
+use rooch_types::gas_schedule_simulation::GasScheduleSimulation;
+use rooch_types::gas_statistics::FunctionGasStatistics;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::state::{
     GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
     IndexerTableState, StateSyncFilter, TableStateFilter,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
-use rooch_types::sequencer::SequencerOrder;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::replica::ChangeFeedEntry;
+use rooch_types::sequencer::{SequencerBatch, SequencerOrder, TxInclusionReceipt};
+use rooch_types::source_verification::{
+    verify_package_modules, BuildMetadata, PackageSource, SourceVerification,
+};
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::{AbstractTransaction, TransactionWithInfo, TypedTransaction};
 use rooch_types::transaction::{TransactionSequenceInfo, TransactionSequenceInfoMapping};
-use rooch_types::transaction::{TransactionWithInfo, TypedTransaction};
 
 /// RpcService is the implementation of the RPC service.
 /// It is the glue between the RPC server(EthAPIServer,RoochApiServer) and the rooch's actors.
@@ -40,6 +69,15 @@ pub struct RpcService {
     pub(crate) sequencer: SequencerProxy,
     pub(crate) proposer: ProposerProxy,
     pub(crate) indexer: IndexerProxy,
+    pub(crate) pending_txs: PendingTxTracker,
+    pub(crate) view_executors: ViewExecutorPool,
+    pub(crate) gas_schedule_simulator: GasScheduleSimulator,
+    pub(crate) tx_classifier: Arc<TxPriorityClassifier>,
+    pub(crate) tx_scheduler: TxScheduler,
+    pub(crate) webhooks: Option<WebhookDispatcher>,
+    pub(crate) object_changes: ObjectChangeNotifier,
+    pub(crate) gas_estimate_margin_percentage: u64,
+    pub(crate) source_verifier: SourceVerifier,
 }
 
 impl RpcService {
@@ -49,6 +87,9 @@ impl RpcService {
         sequencer: SequencerProxy,
         proposer: ProposerProxy,
         indexer: IndexerProxy,
+        view_executors: ViewExecutorPool,
+        gas_schedule_simulator: GasScheduleSimulator,
+        tx_classifier: TxPriorityClassifier,
     ) -> Self {
         Self {
             chain_id,
@@ -56,8 +97,35 @@ impl RpcService {
             sequencer,
             proposer,
             indexer,
+            pending_txs: PendingTxTracker::default(),
+            view_executors,
+            gas_schedule_simulator,
+            tx_classifier: Arc::new(tx_classifier),
+            tx_scheduler: TxScheduler::default(),
+            webhooks: None,
+            object_changes: ObjectChangeNotifier::default(),
+            gas_estimate_margin_percentage: DEFAULT_GAS_ESTIMATE_MARGIN_PERCENTAGE,
+            source_verifier: SourceVerifier::default(),
         }
     }
+
+    /// Subscribe the object changes published by the executor
+    pub fn with_object_change_notifier(mut self, object_changes: ObjectChangeNotifier) -> Self {
+        self.object_changes = object_changes;
+        self
+    }
+
+    /// Notify the executed transactions and events to the webhooks
+    pub fn with_webhooks(mut self, webhooks: WebhookDispatcher) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
+    /// The default safety margin of the suggested max gas amount of the gas estimation
+    pub fn with_gas_estimate_margin_percentage(mut self, margin_percentage: u64) -> Self {
+        self.gas_estimate_margin_percentage = margin_percentage;
+        self
+    }
 }
 
 impl RpcService {
@@ -65,26 +133,180 @@ impl RpcService {
         self.chain_id
     }
 
-    pub async fn quene_tx(&self, tx: TypedTransaction) -> Result<()> {
-        //TODO implement quene tx and do not wait to execute
-        let _ = self.execute_tx(tx).await?;
+    /// Queue the transaction, return the inclusion receipt signed by the sequencer when it sequences the transaction.
+    /// The transaction is executed in the background, the receipt is returned before the execution.
+    pub async fn quene_tx(&self, tx: TypedTransaction) -> Result<TxInclusionReceipt> {
+        let moveos_tx = self.accept_tx(tx.clone()).await?;
+        let (receipt_sender, receipt_receiver) = oneshot::channel();
+        let service = self.clone();
+        let tx_hash = tx.tx_hash();
+        let execution = tokio::spawn(async move {
+            service
+                .execute_accepted_tx(tx, moveos_tx, Some(receipt_sender))
+                .await
+        });
+        match receipt_receiver.await {
+            Ok(receipt) => {
+                tokio::spawn(async move {
+                    let result = execution
+                        .await
+                        .map_err(anyhow::Error::from)
+                        .and_then(|result| result);
+                    if let Err(e) = result {
+                        log::error!(
+                            "Execute the queued transaction {:?} error: {:?}",
+                            tx_hash,
+                            e
+                        );
+                    }
+                });
+                Ok(receipt)
+            }
+            // The receipt sender is dropped if the transaction fails before it is sequenced
+            Err(_) => match execution.await? {
+                Ok(_) => bail!(
+                    "The transaction {:?} is executed without the inclusion receipt",
+                    tx_hash
+                ),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    /// Validate the transaction without sequencing and executing it
+    /// The validation is a dry run from the RPC, so it is bounded by the view executor pool limits.
+    pub async fn validate_tx(&self, tx: TypedTransaction) -> Result<()> {
+        let executor = self.executor.clone();
+        self.view_executors
+            .run(async move { executor.validate_transaction(tx).await })
+            .await?;
         Ok(())
     }
 
+    /// Get the committed sequence number of the account, and the next usable sequence number.
+    /// If `include_pending` is true, the next sequence number considers the pending transactions,
+    /// otherwise it is the same as the committed sequence number.
+    pub async fn get_sequence_number_with_pending(
+        &self,
+        address: AccountAddress,
+        include_pending: bool,
+    ) -> Result<(u64, u64)> {
+        let committed = self
+            .get_states(AccessPath::resource(address, Account::struct_tag()))
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| state.cast::<Account>())
+            .transpose()?
+            .map_or(0, |account| account.sequence_number);
+        let next = if include_pending {
+            self.pending_txs.next_sequence_number(address, committed)
+        } else {
+            committed
+        };
+        Ok((committed, next))
+    }
+
+    /// Get the free transactions the account can still send without paying the gas,
+    /// it is a view function call, so it is bounded by the view executor pool limits.
+    pub async fn get_free_gas_quota(&self, address: AccountAddress) -> Result<u64> {
+        let result = FunctionResult::try_from(
+            self.execute_view_function(FreeGasQuotaModule::create_remaining_quota_call(address))
+                .await?,
+        )?;
+        let value = result
+            .into_result()?
+            .pop()
+            .ok_or_else(|| anyhow::anyhow!("The remaining quota is not returned"))?;
+        Ok(bcs::from_bytes::<u64>(&value.value)?)
+    }
+
+    /// Get the sequence numbers of the transactions of the account which are validated but not executed yet
+    pub fn get_pending_sequence_numbers(&self, address: AccountAddress) -> Vec<u64> {
+        self.pending_txs.pending_sequence_numbers(address)
+    }
+
+    pub fn get_tx_scheduler_stats(&self) -> TxSchedulerStats {
+        self.tx_scheduler.stats()
+    }
+
     pub async fn execute_tx(&self, tx: TypedTransaction) -> Result<ExecuteTransactionResponse> {
+        let moveos_tx = self.accept_tx(tx.clone()).await?;
+        self.execute_accepted_tx(tx, moveos_tx, None).await
+    }
+
+    async fn accept_tx(&self, tx: TypedTransaction) -> Result<VerifiedMoveOSTransaction> {
         // First, validate the transactin
         let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
-        let sequence_info = self.sequencer.sequence_transaction(tx.clone()).await?;
+        // The accepted transaction is persisted until it is sequenced, it is recovered if the node restarts
+        self.sequencer.accept_transaction(tx).await?;
+        Ok(moveos_tx)
+    }
+
+    /// Sequence and execute the accepted transaction, the inclusion receipt is sent to the `receipt_sender`
+    /// once the transaction is sequenced, before it is executed.
+    async fn execute_accepted_tx(
+        &self,
+        tx: TypedTransaction,
+        moveos_tx: VerifiedMoveOSTransaction,
+        receipt_sender: Option<oneshot::Sender<TxInclusionReceipt>>,
+    ) -> Result<ExecuteTransactionResponse> {
+        // The transaction is pending until it is executed
+        let _pending_guard = self
+            .pending_txs
+            .track(moveos_tx.ctx.sender, moveos_tx.ctx.sequence_number);
+        // The protocol-critical transactions are sequenced ahead of the waiting user transactions,
+        // the permit is held until the transaction is executed, so the transactions are executed in the sequenced order.
+        let priority = self
+            .tx_classifier
+            .classify(&moveos_tx.ctx.sender, &moveos_tx.action);
+        let _permit = self.tx_scheduler.acquire(priority).await;
+        // The calls to the module addresses are checked against their per-block gas quotas
+        let module_address = match &moveos_tx.action {
+            MoveAction::Function(call) => Some(*call.function_id.module_id.address()),
+            _ => None,
+        };
+        let (sequence_info, receipt) = self
+            .sequencer
+            .sequence_transaction(tx.clone(), module_address)
+            .await?;
+        if let Some(receipt_sender) = receipt_sender {
+            // The sender stops waiting for the receipt if its request is dropped
+            let _ = receipt_sender.send(receipt);
+        }
         // Then execute
         let (output, execution_info) = self.executor.execute_transaction(moveos_tx.clone()).await?;
+        if let Some(module_address) = module_address {
+            self.sequencer
+                .record_module_gas(
+                    sequence_info.tx_order,
+                    module_address,
+                    execution_info.gas_used,
+                )
+                .await?;
+        }
         self.proposer
             .propose_transaction(tx.clone(), execution_info.clone(), sequence_info.clone())
             .await?;
 
+        // The chain pause state and the relayers are changed by the chain_pause events, sync them to the sequencer
+        for event in &output.events {
+            if event.event_type == ChainPausedEvent::struct_tag() {
+                self.sequencer.update_chain_pause(true).await?;
+            } else if event.event_type == ChainUnpausedEvent::struct_tag() {
+                self.sequencer.update_chain_pause(false).await?;
+            } else if event.event_type == RelayersUpdatedEvent::struct_tag() {
+                let event = bcs::from_bytes::<RelayersUpdatedEvent>(&event.event_data)?;
+                self.sequencer.update_relayers(event.relayers).await?;
+            }
+        }
+
         // Sync lastest state root from writer executor to reader executor
         self.executor
             .refresh_state(execution_info.state_root, output.is_upgrade)
             .await?;
+        self.view_executors
+            .refresh_state(execution_info.state_root, output.is_upgrade)?;
 
         // Last save indexer
         let result = self
@@ -108,6 +330,14 @@ impl RpcService {
             Ok(_) => {}
             Err(error) => log::error!("Indexer transactions error: {}", error),
         };
+        if let Some(webhooks) = &self.webhooks {
+            webhooks.notify(
+                sequence_info.tx_order,
+                moveos_tx.ctx.sender,
+                execution_info.clone().into(),
+                &output.events,
+            );
+        }
         let result = self
             .indexer
             .indexer_events(output.events.clone(), tx, sequence_info.clone(), moveos_tx)
@@ -128,7 +358,10 @@ impl RpcService {
         &self,
         function_call: FunctionCall,
     ) -> Result<AnnotatedFunctionResult> {
-        let resp = self.executor.execute_view_function(function_call).await?;
+        let resp = self
+            .view_executors
+            .execute_view_function(function_call)
+            .await?;
         Ok(resp)
     }
 
@@ -140,6 +373,23 @@ impl RpcService {
         self.executor.get_states(access_path).await
     }
 
+    pub async fn get_module(&self, module_id: ModuleId) -> Result<Option<CompiledModule>> {
+        let mut states = self
+            .get_states(AccessPath::module(
+                *module_id.address(),
+                module_id.name().to_owned(),
+            ))
+            .await?;
+        states
+            .pop()
+            .flatten()
+            .map(|state| {
+                let module = state.cast::<MoveModule>()?;
+                Ok(CompiledModule::deserialize(&module.byte_codes)?)
+            })
+            .transpose()
+    }
+
     pub async fn exists_account(&self, address: AccountAddress) -> Result<bool> {
         let mut resp = self
             .get_states(AccessPath::resource(address, Account::struct_tag()))
@@ -208,6 +458,11 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn get_event_proof(&self, event_id: EventID) -> Result<Option<(Event, EventProof)>> {
+        let resp = self.executor.get_event_proof(event_id).await?;
+        Ok(resp)
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<TypedTransaction>> {
         let resp = self.sequencer.get_transaction_by_hash(hash).await?;
         Ok(resp)
@@ -221,6 +476,48 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn get_sequencer_batch(&self, batch_id: u128) -> Result<Option<SequencerBatch>> {
+        let resp = self.proposer.get_sequencer_batch(batch_id).await?;
+        Ok(resp)
+    }
+
+    /// Rebuild the source of the package on the node, and record the result of comparing it with the on-chain modules
+    pub async fn verify_package_source(&self, source: PackageSource) -> Result<SourceVerification> {
+        let on_chain_modules = self
+            .executor
+            .get_package_modules(source.package_address)
+            .await?;
+        let rebuilt_modules = self.source_verifier.rebuild(&source).await?;
+        let status = verify_package_modules(&rebuilt_modules, &on_chain_modules);
+        let source = PackageSource {
+            build_metadata: BuildMetadata {
+                compiler_version: env!("CARGO_PKG_VERSION").to_owned(),
+                modules: rebuilt_modules,
+                ..source.build_metadata
+            },
+            ..source
+        };
+        let verification = SourceVerification {
+            source_hash: source.source_hash(),
+            source,
+            status,
+            verified_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64,
+        };
+        let resp = self.executor.save_source_verification(verification).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_source_verification(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<SourceVerification>> {
+        let resp = self
+            .executor
+            .get_source_verification(package_address)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn get_transaction_sequence_infos(
         &self,
         orders: Vec<u64>,
@@ -265,6 +562,89 @@ impl RpcService {
         Ok(resp)
     }
 
+    /// Get the change feed entries of the transactions in the `tx_orders`, with the changes recorded by the executor.
+    pub async fn get_change_feed_entries(
+        &self,
+        tx_orders: Vec<u64>,
+    ) -> Result<Vec<ChangeFeedEntry>> {
+        let tx_hashes = self
+            .get_tx_sequence_info_mapping_by_order(tx_orders.clone())
+            .await?
+            .into_iter()
+            .zip(tx_orders.iter())
+            .map(|(mapping, tx_order)| {
+                mapping.map(|mapping| mapping.tx_hash).ok_or_else(|| {
+                    anyhow!("The tx hash of the tx order {} does not exist", tx_order)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let txs = self.get_transactions_by_hash(tx_hashes.clone()).await?;
+        let sequence_infos = self.get_transaction_sequence_infos(tx_orders).await?;
+        let execution_infos = self
+            .get_transaction_execution_infos_by_hash(tx_hashes.clone())
+            .await?;
+        let change_sets = self
+            .executor
+            .get_replica_change_sets(tx_hashes.clone())
+            .await?;
+
+        let mut entries = vec![];
+        for ((((tx_hash, tx), sequence_info), execution_info), change_set) in tx_hashes
+            .into_iter()
+            .zip(txs)
+            .zip(sequence_infos)
+            .zip(execution_infos)
+            .zip(change_sets)
+        {
+            match (tx, sequence_info, execution_info, change_set) {
+                (Some(tx), Some(sequence_info), Some(execution_info), Some(change_set)) => {
+                    entries.push(ChangeFeedEntry {
+                        tx,
+                        sequence_info,
+                        execution_info,
+                        change_set,
+                    })
+                }
+                (_, _, _, None) => bail!(
+                    "The changes of the tx {:?} are not recorded, the change feed is not enabled when it is executed",
+                    tx_hash
+                ),
+                _ => bail!("The tx {:?} is not committed", tx_hash),
+            }
+        }
+        Ok(entries)
+    }
+
+    pub async fn get_gas_statistics(&self) -> Result<Vec<FunctionGasStatistics>> {
+        let resp = self.executor.get_gas_statistics().await?;
+        Ok(resp)
+    }
+
+    /// Estimate the gas of the unsigned transaction by simulating it on the latest state,
+    /// the margin is the default margin of the node if it is not specified.
+    pub async fn estimate_gas(
+        &self,
+        tx_data: RoochTransactionData,
+        margin_percentage: Option<u64>,
+    ) -> Result<GasEstimate> {
+        let (output, gas_prices) = self.view_executors.estimate_gas(tx_data).await?;
+        Ok(GasEstimate::new(
+            &output,
+            gas_prices,
+            margin_percentage.unwrap_or(self.gas_estimate_margin_percentage),
+        ))
+    }
+
+    pub async fn simulate_gas_schedule_change(
+        &self,
+        entries: BTreeMap<String, u64>,
+        tx_hashes: Vec<H256>,
+    ) -> Result<GasScheduleSimulation> {
+        self.gas_schedule_simulator
+            .simulate(entries, tx_hashes)
+            .await
+    }
+
     pub async fn get_sequencer_order(&self) -> Result<Option<SequencerOrder>> {
         let resp = self.sequencer.get_sequencer_order().await?;
         Ok(resp)
@@ -310,6 +690,14 @@ impl RpcService {
         Ok(resp)
     }
 
+    /// Decode the event data of the indexed events, the historical events are decoded with the registered event schemas.
+    pub async fn decode_events(
+        &self,
+        events: Vec<(StructTag, Vec<u8>)>,
+    ) -> Result<Vec<Option<AnnotatedMoveStruct>>> {
+        self.executor.decode_events(events).await
+    }
+
     pub async fn query_global_states(
         &self,
         filter: GlobalStateFilter,
@@ -364,14 +752,10 @@ impl TxSubmiter for RpcService {
     }
     //TODO provide a trait to abstract the async state reader, elemiate the duplicated code bwteen RpcService and Client
     async fn get_sequence_number(&self, address: RoochAddress) -> Result<u64> {
-        Ok(self
-            .get_states(AccessPath::resource(address.into(), Account::struct_tag()))
-            .await?
-            .pop()
-            .flatten()
-            .map(|state| state.cast::<Account>())
-            .transpose()?
-            .map_or(0, |account| account.sequence_number))
+        let (committed, _next) = self
+            .get_sequence_number_with_pending(address.into(), false)
+            .await?;
+        Ok(committed)
     }
     async fn submit_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponseView> {
         Ok(self.execute_tx(TypedTransaction::Rooch(tx)).await?.into())
@@ -0,0 +1,119 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use anyhow::{bail, ensure, Result};
+use moveos_types::gas_config::GasConfig;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::transaction::MoveAction;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_relayer::TxSubmiter;
+use rooch_rpc_api::jsonrpc_types::KeptVMStatusView;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::sequencer_key_registry::SequencerKeyRegistryModule;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use tracing::{info, warn};
+
+/// Drive the sequencer key rotation when the node starts.
+/// If the next sequencer key is configured, the rotation is announced with a system transaction signed by the current sequencer key.
+/// If the node runs with the next key of the pending rotation and the handover window ends, the rotation is completed.
+pub async fn sync_sequencer_key_rotation(
+    rpc_service: &RpcService,
+    executor: &ExecutorProxy,
+    sequencer_keypair: &RoochKeyPair,
+    next_sequencer_keypair: Option<&RoochKeyPair>,
+    handover_window_milliseconds: u64,
+) -> Result<()> {
+    let sequencer: RoochAddress = sequencer_keypair.public().address();
+    let keys = match executor
+        .as_module_binding::<SequencerKeyRegistryModule>()
+        .sequencer_keys()
+    {
+        Ok(keys) => keys,
+        Err(e) if next_sequencer_keypair.is_none() => {
+            warn!("Failed to load the sequencer key registry: {:?}", e);
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    };
+
+    match next_sequencer_keypair {
+        Some(next_sequencer_keypair) => {
+            let next_public_key = next_sequencer_keypair.public();
+            let next_sequencer = next_public_key.address();
+            match &keys.pending_rotation {
+                Some(pending) if pending.next_sequencer == next_sequencer.into() => {
+                    info!(
+                        "The sequencer key rotation to {} is already announced, handover end: {}",
+                        next_sequencer, pending.handover_end
+                    );
+                }
+                Some(pending) => bail!(
+                    "Another sequencer key rotation to {} is pending",
+                    pending.next_sequencer
+                ),
+                None => {
+                    ensure!(
+                        keys.sequencer == sequencer.into(),
+                        "The sequencer {} is not the on-chain sequencer {}, can not announce the key rotation",
+                        sequencer,
+                        keys.sequencer
+                    );
+                    let action = SequencerKeyRegistryModule::create_announce_key_rotation_action(
+                        &next_public_key,
+                        handover_window_milliseconds,
+                    );
+                    submit_system_tx(rpc_service, sequencer_keypair, action).await?;
+                    info!(
+                        "Announced the sequencer key rotation from {} to {}, handover window: {}ms",
+                        sequencer, next_sequencer, handover_window_milliseconds
+                    );
+                }
+            }
+        }
+        None => {
+            if let Some(pending) = &keys.pending_rotation {
+                if pending.next_sequencer != sequencer.into() {
+                    return Ok(());
+                }
+                if keys.timestamp_milliseconds < pending.handover_end {
+                    warn!(
+                        "The handover window of the sequencer key rotation ends at {}, the rotation is not completed yet",
+                        pending.handover_end
+                    );
+                    return Ok(());
+                }
+                let action = SequencerKeyRegistryModule::create_complete_key_rotation_action();
+                submit_system_tx(rpc_service, sequencer_keypair, action).await?;
+                info!("Completed the sequencer key rotation to {}", sequencer);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn submit_system_tx(
+    rpc_service: &RpcService,
+    keypair: &RoochKeyPair,
+    action: MoveAction,
+) -> Result<()> {
+    let sender = keypair.public().address();
+    let sequence_number = rpc_service.get_sequence_number(sender).await?;
+    let tx_data = RoochTransactionData::new(
+        sender,
+        sequence_number,
+        rpc_service.get_chain_id(),
+        GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+        action,
+    );
+    let tx = tx_data.sign(keypair);
+    let result = rpc_service.submit_tx(tx).await?;
+    match result.execution_info.status {
+        KeptVMStatusView::Executed => Ok(()),
+        status => bail!(
+            "The sequencer system transaction failed, status: {:?}",
+            status
+        ),
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, bail, ensure, Result};
+use include_dir::{include_dir, Dir};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_package::source_package::manifest_parser::{
+    parse_move_manifest_string, parse_source_manifest,
+};
+use move_package::BuildConfig;
+use moveos_verifier::build::{run_verifier, BYTECODE_VERSION};
+use rooch_types::source_verification::PackageSource;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Component, Path};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+/// The builds running at the same time, the submissions exceeding it are rejected
+const MAX_CONCURRENT_BUILDS: usize = 2;
+
+/// A package is rebuilt at most once in the interval
+const PACKAGE_VERIFY_INTERVAL: Duration = Duration::from_secs(60);
+
+const MAX_SOURCE_FILES: usize = 256;
+
+/// The total size of the submitted files in bytes
+const MAX_SOURCE_SIZE: usize = 4 * 1024 * 1024;
+
+static MOVE_STDLIB_SOURCES: Dir =
+    include_dir!("$CARGO_MANIFEST_DIR/../../moveos/moveos-stdlib/move-stdlib/sources");
+static MOVEOS_STDLIB_SOURCES: Dir =
+    include_dir!("$CARGO_MANIFEST_DIR/../../moveos/moveos-stdlib/moveos-stdlib/sources");
+static ROOCH_FRAMEWORK_SOURCES: Dir =
+    include_dir!("$CARGO_MANIFEST_DIR/../rooch-framework/sources");
+static BITCOIN_MOVE_SOURCES: Dir =
+    include_dir!("$CARGO_MANIFEST_DIR/../../frameworks/bitcoin-move/sources");
+
+/// The frameworks the submitted package may depend on, pinned to the sources the node is built with.
+/// The packages are extracted with the layout of the repository, so the local dependencies between them resolve.
+const FRAMEWORKS: [(&str, &str, &str, &Dir); 4] = [
+    (
+        "MoveStdlib",
+        "moveos/moveos-stdlib/move-stdlib",
+        include_str!("../../../../moveos/moveos-stdlib/move-stdlib/Move.toml"),
+        &MOVE_STDLIB_SOURCES,
+    ),
+    (
+        "MoveosStdlib",
+        "moveos/moveos-stdlib/moveos-stdlib",
+        include_str!("../../../../moveos/moveos-stdlib/moveos-stdlib/Move.toml"),
+        &MOVEOS_STDLIB_SOURCES,
+    ),
+    (
+        "RoochFramework",
+        "crates/rooch-framework",
+        include_str!("../../../rooch-framework/Move.toml"),
+        &ROOCH_FRAMEWORK_SOURCES,
+    ),
+    (
+        "BitcoinMove",
+        "frameworks/bitcoin-move",
+        include_str!("../../../../frameworks/bitcoin-move/Move.toml"),
+        &BITCOIN_MOVE_SOURCES,
+    ),
+];
+
+/// The directory of the submitted package in the build directory
+const PACKAGE_DIR: &str = "package";
+
+/// Rebuild the submitted package sources on the node, with the compiler of the node and the frameworks pinned to
+/// the sources the node is built with, the bytecode in the submission is never trusted.
+/// The builds are expensive, so they are limited globally and per package.
+#[derive(Clone)]
+pub struct SourceVerifier {
+    builds: Arc<Semaphore>,
+    verified_at: Arc<Mutex<HashMap<AccountAddress, Instant>>>,
+}
+
+impl Default for SourceVerifier {
+    fn default() -> Self {
+        Self {
+            builds: Arc::new(Semaphore::new(MAX_CONCURRENT_BUILDS)),
+            verified_at: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl SourceVerifier {
+    /// Rebuild the package, return the bytecode of the modules keyed by the module name
+    pub async fn rebuild(&self, source: &PackageSource) -> Result<BTreeMap<String, Vec<u8>>> {
+        check_source(source)?;
+        let _permit = self.builds.clone().try_acquire_owned().map_err(|_| {
+            anyhow!("The node is busy verifying other packages, please retry later")
+        })?;
+        {
+            let mut verified_at = self
+                .verified_at
+                .lock()
+                .map_err(|e| anyhow!("Lock the source verifier error: {:?}", e))?;
+            let now = Instant::now();
+            if let Some(last) = verified_at.get(&source.package_address) {
+                let elapsed = now.duration_since(*last);
+                if elapsed < PACKAGE_VERIFY_INTERVAL {
+                    bail!(
+                        "The package {} is verified recently, please retry in {} seconds",
+                        source.package_address,
+                        (PACKAGE_VERIFY_INTERVAL - elapsed).as_secs()
+                    );
+                }
+            }
+            verified_at.retain(|_, last| now.duration_since(*last) < PACKAGE_VERIFY_INTERVAL);
+            verified_at.insert(source.package_address, now);
+        }
+        let source = source.clone();
+        tokio::task::spawn_blocking(move || build_package(&source))
+            .await
+            .map_err(|e| anyhow!("Build the package error: {:?}", e))?
+    }
+}
+
+/// Only the Move.toml and the Move files in the sources directory are accepted
+fn check_source(source: &PackageSource) -> Result<()> {
+    let compiler_version = env!("CARGO_PKG_VERSION");
+    ensure!(
+        source.build_metadata.compiler_version == compiler_version,
+        "The package is built by the compiler {}, but the node rebuilds it by the compiler {}",
+        source.build_metadata.compiler_version,
+        compiler_version
+    );
+    ensure!(
+        source.files.len() <= MAX_SOURCE_FILES,
+        "The package has more than {} files",
+        MAX_SOURCE_FILES
+    );
+    let size: usize = source.files.values().map(|content| content.len()).sum();
+    ensure!(
+        size <= MAX_SOURCE_SIZE,
+        "The package is larger than {} bytes",
+        MAX_SOURCE_SIZE
+    );
+    ensure!(
+        source.files.contains_key("Move.toml"),
+        "The package has no Move.toml"
+    );
+    for path in source.files.keys() {
+        if path == "Move.toml" {
+            continue;
+        }
+        let relative_path = Path::new(path);
+        let valid = relative_path.starts_with("sources")
+            && relative_path.extension().map_or(false, |ext| ext == "move")
+            && relative_path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        ensure!(valid, "Invalid source file path: {}", path);
+    }
+    Ok(())
+}
+
+/// Regenerate the Move.toml of the submitted package, the dependencies are replaced by the pinned frameworks
+fn pinned_manifest(manifest: &str) -> Result<String> {
+    let manifest = parse_source_manifest(parse_move_manifest_string(manifest.to_owned())?)?;
+    let package_name = manifest.package.name.to_string();
+    ensure!(
+        Identifier::is_valid(&package_name),
+        "Invalid package name: {}",
+        package_name
+    );
+    let mut pinned = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.0.1\"\n\n[addresses]\n",
+        package_name
+    );
+    for (name, address) in manifest.addresses.unwrap_or_default() {
+        ensure!(
+            Identifier::is_valid(name.as_str()),
+            "Invalid named address: {}",
+            name
+        );
+        match address {
+            Some(address) => {
+                pinned.push_str(&format!("{} = \"{}\"\n", name, address.to_hex_literal()))
+            }
+            None => pinned.push_str(&format!("{} = \"_\"\n", name)),
+        }
+    }
+    pinned.push_str("\n[dependencies]\n");
+    for name in manifest.dependencies.keys() {
+        let (_, framework_path, _, _) = FRAMEWORKS
+            .iter()
+            .find(|(framework, _, _, _)| *framework == name.as_str())
+            .ok_or_else(|| {
+                anyhow!(
+                    "The package depends on {}, only the frameworks are supported",
+                    name
+                )
+            })?;
+        pinned.push_str(&format!(
+            "{} = {{ local = \"../{}\" }}\n",
+            name, framework_path
+        ));
+    }
+    Ok(pinned)
+}
+
+fn build_package(source: &PackageSource) -> Result<BTreeMap<String, Vec<u8>>> {
+    let build_dir = tempfile::tempdir()?;
+    for (_, framework_path, manifest, sources) in FRAMEWORKS.iter() {
+        let framework_dir = build_dir.path().join(framework_path);
+        std::fs::create_dir_all(framework_dir.join("sources"))?;
+        std::fs::write(framework_dir.join("Move.toml"), manifest)?;
+        sources.extract(framework_dir.join("sources"))?;
+    }
+
+    let package_path = build_dir.path().join(PACKAGE_DIR);
+    for (path, content) in &source.files {
+        let file_path = package_path.join(path);
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if path == "Move.toml" {
+            std::fs::write(file_path, pinned_manifest(content)?)?;
+        } else {
+            std::fs::write(file_path, content)?;
+        }
+    }
+
+    let config = BuildConfig {
+        additional_named_addresses: source.build_metadata.named_addresses.clone(),
+        skip_fetch_latest_git_deps: true,
+        bytecode_version: Some(BYTECODE_VERSION),
+        ..Default::default()
+    };
+    let mut package = config
+        .clone()
+        .compile_package_no_exit(&package_path, &mut std::io::sink())?;
+    run_verifier(&package_path, config, &mut package)?;
+
+    let mut modules = BTreeMap::new();
+    for module in package.root_modules_map().iter_modules() {
+        ensure!(
+            *module.self_id().address() == source.package_address,
+            "The module {} is not at the package address {}",
+            module.self_id(),
+            source.package_address
+        );
+        let mut bytes = vec![];
+        module.serialize(&mut bytes)?;
+        modules.insert(module.self_id().name().to_string(), bytes);
+    }
+    Ok(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_manifest() {
+        let manifest = r#"
+[package]
+name = "Counter"
+version = "1.0.0"
+
+[addresses]
+counter = "_"
+std = "0x1"
+
+[dependencies]
+MoveStdlib = { git = "https://example.com/move-stdlib.git", rev = "main" }
+RoochFramework = { local = "/etc/rooch-framework" }
+"#;
+        let pinned = pinned_manifest(manifest).unwrap();
+        assert!(pinned.contains("name = \"Counter\""));
+        assert!(pinned.contains("counter = \"_\""));
+        assert!(pinned.contains("std = \"0x1\""));
+        assert!(pinned.contains("MoveStdlib = { local = \"../moveos/moveos-stdlib/move-stdlib\" }"));
+        assert!(pinned.contains("RoochFramework = { local = \"../crates/rooch-framework\" }"));
+        assert!(!pinned.contains("example.com"));
+
+        let manifest = r#"
+[package]
+name = "Evil"
+version = "1.0.0"
+
+[dependencies]
+Other = { local = "../other" }
+"#;
+        assert!(pinned_manifest(manifest).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::context::ActorContext;
+use coerce::actor::message::{Handler, Message};
+use coerce::actor::scheduler::timer::TimerTick;
+use coerce::actor::Actor;
+use rooch_config::TelemetryConfig;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::RoochStore;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+/// The reports are kept locally when the endpoint is unreachable, the oldest are dropped beyond the limit.
+pub const MAX_QUEUED_TELEMETRY_REPORTS: usize = 100;
+
+const TELEMETRY_NODE_ID_FILE: &str = "telemetry_node_id";
+const TELEMETRY_QUEUE_FILE: &str = "telemetry_queue.json";
+
+/// The anonymized stats of the node.
+/// The node is identified by a random id generated locally, no address or key of the node is reported.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TelemetryReport {
+    pub node_id: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub os: String,
+    pub arch: String,
+    /// The order of the last sequenced transaction
+    pub tx_order: u64,
+    /// The transactions sequenced per second since the previous report
+    pub tx_throughput: f64,
+    /// The seconds since the unix epoch
+    pub timestamp: u64,
+}
+
+#[derive(Clone)]
+pub struct TelemetryTick {}
+
+impl Message for TelemetryTick {
+    type Result = ();
+}
+
+impl TimerTick for TelemetryTick {}
+
+/// Report the telemetry of the node to the configured endpoint periodically, it only runs when the node opts in.
+pub struct TelemetryActor {
+    config: TelemetryConfig,
+    chain_id: u64,
+    node_id: String,
+    rooch_store: RoochStore,
+    queue_path: PathBuf,
+    queue: VecDeque<TelemetryReport>,
+    client: reqwest::Client,
+    last_tx_order: Option<(u64, Instant)>,
+}
+
+impl TelemetryActor {
+    pub fn new(
+        config: TelemetryConfig,
+        chain_id: u64,
+        rooch_store: RoochStore,
+        data_dir: &Path,
+    ) -> Result<Self> {
+        let node_id = load_or_create_node_id(&data_dir.join(TELEMETRY_NODE_ID_FILE))?;
+        let queue_path = data_dir.join(TELEMETRY_QUEUE_FILE);
+        let queue = match std::fs::read(&queue_path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_default(),
+            Err(_) => VecDeque::new(),
+        };
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+        Ok(Self {
+            config,
+            chain_id,
+            node_id,
+            rooch_store,
+            queue_path,
+            queue,
+            client,
+            last_tx_order: None,
+        })
+    }
+
+    fn collect(&mut self) -> Result<TelemetryReport> {
+        let tx_order = self
+            .rooch_store
+            .get_sequencer_order()?
+            .map_or(0, |order| order.last_order);
+        let now = Instant::now();
+        let tx_throughput = match self.last_tx_order {
+            Some((last_tx_order, last_time)) => {
+                let elapsed = now.duration_since(last_time).as_secs_f64();
+                if elapsed > 0.0 {
+                    tx_order.saturating_sub(last_tx_order) as f64 / elapsed
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        self.last_tx_order = Some((tx_order, now));
+        Ok(TelemetryReport {
+            node_id: self.node_id.clone(),
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            chain_id: self.chain_id,
+            os: std::env::consts::OS.to_owned(),
+            arch: std::env::consts::ARCH.to_owned(),
+            tx_order,
+            tx_throughput,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        })
+    }
+
+    fn enqueue(&mut self, report: TelemetryReport) {
+        self.queue.push_back(report);
+        while self.queue.len() > MAX_QUEUED_TELEMETRY_REPORTS {
+            self.queue.pop_front();
+        }
+    }
+
+    /// Send the queued reports in a batch, they are kept in the queue if the endpoint is unreachable
+    async fn flush(&mut self) -> Result<()> {
+        if self.queue.is_empty() {
+            return Ok(());
+        }
+        let result = self
+            .client
+            .post(&self.config.endpoint)
+            .json(&self.queue)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match result {
+            Ok(_) => {
+                debug!("Sent {} telemetry reports", self.queue.len());
+                self.queue.clear();
+            }
+            Err(e) => warn!(
+                "Failed to send the telemetry reports, {} reports are queued: {:?}",
+                self.queue.len(),
+                e
+            ),
+        }
+        std::fs::write(&self.queue_path, serde_json::to_vec(&self.queue)?)?;
+        Ok(())
+    }
+
+    async fn report(&mut self) -> Result<()> {
+        let report = self.collect()?;
+        self.enqueue(report);
+        self.flush().await
+    }
+}
+
+fn load_or_create_node_id(path: &Path) -> Result<String> {
+    if let Ok(node_id) = std::fs::read_to_string(path) {
+        return Ok(node_id.trim().to_owned());
+    }
+    let node_id = hex::encode(rand::random::<[u8; 16]>());
+    std::fs::write(path, &node_id)?;
+    Ok(node_id)
+}
+
+impl Actor for TelemetryActor {}
+
+#[async_trait]
+impl Handler<TelemetryTick> for TelemetryActor {
+    async fn handle(&mut self, _message: TelemetryTick, _ctx: &mut ActorContext) {
+        if let Err(err) = self.report().await {
+            warn!("Telemetry tick task error: {:?}", err);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use rooch_config::server_config::TlsConfig;
+use rustls_pemfile::Item;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
+
+pub fn load_tls_acceptor(config: &TlsConfig) -> Result<TlsAcceptor> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(&config.cert_path)?))?
+        .into_iter()
+        .map(Certificate)
+        .collect::<Vec<_>>();
+    if certs.is_empty() {
+        bail!("No certificate found in {}", config.cert_path.display());
+    }
+    let key = load_private_key(&config.key_path)?;
+    let server_config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKey> {
+    let mut reader = BufReader::new(File::open(path)?);
+    loop {
+        match rustls_pemfile::read_one(&mut reader)? {
+            Some(Item::PKCS8Key(key)) | Some(Item::RSAKey(key)) | Some(Item::ECKey(key)) => {
+                return Ok(PrivateKey(key))
+            }
+            Some(_) => continue,
+            None => bail!("No private key found in {}", path.display()),
+        }
+    }
+}
+
+/// Terminate the TLS connections on the `addr`, and forward the decrypted streams to the RPC server on the `upstream`.
+/// Both the HTTP and the websocket connections are forwarded.
+pub async fn start_tls_proxy(
+    addr: SocketAddr,
+    upstream: SocketAddr,
+    acceptor: TlsAcceptor,
+) -> Result<JoinHandle<()>> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("TLS proxy listening {:?}, upstream {:?}", addr, upstream);
+    Ok(tokio::spawn(async move {
+        loop {
+            let (stream, remote_addr) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    warn!("TLS proxy failed to accept the connection: {:?}", e);
+                    continue;
+                }
+            };
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                if let Err(e) = proxy_connection(stream, upstream, acceptor).await {
+                    debug!("TLS connection from {:?} closed: {:?}", remote_addr, e);
+                }
+            });
+        }
+    }))
+}
+
+async fn proxy_connection(
+    stream: TcpStream,
+    upstream: SocketAddr,
+    acceptor: TlsAcceptor,
+) -> Result<()> {
+    let mut tls_stream = acceptor.accept(stream).await?;
+    let mut upstream = TcpStream::connect(upstream).await?;
+    tokio::io::copy_bidirectional(&mut tls_stream, &mut upstream).await?;
+    Ok(())
+}
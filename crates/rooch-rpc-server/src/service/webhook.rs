@@ -0,0 +1,353 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use hmac::{Hmac, Mac};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::moveos_std::event::Event;
+use rooch_config::webhook_config::{WebhookConfig, WebhookTarget};
+use rooch_rpc_api::jsonrpc_types::event_view::EventView;
+use rooch_rpc_api::jsonrpc_types::TransactionExecutionInfoView;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Rooch-Signature";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Rooch-Timestamp";
+
+const MAX_WEBHOOK_BACKOFF: Duration = Duration::from_secs(60);
+
+/// The payload posted to the webhooks for an executed transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    pub tx_order: u64,
+    pub sender: String,
+    pub execution_info: TransactionExecutionInfoView,
+    /// The events of the transaction matching the filter of the webhook
+    pub events: Vec<EventView>,
+}
+
+/// The webhook with the parsed filters
+#[derive(Debug, Clone)]
+struct Webhook {
+    url: String,
+    secret: String,
+    event_types: Vec<String>,
+    senders: Vec<AccountAddress>,
+    include_transactions: bool,
+}
+
+impl Webhook {
+    fn new(target: &WebhookTarget) -> Result<Self> {
+        let event_types = target
+            .event_types
+            .iter()
+            .map(|event_type| normalize_type_prefix(event_type))
+            .collect::<Result<Vec<_>>>()?;
+        let senders = target
+            .senders
+            .iter()
+            .map(|sender| {
+                AccountAddress::from_hex_literal(sender)
+                    .map_err(|e| anyhow!("Invalid webhook sender {}: {}", sender, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            url: target.url.clone(),
+            secret: target.secret.clone(),
+            event_types,
+            senders,
+            include_transactions: target.include_transactions,
+        })
+    }
+
+    fn match_event(&self, event: &Event) -> bool {
+        if self.event_types.is_empty() {
+            return true;
+        }
+        let event_type = event.event_type.to_string();
+        self.event_types.iter().any(|prefix| {
+            event_type == *prefix
+                || event_type.starts_with(&format!("{}::", prefix))
+                || event_type.starts_with(&format!("{}<", prefix))
+        })
+    }
+
+    /// Return the matched events, or `None` if the transaction is not notified to the webhook
+    fn filter(&self, sender: &AccountAddress, events: &[Event]) -> Option<Vec<EventView>> {
+        if !self.senders.is_empty() && !self.senders.contains(sender) {
+            return None;
+        }
+        let events = events
+            .iter()
+            .filter(|event| self.match_event(event))
+            .cloned()
+            .map(EventView::from)
+            .collect::<Vec<_>>();
+        if events.is_empty() && !self.include_transactions {
+            return None;
+        }
+        Some(events)
+    }
+}
+
+/// Normalize the address of the type prefix, so `0x03::coin` matches the event type `0x3::coin::DepositEvent`
+fn normalize_type_prefix(prefix: &str) -> Result<String> {
+    let (address, rest) = match prefix.split_once("::") {
+        Some((address, rest)) => (address, Some(rest)),
+        None => (prefix, None),
+    };
+    let address = AccountAddress::from_hex_literal(address)
+        .map_err(|e| anyhow!("Invalid webhook event type {}: {}", prefix, e))?;
+    let address = format!("0x{}", address.short_str_lossless());
+    Ok(match rest {
+        Some(rest) => format!("{}::{}", address, rest),
+        None => address,
+    })
+}
+
+/// Sign the payload with the secret of the webhook, the signature is `hex(hmac_sha256(secret, "{timestamp}.{body}"))`.
+/// The timestamp is signed to prevent the payload from being replayed.
+pub fn sign_webhook_payload(secret: &str, timestamp: u64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// The notifications waiting to be delivered to a webhook, they are delivered one by one in the tx order
+struct WebhookQueue {
+    webhook: Webhook,
+    sender: mpsc::Sender<(u64, Vec<u8>)>,
+}
+
+impl WebhookQueue {
+    fn new(webhook: Webhook, queue_size: usize) -> (Self, mpsc::Receiver<(u64, Vec<u8>)>) {
+        let (sender, receiver) = mpsc::channel(queue_size.max(1));
+        (Self { webhook, sender }, receiver)
+    }
+
+    /// Enqueue the notification without waiting, it is dropped if the queue is full
+    fn push(&self, tx_order: u64, body: Vec<u8>) -> bool {
+        match self.sender.try_send((tx_order, body)) {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(
+                    "Drop the notification of the tx {} to the webhook {}: {}",
+                    tx_order, self.webhook.url, e
+                );
+                false
+            }
+        }
+    }
+}
+
+/// Post the executed transactions and events to the configured webhooks.
+/// Every webhook has a bounded queue delivered by a background task in the tx order, the failed deliveries
+/// are retried with exponential backoff and dropped after the max retries. The notifications are dropped
+/// while the queue of a webhook is full, so a slow webhook never blocks the transaction execution.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    queues: Arc<Vec<WebhookQueue>>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(config: &WebhookConfig) -> Result<Self> {
+        let webhooks = config
+            .webhooks
+            .iter()
+            .map(Webhook::new)
+            .collect::<Result<Vec<_>>>()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+        let queues = webhooks
+            .into_iter()
+            .map(|webhook| {
+                let (queue, receiver) = WebhookQueue::new(webhook.clone(), config.queue_size);
+                let worker = WebhookWorker {
+                    webhook,
+                    max_retries: config.max_retries,
+                    initial_backoff: Duration::from_millis(config.initial_backoff_ms),
+                    client: client.clone(),
+                };
+                tokio::spawn(worker.run(receiver));
+                queue
+            })
+            .collect::<Vec<_>>();
+        Ok(Self {
+            queues: Arc::new(queues),
+        })
+    }
+
+    pub fn notify(
+        &self,
+        tx_order: u64,
+        sender: AccountAddress,
+        execution_info: TransactionExecutionInfoView,
+        events: &[Event],
+    ) {
+        for queue in self.queues.iter() {
+            let Some(events) = queue.webhook.filter(&sender, events) else {
+                continue;
+            };
+            let payload = WebhookPayload {
+                tx_order,
+                sender: sender.to_hex_literal(),
+                execution_info: execution_info.clone(),
+                events,
+            };
+            let body = match serde_json::to_vec(&payload) {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("Failed to serialize the webhook payload: {:?}", e);
+                    continue;
+                }
+            };
+            queue.push(tx_order, body);
+        }
+    }
+}
+
+/// Deliver the queued notifications of a webhook one by one
+struct WebhookWorker {
+    webhook: Webhook,
+    max_retries: u32,
+    initial_backoff: Duration,
+    client: reqwest::Client,
+}
+
+impl WebhookWorker {
+    async fn run(self, mut receiver: mpsc::Receiver<(u64, Vec<u8>)>) {
+        while let Some((tx_order, body)) = receiver.recv().await {
+            self.deliver(tx_order, body).await;
+        }
+    }
+
+    async fn deliver(&self, tx_order: u64, body: Vec<u8>) {
+        let webhook = &self.webhook;
+        let mut backoff = self.initial_backoff;
+        for attempt in 0..=self.max_retries {
+            if attempt > 0 {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_WEBHOOK_BACKOFF);
+            }
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            let result = self
+                .client
+                .post(&webhook.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+                .header(
+                    WEBHOOK_SIGNATURE_HEADER,
+                    sign_webhook_payload(&webhook.secret, timestamp, &body),
+                )
+                .body(body.clone())
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            match result {
+                Ok(_) => {
+                    debug!(
+                        "Notified the tx {} to the webhook {}",
+                        tx_order, webhook.url
+                    );
+                    return;
+                }
+                Err(e) => warn!(
+                    "Failed to notify the tx {} to the webhook {}, attempt {}: {:?}",
+                    tx_order,
+                    webhook.url,
+                    attempt + 1,
+                    e
+                ),
+            }
+        }
+        warn!(
+            "Drop the notification of the tx {} to the webhook {} after {} retries",
+            tx_order, webhook.url, self.max_retries
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::StructTag;
+    use moveos_types::moveos_std::event::EventID;
+    use moveos_types::moveos_std::object::ObjectID;
+
+    fn new_event(module: &str, name: &str) -> Event {
+        Event::new(
+            EventID::new(ObjectID::from(AccountAddress::ONE), 0),
+            StructTag {
+                address: AccountAddress::from_hex_literal("0x3").unwrap(),
+                module: Identifier::new(module).unwrap(),
+                name: Identifier::new(name).unwrap(),
+                type_params: vec![],
+            },
+            vec![],
+            0,
+        )
+    }
+
+    #[test]
+    fn test_webhook_filter() {
+        let sender = AccountAddress::from_hex_literal("0x42").unwrap();
+        let webhook = Webhook::new(&WebhookTarget {
+            url: "http://localhost".to_owned(),
+            secret: "secret".to_owned(),
+            event_types: vec!["0x03::coin".to_owned()],
+            senders: vec!["0x42".to_owned()],
+            include_transactions: false,
+        })
+        .unwrap();
+        let events = vec![
+            new_event("coin", "DepositEvent"),
+            new_event("account", "AccountCreatedEvent"),
+        ];
+        assert_eq!(webhook.filter(&sender, &events).unwrap().len(), 1);
+        assert!(webhook.filter(&AccountAddress::ONE, &events).is_none());
+        assert!(webhook
+            .filter(&sender, &[new_event("account", "AccountCreatedEvent")])
+            .is_none());
+    }
+
+    #[test]
+    fn test_webhook_queue() {
+        let webhook = Webhook::new(&WebhookTarget {
+            url: "http://localhost".to_owned(),
+            secret: "secret".to_owned(),
+            event_types: vec![],
+            senders: vec![],
+            include_transactions: true,
+        })
+        .unwrap();
+        let (queue, mut receiver) = WebhookQueue::new(webhook, 2);
+        assert!(queue.push(1, b"1".to_vec()));
+        assert!(queue.push(2, b"2".to_vec()));
+        assert!(!queue.push(3, b"3".to_vec()));
+        assert_eq!(receiver.try_recv().unwrap().0, 1);
+        assert_eq!(receiver.try_recv().unwrap().0, 2);
+        assert!(receiver.try_recv().is_err());
+        assert!(queue.push(4, b"4".to_vec()));
+        assert_eq!(receiver.try_recv().unwrap().0, 4);
+    }
+
+    #[test]
+    fn test_sign_webhook_payload() {
+        let signature = sign_webhook_payload("secret", 1, b"{}");
+        assert_eq!(signature, sign_webhook_payload("secret", 1, b"{}"));
+        assert_ne!(signature, sign_webhook_payload("secret", 2, b"{}"));
+        assert_ne!(signature, sign_webhook_payload("other", 1, b"{}"));
+    }
+}
@@ -2,18 +2,28 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
+    AcceptTransactionMessage, GetPendingTransactionsMessage, GetSequencerOrderMessage,
+    GetTransactionByHashMessage, GetTransactionsByHashMessage,
     GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
-    GetTxSequenceInfosMessage, TransactionSequenceMessage,
+    GetTxSequenceInfosMessage, IsWritableMessage, RecordModuleGasMessage,
+    RemovePendingTransactionMessage, RestorePendingTransactionMessage, SetReadOnlyMessage,
+    TransactionSequenceMessage, UpdateChainPauseMessage, UpdateRelayersMessage,
 };
-use anyhow::Result;
+use crate::quota::ModuleGasQuotas;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
-use moveos_types::h256::{self, H256};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_store::batch_store::BatchStore;
 use rooch_store::meta_store::MetaStore;
+use rooch_store::pending_tx_store::PendingTxStore;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::address::{MultiChainAddress, RoochAddress};
+use rooch_types::sequencer::{
+    PendingTransaction, ProposerBacklog, SequencerOrder, TxInclusionReceipt,
+};
 use rooch_types::transaction::{
     TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
 };
@@ -21,12 +31,27 @@ use rooch_types::{
     crypto::{RoochKeyPair, Signature},
     transaction::AbstractTransaction,
 };
-use tracing::info;
+use std::collections::BTreeSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
 
 pub struct SequencerActor {
     last_order: u64,
     sequencer_key: RoochKeyPair,
     rooch_store: RoochStore,
+    /// Only the system transactions sent by the sequencer or the relayers are sequenced when the chain is paused
+    chain_paused: bool,
+    /// The relayers registered as the system senders in the on-chain `chain_pause` module
+    relayers: BTreeSet<MultiChainAddress>,
+    /// All the transactions are rejected when the node is read only, the reason is returned to the sender
+    read_only: Option<String>,
+    module_gas_quotas: ModuleGasQuotas,
+    /// The accept order of the next accepted transaction, it continues after the pending transactions
+    next_accept_order: u64,
+    /// The gas used by the executed transactions waiting to be proposed
+    proposer_backlog: ProposerBacklog,
+    /// The transactions are rejected when the gas used by the transactions waiting to be proposed exceeds it
+    max_buffered_gas: Option<u64>,
 }
 
 impl SequencerActor {
@@ -41,41 +66,192 @@ impl SequencerActor {
             .map(|order| order.last_order);
         let last_order = last_order_opt.unwrap_or(0u64);
         info!("Load latest sequencer order {:?}", last_order);
+        let next_accept_order = rooch_store
+            .list_pending_transactions()?
+            .last()
+            .map(|(_, pending_tx)| pending_tx.accept_order + 1)
+            .unwrap_or(0);
         Ok(Self {
             last_order,
             sequencer_key,
             rooch_store,
+            chain_paused: false,
+            relayers: BTreeSet::new(),
+            read_only: None,
+            module_gas_quotas: ModuleGasQuotas::default(),
+            next_accept_order,
+            proposer_backlog: ProposerBacklog::default(),
+            max_buffered_gas: None,
+        })
+    }
+
+    /// Enforce the per-block gas quotas of the module addresses
+    pub fn with_module_gas_quotas(mut self, module_gas_quotas: ModuleGasQuotas) -> Self {
+        self.module_gas_quotas = module_gas_quotas;
+        self
+    }
+
+    /// Apply the backpressure of the proposer, the transactions are rejected while the gas used by the executed transactions
+    /// waiting to be proposed exceeds `max_buffered_gas`, so the proposer lag is bounded under the sustained load.
+    pub fn with_proposer_backlog(
+        mut self,
+        proposer_backlog: ProposerBacklog,
+        max_buffered_gas: Option<u64>,
+    ) -> Self {
+        self.proposer_backlog = proposer_backlog;
+        self.max_buffered_gas = max_buffered_gas;
+        self
+    }
+
+    fn is_backlogged(&self) -> bool {
+        self.max_buffered_gas.map_or(false, |max_buffered_gas| {
+            self.proposer_backlog.buffered_gas_used() > max_buffered_gas
         })
     }
+
+    /// Reject the call to the module address if its gas quota of the current block is exhausted.
+    /// The current block starts after the last proposed batch.
+    fn check_module_gas_quota(&mut self, module_address: Option<AccountAddress>) -> Result<()> {
+        let module_address = match module_address {
+            Some(module_address) if !self.module_gas_quotas.is_empty() => module_address,
+            _ => return Ok(()),
+        };
+        if let Some(batch) = self.rooch_store.get_last_batch()? {
+            self.module_gas_quotas
+                .start_block(batch.first_tx_order + batch.tx_hashes.len() as u64);
+        }
+        self.module_gas_quotas.check(&module_address)
+    }
+
+    fn next_order(&self) -> Result<u64> {
+        if self.last_order != 0 {
+            return Ok(self.last_order + 1);
+        }
+        let last_order_opt = self
+            .rooch_store
+            .get_meta_store()
+            .get_sequencer_order()?
+            .map(|order| order.last_order);
+        Ok(match last_order_opt {
+            Some(last_order) => last_order + 1,
+            None => 0,
+        })
+    }
+
+    /// The system senders are the sequencer and the relayers, the same as `chain_pause::is_system_sender`
+    fn is_system_sender(&self, sender: &MultiChainAddress) -> bool {
+        *sender == MultiChainAddress::from(self.sequencer_key.public().address())
+            || self.relayers.contains(sender)
+    }
+
+    /// Reject the transaction if the node is read only, or it is not a system transaction and the chain is paused
+    /// or the proposer lags behind
+    fn check_accepting(&self, tx: &TypedTransaction) -> Result<()> {
+        if let Some(reason) = &self.read_only {
+            bail!(
+                "The node is read only, the transaction {:?} is rejected: {}",
+                tx.tx_hash(),
+                reason
+            );
+        }
+        if self.is_system_sender(&tx.sender()) {
+            return Ok(());
+        }
+        if self.chain_paused {
+            bail!(
+                "The chain is paused, the transaction {:?} from {} is rejected",
+                tx.tx_hash(),
+                tx.sender()
+            );
+        }
+        if self.is_backlogged() {
+            bail!(
+                "The proposer lags behind, the executed transactions waiting to be proposed use {} gas, \
+                the transaction {:?} is rejected, retry later",
+                self.proposer_backlog.buffered_gas_used(),
+                tx.tx_hash()
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Actor for SequencerActor {}
 
 #[async_trait]
-impl Handler<TransactionSequenceMessage> for SequencerActor {
+impl Handler<AcceptTransactionMessage> for SequencerActor {
     async fn handle(
         &mut self,
-        msg: TransactionSequenceMessage,
+        msg: AcceptTransactionMessage,
         _ctx: &mut ActorContext,
-    ) -> Result<TransactionSequenceInfo> {
+    ) -> Result<()> {
         let tx = msg.tx;
-        let tx_order = if self.last_order == 0 {
-            let last_order_opt = self
-                .rooch_store
-                .get_meta_store()
-                .get_sequencer_order()?
-                .map(|order| order.last_order);
-            match last_order_opt {
-                Some(last_order) => last_order + 1,
-                None => 0,
-            }
-        } else {
-            self.last_order + 1
+        self.check_accepting(&tx)?;
+        let tx_hash = tx.tx_hash();
+        // The recovered transaction is accepted again, it keeps its order among the pending transactions
+        if self.rooch_store.get_pending_transaction(tx_hash)?.is_some() {
+            return Ok(());
+        }
+        let pending_tx = PendingTransaction {
+            tx,
+            accept_order: self.next_accept_order,
         };
+        self.rooch_store
+            .save_pending_transaction(tx_hash, pending_tx)?;
+        self.next_accept_order += 1;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Handler<GetPendingTransactionsMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: GetPendingTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<PendingTransaction>> {
+        let GetPendingTransactionsMessage {} = msg;
+        let pending_txs = self.rooch_store.list_pending_transactions()?;
+        let tx_hashes = pending_txs
+            .iter()
+            .map(|(hash, _)| *hash)
+            .collect::<Vec<_>>();
+        let mappings = self
+            .rooch_store
+            .get_transaction_store()
+            .multi_get_tx_sequence_info_mapping_by_hash(tx_hashes)?;
+        let mut txs = vec![];
+        for ((hash, pending_tx), mapping) in pending_txs.into_iter().zip(mappings) {
+            // The sequencer may stop between sequencing the transaction and removing the pending one
+            if mapping.is_some() {
+                self.rooch_store.remove_pending_transaction(hash)?;
+            } else {
+                txs.push(pending_tx);
+            }
+        }
+        Ok(txs)
+    }
+}
+
+#[async_trait]
+impl Handler<TransactionSequenceMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: TransactionSequenceMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<(TransactionSequenceInfo, TxInclusionReceipt)> {
+        let TransactionSequenceMessage { tx, module_address } = msg;
         let hash = tx.tx_hash();
-        let mut witness_data = hash.as_ref().to_vec();
-        witness_data.extend(tx_order.to_le_bytes().iter());
-        let witness_hash = h256::sha3_256_of(&witness_data);
+        if let Err(e) = self
+            .check_accepting(&tx)
+            .and_then(|_| self.check_module_gas_quota(module_address))
+        {
+            // The rejection is returned to the sender, so the transaction is not pending anymore
+            self.rooch_store.remove_pending_transaction(hash)?;
+            return Err(e);
+        }
+        let tx_order = self.next_order()?;
+        let witness_hash = TransactionSequenceInfo::tx_order_witness_hash(hash, tx_order);
         let tx_order_signature = Signature::new_hashed(&witness_hash.0, &self.sequencer_key).into();
         self.last_order = tx_order;
 
@@ -96,7 +272,88 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
         };
         self.rooch_store
             .save_tx_sequence_info(tx_sequence_info.clone())?;
-        Ok(tx_sequence_info)
+        self.rooch_store.remove_pending_transaction(hash)?;
+        // The receipt is signed after the tx order is persisted, so it never promises an order assigned to another transaction
+        let sequenced_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        let receipt = TxInclusionReceipt::new(hash, tx_order, sequenced_at, &self.sequencer_key);
+        Ok((tx_sequence_info, receipt))
+    }
+}
+
+#[async_trait]
+impl Handler<RemovePendingTransactionMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: RemovePendingTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.rooch_store.remove_pending_transaction(msg.tx_hash)
+    }
+}
+
+#[async_trait]
+impl Handler<RestorePendingTransactionMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: RestorePendingTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        let RestorePendingTransactionMessage { pending_tx } = msg;
+        self.rooch_store
+            .save_pending_transaction(pending_tx.tx.tx_hash(), pending_tx)
+    }
+}
+
+#[async_trait]
+impl Handler<IsWritableMessage> for SequencerActor {
+    async fn handle(&mut self, msg: IsWritableMessage, _ctx: &mut ActorContext) -> bool {
+        let IsWritableMessage {} = msg;
+        self.read_only.is_none() && !self.chain_paused && !self.is_backlogged()
+    }
+}
+
+#[async_trait]
+impl Handler<UpdateChainPauseMessage> for SequencerActor {
+    async fn handle(&mut self, msg: UpdateChainPauseMessage, _ctx: &mut ActorContext) {
+        if self.chain_paused != msg.paused {
+            info!("The chain pause state is updated, paused: {}", msg.paused);
+        }
+        self.chain_paused = msg.paused;
+    }
+}
+
+#[async_trait]
+impl Handler<UpdateRelayersMessage> for SequencerActor {
+    async fn handle(&mut self, msg: UpdateRelayersMessage, _ctx: &mut ActorContext) {
+        info!("The relayers are updated: {:?}", msg.relayers);
+        self.relayers = msg
+            .relayers
+            .into_iter()
+            .map(|relayer| MultiChainAddress::from(RoochAddress::from(relayer)))
+            .collect();
+    }
+}
+
+#[async_trait]
+impl Handler<SetReadOnlyMessage> for SequencerActor {
+    async fn handle(&mut self, msg: SetReadOnlyMessage, _ctx: &mut ActorContext) {
+        if let Some(reason) = &msg.reason {
+            warn!("The node is read only: {}", reason);
+        }
+        self.read_only = msg.reason;
+    }
+}
+
+#[async_trait]
+impl Handler<RecordModuleGasMessage> for SequencerActor {
+    async fn handle(&mut self, msg: RecordModuleGasMessage, _ctx: &mut ActorContext) {
+        let RecordModuleGasMessage {
+            tx_order,
+            module_address,
+            gas_used,
+        } = msg;
+        self.module_gas_quotas
+            .record(tx_order, module_address, gas_used);
     }
 }
 
@@ -175,3 +432,277 @@ impl Handler<GetSequencerOrderMessage> for SequencerActor {
         self.rooch_store.get_meta_store().get_sequencer_order()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proxy::SequencerProxy;
+    use coerce::actor::{system::ActorSystem, IntoActor};
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use moveos_types::transaction::MoveAction;
+    use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+    use rooch_types::framework::empty::Empty;
+    use rooch_types::transaction::rooch::RoochTransactionData;
+
+    fn random_tx(kp: &RoochKeyPair) -> TypedTransaction {
+        let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+        TypedTransaction::Rooch(
+            RoochTransactionData::new_for_test(RoochAddress::random(), 0, action).sign(kp),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_receipt_tx_order() {
+        let actor_system = ActorSystem::global_system();
+        let rooch_store = RoochStore::mock_rooch_store().unwrap();
+        let sequencer_key =
+            RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(sequencer_key.copy(), rooch_store.clone(), true)
+                .unwrap()
+                .into_actor(Some("SequencerReceiptTest"), &actor_system)
+                .await
+                .unwrap()
+                .into(),
+        );
+
+        // The transactions accepted first may be sequenced later, the receipt carries the assigned tx order
+        let tx1 = random_tx(&sequencer_key);
+        let tx2 = random_tx(&sequencer_key);
+        sequencer.accept_transaction(tx1.clone()).await.unwrap();
+        sequencer.accept_transaction(tx2.clone()).await.unwrap();
+        let (info2, receipt2) = sequencer
+            .sequence_transaction(tx2.clone(), None)
+            .await
+            .unwrap();
+        let (info1, receipt1) = sequencer
+            .sequence_transaction(tx1.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(receipt2.tx_hash, tx2.tx_hash());
+        assert_eq!(receipt2.tx_order, info2.tx_order);
+        assert_eq!(receipt1.tx_hash, tx1.tx_hash());
+        assert_eq!(receipt1.tx_order, info1.tx_order);
+        assert_eq!(receipt1.tx_order, receipt2.tx_order + 1);
+        assert_eq!(
+            receipt1.sequencer,
+            RoochAddress::from(&sequencer_key.public())
+        );
+
+        // The tx orders of the receipts are persisted
+        let mappings = rooch_store
+            .get_transaction_store()
+            .multi_get_tx_sequence_info_mapping_by_hash(vec![tx1.tx_hash(), tx2.tx_hash()])
+            .unwrap();
+        assert_eq!(mappings[0].as_ref().unwrap().tx_order, receipt1.tx_order);
+        assert_eq!(mappings[1].as_ref().unwrap().tx_order, receipt2.tx_order);
+
+        // The restarted sequencer never promises the persisted tx orders again
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(sequencer_key.copy(), rooch_store, false)
+                .unwrap()
+                .into_actor(Some("SequencerReceiptTestRestarted"), &actor_system)
+                .await
+                .unwrap()
+                .into(),
+        );
+        let tx3 = random_tx(&sequencer_key);
+        sequencer.accept_transaction(tx3.clone()).await.unwrap();
+        let (_, receipt3) = sequencer.sequence_transaction(tx3, None).await.unwrap();
+        assert_eq!(receipt3.tx_order, receipt1.tx_order + 1);
+    }
+
+    #[tokio::test]
+    async fn test_pending_transactions_accept_order() {
+        let actor_system = ActorSystem::global_system();
+        let rooch_store = RoochStore::mock_rooch_store().unwrap();
+        let sequencer_key =
+            RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(sequencer_key.copy(), rooch_store.clone(), true)
+                .unwrap()
+                .into_actor(Some("SequencerAcceptOrderTest"), &actor_system)
+                .await
+                .unwrap()
+                .into(),
+        );
+
+        // The transactions accepted within the same millisecond keep the accepted order
+        let txs = (0..10)
+            .map(|_| random_tx(&sequencer_key))
+            .collect::<Vec<_>>();
+        for tx in &txs {
+            sequencer.accept_transaction(tx.clone()).await.unwrap();
+        }
+        // Accepting the pending transaction again keeps its order
+        sequencer.accept_transaction(txs[0].clone()).await.unwrap();
+        let pending_hashes = |pending_txs: Vec<PendingTransaction>| {
+            pending_txs
+                .into_iter()
+                .map(|pending_tx| pending_tx.tx.tx_hash())
+                .collect::<Vec<_>>()
+        };
+        let tx_hashes = txs.iter().map(|tx| tx.tx_hash()).collect::<Vec<_>>();
+        assert_eq!(
+            pending_hashes(sequencer.get_pending_transactions().await.unwrap()),
+            tx_hashes
+        );
+
+        // The pending transactions are kept while the node is read only, and the new ones are rejected
+        sequencer
+            .set_read_only(Some("test".to_owned()))
+            .await
+            .unwrap();
+        assert!(!sequencer.is_writable().await.unwrap());
+        assert!(sequencer
+            .accept_transaction(random_tx(&sequencer_key))
+            .await
+            .is_err());
+        let pending_txs = sequencer.get_pending_transactions().await.unwrap();
+        assert_eq!(pending_txs.len(), txs.len());
+        // The pending transaction rejected during the recovery is restored with its accept order
+        sequencer
+            .sequence_transaction(txs[0].clone(), None)
+            .await
+            .unwrap_err();
+        sequencer
+            .restore_pending_transaction(pending_txs[0].clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            pending_hashes(sequencer.get_pending_transactions().await.unwrap()),
+            tx_hashes
+        );
+
+        // The restarted sequencer accepts the new transactions after the pending ones
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(sequencer_key.copy(), rooch_store, false)
+                .unwrap()
+                .into_actor(Some("SequencerAcceptOrderTestRestarted"), &actor_system)
+                .await
+                .unwrap()
+                .into(),
+        );
+        assert!(sequencer.is_writable().await.unwrap());
+        let new_tx = random_tx(&sequencer_key);
+        sequencer.accept_transaction(new_tx.clone()).await.unwrap();
+        let mut expected_hashes = tx_hashes;
+        expected_hashes.push(new_tx.tx_hash());
+        assert_eq!(
+            pending_hashes(sequencer.get_pending_transactions().await.unwrap()),
+            expected_hashes
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proposer_backpressure() {
+        let actor_system = ActorSystem::global_system();
+        let sequencer_key =
+            RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let backlog = ProposerBacklog::default();
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(
+                sequencer_key.copy(),
+                RoochStore::mock_rooch_store().unwrap(),
+                true,
+            )
+            .unwrap()
+            .with_proposer_backlog(backlog.clone(), Some(100))
+            .into_actor(Some("SequencerBackpressureTest"), &actor_system)
+            .await
+            .unwrap()
+            .into(),
+        );
+
+        backlog.set_buffered_gas_used(100);
+        assert!(sequencer.is_writable().await.unwrap());
+        let tx = random_tx(&sequencer_key);
+        sequencer.accept_transaction(tx.clone()).await.unwrap();
+
+        // The transactions are rejected while the proposer lags behind, except the system transactions
+        backlog.set_buffered_gas_used(101);
+        assert!(!sequencer.is_writable().await.unwrap());
+        assert!(sequencer
+            .accept_transaction(random_tx(&sequencer_key))
+            .await
+            .is_err());
+        assert!(sequencer
+            .sequence_transaction(tx.clone(), None)
+            .await
+            .is_err());
+        let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+        let system_tx = TypedTransaction::Rooch(
+            RoochTransactionData::new_for_test(
+                RoochAddress::from(&sequencer_key.public()),
+                0,
+                action,
+            )
+            .sign(&sequencer_key),
+        );
+        sequencer
+            .accept_transaction(system_tx.clone())
+            .await
+            .unwrap();
+        sequencer
+            .sequence_transaction(system_tx, None)
+            .await
+            .unwrap();
+
+        // The transactions are accepted again once the proposer catches up
+        backlog.set_buffered_gas_used(0);
+        assert!(sequencer.is_writable().await.unwrap());
+        sequencer.accept_transaction(tx.clone()).await.unwrap();
+        sequencer.sequence_transaction(tx, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_relayer_accepted_when_paused() {
+        let actor_system = ActorSystem::global_system();
+        let sequencer_key =
+            RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let relayer_key = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer = SequencerProxy::new(
+            SequencerActor::new(
+                sequencer_key.copy(),
+                RoochStore::mock_rooch_store().unwrap(),
+                true,
+            )
+            .unwrap()
+            .into_actor(Some("SequencerRelayerPausedTest"), &actor_system)
+            .await
+            .unwrap()
+            .into(),
+        );
+        let relayer_tx = |kp: &RoochKeyPair| {
+            let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+            TypedTransaction::Rooch(
+                RoochTransactionData::new_for_test(RoochAddress::from(&kp.public()), 0, action)
+                    .sign(kp),
+            )
+        };
+
+        sequencer.update_chain_pause(true).await.unwrap();
+        assert!(sequencer
+            .accept_transaction(relayer_tx(&relayer_key))
+            .await
+            .is_err());
+
+        // The registered relayer is a system sender as the sequencer, its transactions are accepted when paused
+        sequencer
+            .update_relayers(vec![RoochAddress::from(&relayer_key.public()).into()])
+            .await
+            .unwrap();
+        let tx = relayer_tx(&relayer_key);
+        sequencer.accept_transaction(tx.clone()).await.unwrap();
+        sequencer.sequence_transaction(tx, None).await.unwrap();
+        sequencer
+            .accept_transaction(relayer_tx(&sequencer_key))
+            .await
+            .unwrap();
+        assert!(sequencer
+            .accept_transaction(random_tx(&relayer_key))
+            .await
+            .is_err());
+    }
+}
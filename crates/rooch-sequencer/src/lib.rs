@@ -3,4 +3,6 @@
 
 pub mod actor;
 pub mod messages;
+pub mod priority;
 pub mod proxy;
+pub mod quota;
@@ -3,8 +3,9 @@
 
 use anyhow::Result;
 use coerce::actor::message::Message;
+use move_core_types::account_address::AccountAddress;
 use moveos_types::h256::H256;
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::sequencer::{PendingTransaction, SequencerOrder, TxInclusionReceipt};
 use rooch_types::transaction::TransactionSequenceInfoMapping;
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 use serde::{Deserialize, Serialize};
@@ -13,10 +14,54 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TransactionSequenceMessage {
     pub tx: TypedTransaction,
+    /// The address of the module whose function is called by the transaction, it is checked against the module gas quotas
+    pub module_address: Option<AccountAddress>,
 }
 
+/// The sequencer returns the sequence info and the inclusion receipt signed with the assigned tx order
 impl Message for TransactionSequenceMessage {
-    type Result = Result<TransactionSequenceInfo>;
+    type Result = Result<(TransactionSequenceInfo, TxInclusionReceipt)>;
+}
+
+/// Persist the validated transaction before it is scheduled to the sequencer,
+/// so it is not dropped if the sequencer restarts before sequencing it.
+/// The transaction which is pending already keeps its accept order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AcceptTransactionMessage {
+    pub tx: TypedTransaction,
+}
+
+impl Message for AcceptTransactionMessage {
+    type Result = Result<()>;
+}
+
+/// Get the accepted but not sequenced transactions in the accepted order
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPendingTransactionsMessage {}
+
+impl Message for GetPendingTransactionsMessage {
+    type Result = Result<Vec<PendingTransaction>>;
+}
+
+/// Keep the recovered transaction pending with its accept order,
+/// it is rejected because the sequencer turns read only or the chain is paused during the recovery
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RestorePendingTransactionMessage {
+    pub pending_tx: PendingTransaction,
+}
+
+impl Message for RestorePendingTransactionMessage {
+    type Result = Result<()>;
+}
+
+/// Remove the pending transaction which fails the revalidation
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemovePendingTransactionMessage {
+    pub tx_hash: H256,
+}
+
+impl Message for RemovePendingTransactionMessage {
+    type Result = Result<()>;
 }
 
 /// Get Transaction By Hash Message
@@ -72,3 +117,54 @@ pub struct GetSequencerOrderMessage {}
 impl Message for GetSequencerOrderMessage {
     type Result = Result<Option<SequencerOrder>>;
 }
+
+/// Update the chain pause state in the sequencer, it is loaded from the on-chain `chain_pause` module
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateChainPauseMessage {
+    pub paused: bool,
+}
+
+impl Message for UpdateChainPauseMessage {
+    type Result = ();
+}
+
+/// Update the relayers registered as the system senders, they are loaded from the on-chain `chain_pause` module
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateRelayersMessage {
+    pub relayers: Vec<AccountAddress>,
+}
+
+impl Message for UpdateRelayersMessage {
+    type Result = ();
+}
+
+/// Return true if the sequencer accepts the transactions of all the senders,
+/// the node is not read only, the chain is not paused and the proposer does not lag behind
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IsWritableMessage {}
+
+impl Message for IsWritableMessage {
+    type Result = bool;
+}
+
+/// Set the node read only with the reason, or writable with `None`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetReadOnlyMessage {
+    pub reason: Option<String>,
+}
+
+impl Message for SetReadOnlyMessage {
+    type Result = ();
+}
+
+/// Record the gas used by the call to the module address, it is aggregated in the module gas quota of the current block
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordModuleGasMessage {
+    pub tx_order: u64,
+    pub module_address: AccountAddress,
+    pub gas_used: u64,
+}
+
+impl Message for RecordModuleGasMessage {
+    type Result = ();
+}
@@ -0,0 +1,305 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::ModuleId;
+use moveos_types::transaction::MoveAction;
+use parking_lot::Mutex;
+use rooch_types::addresses::{BITCOIN_MOVE_ADDRESS, ROOCH_FRAMEWORK_ADDRESS};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
+use std::sync::Arc;
+use tokio::sync::oneshot;
+
+/// The max system transactions sequenced in a row while the user transactions are waiting,
+/// then a user transaction is sequenced, so the user transactions are not starved under the relay load.
+pub const DEFAULT_MAX_CONSECUTIVE_SYSTEM_TXS: u64 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxPriority {
+    /// The protocol-critical transactions, such as the Bitcoin relay, the oracle and the governance transactions
+    System,
+    User,
+}
+
+/// Classify the transactions by the sender and the module of the called function, only the calls to the
+/// protocol-critical modules sent by the node's own accounts, such as the sequencer, are system transactions,
+/// so the other users can not jump the queue by calling these modules.
+#[derive(Debug, Clone)]
+pub struct TxPriorityClassifier {
+    system_senders: BTreeSet<AccountAddress>,
+    system_modules: BTreeSet<ModuleId>,
+}
+
+impl TxPriorityClassifier {
+    pub fn new(sequencer: AccountAddress) -> Self {
+        let system_modules = [
+            (BITCOIN_MOVE_ADDRESS, "light_client"),
+            (ROOCH_FRAMEWORK_ADDRESS, "ethereum_light_client"),
+            (ROOCH_FRAMEWORK_ADDRESS, "upgrade"),
+            (ROOCH_FRAMEWORK_ADDRESS, "chain_pause"),
+            (ROOCH_FRAMEWORK_ADDRESS, "module_publish_policy"),
+            (ROOCH_FRAMEWORK_ADDRESS, "sequencer_key_registry"),
+        ]
+        .into_iter()
+        .map(|(address, name)| {
+            ModuleId::new(
+                address,
+                Identifier::new(name).expect("module name should be valid"),
+            )
+        })
+        .collect();
+        Self {
+            system_senders: BTreeSet::from([sequencer]),
+            system_modules,
+        }
+    }
+
+    /// Add a sender of the system transactions, such as the relayer
+    pub fn with_sender(mut self, sender: AccountAddress) -> Self {
+        self.system_senders.insert(sender);
+        self
+    }
+
+    pub fn classify(&self, sender: &AccountAddress, action: &MoveAction) -> TxPriority {
+        if !self.system_senders.contains(sender) {
+            return TxPriority::User;
+        }
+        match action {
+            MoveAction::Function(call)
+                if self.system_modules.contains(&call.function_id.module_id) =>
+            {
+                TxPriority::System
+            }
+            _ => TxPriority::User,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxSchedulerStats {
+    pub system_sequenced: u64,
+    pub user_sequenced: u64,
+    /// The user transactions sequenced ahead of the waiting system transactions by the starvation protection
+    pub starvation_grants: u64,
+    pub system_waiting: u64,
+    pub user_waiting: u64,
+}
+
+struct SchedulerState {
+    max_consecutive_system: u64,
+    busy: bool,
+    consecutive_system: u64,
+    system_waiting: VecDeque<oneshot::Sender<TxSchedulerPermit>>,
+    user_waiting: VecDeque<oneshot::Sender<TxSchedulerPermit>>,
+    stats: TxSchedulerStats,
+}
+
+impl SchedulerState {
+    fn record(&mut self, priority: TxPriority) {
+        match priority {
+            TxPriority::System => {
+                self.consecutive_system += 1;
+                self.stats.system_sequenced += 1;
+            }
+            TxPriority::User => {
+                self.consecutive_system = 0;
+                self.stats.user_sequenced += 1;
+            }
+        }
+    }
+
+    /// Hand over the permit to the next waiting transaction, the system transactions first
+    fn release(&mut self, inner: &Arc<Mutex<SchedulerState>>) {
+        loop {
+            let starved = !self.user_waiting.is_empty()
+                && self.consecutive_system >= self.max_consecutive_system;
+            let (priority, sender) = match self.system_waiting.pop_front() {
+                Some(sender) if !starved => (TxPriority::System, sender),
+                system => {
+                    if let Some(sender) = system {
+                        self.system_waiting.push_front(sender);
+                    }
+                    match self.user_waiting.pop_front() {
+                        Some(sender) => {
+                            if !self.system_waiting.is_empty() {
+                                self.stats.starvation_grants += 1;
+                            }
+                            (TxPriority::User, sender)
+                        }
+                        None => {
+                            self.busy = false;
+                            return;
+                        }
+                    }
+                }
+            };
+            let permit = TxSchedulerPermit {
+                inner: Some(inner.clone()),
+            };
+            match sender.send(permit) {
+                Ok(()) => {
+                    self.record(priority);
+                    return;
+                }
+                // The waiting transaction is cancelled, disarm the permit and try the next one
+                Err(mut permit) => {
+                    permit.inner.take();
+                }
+            }
+        }
+    }
+}
+
+/// The permit to sequence and execute a transaction, the next transaction is scheduled when it is dropped
+pub struct TxSchedulerPermit {
+    inner: Option<Arc<Mutex<SchedulerState>>>,
+}
+
+impl Drop for TxSchedulerPermit {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            inner.lock().release(&inner);
+        }
+    }
+}
+
+/// Schedule the transactions to the sequencer one by one.
+/// The system transactions are always scheduled ahead of the waiting user transactions,
+/// except that a user transaction is scheduled after `max_consecutive_system` system transactions in a row.
+#[derive(Clone)]
+pub struct TxScheduler {
+    inner: Arc<Mutex<SchedulerState>>,
+}
+
+impl Default for TxScheduler {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONSECUTIVE_SYSTEM_TXS)
+    }
+}
+
+impl TxScheduler {
+    pub fn new(max_consecutive_system: u64) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SchedulerState {
+                max_consecutive_system: max_consecutive_system.max(1),
+                busy: false,
+                consecutive_system: 0,
+                system_waiting: VecDeque::new(),
+                user_waiting: VecDeque::new(),
+                stats: TxSchedulerStats::default(),
+            })),
+        }
+    }
+
+    pub async fn acquire(&self, priority: TxPriority) -> TxSchedulerPermit {
+        let receiver = {
+            let mut state = self.inner.lock();
+            if !state.busy {
+                state.busy = true;
+                state.record(priority);
+                return TxSchedulerPermit {
+                    inner: Some(self.inner.clone()),
+                };
+            }
+            let (sender, receiver) = oneshot::channel();
+            match priority {
+                TxPriority::System => state.system_waiting.push_back(sender),
+                TxPriority::User => state.user_waiting.push_back(sender),
+            }
+            receiver
+        };
+        receiver
+            .await
+            .expect("the waiting sender should only be dropped after sending the permit")
+    }
+
+    pub fn stats(&self) -> TxSchedulerStats {
+        let state = self.inner.lock();
+        TxSchedulerStats {
+            system_waiting: state.system_waiting.len() as u64,
+            user_waiting: state.user_waiting.len() as u64,
+            ..state.stats.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::move_types::FunctionId;
+    use moveos_types::transaction::FunctionCall;
+
+    #[test]
+    fn test_classify_by_sender_and_module() {
+        let sequencer = AccountAddress::from_hex_literal("0x42").unwrap();
+        let relayer = AccountAddress::from_hex_literal("0x43").unwrap();
+        let classifier = TxPriorityClassifier::new(sequencer).with_sender(relayer);
+        let call = |address, module: &str| {
+            MoveAction::Function(FunctionCall::new(
+                FunctionId::new(
+                    ModuleId::new(address, Identifier::new(module).unwrap()),
+                    Identifier::new("submit").unwrap(),
+                ),
+                vec![],
+                vec![],
+            ))
+        };
+        let light_client = call(BITCOIN_MOVE_ADDRESS, "light_client");
+        assert_eq!(
+            classifier.classify(&sequencer, &light_client),
+            TxPriority::System
+        );
+        assert_eq!(
+            classifier.classify(&relayer, &light_client),
+            TxPriority::System
+        );
+        assert_eq!(
+            classifier.classify(&AccountAddress::ONE, &light_client),
+            TxPriority::User
+        );
+        assert_eq!(
+            classifier.classify(&sequencer, &call(ROOCH_FRAMEWORK_ADDRESS, "coin")),
+            TxPriority::User
+        );
+    }
+
+    #[tokio::test]
+    async fn test_system_txs_first_with_starvation_protection() {
+        let scheduler = TxScheduler::new(2);
+        let order = Arc::new(Mutex::new(vec![]));
+        let first = scheduler.acquire(TxPriority::User).await;
+
+        let mut handles = vec![];
+        for (i, priority) in [
+            TxPriority::User,
+            TxPriority::System,
+            TxPriority::System,
+            TxPriority::System,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let scheduler = scheduler.clone();
+            let order = order.clone();
+            handles.push(tokio::spawn(async move {
+                let _permit = scheduler.acquire(priority).await;
+                order.lock().push(i);
+            }));
+            // Make sure the transactions are waiting in order
+            while scheduler.stats().system_waiting + scheduler.stats().user_waiting <= i as u64 {
+                tokio::task::yield_now().await;
+            }
+        }
+        drop(first);
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(*order.lock(), vec![1, 2, 0, 3]);
+        let stats = scheduler.stats();
+        assert_eq!(stats.system_sequenced, 3);
+        assert_eq!(stats.user_sequenced, 2);
+        assert_eq!(stats.starvation_grants, 1);
+    }
+}
@@ -2,15 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
+    AcceptTransactionMessage, GetPendingTransactionsMessage, GetSequencerOrderMessage,
+    GetTransactionByHashMessage, GetTransactionsByHashMessage,
     GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
-    GetTxSequenceInfosMessage,
+    GetTxSequenceInfosMessage, IsWritableMessage, RecordModuleGasMessage,
+    RemovePendingTransactionMessage, RestorePendingTransactionMessage, SetReadOnlyMessage,
+    UpdateChainPauseMessage, UpdateRelayersMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
 use coerce::actor::ActorRef;
+use move_core_types::account_address::AccountAddress;
 use moveos_types::h256::H256;
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::sequencer::{PendingTransaction, SequencerOrder, TxInclusionReceipt};
 use rooch_types::transaction::TransactionSequenceInfo;
 use rooch_types::transaction::{TransactionSequenceInfoMapping, TypedTransaction};
 
@@ -27,8 +31,47 @@ impl SequencerProxy {
     pub async fn sequence_transaction(
         &self,
         tx: TypedTransaction,
-    ) -> Result<TransactionSequenceInfo> {
-        self.actor.send(TransactionSequenceMessage { tx }).await?
+        module_address: Option<AccountAddress>,
+    ) -> Result<(TransactionSequenceInfo, TxInclusionReceipt)> {
+        self.actor
+            .send(TransactionSequenceMessage { tx, module_address })
+            .await?
+    }
+
+    pub async fn record_module_gas(
+        &self,
+        tx_order: u64,
+        module_address: AccountAddress,
+        gas_used: u64,
+    ) -> Result<()> {
+        self.actor
+            .send(RecordModuleGasMessage {
+                tx_order,
+                module_address,
+                gas_used,
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn accept_transaction(&self, tx: TypedTransaction) -> Result<()> {
+        self.actor.send(AcceptTransactionMessage { tx }).await?
+    }
+
+    pub async fn get_pending_transactions(&self) -> Result<Vec<PendingTransaction>> {
+        self.actor.send(GetPendingTransactionsMessage {}).await?
+    }
+
+    pub async fn remove_pending_transaction(&self, tx_hash: H256) -> Result<()> {
+        self.actor
+            .send(RemovePendingTransactionMessage { tx_hash })
+            .await?
+    }
+
+    pub async fn restore_pending_transaction(&self, pending_tx: PendingTransaction) -> Result<()> {
+        self.actor
+            .send(RestorePendingTransactionMessage { pending_tx })
+            .await?
     }
 
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<TypedTransaction>> {
@@ -76,4 +119,23 @@ impl SequencerProxy {
     pub async fn get_sequencer_order(&self) -> Result<Option<SequencerOrder>> {
         self.actor.send(GetSequencerOrderMessage {}).await?
     }
+
+    pub async fn update_chain_pause(&self, paused: bool) -> Result<()> {
+        self.actor.send(UpdateChainPauseMessage { paused }).await?;
+        Ok(())
+    }
+
+    pub async fn update_relayers(&self, relayers: Vec<AccountAddress>) -> Result<()> {
+        self.actor.send(UpdateRelayersMessage { relayers }).await?;
+        Ok(())
+    }
+
+    pub async fn set_read_only(&self, reason: Option<String>) -> Result<()> {
+        self.actor.send(SetReadOnlyMessage { reason }).await?;
+        Ok(())
+    }
+
+    pub async fn is_writable(&self) -> Result<bool> {
+        Ok(self.actor.send(IsWritableMessage {}).await?)
+    }
 }
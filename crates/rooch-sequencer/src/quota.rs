@@ -0,0 +1,123 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use move_core_types::account_address::AccountAddress;
+use std::collections::{BTreeMap, VecDeque};
+
+/// The per-block gas quotas of the module addresses, so a single popular app can not monopolize the block space.
+/// The gas used by the calls to the functions of a module address is aggregated in the current block,
+/// the further calls are rejected by the sequencer once the quota is exhausted, until the next block is proposed.
+/// The gas is known after the execution, so the call which exhausts the quota is not rejected.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleGasQuotas {
+    quotas: BTreeMap<AccountAddress, u64>,
+    /// The first transaction order of the current block
+    block_start_order: u64,
+    used: BTreeMap<AccountAddress, u64>,
+    /// The gas used records of the current block in the tx order, (tx_order, module_address, gas_used)
+    records: VecDeque<(u64, AccountAddress, u64)>,
+}
+
+impl ModuleGasQuotas {
+    pub fn new(quotas: BTreeMap<AccountAddress, u64>) -> Self {
+        Self {
+            quotas,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.quotas.is_empty()
+    }
+
+    pub fn quota(&self, module_address: &AccountAddress) -> Option<u64> {
+        self.quotas.get(module_address).copied()
+    }
+
+    /// The gas used by the calls to the module address in the current block
+    pub fn used(&self, module_address: &AccountAddress) -> u64 {
+        self.used.get(module_address).copied().unwrap_or_default()
+    }
+
+    /// Start a new block from the transaction order, the records of the previous blocks are dropped
+    pub fn start_block(&mut self, block_start_order: u64) {
+        if block_start_order <= self.block_start_order {
+            return;
+        }
+        self.block_start_order = block_start_order;
+        while let Some((tx_order, module_address, gas_used)) = self.records.front().copied() {
+            if tx_order >= block_start_order {
+                break;
+            }
+            self.records.pop_front();
+            if let Some(used) = self.used.get_mut(&module_address) {
+                *used = used.saturating_sub(gas_used);
+                if *used == 0 {
+                    self.used.remove(&module_address);
+                }
+            }
+        }
+    }
+
+    /// Reject the call to the module address if its quota of the current block is exhausted
+    pub fn check(&self, module_address: &AccountAddress) -> Result<()> {
+        if let Some(quota) = self.quota(module_address) {
+            let used = self.used(module_address);
+            if used >= quota {
+                bail!(
+                    "The gas quota {} of the module address {} in the current block is exhausted, used {}",
+                    quota,
+                    module_address.to_hex_literal(),
+                    used
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the gas used by the executed call, the calls to the module addresses without quota are ignored
+    pub fn record(&mut self, tx_order: u64, module_address: AccountAddress, gas_used: u64) {
+        if tx_order < self.block_start_order || !self.quotas.contains_key(&module_address) {
+            return;
+        }
+        let used = self.used.entry(module_address).or_default();
+        *used = used.saturating_add(gas_used);
+        self.records.push_back((tx_order, module_address, gas_used));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_gas_quotas() {
+        let app = AccountAddress::random();
+        let other = AccountAddress::random();
+        let mut quotas = ModuleGasQuotas::new([(app, 100)].into_iter().collect());
+        assert!(quotas.check(&app).is_ok());
+
+        quotas.record(1, app, 60);
+        quotas.record(2, other, 1000);
+        assert!(quotas.check(&app).is_ok());
+        assert!(quotas.check(&other).is_ok());
+
+        // The call exhausting the quota is executed, the further calls are rejected
+        quotas.record(3, app, 60);
+        assert_eq!(quotas.used(&app), 120);
+        assert!(quotas.check(&app).is_err());
+        assert!(quotas.check(&other).is_ok());
+
+        // The transactions 1 and 2 are in the previous block
+        quotas.start_block(3);
+        assert_eq!(quotas.used(&app), 60);
+        assert!(quotas.check(&app).is_ok());
+
+        quotas.start_block(4);
+        assert_eq!(quotas.used(&app), 0);
+        // The late record of the transaction in the previous block is ignored
+        quotas.record(3, app, 60);
+        assert_eq!(quotas.used(&app), 0);
+    }
+}
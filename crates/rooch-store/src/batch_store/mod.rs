@@ -0,0 +1,59 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{META_LAST_BATCH_PREFIX_NAME, SEQUENCER_BATCH_PREFIX_NAME};
+use anyhow::Result;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use rooch_types::sequencer::SequencerBatch;
+use std::string::ToString;
+
+pub const LAST_BATCH_KEY: &str = "last_batch";
+derive_store!(
+    SequencerBatchStore,
+    u128,
+    SequencerBatch,
+    SEQUENCER_BATCH_PREFIX_NAME
+);
+derive_store!(LastBatchStore, String, u128, META_LAST_BATCH_PREFIX_NAME);
+
+pub trait BatchStore {
+    fn save_batch(&self, batch: SequencerBatch) -> Result<()>;
+
+    fn get_batch(&self, batch_id: u128) -> Result<Option<SequencerBatch>>;
+
+    fn get_last_batch(&self) -> Result<Option<SequencerBatch>>;
+}
+
+#[derive(Clone)]
+pub struct BatchDBStore {
+    batch_store: SequencerBatchStore,
+    last_batch_store: LastBatchStore,
+}
+
+impl BatchDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        BatchDBStore {
+            batch_store: SequencerBatchStore::new(instance.clone()),
+            last_batch_store: LastBatchStore::new(instance),
+        }
+    }
+
+    /// Save the batch and then move the last batch pointer to it
+    pub fn save_batch(&self, batch: SequencerBatch) -> Result<()> {
+        let batch_id = batch.batch_id;
+        self.batch_store.put_sync(batch_id, batch)?;
+        self.last_batch_store
+            .put_sync(LAST_BATCH_KEY.to_string(), batch_id)
+    }
+
+    pub fn get_batch(&self, batch_id: u128) -> Result<Option<SequencerBatch>> {
+        self.batch_store.kv_get(batch_id)
+    }
+
+    pub fn get_last_batch(&self) -> Result<Option<SequencerBatch>> {
+        match self.last_batch_store.kv_get(LAST_BATCH_KEY.to_string())? {
+            Some(batch_id) => self.get_batch(batch_id),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::TypeTag;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::TransactionEvent;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{State, TableTypeInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Read, Write};
+
+/// The max length of the delta chain of a table entry, the entry is stored in full after it,
+/// so reading a change set decodes a bounded number of the prior change sets.
+pub const MAX_DELTA_CHAIN_LEN: u64 = 8;
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+const BROTLI_QUALITY: u32 = 5;
+const BROTLI_LG_WINDOW_SIZE: u32 = 22;
+
+/// The delta of a value against its prior version, the unchanged prefix and suffix bytes are taken from the prior value.
+/// The Move values are BCS encoded with the fields in order, so updating a field of an object only stores the changed bytes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ValueDelta {
+    pub prefix_len: u32,
+    pub suffix_len: u32,
+    #[serde(with = "serde_bytes")]
+    pub middle: Vec<u8>,
+}
+
+impl ValueDelta {
+    pub fn diff(base: &[u8], value: &[u8]) -> Self {
+        let prefix_len = base
+            .iter()
+            .zip(value)
+            .take_while(|(base, value)| base == value)
+            .count();
+        let max_suffix_len = base.len().min(value.len()) - prefix_len;
+        let suffix_len = base
+            .iter()
+            .rev()
+            .zip(value.iter().rev())
+            .take(max_suffix_len)
+            .take_while(|(base, value)| base == value)
+            .count();
+        Self {
+            prefix_len: prefix_len as u32,
+            suffix_len: suffix_len as u32,
+            middle: value[prefix_len..value.len() - suffix_len].to_vec(),
+        }
+    }
+
+    pub fn apply(&self, base: &[u8]) -> Result<Vec<u8>> {
+        let prefix_len = self.prefix_len as usize;
+        let suffix_len = self.suffix_len as usize;
+        ensure!(
+            prefix_len + suffix_len <= base.len(),
+            "The value delta does not match the prior value of length {}",
+            base.len()
+        );
+        let mut value = Vec::with_capacity(prefix_len + self.middle.len() + suffix_len);
+        value.extend_from_slice(&base[..prefix_len]);
+        value.extend_from_slice(&self.middle);
+        value.extend_from_slice(&base[base.len() - suffix_len..]);
+        Ok(value)
+    }
+}
+
+/// The stored form of `StateChangeOp`, a modified value is stored as a delta against the prior version of the entry
+/// in the change set of `base_tx_hash` if they have the same type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CompressedStateChangeOp {
+    New(State),
+    Modify(State),
+    ModifyDelta {
+        base_tx_hash: H256,
+        delta: ValueDelta,
+    },
+    Delete,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CompressedTableChange {
+    pub entries: Vec<(Vec<u8>, CompressedStateChangeOp)>,
+    pub size_increment: i64,
+    pub key_type: TypeTag,
+}
+
+/// The stored form of `ReplicaChangeSet`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CompressedChangeSet {
+    pub accounts: Vec<AccountAddress>,
+    pub new_tables: BTreeMap<ObjectID, TableTypeInfo>,
+    pub removed_tables: BTreeSet<ObjectID>,
+    pub changes: BTreeMap<ObjectID, CompressedTableChange>,
+    pub events: Vec<TransactionEvent>,
+    pub is_upgrade: bool,
+}
+
+impl CompressedChangeSet {
+    /// Encode the change set with BCS and compress it with brotli, the repeated type tags, addresses and
+    /// event payloads in a change set are well compressed by the dictionary of brotli.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>> {
+        let bytes = bcs::to_bytes(self)?;
+        let mut compressed = vec![];
+        {
+            let mut writer = brotli::CompressorWriter::new(
+                &mut compressed,
+                BROTLI_BUFFER_SIZE,
+                BROTLI_QUALITY,
+                BROTLI_LG_WINDOW_SIZE,
+            );
+            writer.write_all(&bytes)?;
+        }
+        Ok(compressed)
+    }
+
+    pub fn from_compressed_bytes(compressed: &[u8]) -> Result<Self> {
+        let mut bytes = vec![];
+        brotli::Decompressor::new(compressed, BROTLI_BUFFER_SIZE).read_to_end(&mut bytes)?;
+        Ok(bcs::from_bytes(&bytes)?)
+    }
+}
+
+/// The latest version of a table entry, the next modification of the entry is stored as a delta against it.
+/// The state is not kept here, it is decoded from the change set of `tx_hash`, so the entries are not duplicated.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ReplicaEntryVersion {
+    /// The transaction which last changed the entry
+    pub tx_hash: H256,
+    /// The number of the deltas to decode to get the entry from a full value
+    pub delta_chain_len: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_delta() {
+        for (base, value) in [
+            (vec![1, 2, 3, 4, 5], vec![1, 2, 9, 4, 5]),
+            (vec![1, 2, 3], vec![1, 2, 3, 4]),
+            (vec![1, 2, 3, 4], vec![4]),
+            (vec![], vec![1, 2]),
+            (vec![7, 7, 7], vec![7, 7, 7]),
+        ] {
+            let delta = ValueDelta::diff(&base, &value);
+            assert!(delta.middle.len() <= value.len());
+            assert_eq!(delta.apply(&base).unwrap(), value);
+        }
+        let delta = ValueDelta::diff(&[1, 2, 3, 4, 5, 6], &[1, 2, 0, 4, 5, 6]);
+        assert_eq!(delta.middle, vec![0]);
+        assert!(delta.apply(&[1, 2]).is_err());
+    }
+}
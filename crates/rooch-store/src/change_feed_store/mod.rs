@@ -0,0 +1,466 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cold_store::{ColdChangeSet, ColdDBStore};
+use crate::{
+    COMPRESSED_REPLICA_CHANGE_SET_PREFIX_NAME, REPLICA_CHANGE_SET_PREFIX_NAME,
+    REPLICA_ENTRY_VERSION_PREFIX_NAME,
+};
+use anyhow::{anyhow, Result};
+use compression::{
+    CompressedChangeSet, CompressedStateChangeOp, CompressedTableChange, ReplicaEntryVersion,
+    ValueDelta, MAX_DELTA_CHAIN_LEN,
+};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::State;
+use raw_store::rocks::batch::WriteBatch;
+use raw_store::{derive_store, CodecKVStore, CodecWriteBatch, ColumnFamilyName, StoreInstance};
+use rooch_types::replica::{ReplicaChangeSet, ReplicaTableChange, StateChangeOp};
+use std::collections::BTreeMap;
+
+pub mod compression;
+
+derive_store!(
+    ReplicaChangeSetStore,
+    H256,
+    ReplicaChangeSet,
+    REPLICA_CHANGE_SET_PREFIX_NAME
+);
+
+derive_store!(
+    CompressedReplicaChangeSetStore,
+    H256,
+    Vec<u8>,
+    COMPRESSED_REPLICA_CHANGE_SET_PREFIX_NAME
+);
+
+derive_store!(
+    ReplicaEntryVersionStore,
+    (ObjectID, Vec<u8>),
+    ReplicaEntryVersion,
+    REPLICA_ENTRY_VERSION_PREFIX_NAME
+);
+
+pub trait ChangeFeedStore {
+    fn save_replica_change_set(&self, tx_hash: H256, change_set: ReplicaChangeSet) -> Result<()>;
+
+    fn get_replica_change_sets(
+        &self,
+        tx_hashes: Vec<H256>,
+    ) -> Result<Vec<Option<ReplicaChangeSet>>>;
+}
+
+/// The change sets are stored compressed, the modified entries are stored as the deltas against their prior versions,
+/// and the change set is compressed with brotli. Only the transaction which last changed an entry is recorded for it,
+/// the prior version is decoded from the change set of that transaction. The change sets saved before the compression are still readable
+/// from the uncompressed store, the compression is transparent to the readers.
+/// The change sets moved to the cold store are read through it, including the bases of the deltas.
+#[derive(Clone)]
+pub struct ChangeFeedDBStore {
+    replica_change_set_store: ReplicaChangeSetStore,
+    compressed_change_set_store: CompressedReplicaChangeSetStore,
+    entry_version_store: ReplicaEntryVersionStore,
+    cold_store: ColdDBStore,
+}
+
+impl ChangeFeedDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        ChangeFeedDBStore {
+            replica_change_set_store: ReplicaChangeSetStore::new(instance.clone()),
+            compressed_change_set_store: CompressedReplicaChangeSetStore::new(instance.clone()),
+            entry_version_store: ReplicaEntryVersionStore::new(instance.clone()),
+            cold_store: ColdDBStore::new(instance),
+        }
+    }
+
+    pub fn with_cold_store(mut self, cold_store: ColdDBStore) -> Self {
+        self.cold_store = cold_store;
+        self
+    }
+
+    /// Save the change set of the transaction, the change sets are saved by the executor in the tx order,
+    /// so the entry versions are the versions before the transaction.
+    pub fn save_replica_change_set(
+        &self,
+        tx_hash: H256,
+        change_set: ReplicaChangeSet,
+    ) -> Result<()> {
+        let mut entry_versions = vec![];
+        let mut deleted_entries = vec![];
+        let mut changes = BTreeMap::new();
+        // The prior change sets decoded for the bases of the deltas
+        let mut decoded = BTreeMap::new();
+        for (object_id, table_change) in change_set.changes {
+            let mut entries = Vec::with_capacity(table_change.entries.len());
+            for (key, op) in table_change.entries {
+                let entry = (object_id, key.clone());
+                let op = match op {
+                    StateChangeOp::New(state) => {
+                        entry_versions.push((entry, Self::entry_version(tx_hash, 0)));
+                        CompressedStateChangeOp::New(state)
+                    }
+                    StateChangeOp::Modify(state) => {
+                        let prior = match self.entry_version_store.kv_get(entry.clone())? {
+                            Some(prior) if prior.delta_chain_len < MAX_DELTA_CHAIN_LEN => {
+                                let base = self.get_base_state(
+                                    prior.tx_hash,
+                                    object_id,
+                                    &key,
+                                    &mut decoded,
+                                )?;
+                                (base.value_type == state.value_type).then_some((prior, base))
+                            }
+                            _ => None,
+                        };
+                        match prior {
+                            Some((prior, base)) => {
+                                let delta = ValueDelta::diff(&base.value, &state.value);
+                                entry_versions.push((
+                                    entry,
+                                    Self::entry_version(tx_hash, prior.delta_chain_len + 1),
+                                ));
+                                CompressedStateChangeOp::ModifyDelta {
+                                    base_tx_hash: prior.tx_hash,
+                                    delta,
+                                }
+                            }
+                            None => {
+                                entry_versions.push((entry, Self::entry_version(tx_hash, 0)));
+                                CompressedStateChangeOp::Modify(state)
+                            }
+                        }
+                    }
+                    StateChangeOp::Delete => {
+                        deleted_entries.push(entry);
+                        CompressedStateChangeOp::Delete
+                    }
+                };
+                entries.push((key, op));
+            }
+            changes.insert(
+                object_id,
+                CompressedTableChange {
+                    entries,
+                    size_increment: table_change.size_increment,
+                    key_type: table_change.key_type,
+                },
+            );
+        }
+        let compressed = CompressedChangeSet {
+            accounts: change_set.accounts,
+            new_tables: change_set.new_tables,
+            removed_tables: change_set.removed_tables,
+            changes,
+            events: change_set.events,
+            is_upgrade: change_set.is_upgrade,
+        };
+        // The change set is saved before the entry versions, so an entry version always refers to a saved change set
+        self.compressed_change_set_store
+            .kv_put(tx_hash, compressed.to_compressed_bytes()?)?;
+        self.entry_version_store.put_all(entry_versions)?;
+        self.entry_version_store.delete_all(deleted_entries)
+    }
+
+    pub fn get_replica_change_sets(
+        &self,
+        tx_hashes: Vec<H256>,
+    ) -> Result<Vec<Option<ReplicaChangeSet>>> {
+        // The prior change sets decoded for the deltas are cached, the consecutive transactions often share them
+        let mut decoded = BTreeMap::new();
+        tx_hashes
+            .into_iter()
+            .map(|tx_hash| self.get_replica_change_set(tx_hash, &mut decoded))
+            .collect()
+    }
+
+    /// Get the change sets in the hot store as they are stored, without decoding the deltas
+    pub fn get_hot_change_sets(&self, tx_hashes: Vec<H256>) -> Result<Vec<Option<ColdChangeSet>>> {
+        tx_hashes
+            .into_iter()
+            .map(|tx_hash| {
+                if let Some(bytes) = self.compressed_change_set_store.kv_get(tx_hash)? {
+                    return Ok(Some(ColdChangeSet::Compressed(bytes)));
+                }
+                Ok(self
+                    .replica_change_set_store
+                    .kv_get(tx_hash)?
+                    .map(ColdChangeSet::Uncompressed))
+            })
+            .collect()
+    }
+
+    /// The batches to delete the change sets from the hot store, they are written together with the index of the
+    /// cold store
+    pub fn delete_hot_change_sets_batches(
+        &self,
+        tx_hashes: Vec<H256>,
+    ) -> Result<Vec<(ColumnFamilyName, WriteBatch)>> {
+        Ok(vec![
+            CodecWriteBatch::<H256, Vec<u8>>::new_deletes(tx_hashes.clone())
+                .into_cf_batch::<CompressedReplicaChangeSetStore>()?,
+            CodecWriteBatch::<H256, ReplicaChangeSet>::new_deletes(tx_hashes)
+                .into_cf_batch::<ReplicaChangeSetStore>()?,
+        ])
+    }
+
+    fn entry_version(tx_hash: H256, delta_chain_len: u64) -> ReplicaEntryVersion {
+        ReplicaEntryVersion {
+            tx_hash,
+            delta_chain_len,
+        }
+    }
+
+    fn get_replica_change_set(
+        &self,
+        tx_hash: H256,
+        decoded: &mut BTreeMap<H256, Option<ReplicaChangeSet>>,
+    ) -> Result<Option<ReplicaChangeSet>> {
+        if let Some(change_set) = decoded.get(&tx_hash) {
+            return Ok(change_set.clone());
+        }
+        let change_set = match self.compressed_change_set_store.kv_get(tx_hash)? {
+            Some(bytes) => Some(self.decode_compressed(&bytes, decoded)?),
+            None => match self.replica_change_set_store.kv_get(tx_hash)? {
+                Some(change_set) => Some(change_set),
+                None => match self
+                    .cold_store
+                    .get_record(tx_hash)?
+                    .and_then(|record| record.change_set)
+                {
+                    Some(ColdChangeSet::Compressed(bytes)) => {
+                        Some(self.decode_compressed(&bytes, decoded)?)
+                    }
+                    Some(ColdChangeSet::Uncompressed(change_set)) => Some(change_set),
+                    None => None,
+                },
+            },
+        };
+        decoded.insert(tx_hash, change_set.clone());
+        Ok(change_set)
+    }
+
+    fn decode_compressed(
+        &self,
+        bytes: &[u8],
+        decoded: &mut BTreeMap<H256, Option<ReplicaChangeSet>>,
+    ) -> Result<ReplicaChangeSet> {
+        self.decode_change_set(CompressedChangeSet::from_compressed_bytes(bytes)?, decoded)
+    }
+
+    fn decode_change_set(
+        &self,
+        compressed: CompressedChangeSet,
+        decoded: &mut BTreeMap<H256, Option<ReplicaChangeSet>>,
+    ) -> Result<ReplicaChangeSet> {
+        let mut changes = BTreeMap::new();
+        for (object_id, table_change) in compressed.changes {
+            let mut entries = Vec::with_capacity(table_change.entries.len());
+            for (key, op) in table_change.entries {
+                let op = match op {
+                    CompressedStateChangeOp::New(state) => StateChangeOp::New(state),
+                    CompressedStateChangeOp::Modify(state) => StateChangeOp::Modify(state),
+                    CompressedStateChangeOp::ModifyDelta {
+                        base_tx_hash,
+                        delta,
+                    } => {
+                        let base = self.get_base_state(base_tx_hash, object_id, &key, decoded)?;
+                        StateChangeOp::Modify(State::new(
+                            delta.apply(&base.value)?,
+                            base.value_type,
+                        ))
+                    }
+                    CompressedStateChangeOp::Delete => StateChangeOp::Delete,
+                };
+                entries.push((key, op));
+            }
+            changes.insert(
+                object_id,
+                ReplicaTableChange {
+                    entries,
+                    size_increment: table_change.size_increment,
+                    key_type: table_change.key_type,
+                },
+            );
+        }
+        Ok(ReplicaChangeSet {
+            accounts: compressed.accounts,
+            new_tables: compressed.new_tables,
+            removed_tables: compressed.removed_tables,
+            changes,
+            events: compressed.events,
+            is_upgrade: compressed.is_upgrade,
+        })
+    }
+
+    /// Get the state of the table entry changed by the base transaction of a delta
+    fn get_base_state(
+        &self,
+        base_tx_hash: H256,
+        object_id: ObjectID,
+        key: &[u8],
+        decoded: &mut BTreeMap<H256, Option<ReplicaChangeSet>>,
+    ) -> Result<State> {
+        let base = self
+            .get_replica_change_set(base_tx_hash, decoded)?
+            .ok_or_else(|| anyhow!("The base change set {:?} does not exist", base_tx_hash))?;
+        base.changes
+            .get(&object_id)
+            .and_then(|table_change| {
+                table_change
+                    .entries
+                    .iter()
+                    .find(|(entry_key, _)| entry_key.as_slice() == key)
+            })
+            .and_then(|(_, op)| match op {
+                StateChangeOp::New(state) | StateChangeOp::Modify(state) => Some(state.clone()),
+                StateChangeOp::Delete => None,
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "The entry of the table {:?} is not changed by the base change set {:?}",
+                    object_id,
+                    base_tx_hash
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RoochStore;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::language_storage::TypeTag;
+
+    fn change_set(object_id: ObjectID, key: &[u8], op: StateChangeOp) -> ReplicaChangeSet {
+        let mut changes = BTreeMap::new();
+        changes.insert(
+            object_id,
+            ReplicaTableChange {
+                entries: vec![(key.to_vec(), op)],
+                size_increment: 0,
+                key_type: TypeTag::U64,
+            },
+        );
+        ReplicaChangeSet {
+            accounts: vec![],
+            new_tables: Default::default(),
+            removed_tables: Default::default(),
+            changes,
+            events: vec![],
+            is_upgrade: false,
+        }
+    }
+
+    fn stored_op(
+        store: &ChangeFeedDBStore,
+        tx_hash: H256,
+        object_id: ObjectID,
+    ) -> CompressedStateChangeOp {
+        let bytes = match store.get_hot_change_sets(vec![tx_hash]).unwrap().pop() {
+            Some(Some(ColdChangeSet::Compressed(bytes))) => bytes,
+            _ => panic!("The change set {:?} is not stored compressed", tx_hash),
+        };
+        let compressed = CompressedChangeSet::from_compressed_bytes(&bytes).unwrap();
+        compressed.changes[&object_id].entries[0].1.clone()
+    }
+
+    #[test]
+    fn test_delta_chain() {
+        let rooch_store = RoochStore::mock_rooch_store().unwrap();
+        let store = rooch_store.get_change_feed_store();
+        let object_id = ObjectID::from(AccountAddress::random());
+        let key = vec![1u8];
+        let value_type = TypeTag::Vector(Box::new(TypeTag::U8));
+
+        // The entry is created, then modified beyond the max delta chain length, and its type is changed at last
+        let mut change_sets = vec![];
+        let mut value = vec![0u8; 64];
+        change_sets.push(change_set(
+            object_id,
+            &key,
+            StateChangeOp::New(State::new(value.clone(), value_type.clone())),
+        ));
+        for i in 1..=(MAX_DELTA_CHAIN_LEN + 2) {
+            value[i as usize] = i as u8;
+            change_sets.push(change_set(
+                object_id,
+                &key,
+                StateChangeOp::Modify(State::new(value.clone(), value_type.clone())),
+            ));
+        }
+        change_sets.push(change_set(
+            object_id,
+            &key,
+            StateChangeOp::Modify(State::new(value.clone(), TypeTag::Address)),
+        ));
+        let tx_hashes: Vec<H256> = (0..change_sets.len()).map(|_| H256::random()).collect();
+        for (tx_hash, change_set) in tx_hashes.iter().zip(&change_sets) {
+            store
+                .save_replica_change_set(*tx_hash, change_set.clone())
+                .unwrap();
+        }
+
+        // The modifications are stored as the deltas against the prior versions, and in full after the max chain length
+        assert!(matches!(
+            stored_op(store, tx_hashes[0], object_id),
+            CompressedStateChangeOp::New(_)
+        ));
+        for i in 1..=(MAX_DELTA_CHAIN_LEN + 2) as usize {
+            let op = stored_op(store, tx_hashes[i], object_id);
+            if i == MAX_DELTA_CHAIN_LEN as usize + 1 {
+                assert!(matches!(op, CompressedStateChangeOp::Modify(_)));
+            } else {
+                match op {
+                    CompressedStateChangeOp::ModifyDelta {
+                        base_tx_hash,
+                        delta,
+                    } => {
+                        assert_eq!(base_tx_hash, tx_hashes[i - 1]);
+                        assert_eq!(delta.middle, vec![i as u8]);
+                    }
+                    op => panic!("The modification {} is not stored as a delta: {:?}", i, op),
+                }
+            }
+        }
+        assert!(matches!(
+            stored_op(store, *tx_hashes.last().unwrap(), object_id),
+            CompressedStateChangeOp::Modify(_)
+        ));
+        // Only the transaction which last changed the entry is recorded for it
+        assert_eq!(
+            store
+                .entry_version_store
+                .kv_get((object_id, key.clone()))
+                .unwrap(),
+            Some(ReplicaEntryVersion {
+                tx_hash: *tx_hashes.last().unwrap(),
+                delta_chain_len: 0,
+            })
+        );
+
+        // The change sets are rebuilt from the delta chain, in any order
+        assert_eq!(
+            store.get_replica_change_sets(tx_hashes.clone()).unwrap(),
+            change_sets.iter().cloned().map(Some).collect::<Vec<_>>()
+        );
+        for (tx_hash, change_set) in tx_hashes.iter().zip(&change_sets).rev() {
+            assert_eq!(
+                store.get_replica_change_sets(vec![*tx_hash]).unwrap(),
+                vec![Some(change_set.clone())]
+            );
+        }
+
+        // The version of the deleted entry is removed, the entry is stored in full when it is created again
+        let tx_hash = H256::random();
+        store
+            .save_replica_change_set(tx_hash, change_set(object_id, &key, StateChangeOp::Delete))
+            .unwrap();
+        assert!(store
+            .entry_version_store
+            .kv_get((object_id, key.clone()))
+            .unwrap()
+            .is_none());
+    }
+}
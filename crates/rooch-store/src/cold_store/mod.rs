@@ -0,0 +1,306 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{COLD_LOCATION_PREFIX_NAME, META_COLD_STORAGE_PREFIX_NAME};
+use anyhow::{anyhow, ensure, Result};
+use moveos_types::h256::H256;
+use raw_store::rocks::batch::WriteBatch;
+use raw_store::{derive_store, CodecKVStore, CodecWriteBatch, ColumnFamilyName, StoreInstance};
+use rooch_types::replica::ReplicaChangeSet;
+use rooch_types::transaction::TypedTransaction;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+derive_store!(
+    ColdLocationStore,
+    H256,
+    ColdLocation,
+    COLD_LOCATION_PREFIX_NAME
+);
+
+pub const COLD_NEXT_ORDER_KEY: &str = "cold_next_order";
+derive_store!(
+    ColdStorageMetaStore,
+    String,
+    u64,
+    META_COLD_STORAGE_PREFIX_NAME
+);
+
+/// The location of a record in the segment files
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ColdLocation {
+    /// The segment is named by the tx order of its first record
+    pub segment: u64,
+    /// The offset of the record bytes, after the length prefix
+    pub offset: u64,
+    pub len: u32,
+}
+
+/// The change set of a tiered transaction, it is moved as it is stored in the hot store,
+/// so the delta chains of the compressed change sets are kept.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ColdChangeSet {
+    Compressed(#[serde(with = "serde_bytes")] Vec<u8>),
+    Uncompressed(ReplicaChangeSet),
+}
+
+/// The data of a transaction moved to the cold store
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ColdRecord {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub transaction: Option<TypedTransaction>,
+    pub change_set: Option<ColdChangeSet>,
+}
+
+/// The cold store keeps the old transactions and change sets in the append-only segment files, which are cheaper
+/// than the RocksDB. A segment is written once with the records of the consecutive transactions, every record is
+/// the bcs bytes prefixed by its u32 little endian length, and the locations of the records are indexed in the
+/// RocksDB by the tx hash.
+#[derive(Clone)]
+pub struct ColdDBStore {
+    location_store: ColdLocationStore,
+    meta_store: ColdStorageMetaStore,
+    segment_dir: Option<PathBuf>,
+}
+
+impl ColdDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        ColdDBStore {
+            location_store: ColdLocationStore::new(instance.clone()),
+            meta_store: ColdStorageMetaStore::new(instance),
+            segment_dir: None,
+        }
+    }
+
+    /// The tx order of the first transaction which is not moved to the cold store
+    pub fn get_next_order(&self) -> Result<u64> {
+        Ok(self
+            .meta_store
+            .kv_get(COLD_NEXT_ORDER_KEY.to_string())?
+            .unwrap_or_default())
+    }
+
+    /// Set the directory of the segment files, the cold records can not be written or read without it
+    pub fn with_segment_dir(mut self, dir: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        self.segment_dir = Some(dir);
+        Ok(self)
+    }
+
+    pub fn segment_dir(&self) -> Option<&Path> {
+        self.segment_dir.as_deref()
+    }
+
+    pub fn segment_path(&self, segment: u64) -> Result<PathBuf> {
+        let dir = self
+            .segment_dir
+            .as_ref()
+            .ok_or_else(|| anyhow!("The cold storage dir is not configured"))?;
+        Ok(dir.join(format!("segment_{:020}.seg", segment)))
+    }
+
+    /// Write the records to a new segment, and return the batches to index them and to advance the tiering progress
+    /// to `next_order`. The segment is synced before the batches are returned, the caller writes the batches together
+    /// with the deletion of the hot data, so a crash never loses the data of a transaction.
+    /// The segment is named by the first tx order, so writing the same records again after a crash replaces the
+    /// unindexed segment, but a segment is never replaced by a segment without all of its records.
+    pub fn write_segment(
+        &self,
+        records: &[ColdRecord],
+        next_order: u64,
+    ) -> Result<Vec<(ColumnFamilyName, WriteBatch)>> {
+        let progress =
+            CodecWriteBatch::new_puts(vec![(COLD_NEXT_ORDER_KEY.to_string(), next_order)])
+                .into_cf_batch::<ColdStorageMetaStore>()?;
+        let Some(first) = records.first() else {
+            return Ok(vec![progress]);
+        };
+        let segment = first.tx_order;
+        let path = self.segment_path(segment)?;
+        if path.exists() {
+            let tx_hashes: HashSet<H256> = records.iter().map(|record| record.tx_hash).collect();
+            let existing = read_segment(&path)?;
+            ensure!(
+                existing
+                    .iter()
+                    .all(|record| tx_hashes.contains(&record.tx_hash)),
+                "Refuse to overwrite the cold segment {:?} of {} records with {} records",
+                path,
+                existing.len(),
+                records.len()
+            );
+        }
+        // Write to a temporary file first, so the indexed segments are always complete
+        let tmp_path = path.with_extension("seg.tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        let mut locations = Vec::with_capacity(records.len());
+        let mut offset = 0u64;
+        for record in records {
+            let bytes = bcs::to_bytes(record)?;
+            let len = u32::try_from(bytes.len())
+                .map_err(|_| anyhow!("The cold record of {:?} is too large", record.tx_hash))?;
+            writer.write_all(&len.to_le_bytes())?;
+            writer.write_all(&bytes)?;
+            offset += 4;
+            locations.push((
+                record.tx_hash,
+                ColdLocation {
+                    segment,
+                    offset,
+                    len,
+                },
+            ));
+            offset += len as u64;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        drop(writer);
+        std::fs::rename(tmp_path, &path)?;
+        // Sync the directory, so the renamed segment survives a crash before the index is written
+        if let Some(dir) = path.parent() {
+            File::open(dir)?.sync_all()?;
+        }
+        Ok(vec![
+            CodecWriteBatch::new_puts(locations).into_cf_batch::<ColdLocationStore>()?,
+            progress,
+        ])
+    }
+
+    pub fn get_record(&self, tx_hash: H256) -> Result<Option<ColdRecord>> {
+        self.location_store
+            .kv_get(tx_hash)?
+            .map(|location| self.read_record(tx_hash, location))
+            .transpose()
+    }
+
+    pub fn get_records(&self, tx_hashes: Vec<H256>) -> Result<Vec<Option<ColdRecord>>> {
+        let locations = self.location_store.multiple_get(tx_hashes.clone())?;
+        tx_hashes
+            .into_iter()
+            .zip(locations)
+            .map(|(tx_hash, location)| {
+                location
+                    .map(|location| self.read_record(tx_hash, location))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    fn read_record(&self, tx_hash: H256, location: ColdLocation) -> Result<ColdRecord> {
+        let mut file = File::open(self.segment_path(location.segment)?)?;
+        file.seek(SeekFrom::Start(location.offset))?;
+        let mut bytes = vec![0u8; location.len as usize];
+        file.read_exact(&mut bytes)?;
+        let record: ColdRecord = bcs::from_bytes(&bytes)?;
+        ensure!(
+            record.tx_hash == tx_hash,
+            "The cold record at {:?} is of the tx {:?}, not {:?}",
+            location,
+            record.tx_hash,
+            tx_hash
+        );
+        Ok(record)
+    }
+}
+
+/// Read all the records of a segment file in the tx order
+pub fn read_segment(path: &Path) -> Result<Vec<ColdRecord>> {
+    let bytes = std::fs::read(path)?;
+    let mut records = vec![];
+    let mut rest = bytes.as_slice();
+    while !rest.is_empty() {
+        ensure!(rest.len() >= 4, "The segment {:?} is truncated", path);
+        let (len, tail) = rest.split_at(4);
+        let len = u32::from_le_bytes(len.try_into()?) as usize;
+        ensure!(tail.len() >= len, "The segment {:?} is truncated", path);
+        let (record, tail) = tail.split_at(len);
+        records.push(bcs::from_bytes(record)?);
+        rest = tail;
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::change_feed_store::ChangeFeedStore;
+    use crate::transaction_store::TransactionStore;
+    use crate::{RoochStore, StoreMeta};
+    use moveos_config::store_config::RocksdbConfig;
+    use raw_store::rocks::RocksDB;
+    use rooch_types::transaction::rooch::RoochTransaction;
+    use rooch_types::transaction::AbstractTransaction;
+
+    #[test]
+    fn test_tier_transactions() {
+        let db_dir = moveos_config::temp_dir();
+        let cold_dir = moveos_config::temp_dir();
+        let mut store = RoochStore::new(StoreInstance::new_db_instance(
+            RocksDB::new(
+                db_dir.path(),
+                StoreMeta::get_column_family_names().to_vec(),
+                RocksdbConfig::default(),
+                None,
+            )
+            .unwrap(),
+        ))
+        .unwrap()
+        .with_cold_store_dir(cold_dir.path().to_path_buf())
+        .unwrap();
+
+        let tx = TypedTransaction::Rooch(RoochTransaction::mock());
+        let tx_hash = tx.tx_hash();
+        let change_set = ReplicaChangeSet {
+            accounts: vec![],
+            new_tables: Default::default(),
+            removed_tables: Default::default(),
+            changes: Default::default(),
+            events: vec![],
+            is_upgrade: false,
+        };
+        store.save_transaction(tx.clone()).unwrap();
+        store.save_tx_sequence_info_mapping(0, tx_hash).unwrap();
+        store
+            .save_replica_change_set(tx_hash, change_set.clone())
+            .unwrap();
+
+        assert_eq!(store.tier_transactions(0, 0).unwrap(), 1);
+        // The transaction is moved out of the hot store, and read through the cold store
+        assert!(store
+            .get_transaction_store()
+            .get_transaction_by_hash(tx_hash)
+            .unwrap()
+            .is_none());
+        assert_eq!(store.get_transaction_by_hash(tx_hash).unwrap(), Some(tx));
+        assert_eq!(
+            store.get_replica_change_sets(vec![tx_hash]).unwrap(),
+            vec![Some(change_set)]
+        );
+        let records = read_segment(&store.get_cold_store().segment_path(0).unwrap()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].tx_hash, tx_hash);
+        // The tiered transactions are skipped
+        assert_eq!(store.get_cold_store().get_next_order().unwrap(), 1);
+        assert_eq!(store.tier_transactions(0, 0).unwrap(), 0);
+
+        // The indexed segment is never replaced by a segment without all of its records
+        let other = ColdRecord {
+            tx_order: 0,
+            tx_hash: H256::random(),
+            transaction: None,
+            change_set: None,
+        };
+        assert!(store
+            .get_cold_store()
+            .write_segment(&[other.clone()], 1)
+            .is_err());
+        assert!(store
+            .get_cold_store()
+            .write_segment(&[records[0].clone(), other], 1)
+            .is_ok());
+    }
+}
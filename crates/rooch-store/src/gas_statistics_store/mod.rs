@@ -0,0 +1,66 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::GAS_STATISTICS_PREFIX_NAME;
+use anyhow::Result;
+use moveos_types::move_types::FunctionId;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use rooch_types::gas_statistics::FunctionGasStatistics;
+
+derive_store!(
+    FunctionGasStatisticsStore,
+    FunctionId,
+    FunctionGasStatistics,
+    GAS_STATISTICS_PREFIX_NAME
+);
+
+pub trait GasStatisticsStore {
+    fn record_function_gas_used(&self, function_id: FunctionId, gas_used: u64) -> Result<()>;
+
+    fn get_function_gas_statistics(
+        &self,
+        function_ids: Vec<FunctionId>,
+    ) -> Result<Vec<Option<FunctionGasStatistics>>>;
+
+    fn list_function_gas_statistics(&self) -> Result<Vec<FunctionGasStatistics>>;
+}
+
+#[derive(Clone)]
+pub struct GasStatisticsDBStore {
+    function_gas_statistics_store: FunctionGasStatisticsStore,
+}
+
+impl GasStatisticsDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        GasStatisticsDBStore {
+            function_gas_statistics_store: FunctionGasStatisticsStore::new(instance),
+        }
+    }
+
+    /// Accumulate the gas used of the function, the statistics is only updated by the executor,
+    /// so there is no concurrent update.
+    pub fn record_function_gas_used(&self, function_id: FunctionId, gas_used: u64) -> Result<()> {
+        let mut statistics = self
+            .function_gas_statistics_store
+            .kv_get(function_id.clone())?
+            .unwrap_or_else(|| FunctionGasStatistics::new(function_id.clone()));
+        statistics.record(gas_used);
+        self.function_gas_statistics_store
+            .kv_put(function_id, statistics)
+    }
+
+    pub fn get_function_gas_statistics(
+        &self,
+        function_ids: Vec<FunctionId>,
+    ) -> Result<Vec<Option<FunctionGasStatistics>>> {
+        self.function_gas_statistics_store
+            .multiple_get(function_ids)
+    }
+
+    pub fn list_function_gas_statistics(&self) -> Result<Vec<FunctionGasStatistics>> {
+        let mut iter = self.function_gas_statistics_store.iter()?;
+        iter.seek_to_first();
+        iter.map(|item| item.map(|(_, statistics)| statistics))
+            .collect()
+    }
+}
@@ -1,22 +1,44 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::batch_store::{BatchDBStore, BatchStore};
+use crate::change_feed_store::{ChangeFeedDBStore, ChangeFeedStore};
+use crate::cold_store::{ColdDBStore, ColdRecord};
+use crate::gas_statistics_store::{GasStatisticsDBStore, GasStatisticsStore};
 use crate::meta_store::{MetaDBStore, MetaStore};
+use crate::migration_store::{MigrationDBStore, MigrationStore};
+use crate::pending_tx_store::{PendingTxDBStore, PendingTxStore};
+use crate::source_registry_store::{SourceRegistryDBStore, SourceRegistryStore};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use anyhow::Result;
+use move_core_types::account_address::AccountAddress;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_config::temp_dir;
 use moveos_types::h256::H256;
+use moveos_types::move_types::FunctionId;
 use once_cell::sync::Lazy;
 use raw_store::rocks::RocksDB;
+use raw_store::traits::DBStore;
 use raw_store::{ColumnFamilyName, StoreInstance};
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::gas_statistics::FunctionGasStatistics;
+use rooch_types::migration::MigrationCheckpoint;
+use rooch_types::replica::ReplicaChangeSet;
+use rooch_types::sequencer::{PendingTransaction, SequencerBatch, SequencerOrder};
+use rooch_types::source_verification::SourceVerification;
 use rooch_types::transaction::{
     TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
 };
 use std::fmt::{Debug, Display, Formatter};
+use std::path::PathBuf;
 
+pub mod batch_store;
+pub mod change_feed_store;
+pub mod cold_store;
+pub mod gas_statistics_store;
 pub mod meta_store;
+pub mod migration_store;
+pub mod pending_tx_store;
+pub mod source_registry_store;
 pub mod transaction_store;
 
 // pub const DEFAULT_PREFIX_NAME: ColumnFamilyName = "default";
@@ -28,6 +50,25 @@ pub const TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME: ColumnFamilyName =
 
 pub const META_SEQUENCER_ORDER_PREFIX_NAME: ColumnFamilyName = "meta_sequencer_order";
 
+pub const GAS_STATISTICS_PREFIX_NAME: ColumnFamilyName = "gas_statistics";
+
+pub const MIGRATION_CHECKPOINT_PREFIX_NAME: ColumnFamilyName = "migration_checkpoint";
+
+pub const SEQUENCER_BATCH_PREFIX_NAME: ColumnFamilyName = "sequencer_batch";
+pub const META_LAST_BATCH_PREFIX_NAME: ColumnFamilyName = "meta_last_batch";
+
+pub const SOURCE_VERIFICATION_PREFIX_NAME: ColumnFamilyName = "source_verification";
+
+pub const PENDING_TRANSACTION_PREFIX_NAME: ColumnFamilyName = "pending_transaction";
+
+pub const REPLICA_CHANGE_SET_PREFIX_NAME: ColumnFamilyName = "replica_change_set";
+pub const COMPRESSED_REPLICA_CHANGE_SET_PREFIX_NAME: ColumnFamilyName =
+    "compressed_replica_change_set";
+pub const REPLICA_ENTRY_VERSION_PREFIX_NAME: ColumnFamilyName = "replica_entry_version";
+
+pub const COLD_LOCATION_PREFIX_NAME: ColumnFamilyName = "cold_location";
+pub const META_COLD_STORAGE_PREFIX_NAME: ColumnFamilyName = "meta_cold_storage";
+
 ///db store use prefix_name vec to init
 /// Please note that adding a prefix needs to be added in vec simultaneously, remember！！
 static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
@@ -37,6 +78,17 @@ static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         TX_SEQUENCE_INFO_MAPPING_PREFIX_NAME,
         META_SEQUENCER_ORDER_PREFIX_NAME,
         TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME,
+        GAS_STATISTICS_PREFIX_NAME,
+        MIGRATION_CHECKPOINT_PREFIX_NAME,
+        SEQUENCER_BATCH_PREFIX_NAME,
+        META_LAST_BATCH_PREFIX_NAME,
+        SOURCE_VERIFICATION_PREFIX_NAME,
+        PENDING_TRANSACTION_PREFIX_NAME,
+        REPLICA_CHANGE_SET_PREFIX_NAME,
+        COMPRESSED_REPLICA_CHANGE_SET_PREFIX_NAME,
+        REPLICA_ENTRY_VERSION_PREFIX_NAME,
+        COLD_LOCATION_PREFIX_NAME,
+        META_COLD_STORAGE_PREFIX_NAME,
     ]
 });
 
@@ -53,17 +105,43 @@ impl StoreMeta {
 pub struct RoochStore {
     pub transaction_store: TransactionDBStore,
     pub meta_store: MetaDBStore,
+    pub gas_statistics_store: GasStatisticsDBStore,
+    pub migration_store: MigrationDBStore,
+    pub batch_store: BatchDBStore,
+    pub source_registry_store: SourceRegistryDBStore,
+    pub pending_tx_store: PendingTxDBStore,
+    pub change_feed_store: ChangeFeedDBStore,
+    pub cold_store: ColdDBStore,
+    instance: StoreInstance,
 }
 
 impl RoochStore {
     pub fn new(instance: StoreInstance) -> Result<Self> {
         let store = Self {
             transaction_store: TransactionDBStore::new(instance.clone()),
-            meta_store: MetaDBStore::new(instance),
+            meta_store: MetaDBStore::new(instance.clone()),
+            gas_statistics_store: GasStatisticsDBStore::new(instance.clone()),
+            migration_store: MigrationDBStore::new(instance.clone()),
+            batch_store: BatchDBStore::new(instance.clone()),
+            source_registry_store: SourceRegistryDBStore::new(instance.clone()),
+            pending_tx_store: PendingTxDBStore::new(instance.clone()),
+            change_feed_store: ChangeFeedDBStore::new(instance.clone()),
+            cold_store: ColdDBStore::new(instance.clone()),
+            instance,
         };
         Ok(store)
     }
 
+    /// Set the directory of the cold store segments, the transactions and change sets moved to the cold store
+    /// are read through it.
+    pub fn with_cold_store_dir(mut self, dir: PathBuf) -> Result<Self> {
+        self.cold_store = self.cold_store.with_segment_dir(dir)?;
+        self.change_feed_store = self
+            .change_feed_store
+            .with_cold_store(self.cold_store.clone());
+        Ok(self)
+    }
+
     //TODO implement a memory mock store
     pub fn mock_rooch_store() -> Result<Self> {
         Self::new(StoreInstance::new_db_instance(RocksDB::new(
@@ -81,6 +159,80 @@ impl RoochStore {
     pub fn get_meta_store(&self) -> &MetaDBStore {
         &self.meta_store
     }
+
+    pub fn get_gas_statistics_store(&self) -> &GasStatisticsDBStore {
+        &self.gas_statistics_store
+    }
+
+    pub fn get_migration_store(&self) -> &MigrationDBStore {
+        &self.migration_store
+    }
+
+    pub fn get_batch_store(&self) -> &BatchDBStore {
+        &self.batch_store
+    }
+
+    pub fn get_source_registry_store(&self) -> &SourceRegistryDBStore {
+        &self.source_registry_store
+    }
+
+    pub fn get_pending_tx_store(&self) -> &PendingTxDBStore {
+        &self.pending_tx_store
+    }
+
+    pub fn get_change_feed_store(&self) -> &ChangeFeedDBStore {
+        &self.change_feed_store
+    }
+
+    pub fn get_cold_store(&self) -> &ColdDBStore {
+        &self.cold_store
+    }
+
+    /// Move the transactions and change sets from `from_order` to `to_order` to the cold store, and return the
+    /// number of the moved transactions. The transactions before the tiering progress are skipped, the sequence
+    /// infos and the mappings are kept in the hot store.
+    pub fn tier_transactions(&self, from_order: u64, to_order: u64) -> Result<u64> {
+        let from_order = from_order.max(self.cold_store.get_next_order()?);
+        if from_order > to_order {
+            return Ok(0);
+        }
+        let mappings: Vec<_> = self
+            .transaction_store
+            .get_tx_sequence_info_mapping_by_order((from_order..=to_order).collect())?
+            .into_iter()
+            .flatten()
+            .collect();
+        let tx_hashes: Vec<H256> = mappings.iter().map(|mapping| mapping.tx_hash).collect();
+        let transactions = self.transaction_store.get_transactions(tx_hashes.clone())?;
+        let change_sets = self
+            .change_feed_store
+            .get_hot_change_sets(tx_hashes.clone())?;
+        let records: Vec<ColdRecord> = mappings
+            .into_iter()
+            .zip(transactions.into_iter().zip(change_sets))
+            .filter(|(_, (transaction, change_set))| transaction.is_some() || change_set.is_some())
+            .map(|(mapping, (transaction, change_set))| ColdRecord {
+                tx_order: mapping.tx_order,
+                tx_hash: mapping.tx_hash,
+                transaction,
+                change_set,
+            })
+            .collect();
+        // The segment is synced before the hot data is deleted, and the index, the progress and the deletion are
+        // written in one batch, so the readers always find the data in a store, even after a crash
+        let mut batches = self.cold_store.write_segment(&records, to_order + 1)?;
+        let tiered: Vec<H256> = records.iter().map(|record| record.tx_hash).collect();
+        batches.push(
+            self.transaction_store
+                .delete_transactions_batch(tiered.clone())?,
+        );
+        batches.extend(
+            self.change_feed_store
+                .delete_hot_change_sets_batches(tiered.clone())?,
+        );
+        self.instance.write_cf_batches_sync(batches)?;
+        Ok(tiered.len() as u64)
+    }
 }
 
 impl Display for RoochStore {
@@ -100,14 +252,36 @@ impl TransactionStore for RoochStore {
     }
 
     fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<TypedTransaction>> {
-        self.transaction_store.get_transaction_by_hash(hash)
+        match self.transaction_store.get_transaction_by_hash(hash)? {
+            Some(transaction) => Ok(Some(transaction)),
+            None => Ok(self
+                .cold_store
+                .get_record(hash)?
+                .and_then(|record| record.transaction)),
+        }
     }
 
     fn get_transactions_by_hash(
         &self,
         tx_hashes: Vec<H256>,
     ) -> Result<Vec<Option<TypedTransaction>>> {
-        self.transaction_store.get_transactions(tx_hashes)
+        let mut transactions = self.transaction_store.get_transactions(tx_hashes.clone())?;
+        let missing: Vec<usize> = transactions
+            .iter()
+            .enumerate()
+            .filter(|(_, transaction)| transaction.is_none())
+            .map(|(index, _)| index)
+            .collect();
+        if missing.is_empty() {
+            return Ok(transactions);
+        }
+        let records = self
+            .cold_store
+            .get_records(missing.iter().map(|index| tx_hashes[*index]).collect())?;
+        for (index, record) in missing.into_iter().zip(records) {
+            transactions[index] = record.and_then(|record| record.transaction);
+        }
+        Ok(transactions)
     }
 
     fn save_tx_sequence_info(&self, tx_sequence_info: TransactionSequenceInfo) -> Result<()> {
@@ -159,3 +333,98 @@ impl MetaStore for RoochStore {
         self.get_meta_store().save_sequencer_order(sequencer_order)
     }
 }
+
+impl GasStatisticsStore for RoochStore {
+    fn record_function_gas_used(&self, function_id: FunctionId, gas_used: u64) -> Result<()> {
+        self.get_gas_statistics_store()
+            .record_function_gas_used(function_id, gas_used)
+    }
+
+    fn get_function_gas_statistics(
+        &self,
+        function_ids: Vec<FunctionId>,
+    ) -> Result<Vec<Option<FunctionGasStatistics>>> {
+        self.get_gas_statistics_store()
+            .get_function_gas_statistics(function_ids)
+    }
+
+    fn list_function_gas_statistics(&self) -> Result<Vec<FunctionGasStatistics>> {
+        self.get_gas_statistics_store()
+            .list_function_gas_statistics()
+    }
+}
+
+impl MigrationStore for RoochStore {
+    fn get_migration_checkpoint(&self, name: &str) -> Result<Option<MigrationCheckpoint>> {
+        self.get_migration_store().get_migration_checkpoint(name)
+    }
+
+    fn save_migration_checkpoint(&self, name: &str, checkpoint: MigrationCheckpoint) -> Result<()> {
+        self.get_migration_store()
+            .save_migration_checkpoint(name, checkpoint)
+    }
+}
+
+impl BatchStore for RoochStore {
+    fn save_batch(&self, batch: SequencerBatch) -> Result<()> {
+        self.get_batch_store().save_batch(batch)
+    }
+
+    fn get_batch(&self, batch_id: u128) -> Result<Option<SequencerBatch>> {
+        self.get_batch_store().get_batch(batch_id)
+    }
+
+    fn get_last_batch(&self) -> Result<Option<SequencerBatch>> {
+        self.get_batch_store().get_last_batch()
+    }
+}
+
+impl SourceRegistryStore for RoochStore {
+    fn save_source_verification(&self, verification: SourceVerification) -> Result<()> {
+        self.get_source_registry_store()
+            .save_source_verification(verification)
+    }
+
+    fn get_source_verification(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<SourceVerification>> {
+        self.get_source_registry_store()
+            .get_source_verification(package_address)
+    }
+}
+
+impl PendingTxStore for RoochStore {
+    fn save_pending_transaction(&self, tx_hash: H256, tx: PendingTransaction) -> Result<()> {
+        self.get_pending_tx_store()
+            .save_pending_transaction(tx_hash, tx)
+    }
+
+    fn remove_pending_transaction(&self, tx_hash: H256) -> Result<()> {
+        self.get_pending_tx_store()
+            .remove_pending_transaction(tx_hash)
+    }
+
+    fn get_pending_transaction(&self, tx_hash: H256) -> Result<Option<PendingTransaction>> {
+        self.get_pending_tx_store().get_pending_transaction(tx_hash)
+    }
+
+    fn list_pending_transactions(&self) -> Result<Vec<(H256, PendingTransaction)>> {
+        self.get_pending_tx_store().list_pending_transactions()
+    }
+}
+
+impl ChangeFeedStore for RoochStore {
+    fn save_replica_change_set(&self, tx_hash: H256, change_set: ReplicaChangeSet) -> Result<()> {
+        self.get_change_feed_store()
+            .save_replica_change_set(tx_hash, change_set)
+    }
+
+    fn get_replica_change_sets(
+        &self,
+        tx_hashes: Vec<H256>,
+    ) -> Result<Vec<Option<ReplicaChangeSet>>> {
+        self.get_change_feed_store()
+            .get_replica_change_sets(tx_hashes)
+    }
+}
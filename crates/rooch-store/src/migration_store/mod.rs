@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::MIGRATION_CHECKPOINT_PREFIX_NAME;
+use anyhow::Result;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use rooch_types::migration::MigrationCheckpoint;
+
+derive_store!(
+    MigrationCheckpointStore,
+    String,
+    MigrationCheckpoint,
+    MIGRATION_CHECKPOINT_PREFIX_NAME
+);
+
+pub trait MigrationStore {
+    fn get_migration_checkpoint(&self, name: &str) -> Result<Option<MigrationCheckpoint>>;
+
+    fn save_migration_checkpoint(&self, name: &str, checkpoint: MigrationCheckpoint) -> Result<()>;
+}
+
+#[derive(Clone)]
+pub struct MigrationDBStore {
+    checkpoint_store: MigrationCheckpointStore,
+}
+
+impl MigrationDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        MigrationDBStore {
+            checkpoint_store: MigrationCheckpointStore::new(instance),
+        }
+    }
+
+    pub fn get_migration_checkpoint(&self, name: &str) -> Result<Option<MigrationCheckpoint>> {
+        self.checkpoint_store.kv_get(name.to_owned())
+    }
+
+    pub fn save_migration_checkpoint(
+        &self,
+        name: &str,
+        checkpoint: MigrationCheckpoint,
+    ) -> Result<()> {
+        self.checkpoint_store.kv_put(name.to_owned(), checkpoint)
+    }
+}
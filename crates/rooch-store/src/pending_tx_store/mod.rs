@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::PENDING_TRANSACTION_PREFIX_NAME;
+use anyhow::Result;
+use moveos_types::h256::H256;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use rooch_types::sequencer::PendingTransaction;
+
+derive_store!(
+    PendingTransactionStore,
+    H256,
+    PendingTransaction,
+    PENDING_TRANSACTION_PREFIX_NAME
+);
+
+pub trait PendingTxStore {
+    fn save_pending_transaction(&self, tx_hash: H256, tx: PendingTransaction) -> Result<()>;
+
+    fn remove_pending_transaction(&self, tx_hash: H256) -> Result<()>;
+
+    fn get_pending_transaction(&self, tx_hash: H256) -> Result<Option<PendingTransaction>>;
+
+    fn list_pending_transactions(&self) -> Result<Vec<(H256, PendingTransaction)>>;
+}
+
+#[derive(Clone)]
+pub struct PendingTxDBStore {
+    pending_transaction_store: PendingTransactionStore,
+}
+
+impl PendingTxDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        PendingTxDBStore {
+            pending_transaction_store: PendingTransactionStore::new(instance),
+        }
+    }
+
+    /// The transaction is acknowledged to the sender after it is saved, so it is written synchronously
+    pub fn save_pending_transaction(&self, tx_hash: H256, tx: PendingTransaction) -> Result<()> {
+        self.pending_transaction_store.put_sync(tx_hash, tx)
+    }
+
+    pub fn remove_pending_transaction(&self, tx_hash: H256) -> Result<()> {
+        self.pending_transaction_store.remove(tx_hash)
+    }
+
+    pub fn get_pending_transaction(&self, tx_hash: H256) -> Result<Option<PendingTransaction>> {
+        self.pending_transaction_store.kv_get(tx_hash)
+    }
+
+    /// List the pending transactions in the accepted order
+    pub fn list_pending_transactions(&self) -> Result<Vec<(H256, PendingTransaction)>> {
+        let mut iter = self.pending_transaction_store.iter()?;
+        iter.seek_to_first();
+        let mut txs = iter.collect::<Result<Vec<_>>>()?;
+        txs.sort_by_key(|(_, tx)| tx.accept_order);
+        Ok(txs)
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::SOURCE_VERIFICATION_PREFIX_NAME;
+use anyhow::Result;
+use move_core_types::account_address::AccountAddress;
+use raw_store::{derive_store, CodecKVStore, StoreInstance};
+use rooch_types::source_verification::SourceVerification;
+
+derive_store!(
+    SourceVerificationStore,
+    AccountAddress,
+    SourceVerification,
+    SOURCE_VERIFICATION_PREFIX_NAME
+);
+
+pub trait SourceRegistryStore {
+    fn save_source_verification(&self, verification: SourceVerification) -> Result<()>;
+
+    fn get_source_verification(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<SourceVerification>>;
+}
+
+#[derive(Clone)]
+pub struct SourceRegistryDBStore {
+    source_verification_store: SourceVerificationStore,
+}
+
+impl SourceRegistryDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        SourceRegistryDBStore {
+            source_verification_store: SourceVerificationStore::new(instance),
+        }
+    }
+
+    /// The latest verification of a package overrides the previous one
+    pub fn save_source_verification(&self, verification: SourceVerification) -> Result<()> {
+        self.source_verification_store
+            .put_sync(verification.source.package_address, verification)
+    }
+
+    pub fn get_source_verification(
+        &self,
+        package_address: AccountAddress,
+    ) -> Result<Option<SourceVerification>> {
+        self.source_verification_store.kv_get(package_address)
+    }
+}
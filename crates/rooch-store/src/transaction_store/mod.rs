@@ -12,7 +12,8 @@ use crate::{
     TX_SEQUENCE_INFO_MAPPING_PREFIX_NAME, TX_SEQUENCE_INFO_PREFIX_NAME,
     TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME, TYPED_TRANSACTION_PREFIX_NAME,
 };
-use raw_store::{derive_store, StoreInstance};
+use raw_store::rocks::batch::WriteBatch;
+use raw_store::{derive_store, CodecWriteBatch, ColumnFamilyName, StoreInstance};
 
 derive_store!(
     TypedTransactionStore,
@@ -101,6 +102,15 @@ impl TransactionDBStore {
         self.tx_store.multiple_get(tx_hashes)
     }
 
+    /// The batch to delete the transactions from the hot store, it is written together with the index of the cold store
+    pub fn delete_transactions_batch(
+        &self,
+        tx_hashes: Vec<H256>,
+    ) -> Result<(ColumnFamilyName, WriteBatch)> {
+        CodecWriteBatch::<H256, TypedTransaction>::new_deletes(tx_hashes)
+            .into_cf_batch::<TypedTransactionStore>()
+    }
+
     pub fn save_tx_sequence_info(&self, tx_sequence_info: TransactionSequenceInfo) -> Result<()> {
         self.tx_sequence_info_store
             .kv_put(tx_sequence_info.tx_order, tx_sequence_info)
@@ -20,6 +20,20 @@ pub const BITCOIN_MOVE_ADDRESS: AccountAddress = {
     AccountAddress::new(addr)
 };
 
+/// The addresses reserved for the framework versions deployed side by side with the framework at `0x3`.
+/// The natives of the framework are registered at these addresses too.
+pub const ROOCH_FRAMEWORK_VERSION_ADDRESSES: [AccountAddress; 6] = {
+    let mut addresses = [AccountAddress::ZERO; 6];
+    let mut i = 0;
+    while i < addresses.len() {
+        let mut addr = [0u8; AccountAddress::LENGTH];
+        addr[AccountAddress::LENGTH - 1] = 5u8 + i as u8;
+        addresses[i] = AccountAddress::new(addr);
+        i += 1;
+    }
+    addresses
+};
+
 pub static ROOCH_NAMED_ADDRESS_MAPPING: [(&str, &str); 2] = [
     (
         ROOCH_FRAMEWORK_ADDRESS_NAME,
@@ -1,12 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use moveos_types::h256::H256;
+use anyhow::{ensure, Result};
+use move_core_types::vm_status::KeptVMStatus;
+#[cfg(any(test, feature = "fuzzing"))]
+use moveos_types::h256::h256_prop_strategy;
+use moveos_types::h256::{self, H256};
+use moveos_types::transaction::TransactionExecutionInfo;
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::{collection::vec, prelude::*};
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
 /// The block in Rooch is constructed by the proposer, representing a batch of transactions
 /// How many transactions in the block is determined by the proposer
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct Block {
     /// The index if the block
     //TODO should we use the U256?
@@ -14,12 +24,30 @@ pub struct Block {
     /// How many transactions in the block
     pub batch_size: u64,
     /// The previous tx accumulator root of the block
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "h256_prop_strategy()")
+    )]
     pub prev_tx_accumulator_root: H256,
     /// The tx accumulator root after the last transaction append to the accumulator
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "h256_prop_strategy()")
+    )]
     pub tx_accumulator_root: H256,
     /// The all transaction's state root
     //TODO should we keep all the state root in the block?
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "vec(h256_prop_strategy(), 0..16)")
+    )]
     pub state_roots: Vec<H256>,
+    /// The checksum of the execution results of the transactions in the block, see `execution_checksum`
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "h256_prop_strategy()")
+    )]
+    pub execution_checksum: H256,
 }
 
 impl Block {
@@ -29,6 +57,7 @@ impl Block {
         prev_tx_accumulator_root: H256,
         tx_accumulator_root: H256,
         state_roots: Vec<H256>,
+        execution_checksum: H256,
     ) -> Self {
         Self {
             block_number,
@@ -36,6 +65,87 @@ impl Block {
             prev_tx_accumulator_root,
             tx_accumulator_root,
             state_roots,
+            execution_checksum,
+        }
+    }
+
+    /// Verify the execution results of the transactions in the block executed locally,
+    /// the follower nodes call it after executing the block to detect the divergence from the sequencer.
+    pub fn verify_execution_checksum<'a>(
+        &self,
+        execution_infos: impl IntoIterator<Item = &'a TransactionExecutionInfo>,
+    ) -> Result<()> {
+        let local_checksum = execution_checksum(execution_infos);
+        ensure!(
+            local_checksum == self.execution_checksum,
+            "The execution diverges at block {}, the execution checksum of the block is {:?}, but the local is {:?}",
+            self.block_number,
+            self.execution_checksum,
+            local_checksum
+        );
+        Ok(())
+    }
+}
+
+/// The checksum of the execution results in a block, it is `sha3_256(bcs([(tx_hash, status, gas_used, state_root)]))`
+/// of the transactions in the sequenced order, so any divergence in the execution changes the checksum.
+pub fn execution_checksum<'a>(
+    execution_infos: impl IntoIterator<Item = &'a TransactionExecutionInfo>,
+) -> H256 {
+    let results = execution_infos
+        .into_iter()
+        .map(|info| (info.tx_hash, &info.status, info.gas_used, info.state_root))
+        .collect::<Vec<(H256, &KeptVMStatus, u64, H256)>>();
+    let bytes = bcs::to_bytes(&results).expect("Serialize the execution results should success");
+    h256::sha3_256_of(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::transaction::GasBreakdown;
+
+    fn execution_info(gas_used: u64, status: KeptVMStatus) -> TransactionExecutionInfo {
+        TransactionExecutionInfo::new(
+            H256::random(),
+            H256::random(),
+            H256::random(),
+            gas_used,
+            status,
+            None,
+            GasBreakdown::default(),
+        )
+    }
+
+    #[test]
+    fn test_verify_execution_checksum() {
+        let infos = vec![
+            execution_info(100, KeptVMStatus::Executed),
+            execution_info(200, KeptVMStatus::OutOfGas),
+        ];
+        let block = Block::new(
+            1,
+            infos.len() as u64,
+            H256::zero(),
+            H256::random(),
+            infos.iter().map(|info| info.state_root).collect(),
+            execution_checksum(&infos),
+        );
+        block.verify_execution_checksum(&infos).unwrap();
+
+        let mut diverged = infos.clone();
+        diverged[1].gas_used += 1;
+        assert!(block.verify_execution_checksum(&diverged).is_err());
+        let reordered = vec![infos[1].clone(), infos[0].clone()];
+        assert!(block.verify_execution_checksum(&reordered).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_block_bcs_serde(input in any::<Block>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: Block = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
         }
     }
 }
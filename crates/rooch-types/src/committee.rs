@@ -0,0 +1,346 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The threshold BLS signatures of a sequencer committee. The committee key is split into the key shares of the
+//! operators with a `threshold`-of-`n` Shamir secret sharing, every operator signs a batch commitment with its share,
+//! and any `threshold` valid partial signatures are aggregated into the signature of the committee key.
+//! The signatures are in G1 and the public keys in G2, the aggregated signature is 48 bytes.
+
+use anyhow::{anyhow, bail, ensure, Result};
+use fastcrypto::groups::bls12381::{G1Element, G2Element, Scalar};
+use fastcrypto::groups::{GroupElement, HashToGroupElement, Pairing, Scalar as _};
+use fastcrypto::serde_helpers::ToFromByteArray;
+use fastcrypto::traits::AllowedRng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+
+/// The domain separation tag prepended to the signed messages
+pub const COMMITTEE_SIGNING_DOMAIN: &[u8] = b"ROOCH_SEQUENCER_COMMITTEE";
+
+/// The length of a partial or an aggregated signature, a compressed G1 point
+pub const THRESHOLD_SIGNATURE_LENGTH: usize = 48;
+
+/// The index of an operator in the committee, it starts from 1, the share of the index 0 is the committee secret
+pub type OperatorIndex = u16;
+
+/// The public keys of a committee, the operators' public key shares verify the partial signatures,
+/// and the committee public key verifies the aggregated signatures.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CommitteePublicKey {
+    pub threshold: u16,
+    pub public_key: G2Element,
+    /// The public key share of the operator `i` is at `i - 1`
+    pub public_shares: Vec<G2Element>,
+}
+
+/// The secret key share of an operator
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KeyShare {
+    pub index: OperatorIndex,
+    secret: Scalar,
+}
+
+impl fmt::Debug for KeyShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyShare")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PartialSignature {
+    pub index: OperatorIndex,
+    pub signature: G1Element,
+}
+
+impl PartialSignature {
+    pub fn signature_bytes(&self) -> Vec<u8> {
+        self.signature.to_byte_array().to_vec()
+    }
+
+    pub fn from_bytes(index: OperatorIndex, bytes: &[u8]) -> Result<Self> {
+        Ok(Self {
+            index,
+            signature: signature_from_bytes(bytes)?,
+        })
+    }
+}
+
+/// The signature of the committee key aggregated from the partial signatures
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdSignature(G1Element);
+
+impl ThresholdSignature {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_byte_array().to_vec()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        signature_from_bytes(bytes).map(Self)
+    }
+}
+
+fn signature_from_bytes(bytes: &[u8]) -> Result<G1Element> {
+    let bytes: [u8; THRESHOLD_SIGNATURE_LENGTH] = bytes.try_into().map_err(|_| {
+        anyhow!(
+            "The threshold signature should be {} bytes, got {}",
+            THRESHOLD_SIGNATURE_LENGTH,
+            bytes.len()
+        )
+    })?;
+    G1Element::from_byte_array(&bytes).map_err(|e| anyhow!("Invalid threshold signature: {:?}", e))
+}
+
+/// The key file of an operator node, it holds the committee public key and the key shares of the operators run by the node
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CommitteeKeyFile {
+    pub public_key: CommitteePublicKey,
+    pub shares: Vec<KeyShare>,
+}
+
+impl CommitteeKeyFile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read(path)
+            .map_err(|e| anyhow!("Read the committee key file {:?} failed: {}", path, e))?;
+        let key_file: Self = serde_json::from_slice(&content)
+            .map_err(|e| anyhow!("Invalid committee key file {:?}: {}", path, e))?;
+        for share in &key_file.shares {
+            ensure!(
+                key_file.public_key.public_share(share.index)? == &share.public_share(),
+                "The key share {} in {:?} mismatches the committee public key",
+                share.index,
+                path
+            );
+        }
+        Ok(key_file)
+    }
+
+    /// Write the key file readable by the owner only, it holds the secret key shares
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options
+            .open(path)
+            .map_err(|e| anyhow!("Create the committee key file {:?} failed: {}", path, e))?;
+        // The mode only applies to a new file, restrict the existing one as well
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(&serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Generate the committee key and split it to the key shares of `committee_size` operators with a trusted dealer,
+/// any `threshold` of the operators can sign for the committee.
+pub fn generate_committee_keys<R: AllowedRng>(
+    threshold: u16,
+    committee_size: u16,
+    rng: &mut R,
+) -> Result<(CommitteePublicKey, Vec<KeyShare>)> {
+    ensure!(
+        threshold >= 1 && threshold <= committee_size,
+        "The threshold {} should be in [1, {}]",
+        threshold,
+        committee_size
+    );
+    // The committee secret is the constant term of the polynomial of degree `threshold - 1`
+    let coefficients: Vec<Scalar> = (0..threshold).map(|_| Scalar::rand(rng)).collect();
+    let shares: Vec<KeyShare> = (1..=committee_size)
+        .map(|index| KeyShare {
+            index,
+            secret: evaluate_polynomial(&coefficients, index),
+        })
+        .collect();
+    let public_key = CommitteePublicKey {
+        threshold,
+        public_key: G2Element::generator() * coefficients[0],
+        public_shares: shares.iter().map(KeyShare::public_share).collect(),
+    };
+    Ok((public_key, shares))
+}
+
+impl KeyShare {
+    pub fn public_share(&self) -> G2Element {
+        G2Element::generator() * self.secret
+    }
+
+    pub fn sign(&self, msg: &[u8]) -> PartialSignature {
+        PartialSignature {
+            index: self.index,
+            signature: hash_message(msg) * self.secret,
+        }
+    }
+}
+
+impl CommitteePublicKey {
+    pub fn committee_size(&self) -> u16 {
+        self.public_shares.len() as u16
+    }
+
+    pub fn public_share(&self, index: OperatorIndex) -> Result<&G2Element> {
+        index
+            .checked_sub(1)
+            .and_then(|i| self.public_shares.get(i as usize))
+            .ok_or_else(|| anyhow!("The operator {} is not in the committee", index))
+    }
+
+    pub fn verify_partial(&self, msg: &[u8], partial: &PartialSignature) -> Result<()> {
+        let public_share = self.public_share(partial.index)?;
+        ensure!(
+            verify_pairing(msg, &partial.signature, public_share),
+            "Invalid partial signature of the operator {}",
+            partial.index
+        );
+        Ok(())
+    }
+
+    /// Aggregate the partial signatures of `msg`, the invalid and the duplicated partial signatures are skipped,
+    /// it fails if there are less than `threshold` valid ones.
+    pub fn aggregate(
+        &self,
+        msg: &[u8],
+        partials: &[PartialSignature],
+    ) -> Result<ThresholdSignature> {
+        let mut valid = BTreeMap::new();
+        for partial in partials {
+            if valid.len() == self.threshold as usize {
+                break;
+            }
+            if valid.contains_key(&partial.index) || self.verify_partial(msg, partial).is_err() {
+                continue;
+            }
+            valid.insert(partial.index, partial.signature);
+        }
+        if valid.len() < self.threshold as usize {
+            bail!(
+                "Got {} valid partial signatures, the threshold is {}",
+                valid.len(),
+                self.threshold
+            );
+        }
+        let indexes: Vec<OperatorIndex> = valid.keys().copied().collect();
+        let mut signature = G1Element::zero();
+        for (index, partial_signature) in valid {
+            signature = signature + partial_signature * lagrange_coefficient(index, &indexes)?;
+        }
+        Ok(ThresholdSignature(signature))
+    }
+
+    pub fn verify(&self, msg: &[u8], signature: &ThresholdSignature) -> Result<()> {
+        ensure!(
+            verify_pairing(msg, &signature.0, &self.public_key),
+            "Invalid threshold signature of the committee"
+        );
+        Ok(())
+    }
+}
+
+fn hash_message(msg: &[u8]) -> G1Element {
+    G1Element::hash_to_group_element(&[COMMITTEE_SIGNING_DOMAIN, msg].concat())
+}
+
+/// e(signature, g2) == e(H(msg), public_key)
+fn verify_pairing(msg: &[u8], signature: &G1Element, public_key: &G2Element) -> bool {
+    signature.pairing(&G2Element::generator()) == hash_message(msg).pairing(public_key)
+}
+
+fn index_scalar(index: OperatorIndex) -> Scalar {
+    Scalar::from(index as u128)
+}
+
+fn evaluate_polynomial(coefficients: &[Scalar], index: OperatorIndex) -> Scalar {
+    let x = index_scalar(index);
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, coefficient| acc * x + *coefficient)
+}
+
+/// The Lagrange coefficient of the operator `index` to interpolate the polynomial at 0 with the shares of `indexes`
+fn lagrange_coefficient(index: OperatorIndex, indexes: &[OperatorIndex]) -> Result<Scalar> {
+    let x_i = index_scalar(index);
+    let mut numerator = Scalar::generator();
+    let mut denominator = Scalar::generator();
+    for &other in indexes.iter().filter(|&&other| other != index) {
+        let x_j = index_scalar(other);
+        numerator = numerator * x_j;
+        denominator = denominator * (x_j - x_i);
+    }
+    let inverse = denominator
+        .inverse()
+        .map_err(|e| anyhow!("Invalid operator indexes {:?}: {:?}", indexes, e))?;
+    Ok(numerator * inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold_signature() {
+        let mut rng = rand::thread_rng();
+        let (public_key, shares) = generate_committee_keys(3, 5, &mut rng).unwrap();
+        let msg = b"batch commitment";
+
+        let partials: Vec<PartialSignature> = shares.iter().map(|share| share.sign(msg)).collect();
+        for partial in &partials {
+            public_key.verify_partial(msg, partial).unwrap();
+        }
+        // Any threshold partial signatures aggregate to the same signature
+        let signature = public_key.aggregate(msg, &partials[..3]).unwrap();
+        public_key.verify(msg, &signature).unwrap();
+        assert_eq!(
+            public_key.aggregate(msg, &partials[2..]).unwrap(),
+            signature
+        );
+        assert!(public_key.verify(b"another batch", &signature).is_err());
+        assert_eq!(
+            ThresholdSignature::from_bytes(&signature.to_bytes()).unwrap(),
+            signature
+        );
+
+        // The invalid and the duplicated partial signatures do not count
+        let forged = PartialSignature {
+            index: 4,
+            signature: shares[0].sign(msg).signature,
+        };
+        assert!(public_key.verify_partial(msg, &forged).is_err());
+        assert!(public_key
+            .aggregate(msg, &[partials[0], partials[0], forged, partials[1]])
+            .is_err());
+        assert!(generate_committee_keys(6, 5, &mut rng).is_err());
+        assert_eq!(
+            PartialSignature::from_bytes(partials[0].index, &partials[0].signature_bytes())
+                .unwrap(),
+            partials[0]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_key_file_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (public_key, shares) = generate_committee_keys(1, 1, &mut rand::thread_rng()).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("committee_operator_1.key.json");
+        std::fs::write(&path, b"").unwrap();
+        let key_file = CommitteeKeyFile { public_key, shares };
+        key_file.save(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(CommitteeKeyFile::load(&path).unwrap(), key_file);
+    }
+}
@@ -360,12 +360,20 @@ impl Signature {
     }
 
     pub fn new_secure<T>(value: &T, secret: &dyn Signer<Signature>) -> Self
+    where
+        T: Serialize,
+    {
+        Signer::sign(secret, &Self::secure_digest(value))
+    }
+
+    /// The digest of the message signed by `new_secure`, the hash of the BCS serialized message.
+    pub fn secure_digest<T>(value: &T) -> [u8; 32]
     where
         T: Serialize,
     {
         let mut hasher = DefaultHash::default();
         hasher.update(&bcs::to_bytes(&value).expect("Message serialization should not fail"));
-        Signer::sign(secret, &hasher.finalize().digest)
+        hasher.finalize().digest
     }
 
     /// Parse [enum CompressedSignature] from trait Signature `flag || sig || pk`.
@@ -381,6 +389,19 @@ impl Signature {
         ))
     }
 
+    /// Verify the signature over the hashed message, and the signer must be the `author`.
+    pub fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> RoochResult<()> {
+        match self {
+            Signature::Ed25519RoochSignature(signature) => {
+                let (sig, pk) = signature.get_verification_inputs(author)?;
+                pk.verify(hashed_msg, &sig)
+                    .map_err(|e| RoochError::InvalidSignature {
+                        error: format!("Fail to verify hashed sig {}", e),
+                    })
+            }
+        }
+    }
+
     /// Parse [struct PublicKey] from trait RoochSignature `flag || sig || pk`.
     /// This is useful for the MultiSig to construct the bitmap in [struct MultiPublicKey].
     pub fn to_public_key(&self) -> Result<PublicKey, RoochError> {
@@ -0,0 +1,146 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use accumulator::inmemory::InMemoryAccumulator;
+use accumulator::proof::AccumulatorProof;
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::event::Event;
+use moveos_types::transaction::TransactionExecutionInfo;
+use serde::{Deserialize, Serialize};
+
+/// The proof that an event is included in the event accumulator of a transaction.
+/// The root of the accumulator is the `event_root` in the `TransactionExecutionInfo`,
+/// so a light client can trust the event occurrence with a trusted execution info, without replaying the execution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventProof {
+    pub tx_hash: H256,
+    /// The index of the event leaf in the transaction event accumulator
+    pub leaf_index: u64,
+    pub proof: AccumulatorProof,
+}
+
+impl EventProof {
+    pub fn new(tx_hash: H256, leaf_index: u64, siblings: Vec<H256>) -> Self {
+        Self {
+            tx_hash,
+            leaf_index,
+            proof: AccumulatorProof::new(siblings),
+        }
+    }
+
+    /// The siblings from the event leaf to the root
+    pub fn siblings(&self) -> &[H256] {
+        self.proof.siblings()
+    }
+
+    /// Build the proof of the leaf at `leaf_index` from all the event hashes of the transaction
+    pub fn build(tx_hash: H256, event_hashes: &[H256], leaf_index: u64) -> Result<Self> {
+        ensure!(
+            (leaf_index as usize) < event_hashes.len(),
+            "The leaf index {} is out of the event count {} of the transaction {:?}",
+            leaf_index,
+            event_hashes.len(),
+            tx_hash
+        );
+        let proof = InMemoryAccumulator::get_proof_from_leaves(event_hashes, leaf_index)?;
+        Ok(Self {
+            tx_hash,
+            leaf_index,
+            proof,
+        })
+    }
+
+    /// Verify the event, with its id, type and data, is included in the event accumulator whose root is `event_root`
+    pub fn verify(&self, event_root: H256, event: &Event) -> Result<()> {
+        self.proof.verify(event_root, event.hash(), self.leaf_index)
+    }
+
+    /// Verify the event is emitted by the transaction of the trusted execution info
+    pub fn verify_event(
+        &self,
+        tx_execution_info: &TransactionExecutionInfo,
+        event: &Event,
+    ) -> Result<()> {
+        ensure!(
+            self.tx_hash == tx_execution_info.tx_hash,
+            "The tx hash of the proof {:?} does not match the execution info {:?}",
+            self.tx_hash,
+            tx_execution_info.tx_hash
+        );
+        self.verify(tx_execution_info.event_root, event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::ident_str;
+    use move_core_types::language_storage::StructTag;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::moveos_std::event::EventID;
+    use moveos_types::moveos_std::object::ObjectID;
+    use moveos_types::transaction::GasBreakdown;
+
+    #[test]
+    fn test_event_proof() {
+        let event_type = StructTag {
+            address: AccountAddress::ONE,
+            module: ident_str!("test").to_owned(),
+            name: ident_str!("TestEvent").to_owned(),
+            type_params: vec![],
+        };
+        let event_handle_id = ObjectID::from(AccountAddress::random());
+        let events = (0..5u8)
+            .map(|i| {
+                Event::new(
+                    EventID::new(event_handle_id, i as u64),
+                    event_type.clone(),
+                    vec![i; 8],
+                    i as u64,
+                )
+            })
+            .collect::<Vec<_>>();
+        let event_hashes = events.iter().map(|event| event.hash()).collect::<Vec<_>>();
+        let event_root = InMemoryAccumulator::from_leaves(&event_hashes).root_hash();
+        let tx_hash = H256::random();
+
+        for (index, event) in events.iter().enumerate() {
+            let proof = EventProof::build(tx_hash, &event_hashes, index as u64).unwrap();
+            proof.verify(event_root, event).unwrap();
+            assert!(proof.verify(H256::random(), event).is_err());
+
+            let mut tampered_data = event.clone();
+            tampered_data.event_data = b"tampered".to_vec();
+            assert!(proof.verify(event_root, &tampered_data).is_err());
+            let mut tampered_id = event.clone();
+            tampered_id.event_id = EventID::new(ObjectID::from(AccountAddress::random()), 0);
+            assert!(proof.verify(event_root, &tampered_id).is_err());
+            let mut tampered_type = event.clone();
+            tampered_type.event_type.name = ident_str!("OtherEvent").to_owned();
+            assert!(proof.verify(event_root, &tampered_type).is_err());
+
+            let rebuilt = EventProof::new(tx_hash, proof.leaf_index, proof.siblings().to_vec());
+            assert_eq!(rebuilt, proof);
+        }
+        assert!(EventProof::build(tx_hash, &event_hashes, 5).is_err());
+
+        let execution_info = TransactionExecutionInfo::new(
+            tx_hash,
+            H256::random(),
+            event_root,
+            0,
+            KeptVMStatus::Executed,
+            None,
+            GasBreakdown::default(),
+        );
+        let proof = EventProof::build(tx_hash, &event_hashes, 1).unwrap();
+        let other_tx_proof = EventProof::new(H256::random(), 1, proof.siblings().to_vec());
+        proof.verify_event(&execution_info, &events[1]).unwrap();
+        assert!(proof.verify_event(&execution_info, &events[2]).is_err());
+        assert!(other_tx_proof
+            .verify_event(&execution_info, &events[1])
+            .is_err());
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::address::MultiChainAddress;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::MoveState,
+    transaction::FunctionCall,
+};
+
+/// Rust bindings for RoochFramework bitcoin_validator module
+pub struct BitcoinValidatorModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> BitcoinValidatorModule<'a> {
+    const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+
+    /// Validate the authenticator payload, the signature followed by the BCS bytes of the Bitcoin `MultiChainAddress`,
+    /// and return the Bitcoin address of the sender.
+    pub fn validate(&self, ctx: &TxContext, payload: Vec<u8>) -> Result<MultiChainAddress> {
+        let auth_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(payload).simple_serialize().unwrap()],
+        );
+        let address = self
+            .caller
+            .call_function(ctx, auth_validator_call)?
+            .into_result()
+            .map(|values| {
+                let value = values.get(0).expect("Expected return value");
+                MultiChainAddress::from_bytes(&value.value).expect("Expected MultiChainAddress")
+            })?;
+        Ok(address)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for BitcoinValidatorModule<'a> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("bitcoin_validator");
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    moveos_std::object::{self, ObjectID},
+    state::{MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("capability");
+
+/// The capability to upgrade the framework packages
+#[derive(Debug, Clone)]
+pub struct UpgradeCap;
+
+impl MoveStructType for UpgradeCap {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("UpgradeCap");
+}
+
+impl UpgradeCap {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+}
+
+/// The capability to mint and burn the gas coin
+#[derive(Debug, Clone)]
+pub struct TreasuryCap;
+
+impl MoveStructType for TreasuryCap {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TreasuryCap");
+}
+
+impl TreasuryCap {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+}
+
+/// The capability of a package publisher
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublisherCap {
+    pub package: AccountAddress,
+}
+
+impl MoveStructType for PublisherCap {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PublisherCap");
+}
+
+impl MoveStructState for PublisherCap {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::Address,
+        ])
+    }
+}
+
+impl PublisherCap {
+    pub fn object_id(package: AccountAddress) -> ObjectID {
+        object::account_named_object_id(package, &Self::struct_tag())
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::MoveStructType,
+    transaction::MoveAction,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("chain_pause");
+
+/// The event emitted when the chain is paused
+pub struct ChainPausedEvent;
+
+impl MoveStructType for ChainPausedEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ChainPausedEvent");
+}
+
+/// The event emitted when the chain is unpaused
+pub struct ChainUnpausedEvent;
+
+impl MoveStructType for ChainUnpausedEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ChainUnpausedEvent");
+}
+
+/// The event emitted when the relayers registered as the system senders are updated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayersUpdatedEvent {
+    pub relayers: Vec<AccountAddress>,
+}
+
+impl MoveStructType for RelayersUpdatedEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RelayersUpdatedEvent");
+}
+
+/// Rust bindings for RoochFramework chain_pause module
+pub struct ChainPauseModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> ChainPauseModule<'a> {
+    pub const IS_PAUSED_FUNCTION_NAME: &'static IdentStr = ident_str!("is_paused");
+    pub const PAUSE_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("pause");
+    pub const UNPAUSE_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("unpause");
+    pub const RELAYERS_FUNCTION_NAME: &'static IdentStr = ident_str!("relayers");
+    pub const ADD_RELAYER_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("add_relayer");
+
+    pub fn is_paused(&self) -> Result<bool> {
+        let call = Self::create_function_call(Self::IS_PAUSED_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let paused = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+            })?;
+        Ok(paused)
+    }
+
+    /// The relayers registered as the system senders besides the sequencer
+    pub fn relayers(&self) -> Result<Vec<AccountAddress>> {
+        let call = Self::create_function_call(Self::RELAYERS_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let relayers = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<AccountAddress>>(&value.value)
+                    .expect("should be a valid vector<address>")
+            })?;
+        Ok(relayers)
+    }
+
+    pub fn create_pause_action(reason: String) -> MoveAction {
+        Self::create_move_action(
+            Self::PAUSE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(reason.into_bytes())],
+        )
+    }
+
+    pub fn create_unpause_action() -> MoveAction {
+        Self::create_move_action(Self::UNPAUSE_ENTRY_FUNCTION_NAME, vec![], vec![])
+    }
+
+    pub fn create_add_relayer_action(relayer: AccountAddress) -> MoveAction {
+        Self::create_move_action(
+            Self::ADD_RELAYER_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(relayer)],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for ChainPauseModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::{anyhow, bail, Result};
+use move_binary_format::file_format::AbilitySet;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    language_storage::{StructTag, TypeTag},
+    value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue},
+};
+use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    transaction::{FunctionCall, MoveAction},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("event_schema_registry");
+
+/// A registered version of the layout of an event struct
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EventSchema {
+    pub version: u64,
+    /// The BCS bytes of the annotated `MoveStructLayout`
+    pub layout: Vec<u8>,
+    /// The time in milliseconds when the schema is registered
+    pub registered_at: u64,
+}
+
+impl EventSchema {
+    pub fn struct_layout(&self) -> Result<MoveStructLayout> {
+        bcs::from_bytes::<MoveStructLayout>(&self.layout).map_err(|e| {
+            anyhow!(
+                "Invalid layout of the event schema version {}: {:?}",
+                self.version,
+                e
+            )
+        })
+    }
+
+    /// Decode the event data with the layout of this schema, the data must be fully consumed by the layout.
+    pub fn decode(&self, event_data: &[u8]) -> Result<AnnotatedMoveStruct> {
+        let layout = self.struct_layout()?;
+        let value = MoveStruct::simple_deserialize(event_data, &layout)?;
+        annotate_struct(&layout, value)
+    }
+}
+
+/// Decode the event data with the registered schemas, the latest version is tried first.
+pub fn decode_with_schemas(
+    schemas: &[EventSchema],
+    event_data: &[u8],
+) -> Result<(u64, AnnotatedMoveStruct)> {
+    for schema in schemas.iter().rev() {
+        if let Ok(value) = schema.decode(event_data) {
+            return Ok((schema.version, value));
+        }
+    }
+    bail!(
+        "Failed to decode the event data with the {} registered schemas",
+        schemas.len()
+    )
+}
+
+fn annotate_struct(layout: &MoveStructLayout, value: MoveStruct) -> Result<AnnotatedMoveStruct> {
+    let (type_, field_layouts) = match layout {
+        MoveStructLayout::WithTypes { type_, fields } => (type_, fields),
+        _ => bail!("The event schema layout should be annotated with the struct types"),
+    };
+    let field_values = match value {
+        MoveStruct::WithTypes { fields, .. } | MoveStruct::WithFields(fields) => fields,
+        MoveStruct::Runtime(_) => bail!("The event schema layout should have the field names"),
+    };
+    let value = field_layouts
+        .iter()
+        .zip(field_values)
+        .map(|(field_layout, (name, value))| {
+            Ok((name, annotate_value(&field_layout.layout, value)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(AnnotatedMoveStruct {
+        // The abilities are not recorded in the layout
+        abilities: AbilitySet::EMPTY,
+        type_: type_.clone(),
+        value,
+    })
+}
+
+fn annotate_value(layout: &MoveTypeLayout, value: MoveValue) -> Result<AnnotatedMoveValue> {
+    Ok(match (layout, value) {
+        (MoveTypeLayout::Bool, MoveValue::Bool(b)) => AnnotatedMoveValue::Bool(b),
+        (MoveTypeLayout::U8, MoveValue::U8(u)) => AnnotatedMoveValue::U8(u),
+        (MoveTypeLayout::U16, MoveValue::U16(u)) => AnnotatedMoveValue::U16(u),
+        (MoveTypeLayout::U32, MoveValue::U32(u)) => AnnotatedMoveValue::U32(u),
+        (MoveTypeLayout::U64, MoveValue::U64(u)) => AnnotatedMoveValue::U64(u),
+        (MoveTypeLayout::U128, MoveValue::U128(u)) => AnnotatedMoveValue::U128(u),
+        (MoveTypeLayout::U256, MoveValue::U256(u)) => AnnotatedMoveValue::U256(u),
+        (MoveTypeLayout::Address, MoveValue::Address(addr)) => AnnotatedMoveValue::Address(addr),
+        (MoveTypeLayout::Vector(elem_layout), MoveValue::Vector(values)) => {
+            if matches!(elem_layout.as_ref(), MoveTypeLayout::U8) {
+                let bytes = values
+                    .into_iter()
+                    .map(|value| match value {
+                        MoveValue::U8(u) => Ok(u),
+                        _ => bail!("Expect u8 in the vector<u8>"),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                AnnotatedMoveValue::Bytes(bytes)
+            } else {
+                AnnotatedMoveValue::Vector(
+                    layout_type_tag(elem_layout)?,
+                    values
+                        .into_iter()
+                        .map(|value| annotate_value(elem_layout, value))
+                        .collect::<Result<Vec<_>>>()?,
+                )
+            }
+        }
+        (MoveTypeLayout::Struct(struct_layout), MoveValue::Struct(value)) => {
+            AnnotatedMoveValue::Struct(annotate_struct(struct_layout, value)?)
+        }
+        (layout, value) => bail!(
+            "The value {:?} does not match the layout {:?}",
+            value,
+            layout
+        ),
+    })
+}
+
+fn layout_type_tag(layout: &MoveTypeLayout) -> Result<TypeTag> {
+    Ok(match layout {
+        MoveTypeLayout::Bool => TypeTag::Bool,
+        MoveTypeLayout::U8 => TypeTag::U8,
+        MoveTypeLayout::U16 => TypeTag::U16,
+        MoveTypeLayout::U32 => TypeTag::U32,
+        MoveTypeLayout::U64 => TypeTag::U64,
+        MoveTypeLayout::U128 => TypeTag::U128,
+        MoveTypeLayout::U256 => TypeTag::U256,
+        MoveTypeLayout::Address => TypeTag::Address,
+        MoveTypeLayout::Signer => TypeTag::Signer,
+        MoveTypeLayout::Vector(elem_layout) => {
+            TypeTag::Vector(Box::new(layout_type_tag(elem_layout)?))
+        }
+        MoveTypeLayout::Struct(MoveStructLayout::WithTypes { type_, .. }) => {
+            TypeTag::Struct(Box::new(type_.clone()))
+        }
+        MoveTypeLayout::Struct(_) => {
+            bail!("The event schema layout should be annotated with the struct types")
+        }
+    })
+}
+
+/// Rust bindings for RoochFramework event_schema_registry module
+pub struct EventSchemaRegistryModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> EventSchemaRegistryModule<'a> {
+    pub const EVENT_SCHEMAS_FUNCTION_NAME: &'static IdentStr = ident_str!("event_schemas");
+    pub const REGISTER_EVENT_SCHEMA_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("register_event_schema");
+
+    /// Return all the registered schemas of the event type, ordered by version
+    pub fn event_schemas(&self, event_type: &StructTag) -> Result<Vec<EventSchema>> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let call = Self::create_function_call(
+            Self::EVENT_SCHEMAS_FUNCTION_NAME,
+            vec![TypeTag::Struct(Box::new(event_type.clone()))],
+            vec![],
+        );
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(bcs::from_bytes::<Vec<EventSchema>>(&value.value)?)
+    }
+
+    /// Register the annotated layout of the event type as a new schema version.
+    /// The sender must be the account of the module which defines the event type.
+    pub fn create_register_event_schema_action(
+        event_type: StructTag,
+        layout: &MoveStructLayout,
+    ) -> Result<MoveAction> {
+        if !matches!(layout, MoveStructLayout::WithTypes { .. }) {
+            bail!("The event schema layout should be annotated with the struct types");
+        }
+        Ok(Self::create_move_action(
+            Self::REGISTER_EVENT_SCHEMA_ENTRY_FUNCTION_NAME,
+            vec![TypeTag::Struct(Box::new(event_type))],
+            vec![MoveValue::vector_u8(bcs::to_bytes(layout)?)],
+        ))
+    }
+}
+
+impl<'a> ModuleBinding<'a> for EventSchemaRegistryModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::{identifier::Identifier, value::MoveFieldLayout};
+
+    fn event_type() -> StructTag {
+        StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("test").unwrap(),
+            name: Identifier::new("TestEvent").unwrap(),
+            type_params: vec![],
+        }
+    }
+
+    fn schema(version: u64, fields: Vec<(&str, MoveTypeLayout)>) -> EventSchema {
+        let layout = MoveStructLayout::WithTypes {
+            type_: event_type(),
+            fields: fields
+                .into_iter()
+                .map(|(name, layout)| MoveFieldLayout::new(Identifier::new(name).unwrap(), layout))
+                .collect(),
+        };
+        EventSchema {
+            version,
+            layout: bcs::to_bytes(&layout).unwrap(),
+            registered_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_decode_with_schemas() {
+        let schemas = vec![
+            schema(0, vec![("amount", MoveTypeLayout::U64)]),
+            schema(
+                1,
+                vec![
+                    ("amount", MoveTypeLayout::U64),
+                    ("memo", MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8))),
+                ],
+            ),
+        ];
+
+        let old_event_data = bcs::to_bytes(&100u64).unwrap();
+        let (version, value) = decode_with_schemas(&schemas, &old_event_data).unwrap();
+        assert_eq!(version, 0);
+        assert_eq!(value.type_, event_type());
+        assert_eq!(value.value.len(), 1);
+
+        let new_event_data = bcs::to_bytes(&(100u64, b"memo".to_vec())).unwrap();
+        let (version, value) = decode_with_schemas(&schemas, &new_event_data).unwrap();
+        assert_eq!(version, 1);
+        assert!(matches!(&value.value[1].1, AnnotatedMoveValue::Bytes(bytes) if bytes == b"memo"));
+
+        assert!(decode_with_schemas(&schemas, &[1u8]).is_err());
+    }
+}
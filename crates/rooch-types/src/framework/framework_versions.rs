@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::StructTag,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("framework_versions");
+pub const ACTIVATED_EVENT_NAME: &IdentStr = ident_str!("FrameworkVersionActivatedEvent");
+
+/// Whether the event is a `FrameworkVersionActivatedEvent`, the active framework version may change with it
+pub fn is_activated_event(event_type: &StructTag) -> bool {
+    event_type.address == ROOCH_FRAMEWORK_ADDRESS
+        && event_type.module.as_ident_str() == MODULE_NAME
+        && event_type.name.as_ident_str() == ACTIVATED_EVENT_NAME
+}
+
+/// Rust bindings for RoochFramework framework_versions module
+pub struct FrameworkVersionsModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> FrameworkVersionsModule<'a> {
+    pub const ACTIVE_FRAMEWORK_ADDRESS_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("active_framework_address");
+
+    /// Return the address of the active framework version, the system functions are called at this address.
+    pub fn active_framework_address(&self) -> Result<AccountAddress> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let call = Self::create_function_call(
+            Self::ACTIVE_FRAMEWORK_ADDRESS_FUNCTION_NAME,
+            vec![],
+            vec![],
+        );
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(bcs::from_bytes::<AccountAddress>(&value.value)?)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for FrameworkVersionsModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
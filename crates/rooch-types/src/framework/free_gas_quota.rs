@@ -0,0 +1,87 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::capability::UpgradeCap;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    transaction::{FunctionCall, MoveAction},
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("free_gas_quota");
+
+/// The default max gas amount of a free transaction, the transaction with a higher max gas amount pays the gas
+pub const DEFAULT_FREE_TX_MAX_GAS_AMOUNT: u64 = 10_000_000;
+
+/// Rust bindings for RoochFramework free_gas_quota module
+pub struct FreeGasQuotaModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> FreeGasQuotaModule<'a> {
+    pub const REMAINING_QUOTA_FUNCTION_NAME: &'static IdentStr = ident_str!("remaining_quota");
+    pub const UPDATE_CONFIG_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("update_config");
+    pub const INIT_AFTER_UPGRADE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("init_after_upgrade");
+
+    /// The free transactions the `account` can still send without paying the gas
+    pub fn remaining_quota(&self, account: AccountAddress) -> Result<u64> {
+        let call = Self::create_remaining_quota_call(account);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(bcs::from_bytes::<u64>(&value.value)?)
+    }
+
+    /// The view function call of `remaining_quota`, the result is the BCS bytes of a `u64`
+    pub fn create_remaining_quota_call(account: AccountAddress) -> FunctionCall {
+        Self::create_function_call(
+            Self::REMAINING_QUOTA_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(account)],
+        )
+    }
+
+    /// The sender must be the owner of the UpgradeCap
+    pub fn create_update_config_action(tx_quota: u64, max_gas_amount: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::UPDATE_CONFIG_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(UpgradeCap::object_id().into()),
+                MoveValue::U64(tx_quota),
+                MoveValue::U64(max_gas_amount),
+            ],
+        )
+    }
+
+    /// Create the free gas quota on the chain upgraded from a framework without it,
+    /// the sender must be the owner of the UpgradeCap
+    pub fn create_init_after_upgrade_action() -> MoveAction {
+        Self::create_move_action(
+            Self::INIT_AFTER_UPGRADE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(UpgradeCap::object_id().into())],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for FreeGasQuotaModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::capability::UpgradeCap;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout, MoveValue},
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::{option::MoveOption, string::MoveString},
+    moveos_std::tx_context::TxContext,
+    state::{MoveState, MoveStructState, MoveStructType},
+    transaction::{FunctionCall, MoveAction},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("gas_schedule");
+
+/// The version of the builtin gas schedule of the node, it is the schedule at genesis
+pub const BUILTIN_GAS_SCHEDULE_VERSION: u64 = 0;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GasEntry {
+    pub key: MoveString,
+    pub val: u64,
+}
+
+impl GasEntry {
+    pub fn new(key: String, val: u64) -> Self {
+        Self {
+            key: key.into(),
+            val,
+        }
+    }
+}
+
+impl MoveStructType for GasEntry {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasEntry");
+}
+
+impl MoveStructState for GasEntry {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveString::type_layout(), MoveTypeLayout::U64])
+    }
+}
+
+/// The gas schedule of a version, the entries override the initial gas schedule
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GasScheduleConfig {
+    pub schedule_version: u64,
+    pub entries: Vec<GasEntry>,
+}
+
+impl GasScheduleConfig {
+    pub fn builtin() -> Self {
+        Self {
+            schedule_version: BUILTIN_GAS_SCHEDULE_VERSION,
+            entries: vec![],
+        }
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        self.schedule_version == BUILTIN_GAS_SCHEDULE_VERSION
+    }
+
+    pub fn entries_map(&self) -> BTreeMap<String, u64> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect()
+    }
+}
+
+/// The gas schedule waiting for its upgrade epoch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PendingGasSchedule {
+    pub config: GasScheduleConfig,
+    /// The time in milliseconds when the schedule is activated
+    pub activation_time: u64,
+}
+
+/// The event emitted when a new gas schedule is proposed
+pub struct GasScheduleProposedEvent;
+
+impl MoveStructType for GasScheduleProposedEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasScheduleProposedEvent");
+}
+
+/// Rust bindings for RoochFramework gas_schedule module
+pub struct GasScheduleModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> GasScheduleModule<'a> {
+    pub const CURRENT_GAS_SCHEDULE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("current_gas_schedule");
+    pub const GENESIS_GAS_SCHEDULE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("genesis_gas_schedule");
+    pub const SCHEDULE_VERSION_FUNCTION_NAME: &'static IdentStr = ident_str!("schedule_version");
+    pub const PENDING_GAS_SCHEDULE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("pending_gas_schedule");
+    pub const PROPOSE_GAS_SCHEDULE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("propose_gas_schedule");
+    pub const CANCEL_GAS_SCHEDULE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("cancel_gas_schedule");
+
+    fn call_view_function(&self, call: FunctionCall) -> Result<Vec<u8>> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(value.value)
+    }
+
+    /// The version of the active gas schedule
+    pub fn schedule_version(&self) -> Result<u64> {
+        let call = Self::create_function_call(Self::SCHEDULE_VERSION_FUNCTION_NAME, vec![], vec![]);
+        Ok(bcs::from_bytes::<u64>(&self.call_view_function(call)?)?)
+    }
+
+    /// The active gas schedule, the pending schedule is active once its upgrade epoch is reached
+    pub fn current_gas_schedule(&self) -> Result<GasScheduleConfig> {
+        let call =
+            Self::create_function_call(Self::CURRENT_GAS_SCHEDULE_FUNCTION_NAME, vec![], vec![]);
+        Ok(bcs::from_bytes::<GasScheduleConfig>(
+            &self.call_view_function(call)?,
+        )?)
+    }
+
+    /// The builtin gas schedule of the version 0 stored at genesis, the entries are the genesis cost table entries
+    pub fn genesis_gas_schedule(&self) -> Result<GasScheduleConfig> {
+        let call =
+            Self::create_function_call(Self::GENESIS_GAS_SCHEDULE_FUNCTION_NAME, vec![], vec![]);
+        Ok(bcs::from_bytes::<GasScheduleConfig>(
+            &self.call_view_function(call)?,
+        )?)
+    }
+
+    pub fn pending_gas_schedule(&self) -> Result<Option<PendingGasSchedule>> {
+        let call =
+            Self::create_function_call(Self::PENDING_GAS_SCHEDULE_FUNCTION_NAME, vec![], vec![]);
+        Ok(
+            bcs::from_bytes::<MoveOption<PendingGasSchedule>>(&self.call_view_function(call)?)?
+                .into(),
+        )
+    }
+
+    /// The sender must be the owner of the UpgradeCap
+    pub fn create_propose_gas_schedule_action(
+        schedule_version: u64,
+        entries: &BTreeMap<String, u64>,
+        activation_time: u64,
+    ) -> MoveAction {
+        let keys = entries
+            .keys()
+            .map(|key| MoveValue::vector_u8(key.as_bytes().to_vec()))
+            .collect();
+        let vals = entries.values().map(|val| MoveValue::U64(*val)).collect();
+        let args = vec![
+            MoveValue::Address(UpgradeCap::object_id().into()),
+            MoveValue::U64(schedule_version),
+            MoveValue::Vector(keys),
+            MoveValue::Vector(vals),
+            MoveValue::U64(activation_time),
+        ];
+        Self::create_move_action(Self::PROPOSE_GAS_SCHEDULE_ENTRY_FUNCTION_NAME, vec![], args)
+    }
+
+    /// The sender must be the owner of the UpgradeCap
+    pub fn create_cancel_gas_schedule_action() -> MoveAction {
+        Self::create_move_action(
+            Self::CANCEL_GAS_SCHEDULE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(UpgradeCap::object_id().into())],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for GasScheduleModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
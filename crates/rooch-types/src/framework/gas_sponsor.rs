@@ -0,0 +1,100 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::{MoveStructState, MoveStructType},
+    transaction::MoveAction,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("gas_sponsor");
+
+/// The sponsor paying the gas of a transaction, it is added to the tx context
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TxSponsor {
+    pub sponsor: AccountAddress,
+    /// The auth validator of the sponsor's authenticator
+    pub auth_validator_id: u64,
+    /// The authenticator payload of the sponsor's signature of the transaction hash
+    pub authenticator_payload: Vec<u8>,
+}
+
+impl MoveStructType for TxSponsor {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TxSponsor");
+}
+
+impl MoveStructState for TxSponsor {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+        ])
+    }
+}
+
+/// Rust bindings for RoochFramework gas_sponsor module
+pub struct GasSponsorModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> GasSponsorModule<'a> {
+    pub const GRANT_ALLOWANCE_FUNCTION_NAME: &'static IdentStr = ident_str!("grant_allowance");
+    pub const REVOKE_ALLOWANCE_FUNCTION_NAME: &'static IdentStr = ident_str!("revoke_allowance");
+    pub const ALLOWANCE_FUNCTION_NAME: &'static IdentStr = ident_str!("allowance");
+
+    /// The gas coin the `sponsor` allows the `account` to spend
+    pub fn allowance(&self, sponsor: AccountAddress, account: AccountAddress) -> Result<U256> {
+        let call = Self::create_function_call(
+            Self::ALLOWANCE_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(sponsor), MoveValue::Address(account)],
+        );
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(bcs::from_bytes::<U256>(&value.value)?)
+    }
+
+    pub fn create_grant_allowance_action(account: AccountAddress, amount: U256) -> MoveAction {
+        Self::create_move_action(
+            Self::GRANT_ALLOWANCE_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(account), MoveValue::U256(amount)],
+        )
+    }
+
+    pub fn create_revoke_allowance_action(account: AccountAddress) -> MoveAction {
+        Self::create_move_action(
+            Self::REVOKE_ALLOWANCE_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(account)],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for GasSponsorModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -1,9 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::gas_schedule::GasEntry;
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
-use moveos_types::state::{MoveStructState, MoveStructType};
+use moveos_types::state::{MoveState, MoveStructState, MoveStructType};
 use serde::{Deserialize, Serialize};
 
 pub const MODULE_NAME: &IdentStr = ident_str!("genesis");
@@ -15,6 +16,15 @@ pub struct GenesisContext {
     pub timestamp: u64,
     /// Sequencer account
     pub sequencer: AccountAddress,
+    /// The owner of the UpgradeCap
+    pub upgrade_cap_owner: AccountAddress,
+    /// The owner of the TreasuryCap
+    pub treasury_cap_owner: AccountAddress,
+    /// The owner of the framework packages PublisherCap
+    pub publisher_cap_owner: AccountAddress,
+    /// The cost table entries of the genesis gas schedule, they override the initial cost table,
+    /// so the instruction and stack tiers can be tuned without recompiling the node.
+    pub gas_schedule_entries: Vec<GasEntry>,
 }
 
 impl MoveStructType for GenesisContext {
@@ -29,16 +39,45 @@ impl MoveStructState for GenesisContext {
             move_core_types::value::MoveTypeLayout::U64,
             move_core_types::value::MoveTypeLayout::U64,
             move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(GasEntry::type_layout())),
         ])
     }
 }
 
 impl GenesisContext {
+    /// Create a GenesisContext, the capabilities are owned by the sequencer by default.
     pub fn new(chain_id: u64, timestamp: u64, sequencer: AccountAddress) -> Self {
         Self {
             chain_id,
             timestamp,
             sequencer,
+            upgrade_cap_owner: sequencer,
+            treasury_cap_owner: sequencer,
+            publisher_cap_owner: sequencer,
+            gas_schedule_entries: vec![],
         }
     }
+
+    pub fn with_upgrade_cap_owner(mut self, owner: AccountAddress) -> Self {
+        self.upgrade_cap_owner = owner;
+        self
+    }
+
+    pub fn with_treasury_cap_owner(mut self, owner: AccountAddress) -> Self {
+        self.treasury_cap_owner = owner;
+        self
+    }
+
+    pub fn with_publisher_cap_owner(mut self, owner: AccountAddress) -> Self {
+        self.publisher_cap_owner = owner;
+        self
+    }
+
+    pub fn with_gas_schedule_entries(mut self, entries: Vec<GasEntry>) -> Self {
+        self.gas_schedule_entries = entries;
+        self
+    }
 }
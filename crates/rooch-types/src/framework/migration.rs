@@ -0,0 +1,86 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::ModuleId,
+    value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_types::FunctionId,
+    moveos_std::tx_context::TxContext,
+    transaction::{FunctionCall, MoveAction},
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("migration");
+
+/// Rust bindings for RoochFramework migration module
+pub struct MigrationModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> MigrationModule<'a> {
+    pub const IS_COMPLETED_FUNCTION_NAME: &'static IdentStr = ident_str!("is_completed");
+    pub const COMPLETE_MIGRATION_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("complete_migration");
+
+    fn call_view_function(&self, call: FunctionCall) -> Result<Vec<u8>> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(value.value)
+    }
+
+    /// The current framework version in the `onchain_config`, the migrations run at the version boundary
+    pub fn framework_version(&self) -> Result<u64> {
+        let call = FunctionCall::new(
+            FunctionId::new(
+                ModuleId::new(
+                    ROOCH_FRAMEWORK_ADDRESS,
+                    ident_str!("onchain_config").to_owned(),
+                ),
+                ident_str!("framework_version").to_owned(),
+            ),
+            vec![],
+            vec![],
+        );
+        Ok(bcs::from_bytes::<u64>(&self.call_view_function(call)?)?)
+    }
+
+    pub fn is_completed(&self, name: &str) -> Result<bool> {
+        let call = Self::create_function_call(
+            Self::IS_COMPLETED_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(name.as_bytes().to_vec())],
+        );
+        Ok(bcs::from_bytes::<bool>(&self.call_view_function(call)?)?)
+    }
+
+    pub fn create_complete_migration_action(name: &str, migrated: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::COMPLETE_MIGRATION_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::vector_u8(name.as_bytes().to_vec()),
+                MoveValue::U64(migrated),
+            ],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for MigrationModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -4,26 +4,42 @@
 /// Types mapping from Framework Move types to Rust types
 /// Module binding for the Framework
 //TODO there modules maybe generated by ABI in the future
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use crate::framework::transaction_validator::TransactionValidator;
+use move_core_types::account_address::AccountAddress;
 use moveos_types::transaction::FunctionCall;
 
 pub mod account_authentication;
 pub mod account_coin_store;
 pub mod address_mapping;
 pub mod auth_validator;
+pub mod bitcoin_validator;
+pub mod capability;
+pub mod chain_pause;
 pub mod coin;
 pub mod coin_store;
 pub mod empty;
 pub mod ethereum_address;
 pub mod ethereum_light_client;
 pub mod ethereum_validator;
+pub mod event_schema_registry;
+pub mod framework_versions;
+pub mod free_gas_quota;
 pub mod gas_coin;
+pub mod gas_sponsor;
+pub mod gas_schedule;
 pub mod genesis;
+pub mod migration;
+pub mod module_blocklist;
+pub mod name_service;
 pub mod native_validator;
+pub mod sequencer_key_registry;
 pub mod session_key;
 pub mod timestamp;
+pub mod transaction_fee;
 pub mod transaction_validator;
 pub mod transfer;
+pub mod treasury;
 
 /// MoveOS system pre_execute functions registry.
 /// The registry is used to filter out system pre_execute functions.
@@ -32,7 +48,14 @@ pub mod transfer;
 /// system pre_execute functions on-chain and dynamically?
 #[inline]
 pub fn system_pre_execute_functions() -> Vec<FunctionCall> {
-    vec![TransactionValidator::pre_execute_function_call()]
+    system_pre_execute_functions_at(ROOCH_FRAMEWORK_ADDRESS)
+}
+
+/// The system pre_execute functions of the framework version at `framework_address`
+pub fn system_pre_execute_functions_at(framework_address: AccountAddress) -> Vec<FunctionCall> {
+    vec![TransactionValidator::pre_execute_function_call_at(
+        framework_address,
+    )]
 }
 /// MoveOS system post_execute functions registry.
 /// The registry is used to filter out system post_execute functions.
@@ -41,5 +64,12 @@ pub fn system_pre_execute_functions() -> Vec<FunctionCall> {
 /// system pre_execute functions on-chain and dynamically?
 #[inline]
 pub fn system_post_execute_functions() -> Vec<FunctionCall> {
-    vec![TransactionValidator::post_execute_function_call()]
+    system_post_execute_functions_at(ROOCH_FRAMEWORK_ADDRESS)
+}
+
+/// The system post_execute functions of the framework version at `framework_address`
+pub fn system_post_execute_functions_at(framework_address: AccountAddress) -> Vec<FunctionCall> {
+    vec![TransactionValidator::post_execute_function_call_at(
+        framework_address,
+    )]
 }
@@ -0,0 +1,162 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::{anyhow, Result};
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::binary_views::BinaryIndexedView;
+use move_binary_format::file_format::Bytecode;
+use move_binary_format::{CompiledModule, CompiledScript};
+use move_core_types::language_storage::ModuleId;
+use move_core_types::resolver::ModuleResolver;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_types::FunctionId,
+    moveos_std::tx_context::TxContext,
+    transaction::{FunctionCall, MoveAction},
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("module_blocklist");
+
+/// Rust bindings for RoochFramework module_blocklist module
+pub struct ModuleBlocklistModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> ModuleBlocklistModule<'a> {
+    pub const IS_EMPTY_FUNCTION_NAME: &'static IdentStr = ident_str!("is_empty");
+    pub const IS_ANY_FUNCTION_BLOCKED_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("is_any_function_blocked");
+
+    pub fn is_empty(&self) -> Result<bool> {
+        let call = Self::create_function_call(Self::IS_EMPTY_FUNCTION_NAME, vec![], vec![]);
+        self.call_bool(call)
+    }
+
+    /// Check any of the functions, or any of their modules, is blocked
+    pub fn is_any_function_blocked<'f>(
+        &self,
+        functions: impl IntoIterator<Item = &'f FunctionId>,
+    ) -> Result<bool> {
+        let mut module_addresses = vec![];
+        let mut module_names = vec![];
+        let mut function_names = vec![];
+        for function_id in functions {
+            module_addresses.push(MoveValue::Address(*function_id.module_id.address()));
+            module_names.push(MoveValue::vector_u8(
+                function_id.module_id.name().as_bytes().to_vec(),
+            ));
+            function_names.push(MoveValue::vector_u8(
+                function_id.function_name.as_bytes().to_vec(),
+            ));
+        }
+        let call = Self::create_function_call(
+            Self::IS_ANY_FUNCTION_BLOCKED_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Vector(module_addresses),
+                MoveValue::Vector(module_names),
+                MoveValue::Vector(function_names),
+            ],
+        );
+        self.call_bool(call)
+    }
+
+    fn call_bool(&self, call: FunctionCall) -> Result<bool> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+            })?;
+        Ok(value)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for ModuleBlocklistModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
+
+/// The functions reachable from the entry function or the script of the action by the static calls,
+/// including the entry function itself. Move has no dynamic dispatch, so a function not in the set is never
+/// called by the action. The module publishing calls no function, the set is empty.
+pub fn reachable_functions<R: ModuleResolver>(
+    resolver: &R,
+    action: &MoveAction,
+) -> Result<BTreeSet<FunctionId>> {
+    let mut pending = match action {
+        MoveAction::Function(call) => vec![call.function_id.clone()],
+        MoveAction::Script(call) => {
+            let script = CompiledScript::deserialize(&call.code)?;
+            called_functions(BinaryIndexedView::Script(&script), &script.code.code)
+        }
+        MoveAction::ModuleBundle(_) => vec![],
+    };
+    let mut modules: BTreeMap<ModuleId, CompiledModule> = BTreeMap::new();
+    let mut reachable = BTreeSet::new();
+    while let Some(function_id) = pending.pop() {
+        if reachable.contains(&function_id) {
+            continue;
+        }
+        let module_id = &function_id.module_id;
+        if !modules.contains_key(module_id) {
+            let bytes = resolver
+                .get_module(module_id)?
+                .ok_or_else(|| anyhow!("The module {} does not exist", module_id))?;
+            modules.insert(module_id.clone(), CompiledModule::deserialize(&bytes)?);
+        }
+        let module = &modules[module_id];
+        let code = module.function_defs().iter().find_map(|def| {
+            let name = module.identifier_at(module.function_handle_at(def.function).name);
+            (name == function_id.function_name.as_ident_str())
+                .then_some(def.code.as_ref())
+                .flatten()
+        });
+        // The native functions have no code
+        if let Some(code) = code {
+            pending.extend(called_functions(
+                BinaryIndexedView::Module(module),
+                &code.code,
+            ));
+        }
+        reachable.insert(function_id);
+    }
+    Ok(reachable)
+}
+
+fn called_functions(view: BinaryIndexedView, code: &[Bytecode]) -> Vec<FunctionId> {
+    code.iter()
+        .filter_map(|instruction| {
+            let handle = match instruction {
+                Bytecode::Call(index) => view.function_handle_at(*index),
+                Bytecode::CallGeneric(index) => {
+                    view.function_handle_at(view.function_instantiation_at(*index).handle)
+                }
+                _ => return None,
+            };
+            let module_handle = view.module_handle_at(handle.module);
+            Some(FunctionId::new(
+                ModuleId::new(
+                    *view.address_identifier_at(module_handle.address),
+                    view.identifier_at(module_handle.name).to_owned(),
+                ),
+                view.identifier_at(handle.name).to_owned(),
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,55 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::{option::MoveOption, string::MoveString},
+    moveos_std::tx_context::TxContext,
+    state::MoveState,
+    transaction::FunctionCall,
+};
+
+/// Rust bindings for RoochFramework name_service module
+pub struct NameService<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> NameService<'a> {
+    const RESOLVE_FUNCTION_NAME: &'static IdentStr = ident_str!("resolve");
+
+    /// Resolve the name to the target address, return None if the name is not registered or expired
+    pub fn resolve(&self, name: &str) -> Result<Option<AccountAddress>> {
+        let ctx = TxContext::zero();
+        let call = FunctionCall::new(
+            Self::function_id(Self::RESOLVE_FUNCTION_NAME),
+            vec![],
+            vec![MoveString::from(name.to_owned()).to_bytes()],
+        );
+        let result = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|values| {
+                let value = values.get(0).expect("Expected return value");
+                let result = MoveOption::<AccountAddress>::from_bytes(&value.value)
+                    .expect("Expected Option<address>");
+                result.into()
+            })?;
+        Ok(result)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for NameService<'a> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("name_service");
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::timestamp::TimestampModule;
+use crate::address::RoochAddress;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::crypto::{PublicKey, Signature, ToFromBytes};
+use crate::transaction::TransactionSequenceInfo;
+use anyhow::{anyhow, bail, Result};
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::ModuleId,
+    value::MoveValue,
+};
+use moveos_types::{
+    h256::H256,
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
+    move_types::FunctionId,
+    moveos_std::tx_context::TxContext,
+    transaction::{FunctionCall, MoveAction},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("sequencer_key_registry");
+
+/// The default handover window of the sequencer key rotation is 1 hour
+pub const DEFAULT_HANDOVER_WINDOW_MILLISECONDS: u64 = 3_600_000;
+
+/// The key rotation announced by the current sequencer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PendingRotation {
+    pub next_sequencer: AccountAddress,
+    pub next_public_key: Vec<u8>,
+    /// The time in milliseconds when the rotation is announced
+    pub announced_at: u64,
+    /// The time in milliseconds when the handover window ends
+    pub handover_end: u64,
+}
+
+/// The snapshot of the sequencer keys in the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequencerKeys {
+    pub sequencer: AccountAddress,
+    pub key_version: u64,
+    pub pending_rotation: Option<PendingRotation>,
+    /// The on-chain time in milliseconds when the snapshot is taken
+    pub timestamp_milliseconds: u64,
+}
+
+impl SequencerKeys {
+    /// Check whether the snapshot is in the handover window, both the current and the next key are valid in it.
+    pub fn in_handover(&self) -> bool {
+        self.pending_rotation
+            .as_ref()
+            .map(|pending| self.timestamp_milliseconds < pending.handover_end)
+            .unwrap_or(false)
+    }
+
+    /// The same rule as `sequencer_key_registry::is_valid_sequencer` in Move
+    pub fn is_valid_sequencer(&self, addr: AccountAddress) -> bool {
+        match &self.pending_rotation {
+            None => addr == self.sequencer,
+            Some(pending) => {
+                addr == pending.next_sequencer
+                    || (addr == self.sequencer
+                        && self.timestamp_milliseconds < pending.handover_end)
+            }
+        }
+    }
+
+    /// Verify the sequencer signature over the hashed message, return the signer address
+    pub fn verify_hashed(&self, hashed_msg: &[u8], signature: &Signature) -> Result<RoochAddress> {
+        let signer = signature.to_public_key()?.address();
+        if !self.is_valid_sequencer(signer.into()) {
+            bail!(
+                "The signer {} is not a valid sequencer, the sequencer keys: {:?}",
+                signer,
+                self
+            );
+        }
+        signature.verify_hashed(hashed_msg, signer)?;
+        Ok(signer)
+    }
+
+    /// Verify the tx order signature of the sequence info
+    pub fn verify_tx_order_signature(
+        &self,
+        tx_hash: H256,
+        sequence_info: &TransactionSequenceInfo,
+    ) -> Result<RoochAddress> {
+        let signature = Signature::from_bytes(&sequence_info.tx_order_signature.payload)
+            .map_err(|e| anyhow!("Invalid tx order signature: {:?}", e))?;
+        let witness_hash =
+            TransactionSequenceInfo::tx_order_witness_hash(tx_hash, sequence_info.tx_order);
+        self.verify_hashed(witness_hash.as_bytes(), &signature)
+    }
+}
+
+/// Rust bindings for RoochFramework sequencer_key_registry module
+pub struct SequencerKeyRegistryModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> SequencerKeyRegistryModule<'a> {
+    pub const KEY_VERSION_FUNCTION_NAME: &'static IdentStr = ident_str!("key_version");
+    pub const PENDING_ROTATION_FUNCTION_NAME: &'static IdentStr = ident_str!("pending_rotation");
+    pub const ANNOUNCE_KEY_ROTATION_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("announce_key_rotation");
+    pub const COMPLETE_KEY_ROTATION_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("complete_key_rotation");
+    pub const CANCEL_KEY_ROTATION_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("cancel_key_rotation");
+
+    fn call_view_function(&self, call: FunctionCall) -> Result<Vec<u8>> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(value.value)
+    }
+
+    /// The current sequencer in the `onchain_config`
+    pub fn sequencer(&self) -> Result<AccountAddress> {
+        let call = FunctionCall::new(
+            FunctionId::new(
+                ModuleId::new(
+                    ROOCH_FRAMEWORK_ADDRESS,
+                    ident_str!("onchain_config").to_owned(),
+                ),
+                ident_str!("sequencer").to_owned(),
+            ),
+            vec![],
+            vec![],
+        );
+        Ok(bcs::from_bytes::<AccountAddress>(
+            &self.call_view_function(call)?,
+        )?)
+    }
+
+    pub fn key_version(&self) -> Result<u64> {
+        let call = Self::create_function_call(Self::KEY_VERSION_FUNCTION_NAME, vec![], vec![]);
+        Ok(bcs::from_bytes::<u64>(&self.call_view_function(call)?)?)
+    }
+
+    pub fn pending_rotation(&self) -> Result<Option<PendingRotation>> {
+        let call = Self::create_function_call(Self::PENDING_ROTATION_FUNCTION_NAME, vec![], vec![]);
+        Ok(bcs::from_bytes::<MoveOption<PendingRotation>>(&self.call_view_function(call)?)?.into())
+    }
+
+    fn now_milliseconds(&self) -> Result<u64> {
+        let call = FunctionCall::new(
+            TimestampModule::function_id(TimestampModule::NOW_MICROSECONDS_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        Ok(bcs::from_bytes::<u64>(&self.call_view_function(call)?)?)
+    }
+
+    /// Take a snapshot of the sequencer keys at the current on-chain time
+    pub fn sequencer_keys(&self) -> Result<SequencerKeys> {
+        Ok(SequencerKeys {
+            sequencer: self.sequencer()?,
+            key_version: self.key_version()?,
+            pending_rotation: self.pending_rotation()?,
+            timestamp_milliseconds: self.now_milliseconds()?,
+        })
+    }
+
+    pub fn create_announce_key_rotation_action(
+        next_public_key: &PublicKey,
+        handover_window_milliseconds: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::ANNOUNCE_KEY_ROTATION_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::vector_u8(next_public_key.as_ref().to_vec()),
+                MoveValue::U64(handover_window_milliseconds),
+            ],
+        )
+    }
+
+    pub fn create_complete_key_rotation_action() -> MoveAction {
+        Self::create_move_action(
+            Self::COMPLETE_KEY_ROTATION_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![],
+        )
+    }
+
+    pub fn create_cancel_key_rotation_action() -> MoveAction {
+        Self::create_move_action(
+            Self::CANCEL_KEY_ROTATION_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for SequencerKeyRegistryModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::capability::TreasuryCap;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout, MoveValue};
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::{MoveStructState, MoveStructType},
+    transaction::MoveAction,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("transaction_fee");
+
+/// The gas used by a fee block at which the base gas price stays unchanged, the default of the on-chain fee market
+pub const DEFAULT_TARGET_BLOCK_GAS: u64 = 1_000_000_000;
+
+/// The max gas price signed by the sender in the context of the transaction,
+/// the gas of the transaction is never priced higher than it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaxGasPrice {
+    pub max_gas_price: u64,
+}
+
+impl MaxGasPrice {
+    pub fn new(max_gas_price: u64) -> Self {
+        Self { max_gas_price }
+    }
+}
+
+impl MoveStructType for MaxGasPrice {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("MaxGasPrice");
+}
+
+impl MoveStructState for MaxGasPrice {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64])
+    }
+}
+
+/// The status of the on-chain fee market, the base gas price is adjusted at the end of every fee block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeMarketStatus {
+    pub base_gas_price: u64,
+    pub min_base_gas_price: u64,
+    pub target_block_gas: u64,
+    /// The duration of a fee block in milliseconds
+    pub block_interval: u64,
+    /// The on-chain time in milliseconds when the current fee block starts
+    pub block_start_time: u64,
+    /// The gas used by the transactions of the current fee block
+    pub block_gas_used: u64,
+    pub last_block_gas_used: u64,
+    /// The number of the ended fee blocks
+    pub blocks: u64,
+}
+
+impl MoveStructType for FeeMarketStatus {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("FeeMarketStatus");
+}
+
+impl MoveStructState for FeeMarketStatus {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// Rust bindings for RoochFramework transaction_fee module
+pub struct TransactionFeeModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> TransactionFeeModule<'a> {
+    pub const FEE_MARKET_STATUS_FUNCTION_NAME: &'static IdentStr = ident_str!("fee_market_status");
+    pub const UPDATE_FEE_MARKET_CONFIG_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("update_fee_market_config");
+
+    pub fn fee_market_status(&self) -> Result<FeeMarketStatus> {
+        let call =
+            Self::create_function_call(Self::FEE_MARKET_STATUS_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(bcs::from_bytes::<FeeMarketStatus>(&value.value)?)
+    }
+
+    /// The sender must be the owner of the TreasuryCap
+    pub fn create_update_fee_market_config_action(
+        min_base_gas_price: u64,
+        target_block_gas: u64,
+        block_interval: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::UPDATE_FEE_MARKET_CONFIG_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(TreasuryCap::object_id().into()),
+                MoveValue::U64(min_base_gas_price),
+                MoveValue::U64(target_block_gas),
+                MoveValue::U64(block_interval),
+            ],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for TransactionFeeModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
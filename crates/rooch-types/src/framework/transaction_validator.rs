@@ -3,7 +3,8 @@
 
 use anyhow::Result;
 use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+    account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::ModuleId,
+    value::MoveValue,
 };
 
 use moveos_types::{
@@ -21,12 +22,22 @@ use crate::transaction::AuthenticatorInfo;
 /// Rust bindings for RoochFramework transaction_validator module
 pub struct TransactionValidator<'a> {
     caller: &'a dyn MoveFunctionCaller,
+    /// The address of the framework version which the validator is called at
+    framework_address: AccountAddress,
 }
 
 impl<'a> TransactionValidator<'a> {
     pub const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
     pub const PRE_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("pre_execute");
     pub const POST_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("post_execute");
+    /// The abort code of the validation when the transaction calls a blocked module or function
+    pub const ERROR_VALIDATE_MODULE_BLOCKED: u64 = 1011;
+
+    /// Call the transaction_validator module of the framework version at `framework_address`
+    pub fn with_framework_address(mut self, framework_address: AccountAddress) -> Self {
+        self.framework_address = framework_address;
+        self
+    }
 
     pub fn validate(
         &self,
@@ -34,7 +45,7 @@ impl<'a> TransactionValidator<'a> {
         auth: AuthenticatorInfo,
     ) -> Result<DecodedFunctionResult<TxValidateResult>> {
         let tx_validator_call = FunctionCall::new(
-            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            Self::framework_function_id(self.framework_address, Self::VALIDATE_FUNCTION_NAME),
             vec![],
             vec![
                 MoveValue::U64(auth.chain_id).simple_serialize().unwrap(),
@@ -62,7 +73,15 @@ impl<'a> TransactionValidator<'a> {
     }
 
     pub fn pre_execute_function_call() -> FunctionCall {
-        FunctionCall::new(Self::pre_execute_function_id(), vec![], vec![])
+        Self::pre_execute_function_call_at(Self::MODULE_ADDRESS)
+    }
+
+    pub fn pre_execute_function_call_at(framework_address: AccountAddress) -> FunctionCall {
+        FunctionCall::new(
+            Self::framework_function_id(framework_address, Self::PRE_EXECUTE_FUNCTION_NAME),
+            vec![],
+            vec![],
+        )
     }
 
     pub fn post_execute_function_id() -> FunctionId {
@@ -70,7 +89,25 @@ impl<'a> TransactionValidator<'a> {
     }
 
     pub fn post_execute_function_call() -> FunctionCall {
-        FunctionCall::new(Self::post_execute_function_id(), vec![], vec![])
+        Self::post_execute_function_call_at(Self::MODULE_ADDRESS)
+    }
+
+    pub fn post_execute_function_call_at(framework_address: AccountAddress) -> FunctionCall {
+        FunctionCall::new(
+            Self::framework_function_id(framework_address, Self::POST_EXECUTE_FUNCTION_NAME),
+            vec![],
+            vec![],
+        )
+    }
+
+    fn framework_function_id(
+        framework_address: AccountAddress,
+        function_name: &IdentStr,
+    ) -> FunctionId {
+        FunctionId::new(
+            ModuleId::new(framework_address, Self::MODULE_NAME.to_owned()),
+            function_name.to_owned(),
+        )
     }
 }
 
@@ -82,6 +119,9 @@ impl<'a> ModuleBinding<'a> for TransactionValidator<'a> {
     where
         Self: Sized,
     {
-        Self { caller }
+        Self {
+            caller,
+            framework_address: ROOCH_FRAMEWORK_ADDRESS,
+        }
     }
 }
@@ -12,6 +12,8 @@ use move_core_types::{
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::object::ObjectID,
+    state::MoveState,
     transaction::MoveAction,
 };
 
@@ -24,6 +26,7 @@ impl TransferModule {
     pub const TRANSFER_COIN_FUNCTION_NAME: &'static IdentStr = ident_str!("transfer_coin");
     pub const TRANSFER_COIN_TO_MULTICHAIN_ADDRESS_FUNCTION_NAME: &'static IdentStr =
         ident_str!("transfer_coin_to_multichain_address");
+    pub const TRANSFER_OBJECT_FUNCTION_NAME: &'static IdentStr = ident_str!("transfer_object");
 
     pub fn create_transfer_coin_action(
         coin_type: StructTag,
@@ -52,6 +55,18 @@ impl TransferModule {
             ],
         )
     }
+
+    pub fn create_transfer_object_action(
+        object_type: StructTag,
+        to: AccountAddress,
+        object_id: ObjectID,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::TRANSFER_OBJECT_FUNCTION_NAME,
+            vec![TypeTag::Struct(Box::new(object_type))],
+            vec![MoveValue::Address(to), object_id.to_move_value()],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for TransferModule {
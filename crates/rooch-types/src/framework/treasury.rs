@@ -0,0 +1,175 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::capability::TreasuryCap;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    u256::U256,
+    value::{MoveStructLayout, MoveTypeLayout, MoveValue},
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::{MoveStructState, MoveStructType},
+    transaction::MoveAction,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("treasury");
+
+/// The denominator of the percentages of the fee distribution
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10000;
+
+/// The percentages of the fees in basis points, the rest of the fees is burned
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DistributionConfig {
+    pub sequencer_bps: u64,
+    pub community_bps: u64,
+}
+
+impl DistributionConfig {
+    pub fn burn_bps(&self) -> u64 {
+        BASIS_POINTS_DENOMINATOR.saturating_sub(self.sequencer_bps + self.community_bps)
+    }
+}
+
+impl MoveStructType for DistributionConfig {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("DistributionConfig");
+}
+
+impl MoveStructState for DistributionConfig {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64, MoveTypeLayout::U64])
+    }
+}
+
+/// The accounting of the treasury
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TreasuryStatus {
+    /// The number of the epochs whose fees are distributed
+    pub epoch: u64,
+    /// The duration of an epoch in milliseconds
+    pub epoch_interval: u64,
+    /// The on-chain time in milliseconds when the current epoch starts
+    pub epoch_start_time: u64,
+    pub config: DistributionConfig,
+    /// The fees accumulated in the current epoch
+    pub pending_fee: U256,
+    pub community_balance: U256,
+    pub total_to_sequencer: U256,
+    pub total_to_community: U256,
+    pub total_burned: U256,
+}
+
+impl MoveStructType for TreasuryStatus {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TreasuryStatus");
+}
+
+impl MoveStructState for TreasuryStatus {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Struct(DistributionConfig::struct_layout()),
+            MoveTypeLayout::U256,
+            MoveTypeLayout::U256,
+            MoveTypeLayout::U256,
+            MoveTypeLayout::U256,
+            MoveTypeLayout::U256,
+        ])
+    }
+}
+
+/// The event emitted when the fees of an epoch are distributed
+pub struct FeeDistributedEvent;
+
+impl MoveStructType for FeeDistributedEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("FeeDistributedEvent");
+}
+
+/// Rust bindings for RoochFramework treasury module
+pub struct TreasuryModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> TreasuryModule<'a> {
+    pub const STATUS_FUNCTION_NAME: &'static IdentStr = ident_str!("status");
+    pub const UPDATE_DISTRIBUTION_CONFIG_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("update_distribution_config");
+    pub const UPDATE_EPOCH_INTERVAL_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("update_epoch_interval");
+    pub const WITHDRAW_COMMUNITY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("withdraw_community");
+
+    pub fn status(&self) -> Result<TreasuryStatus> {
+        let call = Self::create_function_call(Self::STATUS_FUNCTION_NAME, vec![], vec![]);
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let value = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| values.pop().expect("should have one return value"))?;
+        Ok(bcs::from_bytes::<TreasuryStatus>(&value.value)?)
+    }
+
+    /// The sender must be the owner of the TreasuryCap
+    pub fn create_update_distribution_config_action(config: DistributionConfig) -> MoveAction {
+        Self::create_move_action(
+            Self::UPDATE_DISTRIBUTION_CONFIG_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(TreasuryCap::object_id().into()),
+                MoveValue::U64(config.sequencer_bps),
+                MoveValue::U64(config.community_bps),
+            ],
+        )
+    }
+
+    /// The sender must be the owner of the TreasuryCap
+    pub fn create_update_epoch_interval_action(epoch_interval: u64) -> MoveAction {
+        Self::create_move_action(
+            Self::UPDATE_EPOCH_INTERVAL_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(TreasuryCap::object_id().into()),
+                MoveValue::U64(epoch_interval),
+            ],
+        )
+    }
+
+    /// The sender must be the owner of the TreasuryCap
+    pub fn create_withdraw_community_action(to: AccountAddress, amount: U256) -> MoveAction {
+        Self::create_move_action(
+            Self::WITHDRAW_COMMUNITY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::Address(TreasuryCap::object_id().into()),
+                MoveValue::Address(to),
+                MoveValue::U256(amount),
+            ],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for TreasuryModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
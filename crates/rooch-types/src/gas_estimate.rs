@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::vm_status::KeptVMStatus;
+use moveos::gas::budget::GasPrices;
+use moveos_types::transaction::{GasDimension, RawTransactionOutput};
+use serde::{Deserialize, Serialize};
+
+/// The gas used by a transaction simulated on the latest state, and the max gas amount suggested for it
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasEstimate {
+    pub status: KeptVMStatus,
+    pub gas_used: u64,
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    pub publish_gas_used: u64,
+    pub storage_rebate: u64,
+    /// The compute gas charged, the execution and the module publishing
+    pub compute_gas_used: u64,
+    /// The dimension of the charge exhausting the budget if the simulated transaction runs out of gas
+    pub out_of_gas_dimension: Option<GasDimension>,
+    /// The safety margin added to the gas charged, in percentage
+    pub margin_percentage: u64,
+    pub suggested_max_gas_amount: u64,
+    /// The compute budget suggested for the transaction, the compute gas with the safety margin
+    pub suggested_compute_budget: u64,
+    /// The storage budget suggested for the transaction, the storage gas with the safety margin
+    pub suggested_storage_budget: u64,
+}
+
+impl GasEstimate {
+    /// The suggested max gas amount is based on the gas charged before the storage rebate,
+    /// the max gas amount limits the gas charged, and the state may change before the transaction is executed.
+    /// The compute and storage budgets are suggested by the gas of each dimension before it is priced,
+    /// the max gas amount by the gas priced with the `gas_prices`.
+    pub fn new(
+        output: &RawTransactionOutput,
+        gas_prices: GasPrices,
+        margin_percentage: u64,
+    ) -> Self {
+        let gas_statement = &output.gas_statement;
+        let compute_gas_used = gas_statement.compute_gas_used();
+        let gas_charged = gas_prices
+            .priced_gas_used(compute_gas_used, gas_statement.storage_gas_used)
+            .max(output.gas_used);
+        Self {
+            status: output.status.clone(),
+            gas_used: output.gas_used,
+            execution_gas_used: gas_statement.execution_gas_used,
+            storage_gas_used: gas_statement.storage_gas_used,
+            publish_gas_used: gas_statement.publish_gas_used,
+            storage_rebate: gas_statement.storage_rebate,
+            compute_gas_used,
+            out_of_gas_dimension: output.out_of_gas_dimension,
+            margin_percentage,
+            suggested_max_gas_amount: suggested_max_gas_amount(gas_charged, margin_percentage),
+            suggested_compute_budget: suggested_max_gas_amount(compute_gas_used, margin_percentage),
+            suggested_storage_budget: suggested_max_gas_amount(
+                gas_statement.storage_gas_used,
+                margin_percentage,
+            ),
+        }
+    }
+}
+
+/// The gas charged with the margin in percentage added, saturated at `u64::MAX`
+pub fn suggested_max_gas_amount(gas_charged: u64, margin_percentage: u64) -> u64 {
+    let amount = gas_charged as u128 * (100 + margin_percentage as u128) / 100;
+    amount.min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggested_max_gas_amount() {
+        assert_eq!(suggested_max_gas_amount(1000, 0), 1000);
+        assert_eq!(suggested_max_gas_amount(1000, 20), 1200);
+        assert_eq!(suggested_max_gas_amount(999, 10), 1098);
+        assert_eq!(suggested_max_gas_amount(0, 50), 0);
+        assert_eq!(suggested_max_gas_amount(u64::MAX, 20), u64::MAX);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+
+/// The basis points of a ratio, 10000 basis points is 100%
+pub const BASIS_POINTS: i128 = 10000;
+
+/// A historical transaction re-priced under a proposed gas schedule
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionGasSimulation {
+    pub tx_hash: H256,
+    pub tx_order: u64,
+    pub original_status: KeptVMStatus,
+    pub original_gas_used: u64,
+    pub simulated_status: KeptVMStatus,
+    pub simulated_gas_used: u64,
+}
+
+impl TransactionGasSimulation {
+    pub fn gas_change(&self) -> i128 {
+        self.simulated_gas_used as i128 - self.original_gas_used as i128
+    }
+
+    /// The gas change relative to the original gas used, in basis points
+    pub fn gas_change_bps(&self) -> i64 {
+        change_bps(self.original_gas_used, self.simulated_gas_used)
+    }
+
+    /// The transaction has a different status under the proposed gas schedule, such as running out of gas
+    pub fn status_changed(&self) -> bool {
+        self.original_status != self.simulated_status
+    }
+}
+
+/// The aggregate cost changes of the sampled transactions under a proposed gas schedule
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasScheduleSimulationStatistics {
+    pub sample_count: u64,
+    pub total_original_gas_used: u64,
+    pub total_simulated_gas_used: u64,
+    /// The change of the total gas used, in basis points
+    pub total_change_bps: i64,
+    pub median_change_bps: i64,
+    pub max_increase_bps: i64,
+    pub max_decrease_bps: i64,
+    pub increased_count: u64,
+    pub decreased_count: u64,
+    pub unchanged_count: u64,
+    pub status_changed_count: u64,
+}
+
+impl GasScheduleSimulationStatistics {
+    pub fn new(transactions: &[TransactionGasSimulation]) -> Self {
+        let mut statistics = Self {
+            sample_count: transactions.len() as u64,
+            ..Default::default()
+        };
+        let mut changes_bps = Vec::with_capacity(transactions.len());
+        for tx in transactions {
+            statistics.total_original_gas_used = statistics
+                .total_original_gas_used
+                .saturating_add(tx.original_gas_used);
+            statistics.total_simulated_gas_used = statistics
+                .total_simulated_gas_used
+                .saturating_add(tx.simulated_gas_used);
+            match tx.gas_change() {
+                change if change > 0 => statistics.increased_count += 1,
+                change if change < 0 => statistics.decreased_count += 1,
+                _ => statistics.unchanged_count += 1,
+            }
+            statistics.status_changed_count += tx.status_changed() as u64;
+            changes_bps.push(tx.gas_change_bps());
+        }
+        changes_bps.sort_unstable();
+        statistics.total_change_bps = change_bps(
+            statistics.total_original_gas_used,
+            statistics.total_simulated_gas_used,
+        );
+        statistics.median_change_bps = changes_bps
+            .get(changes_bps.len() / 2)
+            .copied()
+            .unwrap_or_default();
+        statistics.max_increase_bps = changes_bps.last().copied().unwrap_or_default().max(0);
+        statistics.max_decrease_bps = changes_bps.first().copied().unwrap_or_default().min(0);
+        statistics
+    }
+}
+
+/// The result of re-pricing the sampled transactions under a proposed gas schedule
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasScheduleSimulation {
+    pub statistics: GasScheduleSimulationStatistics,
+    pub transactions: Vec<TransactionGasSimulation>,
+}
+
+impl GasScheduleSimulation {
+    pub fn new(transactions: Vec<TransactionGasSimulation>) -> Self {
+        Self {
+            statistics: GasScheduleSimulationStatistics::new(&transactions),
+            transactions,
+        }
+    }
+}
+
+fn change_bps(original: u64, simulated: u64) -> i64 {
+    let change = simulated as i128 - original as i128;
+    if original == 0 {
+        // A transaction which used no gas is counted as doubled if it is charged now
+        return if change == 0 { 0 } else { BASIS_POINTS as i64 };
+    }
+    (change * BASIS_POINTS / original as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_simulation(original_gas_used: u64, simulated_gas_used: u64) -> TransactionGasSimulation {
+        TransactionGasSimulation {
+            tx_hash: H256::random(),
+            tx_order: 0,
+            original_status: KeptVMStatus::Executed,
+            original_gas_used,
+            simulated_status: KeptVMStatus::Executed,
+            simulated_gas_used,
+        }
+    }
+
+    #[test]
+    fn test_gas_schedule_simulation_statistics() {
+        let mut out_of_gas = new_simulation(100, 300);
+        out_of_gas.simulated_status = KeptVMStatus::OutOfGas;
+        let simulation = GasScheduleSimulation::new(vec![
+            new_simulation(100, 150),
+            new_simulation(200, 100),
+            new_simulation(100, 100),
+            out_of_gas,
+        ]);
+        let statistics = simulation.statistics;
+        assert_eq!(statistics.sample_count, 4);
+        assert_eq!(statistics.total_original_gas_used, 500);
+        assert_eq!(statistics.total_simulated_gas_used, 650);
+        assert_eq!(statistics.total_change_bps, 3000);
+        assert_eq!(statistics.median_change_bps, 5000);
+        assert_eq!(statistics.max_increase_bps, 20000);
+        assert_eq!(statistics.max_decrease_bps, -5000);
+        assert_eq!(statistics.increased_count, 2);
+        assert_eq!(statistics.decreased_count, 1);
+        assert_eq!(statistics.unchanged_count, 1);
+        assert_eq!(statistics.status_changed_count, 1);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::move_types::FunctionId;
+use serde::{Deserialize, Serialize};
+
+/// The cumulative gas usage of a Move function across all executed transactions.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FunctionGasStatistics {
+    pub function_id: FunctionId,
+    /// How many times the function is called as a transaction entry function
+    pub call_count: u64,
+    pub total_gas_used: u64,
+    pub max_gas_used: u64,
+}
+
+impl FunctionGasStatistics {
+    pub fn new(function_id: FunctionId) -> Self {
+        Self {
+            function_id,
+            call_count: 0,
+            total_gas_used: 0,
+            max_gas_used: 0,
+        }
+    }
+
+    pub fn record(&mut self, gas_used: u64) {
+        self.call_count = self.call_count.saturating_add(1);
+        self.total_gas_used = self.total_gas_used.saturating_add(gas_used);
+        self.max_gas_used = self.max_gas_used.max(gas_used);
+    }
+
+    pub fn avg_gas_used(&self) -> u64 {
+        if self.call_count == 0 {
+            0
+        } else {
+            self.total_gas_used / self.call_count
+        }
+    }
+}
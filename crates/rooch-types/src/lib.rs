@@ -9,14 +9,22 @@ pub mod bitcoin;
 pub mod block;
 pub mod chain_id;
 pub mod coin_type;
+pub mod committee;
 pub mod crypto;
 pub mod error;
+pub mod event_proof;
 pub mod framework;
 pub mod function_arg;
+pub mod gas_estimate;
+pub mod gas_schedule_simulation;
+pub mod gas_statistics;
 pub mod indexer;
 pub mod into_address;
 pub mod key_struct;
+pub mod migration;
 pub mod multichain_id;
+pub mod replica;
 pub mod sequencer;
+pub mod source_verification;
 pub mod stdlib_version;
 pub mod transaction;
@@ -0,0 +1,208 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use move_core_types::language_storage::StructTag;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_types::FunctionId;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::State;
+use moveos_types::transaction::{FunctionCall, MoveAction};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The states to migrate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationTarget {
+    /// The objects of the type in the global object storage
+    Object(StructTag),
+    /// The resources of the type in all the accounts
+    Resource(StructTag),
+}
+
+impl MigrationTarget {
+    pub fn struct_tag(&self) -> &StructTag {
+        match self {
+            MigrationTarget::Object(struct_tag) | MigrationTarget::Resource(struct_tag) => {
+                struct_tag
+            }
+        }
+    }
+
+    /// Check the type matches the target, the type parameters are ignored,
+    /// so a migration of `Coin<T>` matches the coins of all types.
+    pub fn matches(&self, struct_tag: &StructTag) -> bool {
+        let target = self.struct_tag();
+        target.address == struct_tag.address
+            && target.module == struct_tag.module
+            && target.name == struct_tag.name
+    }
+}
+
+/// A state to migrate.
+/// The id is the object id for the object target, and the account address for the resource target.
+#[derive(Debug, Clone)]
+pub struct MigrationItem {
+    pub id: ObjectID,
+    pub state: State,
+}
+
+/// The migration implemented in Rust, it builds the action to migrate a batch of states.
+pub trait RustMigration: Send + Sync {
+    /// Return `None` if there is nothing to migrate in the batch
+    fn migrate(
+        &self,
+        caller: &dyn MoveFunctionCaller,
+        items: &[MigrationItem],
+    ) -> Result<Option<MoveAction>>;
+}
+
+#[derive(Clone)]
+pub enum MigrationFunction {
+    /// The Move entry function `fun migrate(ctx: &mut Context, account: &signer, object_ids: vector<ObjectID>)`,
+    /// it is called with the ids of every batch.
+    Move(FunctionId),
+    Rust(Arc<dyn RustMigration>),
+}
+
+impl MigrationFunction {
+    pub fn migrate(
+        &self,
+        caller: &dyn MoveFunctionCaller,
+        items: &[MigrationItem],
+    ) -> Result<Option<MoveAction>> {
+        if items.is_empty() {
+            return Ok(None);
+        }
+        match self {
+            MigrationFunction::Move(function_id) => {
+                let object_ids = items.iter().map(|item| item.id).collect::<Vec<_>>();
+                Ok(Some(MoveAction::Function(FunctionCall::new(
+                    function_id.clone(),
+                    vec![],
+                    vec![bcs::to_bytes(&object_ids)?],
+                ))))
+            }
+            MigrationFunction::Rust(migration) => migration.migrate(caller, items),
+        }
+    }
+}
+
+/// A state migration which runs when the framework is upgraded to the `framework_version`
+#[derive(Clone)]
+pub struct Migration {
+    /// The unique name of the migration, it is recorded on-chain when the migration is completed
+    pub name: String,
+    pub framework_version: u64,
+    pub target: MigrationTarget,
+    pub function: MigrationFunction,
+}
+
+#[derive(Clone, Default)]
+pub struct MigrationRegistry {
+    migrations: Vec<Migration>,
+}
+
+impl MigrationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The migrations of the framework upgrades, a migration should be registered here
+    /// when a framework upgrade changes the struct layouts.
+    pub fn builtin() -> Self {
+        Self::new()
+    }
+
+    pub fn register(&mut self, migration: Migration) -> Result<()> {
+        ensure!(
+            self.migrations.iter().all(|m| m.name != migration.name),
+            "The migration {} is already registered",
+            migration.name
+        );
+        self.migrations.push(migration);
+        Ok(())
+    }
+
+    /// The migrations which should run at the framework version, ordered by the framework version
+    pub fn migrations_at(&self, framework_version: u64) -> Vec<&Migration> {
+        let mut migrations = self
+            .migrations
+            .iter()
+            .filter(|m| m.framework_version <= framework_version)
+            .collect::<Vec<_>>();
+        migrations.sort_by_key(|m| m.framework_version);
+        migrations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.migrations.is_empty()
+    }
+}
+
+/// The progress of a migration, it is saved after every batch, so the migration resumes from it after restart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MigrationCheckpoint {
+    /// The key of the last scanned state, the next batch starts after it
+    pub cursor: Option<Vec<u8>>,
+    pub scanned: u64,
+    pub migrated: u64,
+    pub completed: bool,
+}
+
+/// The report of a migration run
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationReport {
+    pub name: String,
+    pub dry_run: bool,
+    pub scanned: u64,
+    pub migrated: u64,
+    pub batches: u64,
+    pub gas_used: u64,
+    /// The errors of the failed batches, only reported in the dry run
+    pub failed_batches: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use std::str::FromStr;
+
+    fn migration(name: &str, framework_version: u64) -> Migration {
+        Migration {
+            name: name.to_owned(),
+            framework_version,
+            target: MigrationTarget::Object(
+                StructTag::from_str("0x42::test::TestObject<u64>").unwrap(),
+            ),
+            function: MigrationFunction::Move(FunctionId::from_str("0x42::test::migrate").unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_migration_registry() {
+        let mut registry = MigrationRegistry::new();
+        registry.register(migration("v2", 2)).unwrap();
+        registry.register(migration("v1", 1)).unwrap();
+        assert!(registry.register(migration("v1", 1)).is_err());
+
+        assert!(registry.migrations_at(0).is_empty());
+        let names = registry
+            .migrations_at(2)
+            .into_iter()
+            .map(|m| m.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["v1", "v2"]);
+
+        let target = &registry.migrations_at(1)[0].target;
+        assert!(target.matches(&StructTag::from_str("0x42::test::TestObject<u8>").unwrap()));
+        assert!(!target.matches(&StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("test").unwrap(),
+            name: Identifier::new("TestObject").unwrap(),
+            type_params: vec![],
+        }));
+    }
+}
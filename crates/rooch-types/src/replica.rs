@@ -0,0 +1,195 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transaction::{TransactionSequenceInfo, TypedTransaction};
+use anyhow::Result;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::{AccountChangeSet, ChangeSet, Op};
+use move_core_types::language_storage::TypeTag;
+use moveos_types::moveos_std::event::{Event, TransactionEvent};
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{State, StateChangeSet, TableChange, TableTypeInfo};
+use moveos_types::transaction::TransactionExecutionInfo;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The serializable form of `Op<State>`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StateChangeOp {
+    New(State),
+    Modify(State),
+    Delete,
+}
+
+impl From<Op<State>> for StateChangeOp {
+    fn from(op: Op<State>) -> Self {
+        match op {
+            Op::New(state) => StateChangeOp::New(state),
+            Op::Modify(state) => StateChangeOp::Modify(state),
+            Op::Delete => StateChangeOp::Delete,
+        }
+    }
+}
+
+impl From<StateChangeOp> for Op<State> {
+    fn from(op: StateChangeOp) -> Self {
+        match op {
+            StateChangeOp::New(state) => Op::New(state),
+            StateChangeOp::Modify(state) => Op::Modify(state),
+            StateChangeOp::Delete => Op::Delete,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ReplicaTableChange {
+    pub entries: Vec<(Vec<u8>, StateChangeOp)>,
+    pub size_increment: i64,
+    pub key_type: TypeTag,
+}
+
+/// The changes of a committed transaction, the read replicas apply them without executing the transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ReplicaChangeSet {
+    /// The accounts of the `ChangeSet`, they are only used to init the account storage
+    pub accounts: Vec<AccountAddress>,
+    pub new_tables: BTreeMap<ObjectID, TableTypeInfo>,
+    pub removed_tables: BTreeSet<ObjectID>,
+    pub changes: BTreeMap<ObjectID, ReplicaTableChange>,
+    pub events: Vec<TransactionEvent>,
+    pub is_upgrade: bool,
+}
+
+impl ReplicaChangeSet {
+    pub fn new(
+        changeset: &ChangeSet,
+        state_changeset: &StateChangeSet,
+        events: &[Event],
+        is_upgrade: bool,
+    ) -> Self {
+        Self {
+            accounts: changeset.accounts().keys().cloned().collect(),
+            new_tables: state_changeset.new_tables.clone(),
+            removed_tables: state_changeset.removed_tables.clone(),
+            changes: state_changeset
+                .changes
+                .iter()
+                .map(|(object_id, table_change)| {
+                    let table_change = ReplicaTableChange {
+                        entries: table_change
+                            .entries
+                            .iter()
+                            .map(|(key, op)| (key.clone(), op.clone().into()))
+                            .collect(),
+                        size_increment: table_change.size_increment,
+                        key_type: table_change.key_type.clone(),
+                    };
+                    (*object_id, table_change)
+                })
+                .collect(),
+            events: events
+                .iter()
+                .map(|event| {
+                    TransactionEvent::new(
+                        event.event_type.clone(),
+                        event.event_data.clone(),
+                        event.event_index,
+                    )
+                })
+                .collect(),
+            is_upgrade,
+        }
+    }
+
+    pub fn into_change_sets(self) -> Result<(ChangeSet, StateChangeSet, Vec<TransactionEvent>)> {
+        let mut changeset = ChangeSet::new();
+        for account in self.accounts {
+            changeset.add_account_changeset(
+                account,
+                AccountChangeSet::from_modules_resources(BTreeMap::new(), BTreeMap::new()),
+            )?;
+        }
+        let state_changeset = StateChangeSet {
+            new_tables: self.new_tables,
+            removed_tables: self.removed_tables,
+            changes: self
+                .changes
+                .into_iter()
+                .map(|(object_id, table_change)| {
+                    let table_change = TableChange {
+                        entries: table_change
+                            .entries
+                            .into_iter()
+                            .map(|(key, op)| (key, op.into()))
+                            .collect(),
+                        size_increment: table_change.size_increment,
+                        key_type: table_change.key_type,
+                    };
+                    (object_id, table_change)
+                })
+                .collect(),
+        };
+        Ok((changeset, state_changeset, self.events))
+    }
+}
+
+/// An entry of the change feed, the primary node streams the committed transactions with their changes to the replicas.
+/// The replica verifies the state root and the event root of the applied changes with the execution info.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChangeFeedEntry {
+    pub tx: TypedTransaction,
+    pub sequence_info: TransactionSequenceInfo,
+    pub execution_info: TransactionExecutionInfo,
+    pub change_set: ReplicaChangeSet,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::StructTag;
+    use moveos_types::moveos_std::event::EventID;
+
+    #[test]
+    fn test_replica_change_set_roundtrip() {
+        let object_id = ObjectID::from(AccountAddress::random());
+        let mut state_changeset = StateChangeSet::default();
+        let table_change = state_changeset.get_or_insert_table_change(object_id, TypeTag::U64);
+        table_change
+            .entries
+            .insert(vec![1], Op::New(State::new(vec![1, 2], TypeTag::U8)));
+        table_change.entries.insert(vec![2], Op::Delete);
+        table_change.size_increment = 0;
+        state_changeset.removed_tables.insert(object_id);
+
+        let mut changeset = ChangeSet::new();
+        changeset
+            .add_account_changeset(
+                AccountAddress::ONE,
+                AccountChangeSet::from_modules_resources(BTreeMap::new(), BTreeMap::new()),
+            )
+            .unwrap();
+        let event = Event::new(
+            EventID::new(object_id, 0),
+            StructTag {
+                address: AccountAddress::ONE,
+                module: Identifier::new("m").unwrap(),
+                name: Identifier::new("E").unwrap(),
+                type_params: vec![],
+            },
+            vec![3],
+            0,
+        );
+
+        let replica_changeset =
+            ReplicaChangeSet::new(&changeset, &state_changeset, &[event.clone()], false);
+        let bytes = bcs::to_bytes(&replica_changeset).unwrap();
+        let decoded: ReplicaChangeSet = bcs::from_bytes(&bytes).unwrap();
+        let (decoded_changeset, decoded_state_changeset, events) =
+            decoded.into_change_sets().unwrap();
+        assert_eq!(decoded_changeset, changeset);
+        assert_eq!(decoded_state_changeset, state_changeset);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, event.event_type);
+    }
+}
@@ -1,9 +1,18 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::address::RoochAddress;
+use crate::crypto::{RoochKeyPair, Signature};
+use crate::framework::sequencer_key_registry::{SequencerKeyRegistryModule, SequencerKeys};
+use crate::transaction::{TransactionSequenceInfo, TypedTransaction};
+use anyhow::Result;
+use moveos_types::h256::{self, H256};
+use moveos_types::module_binding::MoveFunctionCaller;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 #[derive(Eq, PartialEq, Hash, Deserialize, Serialize, Clone, Debug)]
 pub struct SequencerOrder {
@@ -21,3 +30,165 @@ impl SequencerOrder {
         SequencerOrder { last_order }
     }
 }
+
+/// The gas used by the executed transactions buffered in the proposer, it is updated by the proposer
+/// and read by the sequencer to reject the transactions when the proposer lags behind.
+#[derive(Clone, Debug, Default)]
+pub struct ProposerBacklog {
+    buffered_gas_used: Arc<AtomicU64>,
+}
+
+impl ProposerBacklog {
+    pub fn buffered_gas_used(&self) -> u64 {
+        self.buffered_gas_used.load(Ordering::Relaxed)
+    }
+
+    pub fn set_buffered_gas_used(&self, buffered_gas_used: u64) {
+        self.buffered_gas_used
+            .store(buffered_gas_used, Ordering::Relaxed);
+    }
+}
+
+/// A transaction accepted by the sequencer but not sequenced yet.
+/// It is persisted when it passes the validation, so it survives the sequencer restart,
+/// and it is revalidated and sequenced in the accepted order when the node starts.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct PendingTransaction {
+    pub tx: TypedTransaction,
+    /// The order of the acceptance, it increases monotonically while the transactions are pending,
+    /// so the transactions are recovered in the accepted order regardless of the clock
+    pub accept_order: u64,
+}
+
+/// Track the sequencer keys in the on-chain sequencer key registry.
+/// The verifying followers refresh the tracker after applying the transactions, and verify the sequencer signatures with it,
+/// so the next sequencer key is accepted once it is announced, and the previous key is rejected after the handover window.
+#[derive(Debug, Clone)]
+pub struct SequencerKeyTracker {
+    keys: SequencerKeys,
+}
+
+impl SequencerKeyTracker {
+    pub fn new(caller: &impl MoveFunctionCaller) -> Result<Self> {
+        let keys = caller
+            .as_module_binding::<SequencerKeyRegistryModule>()
+            .sequencer_keys()?;
+        Ok(Self { keys })
+    }
+
+    /// Reload the sequencer keys from the registry, return true if the sequencer or the pending rotation is changed
+    pub fn refresh(&mut self, caller: &impl MoveFunctionCaller) -> Result<bool> {
+        let keys = caller
+            .as_module_binding::<SequencerKeyRegistryModule>()
+            .sequencer_keys()?;
+        let changed = keys.sequencer != self.keys.sequencer
+            || keys.key_version != self.keys.key_version
+            || keys.pending_rotation != self.keys.pending_rotation;
+        self.keys = keys;
+        Ok(changed)
+    }
+
+    pub fn keys(&self) -> &SequencerKeys {
+        &self.keys
+    }
+
+    pub fn verify_tx_order_signature(
+        &self,
+        tx_hash: H256,
+        sequence_info: &TransactionSequenceInfo,
+    ) -> Result<RoochAddress> {
+        self.keys.verify_tx_order_signature(tx_hash, sequence_info)
+    }
+}
+
+/// A batch of transactions sequenced and signed by the sequencer, the batch id is the block number in the SCC.
+/// It is kept by the proposer, so the third parties can audit the sequencing against the DA.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct SequencerBatch {
+    pub batch_id: u128,
+    /// The tx order of the first transaction in the batch, the tx orders in the batch are continuous
+    pub first_tx_order: u64,
+    pub tx_hashes: Vec<H256>,
+    /// The DA commitment of the batch, the sha3_256 hash of the concatenated encoded transactions
+    pub batch_hash: H256,
+    pub execution_checksum: H256,
+    pub prev_tx_accumulator_root: H256,
+    pub tx_accumulator_root: H256,
+    /// The signatures of the signing hash by the sequencer keys,
+    /// there are two signatures during the sequencer key rotation handover window,
+    /// and the threshold signature of the sequencer committee is appended if the committee is configured
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl SequencerBatch {
+    pub fn batch_size(&self) -> u64 {
+        self.tx_hashes.len() as u64
+    }
+
+    /// The tx orders of the transactions in the batch
+    pub fn tx_orders(&self) -> Vec<u64> {
+        (self.first_tx_order..self.first_tx_order + self.batch_size()).collect()
+    }
+
+    /// The hash signed by the sequencer keys, it covers every field except the signatures,
+    /// `sha3_256(bcs(batch_id, first_tx_order, tx_hashes, batch_hash, execution_checksum, prev_tx_accumulator_root, tx_accumulator_root))`,
+    /// the same as the signing hash of the batch meta submitted to the DA.
+    pub fn signing_hash(&self) -> H256 {
+        let bytes = bcs::to_bytes(&(
+            self.batch_id,
+            self.first_tx_order,
+            &self.tx_hashes,
+            self.batch_hash,
+            self.execution_checksum,
+            self.prev_tx_accumulator_root,
+            self.tx_accumulator_root,
+        ))
+        .expect("Serialize batch signing message should success");
+        h256::sha3_256_of(&bytes)
+    }
+}
+
+/// The hash signed by the sequencer for an inclusion receipt, it is `sha3_256(bcs(tx_hash, tx_order, timestamp_ms))`
+pub fn receipt_signing_hash(tx_hash: H256, tx_order: u64, timestamp_ms: u64) -> H256 {
+    let bytes = bcs::to_bytes(&(tx_hash, tx_order, timestamp_ms))
+        .expect("Serialize receipt signing message should success");
+    h256::sha3_256_of(&bytes)
+}
+
+/// The pre-confirmation signed by the sequencer when it sequences a transaction, before the transaction is executed.
+/// It is signed after the tx order is assigned and persisted, so a receipt with the batch covering the tx order
+/// is the evidence the sequencer censors or reorders the transaction.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct TxInclusionReceipt {
+    pub tx_hash: H256,
+    /// The tx order the transaction is sequenced at
+    pub tx_order: u64,
+    /// The timestamp of the sequencing in milliseconds
+    pub timestamp_ms: u64,
+    pub sequencer: RoochAddress,
+    /// The signature of the signing hash by the sequencer key
+    pub signature: Vec<u8>,
+}
+
+impl TxInclusionReceipt {
+    pub fn new(
+        tx_hash: H256,
+        tx_order: u64,
+        timestamp_ms: u64,
+        sequencer_key: &RoochKeyPair,
+    ) -> Self {
+        let signing_hash = receipt_signing_hash(tx_hash, tx_order, timestamp_ms);
+        let signature = Signature::new_hashed(signing_hash.as_bytes(), sequencer_key);
+        Self {
+            tx_hash,
+            tx_order,
+            timestamp_ms,
+            sequencer: sequencer_key.public().address(),
+            signature: signature.as_ref().to_vec(),
+        }
+    }
+
+    pub fn signing_hash(&self) -> H256 {
+        receipt_signing_hash(self.tx_hash, self.tx_order, self.timestamp_ms)
+    }
+}
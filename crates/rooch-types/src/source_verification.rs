@@ -0,0 +1,114 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::{self, H256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The source code of a published package, and the metadata to rebuild it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PackageSource {
+    pub package_address: AccountAddress,
+    pub package_name: String,
+    /// The Move.toml and the source files, keyed by the path relative to the package root
+    pub files: BTreeMap<String, String>,
+    pub build_metadata: BuildMetadata,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BuildMetadata {
+    /// The version of the rooch cli which built the package
+    pub compiler_version: String,
+    pub named_addresses: BTreeMap<String, AccountAddress>,
+    /// The bytecode of the modules built from the source, keyed by the module name
+    pub modules: BTreeMap<String, Vec<u8>>,
+}
+
+impl PackageSource {
+    pub fn source_hash(&self) -> H256 {
+        h256::sha3_256_of(
+            &bcs::to_bytes(&self.files).expect("Serialize the source files should success"),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SourceVerificationStatus {
+    /// The rebuilt modules are the same as the on-chain modules
+    Verified,
+    /// The modules which are different, missing on-chain or missing in the rebuilt package
+    Mismatched { modules: Vec<String> },
+}
+
+impl SourceVerificationStatus {
+    pub fn is_verified(&self) -> bool {
+        matches!(self, SourceVerificationStatus::Verified)
+    }
+}
+
+/// The verification record of a package in the source registry
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SourceVerification {
+    pub source: PackageSource,
+    pub source_hash: H256,
+    pub status: SourceVerificationStatus,
+    /// The timestamp of the verification in milliseconds
+    pub verified_at: u64,
+}
+
+/// Compare the bytecode hashes of the rebuilt modules and the on-chain modules, both keyed by the module name.
+pub fn verify_package_modules(
+    rebuilt: &BTreeMap<String, Vec<u8>>,
+    on_chain: &BTreeMap<String, Vec<u8>>,
+) -> SourceVerificationStatus {
+    let mut modules = on_chain
+        .iter()
+        .filter(|(name, bytes)| {
+            rebuilt
+                .get(*name)
+                .map(|rebuilt_bytes| h256::sha3_256_of(rebuilt_bytes))
+                != Some(h256::sha3_256_of(bytes))
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    modules.extend(
+        rebuilt
+            .keys()
+            .filter(|name| !on_chain.contains_key(*name))
+            .cloned(),
+    );
+    if modules.is_empty() && !on_chain.is_empty() {
+        SourceVerificationStatus::Verified
+    } else {
+        modules.sort();
+        SourceVerificationStatus::Mismatched { modules }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_package_modules() {
+        let on_chain =
+            BTreeMap::from([("a".to_owned(), vec![1u8, 2]), ("b".to_owned(), vec![3u8])]);
+        assert_eq!(
+            verify_package_modules(&on_chain, &on_chain),
+            SourceVerificationStatus::Verified
+        );
+
+        let mut rebuilt = on_chain.clone();
+        rebuilt.insert("b".to_owned(), vec![4u8]);
+        rebuilt.insert("c".to_owned(), vec![5u8]);
+        rebuilt.remove("a");
+        assert_eq!(
+            verify_package_modules(&rebuilt, &on_chain),
+            SourceVerificationStatus::Mismatched {
+                modules: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]
+            }
+        );
+        assert!(!verify_package_modules(&BTreeMap::new(), &BTreeMap::new()).is_verified());
+    }
+}
@@ -7,7 +7,12 @@ use crate::multichain_id::{MultiChainID, ETHER, ROOCH};
 use anyhow::{format_err, Result};
 use move_core_types::account_address::AccountAddress;
 use moveos_types::transaction::TransactionExecutionInfo;
-use moveos_types::{h256::H256, transaction::MoveOSTransaction};
+use moveos_types::{
+    h256::{self, H256},
+    transaction::{MoveOSTransaction, TxDependencyHints},
+};
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -129,6 +134,16 @@ impl TryFrom<RawTransaction> for TypedTransaction {
     }
 }
 
+impl TypedTransaction {
+    /// The dependency hints declared by the transaction, the Ethereum transactions do not declare them
+    pub fn dependency_hints(&self) -> Option<&TxDependencyHints> {
+        match self {
+            TypedTransaction::Rooch(tx) => tx.dependency_hints(),
+            TypedTransaction::Ethereum(_) => None,
+        }
+    }
+}
+
 impl AbstractTransaction for TypedTransaction {
     fn transaction_type(&self) -> TransactionType {
         match self {
@@ -204,12 +219,17 @@ impl AbstractTransaction for TypedTransaction {
 
 ///`TransactionSequenceInfo` represents the result of sequence a transaction.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct TransactionSequenceInfo {
     /// The tx order
     pub tx_order: u64,
     /// The tx order signature, it is the signature of the sequencer to commit the tx order.
     pub tx_order_signature: Authenticator,
     /// The tx accumulator root after the tx is append to the accumulator.
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "moveos_types::h256::h256_prop_strategy()")
+    )]
     pub tx_accumulator_root: H256,
 }
 
@@ -225,6 +245,13 @@ impl TransactionSequenceInfo {
             tx_accumulator_root,
         }
     }
+
+    /// The hash signed by the sequencer to commit the tx order, it is `sha3_256(tx_hash || tx_order_le_bytes)`
+    pub fn tx_order_witness_hash(tx_hash: H256, tx_order: u64) -> H256 {
+        let mut witness_data = tx_hash.as_ref().to_vec();
+        witness_data.extend(tx_order.to_le_bytes().iter());
+        h256::sha3_256_of(&witness_data)
+    }
 }
 
 /// Transaction with sequence info and execution info.
@@ -252,6 +279,8 @@ impl TransactionSequenceInfoMapping {
 #[cfg(test)]
 mod tests {
     use super::rooch::RoochTransaction;
+    use super::TransactionSequenceInfo;
+    use proptest::prelude::*;
 
     fn test_serialize_deserialize_roundtrip<T>(tx: T)
     where
@@ -267,4 +296,18 @@ mod tests {
         let tx = RoochTransaction::mock();
         test_serialize_deserialize_roundtrip(tx)
     }
+
+    proptest! {
+        #[test]
+        fn test_rooch_transaction_serialize_deserialize(tx in any::<RoochTransaction>()) {
+            test_serialize_deserialize_roundtrip(tx)
+        }
+
+        #[test]
+        fn test_transaction_sequence_info_bcs_serde(input in any::<TransactionSequenceInfo>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: TransactionSequenceInfo = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+    }
 }
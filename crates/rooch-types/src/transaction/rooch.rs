@@ -4,7 +4,11 @@
 use super::{
     authenticator::Authenticator, AbstractTransaction, AuthenticatorInfo, TransactionType,
 };
+use crate::addresses::{BITCOIN_MOVE_ADDRESS, ROOCH_FRAMEWORK_ADDRESS};
 use crate::crypto::{Ed25519RoochSignature, RoochKeyPair, Signature};
+use crate::framework::auth_validator::BuiltinAuthValidator;
+use crate::framework::gas_sponsor::TxSponsor;
+use crate::framework::transaction_fee::MaxGasPrice;
 use crate::multichain_id::{MultiChainID, ROOCH};
 use crate::{address::RoochAddress, chain_id::RoochChainID};
 use anyhow::Result;
@@ -13,12 +17,24 @@ use moveos_types::gas_config::GasConfig;
 use moveos_types::h256::H256;
 use moveos_types::{
     moveos_std::tx_context::TxContext,
-    transaction::{MoveAction, MoveOSTransaction},
+    transaction::{GasBudget, MoveAction, MoveOSTransaction, TxDependencyHints},
 };
-use serde::{Deserialize, Serialize};
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest_derive::Arbitrary;
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::debug_assert;
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+/// The marker of the versioned layouts of the transaction data, it takes the place of the sender of the first layout.
+/// It is the sha3-256 of `ROOCH::VersionedRoochTransactionData`, so it is never the address of a sender.
+pub const VERSIONED_TX_DATA_MARKER: [u8; 32] = [
+    0xd3, 0xa8, 0xbf, 0x09, 0xd9, 0x1d, 0x94, 0xde, 0x0f, 0x33, 0x8d, 0x15, 0x77, 0xcc, 0xf2, 0x77,
+    0xdd, 0x11, 0x5c, 0xed, 0xfb, 0x43, 0x02, 0xd2, 0x38, 0xd5, 0xaa, 0x51, 0xc7, 0x6b, 0x8e, 0x51,
+];
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct RoochTransactionData {
     /// Sender's address.
     pub sender: RoochAddress,
@@ -30,6 +46,197 @@ pub struct RoochTransactionData {
     pub max_gas_amount: u64,
     // The MoveAction to execute.
     pub action: MoveAction,
+    // The objects and the accounts the transaction declares to touch, they are checked at the execution.
+    pub dependency_hints: Option<TxDependencyHints>,
+    // The sponsor paying the gas of the transaction instead of the sender, the sponsor co-signs the transaction.
+    pub sponsor: Option<RoochAddress>,
+    // The max gas price the sender accepts, the transaction is rejected if the base gas price is higher,
+    // and the gas is never priced higher than it. The gas is priced by the base gas price if it is `None`.
+    pub max_gas_price: Option<u64>,
+    // The compute and storage budgets of the transaction, the gas of each dimension is limited by its own budget,
+    // and their priced gas by the max gas amount. Both dimensions share the max gas amount if it is `None`.
+    pub gas_budget: Option<GasBudget>,
+}
+
+/// The first layout of the transaction data, it is not prefixed. The transaction data without the fields of the
+/// later layouts is always encoded in it, so the transactions signed by the old clients are still valid.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename = "RoochTransactionData")]
+pub struct RoochTransactionDataV0 {
+    pub sender: RoochAddress,
+    pub sequence_number: u64,
+    pub chain_id: u64,
+    pub max_gas_amount: u64,
+    pub action: MoveAction,
+}
+
+/// The fields of the first layout after the sender
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RoochTransactionDataV0Body {
+    sequence_number: u64,
+    chain_id: u64,
+    max_gas_amount: u64,
+    action: MoveAction,
+}
+
+/// The later layouts of the transaction data, they are prefixed by the `VERSIONED_TX_DATA_MARKER`.
+/// The fields are only added in a new version.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum VersionedRoochTransactionData {
+    V1(RoochTransactionDataV1),
+    V2(RoochTransactionDataV2),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoochTransactionDataV1 {
+    pub sender: RoochAddress,
+    pub sequence_number: u64,
+    pub chain_id: u64,
+    pub max_gas_amount: u64,
+    pub action: MoveAction,
+    pub dependency_hints: Option<TxDependencyHints>,
+    pub sponsor: Option<RoochAddress>,
+    pub max_gas_price: Option<u64>,
+}
+
+/// The layout with the compute and storage budgets
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoochTransactionDataV2 {
+    pub sender: RoochAddress,
+    pub sequence_number: u64,
+    pub chain_id: u64,
+    pub max_gas_amount: u64,
+    pub action: MoveAction,
+    pub dependency_hints: Option<TxDependencyHints>,
+    pub sponsor: Option<RoochAddress>,
+    pub max_gas_price: Option<u64>,
+    pub gas_budget: GasBudget,
+}
+
+impl Serialize for RoochTransactionData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        if self.is_versioned() {
+            let versioned = match self.gas_budget {
+                Some(gas_budget) => VersionedRoochTransactionData::V2(RoochTransactionDataV2 {
+                    sender: self.sender,
+                    sequence_number: self.sequence_number,
+                    chain_id: self.chain_id,
+                    max_gas_amount: self.max_gas_amount,
+                    action: self.action.clone(),
+                    dependency_hints: self.dependency_hints.clone(),
+                    sponsor: self.sponsor,
+                    max_gas_price: self.max_gas_price,
+                    gas_budget,
+                }),
+                None => VersionedRoochTransactionData::V1(RoochTransactionDataV1 {
+                    sender: self.sender,
+                    sequence_number: self.sequence_number,
+                    chain_id: self.chain_id,
+                    max_gas_amount: self.max_gas_amount,
+                    action: self.action.clone(),
+                    dependency_hints: self.dependency_hints.clone(),
+                    sponsor: self.sponsor,
+                    max_gas_price: self.max_gas_price,
+                }),
+            };
+            tuple.serialize_element(&RoochAddress(H256(VERSIONED_TX_DATA_MARKER)))?;
+            tuple.serialize_element(&versioned)?;
+        } else {
+            if self.sender.0 .0 == VERSIONED_TX_DATA_MARKER {
+                return Err(serde::ser::Error::custom(
+                    "The sender of the transaction is the versioned layout marker",
+                ));
+            }
+            // The same bytes as the `RoochTransactionDataV0`
+            tuple.serialize_element(&self.sender)?;
+            tuple.serialize_element(&RoochTransactionDataV0Body {
+                sequence_number: self.sequence_number,
+                chain_id: self.chain_id,
+                max_gas_amount: self.max_gas_amount,
+                action: self.action.clone(),
+            })?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransactionData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RoochTransactionDataVisitor;
+
+        impl<'de> Visitor<'de> for RoochTransactionDataVisitor {
+            type Value = RoochTransactionData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter
+                    .write_str("the transaction data prefixed by the sender or the version marker")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let sender: RoochAddress = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                if sender.0 .0 != VERSIONED_TX_DATA_MARKER {
+                    let body: RoochTransactionDataV0Body = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                    return Ok(RoochTransactionData::new(
+                        sender,
+                        body.sequence_number,
+                        body.chain_id,
+                        body.max_gas_amount,
+                        body.action,
+                    ));
+                }
+                let versioned: VersionedRoochTransactionData = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                let data = match versioned {
+                    VersionedRoochTransactionData::V1(data) => RoochTransactionData {
+                        sender: data.sender,
+                        sequence_number: data.sequence_number,
+                        chain_id: data.chain_id,
+                        max_gas_amount: data.max_gas_amount,
+                        action: data.action,
+                        dependency_hints: data.dependency_hints,
+                        sponsor: data.sponsor,
+                        max_gas_price: data.max_gas_price,
+                        gas_budget: None,
+                    },
+                    VersionedRoochTransactionData::V2(data) => RoochTransactionData {
+                        sender: data.sender,
+                        sequence_number: data.sequence_number,
+                        chain_id: data.chain_id,
+                        max_gas_amount: data.max_gas_amount,
+                        action: data.action,
+                        dependency_hints: data.dependency_hints,
+                        sponsor: data.sponsor,
+                        max_gas_price: data.max_gas_price,
+                        gas_budget: Some(data.gas_budget),
+                    },
+                };
+                // The data must be encoded in the lowest layout carrying its fields, so its hash is unique
+                if !data.is_versioned() {
+                    return Err(serde::de::Error::custom(
+                        "The transaction data without the versioned fields must be in the first layout",
+                    ));
+                }
+                Ok(data)
+            }
+        }
+
+        deserializer.deserialize_tuple(2, RoochTransactionDataVisitor)
+    }
 }
 
 impl RoochTransactionData {
@@ -46,9 +253,41 @@ impl RoochTransactionData {
             chain_id,
             max_gas_amount,
             action,
+            dependency_hints: None,
+            sponsor: None,
+            max_gas_price: None,
+            gas_budget: None,
         }
     }
 
+    /// The transaction data carrying the fields after the first layout is encoded in the versioned layouts
+    pub fn is_versioned(&self) -> bool {
+        self.dependency_hints.is_some()
+            || self.sponsor.is_some()
+            || self.max_gas_price.is_some()
+            || self.gas_budget.is_some()
+    }
+
+    pub fn with_dependency_hints(mut self, dependency_hints: TxDependencyHints) -> Self {
+        self.dependency_hints = Some(dependency_hints);
+        self
+    }
+
+    pub fn with_sponsor(mut self, sponsor: RoochAddress) -> Self {
+        self.sponsor = Some(sponsor);
+        self
+    }
+
+    pub fn with_max_gas_price(mut self, max_gas_price: u64) -> Self {
+        self.max_gas_price = Some(max_gas_price);
+        self
+    }
+
+    pub fn with_gas_budget(mut self, gas_budget: GasBudget) -> Self {
+        self.gas_budget = Some(gas_budget);
+        self
+    }
+
     pub fn new_for_test(sender: RoochAddress, sequence_number: u64, action: MoveAction) -> Self {
         Self {
             sender,
@@ -56,6 +295,10 @@ impl RoochTransactionData {
             chain_id: RoochChainID::LOCAL.chain_id().id(),
             max_gas_amount: GasConfig::DEFAULT_MAX_GAS_AMOUNT * 100,
             action,
+            dependency_hints: None,
+            sponsor: None,
+            max_gas_price: None,
+            gas_budget: None,
         }
     }
 
@@ -64,7 +307,7 @@ impl RoochTransactionData {
     }
 
     pub fn hash(&self) -> H256 {
-        moveos_types::h256::sha3_256_of(self.encode().as_slice())
+        moveos_types::h256::sha3_256_of_bcs(self).expect("encode transaction should success")
     }
 
     pub fn sign(self, kp: &RoochKeyPair) -> RoochTransaction {
@@ -75,10 +318,92 @@ impl RoochTransactionData {
     }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+#[cfg_attr(
+    any(test, feature = "fuzzing"),
+    proptest(filter = "|tx| tx.data.is_versioned() || tx.sponsor_authenticator.is_none()")
+)]
 pub struct RoochTransaction {
     data: RoochTransactionData,
     authenticator: Authenticator,
+    // The sponsor's authenticator of the transaction hash, required if the transaction designates a sponsor.
+    sponsor_authenticator: Option<Authenticator>,
+}
+
+/// The authenticators of the transaction with the versioned data, the transaction with the data of the first layout
+/// is followed by the sender's authenticator only, the same bytes as before the sponsor is introduced.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RoochTransactionAuthenticators {
+    pub authenticator: Authenticator,
+    pub sponsor_authenticator: Option<Authenticator>,
+}
+
+impl Serialize for RoochTransaction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&self.data)?;
+        if self.data.is_versioned() {
+            tuple.serialize_element(&RoochTransactionAuthenticators {
+                authenticator: self.authenticator.clone(),
+                sponsor_authenticator: self.sponsor_authenticator.clone(),
+            })?;
+        } else {
+            // Only the transaction designating a sponsor, which is versioned, carries the sponsor's authenticator
+            if self.sponsor_authenticator.is_some() {
+                return Err(serde::ser::Error::custom(
+                    "The transaction without a sponsor has the sponsor's authenticator",
+                ));
+            }
+            tuple.serialize_element(&self.authenticator)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransaction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RoochTransactionVisitor;
+
+        impl<'de> Visitor<'de> for RoochTransactionVisitor {
+            type Value = RoochTransaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("the transaction data followed by the authenticators")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let data: RoochTransactionData = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                if !data.is_versioned() {
+                    let authenticator: Authenticator = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                    return Ok(RoochTransaction::new(data, authenticator));
+                }
+                let authenticators: RoochTransactionAuthenticators = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                Ok(RoochTransaction {
+                    data,
+                    authenticator: authenticators.authenticator,
+                    sponsor_authenticator: authenticators.sponsor_authenticator,
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(2, RoochTransactionVisitor)
+    }
 }
 
 impl RoochTransaction {
@@ -86,9 +411,22 @@ impl RoochTransaction {
         Self {
             data,
             authenticator,
+            sponsor_authenticator: None,
         }
     }
 
+    /// Co-sign the transaction by the sponsor designated in the transaction data
+    pub fn sponsor_sign(mut self, kp: &RoochKeyPair) -> Self {
+        let signature = Signature::new_hashed(self.data.hash().as_bytes(), kp);
+        self.sponsor_authenticator = Some(Authenticator::rooch(signature));
+        self
+    }
+
+    pub fn with_sponsor_authenticator(mut self, sponsor_authenticator: Authenticator) -> Self {
+        self.sponsor_authenticator = Some(sponsor_authenticator);
+        self
+    }
+
     pub fn new_genesis_tx(
         genesis_address: RoochAddress,
         chain_id: u64,
@@ -99,6 +437,7 @@ impl RoochTransaction {
             authenticator: Authenticator::rooch(Signature::Ed25519RoochSignature(
                 Ed25519RoochSignature::default(),
             )),
+            sponsor_authenticator: None,
         }
     }
 
@@ -122,6 +461,26 @@ impl RoochTransaction {
         &self.data.action
     }
 
+    pub fn dependency_hints(&self) -> Option<&TxDependencyHints> {
+        self.data.dependency_hints.as_ref()
+    }
+
+    pub fn sponsor(&self) -> Option<RoochAddress> {
+        self.data.sponsor
+    }
+
+    pub fn max_gas_price(&self) -> Option<u64> {
+        self.data.max_gas_price
+    }
+
+    pub fn gas_budget(&self) -> Option<GasBudget> {
+        self.data.gas_budget
+    }
+
+    pub fn sponsor_authenticator(&self) -> Option<&Authenticator> {
+        self.sponsor_authenticator.as_ref()
+    }
+
     //TODO use protest Arbitrary to generate mock data
     #[cfg(test)]
     pub fn mock() -> RoochTransaction {
@@ -155,14 +514,44 @@ impl From<RoochTransaction> for MoveOSTransaction {
     fn from(tx: RoochTransaction) -> Self {
         let tx_hash = tx.tx_hash();
         let tx_size = tx.tx_size();
-        let tx_ctx = TxContext::new(
+        let mut tx_ctx = TxContext::new(
             tx.data.sender.into(),
             tx.data.sequence_number,
             tx.data.max_gas_amount,
             tx_hash,
             tx_size,
         );
+        // The sponsor without an authenticator fails the validation of its signature
+        if let Some(sponsor) = tx.data.sponsor {
+            let (auth_validator_id, authenticator_payload) = tx
+                .sponsor_authenticator
+                .map(|authenticator| (authenticator.auth_validator_id, authenticator.payload))
+                .unwrap_or((BuiltinAuthValidator::Rooch.flag().into(), vec![]));
+            tx_ctx
+                .add(TxSponsor {
+                    sponsor: sponsor.into(),
+                    auth_validator_id,
+                    authenticator_payload,
+                })
+                .expect("adding TxSponsor to tx context should success");
+        }
+        if let Some(max_gas_price) = tx.data.max_gas_price {
+            tx_ctx
+                .add(MaxGasPrice::new(max_gas_price))
+                .expect("adding MaxGasPrice to tx context should success");
+        }
+        // The objects of the Rooch framework accounts are touched by every transaction, such as the gas payment
+        let sponsor = tx.data.sponsor;
+        let dependency_hints = tx.data.dependency_hints.map(|mut hints| {
+            hints
+                .accounts
+                .extend([ROOCH_FRAMEWORK_ADDRESS, BITCOIN_MOVE_ADDRESS]);
+            hints.accounts.extend(sponsor.map(AccountAddress::from));
+            hints
+        });
         MoveOSTransaction::new(tx_ctx, tx.data.action)
+            .with_dependency_hints(dependency_hints)
+            .with_gas_budget(tx.data.gas_budget)
     }
 }
 
@@ -219,3 +608,115 @@ impl AbstractTransaction for RoochTransaction {
         self.encode().len() as u64
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::RoochSupportedAddress;
+
+    #[test]
+    fn test_transaction_data_version() {
+        assert_eq!(
+            VERSIONED_TX_DATA_MARKER,
+            moveos_types::h256::sha3_256_of(b"ROOCH::VersionedRoochTransactionData").0
+        );
+
+        // The transaction data without the versioned fields is encoded in the first layout
+        let data = RoochTransaction::mock().data;
+        let bytes = data.encode();
+        let v0 = RoochTransactionDataV0 {
+            sender: data.sender,
+            sequence_number: data.sequence_number,
+            chain_id: data.chain_id,
+            max_gas_amount: data.max_gas_amount,
+            action: data.action.clone(),
+        };
+        assert_eq!(bytes, bcs::to_bytes(&v0).unwrap());
+        let decoded = bcs::from_bytes::<RoochTransactionData>(&bytes).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.hash(), data.hash());
+
+        // The versioned transaction data is prefixed by the marker and the version
+        let data = data.with_max_gas_price(100);
+        let bytes = data.encode();
+        assert_eq!(bytes[..32], VERSIONED_TX_DATA_MARKER);
+        assert_eq!(bytes[32], 0);
+        let decoded = bcs::from_bytes::<RoochTransactionData>(&bytes).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.hash(), data.hash());
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RoochTransactionData>(&json).unwrap(),
+            data
+        );
+
+        // The data without the versioned fields is rejected in the versioned layout
+        let mut bytes = VERSIONED_TX_DATA_MARKER.to_vec();
+        bytes.extend(
+            bcs::to_bytes(&VersionedRoochTransactionData::V1(RoochTransactionDataV1 {
+                sender: v0.sender,
+                sequence_number: v0.sequence_number,
+                chain_id: v0.chain_id,
+                max_gas_amount: v0.max_gas_amount,
+                action: v0.action,
+                dependency_hints: None,
+                sponsor: None,
+                max_gas_price: None,
+            }))
+            .unwrap(),
+        );
+        assert!(bcs::from_bytes::<RoochTransactionData>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_transaction_data_gas_budget() {
+        // The transaction data with the compute and storage budgets is encoded in the second versioned layout
+        let data = RoochTransaction::mock()
+            .data
+            .with_max_gas_price(100)
+            .with_gas_budget(GasBudget::new(1000, 2000));
+        let bytes = data.encode();
+        assert_eq!(bytes[..32], VERSIONED_TX_DATA_MARKER);
+        assert_eq!(bytes[32], 1);
+        let decoded = bcs::from_bytes::<RoochTransactionData>(&bytes).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.hash(), data.hash());
+
+        // The budgets are signed, so they are carried to the executed transaction
+        let authenticator = RoochTransaction::mock().authenticator;
+        let moveos_tx: MoveOSTransaction = RoochTransaction::new(data, authenticator).into();
+        assert_eq!(moveos_tx.gas_budget, Some(GasBudget::new(1000, 2000)));
+    }
+
+    #[test]
+    fn test_transaction_authenticators_version() {
+        // The transaction with the data of the first layout is followed by the sender's authenticator only
+        let tx = RoochTransaction::mock();
+        let bytes = tx.encode();
+        assert_eq!(
+            bytes,
+            bcs::to_bytes(&(&tx.data, &tx.authenticator)).unwrap()
+        );
+        assert_eq!(bcs::from_bytes::<RoochTransaction>(&bytes).unwrap(), tx);
+
+        // The sponsored transaction carries the sponsor's authenticator
+        let sponsor = RoochAddress::random();
+        let data = tx.data.clone().with_sponsor(sponsor);
+        let sponsored = RoochTransaction::new(data, tx.authenticator.clone())
+            .with_sponsor_authenticator(tx.authenticator.clone());
+        let bytes = sponsored.encode();
+        assert_eq!(
+            bcs::from_bytes::<RoochTransaction>(&bytes).unwrap(),
+            sponsored
+        );
+        let json = serde_json::to_string(&sponsored).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RoochTransaction>(&json).unwrap(),
+            sponsored
+        );
+
+        // The sponsor's authenticator can't be encoded without the sponsor
+        let tx = tx.with_sponsor_authenticator(sponsored.authenticator);
+        assert!(bcs::to_bytes(&tx).is_err());
+    }
+}
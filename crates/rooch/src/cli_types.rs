@@ -8,9 +8,11 @@ use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_types::authentication_key::AuthenticationKey;
 use rooch_types::error::{RoochError, RoochResult};
 use rooch_types::transaction::authenticator::Authenticator;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 pub use rooch_types::function_arg::{FunctionArg, FunctionArgType};
 
@@ -28,6 +30,52 @@ pub trait CommandAction<T: Serialize + Send>: Sized + Send {
     }
 }
 
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Switch the output of all the commands to JSON, it is set by the global `--json` flag.
+pub fn set_json_output(enabled: bool) {
+    JSON_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+pub fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Format the result of a command, it is serialized to JSON in the JSON output mode,
+/// otherwise `format_human` formats it as a table or text.
+pub fn format_output<T: Serialize>(result: T, format_human: impl FnOnce(T) -> String) -> String {
+    if json_output() {
+        serde_json::to_string_pretty(&result).expect("Failed to serialize response")
+    } else {
+        format_human(result)
+    }
+}
+
+/// The output of the commands which only report a status message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusOutput {
+    pub message: String,
+}
+
+pub fn format_status(message: impl Into<String>) -> String {
+    format_output(
+        StatusOutput {
+            message: message.into(),
+        },
+        |status| status.message,
+    )
+}
+
+/// Print a progress or hint message for humans,
+/// it goes to stderr in the JSON output mode so stdout only contains the JSON result.
+pub fn print_message(message: impl Display) {
+    if json_output() {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthenticatorOptions {
     pub auth_validator_id: u64,
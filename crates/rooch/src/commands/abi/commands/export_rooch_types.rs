@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::cli_types::{print_message, CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
 use move_core_types::{
@@ -13,13 +13,28 @@ use moveos_types::move_std::ascii::MoveAsciiString;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::transaction::MoveAction;
 use rooch_types::error::RoochResult;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{
+    RoochTransactionAuthenticators, RoochTransactionDataV0, VersionedRoochTransactionData,
+};
+use serde::Deserialize;
 use serde_reflection::{Samples, Tracer, TracerConfig};
 use std::fmt::Debug;
 use std::fs;
 use std::path::Path;
 use std::str::FromStr;
 
+/// The layout of the `RoochTransaction` for the clients. The transaction data is encoded in the first layout
+/// followed by the sender's authenticator, or in the `VersionedRoochTransactionData` prefixed by the versioned layout
+/// marker followed by the `RoochTransactionAuthenticators`.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+#[serde(rename = "RoochTransaction")]
+struct RoochTransactionLayout {
+    data: RoochTransactionDataV0,
+    authenticator: Authenticator,
+}
+
 #[derive(Debug, Parser)]
 pub struct ExportRoochTypesCommand {
     #[clap(flatten)]
@@ -87,7 +102,15 @@ fn export_rooch_types_yaml(file_path: &String) -> RoochResult<()> {
     // Define TypeTag and MoveAction
     tracer.trace_type::<TypeTag>(&samples).unwrap();
     tracer.trace_type::<MoveAction>(&samples).unwrap();
-    tracer.trace_type::<RoochTransaction>(&samples).unwrap();
+    tracer
+        .trace_type::<RoochTransactionLayout>(&samples)
+        .unwrap();
+    tracer
+        .trace_type::<VersionedRoochTransactionData>(&samples)
+        .unwrap();
+    tracer
+        .trace_type::<RoochTransactionAuthenticators>(&samples)
+        .unwrap();
 
     // More types
     let example_ascii_string: MoveAsciiString = MoveAsciiString::from_str("test").unwrap();
@@ -127,11 +150,11 @@ fn export_rooch_types_yaml(file_path: &String) -> RoochResult<()> {
 
             fs::write(path, replaced_yaml_string)?; // 创建文件并写入数据
 
-            println!("export rooch types to file: {file_path} ok!");
+            print_message(format!("export rooch types to file: {file_path} ok!"));
         }
         Err(e) => {
             let msg = e.explanation();
-            println!("export rooch_types.yaml error: {msg}");
+            print_message(format!("export rooch_types.yaml error: {msg}"));
         }
     }
 
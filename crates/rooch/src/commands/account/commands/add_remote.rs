@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::{Parser, ValueEnum};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::remote_signer::{RemoteSignerBackend, RemoteSignerConfig};
+use rooch_types::{
+    crypto::{EncodeDecodeBase64, PublicKey},
+    error::{RoochError, RoochResult},
+};
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum RemoteSignerBackendType {
+    Http,
+    AwsKms,
+    GcpKms,
+}
+
+/// Add an account which key is managed by an external signing service (KMS) to the Rooch keystore
+#[derive(Debug, Parser)]
+pub struct AddRemoteCommand {
+    /// The base64 encoded public key of the account, with the scheme flag byte
+    #[clap(long)]
+    public_key: String,
+    #[clap(long, value_enum)]
+    backend: RemoteSignerBackendType,
+    /// The endpoint of the signing service
+    #[clap(long)]
+    endpoint: String,
+    /// The key id of the signing service, or the key version resource name for the GCP KMS
+    #[clap(long)]
+    key_id: String,
+    /// The name of the environment variable which holds the bearer token of the signing service
+    #[clap(long)]
+    auth_token_env: Option<String>,
+    /// The timeout in seconds of the signing request
+    #[clap(long)]
+    timeout_seconds: Option<u64>,
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for AddRemoteCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let mut context = self.context_options.build()?;
+        let public_key = PublicKey::decode_base64(&self.public_key)
+            .map_err(|e| RoochError::CommandArgumentError(format!("Invalid public key: {}", e)))?;
+        let backend = match self.backend {
+            RemoteSignerBackendType::Http => RemoteSignerBackend::Http {
+                endpoint: self.endpoint,
+                key_id: self.key_id,
+            },
+            RemoteSignerBackendType::AwsKms => RemoteSignerBackend::AwsKms {
+                endpoint: self.endpoint,
+                key_id: self.key_id,
+            },
+            RemoteSignerBackendType::GcpKms => RemoteSignerBackend::GcpKms {
+                endpoint: self.endpoint,
+                key_name: self.key_id,
+            },
+        };
+        let mut config = RemoteSignerConfig::new(public_key, backend);
+        config.auth_token_env = self.auth_token_env;
+        config.timeout_seconds = self.timeout_seconds;
+
+        let address = context.keystore.add_remote_signer(config)?;
+        Ok(format!("Added the remote signer account: {}", address))
+    }
+}
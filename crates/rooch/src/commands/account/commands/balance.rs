@@ -10,6 +10,7 @@ use clap::Parser;
 use move_command_line_common::address::ParsedAddress;
 use move_command_line_common::types::ParsedStructType;
 use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
+use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
 use rooch_types::error::RoochResult;
 
 /// Show account balance, only the accounts managed by the current node are supported
@@ -29,10 +30,10 @@ pub struct BalanceCommand {
 }
 
 #[async_trait]
-impl CommandAction<()> for BalanceCommand {
-    async fn execute(self) -> RoochResult<()> {
+impl CommandAction<Vec<BalanceInfoView>> for BalanceCommand {
+    async fn execute(self) -> RoochResult<Vec<BalanceInfoView>> {
         let context = self.context_options.build()?;
-        let mapping = context.address_mapping();
+        let mapping = context.address_mapping_with_name_service().await?;
         let address_addr = self.address.into_account_address(&mapping)?;
         let coin_type = self
             .coin_type
@@ -58,22 +59,26 @@ impl CommandAction<()> for BalanceCommand {
             }
         };
 
-        println!(
+        Ok(balances)
+    }
+}
+
+pub fn format_balances(balances: Vec<BalanceInfoView>) -> String {
+    let mut lines = vec![
+        format!(
             "{0: ^102} | {1: ^16} | {2: ^6} |  {3: ^32} ",
             "Coin Type", "Symbol", "Decimals", "Balance"
-        );
-        println!("{}", ["-"; 68].join(""));
-
-        for balance_info in balances {
-            println!(
-                "{0: ^102} | {1: ^16} | {2: ^6} | {3: ^32} ",
-                balance_info.coin_info.coin_type,
-                balance_info.coin_info.symbol,
-                balance_info.coin_info.decimals,
-                balance_info.balance,
-            );
-        }
-
-        Ok(())
+        ),
+        ["-"; 68].join(""),
+    ];
+    for balance_info in balances {
+        lines.push(format!(
+            "{0: ^102} | {1: ^16} | {2: ^6} | {3: ^32} ",
+            balance_info.coin_info.coin_type,
+            balance_info.coin_info.symbol,
+            balance_info.coin_info.decimals,
+            balance_info.balance,
+        ));
     }
+    lines.join("\n")
 }
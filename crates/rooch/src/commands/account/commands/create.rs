@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::WalletContextOptions;
+use crate::cli_types::{print_message, WalletContextOptions};
 use clap::Parser;
 use move_core_types::account_address::AccountAddress;
 use rooch_key::key_derive::verify_password;
@@ -46,14 +46,14 @@ impl CreateCommand {
         };
 
         let address = AccountAddress::from(result.address).to_hex_literal();
-        println!(
+        print_message(format!(
             "Generated new keypair for address with key pair type [{}]",
             result.address
-        );
-        println!(
+        ));
+        print_message(format!(
             "Secret Recovery Phrase : [{}]",
             result.key_pair_data.mnemonic_phrase
-        );
+        ));
 
         Ok(address)
     }
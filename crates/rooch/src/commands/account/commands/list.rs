@@ -5,8 +5,9 @@ use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
 use rooch_key::keystore::account_keystore::AccountKeystore;
-use rooch_types::{crypto::EncodeDecodeBase64, error::RoochResult};
+use rooch_types::{address::RoochAddress, crypto::EncodeDecodeBase64, error::RoochResult};
 use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 /// List all keys by its Rooch address, Base64 encoded public key
@@ -16,9 +17,19 @@ pub struct ListCommand {
     pub context_options: WalletContextOptions,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountView {
+    pub address: RoochAddress,
+    pub multichain_address: Option<String>,
+    /// Base64 encoded public key
+    pub public_key: Option<String>,
+    pub has_session_key: bool,
+    pub active: bool,
+}
+
 #[async_trait]
-impl CommandAction<()> for ListCommand {
-    async fn execute(self) -> RoochResult<()> {
+impl CommandAction<Vec<AccountView>> for ListCommand {
+    async fn execute(self) -> RoochResult<Vec<AccountView>> {
         let context = self.context_options.build()?;
         let active_address = context.client_config.active_address;
 
@@ -30,40 +41,46 @@ impl CommandAction<()> for ListCommand {
             )
         };
 
-        println!(
+        Ok(context
+            .keystore
+            .get_accounts(password)?
+            .into_iter()
+            .map(|account| AccountView {
+                address: account.address,
+                multichain_address: account
+                    .multichain_address
+                    .map(|multichain_address| multichain_address.to_string()),
+                public_key: account
+                    .public_key
+                    .map(|public_key| public_key.encode_base64()),
+                has_session_key: account.has_session_key,
+                active: active_address == Some(account.address),
+            })
+            .collect())
+    }
+}
+
+pub fn format_accounts(accounts: Vec<AccountView>) -> String {
+    let mut lines = vec![
+        format!(
             "{:^66} | {:^66} | {:^48} | {:^16} | {:^12}",
             "Rooch Address (Ed25519)",
             "Multichain Address",
             "Public Key (Base64)",
             "Has session key",
             "Active Address"
-        );
-        println!("{}", ["-"; 153].join(""));
-
-        for account in context.keystore.get_accounts(password)? {
-            let address = account.address;
-            let active = if active_address == Some(address) {
-                "True"
-            } else {
-                ""
-            };
-
-            println!(
-                "{:^66} | {:^66} | {:^48} | {:^16} | {:^12}",
-                address,
-                account
-                    .multichain_address
-                    .map(|multichain_address| multichain_address.to_string())
-                    .unwrap_or_default(),
-                account
-                    .public_key
-                    .map(|public_key| public_key.encode_base64())
-                    .unwrap_or_default(),
-                account.has_session_key.to_string(),
-                active
-            );
-        }
-
-        Ok(())
+        ),
+        ["-"; 153].join(""),
+    ];
+    for account in accounts {
+        lines.push(format!(
+            "{:^66} | {:^66} | {:^48} | {:^16} | {:^12}",
+            account.address,
+            account.multichain_address.unwrap_or_default(),
+            account.public_key.unwrap_or_default(),
+            account.has_session_key.to_string(),
+            if account.active { "True" } else { "" }
+        ));
     }
+    lines.join("\n")
 }
@@ -1,8 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod add_remote;
 pub mod balance;
 pub mod create;
 pub mod list;
 pub mod nullify;
 pub mod switch;
+pub mod transfer;
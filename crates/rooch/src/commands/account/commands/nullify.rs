@@ -15,7 +15,7 @@ use rooch_types::{
     framework::native_validator::NativeValidatorModule,
 };
 
-use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::cli_types::{print_message, CommandAction, WalletContextOptions};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use std::str::FromStr;
 
@@ -38,10 +38,7 @@ impl CommandAction<ExecuteTransactionResponseView> for NullifyCommand {
             RoochError::CommandArgumentError(format!("Invalid Rooch address String: {}", e))
         })?;
 
-        println!(
-            "{}",
-            AccountAddress::from(existing_address).to_hex_literal()
-        );
+        print_message(AccountAddress::from(existing_address).to_hex_literal());
 
         // Create MoveAction from validator
         let action = NativeValidatorModule::remove_authentication_key_action();
@@ -75,7 +72,10 @@ impl CommandAction<ExecuteTransactionResponseView> for NullifyCommand {
             .nullify_address(&existing_address)
             .map_err(|e| RoochError::NullifyAccountError(e.to_string()))?;
 
-        println!("Dropped an existing address {:?}", existing_address,);
+        print_message(format!(
+            "Dropped an existing address {:?}",
+            existing_address
+        ));
 
         // Return transaction result
         Ok(result)
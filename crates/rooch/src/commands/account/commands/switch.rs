@@ -22,8 +22,8 @@ pub struct SwitchCommand {
 }
 
 #[async_trait]
-impl CommandAction<()> for SwitchCommand {
-    async fn execute(self) -> RoochResult<()> {
+impl CommandAction<String> for SwitchCommand {
+    async fn execute(self) -> RoochResult<String> {
         let mut context = self.context_options.build()?;
         let rooch_address = RoochAddress::from_str(self.address.as_str()).map_err(|e| {
             RoochError::CommandArgumentError(format!("Invalid Rooch address String: {}", e))
@@ -39,11 +39,9 @@ impl CommandAction<()> for SwitchCommand {
         context.client_config.active_address = Some(rooch_address);
         context.client_config.save()?;
 
-        println!(
+        Ok(format!(
             "The active account was successfully switched to `{}`",
             self.address
-        );
-
-        Ok(())
+        ))
     }
 }
@@ -0,0 +1,123 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{print_message, CommandAction, TransactionOptions, WalletContextOptions};
+use crate::utils::{confirm, format_coin_amount, parse_coin_amount};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::address::RoochAddress;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::transfer::{self, TransferModule};
+use rpassword::prompt_password;
+
+/// Transfer coins of any registered coin type to an account,
+/// the amount is in the coin unit, the decimals of the coin are resolved from the coin registry
+#[derive(Debug, Parser)]
+pub struct TransferCommand {
+    /// The recipient account's address
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    to: ParsedAddress,
+
+    /// The amount to transfer in the coin unit, such as `1.5`
+    #[clap(long)]
+    amount: String,
+
+    /// Struct name as `<ADDRESS>::<MODULE_ID>::<STRUCT_NAME><TypeParam>`
+    /// Example: `0x3::gas_coin::GasCoin`, `0x123::Coin::Box<0x123::coin_box::FCoin>`
+    #[clap(long, value_parser=ParsedStructType::parse, default_value = "0x3::gas_coin::GasCoin")]
+    coin_type: ParsedStructType,
+
+    /// Submit the transaction without the confirmation
+    #[clap(long, short = 'y')]
+    yes: bool,
+
+    #[clap(flatten)]
+    tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for TransferCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping_with_name_service().await?;
+        let sender_address = context
+            .resolve_address_with_name_service(self.tx_options.sender)
+            .await?;
+        let sender: RoochAddress = sender_address.into();
+        let to = context.resolve_address_with_name_service(self.to).await?;
+        let coin_type = self.coin_type.into_struct_tag(&mapping)?;
+
+        // The coin info is registered in the coin registry, the balance query fails if the coin type is not registered
+        let client = context.get_client().await?;
+        let balance_info = client
+            .rooch
+            .get_balance(sender_address.into(), coin_type.clone().into())
+            .await
+            .map_err(|e| {
+                RoochError::AccountBalanceError(format!(
+                    "Failed to get the coin info of {}: {}",
+                    coin_type, e
+                ))
+            })?;
+        let coin_info = balance_info.coin_info;
+        let amount = parse_coin_amount(&self.amount, coin_info.decimals)
+            .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?;
+        if amount > balance_info.balance.0 {
+            return Err(RoochError::AccountBalanceError(format!(
+                "The balance {} {} of {} is insufficient to transfer {} {}",
+                format_coin_amount(balance_info.balance.0, coin_info.decimals),
+                coin_info.symbol,
+                sender,
+                format_coin_amount(amount, coin_info.decimals),
+                coin_info.symbol
+            )));
+        }
+
+        let action = TransferModule::create_transfer_coin_action(coin_type.clone(), to, amount);
+        print_message(format!(
+            "Transfer {} {} ({} in the smallest unit, {} decimals) of {} from {} to {}\nCall: {}::{}::{}<{}>",
+            format_coin_amount(amount, coin_info.decimals),
+            coin_info.symbol,
+            amount,
+            coin_info.decimals,
+            coin_type,
+            sender,
+            to.to_hex_literal(),
+            ROOCH_FRAMEWORK_ADDRESS.to_hex_literal(),
+            transfer::MODULE_NAME,
+            TransferModule::TRANSFER_COIN_FUNCTION_NAME,
+            coin_type
+        ));
+        if !self.yes && !confirm("Do you want to submit the transaction?")? {
+            return Err(RoochError::AbortedError);
+        }
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password = prompt_password("Enter the password to transfer:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        context.assert_execute_success(result)
+    }
+}
@@ -1,11 +1,16 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::CommandAction;
-use crate::commands::account::commands::balance::BalanceCommand;
+use crate::cli_types::{format_output, format_status, CommandAction};
 use async_trait::async_trait;
 use commands::{
-    create::CreateCommand, list::ListCommand, nullify::NullifyCommand, switch::SwitchCommand,
+    add_remote::AddRemoteCommand,
+    balance::{self, BalanceCommand},
+    create::CreateCommand,
+    list::{self, ListCommand},
+    nullify::NullifyCommand,
+    switch::SwitchCommand,
+    transfer::TransferCommand,
 };
 use rooch_types::error::{RoochError, RoochResult};
 use std::path::PathBuf;
@@ -29,10 +34,21 @@ impl CommandAction<String> for Account {
             AccountCommand::Create(create) => create.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
-            AccountCommand::List(list) => list.execute().await.map(|_| "".to_owned()),
-            AccountCommand::Switch(switch) => switch.execute().await.map(|_| "".to_owned()),
-            AccountCommand::Nullify(nullify) => nullify.execute().await.map(|_| "".to_owned()),
-            AccountCommand::Balance(balance) => balance.execute().await.map(|_| "".to_owned()),
+            AccountCommand::List(list) => list
+                .execute()
+                .await
+                .map(|accounts| format_output(accounts, list::format_accounts)),
+            AccountCommand::Switch(switch) => switch.execute().await.map(format_status),
+            AccountCommand::Nullify(nullify) => nullify
+                .execute()
+                .await
+                .map(|resp| format_output(resp, |_| "".to_owned())),
+            AccountCommand::Balance(balance) => balance
+                .execute()
+                .await
+                .map(|balances| format_output(balances, balance::format_balances)),
+            AccountCommand::AddRemote(add_remote) => add_remote.execute().await.map(format_status),
+            AccountCommand::Transfer(transfer) => transfer.execute_serialized().await,
         }
         .map_err(RoochError::from)
     }
@@ -46,4 +62,6 @@ pub enum AccountCommand {
     Switch(SwitchCommand),
     Nullify(NullifyCommand),
     Balance(BalanceCommand),
+    AddRemote(AddRemoteCommand),
+    Transfer(TransferCommand),
 }
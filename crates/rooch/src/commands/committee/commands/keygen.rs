@@ -0,0 +1,69 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::committee::{generate_committee_keys, CommitteeKeyFile};
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Generate the committee key and split it to the key shares of the operators.
+/// Every operator gets a key file with its own share, the key files are passed to the nodes by `--committee-key-file`,
+/// and the sequencer requests the partial signatures of the other operators by `--committee-operator <index>@<url>`.
+/// The key is generated by this process as a trusted dealer, so the key files should be distributed securely and
+/// removed from the dealer afterwards.
+#[derive(Debug, Parser)]
+pub struct KeygenCommand {
+    /// The number of the operators signing a batch
+    #[clap(long)]
+    pub threshold: u16,
+
+    /// The number of the operators in the committee
+    #[clap(long)]
+    pub committee_size: u16,
+
+    /// The directory to write the key files to
+    #[clap(long, short = 'o')]
+    pub output_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeygenOutput {
+    /// The committee public key verifying the threshold signatures
+    pub public_key_file: PathBuf,
+    pub key_files: Vec<PathBuf>,
+}
+
+#[async_trait]
+impl CommandAction<KeygenOutput> for KeygenCommand {
+    async fn execute(self) -> RoochResult<KeygenOutput> {
+        let (public_key, shares) =
+            generate_committee_keys(self.threshold, self.committee_size, &mut rand::thread_rng())
+                .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?;
+        std::fs::create_dir_all(&self.output_dir)?;
+
+        let public_key_file = self.output_dir.join("committee_public_key.json");
+        let content = serde_json::to_vec_pretty(&public_key)
+            .expect("Serialize the committee public key should success");
+        std::fs::write(&public_key_file, content)?;
+
+        let mut key_files = Vec::with_capacity(shares.len());
+        for share in shares {
+            let key_file = self
+                .output_dir
+                .join(format!("committee_operator_{}.key.json", share.index));
+            CommitteeKeyFile {
+                public_key: public_key.clone(),
+                shares: vec![share],
+            }
+            .save(&key_file)?;
+            key_files.push(key_file);
+        }
+        Ok(KeygenOutput {
+            public_key_file,
+            key_files,
+        })
+    }
+}
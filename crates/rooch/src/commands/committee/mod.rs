@@ -0,0 +1,31 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::keygen::KeygenCommand;
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Tool for setting up the sequencer committee which threshold-signs the batches
+#[derive(Parser)]
+pub struct Committee {
+    #[clap(subcommand)]
+    cmd: CommitteeCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Committee {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            CommitteeCommand::Keygen(keygen) => keygen.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+pub enum CommitteeCommand {
+    Keygen(KeygenCommand),
+}
@@ -21,7 +21,7 @@ pub struct AddCommand {
 }
 
 impl AddCommand {
-    pub async fn execute(self) -> RoochResult<()> {
+    pub async fn execute(self) -> RoochResult<String> {
         let mut context = self.context_options.build()?;
         let AddCommand { alias, rpc, ws, .. } = self;
         let env = Env {
@@ -35,8 +35,6 @@ impl AddCommand {
         context.client_config.add_env(env);
         context.client_config.save()?;
 
-        println!("Environment `{} was successfully added", alias);
-
-        Ok(())
+        Ok(format!("Environment `{}` was successfully added", alias))
     }
 }
@@ -3,6 +3,7 @@
 
 use clap::Parser;
 use rooch_types::error::RoochResult;
+use serde::{Deserialize, Serialize};
 
 use crate::cli_types::WalletContextOptions;
 
@@ -12,29 +13,48 @@ pub struct ListCommand {
     pub context_options: WalletContextOptions,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvView {
+    pub alias: String,
+    pub rpc: String,
+    pub ws: Option<String>,
+    pub active: bool,
+}
+
 impl ListCommand {
-    pub async fn execute(self) -> RoochResult<()> {
+    pub async fn execute(self) -> RoochResult<Vec<EnvView>> {
         let context = self.context_options.build()?;
+        let active_env = context.client_config.active_env.clone();
+
+        Ok(context
+            .client_config
+            .envs
+            .into_iter()
+            .map(|env| EnvView {
+                active: active_env.as_ref() == Some(&env.alias),
+                alias: env.alias,
+                rpc: env.rpc,
+                ws: env.ws,
+            })
+            .collect())
+    }
+}
 
-        println!(
+pub fn format_envs(envs: Vec<EnvView>) -> String {
+    let mut lines = vec![
+        format!(
             "{:^24} | {:^48} | {:^48} | {:^12}",
             "Env Alias", "RPC URL", "Websocket URL", "Active Env"
-        );
-        println!("{}", ["-"; 153].join(""));
-
-        for env in context.client_config.envs.iter() {
-            let mut active = "";
-            if context.client_config.active_env == Some(env.alias.clone()) {
-                active = "True"
-            }
-
-            let ws = env.ws.clone().unwrap_or("Null".to_owned());
-            println!(
-                "{:^24} | {:^48} | {:^48} | {:^12}",
-                env.alias, env.rpc, ws, active
-            )
-        }
-
-        Ok(())
+        ),
+        ["-"; 153].join(""),
+    ];
+    for env in envs {
+        let active = if env.active { "True" } else { "" };
+        let ws = env.ws.unwrap_or("Null".to_owned());
+        lines.push(format!(
+            "{:^24} | {:^48} | {:^48} | {:^12}",
+            env.alias, env.rpc, ws, active
+        ));
     }
+    lines.join("\n")
 }
@@ -15,7 +15,7 @@ pub struct RemoveCommand {
 }
 
 impl RemoveCommand {
-    pub async fn execute(self) -> RoochResult<()> {
+    pub async fn execute(self) -> RoochResult<String> {
         let mut context = self.context_options.build()?;
         if let Some(active_env) = &context.client_config.active_env {
             if active_env == &self.env {
@@ -31,8 +31,9 @@ impl RemoveCommand {
             .retain(|env| env.alias != self.env);
         context.client_config.save()?;
 
-        println!("Environment `{}` was successfully removed", self.env);
-
-        Ok(())
+        Ok(format!(
+            "Environment `{}` was successfully removed",
+            self.env
+        ))
     }
 }
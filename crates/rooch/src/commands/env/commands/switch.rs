@@ -15,7 +15,7 @@ pub struct SwitchCommand {
 }
 
 impl SwitchCommand {
-    pub async fn execute(self) -> RoochResult<()> {
+    pub async fn execute(self) -> RoochResult<String> {
         let mut context = self.context_options.build()?;
         let env = Some(self.alias.clone());
 
@@ -29,11 +29,9 @@ impl SwitchCommand {
         context.client_config.active_env = env;
         context.client_config.save()?;
 
-        println!(
+        Ok(format!(
             "The active environment was successfully switched to `{}`",
             self.alias
-        );
-
-        Ok(())
+        ))
     }
 }
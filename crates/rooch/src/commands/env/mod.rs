@@ -1,13 +1,16 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::CommandAction;
+use crate::cli_types::{format_output, format_status, CommandAction};
 use async_trait::async_trait;
 use rooch_types::error::{RoochError, RoochResult};
 use std::path::PathBuf;
 
 use self::commands::{
-    add::AddCommand, list::ListCommand, remove::RemoveCommand, switch::SwitchCommand,
+    add::AddCommand,
+    list::{self, ListCommand},
+    remove::RemoveCommand,
+    switch::SwitchCommand,
 };
 
 pub mod commands;
@@ -26,10 +29,13 @@ pub struct Env {
 impl CommandAction<String> for Env {
     async fn execute(self) -> RoochResult<String> {
         match self.cmd {
-            EnvCommand::Add(add) => add.execute().await.map(|_| "".to_owned()),
-            EnvCommand::List(list) => list.execute().await.map(|_| "".to_owned()),
-            EnvCommand::Switch(switch) => switch.execute().await.map(|_| "".to_owned()),
-            EnvCommand::Remove(remove) => remove.execute().await.map(|_| "".to_owned()),
+            EnvCommand::Add(add) => add.execute().await.map(format_status),
+            EnvCommand::List(list) => list
+                .execute()
+                .await
+                .map(|envs| format_output(envs, list::format_envs)),
+            EnvCommand::Switch(switch) => switch.execute().await.map(format_status),
+            EnvCommand::Remove(remove) => remove.execute().await.map(format_status),
         }
         .map_err(RoochError::from)
     }
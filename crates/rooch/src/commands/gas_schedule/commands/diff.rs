@@ -0,0 +1,246 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::gas_schedule::local_gas_schedule;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_framework::natives::gas_parameter::gas_schedule::load_genesis_cost_table;
+use rooch_genesis::RoochGenesis;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::gas_schedule::GasScheduleModule;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Compare the gas schedule of this binary, or an exported gas schedule, with the gas schedule metering the network.
+/// The network only stores the entries overriding the genesis cost table and the initial native gas parameters,
+/// so they are applied to the genesis cost table of the network to compare the full schedules.
+#[derive(Debug, Parser)]
+pub struct DiffCommand {
+    /// The gas schedule file exported by `rooch gas-schedule export`, the gas schedule of this binary if it is not set
+    #[clap(long)]
+    pub schedule: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasEntryChange {
+    pub network: u64,
+    pub local: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasScheduleDiff {
+    /// The version of the gas schedule on the network
+    pub schedule_version: u64,
+    /// The entries not metering the network
+    pub added: BTreeMap<String, u64>,
+    /// The entries metering the network but not in the local gas schedule
+    pub removed: BTreeMap<String, u64>,
+    pub changed: BTreeMap<String, GasEntryChange>,
+}
+
+impl GasScheduleDiff {
+    pub fn new(
+        schedule_version: u64,
+        network: &BTreeMap<String, u64>,
+        local: &BTreeMap<String, u64>,
+    ) -> Self {
+        let mut diff = Self {
+            schedule_version,
+            ..Default::default()
+        };
+        for (key, local_val) in local {
+            match network.get(key) {
+                None => {
+                    diff.added.insert(key.clone(), *local_val);
+                }
+                Some(network_val) if network_val != local_val => {
+                    diff.changed.insert(
+                        key.clone(),
+                        GasEntryChange {
+                            network: *network_val,
+                            local: *local_val,
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, network_val) in network {
+            if !local.contains_key(key) {
+                diff.removed.insert(key.clone(), *network_val);
+            }
+        }
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for GasScheduleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Compared with the gas schedule version {} of the network",
+            self.schedule_version
+        )?;
+        if self.is_empty() {
+            return write!(f, "No difference");
+        }
+        for (key, val) in &self.added {
+            writeln!(f, "+ {} = {}", key, val)?;
+        }
+        for (key, val) in &self.removed {
+            writeln!(f, "- {} = {}", key, val)?;
+        }
+        for (key, change) in &self.changed {
+            writeln!(f, "~ {} = {} -> {}", key, change.network, change.local)?;
+        }
+        write!(
+            f,
+            "{} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+#[async_trait]
+impl CommandAction<GasScheduleDiff> for DiffCommand {
+    async fn execute(self) -> RoochResult<GasScheduleDiff> {
+        let local = match &self.schedule {
+            Some(path) => {
+                let content = std::fs::read(path).map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Read the gas schedule {:?} failed: {}",
+                        path, e
+                    ))
+                })?;
+                serde_json::from_slice(&content).map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Invalid gas schedule {:?}: {}",
+                        path, e
+                    ))
+                })?
+            }
+            None => local_gas_schedule()?,
+        };
+        let context = self.context_options.build()?;
+        let client = context.get_client().await?;
+        let gas_schedule_module = client.as_module_binding::<GasScheduleModule>();
+        let current_gas_schedule = gas_schedule_module.current_gas_schedule().map_err(|e| {
+            RoochError::ViewFunctionError(format!(
+                "Failed to get the gas schedule of the network: {}",
+                e
+            ))
+        })?;
+        let genesis_gas_schedule = gas_schedule_module.genesis_gas_schedule().map_err(|e| {
+            RoochError::ViewFunctionError(format!(
+                "Failed to get the genesis gas schedule of the network: {}",
+                e
+            ))
+        })?;
+        let genesis_cost_table = load_genesis_cost_table(&genesis_gas_schedule.entries)?;
+        let network =
+            RoochGenesis::effective_gas_schedule(&genesis_cost_table, &current_gas_schedule)?;
+        Ok(GasScheduleDiff::new(
+            current_gas_schedule.schedule_version,
+            &network,
+            &local,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos::gas::table::{initial_cost_schedule, CostTable};
+    use rooch_types::framework::gas_schedule::{GasEntry, GasScheduleConfig};
+
+    #[test]
+    fn test_gas_schedule_diff() {
+        let network = BTreeMap::from([
+            ("instruction_tiers.0".to_owned(), 1),
+            (
+                "extra_gas_parameter.storage_fee_per_event_byte".to_owned(),
+                2,
+            ),
+            ("move_stdlib.bcs.to_bytes.per_byte".to_owned(), 3),
+        ]);
+        let local = BTreeMap::from([
+            ("instruction_tiers.0".to_owned(), 1),
+            (
+                "extra_gas_parameter.storage_fee_per_event_byte".to_owned(),
+                4,
+            ),
+            ("rooch_framework.hash.sha3_256.base".to_owned(), 5),
+        ]);
+        let diff = GasScheduleDiff::new(1, &network, &local);
+        assert_eq!(
+            diff.added,
+            BTreeMap::from([("rooch_framework.hash.sha3_256.base".to_owned(), 5)])
+        );
+        assert_eq!(
+            diff.removed,
+            BTreeMap::from([("move_stdlib.bcs.to_bytes.per_byte".to_owned(), 3)])
+        );
+        assert_eq!(
+            diff.changed,
+            BTreeMap::from([(
+                "extra_gas_parameter.storage_fee_per_event_byte".to_owned(),
+                GasEntryChange {
+                    network: 2,
+                    local: 4
+                }
+            )])
+        );
+        assert!(GasScheduleDiff::new(1, &local, &local).is_empty());
+    }
+
+    #[test]
+    fn test_effective_gas_schedule_diff() {
+        let genesis_cost_table = initial_cost_schedule();
+        // The builtin gas schedule stores no entries, its cost table is the genesis cost table
+        let builtin = RoochGenesis::effective_gas_schedule(
+            &genesis_cost_table,
+            &GasScheduleConfig::builtin(),
+        )
+        .unwrap();
+        let local = local_gas_schedule().unwrap();
+        let diff = GasScheduleDiff::new(0, &builtin, &local);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert!(diff.changed.keys().all(|key| !CostTable::is_entry_key(key)));
+
+        // An on-chain schedule overriding one entry differs from the local schedule by that entry only
+        let key = "instruction_tiers.0".to_owned();
+        let val = local[&key] + 1;
+        let gas_schedule = GasScheduleConfig {
+            schedule_version: 1,
+            entries: vec![GasEntry::new(key.clone(), val)],
+        };
+        let network =
+            RoochGenesis::effective_gas_schedule(&genesis_cost_table, &gas_schedule).unwrap();
+        let diff = GasScheduleDiff::new(1, &network, &local);
+        assert_eq!(
+            diff.changed,
+            BTreeMap::from([(
+                key,
+                GasEntryChange {
+                    network: val,
+                    local: val - 1
+                }
+            )])
+        );
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+    }
+}
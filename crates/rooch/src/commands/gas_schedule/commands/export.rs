@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::gas_schedule::local_gas_schedule;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::error::{RoochError, RoochResult};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Export the gas schedule of this binary as the JSON map of the on-chain gas schedule entries
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// Write the gas schedule to the file instead of the stdout
+    #[clap(long, short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
+#[async_trait]
+impl CommandAction<Option<BTreeMap<String, u64>>> for ExportCommand {
+    async fn execute(self) -> RoochResult<Option<BTreeMap<String, u64>>> {
+        let gas_schedule = local_gas_schedule()?;
+        match self.output {
+            Some(output) => {
+                let content = serde_json::to_string_pretty(&gas_schedule)
+                    .expect("Serialize the gas schedule should success");
+                std::fs::write(&output, content + "\n").map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Write the gas schedule to {:?} failed: {}",
+                        output, e
+                    ))
+                })?;
+                Ok(None)
+            }
+            None => Ok(Some(gas_schedule)),
+        }
+    }
+}
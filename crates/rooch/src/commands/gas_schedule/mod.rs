@@ -0,0 +1,48 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{format_output, CommandAction};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::diff::DiffCommand;
+use commands::export::ExportCommand;
+use moveos::gas::table::initial_cost_schedule;
+use rooch_genesis::RoochGenesis;
+use rooch_types::error::RoochResult;
+use std::collections::BTreeMap;
+
+pub mod commands;
+
+/// Tool for exporting the gas schedule and comparing it with the gas schedule of a network
+#[derive(Parser)]
+pub struct GasSchedule {
+    #[clap(subcommand)]
+    cmd: GasScheduleCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for GasSchedule {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            GasScheduleCommand::Export(export) => export.execute_serialized().await,
+            GasScheduleCommand::Diff(diff) => diff
+                .execute()
+                .await
+                .map(|diff| format_output(diff, |diff| diff.to_string())),
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+pub enum GasScheduleCommand {
+    Export(ExportCommand),
+    Diff(DiffCommand),
+}
+
+/// The gas schedule of this binary, the entries of the initial cost table and the native gas parameters
+pub fn local_gas_schedule() -> Result<BTreeMap<String, u64>> {
+    let mut entries = initial_cost_schedule().to_entries();
+    entries.extend(RoochGenesis::native_gas_schedule()?);
+    Ok(entries)
+}
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::cli_types::{print_message, CommandAction, WalletContextOptions};
 use crate::utils::read_line;
 use async_trait::async_trait;
 use clap::Parser;
@@ -75,15 +75,15 @@ impl CommandAction<()> for Init {
                 .persisted(server_config_path.as_path())
                 .save()?;
 
-            println!(
+            print_message(format!(
                 "Rooch server config file generated at {}",
                 server_config_path.display()
-            );
+            ));
         } else {
-            println!(
+            print_message(format!(
                 "Rooch server config file already exists at {}",
                 server_config_path.display()
-            );
+            ));
         }
 
         // Prompt user for connect to devnet fullnode if config does not exist.
@@ -104,10 +104,10 @@ impl CommandAction<()> for Init {
                 }
 
                 None => {
-                    println!(
+                    print_message(format!(
                         "Creating client config file [{:?}] with rooch native validator.",
                         client_config_path
-                    );
+                    ));
                     let url = if self.server_url.is_none() {
                         String::new()
                     } else {
@@ -161,11 +161,14 @@ impl CommandAction<()> for Init {
                     None,
                     password.clone(),
                 )?;
-                println!("Generated new keypair for address [{}]", result.address);
-                println!(
+                print_message(format!(
+                    "Generated new keypair for address [{}]",
+                    result.address
+                ));
+                print_message(format!(
                     "Secret Recovery Phrase : [{}]",
                     result.key_pair_data.mnemonic_phrase
-                );
+                ));
                 let dev_env = Env::new_dev_env();
                 let active_env_alias = dev_env.alias.clone();
 
@@ -189,15 +192,15 @@ impl CommandAction<()> for Init {
                     .save()?;
             }
 
-            println!(
+            print_message(format!(
                 "Rooch client config file generated at {}",
                 client_config_path.display()
-            );
+            ));
         } else {
-            println!(
+            print_message(format!(
                 "Rooch client config file already exists at {}",
                 client_config_path.display()
-            );
+            ));
         }
 
         Ok(())
@@ -3,8 +3,10 @@
 
 pub mod abi;
 pub mod account;
+pub mod committee;
 pub mod env;
 pub mod event;
+pub mod gas_schedule;
 pub mod init;
 pub mod move_cli;
 pub mod object;
@@ -13,4 +15,6 @@ pub mod rpc;
 pub mod server;
 pub mod session_key;
 pub mod state;
+pub mod statedb;
 pub mod transaction;
+pub mod update;
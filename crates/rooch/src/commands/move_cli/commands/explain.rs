@@ -34,7 +34,7 @@ pub struct Explain {
 }
 
 impl Explain {
-    pub async fn execute(self) -> anyhow::Result<()> {
+    pub async fn execute(self) -> anyhow::Result<MoveAbortExplain> {
         let context = self.context_options.build()?;
         let address_mapping = context.address_mapping();
         let module_id = self.location.into_module_id(&address_mapping)?;
@@ -49,17 +49,13 @@ impl Explain {
         };
 
         match error_description_bytes {
-            Some(bytes) => {
-                let explain_result =
-                    explain_move_abort(AbortLocation::Module(module_id), self.abort_code, bytes);
-                println!("{}", explain_result)
-            }
-            None => {
-                return Err(anyhow::Error::msg("Error map data not found."));
-            }
+            Some(bytes) => Ok(explain_move_abort(
+                AbortLocation::Module(module_id),
+                self.abort_code,
+                bytes,
+            )),
+            None => Err(anyhow::Error::msg("Error map data not found.")),
         }
-
-        Ok(())
     }
 }
 
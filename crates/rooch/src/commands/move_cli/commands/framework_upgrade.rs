@@ -16,6 +16,7 @@ use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
 use moveos_types::{move_types::FunctionId, transaction::MoveAction};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_types::addresses::{BITCOIN_MOVE_ADDRESS, ROOCH_FRAMEWORK_ADDRESS};
+use rooch_types::framework::capability::UpgradeCap;
 use rooch_types::error::{RoochError, RoochResult};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -51,7 +52,9 @@ impl CommandAction<ExecuteTransactionResponseView> for FrameworkUpgrade {
             .expect("get bundles failed")
             .into_iter()
             .collect();
+        // The sender must be the owner of the UpgradeCap
         let args = vec![
+            bcs::to_bytes(&UpgradeCap::object_id()).unwrap(),
             bcs::to_bytes(bundles_map.get(&MOVE_STD_ADDRESS).unwrap()).unwrap(),
             bcs::to_bytes(bundles_map.get(&MOVEOS_STD_ADDRESS).unwrap()).unwrap(),
             bcs::to_bytes(bundles_map.get(&ROOCH_FRAMEWORK_ADDRESS).unwrap()).unwrap(),
@@ -8,5 +8,7 @@ pub mod integration_test;
 pub mod new;
 pub mod publish;
 pub mod run_function;
+pub mod run_local;
 pub mod run_view_function;
 pub mod unit_test;
+pub mod verify_source;
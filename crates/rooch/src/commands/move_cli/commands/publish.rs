@@ -9,11 +9,14 @@ use move_bytecode_utils::dependency_graph::DependencyGraph;
 use move_bytecode_utils::Modules;
 use move_cli::Move;
 use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use moveos_verifier::static_analysis::{analyze_module, StaticAnalysisWarning};
 use moveos_verifier::verifier;
 use rooch_key::key_derive::verify_password;
+use rooch_rpc_api::jsonrpc_types::static_analysis_view::StaticAnalysisWarningView;
 use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
 use rooch_types::transaction::rooch::RoochTransaction;
 use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
 
 use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
 use moveos::vm::dependency_order::sort_by_dependency_order;
@@ -51,6 +54,21 @@ pub struct Publish {
     /// `moveos_std::context::publish_modules_entry`
     #[clap(long)]
     pub by_move_action: bool,
+
+    /// Only compile, verify and analyze the modules, do not send the transaction
+    #[clap(long)]
+    pub dry_run: bool,
+}
+
+/// The result of the publish, the dry run returns the warnings of the static analysis without publishing,
+/// the response of the published transaction carries the warnings of the node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PublishOutput {
+    Published(ExecuteTransactionResponseView),
+    DryRun {
+        warnings: Vec<StaticAnalysisWarningView>,
+    },
 }
 
 impl Publish {
@@ -65,8 +83,8 @@ impl Publish {
 }
 
 #[async_trait]
-impl CommandAction<ExecuteTransactionResponseView> for Publish {
-    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+impl CommandAction<PublishOutput> for Publish {
+    async fn execute(self) -> RoochResult<PublishOutput> {
         // Build context and handle errors
         let context = self.context_options.build()?;
 
@@ -102,6 +120,7 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
 
         // Initialize bundles vector and sort modules by dependency order
         let mut bundles: Vec<Vec<u8>> = vec![];
+        let mut warnings: Vec<StaticAnalysisWarning> = vec![];
         let sorted_modules = sort_by_dependency_order(modules.iter_modules())?;
         let resolver = context.get_client().await?;
         // Serialize and collect module binaries into bundles
@@ -115,11 +134,21 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
                 )));
             };
             verifier::verify_module(&module, &resolver)?;
+            warnings.extend(analyze_module(&module));
             let mut binary: Vec<u8> = vec![];
             module.serialize(&mut binary)?;
             bundles.push(binary);
         }
 
+        for warning in &warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        if self.dry_run {
+            return Ok(PublishOutput::DryRun {
+                warnings: warnings.into_iter().map(Into::into).collect(),
+            });
+        }
+
         // Validate sender account if provided
         if pkg_address != context.resolve_address(self.tx_options.sender)? {
             return Err(RoochError::CommandArgumentError(
@@ -202,6 +231,6 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
         };
         //Directly return the result, the publish transaction may be failed.
         //Caller need to check the `execution_info.status` field.
-        Ok(tx_result)
+        Ok(PublishOutput::Published(tx_result))
     }
 }
@@ -58,8 +58,11 @@ pub struct RunFunction {
 impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
     async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
         let context = self.context.build()?;
-        let address_mapping = context.address_mapping();
-        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let address_mapping = context.address_mapping_with_name_service().await?;
+        let sender: RoochAddress = context
+            .resolve_address_with_name_service(self.tx_options.sender)
+            .await?
+            .into();
         let function_id = self.function.into_function_id(&address_mapping)?;
         let args = self
             .args
@@ -0,0 +1,214 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, FunctionArg, WalletContextOptions};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use move_core_types::language_storage::TypeTag;
+use moveos::moveos::{GasPaymentAccount, MoveOS};
+use moveos_types::gas_config::{GasConfig, GasTraceFormat};
+use moveos_types::h256::sha3_256_of_bcs;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::{MoveAction, MoveOSTransaction};
+use rooch_config::store_config::{StoreConfig, R_DEFAULT_DB_DIR, R_DEFAULT_DB_MOVEOS_SUBDIR};
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_genesis::RoochGenesis;
+use rooch_rpc_api::jsonrpc_types::event_view::TransactionEventView;
+use rooch_rpc_api::jsonrpc_types::{KeptVMStatusView, StateChangeSetView};
+use rooch_rpc_server::init_storage;
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use rooch_types::function_arg::{parse_function_arg, ParsedFunctionId};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Run a Move function against a state snapshot in an isolated MoveOS instance,
+/// and print the state change set, the events and the gas used.
+/// The snapshot is the data dir of a node (the dir contains `roochdb`), it is copied to a temporary store,
+/// so the snapshot is never modified. The transaction authenticator is not validated.
+#[derive(Parser)]
+pub struct RunLocal {
+    /// The data dir of the state snapshot, such as `~/.rooch/local`
+    #[clap(long = "state")]
+    pub state: PathBuf,
+
+    /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
+    /// Example: `0x42::message::set_message`, `rooch_framework::empty::empty`
+    #[clap(long)]
+    pub function: ParsedFunctionId,
+
+    /// TypeTag arguments separated by spaces.
+    ///
+    /// Example: `0x1::M::T1 0x1::M::T2 rooch_framework::empty::Empty`
+    #[clap(
+        long = "type-args",
+        value_parser=ParsedStructType::parse,
+    )]
+    pub type_args: Vec<ParsedStructType>,
+
+    /// Arguments combined with their type separated by spaces.
+    ///
+    /// Supported types [u8, u16, u32, u64, u128, u256, bool, object_id, string, address, vector<inner_type>]
+    ///
+    /// Example: `address:0x1 bool:true u8:0 u256:1234 'vector<u32>:a,b,c,d'`
+    ///     address and uint can be written in short form like `@0x1 1u8 4123u256`.
+    #[clap(long = "args", value_parser=parse_function_arg)]
+    pub args: Vec<FunctionArg>,
+
+    /// Sender account address.
+    #[clap(long, alias = "sender-account", value_parser=ParsedAddress::parse, default_value = "default")]
+    pub sender: ParsedAddress,
+
+    /// The sequence number of the transaction, it should be the sender's sequence number in the snapshot
+    /// if the function depends on it.
+    #[clap(long, default_value = "0")]
+    pub sequence_number: u64,
+
+    /// Max gas amount of the transaction
+    #[clap(long = "max-gas-amount", default_value_t = GasConfig::DEFAULT_MAX_GAS_AMOUNT)]
+    pub max_gas_amount: u64,
+
+    /// The bitcoin network of the snapshot
+    #[clap(long)]
+    pub btc_network: Option<u8>,
+
+    /// Write the gas call tree of the execution to the file, in the folded stack format.
+    /// Render it with `inferno-flamegraph` or open it in speedscope.
+    #[clap(long)]
+    pub gas_trace: Option<PathBuf>,
+
+    /// The format of the gas trace, `folded` or `json`, the `json` format is the gas heatmap keyed by `module::function`
+    #[clap(long, default_value_t = GasTraceFormat::Folded, requires = "gas_trace")]
+    pub gas_trace_format: GasTraceFormat,
+
+    #[clap(flatten)]
+    context: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunLocalOutput {
+    pub status: KeptVMStatusView,
+    pub gas_used: u64,
+    pub execution_gas_used: u64,
+    pub storage_gas_used: u64,
+    pub is_upgrade: bool,
+    pub changeset: StateChangeSetView,
+    pub events: Vec<TransactionEventView>,
+}
+
+#[async_trait]
+impl CommandAction<RunLocalOutput> for RunLocal {
+    async fn execute(self) -> RoochResult<RunLocalOutput> {
+        let context = self.context.build()?;
+        let address_mapping = context.address_mapping();
+        let sender = context.resolve_address(self.sender)?;
+        let function_id = self.function.into_function_id(&address_mapping)?;
+        let args = self
+            .args
+            .into_iter()
+            .map(|arg| arg.into_bytes(&address_mapping))
+            .collect::<Result<Vec<_>>>()?;
+        let type_args = self
+            .type_args
+            .into_iter()
+            .map(|tag| {
+                Ok(TypeTag::Struct(Box::new(
+                    tag.into_struct_tag(&address_mapping)?,
+                )))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let action = MoveAction::new_function_call(function_id, type_args, args);
+
+        let snapshot_dir = self
+            .state
+            .join(R_DEFAULT_DB_DIR.as_path())
+            .join(R_DEFAULT_DB_MOVEOS_SUBDIR.as_path());
+        if !snapshot_dir.exists() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "Can not find the moveos store in the state snapshot: {:?}",
+                snapshot_dir
+            )));
+        }
+
+        let opt = RoochOpt::new_with_temp_store();
+        let base_config = BaseConfig::load_with_opt(&opt)?;
+        let mut store_config = StoreConfig::default();
+        store_config.merge_with_opt_with_init(&opt, Arc::new(base_config), true)?;
+        copy_dir_all(&snapshot_dir, &store_config.get_moveos_store_dir())?;
+        let (moveos_store, _rooch_store) = init_storage(&store_config)?;
+
+        // The genesis is only used to build the natives and the config,
+        // the genesis of the snapshot is not checked and not executed.
+        let btc_network = self.btc_network.unwrap_or(Network::default().to_num());
+        let genesis = RoochGenesis::build(
+            RoochChainID::LOCAL.genesis_ctx(sender.into()),
+            BitcoinGenesisContext::new(btc_network),
+        )?;
+        let moveos = MoveOS::new(
+            moveos_store,
+            genesis.all_natives()?,
+            genesis.config.clone(),
+            system_pre_execute_functions(),
+            system_post_execute_functions(),
+        )?;
+
+        let tx_bytes = bcs::to_bytes(&(sender, self.sequence_number, &action))?;
+        let tx_hash = sha3_256_of_bcs(&(sender, self.sequence_number, &action))?;
+        let mut tx_ctx = TxContext::new(
+            sender,
+            self.sequence_number,
+            self.max_gas_amount,
+            tx_hash,
+            tx_bytes.len() as u64,
+        );
+        tx_ctx
+            .add(GasPaymentAccount {
+                account: sender,
+                pay_gas_by_module_account: false,
+            })
+            .expect("adding GasPaymentAccount to tx context failed.");
+
+        let verified_tx = moveos
+            .verify(MoveOSTransaction::new(tx_ctx, action))
+            .map_err(RoochError::from)?;
+        let output = match &self.gas_trace {
+            Some(gas_trace_path) => {
+                let (output, gas_trace) = moveos.execute_with_gas_trace(verified_tx)?;
+                std::fs::write(gas_trace_path, gas_trace.export(self.gas_trace_format))?;
+                output
+            }
+            None => moveos.execute(verified_tx)?,
+        };
+
+        Ok(RunLocalOutput {
+            status: output.status.into(),
+            gas_used: output.gas_used,
+            execution_gas_used: output.gas_statement.execution_gas_used,
+            storage_gas_used: output.gas_statement.storage_gas_used,
+            is_upgrade: output.is_upgrade,
+            changeset: output.state_changeset.into(),
+            events: output.events.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
@@ -49,7 +49,7 @@ pub struct RunViewFunction {
 impl CommandAction<AnnotatedFunctionResultView> for RunViewFunction {
     async fn execute(self) -> RoochResult<AnnotatedFunctionResultView> {
         let context = self.context.build()?;
-        let address_mapping = context.address_mapping();
+        let address_mapping = context.address_mapping_with_name_service().await?;
         let function_id = self.function.into_function_id(&address_mapping)?;
         let args = self
             .args
@@ -15,10 +15,14 @@ use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use termcolor::Buffer;
 
+use moveos_stdlib::natives::instrumentation;
+use moveos_stdlib::natives::memory_accountant::MemoryAccountant;
 use moveos_stdlib::natives::moveos_stdlib::{
+    dispatch::NativeDispatchContext,
     event::NativeEventContext,
     move_module::NativeModuleContext,
     raw_table::{NativeTableContext, TableData},
+    test_helper::NativeTestEnvContext,
 };
 use moveos_store::MoveOSStore;
 use moveos_types::state_resolver::MoveOSResolverProxy;
@@ -44,6 +48,12 @@ pub struct Test {
 
     #[clap(flatten)]
     config_options: WalletContextOptions,
+
+    /// Record the wall-clock time, allocations and gas of each native called by the tests,
+    /// and write the statistics as JSON to the file, for the gas calibration.
+    /// The allocations are only counted when rooch is built with the `native-instrumentation` feature.
+    #[clap(long)]
+    pub native_stats: Option<PathBuf>,
 }
 
 impl Test {
@@ -95,10 +105,21 @@ impl Test {
 
         //TODO define gas metering
         let cost_table = move_vm_test_utils::gas_schedule::INITIAL_COST_SCHEDULE.clone();
-        let natives = all_natives(GasParameters::zeros());
+        let natives = all_natives(GasParameters::zeros())?;
         set_extension_hook(Box::new(new_moveos_natives_runtime));
-        self.test
-            .execute(path, build_config, natives, Some(cost_table))
+        if self.native_stats.is_some() {
+            instrumentation::reset();
+            instrumentation::enable();
+        }
+        let result = self
+            .test
+            .execute(path, build_config, natives, Some(cost_table));
+        if let Some(native_stats) = self.native_stats {
+            instrumentation::disable();
+            let stats = serde_json::to_string_pretty(&instrumentation::snapshot())?;
+            std::fs::write(native_stats, stats)?;
+        }
+        result
     }
 }
 
@@ -115,7 +136,13 @@ fn new_moveos_natives_runtime(ext: &mut NativeContextExtensions) {
     let table_ext = NativeTableContext::new(statedb, table_data);
     let module_ext = NativeModuleContext::new(statedb);
     let event_ext = NativeEventContext::default();
+    let dispatch_ext = NativeDispatchContext::default();
+    let test_env_ext = NativeTestEnvContext::default();
+    let memory_ext = MemoryAccountant::default();
     ext.add(table_ext);
     ext.add(module_ext);
     ext.add(event_ext);
+    ext.add(dispatch_ext);
+    ext.add(test_env_ext);
+    ext.add(memory_ext);
 }
@@ -0,0 +1,150 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::move_cli::package_hooks::list_modules;
+use async_trait::async_trait;
+use clap::Parser;
+use move_binary_format::CompiledModule;
+use move_cli::Move;
+use moveos_verifier::build::run_verifier;
+use rooch_rpc_api::jsonrpc_types::source_verification_view::SourceVerificationView;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::source_verification::{
+    verify_package_modules, BuildMetadata, PackageSource, SourceVerification,
+};
+use std::collections::BTreeMap;
+use std::io::stderr;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+/// Verify the source of a published package.
+/// The package is built locally, then the source and the build metadata are submitted to the source registry of the node,
+/// the node rebuilds the source with its own compiler, compares the bytecode hashes of the rebuilt modules with the on-chain modules
+/// and records the verification status.
+/// With `--offline`, the bytecode hashes are compared locally and nothing is submitted.
+#[derive(Parser)]
+pub struct VerifySource {
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser=crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    /// Compare the bytecode with the on-chain modules locally, do not submit the source to the registry
+    #[clap(long)]
+    offline: bool,
+}
+
+#[async_trait]
+impl CommandAction<SourceVerificationView> for VerifySource {
+    async fn execute(self) -> RoochResult<SourceVerificationView> {
+        let context = self.context_options.build()?;
+        let package_path = self
+            .move_args
+            .package_path
+            .unwrap_or_else(|| std::env::current_dir().unwrap());
+        let mut config = self.move_args.build_config.clone();
+        config.additional_named_addresses =
+            context.parse_and_resolve_addresses(self.named_addresses)?;
+        let named_addresses = config.additional_named_addresses.clone();
+
+        let mut package = config
+            .clone()
+            .compile_package_no_exit(&package_path, &mut stderr())?;
+        run_verifier(package_path.clone(), config, &mut package)?;
+
+        let root_modules = package.root_modules_map();
+        let modules = root_modules.iter_modules();
+        let package_address = match modules.first() {
+            Some(module) => *module.self_id().address(),
+            None => {
+                return Err(RoochError::MoveCompilationError(
+                    "The package has no module".to_owned(),
+                ))
+            }
+        };
+        let mut built_modules = BTreeMap::new();
+        for module in modules {
+            if *module.self_id().address() != package_address {
+                return Err(RoochError::MoveCompilationError(format!(
+                    "module's address ({:?}) not same as package module address {:?}",
+                    module.self_id().address(),
+                    package_address,
+                )));
+            }
+            let mut bytes = vec![];
+            module.serialize(&mut bytes)?;
+            built_modules.insert(module.self_id().name().to_string(), bytes);
+        }
+
+        let source = PackageSource {
+            package_address,
+            package_name: package.compiled_package_info.package_name.to_string(),
+            files: collect_source_files(&package_path)?,
+            build_metadata: BuildMetadata {
+                compiler_version: env!("CARGO_PKG_VERSION").to_owned(),
+                named_addresses,
+                modules: built_modules,
+            },
+        };
+
+        let client = context.get_client().await?;
+        if !self.offline {
+            return Ok(client.rooch.submit_package_source(source.into()).await?);
+        }
+
+        let on_chain_modules = list_modules(&client, package_address)
+            .await?
+            .into_iter()
+            .map(|bytes| {
+                let module = CompiledModule::deserialize(&bytes)?;
+                Ok((module.self_id().name().to_string(), bytes))
+            })
+            .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+        let status = verify_package_modules(&source.build_metadata.modules, &on_chain_modules);
+        Ok(SourceVerification {
+            source_hash: source.source_hash(),
+            source,
+            status,
+            verified_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| RoochError::UnexpectedError(e.to_string()))?
+                .as_millis() as u64,
+        }
+        .into())
+    }
+}
+
+/// Collect the Move.toml and the Move source files of the package, keyed by the path relative to the package root
+fn collect_source_files(package_path: &Path) -> RoochResult<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    files.insert(
+        "Move.toml".to_owned(),
+        std::fs::read_to_string(package_path.join("Move.toml"))?,
+    );
+    for entry in WalkDir::new(package_path.join("sources")) {
+        let entry = entry.map_err(|e| RoochError::IOError(e.to_string()))?;
+        let path = entry.path();
+        if !entry.file_type().is_file() || path.extension().map_or(true, |ext| ext != "move") {
+            continue;
+        }
+        let relative_path = path
+            .strip_prefix(package_path)
+            .map_err(|e| RoochError::IOError(e.to_string()))?;
+        files.insert(
+            relative_path.to_string_lossy().replace('\\', "/"),
+            std::fs::read_to_string(path)?,
+        );
+    }
+    Ok(files)
+}
@@ -1,12 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::cli_types::{format_output, format_status};
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
 use commands::{
     build::Build, framework_upgrade::FrameworkUpgrade, integration_test::IntegrationTest, new::New,
-    publish::Publish, run_function::RunFunction, run_view_function::RunViewFunction,
-    unit_test::Test,
+    publish::Publish, run_function::RunFunction, run_local::RunLocal,
+    run_view_function::RunViewFunction, unit_test::Test, verify_source::VerifySource,
 };
 use move_cli::{
     base::{
@@ -18,9 +19,11 @@ use move_cli::{
 use rooch_types::error::{RoochError, RoochResult};
 
 use crate::commands::move_cli::commands::explain::Explain;
+use crate::commands::move_cli::package_hooks::{check_git_dependencies, RoochPackageHooks};
 use crate::CommandAction;
 
 pub mod commands;
+pub mod package_hooks;
 
 #[derive(Parser)]
 pub struct MoveCli {
@@ -44,33 +47,43 @@ pub enum MoveCommand {
     Test(Test),
     Publish(Publish),
     Run(RunFunction),
+    RunLocal(RunLocal),
     View(RunViewFunction),
     IntegrationTest(IntegrationTest),
     Explain(Explain),
     FrameworkUpgrade(FrameworkUpgrade),
+    VerifySource(VerifySource),
 }
 
 #[async_trait]
 impl CommandAction<String> for MoveCli {
     async fn execute(self) -> RoochResult<String> {
+        RoochPackageHooks::register();
         let move_args = self.move_args;
+        if let Ok(package_path) = move_args
+            .package_path
+            .clone()
+            .map_or_else(std::env::current_dir, Ok)
+        {
+            check_git_dependencies(&package_path)?;
+        }
         match self.cmd {
             MoveCommand::Build(c) => c
                 .execute(move_args.package_path, move_args.build_config)
                 .await
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Coverage(c) => c
                 .execute(move_args.package_path, move_args.build_config)
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Disassemble(c) => c
                 .execute(move_args.package_path, move_args.build_config)
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Docgen(c) => c
                 .execute(move_args.package_path, move_args.build_config)
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Errmap(mut c) => {
                 match c.error_prefix {
@@ -93,41 +106,43 @@ impl CommandAction<String> for MoveCli {
                 }
 
                 c.execute(move_args.package_path, move_args.build_config)
-                    .map(|_| "Success".to_owned())
+                    .map(|_| format_status("Success"))
                     .map_err(RoochError::from)
             }
             MoveCommand::Info(c) => c
                 .execute(move_args.package_path, move_args.build_config)
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::New(c) => c
                 .execute(move_args.package_path)
                 .await
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Prove(c) => c
                 .execute(move_args.package_path, move_args.build_config)
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Test(c) => c
                 .execute(move_args.package_path, move_args.build_config)
                 .await
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Publish(c) => c.execute_serialized().await,
             MoveCommand::Run(c) => c.execute_serialized().await,
+            MoveCommand::RunLocal(c) => c.execute_serialized().await,
             MoveCommand::View(c) => c.execute_serialized().await,
             MoveCommand::IntegrationTest(c) => c
                 .execute(move_args)
                 .await
-                .map(|_| "Success".to_owned())
+                .map(|_| format_status("Success"))
                 .map_err(RoochError::from),
             MoveCommand::Explain(c) => c
                 .execute()
                 .await
-                .map(|_| "Success".to_owned())
+                .map(|explain| format_output(explain, |explain| explain.to_string()))
                 .map_err(RoochError::from),
             MoveCommand::FrameworkUpgrade(c) => c.execute_serialized().await,
+            MoveCommand::VerifySource(c) => c.execute_serialized().await,
         }
     }
 }
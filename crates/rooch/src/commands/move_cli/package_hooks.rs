@@ -0,0 +1,320 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolve the on-chain dependencies of a Move package.
+//!
+//! Git dependencies are resolved by the package system, they should be pinned to a commit:
+//! `MyDep = { git = "https://github.com/xxx/yyy.git", subdir = "path/to/package", rev = "<commit>" }`.
+//! A git dependency pinned to a branch or a tag is warned by `check_git_dependencies`, the build is not reproducible.
+//!
+//! An on-chain dependency is declared with the RPC url of a node and the address of the package:
+//! `MyDep = { rooch = "http://localhost:50051", address = "0x42", digest = "<package digest>" }`.
+//! The modules at the address are downloaded and verified, then an interface package named `MyDep`
+//! is generated from the bytecode, so the dependent package can be built without the source code.
+//! The manifest of the interface package records the digest of the bytecode, so the `digest` of the dependency,
+//! which is verified by the package system, pins the bytecode, an upgraded on-chain package fails the build.
+//! The interface package is cached in the Move home, it is generated again if the downloaded modules change,
+//! and the cache is only used without verifying the on-chain modules if the node is unreachable.
+//! If the on-chain package depends on other non-framework packages, they need to be declared too.
+
+use anyhow::{bail, ensure, Result};
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_package::package_hooks::{register_package_hooks, PackageHooks};
+use move_package::source_package::manifest_parser::{
+    parse_move_manifest_string, parse_source_manifest,
+};
+use move_package::source_package::parsed_manifest::CustomDepInfo;
+use move_symbol_pool::Symbol;
+use moveos_types::access_path::{AccessPath, Path as AccessPathInner};
+use moveos_types::h256::{self, H256};
+use moveos_types::moveos_std::move_module::MoveModule;
+use rooch_rpc_client::{Client, ClientBuilder};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The key of the on-chain dependency in the Move.toml
+pub const ROOCH_DEPENDENCY_KEY: &str = "rooch";
+/// The field of the interface package recording the digest of the bytecode
+pub const BYTECODE_DIGEST_FIELD: &str = "bytecode_digest";
+
+const LIST_MODULES_PAGE_SIZE: usize = 100;
+
+pub struct RoochPackageHooks;
+
+impl RoochPackageHooks {
+    pub fn register() {
+        register_package_hooks(Box::new(RoochPackageHooks));
+    }
+}
+
+impl PackageHooks for RoochPackageHooks {
+    fn custom_package_info_fields(&self) -> Vec<String> {
+        vec![BYTECODE_DIGEST_FIELD.to_owned()]
+    }
+
+    fn custom_dependency_key(&self) -> Option<String> {
+        Some(ROOCH_DEPENDENCY_KEY.to_owned())
+    }
+
+    fn resolve_custom_dependency(&self, dep_name: Symbol, info: &CustomDepInfo) -> Result<()> {
+        let address = AccountAddress::from_hex_literal(info.package_address.as_str())?;
+        let node_url = info.node_url.as_str().to_owned();
+        let cached_digest = cached_bytecode_digest(&info.download_to);
+        // The package system resolves the dependencies synchronously, and it may run in an async context,
+        // so download the modules in a dedicated thread with its own runtime.
+        let downloaded = std::thread::spawn(move || -> Result<Vec<Vec<u8>>> {
+            tokio::runtime::Runtime::new()?.block_on(download_modules(&node_url, address))
+        })
+        .join()
+        .map_err(|_| anyhow::anyhow!("Download the modules of {} panicked", address))?;
+        let modules = match (downloaded, cached_digest) {
+            (Ok(modules), _) => verify_modules(address, modules)?,
+            (Err(e), Some(digest)) => {
+                eprintln!(
+                    "Failed to download the modules of {} from {}, use the cached package with the bytecode digest {}: {}",
+                    address,
+                    info.node_url,
+                    hex::encode(digest),
+                    e
+                );
+                return Ok(());
+            }
+            (Err(e), None) => return Err(e),
+        };
+        let digest = bytecode_digest(&modules)?;
+        if cached_digest == Some(digest) {
+            return Ok(());
+        }
+        write_interface_package(&info.download_to, dep_name.as_str(), &modules)
+    }
+}
+
+/// The digest of the modules ordered by the module id, it is independent of the download order
+pub fn bytecode_digest(modules: &[CompiledModule]) -> Result<H256> {
+    let mut modules = modules
+        .iter()
+        .map(|module| {
+            let mut bytes = vec![];
+            module.serialize(&mut bytes)?;
+            Ok((module.self_id(), bytes))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    modules.sort_by(|(a, _), (b, _)| a.cmp(b));
+    h256::sha3_256_of_bcs(
+        &modules
+            .into_iter()
+            .map(|(_, bytes)| bytes)
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// The bytecode digest recorded by the cached interface package, it is verified against the cached bytecode,
+/// `None` if the package is not cached or it is modified.
+fn cached_bytecode_digest(package_dir: &Path) -> Option<H256> {
+    let manifest = fs::read_to_string(package_dir.join("Move.toml")).ok()?;
+    let recorded = manifest.lines().find_map(|line| {
+        line.strip_prefix(&format!("{} = \"", BYTECODE_DIGEST_FIELD))?
+            .strip_suffix('"')
+    })?;
+    let recorded = hex::decode(recorded).ok()?;
+    if recorded.len() != h256::LENGTH {
+        return None;
+    }
+    let recorded = H256::from_slice(&recorded);
+    let modules = fs::read_dir(package_dir.join("bytecode_modules"))
+        .ok()?
+        .map(|entry| {
+            let bytes = fs::read(entry?.path())?;
+            Ok(CompiledModule::deserialize(&bytes)?)
+        })
+        .collect::<Result<Vec<_>>>()
+        .ok()?;
+    (bytecode_digest(&modules).ok()? == recorded).then_some(recorded)
+}
+
+/// Warn the git dependencies of the package at `package_path` which are not pinned to a commit.
+pub fn check_git_dependencies(package_path: &Path) -> Result<()> {
+    let manifest_path = package_path.join("Move.toml");
+    if !manifest_path.exists() {
+        return Ok(());
+    }
+    for (name, rev) in unpinned_git_dependencies(&fs::read_to_string(manifest_path)?)? {
+        eprintln!(
+            "Warning: the git dependency {} is not pinned to a commit (rev = \"{}\"), the build is not reproducible",
+            name, rev
+        );
+    }
+    Ok(())
+}
+
+/// The git dependencies whose revision is not a commit hash, with their revisions
+fn unpinned_git_dependencies(manifest: &str) -> Result<Vec<(String, String)>> {
+    let manifest = parse_source_manifest(parse_move_manifest_string(manifest.to_owned())?)?;
+    Ok(manifest
+        .dependencies
+        .iter()
+        .chain(manifest.dev_dependencies.iter())
+        .filter_map(|(name, dep)| {
+            let rev = dep.git_info.as_ref()?.git_rev.as_str();
+            let pinned = rev.len() == 40 && rev.chars().all(|c| c.is_ascii_hexdigit());
+            (!pinned).then(|| (name.to_string(), rev.to_owned()))
+        })
+        .collect())
+}
+
+async fn download_modules(node_url: &str, address: AccountAddress) -> Result<Vec<Vec<u8>>> {
+    let client = ClientBuilder::default().build(node_url).await?;
+    list_modules(&client, address).await
+}
+
+/// List the bytecode of all the modules published at the address
+pub(crate) async fn list_modules(client: &Client, address: AccountAddress) -> Result<Vec<Vec<u8>>> {
+    let access_path = AccessPath(AccessPathInner::Module {
+        account: address,
+        module_names: None,
+    });
+    let mut modules = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .rooch
+            .list_states(
+                access_path.clone().into(),
+                cursor,
+                Some(LIST_MODULES_PAGE_SIZE),
+            )
+            .await?;
+        for kv in page.data {
+            let module: MoveModule = bcs::from_bytes(&kv.state.value.0)?;
+            modules.push(module.byte_codes);
+        }
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    Ok(modules)
+}
+
+/// Deserialize and verify the downloaded modules, all the modules must be published at the address.
+fn verify_modules(address: AccountAddress, modules: Vec<Vec<u8>>) -> Result<Vec<CompiledModule>> {
+    if modules.is_empty() {
+        bail!("No module is published at address {}", address);
+    }
+    modules
+        .into_iter()
+        .map(|bytes| {
+            let module = CompiledModule::deserialize(&bytes)?;
+            ensure!(
+                *module.self_id().address() == address,
+                "Module {} is not published at address {}",
+                module.self_id(),
+                address
+            );
+            move_bytecode_verifier::verify_module(&module)?;
+            Ok(module)
+        })
+        .collect()
+}
+
+/// Generate a package which contains the interface source and the bytecode of the modules,
+/// the previously cached package is removed, so the modules removed on-chain are not left.
+fn write_interface_package(
+    package_dir: &Path,
+    package_name: &str,
+    modules: &[CompiledModule],
+) -> Result<()> {
+    if package_dir.exists() {
+        fs::remove_dir_all(package_dir)?;
+    }
+    let sources_dir = package_dir.join("sources");
+    let bytecode_dir = package_dir.join("bytecode_modules");
+    fs::create_dir_all(&sources_dir)?;
+    fs::create_dir_all(&bytecode_dir)?;
+    for module in modules {
+        let (module_id, interface) =
+            move_compiler::interface_generator::write_module_to_string(&BTreeMap::new(), module)?;
+        let name = module_id.name().as_str();
+        fs::write(sources_dir.join(format!("{}.move", name)), interface)?;
+        let mut bytes = vec![];
+        module.serialize(&mut bytes)?;
+        fs::write(bytecode_dir.join(format!("{}.mv", name)), bytes)?;
+    }
+    // Write the manifest at last, the package is only treated as downloaded when the manifest exists.
+    fs::write(
+        package_dir.join("Move.toml"),
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.0.0\"\n{} = \"{}\"\n\n[dependencies]\n",
+            package_name,
+            BYTECODE_DIGEST_FIELD,
+            hex::encode(bytecode_digest(modules)?)
+        ),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::empty_module;
+
+    #[test]
+    fn test_write_interface_package() {
+        let module = empty_module();
+        let address = *module.self_id().address();
+        let mut bytes = vec![];
+        module.serialize(&mut bytes).unwrap();
+        assert!(verify_modules(AccountAddress::ONE, vec![bytes.clone()]).is_err());
+        assert!(verify_modules(address, vec![]).is_err());
+        let modules = verify_modules(address, vec![bytes]).unwrap();
+
+        let package_dir =
+            std::env::temp_dir().join(format!("rooch_interface_package_{}", std::process::id()));
+        write_interface_package(&package_dir, "Dep", &modules).unwrap();
+        let name = module.self_id().name().to_string();
+        let manifest = fs::read_to_string(package_dir.join("Move.toml")).unwrap();
+        let interface =
+            fs::read_to_string(package_dir.join("sources").join(format!("{}.move", name))).unwrap();
+        let bytecode_exists = package_dir
+            .join("bytecode_modules")
+            .join(format!("{}.mv", name))
+            .exists();
+        let cached_digest = cached_bytecode_digest(&package_dir);
+        // The cached package is invalid once the recorded digest mismatches the bytecode
+        fs::write(
+            package_dir.join("Move.toml"),
+            manifest.replace(
+                &hex::encode(bytecode_digest(&modules).unwrap()),
+                &hex::encode(H256::zero()),
+            ),
+        )
+        .unwrap();
+        let tampered_digest = cached_bytecode_digest(&package_dir);
+        fs::remove_dir_all(&package_dir).unwrap();
+        assert_eq!(cached_digest, Some(bytecode_digest(&modules).unwrap()));
+        assert_eq!(tampered_digest, None);
+        assert!(manifest.contains("name = \"Dep\""));
+        assert!(interface.contains(&format!("::{} {{", name)));
+        assert!(bytecode_exists);
+    }
+
+    #[test]
+    fn test_unpinned_git_dependencies() {
+        let manifest = r#"
+[package]
+name = "Test"
+version = "0.0.1"
+
+[dependencies]
+Pinned = { git = "https://github.com/xxx/yyy.git", rev = "6ab3a7445b89e676b495370e4d21296f61f0c3f4" }
+Branch = { git = "https://github.com/xxx/yyy.git", rev = "main" }
+Local = { local = "../local" }
+"#;
+        assert_eq!(
+            unpinned_git_dependencies(manifest).unwrap(),
+            vec![("Branch".to_owned(), "main".to_owned())]
+        );
+    }
+}
@@ -1,29 +1,73 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::cli_types::{print_message, CommandAction, TransactionOptions, WalletContextOptions};
+use crate::utils::confirm;
 use async_trait::async_trait;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use move_command_line_common::address::ParsedAddress;
 use moveos_types::access_path::AccessPath;
-use rooch_rpc_api::jsonrpc_types::StateView;
-use rooch_types::{error::RoochResult, function_arg::ParsedObjectID};
+use moveos_types::state::State;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, StateView};
+use rooch_types::address::RoochAddress;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::transfer::{self, TransferModule};
+use rooch_types::function_arg::ParsedObjectID;
+use rpassword::prompt_password;
 
 /// Get object by object id
 #[derive(Debug, Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
 pub struct ObjectCommand {
     /// Object id.
     #[clap(long)]
-    pub id: ParsedObjectID,
+    pub id: Option<ParsedObjectID>,
 
     #[clap(flatten)]
     pub(crate) context_options: WalletContextOptions,
+
+    #[clap(subcommand)]
+    cmd: Option<ObjectSubCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ObjectSubCommand {
+    Transfer(TransferObjectCommand),
+}
+
+#[async_trait]
+impl CommandAction<String> for ObjectCommand {
+    async fn execute(self) -> RoochResult<String> {
+        match (self.cmd, self.id) {
+            (Some(ObjectSubCommand::Transfer(cmd)), _) => cmd.execute_serialized().await,
+            (None, Some(id)) => {
+                GetObjectCommand {
+                    id,
+                    context_options: self.context_options,
+                }
+                .execute_serialized()
+                .await
+            }
+            (None, None) => Err(RoochError::CommandArgumentError(
+                "The --id or a subcommand is required".to_owned(),
+            )),
+        }
+    }
+}
+
+struct GetObjectCommand {
+    id: ParsedObjectID,
+    context_options: WalletContextOptions,
 }
 
 #[async_trait]
-impl CommandAction<Option<StateView>> for ObjectCommand {
+impl CommandAction<Option<StateView>> for GetObjectCommand {
     async fn execute(self) -> RoochResult<Option<StateView>> {
         let context = self.context_options.build()?;
-        let mapping = context.address_mapping();
+        let mapping = context.address_mapping_with_name_service().await?;
         let id = self.id.into_object_id(&mapping)?;
         let client = context.get_client().await?;
         let resp = client
@@ -36,3 +80,103 @@ impl CommandAction<Option<StateView>> for ObjectCommand {
         Ok(resp)
     }
 }
+
+/// Transfer an object owned by the sender to an account,
+/// the object type is resolved from the on-chain object
+#[derive(Debug, Parser)]
+pub struct TransferObjectCommand {
+    /// Object id.
+    #[clap(long)]
+    id: ParsedObjectID,
+
+    /// The recipient account's address
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    to: ParsedAddress,
+
+    /// Submit the transaction without the confirmation
+    #[clap(long, short = 'y')]
+    yes: bool,
+
+    #[clap(flatten)]
+    tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for TransferObjectCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping_with_name_service().await?;
+        let sender_address = context
+            .resolve_address_with_name_service(self.tx_options.sender)
+            .await?;
+        let sender: RoochAddress = sender_address.into();
+        let to = context.resolve_address_with_name_service(self.to).await?;
+        let id = self.id.into_object_id(&mapping)?;
+
+        let client = context.get_client().await?;
+        let state: State = client
+            .rooch
+            .get_states(AccessPath::object(id))
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError(format!("The object {} does not exist", id))
+            })?
+            .into();
+        let object = state.as_raw_object()?;
+        if object.owner != sender_address {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The object {} is owned by {}, not the sender {}",
+                id,
+                object.owner.to_hex_literal(),
+                sender
+            )));
+        }
+        if object.is_shared() || object.is_frozen() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The object {} is shared or frozen, it can not be transferred",
+                id
+            )));
+        }
+        let object_type = object.value.struct_tag;
+
+        let action = TransferModule::create_transfer_object_action(object_type.clone(), to, id);
+        print_message(format!(
+            "Transfer the object {} of {} from {} to {}\nCall: {}::{}::{}<{}>",
+            id,
+            object_type,
+            sender,
+            to.to_hex_literal(),
+            ROOCH_FRAMEWORK_ADDRESS.to_hex_literal(),
+            transfer::MODULE_NAME,
+            TransferModule::TRANSFER_OBJECT_FUNCTION_NAME,
+            object_type
+        ));
+        if !self.yes && !confirm("Do you want to submit the transaction?")? {
+            return Err(RoochError::AbortedError);
+        }
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password = prompt_password("Enter the password to transfer:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        context.assert_execute_success(result)
+    }
+}
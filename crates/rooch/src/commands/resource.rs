@@ -30,7 +30,7 @@ pub struct ResourceCommand {
 impl CommandAction<Option<StateView>> for ResourceCommand {
     async fn execute(self) -> RoochResult<Option<StateView>> {
         let context = self.context_options.build()?;
-        let mapping = context.address_mapping();
+        let mapping = context.address_mapping_with_name_service().await?;
         let address = self.address.into_account_address(&mapping)?;
         let resource = self.resource.into_struct_tag(&mapping)?;
         let client = context.get_client().await?;
@@ -0,0 +1,48 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_open_rpc_spec_builder::{build_full_rooch_rpc_spec, build_rooch_rpc_spec};
+use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+
+/// Export the OpenRPC document of the RPC API, with the JSON schemas of all the RPC types
+#[derive(Debug, Parser)]
+pub struct SchemaCommand {
+    /// Only export the `rooch` namespace, the same as the spec used to generate the TypeScript SDK
+    #[clap(long)]
+    pub rooch_only: bool,
+
+    /// Write the document to the file instead of the stdout
+    #[clap(long, short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
+#[async_trait]
+impl CommandAction<Option<serde_json::Value>> for SchemaCommand {
+    async fn execute(self) -> RoochResult<Option<serde_json::Value>> {
+        let open_rpc = if self.rooch_only {
+            build_rooch_rpc_spec()
+        } else {
+            build_full_rooch_rpc_spec()
+        };
+        let document =
+            serde_json::to_value(&open_rpc).expect("Serialize the OpenRPC document should success");
+        match self.output {
+            Some(output) => {
+                let content = serde_json::to_string_pretty(&document)
+                    .expect("Serialize the OpenRPC document should success");
+                std::fs::write(&output, content + "\n").map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Write the schema to {:?} failed: {}",
+                        output, e
+                    ))
+                })?;
+                Ok(None)
+            }
+            None => Ok(Some(document)),
+        }
+    }
+}
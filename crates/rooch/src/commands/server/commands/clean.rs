@@ -17,7 +17,7 @@ pub struct CleanCommand {
 }
 
 impl CleanCommand {
-    pub fn execute(self) -> RoochResult<()> {
+    pub fn execute(self) -> RoochResult<String> {
         let base_config = BaseConfig::load_with_opt(&self.opt)?;
         let mut store_config = StoreConfig::default();
         store_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config.clone()), false)?;
@@ -32,9 +32,7 @@ impl CleanCommand {
         self.remove_store_dir(&moveos_store_dir, "MoveOS")?;
         self.remove_store_file(&indexer_store_file, "Indexer")?;
 
-        println!("Rooch server storage successfully cleaned");
-
-        Ok(())
+        Ok("Rooch server storage successfully cleaned".to_owned())
     }
 
     fn remove_store_dir(&self, store_dir: &Path, name: &str) -> RoochResult<()> {
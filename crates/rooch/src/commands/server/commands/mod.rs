@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod clean;
+pub mod shadow_fork;
 pub mod start;
@@ -0,0 +1,283 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos::gas::table::CostTable;
+use moveos_types::transaction::MoveAction;
+use rooch_config::store_config::StoreConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_genesis::GenesisPackage;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
+use rooch_rpc_api::jsonrpc_types::{
+    H256View, KeptVMStatusView, TransactionExecutionInfoView, TransactionTypeView,
+};
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_rpc_server::init_storage;
+use rooch_types::address::RoochAddress;
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::{RawTransaction, TransactionType, TypedTransaction};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::signal::ctrl_c;
+use tracing::info;
+
+/// Shadow fork a remote network: stream its transactions and execute them locally
+/// against a patched framework or gas schedule, and report the divergence in
+/// the execution statuses, the gas used and the state roots.
+/// The local node starts from the genesis of the remote network with a temporary store,
+/// so the sequencer account must be the same as the remote network's.
+#[derive(Debug, Parser)]
+pub struct ShadowForkCommand {
+    /// The RPC url of the remote network
+    #[clap(long)]
+    remote_url: String,
+
+    /// The chain id of the remote network, it decides the genesis of the local node
+    #[clap(long)]
+    chain_id: RoochChainID,
+
+    /// The sequencer account of the remote network
+    #[clap(long)]
+    sequencer_account: RoochAddress,
+
+    /// The bitcoin network of the remote network
+    #[clap(long)]
+    btc_network: Option<u8>,
+
+    /// Build the framework from the local source and publish it before the fork order,
+    /// to validate a framework upgrade before proposing it.
+    #[clap(long)]
+    patch_framework: bool,
+
+    /// A json file of the `CostTable` to meter the transactions from the fork order
+    #[clap(long)]
+    gas_schedule: Option<PathBuf>,
+
+    /// The transactions before the fork order are executed without the patches
+    #[clap(long, default_value = "0")]
+    fork_order: u64,
+
+    /// Stop after the transaction of the end order
+    #[clap(long)]
+    end_order: Option<u64>,
+
+    /// Keep polling the new transactions of the remote network until Ctrl-C
+    #[clap(long)]
+    follow: bool,
+
+    /// The number of the transactions to fetch per request
+    #[clap(long, default_value = "100")]
+    batch_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionDivergence {
+    pub tx_order: u64,
+    pub tx_hash: H256View,
+    pub remote_status: KeptVMStatusView,
+    pub local_status: KeptVMStatusView,
+    pub remote_gas_used: u64,
+    pub local_gas_used: u64,
+    pub remote_state_root: H256View,
+    pub local_state_root: H256View,
+}
+
+impl TransactionDivergence {
+    pub fn status_diverged(&self) -> bool {
+        self.remote_status != self.local_status
+    }
+
+    pub fn gas_diverged(&self) -> bool {
+        self.remote_gas_used != self.local_gas_used
+    }
+
+    pub fn state_root_diverged(&self) -> bool {
+        self.remote_state_root != self.local_state_root
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ShadowForkReport {
+    /// The number of the replayed transactions
+    pub replayed: u64,
+    pub status_diverged: u64,
+    pub gas_diverged: u64,
+    pub state_root_diverged: u64,
+    /// The sum of the gas used by the replayed transactions on the remote network and locally
+    pub remote_gas_used: u64,
+    pub local_gas_used: u64,
+    /// The transactions which diverge in any of the status, the gas used or the state root
+    pub divergences: Vec<TransactionDivergence>,
+}
+
+impl ShadowForkReport {
+    fn record(&mut self, divergence: TransactionDivergence) {
+        self.replayed += 1;
+        self.remote_gas_used += divergence.remote_gas_used;
+        self.local_gas_used += divergence.local_gas_used;
+        let status_diverged = divergence.status_diverged();
+        let gas_diverged = divergence.gas_diverged();
+        let state_root_diverged = divergence.state_root_diverged();
+        self.status_diverged += status_diverged as u64;
+        self.gas_diverged += gas_diverged as u64;
+        self.state_root_diverged += state_root_diverged as u64;
+        if status_diverged || gas_diverged || state_root_diverged {
+            info!("Shadow fork divergence: {:?}", divergence);
+            self.divergences.push(divergence);
+        }
+    }
+}
+
+#[async_trait]
+impl CommandAction<ShadowForkReport> for ShadowForkCommand {
+    async fn execute(self) -> RoochResult<ShadowForkReport> {
+        let client = ClientBuilder::default().build(&self.remote_url).await?;
+        let remote_chain_id = client.rooch.get_chain_id().await?;
+        if remote_chain_id != self.chain_id.chain_id().id() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The chain id {} mismatches the chain id {} of the remote network",
+                self.chain_id, remote_chain_id
+            )));
+        }
+        let cost_table = self
+            .gas_schedule
+            .as_ref()
+            .map(|path| -> Result<CostTable> { Ok(serde_json::from_slice(&std::fs::read(path)?)?) })
+            .transpose()?;
+
+        let mut executor = self.init_executor()?;
+        let mut report = ShadowForkReport::default();
+        let mut patched = false;
+        let mut cursor = None;
+        loop {
+            let page = client
+                .rooch
+                .get_transactions_by_order(cursor, Some(self.batch_size))
+                .await?;
+            for tx in page.data {
+                let tx_order = tx.sequence_info.tx_order.0;
+                if self
+                    .end_order
+                    .map_or(false, |end_order| tx_order > end_order)
+                {
+                    return Ok(report);
+                }
+                if !patched && tx_order >= self.fork_order {
+                    self.apply_patches(&mut executor, cost_table.clone())?;
+                    patched = true;
+                }
+                report.record(replay_tx(&mut executor, tx)?);
+            }
+            cursor = page.next_cursor;
+            if !page.has_next_page && !self.wait_for_new_txs(&client, cursor).await? {
+                break;
+            }
+        }
+        Ok(report)
+    }
+}
+
+impl ShadowForkCommand {
+    fn init_executor(&self) -> Result<ExecutorActor> {
+        let mut opt = RoochOpt::new_with_temp_store();
+        opt.chain_id = Some(self.chain_id.clone());
+        let base_config = BaseConfig::load_with_opt(&opt)?;
+        let mut store_config = StoreConfig::default();
+        store_config.merge_with_opt_with_init(&opt, Arc::new(base_config), true)?;
+        let (moveos_store, rooch_store) = init_storage(&store_config)?;
+        let btc_network = self.btc_network.unwrap_or(Network::default().to_num());
+        ExecutorActor::new(
+            self.chain_id.genesis_ctx(self.sequencer_account),
+            BitcoinGenesisContext::new(btc_network),
+            moveos_store,
+            rooch_store,
+        )
+    }
+
+    /// Publish the framework built from the local source like the genesis does, and replace the gas schedule.
+    fn apply_patches(
+        &self,
+        executor: &mut ExecutorActor,
+        cost_table: Option<CostTable>,
+    ) -> Result<()> {
+        if self.patch_framework {
+            let stdlib = GenesisPackage::build_stdlib()?;
+            for (genesis_account, bundle) in stdlib.module_bundles()? {
+                let tx = RoochTransaction::new_genesis_tx(
+                    genesis_account.into(),
+                    self.chain_id.chain_id().id(),
+                    MoveAction::ModuleBundle(bundle),
+                );
+                let verified_tx = executor.moveos().verify(tx.into())?;
+                let result = executor.execute(verified_tx)?;
+                info!(
+                    "Publish the patched framework to {}, status: {:?}",
+                    genesis_account, result.transaction_info.status
+                );
+            }
+        }
+        if let Some(cost_table) = cost_table {
+            executor.set_cost_table(cost_table);
+        }
+        Ok(())
+    }
+
+    /// Wait until the remote network sequences new transactions after the cursor,
+    /// return false if the shadow fork should stop.
+    async fn wait_for_new_txs(&self, client: &Client, cursor: Option<u64>) -> Result<bool> {
+        if !self.follow {
+            return Ok(false);
+        }
+        loop {
+            tokio::select! {
+                _ = ctrl_c() => return Ok(false),
+                _ = tokio::time::sleep(Duration::from_secs(1)) => {}
+            }
+            let page = client
+                .rooch
+                .get_transactions_by_order(cursor, Some(1))
+                .await?;
+            if !page.data.is_empty() {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+fn replay_tx(
+    executor: &mut ExecutorActor,
+    tx: TransactionWithInfoView,
+) -> Result<TransactionDivergence> {
+    let transaction_type = match tx.transaction.transaction_type {
+        TransactionTypeView::Rooch => TransactionType::Rooch,
+        TransactionTypeView::Ethereum => TransactionType::Ethereum,
+    };
+    let typed_tx = TypedTransaction::try_from(RawTransaction {
+        transaction_type,
+        raw: tx.transaction.raw.0,
+    })?;
+    let verified_tx = executor.validate(typed_tx)?;
+    let local_execution_info =
+        TransactionExecutionInfoView::from(executor.execute(verified_tx)?.transaction_info);
+    let remote_execution_info = tx.execution_info;
+    Ok(TransactionDivergence {
+        tx_order: tx.sequence_info.tx_order.0,
+        tx_hash: remote_execution_info.tx_hash,
+        remote_status: remote_execution_info.status,
+        local_status: local_execution_info.status,
+        remote_gas_used: remote_execution_info.gas_used,
+        local_gas_used: local_execution_info.gas_used,
+        remote_state_root: remote_execution_info.state_root,
+        local_state_root: local_execution_info.state_root,
+    })
+}
@@ -83,57 +83,65 @@ impl CommandAction<()> for StartCommand {
             )?
         };
 
-        let (sequencer_keypair, proposer_keypair, relayer_keypair) =
-            if context.keystore.get_if_password_is_empty() {
-                let sequencer_keypair = context
-                    .keystore
-                    .get_key_pair_with_password(&sequencer_account, None)
-                    .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
-
-                let proposer_keypair = context
-                    .keystore
-                    .get_key_pair_with_password(&proposer_account, None)
-                    .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
-
-                let relayer_keypair = context
-                    .keystore
-                    .get_key_pair_with_password(&relayer_account, None)
-                    .map_err(|e| RoochError::RelayerKeyPairDoesNotExistError(e.to_string()))?;
+        let next_sequencer_account = self
+            .opt
+            .next_sequencer_account
+            .as_ref()
+            .map(|account| {
+                RoochAddress::from_str(account).map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Invalid next sequencer account address: {}",
+                        e
+                    ))
+                })
+            })
+            .transpose()?;
 
-                (sequencer_keypair, proposer_keypair, relayer_keypair)
-            } else {
-                let password = prompt_password("Enter the password:").unwrap_or_default();
-                let is_verified =
-                    verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
 
-                if !is_verified {
-                    return Err(RoochError::InvalidPasswordError(
-                        "Password is invalid".to_owned(),
-                    ));
-                }
+        let sequencer_keypair = context
+            .keystore
+            .get_key_pair_with_password(&sequencer_account, password.clone())
+            .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
 
-                let sequencer_keypair = context
-                    .keystore
-                    .get_key_pair_with_password(&sequencer_account, Some(password.clone()))
-                    .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
+        let proposer_keypair = context
+            .keystore
+            .get_key_pair_with_password(&proposer_account, password.clone())
+            .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
 
-                let proposer_keypair = context
-                    .keystore
-                    .get_key_pair_with_password(&proposer_account, Some(password.clone()))
-                    .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
+        let relayer_keypair = context
+            .keystore
+            .get_key_pair_with_password(&relayer_account, password.clone())
+            .map_err(|e| RoochError::RelayerKeyPairDoesNotExistError(e.to_string()))?;
 
-                let relayer_keypair = context
+        let next_sequencer_keypair = next_sequencer_account
+            .map(|account| {
+                context
                     .keystore
-                    .get_key_pair_with_password(&relayer_account, Some(password))
-                    .map_err(|e| RoochError::RelayerKeyPairDoesNotExistError(e.to_string()))?;
+                    .get_key_pair_with_password(&account, password.clone())
+                    .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))
+            })
+            .transpose()?;
 
-                (sequencer_keypair, proposer_keypair, relayer_keypair)
-            };
         // Construct sequencer, proposer and relayer keypair
         let mut server_opt = ServerOpt::new();
         server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
         server_opt.proposer_keypair = Some(proposer_keypair.copy());
         server_opt.relayer_keypair = Some(relayer_keypair.copy());
+        server_opt.next_sequencer_keypair = next_sequencer_keypair;
 
         let active_env = context.client_config.get_active_env()?;
         server_opt.active_env = Some(active_env.clone().alias);
@@ -1,13 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::CommandAction;
+use crate::cli_types::{format_status, CommandAction};
 use async_trait::async_trait;
 use clap::Parser;
 use commands::start::StartCommand;
 use rooch_types::error::RoochResult;
 
 use self::commands::clean::CleanCommand;
+use self::commands::shadow_fork::ShadowForkCommand;
 
 pub mod commands;
 
@@ -23,7 +24,8 @@ impl CommandAction<String> for Server {
     async fn execute(self) -> RoochResult<String> {
         match self.cmd {
             ServerCommand::Start(start) => start.execute_serialized().await,
-            ServerCommand::Clean(clean) => clean.execute().map(|_| "".to_owned()),
+            ServerCommand::Clean(clean) => clean.execute().map(format_status),
+            ServerCommand::ShadowFork(shadow_fork) => shadow_fork.execute_serialized().await,
         }
     }
 }
@@ -33,4 +35,5 @@ impl CommandAction<String> for Server {
 pub enum ServerCommand {
     Start(StartCommand),
     Clean(CleanCommand),
+    ShadowFork(ShadowForkCommand),
 }
@@ -1,7 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::cli_types::{TransactionOptions, WalletContextOptions};
+use crate::cli_types::{print_message, TransactionOptions, WalletContextOptions};
 use clap::Parser;
 use moveos_types::module_binding::MoveFunctionCaller;
 use rooch_key::key_derive::verify_password;
@@ -38,7 +38,10 @@ impl CreateCommand {
     pub async fn execute(self) -> RoochResult<SessionKey> {
         let mut context = self.context_options.build()?;
 
-        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let sender: RoochAddress = context
+            .resolve_address_with_name_service(self.tx_options.sender)
+            .await?
+            .into();
 
         let session_auth_key = if context.keystore.get_if_password_is_empty() {
             context.keystore.generate_session_key(&sender, None)?
@@ -67,7 +70,9 @@ impl CreateCommand {
                 self.max_inactive_interval,
             );
 
-        println!("Generated new session key {session_auth_key} for address [{sender}]",);
+        print_message(format!(
+            "Generated new session key {session_auth_key} for address [{sender}]"
+        ));
 
         let result = if context.keystore.get_if_password_is_empty() {
             context.sign_and_execute(sender, action, None).await?
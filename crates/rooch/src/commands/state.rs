@@ -2,14 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::utils::read_bytes_input;
 use async_trait::async_trait;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use move_cli::Move;
+use move_core_types::language_storage::TypeTag;
 use moveos_types::access_path::AccessPath;
-use rooch_rpc_api::jsonrpc_types::StateView;
+use moveos_types::value_decoder::{EmptyModuleResolver, ValueDecoder};
+use rooch_rpc_api::jsonrpc_types::{AnnotatedMoveValueView, StateView};
 use rooch_types::error::{RoochError, RoochResult};
+use std::collections::BTreeMap;
+use std::io::stderr;
 
 /// Get states by accessPath
 #[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
 pub struct StateCommand {
     //TODO access path should support named address?
     /// /object/$object_id1[,$object_id2]
@@ -17,15 +24,48 @@ pub struct StateCommand {
     /// /module/$account_address/$module_name1[,$module_name2]
     /// /table/$table_handle/$key1[,$key2]
     #[clap(long = "access-path", short = 'a')]
-    pub access_path: AccessPath,
+    pub access_path: Option<AccessPath>,
 
     /// RPC client options.
     #[clap(flatten)]
     context_options: WalletContextOptions,
+
+    #[clap(subcommand)]
+    cmd: Option<StateSubCommand>,
+}
+
+#[derive(Subcommand)]
+pub enum StateSubCommand {
+    Decode(DecodeStateCommand),
+}
+
+#[async_trait]
+impl CommandAction<String> for StateCommand {
+    async fn execute(self) -> RoochResult<String> {
+        match (self.cmd, self.access_path) {
+            (Some(StateSubCommand::Decode(cmd)), _) => cmd.execute_serialized().await,
+            (None, Some(access_path)) => {
+                GetStatesCommand {
+                    access_path,
+                    context_options: self.context_options,
+                }
+                .execute_serialized()
+                .await
+            }
+            (None, None) => Err(RoochError::CommandArgumentError(
+                "The --access-path or a subcommand is required".to_owned(),
+            )),
+        }
+    }
+}
+
+struct GetStatesCommand {
+    access_path: AccessPath,
+    context_options: WalletContextOptions,
 }
 
 #[async_trait]
-impl CommandAction<Vec<Option<StateView>>> for StateCommand {
+impl CommandAction<Vec<Option<StateView>>> for GetStatesCommand {
     async fn execute(self) -> RoochResult<Vec<Option<StateView>>> {
         let client = self.context_options.build()?.get_client().await?;
 
@@ -37,3 +77,65 @@ impl CommandAction<Vec<Option<StateView>>> for StateCommand {
         Ok(resp)
     }
 }
+
+/// Decode the BCS bytes of a Move value of the type into JSON.
+/// The struct layouts are resolved from the modules of the local package if the package path is given,
+/// and then from the on-chain modules.
+#[derive(Parser)]
+pub struct DecodeStateCommand {
+    /// The type of the value, e.g. `u64`, `vector<u8>`, `0x3::account::Account`
+    #[clap(long = "type")]
+    type_tag: TypeTag,
+
+    /// The hex of the BCS bytes, or a file of the bytes in hex or binary
+    input: String,
+
+    /// Resolve the struct layouts from the local package build, the package path is required
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Named addresses for the local package
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    #[clap(long, value_parser=crate::utils::parse_map::<String, String>, default_value = "")]
+    named_addresses: BTreeMap<String, String>,
+
+    /// Do not resolve the struct layouts from the on-chain modules
+    #[clap(long)]
+    offline: bool,
+
+    /// RPC client options.
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<AnnotatedMoveValueView> for DecodeStateCommand {
+    async fn execute(self) -> RoochResult<AnnotatedMoveValueView> {
+        let bytes = read_bytes_input(&self.input)?;
+        let context = self.context_options.build()?;
+
+        let mut local_modules = vec![];
+        if let Some(package_path) = self.move_args.package_path {
+            let mut config = self.move_args.build_config;
+            config.additional_named_addresses =
+                context.parse_and_resolve_addresses(self.named_addresses)?;
+            let package = config
+                .compile_package_no_exit(&package_path, &mut stderr())
+                .map_err(|e| RoochError::MoveCompilationError(e.to_string()))?;
+            local_modules = package.all_modules_map().iter_modules_owned();
+        }
+
+        let value = if self.offline {
+            ValueDecoder::new(EmptyModuleResolver)
+                .with_modules(local_modules.iter())?
+                .decode(&self.type_tag, &bytes)?
+        } else {
+            let client = context.get_client().await?;
+            let decoder = ValueDecoder::new(&client).with_modules(local_modules.iter())?;
+            // The on-chain modules are fetched by blocking on the RPC client
+            tokio::task::block_in_place(|| decoder.decode(&self.type_tag, &bytes))?
+        };
+        Ok(value.into())
+    }
+}
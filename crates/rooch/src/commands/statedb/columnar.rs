@@ -0,0 +1,314 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The columnar tables of the state export, a table is written as a CSV or a Parquet file.
+//! The rows are buffered and written in batches, so a large table is not kept in memory.
+
+use anyhow::{bail, ensure, Result};
+use arrow_array::{
+    ArrayRef, BooleanArray, RecordBatch, StringArray, UInt16Array, UInt32Array, UInt64Array,
+    UInt8Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+const BATCH_SIZE: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Parquet,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Parquet => "parquet",
+        }
+    }
+}
+
+/// The column types, u128 and u256 are exported as decimal strings,
+/// because they are not supported by all the analytics engines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    U8,
+    U16,
+    U32,
+    U64,
+    Bool,
+    Utf8,
+}
+
+impl ColumnType {
+    fn data_type(&self) -> DataType {
+        match self {
+            ColumnType::U8 => DataType::UInt8,
+            ColumnType::U16 => DataType::UInt16,
+            ColumnType::U32 => DataType::UInt32,
+            ColumnType::U64 => DataType::UInt64,
+            ColumnType::Bool => DataType::Boolean,
+            ColumnType::Utf8 => DataType::Utf8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    pub column_type: ColumnType,
+}
+
+impl Column {
+    pub fn new(name: impl Into<String>, column_type: ColumnType) -> Self {
+        Self {
+            name: name.into(),
+            column_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    Bool(bool),
+    Utf8(String),
+    Null,
+}
+
+impl Cell {
+    fn matches(&self, column_type: ColumnType) -> bool {
+        matches!(
+            (self, column_type),
+            (Cell::Null, _)
+                | (Cell::U8(_), ColumnType::U8)
+                | (Cell::U16(_), ColumnType::U16)
+                | (Cell::U32(_), ColumnType::U32)
+                | (Cell::U64(_), ColumnType::U64)
+                | (Cell::Bool(_), ColumnType::Bool)
+                | (Cell::Utf8(_), ColumnType::Utf8)
+        )
+    }
+
+    fn to_csv_field(&self) -> String {
+        match self {
+            Cell::U8(v) => v.to_string(),
+            Cell::U16(v) => v.to_string(),
+            Cell::U32(v) => v.to_string(),
+            Cell::U64(v) => v.to_string(),
+            Cell::Bool(v) => v.to_string(),
+            Cell::Utf8(v) => v.clone(),
+            Cell::Null => String::new(),
+        }
+    }
+}
+
+enum Sink {
+    Csv(csv::Writer<File>),
+    Parquet(ArrowWriter<File>, SchemaRef),
+}
+
+pub struct TableWriter {
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+    rows_written: u64,
+    sink: Sink,
+}
+
+impl TableWriter {
+    pub fn create(path: &Path, format: ExportFormat, columns: Vec<Column>) -> Result<Self> {
+        let sink = match format {
+            ExportFormat::Csv => {
+                let mut writer = csv::Writer::from_path(path)?;
+                writer.write_record(columns.iter().map(|column| column.name.as_str()))?;
+                Sink::Csv(writer)
+            }
+            ExportFormat::Parquet => {
+                let schema: SchemaRef = Arc::new(Schema::new(
+                    columns
+                        .iter()
+                        .map(|column| {
+                            Field::new(&column.name, column.column_type.data_type(), true)
+                        })
+                        .collect::<Vec<_>>(),
+                ));
+                let props = WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build();
+                let writer =
+                    ArrowWriter::try_new(File::create(path)?, schema.clone(), Some(props))?;
+                Sink::Parquet(writer, schema)
+            }
+        };
+        Ok(Self {
+            columns,
+            rows: Vec::with_capacity(BATCH_SIZE),
+            rows_written: 0,
+            sink,
+        })
+    }
+
+    pub fn write_row(&mut self, row: Vec<Cell>) -> Result<()> {
+        ensure!(
+            row.len() == self.columns.len(),
+            "Expect {} cells, but got {}",
+            self.columns.len(),
+            row.len()
+        );
+        for (cell, column) in row.iter().zip(self.columns.iter()) {
+            if !cell.matches(column.column_type) {
+                bail!(
+                    "Cell {:?} does not match the type {:?} of column {}",
+                    cell,
+                    column.column_type,
+                    column.name
+                );
+            }
+        }
+        self.rows.push(row);
+        if self.rows.len() >= BATCH_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Flush the buffered rows and close the file, return the count of the rows in the table.
+    pub fn finish(mut self) -> Result<u64> {
+        self.flush()?;
+        match self.sink {
+            Sink::Csv(mut writer) => writer.flush()?,
+            Sink::Parquet(writer, _) => {
+                writer.close()?;
+            }
+        }
+        Ok(self.rows_written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+        let rows = std::mem::take(&mut self.rows);
+        match &mut self.sink {
+            Sink::Csv(writer) => {
+                for row in rows.iter() {
+                    writer.write_record(row.iter().map(Cell::to_csv_field))?;
+                }
+            }
+            Sink::Parquet(writer, schema) => {
+                let arrays = self
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(index, column)| build_array(column.column_type, &rows, index))
+                    .collect::<Vec<_>>();
+                writer.write(&RecordBatch::try_new(schema.clone(), arrays)?)?;
+            }
+        }
+        self.rows_written += rows.len() as u64;
+        Ok(())
+    }
+}
+
+fn build_array(column_type: ColumnType, rows: &[Vec<Cell>], index: usize) -> ArrayRef {
+    let cells = rows.iter().map(|row| &row[index]);
+    match column_type {
+        ColumnType::U8 => Arc::new(
+            cells
+                .map(|cell| match cell {
+                    Cell::U8(v) => Some(*v),
+                    _ => None,
+                })
+                .collect::<UInt8Array>(),
+        ),
+        ColumnType::U16 => Arc::new(
+            cells
+                .map(|cell| match cell {
+                    Cell::U16(v) => Some(*v),
+                    _ => None,
+                })
+                .collect::<UInt16Array>(),
+        ),
+        ColumnType::U32 => Arc::new(
+            cells
+                .map(|cell| match cell {
+                    Cell::U32(v) => Some(*v),
+                    _ => None,
+                })
+                .collect::<UInt32Array>(),
+        ),
+        ColumnType::U64 => Arc::new(
+            cells
+                .map(|cell| match cell {
+                    Cell::U64(v) => Some(*v),
+                    _ => None,
+                })
+                .collect::<UInt64Array>(),
+        ),
+        ColumnType::Bool => Arc::new(
+            cells
+                .map(|cell| match cell {
+                    Cell::Bool(v) => Some(*v),
+                    _ => None,
+                })
+                .collect::<BooleanArray>(),
+        ),
+        ColumnType::Utf8 => Arc::new(
+            cells
+                .map(|cell| match cell {
+                    Cell::Utf8(v) => Some(v.as_str()),
+                    _ => None,
+                })
+                .collect::<StringArray>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_csv_table() {
+        let path = std::env::temp_dir().join(format!("rooch_export_{}.csv", std::process::id()));
+        let mut writer = TableWriter::create(
+            &path,
+            ExportFormat::Csv,
+            vec![
+                Column::new("id", ColumnType::Utf8),
+                Column::new("seq", ColumnType::U64),
+                Column::new("frozen", ColumnType::Bool),
+            ],
+        )
+        .unwrap();
+        assert!(writer
+            .write_row(vec![Cell::U64(1), Cell::U64(1), Cell::Null])
+            .is_err());
+        assert!(writer.write_row(vec![Cell::Null]).is_err());
+        for seq in 0..(BATCH_SIZE as u64 + 1) {
+            writer
+                .write_row(vec![
+                    Cell::Utf8(format!("0x{}", seq)),
+                    Cell::U64(seq),
+                    Cell::Null,
+                ])
+                .unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), BATCH_SIZE as u64 + 1);
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), BATCH_SIZE + 2);
+        assert_eq!(lines[0], "id,seq,frozen");
+        assert_eq!(lines[1], "0x0,0,");
+    }
+}
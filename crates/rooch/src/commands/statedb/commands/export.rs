@@ -0,0 +1,461 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::statedb::columnar::{Cell, Column, ColumnType, ExportFormat, TableWriter};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use move_core_types::language_storage::StructTag;
+use move_resource_viewer::{AnnotatedMoveValue, MoveValueAnnotator};
+use moveos_store::event_store::EventStore;
+use moveos_store::state_store::statedb::StateDBStore;
+use moveos_store::transaction_store::TransactionStore as MoveOSTransactionStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::{sha3_256_of_bcs, H256};
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{MoveStructType, PlaceholderStruct, State};
+use moveos_types::state_resolver::MoveOSResolverProxy;
+use rooch_config::store_config::StoreConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_rpc_api::jsonrpc_types::{AnnotatedMoveStructView, AnnotatedMoveValueView};
+use rooch_rpc_server::init_storage;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::coin_store::CoinStore;
+use serde::{Deserialize, Serialize};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const LIST_BATCH_SIZE: usize = 1000;
+const ORDER_BATCH_SIZE: u64 = 100;
+const MAX_FILE_STEM_LENGTH: usize = 120;
+
+/// Export the decoded objects, the coin balances and the events in the store into columnar files,
+/// for the offline analytics in DuckDB or Spark.
+/// The objects are grouped by type, one file per type under `objects/`, the columns are derived from the type layout:
+/// `id`, `owner`, `flag`, and a `value_<field>` column for every field of the object value.
+/// The integer and bool fields keep their types, u128 and u256 are decimal strings, and the nested values are JSON.
+/// With `--from-order`, only the objects changed or removed since the tx and the events of the txs in the range are exported.
+/// Only the objects in the global object storage are exported, the fields of the objects are not.
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// The dir to write the exported files, it is created if it does not exist
+    #[clap(long, short = 'o')]
+    pub output: PathBuf,
+
+    /// The format of the exported files
+    #[clap(long, value_enum, default_value = "csv")]
+    pub format: ExportFormat,
+
+    /// Export incrementally from this tx order (inclusive), the states are diffed against the state before the tx
+    #[clap(long)]
+    pub from_order: Option<u64>,
+
+    /// Export the state after this tx order (inclusive), default is the latest tx
+    #[clap(long)]
+    pub to_order: Option<u64>,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedFile {
+    pub path: PathBuf,
+    /// The object type of the rows, only for the object files
+    pub object_type: Option<String>,
+    pub rows: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOutput {
+    pub from_order: Option<u64>,
+    pub to_order: u64,
+    pub state_root: H256,
+    pub files: Vec<ExportedFile>,
+}
+
+#[async_trait]
+impl CommandAction<ExportOutput> for ExportCommand {
+    async fn execute(self) -> RoochResult<ExportOutput> {
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut store_config = StoreConfig::default();
+        store_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let (moveos_store, rooch_store) = init_storage(&store_config)?;
+
+        let last_order = rooch_store
+            .get_sequencer_order()?
+            .map(|order| order.last_order)
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError("There is no transaction in the store".to_owned())
+            })?;
+        let to_order = self.to_order.unwrap_or(last_order);
+        if to_order > last_order {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The to order {} is greater than the last order {}",
+                to_order, last_order
+            )));
+        }
+        if let Some(from_order) = self.from_order {
+            if from_order > to_order {
+                return Err(RoochError::CommandArgumentError(format!(
+                    "The from order {} is greater than the to order {}",
+                    from_order, to_order
+                )));
+            }
+        }
+
+        let state_root = state_root_at(&moveos_store, &rooch_store, to_order)?;
+        let base_state_root = match self.from_order {
+            Some(from_order) if from_order > 0 => {
+                Some(state_root_at(&moveos_store, &rooch_store, from_order - 1)?)
+            }
+            _ => None,
+        };
+
+        std::fs::create_dir_all(self.output.join("objects"))?;
+        let exporter = Exporter {
+            moveos_store,
+            rooch_store,
+            output: self.output,
+            format: self.format,
+        };
+        let mut files = exporter.export_objects(state_root, base_state_root)?;
+        files.push(exporter.export_events(self.from_order.unwrap_or(0), to_order)?);
+
+        Ok(ExportOutput {
+            from_order: self.from_order,
+            to_order,
+            state_root,
+            files,
+        })
+    }
+}
+
+/// Return the state root after the execution of the tx
+pub(crate) fn state_root_at(
+    moveos_store: &MoveOSStore,
+    rooch_store: &RoochStore,
+    tx_order: u64,
+) -> Result<H256> {
+    let tx_hash = rooch_store
+        .get_tx_sequence_info_mapping_by_order(vec![tx_order])?
+        .pop()
+        .flatten()
+        .ok_or_else(|| anyhow::anyhow!("Can not find the transaction of order {}", tx_order))?
+        .tx_hash;
+    let execution_info = moveos_store
+        .get_tx_execution_info(tx_hash)?
+        .ok_or_else(|| {
+            anyhow::anyhow!("Can not find the execution info of transaction {}", tx_hash)
+        })?;
+    Ok(execution_info.state_root)
+}
+
+struct Exporter {
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    output: PathBuf,
+    format: ExportFormat,
+}
+
+impl Exporter {
+    fn statedb(&self, state_root: H256) -> StateDBStore {
+        StateDBStore::new_with_root(
+            self.moveos_store.get_state_store().node_store.clone(),
+            Some(state_root),
+        )
+    }
+
+    fn file_path(&self, name: &str) -> PathBuf {
+        self.output
+            .join(format!("{}.{}", name, self.format.extension()))
+    }
+
+    fn export_objects(
+        &self,
+        state_root: H256,
+        base_state_root: Option<H256>,
+    ) -> Result<Vec<ExportedFile>> {
+        let statedb = self.statedb(state_root);
+        let base_statedb = base_state_root.map(|root| self.statedb(root));
+        let resolver = MoveOSResolverProxy(self.moveos_store.clone());
+        let annotator = MoveValueAnnotator::new(&resolver);
+
+        let balances_path = self.file_path("balances");
+        let mut balances = TableWriter::create(
+            &balances_path,
+            self.format,
+            vec![
+                Column::new("coin_store_id", ColumnType::Utf8),
+                Column::new("owner", ColumnType::Utf8),
+                Column::new("coin_type", ColumnType::Utf8),
+                Column::new("balance", ColumnType::Utf8),
+                Column::new("frozen", ColumnType::Bool),
+            ],
+        )?;
+        let mut objects: BTreeMap<StructTag, (PathBuf, TableWriter)> = BTreeMap::new();
+
+        for_each_state(&statedb, |key, state| {
+            if let Some(base_statedb) = &base_statedb {
+                let object_id = ObjectID::from_bytes(key)?;
+                if base_statedb.get(object_id)?.as_ref() == Some(state) {
+                    return Ok(());
+                }
+            }
+            let raw_object = match state.as_raw_object() {
+                Ok(raw_object) => raw_object,
+                Err(e) => {
+                    tracing::warn!("Skip the state of type {}: {:?}", state.value_type, e);
+                    return Ok(());
+                }
+            };
+            let struct_tag = raw_object.value.struct_tag;
+            let fields = match annotator.view_resource(&struct_tag, &raw_object.value.value) {
+                Ok(annotated) => annotated.value,
+                Err(e) => {
+                    tracing::warn!(
+                        "Skip the object {} of type {}: {:?}",
+                        raw_object.id,
+                        struct_tag,
+                        e
+                    );
+                    return Ok(());
+                }
+            };
+            let mut row = vec![
+                Cell::Utf8(raw_object.id.to_string()),
+                Cell::Utf8(raw_object.owner.to_hex_literal()),
+                Cell::U8(raw_object.flag),
+            ];
+            let mut columns = vec![
+                Column::new("id", ColumnType::Utf8),
+                Column::new("owner", ColumnType::Utf8),
+                Column::new("flag", ColumnType::U8),
+            ];
+            for (name, value) in fields {
+                let (column_type, cell) = field_cell(value)?;
+                columns.push(Column::new(format!("value_{}", name), column_type));
+                row.push(cell);
+            }
+
+            if is_coin_store(&struct_tag) {
+                let coin_store = state
+                    .as_object_uncheck::<CoinStore<PlaceholderStruct>>()?
+                    .value;
+                balances.write_row(vec![
+                    Cell::Utf8(raw_object.id.to_string()),
+                    Cell::Utf8(raw_object.owner.to_hex_literal()),
+                    Cell::Utf8(coin_store.coin_type()),
+                    Cell::Utf8(coin_store.balance().to_string()),
+                    Cell::Bool(coin_store.frozen()),
+                ])?;
+            }
+
+            let (_, writer) = match objects.entry(struct_tag) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let path = self.file_path(&format!("objects/{}", file_stem(entry.key())?));
+                    let writer = TableWriter::create(&path, self.format, columns)?;
+                    entry.insert((path, writer))
+                }
+            };
+            writer.write_row(row)
+        })?;
+
+        let mut files = vec![ExportedFile {
+            path: balances_path,
+            object_type: None,
+            rows: balances.finish()?,
+        }];
+        for (struct_tag, (path, writer)) in objects {
+            files.push(ExportedFile {
+                path,
+                object_type: Some(struct_tag.to_canonical_string()),
+                rows: writer.finish()?,
+            });
+        }
+        if let Some(base_statedb) = base_statedb {
+            files.push(self.export_removed_objects(&statedb, &base_statedb)?);
+        }
+        Ok(files)
+    }
+
+    /// Export the objects in the base state which are removed in the state
+    fn export_removed_objects(
+        &self,
+        statedb: &StateDBStore,
+        base_statedb: &StateDBStore,
+    ) -> Result<ExportedFile> {
+        let path = self.file_path("removed_objects");
+        let mut writer = TableWriter::create(
+            &path,
+            self.format,
+            vec![
+                Column::new("id", ColumnType::Utf8),
+                Column::new("object_type", ColumnType::Utf8),
+            ],
+        )?;
+        for_each_state(base_statedb, |key, state| {
+            let object_id = ObjectID::from_bytes(key)?;
+            if statedb.get(object_id)?.is_some() {
+                return Ok(());
+            }
+            let object_type = state
+                .get_object_struct_tag()
+                .map(|struct_tag| Cell::Utf8(struct_tag.to_canonical_string()))
+                .unwrap_or(Cell::Null);
+            writer.write_row(vec![Cell::Utf8(object_id.to_string()), object_type])
+        })?;
+        Ok(ExportedFile {
+            path,
+            object_type: None,
+            rows: writer.finish()?,
+        })
+    }
+
+    fn export_events(&self, from_order: u64, to_order: u64) -> Result<ExportedFile> {
+        let resolver = MoveOSResolverProxy(self.moveos_store.clone());
+        let annotator = MoveValueAnnotator::new(&resolver);
+        let path = self.file_path("events");
+        let mut writer = TableWriter::create(
+            &path,
+            self.format,
+            vec![
+                Column::new("tx_order", ColumnType::U64),
+                Column::new("tx_hash", ColumnType::Utf8),
+                Column::new("event_handle_id", ColumnType::Utf8),
+                Column::new("event_seq", ColumnType::U64),
+                Column::new("event_index", ColumnType::U64),
+                Column::new("event_type", ColumnType::Utf8),
+                Column::new("event_data", ColumnType::Utf8),
+            ],
+        )?;
+
+        let mut start = from_order;
+        while start <= to_order {
+            let end = to_order.min(start.saturating_add(ORDER_BATCH_SIZE - 1));
+            let mappings = self
+                .rooch_store
+                .get_tx_sequence_info_mapping_by_order((start..=end).collect())?;
+            for mapping in mappings.into_iter().flatten() {
+                let event_ids = self
+                    .moveos_store
+                    .get_tx_event_ids(mapping.tx_hash)?
+                    .unwrap_or_default();
+                if event_ids.is_empty() {
+                    continue;
+                }
+                for event in self
+                    .moveos_store
+                    .multi_get_events(event_ids)?
+                    .into_iter()
+                    .flatten()
+                {
+                    let event_data =
+                        match annotator.view_resource(&event.event_type, &event.event_data) {
+                            Ok(annotated) => Cell::Utf8(serde_json::to_string(
+                                &AnnotatedMoveStructView::from(annotated),
+                            )?),
+                            Err(e) => {
+                                tracing::warn!(
+                                    "Can not decode the event of type {}: {:?}",
+                                    event.event_type,
+                                    e
+                                );
+                                Cell::Null
+                            }
+                        };
+                    writer.write_row(vec![
+                        Cell::U64(mapping.tx_order),
+                        Cell::Utf8(format!("{:?}", mapping.tx_hash)),
+                        Cell::Utf8(event.event_id.event_handle_id.to_string()),
+                        Cell::U64(event.event_id.event_seq),
+                        Cell::U64(event.event_index),
+                        Cell::Utf8(event.event_type.to_canonical_string()),
+                        event_data,
+                    ])?;
+                }
+            }
+            if end == u64::MAX {
+                break;
+            }
+            start = end + 1;
+        }
+        Ok(ExportedFile {
+            path,
+            object_type: None,
+            rows: writer.finish()?,
+        })
+    }
+}
+
+/// Iterate all the states in the global object storage of the statedb
+fn for_each_state<F>(statedb: &StateDBStore, mut f: F) -> Result<()>
+where
+    F: FnMut(&[u8], &State) -> Result<()>,
+{
+    let mut cursor = None;
+    loop {
+        let states = statedb.list(cursor, LIST_BATCH_SIZE)?;
+        for (key, state) in states.iter() {
+            f(key, state)?;
+        }
+        if states.len() < LIST_BATCH_SIZE {
+            return Ok(());
+        }
+        cursor = states.last().map(|(key, _)| key.clone());
+    }
+}
+
+/// The primitive fields keep their column types, the others are JSON or strings
+fn field_cell(value: AnnotatedMoveValue) -> Result<(ColumnType, Cell)> {
+    Ok(match value {
+        AnnotatedMoveValue::U8(v) => (ColumnType::U8, Cell::U8(v)),
+        AnnotatedMoveValue::U16(v) => (ColumnType::U16, Cell::U16(v)),
+        AnnotatedMoveValue::U32(v) => (ColumnType::U32, Cell::U32(v)),
+        AnnotatedMoveValue::U64(v) => (ColumnType::U64, Cell::U64(v)),
+        AnnotatedMoveValue::U128(v) => (ColumnType::Utf8, Cell::Utf8(v.to_string())),
+        AnnotatedMoveValue::U256(v) => (ColumnType::Utf8, Cell::Utf8(v.to_string())),
+        AnnotatedMoveValue::Bool(v) => (ColumnType::Bool, Cell::Bool(v)),
+        AnnotatedMoveValue::Address(v) => (ColumnType::Utf8, Cell::Utf8(v.to_hex_literal())),
+        value => (
+            ColumnType::Utf8,
+            Cell::Utf8(serde_json::to_string(&AnnotatedMoveValueView::from(value))?),
+        ),
+    })
+}
+
+fn is_coin_store(struct_tag: &StructTag) -> bool {
+    struct_tag.address == CoinStore::<PlaceholderStruct>::ADDRESS
+        && struct_tag.module.as_ident_str() == CoinStore::<PlaceholderStruct>::MODULE_NAME
+        && struct_tag.name.as_ident_str() == CoinStore::<PlaceholderStruct>::STRUCT_NAME
+}
+
+/// The file name of the object type, such as `0x3.coin_store.CoinStore`.
+/// The generic or long type names are truncated and suffixed with the hash of the type to keep them unique.
+fn file_stem(struct_tag: &StructTag) -> Result<String> {
+    let type_name = struct_tag.to_canonical_string();
+    let mut stem = type_name
+        .replace("::", ".")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if !struct_tag.type_params.is_empty() || stem.len() > MAX_FILE_STEM_LENGTH {
+        let hash = sha3_256_of_bcs(&type_name)?;
+        stem.truncate(MAX_FILE_STEM_LENGTH);
+        stem.push_str(&format!("_{}", hex::encode(&hash.as_bytes()[..8])));
+    }
+    Ok(stem)
+}
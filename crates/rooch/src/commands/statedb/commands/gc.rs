@@ -0,0 +1,71 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::statedb::commands::export::state_root_at;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_store::config_store::ConfigStore;
+use moveos_store::state_store::gc::{gc_state_nodes, StateGCReport};
+use rooch_config::store_config::StoreConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_rpc_server::init_storage;
+use rooch_store::meta_store::MetaStore;
+use rooch_types::error::{RoochError, RoochResult};
+use std::sync::Arc;
+
+/// Remove the state nodes older than the pruning window, such as the tombstones of the deleted objects
+/// and the child fields of the deleted tables, to reclaim the disk space.
+/// The states after the last `--retain-txs` transactions are kept, the older states can not be queried after the GC.
+/// The node must be stopped before running the GC.
+#[derive(Debug, Parser)]
+pub struct GCCommand {
+    /// The count of the latest transactions whose states are kept
+    #[clap(long, default_value = "1000")]
+    pub retain_txs: u64,
+
+    /// Only count the nodes to remove, do not remove them
+    #[clap(long)]
+    pub dry_run: bool,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[async_trait]
+impl CommandAction<StateGCReport> for GCCommand {
+    async fn execute(self) -> RoochResult<StateGCReport> {
+        if self.retain_txs == 0 {
+            return Err(RoochError::CommandArgumentError(
+                "The retain txs should be greater than 0".to_owned(),
+            ));
+        }
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut store_config = StoreConfig::default();
+        store_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let (moveos_store, rooch_store) = init_storage(&store_config)?;
+
+        let last_order = rooch_store
+            .get_sequencer_order()?
+            .map(|order| order.last_order)
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError("There is no transaction in the store".to_owned())
+            })?;
+        let from_order = last_order.saturating_sub(self.retain_txs - 1);
+        let mut retained_roots = (from_order..=last_order)
+            .map(|order| state_root_at(&moveos_store, &rooch_store, order))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        // The node starts from the state root in the startup info
+        if let Some(startup_info) = moveos_store.get_startup_info()? {
+            retained_roots.push(startup_info.state_root_hash);
+        }
+        retained_roots.sort();
+        retained_roots.dedup();
+
+        Ok(gc_state_nodes(
+            moveos_store.get_state_node_store(),
+            &retained_roots,
+            self.dry_run,
+        )?)
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::export::ExportCommand;
+use commands::gc::GCCommand;
+use rooch_types::error::RoochResult;
+
+pub mod columnar;
+pub mod commands;
+
+/// Tools for the state db of a stopped node
+#[derive(Parser)]
+pub struct StateDB {
+    #[clap(subcommand)]
+    cmd: StateDBCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for StateDB {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            StateDBCommand::Export(export) => export.execute_serialized().await,
+            StateDBCommand::GC(gc) => gc.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "statedb")]
+pub enum StateDBCommand {
+    Export(ExportCommand),
+    GC(GCCommand),
+}
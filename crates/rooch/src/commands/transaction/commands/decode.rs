@@ -0,0 +1,303 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::utils::read_bytes_input;
+use anyhow::{anyhow, bail, ensure, Result};
+use async_trait::async_trait;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue};
+use moveos_types::move_std::ascii::MoveAsciiString;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::context::Context;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{MoveStructState, MoveStructType};
+use moveos_types::transaction::{FunctionCall, GasBudget, MoveAction, TxDependencyHints};
+use rooch_rpc_api::jsonrpc_types::{
+    AnnotatedMoveValueView, AuthenticatorView, BytesView, H256View, MoveActionTypeView,
+    MoveActionView, SpecificStructView, StrView,
+};
+use rooch_rpc_client::Client;
+use rooch_types::error::RoochResult;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::AbstractTransaction;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Decode the raw BCS bytes of a signed or unsigned Rooch transaction into JSON,
+/// the function arguments are decoded with the ABI of the on-chain module.
+#[derive(Debug, clap::Parser)]
+pub struct DecodeCommand {
+    /// The hex of the transaction bytes, or a file of the transaction bytes in hex or binary
+    input: String,
+
+    /// Do not fetch the on-chain ABI to decode the function arguments
+    #[clap(long)]
+    no_abi: bool,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DecodedArgView {
+    /// The parameter type in the function ABI
+    pub type_tag: String,
+    /// None if the value of the type can not be decoded without the struct layout
+    pub value: Option<AnnotatedMoveValueView>,
+    pub raw: BytesView,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DecodedTransactionView {
+    /// Whether the bytes are a signed transaction or the unsigned transaction data
+    pub signed: bool,
+    pub tx_hash: H256View,
+    pub sender: String,
+    pub sequence_number: u64,
+    pub chain_id: u64,
+    pub max_gas_amount: u64,
+    pub action_type: MoveActionTypeView,
+    pub action: MoveActionView,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_hints: Option<TxDependencyHints>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_budget: Option<GasBudget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_args: Option<Vec<DecodedArgView>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authenticator: Option<AuthenticatorView>,
+}
+
+#[async_trait]
+impl CommandAction<DecodedTransactionView> for DecodeCommand {
+    async fn execute(self) -> RoochResult<DecodedTransactionView> {
+        let bytes = read_bytes_input(&self.input)?;
+        let (data, authenticator) = match RoochTransaction::decode(&bytes) {
+            Ok(tx) => {
+                let authenticator = tx.authenticator_info()?.authenticator;
+                let mut data = RoochTransactionData::new(
+                    tx.sender(),
+                    tx.sequence_number(),
+                    tx.chain_id(),
+                    tx.max_gas_amount(),
+                    tx.action().clone(),
+                );
+                data.dependency_hints = tx.dependency_hints().cloned();
+                data.gas_budget = tx.gas_budget();
+                (data, Some(authenticator))
+            }
+            Err(_) => {
+                let data = bcs::from_bytes::<RoochTransactionData>(&bytes).map_err(|e| {
+                    anyhow!(
+                        "The bytes are neither a signed transaction nor the transaction data: {}",
+                        e
+                    )
+                })?;
+                (data, None)
+            }
+        };
+
+        let decoded_args = match (&data.action, self.no_abi) {
+            (MoveAction::Function(call), false) => {
+                let client = self.context_options.build()?.get_client().await?;
+                Some(decode_args(&client, call).await?)
+            }
+            _ => None,
+        };
+
+        Ok(DecodedTransactionView {
+            signed: authenticator.is_some(),
+            tx_hash: data.hash().into(),
+            sender: data.sender.to_string(),
+            sequence_number: data.sequence_number,
+            chain_id: data.chain_id,
+            max_gas_amount: data.max_gas_amount,
+            action_type: data.action.clone().into(),
+            action: data.action.into(),
+            dependency_hints: data.dependency_hints,
+            gas_budget: data.gas_budget,
+            decoded_args,
+            authenticator: authenticator.map(Into::into),
+        })
+    }
+}
+
+async fn decode_args(client: &Client, call: &FunctionCall) -> Result<Vec<DecodedArgView>> {
+    let module_id = &call.function_id.module_id;
+    let function_name = call.function_id.function_name.as_str();
+    let abi = client
+        .rooch
+        .get_module_abi(module_id.clone())
+        .await?
+        .ok_or_else(|| anyhow!("The module {} does not exist on-chain", module_id))?;
+    let function = abi
+        .functions
+        .iter()
+        .find(|function| function.name == function_name)
+        .ok_or_else(|| anyhow!("The function {} does not exist", call.function_id))?;
+    let param_types = function
+        .params
+        .iter()
+        .map(|param| arg_type(param, &call.ty_args))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    ensure!(
+        param_types.len() == call.args.len(),
+        "The function {} expects {} arguments, but got {}",
+        call.function_id,
+        param_types.len(),
+        call.args.len()
+    );
+    Ok(param_types
+        .into_iter()
+        .zip(call.args.iter())
+        .map(|(type_tag, arg)| DecodedArgView {
+            type_tag: type_tag.to_string(),
+            value: decode_arg(&type_tag, arg).ok(),
+            raw: StrView(arg.clone()),
+        })
+        .collect())
+}
+
+/// The type of the argument passed by the transaction for the ABI parameter,
+/// None if the parameter is the signer or the Context which is provided by the VM.
+fn arg_type(param: &str, ty_args: &[TypeTag]) -> Result<Option<TypeTag>> {
+    let param = param.trim_start_matches("&mut ").trim_start_matches('&');
+    if param == "signer" {
+        return Ok(None);
+    }
+    let type_tag = TypeTag::from_str(&substitute_type_params(param, ty_args)?)?;
+    match &type_tag {
+        TypeTag::Struct(struct_tag) if Context::struct_tag_match(struct_tag) => Ok(None),
+        _ => Ok(Some(type_tag)),
+    }
+}
+
+/// Substitute the type parameters `T0`, `T1`... in the ABI type with the type arguments
+fn substitute_type_params(ty: &str, ty_args: &[TypeTag]) -> Result<String> {
+    let substitute = |token: &str| -> Result<String> {
+        match token
+            .strip_prefix('T')
+            .and_then(|i| i.parse::<usize>().ok())
+        {
+            Some(index) => ty_args
+                .get(index)
+                .map(|ty_arg| ty_arg.to_string())
+                .ok_or_else(|| anyhow!("The type argument T{} is missing", index)),
+            None => Ok(token.to_owned()),
+        }
+    };
+    let mut result = String::with_capacity(ty.len());
+    let mut token = String::new();
+    for c in ty.chars() {
+        if matches!(c, '<' | '>' | ',' | ' ') {
+            result.push_str(&substitute(&token)?);
+            token.clear();
+            result.push(c);
+        } else {
+            token.push(c);
+        }
+    }
+    result.push_str(&substitute(&token)?);
+    Ok(result)
+}
+
+/// The `Object<T>` argument is passed by the ObjectID
+fn is_object_id(type_tag: &TypeTag) -> bool {
+    match type_tag {
+        TypeTag::Struct(struct_tag) => {
+            ObjectID::struct_tag_match(struct_tag)
+                || (struct_tag.address == ObjectID::ADDRESS
+                    && struct_tag.module.as_ident_str() == ObjectID::MODULE_NAME
+                    && struct_tag.name.as_str() == "Object")
+        }
+        _ => false,
+    }
+}
+
+/// The layouts of the argument types which can be decoded without resolving the modules
+fn arg_layout(type_tag: &TypeTag) -> Result<MoveTypeLayout> {
+    Ok(match type_tag {
+        TypeTag::Bool => MoveTypeLayout::Bool,
+        TypeTag::U8 => MoveTypeLayout::U8,
+        TypeTag::U16 => MoveTypeLayout::U16,
+        TypeTag::U32 => MoveTypeLayout::U32,
+        TypeTag::U64 => MoveTypeLayout::U64,
+        TypeTag::U128 => MoveTypeLayout::U128,
+        TypeTag::U256 => MoveTypeLayout::U256,
+        TypeTag::Address => MoveTypeLayout::Address,
+        TypeTag::Vector(elem) => MoveTypeLayout::Vector(Box::new(arg_layout(elem)?)),
+        TypeTag::Struct(struct_tag)
+            if MoveString::struct_tag_match(struct_tag)
+                || MoveAsciiString::struct_tag_match(struct_tag) =>
+        {
+            MoveTypeLayout::Struct(MoveString::struct_layout())
+        }
+        _ if is_object_id(type_tag) => {
+            MoveTypeLayout::Struct(MoveStructLayout::new(vec![MoveTypeLayout::Address]))
+        }
+        _ => bail!("The argument of type {} can not be decoded", type_tag),
+    })
+}
+
+fn decode_arg(type_tag: &TypeTag, arg: &[u8]) -> Result<AnnotatedMoveValueView> {
+    let value = MoveValue::simple_deserialize(arg, &arg_layout(type_tag)?)?;
+    arg_value_view(type_tag, value)
+}
+
+fn arg_value_view(type_tag: &TypeTag, value: MoveValue) -> Result<AnnotatedMoveValueView> {
+    Ok(match (type_tag, value) {
+        (_, MoveValue::Bool(b)) => AnnotatedMoveValueView::Bool(b),
+        (_, MoveValue::U8(u)) => AnnotatedMoveValueView::U8(u),
+        (_, MoveValue::U16(u)) => AnnotatedMoveValueView::U16(u),
+        (_, MoveValue::U32(u)) => AnnotatedMoveValueView::U32(u),
+        (_, MoveValue::U64(u)) => AnnotatedMoveValueView::U64(StrView(u)),
+        (_, MoveValue::U128(u)) => AnnotatedMoveValueView::U128(StrView(u)),
+        (_, MoveValue::U256(u)) => AnnotatedMoveValueView::U256(StrView(u)),
+        (_, MoveValue::Address(addr)) => AnnotatedMoveValueView::Address(StrView(addr)),
+        (TypeTag::Vector(elem), MoveValue::Vector(values)) => {
+            if matches!(elem.as_ref(), TypeTag::U8) {
+                AnnotatedMoveValueView::Bytes(StrView(bytes_of(values)?))
+            } else {
+                AnnotatedMoveValueView::Vector(
+                    values
+                        .into_iter()
+                        .map(|value| arg_value_view(elem, value))
+                        .collect::<Result<Vec<_>>>()?,
+                )
+            }
+        }
+        (TypeTag::Struct(struct_tag), MoveValue::Struct(MoveStruct::Runtime(mut fields)))
+            if fields.len() == 1 =>
+        {
+            let field = fields.pop().expect("the struct should have one field");
+            let specific_struct = match field {
+                MoveValue::Address(addr) => SpecificStructView::ObjectID(ObjectID::from(addr)),
+                MoveValue::Vector(values) => {
+                    let s = String::from_utf8(bytes_of(values)?)?;
+                    if MoveAsciiString::struct_tag_match(struct_tag) {
+                        SpecificStructView::MoveAsciiString(MoveAsciiString::from_str(&s)?)
+                    } else {
+                        SpecificStructView::MoveString(MoveString::from_str(&s)?)
+                    }
+                }
+                field => bail!("Unexpected field {:?} of {}", field, struct_tag),
+            };
+            AnnotatedMoveValueView::SpecificStruct(specific_struct)
+        }
+        (type_tag, value) => bail!("The value {:?} does not match the type {}", value, type_tag),
+    })
+}
+
+fn bytes_of(values: Vec<MoveValue>) -> Result<Vec<u8>> {
+    values
+        .into_iter()
+        .map(|value| match value {
+            MoveValue::U8(u) => Ok(u),
+            _ => bail!("Expect u8 in the vector<u8>"),
+        })
+        .collect()
+}
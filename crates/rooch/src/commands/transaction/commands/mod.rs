@@ -1,5 +1,6 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod decode;
 pub mod get_transactions_by_hash;
 pub mod get_transactions_by_order;
@@ -0,0 +1,206 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use anyhow::{anyhow, bail, ensure, Result};
+use async_trait::async_trait;
+use clap::Parser;
+use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use fastcrypto::traits::{ToFromBytes, VerifyingKey};
+use rooch_types::error::RoochResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_RELEASE_MANIFEST_URL: &str =
+    "https://github.com/rooch-network/rooch/releases/latest/download/manifest.json";
+
+/// The hex encoded Ed25519 public keys of the maintainers, separated by comma.
+/// They are embedded by the release build, the binary signed by any of them is trusted.
+const MAINTAINER_PUBLIC_KEYS: Option<&str> = option_env!("ROOCH_RELEASE_PUBLIC_KEYS");
+
+/// The release manifest published with every release
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseManifest {
+    pub version: String,
+    /// The binaries keyed by the target, such as `linux-x86_64` and `macos-aarch64`
+    pub binaries: BTreeMap<String, ReleaseBinary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseBinary {
+    pub url: String,
+    /// The hex encoded Ed25519 signature of the binary by a maintainer key
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateOutput {
+    pub current_version: String,
+    pub latest_version: String,
+    pub updated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+/// Update the rooch binary to the latest release.
+/// The binary is verified by the detached signature against the maintainer keys embedded in the current binary,
+/// and then the current executable is replaced atomically.
+#[derive(Debug, Parser)]
+pub struct UpdateCommand {
+    /// The URL of the release manifest
+    #[clap(long, env = "ROOCH_RELEASE_MANIFEST_URL", default_value = DEFAULT_RELEASE_MANIFEST_URL)]
+    manifest_url: String,
+
+    /// Only check the latest version, do not update
+    #[clap(long)]
+    check: bool,
+
+    /// Update even if the latest version is not newer than the current version
+    #[clap(long)]
+    force: bool,
+}
+
+#[async_trait]
+impl CommandAction<UpdateOutput> for UpdateCommand {
+    async fn execute(self) -> RoochResult<UpdateOutput> {
+        let current_version = env!("CARGO_PKG_VERSION").to_owned();
+        let client = reqwest::Client::new();
+        let manifest: ReleaseManifest = client
+            .get(&self.manifest_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| anyhow!("Failed to fetch the release manifest: {}", e))?
+            .json()
+            .await
+            .map_err(|e| anyhow!("Invalid release manifest: {}", e))?;
+        let mut output = UpdateOutput {
+            current_version: current_version.clone(),
+            latest_version: manifest.version.clone(),
+            updated: false,
+            path: None,
+        };
+        let newer = is_newer_version(&manifest.version, &current_version)?;
+        if self.check || (!newer && !self.force) {
+            return Ok(output);
+        }
+
+        let target = current_target();
+        let release_binary = manifest.binaries.get(&target).ok_or_else(|| {
+            anyhow!(
+                "The release {} has no binary for the target {}",
+                manifest.version,
+                target
+            )
+        })?;
+        let binary = client
+            .get(&release_binary.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| anyhow!("Failed to download the binary: {}", e))?
+            .bytes()
+            .await
+            .map_err(|e| anyhow!("Failed to download the binary: {}", e))?;
+        verify_signature(&binary, &release_binary.signature, &maintainer_keys()?)?;
+
+        let path = std::env::current_exe()?;
+        replace_executable(&path, &binary)?;
+        output.updated = true;
+        output.path = Some(path);
+        Ok(output)
+    }
+}
+
+fn current_target() -> String {
+    format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH)
+}
+
+fn parse_version(version: &str) -> Result<Vec<u64>> {
+    version
+        .trim_start_matches('v')
+        .split(['.', '-'])
+        .take(3)
+        .map(|part| {
+            part.parse::<u64>()
+                .map_err(|_| anyhow!("Invalid version {}", version))
+        })
+        .collect()
+}
+
+fn is_newer_version(latest: &str, current: &str) -> Result<bool> {
+    Ok(parse_version(latest)? > parse_version(current)?)
+}
+
+fn maintainer_keys() -> Result<Vec<Ed25519PublicKey>> {
+    let keys = MAINTAINER_PUBLIC_KEYS
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| {
+            let bytes = hex::decode(key.trim_start_matches("0x"))?;
+            Ed25519PublicKey::from_bytes(&bytes)
+                .map_err(|e| anyhow!("Invalid maintainer key {}: {}", key, e))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(
+        !keys.is_empty(),
+        "No maintainer key is embedded in this binary, it can not verify the release"
+    );
+    Ok(keys)
+}
+
+/// Verify the binary is signed by one of the maintainer keys
+fn verify_signature(binary: &[u8], signature: &str, keys: &[Ed25519PublicKey]) -> Result<()> {
+    let signature = Ed25519Signature::from_bytes(&hex::decode(signature.trim_start_matches("0x"))?)
+        .map_err(|e| anyhow!("Invalid release signature: {}", e))?;
+    if keys
+        .iter()
+        .any(|key| key.verify(binary, &signature).is_ok())
+    {
+        Ok(())
+    } else {
+        bail!("The release binary is not signed by any maintainer key")
+    }
+}
+
+/// Write the new binary next to the executable, and rename it over the executable,
+/// so the executable is either the old or the new binary if the update is interrupted.
+fn replace_executable(path: &Path, binary: &[u8]) -> Result<()> {
+    let new_path = path.with_extension("new");
+    std::fs::write(&new_path, binary)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&new_path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    // The running executable can not be replaced on Windows, but it can be renamed
+    #[cfg(windows)]
+    std::fs::rename(path, path.with_extension("old"))?;
+    std::fs::rename(&new_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::{KeyPair, Signer};
+
+    #[test]
+    fn test_version_and_signature() {
+        assert!(is_newer_version("v0.2.0", "0.1.9").unwrap());
+        assert!(!is_newer_version("0.1.0", "0.1.0").unwrap());
+        assert!(is_newer_version("0.10.0-rc1", "0.9.3").unwrap());
+
+        let keypair = Ed25519KeyPair::generate(&mut rand::thread_rng());
+        let binary = b"rooch binary";
+        let signature: Ed25519Signature = keypair.sign(binary);
+        let signature = hex::encode(signature.as_ref());
+        let keys = vec![keypair.public().clone()];
+        verify_signature(binary, &signature, &keys).unwrap();
+        assert!(verify_signature(b"tampered binary", &signature, &keys).is_err());
+    }
+}
@@ -4,9 +4,10 @@
 use crate::commands::event::EventCommand;
 use cli_types::CommandAction;
 use commands::{
-    abi::ABI, account::Account, env::Env, init::Init, move_cli::MoveCli, object::ObjectCommand,
-    resource::ResourceCommand, rpc::Rpc, server::Server, session_key::SessionKey,
-    state::StateCommand, transaction::Transaction,
+    abi::ABI, account::Account, committee::Committee, env::Env, gas_schedule::GasSchedule,
+    init::Init, move_cli::MoveCli, object::ObjectCommand, resource::ResourceCommand, rpc::Rpc,
+    server::Server, session_key::SessionKey, state::StateCommand, statedb::StateDB,
+    transaction::Transaction, update::UpdateCommand,
 };
 use rooch_types::error::RoochResult;
 
@@ -17,6 +18,10 @@ pub mod utils;
 #[derive(clap::Parser)]
 #[clap(author, version, about, long_about = None)]
 pub struct RoochCli {
+    /// Print the result of the command as JSON, so it can be consumed by scripts
+    #[clap(long, global = true)]
+    pub json: bool,
+
     #[clap(subcommand)]
     pub cmd: Command,
 }
@@ -31,22 +36,29 @@ pub enum Command {
     State(StateCommand),
     Object(ObjectCommand),
     Resource(ResourceCommand),
+    #[clap(alias = "tx")]
     Transaction(Transaction),
     Event(EventCommand),
     ABI(ABI),
     Env(Env),
     SessionKey(SessionKey),
     Rpc(Rpc),
+    Update(UpdateCommand),
+    #[clap(name = "statedb")]
+    StateDB(StateDB),
+    GasSchedule(GasSchedule),
+    Committee(Committee),
 }
 
 pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
+    cli_types::set_json_output(opt.json);
     match opt.cmd {
         Command::Account(account) => account.execute().await,
         Command::Move(move_cli) => move_cli.execute().await,
         Command::Server(server) => server.execute().await,
         Command::Init(init) => init.execute_serialized().await,
-        Command::State(state) => state.execute_serialized().await,
-        Command::Object(object) => object.execute_serialized().await,
+        Command::State(state) => state.execute().await,
+        Command::Object(object) => object.execute().await,
         Command::Resource(resource) => resource.execute_serialized().await,
         Command::Transaction(transation) => transation.execute().await,
         Command::Event(event) => event.execute().await,
@@ -54,5 +66,9 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Env(env) => env.execute().await,
         Command::SessionKey(session_key) => session_key.execute().await,
         Command::Rpc(rpc) => rpc.execute().await,
+        Command::Update(update) => update.execute_serialized().await,
+        Command::StateDB(statedb) => statedb.execute().await,
+        Command::GasSchedule(gas_schedule) => gas_schedule.execute().await,
+        Command::Committee(committee) => committee.execute().await,
     }
 }
@@ -5,16 +5,26 @@ use clap::Parser;
 use rooch::RoochCli;
 use std::process::exit;
 
+#[cfg(feature = "native-instrumentation")]
+#[global_allocator]
+static GLOBAL: moveos_stdlib::natives::instrumentation::CountingAllocator =
+    moveos_stdlib::natives::instrumentation::CountingAllocator;
+
 /// rooch is a command line tools for Rooch Network
 #[tokio::main]
 async fn main() {
     let _ = tracing_subscriber::fmt::try_init();
 
     let opt = RoochCli::parse();
+    let json = opt.json;
     let result = rooch::run_cli(opt).await;
 
     match result {
         Ok(s) => println!("{}", s),
+        Err(e) if json => {
+            println!("{}", serde_json::json!({ "error": e.to_string() }));
+            exit(1);
+        }
         Err(e) => {
             println!("{}", e);
             exit(1);
@@ -1,8 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::cli_types::print_message;
+use anyhow::{bail, ensure};
 use itertools::Itertools;
+use move_core_types::u256::U256;
 use std::io::{self, stdout, Write};
+use std::path::Path;
 use std::{collections::BTreeMap, str::FromStr};
 
 /// Error message for parsing a map
@@ -64,3 +68,102 @@ pub fn read_line() -> Result<String, anyhow::Error> {
     io::stdin().read_line(&mut s)?;
     Ok(s.trim_end().to_string())
 }
+
+/// Read the bytes from the hex, or the file of the hex or the binary
+pub fn read_bytes_input(input: &str) -> anyhow::Result<Vec<u8>> {
+    let path = Path::new(input);
+    if !path.is_file() {
+        return Ok(hex::decode(input.trim().trim_start_matches("0x"))?);
+    }
+    let content = std::fs::read(path)?;
+    let hex_bytes = std::str::from_utf8(&content)
+        .ok()
+        .and_then(|s| hex::decode(s.trim().trim_start_matches("0x")).ok());
+    Ok(hex_bytes.unwrap_or(content))
+}
+
+/// Ask the user to confirm, only `y` or `yes` confirms
+pub fn confirm(question: &str) -> Result<bool, anyhow::Error> {
+    print_message(format!("{} [y/N]", question));
+    let answer = read_line()?.trim().to_lowercase();
+    Ok(answer == "y" || answer == "yes")
+}
+
+/// Parse the decimal coin amount into the amount in the smallest unit of the coin
+///
+/// Example: `1.5` of a coin with 8 decimals is `150000000`
+pub fn parse_coin_amount(amount: &str, decimals: u8) -> anyhow::Result<U256> {
+    let amount = amount.trim();
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    ensure!(
+        !(integer.is_empty() && fraction.is_empty())
+            && integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit()),
+        "Invalid coin amount: {}",
+        amount
+    );
+    if fraction.len() > decimals as usize {
+        bail!(
+            "The coin amount {} has more than {} decimals",
+            amount,
+            decimals
+        );
+    }
+    let digits = format!(
+        "{}{:0<width$}",
+        integer,
+        fraction,
+        width = decimals as usize
+    );
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(U256::zero());
+    }
+    U256::from_str_radix(digits, 10)
+        .map_err(|e| anyhow::anyhow!("Invalid coin amount {}: {:?}", amount, e))
+}
+
+/// Format the amount in the smallest unit of the coin as the decimal coin amount
+pub fn format_coin_amount(amount: U256, decimals: u8) -> String {
+    let digits = amount.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let digits = format!("{:0>width$}", digits, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_owned()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coin_amount() {
+        assert_eq!(
+            parse_coin_amount("1.5", 8).unwrap(),
+            U256::from(150_000_000u64)
+        );
+        assert_eq!(parse_coin_amount("0.00000001", 8).unwrap(), U256::one());
+        assert_eq!(parse_coin_amount("10", 0).unwrap(), U256::from(10u64));
+        assert_eq!(parse_coin_amount(".5", 1).unwrap(), U256::from(5u64));
+        assert_eq!(parse_coin_amount("0", 8).unwrap(), U256::zero());
+        assert!(parse_coin_amount("0.000000001", 8).is_err());
+        assert!(parse_coin_amount("1.2.3", 8).is_err());
+        assert!(parse_coin_amount("-1", 8).is_err());
+        assert!(parse_coin_amount(".", 8).is_err());
+
+        assert_eq!(format_coin_amount(U256::from(150_000_000u64), 8), "1.5");
+        assert_eq!(format_coin_amount(U256::one(), 8), "0.00000001");
+        assert_eq!(format_coin_amount(U256::from(10u64), 0), "10");
+        assert_eq!(format_coin_amount(U256::zero(), 8), "0");
+    }
+}
@@ -37,6 +37,8 @@ Feature: Rooch CLI integration tests
 
       Then cmd: "account create"
       Then cmd: "account list"
+      Then cmd: "account list --json"
+      Then assert: "{{$.account[-1][0].has_session_key}} == false"
       #Then cmd: "account nullify --address 0xebf29d2aed4da3d2e13a32d71266a302fbfd5ceb3ff1f465c006fa207f1789ce"
 
       Then cmd: "rpc request --method rooch_getBalance --params '["{{$.address_mapping.default}}", "0x3::gas_coin::GasCoin"]'"
@@ -55,10 +57,14 @@ Feature: Rooch CLI integration tests
       # transaction
       Then cmd: "transaction get-transactions-by-order --cursor 0 --limit 1"
       Then cmd: "transaction get-transactions-by-hash --hashes {{$.transaction[-1].data[0].execution_info.tx_hash}}"
+      Then cmd: "transaction decode {{$.transaction[-1][0].transaction.raw}}"
+      Then assert: "{{$.transaction[-1].signed}} == true"
 
       # account balance
       Then cmd: "account balance"
       Then cmd: "account balance --coin-type rooch_framework::gas_coin::GasCoin"
+      Then cmd: "account balance --coin-type rooch_framework::gas_coin::GasCoin --json"
+      Then assert: "'{{$.account[-1][0].coin_type}}' == '0x3::gas_coin::GasCoin'"
       Then cmd: "rpc request --method rooch_getBalance --params '["{{$.address_mapping.default}}", "0x3::gas_coin::GasCoin"]'"
       Then assert: "'{{$.rpc[-1].balance}}' != '0'"
 
@@ -74,6 +80,8 @@ Feature: Rooch CLI integration tests
       Then cmd: "state --access-path /object/0x3::chain_id::ChainID"
       Then assert: "{{$.state[-1][0].value_type}} == '0x2::object::ObjectEntity<0x3::chain_id::ChainID>'"
       Then assert: "{{$.state[-1][0].decoded_value.value.value.value.id}} == 20230104"
+      Then cmd: "state decode --type 0x3::chain_id::ChainID 0xd8af340100000000"
+      Then assert: "{{$.state[-1].value.id}} == 20230104"
       Then cmd: "state --access-path /object/0x3::address_mapping::AddressMapping"
       Then assert: "{{$.state[-1][0].value_type}} == '0x2::object::ObjectEntity<0x3::address_mapping::AddressMapping>'"
       Then cmd: "state --access-path /object/0x3::coin::CoinInfo<0x3::gas_coin::GasCoin>"
@@ -3,35 +3,18 @@
 
 use move_vm_runtime::native_functions::make_table_from_iter;
 use move_vm_runtime::native_functions::NativeFunctionTable;
-use rooch_framework::natives::gas_parameter::gas_member::FromOnChainGasSchedule;
 use rooch_framework::natives::gas_parameter::gas_member::InitialGasSchedule;
+use rooch_framework::natives::gas_parameter::GasSchedule;
 use rooch_types::addresses::BITCOIN_MOVE_ADDRESS;
-use std::collections::BTreeMap;
 
 mod gas_parameter;
 pub mod ord;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, GasSchedule)]
 pub struct GasParameters {
     ord: ord::GasParameters,
 }
 
-impl FromOnChainGasSchedule for GasParameters {
-    fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
-        Some(Self {
-            ord: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
-        })
-    }
-}
-
-impl InitialGasSchedule for GasParameters {
-    fn initial() -> Self {
-        Self {
-            ord: InitialGasSchedule::initial(),
-        }
-    }
-}
-
 pub fn get_global_gas_parameter() {
     let gas_parameter = GasParameters::initial();
     println!("global gas parameter {:?}", gas_parameter);
@@ -10,6 +10,8 @@ module moveos_std::address {
     use std::ascii;
     use moveos_std::bcs;
     use moveos_std::hex;
+    #[test_only]
+    use std::vector;
 
     /// The length of an address, in bytes
     const LENGTH: u64 = 32;
@@ -23,21 +25,17 @@ module moveos_std::address {
     /// Error from `from_u256` when
     const ErrorU256TooBigToConvertToAddress: u64 = 2;
 
-    //TODO
     /// Convert `a` into a u256 by interpreting `a` as the bytes of a big-endian integer
     /// (e.g., `to_u256(0x1) == 1`)
-    //native public fun to_u256(a: address): u256;
+    native public fun to_u256(a: address): u256;
 
-    //TODO
     /// Convert `n` into an address by encoding it as a big-endian integer (e.g., `from_u256(1) = @0x1`)
     /// Aborts if `n` > `MAX_ADDRESS`
-    //native public fun from_u256(n: u256): address;
+    native public fun from_u256(n: u256): address;
 
     /// Convert `bytes` into an address.
     /// Aborts with `ErrorAddressParseError` if the length of `bytes` is invalid length
-    public fun from_bytes(bytes: vector<u8>): address{
-        bcs::to_address(bytes)
-    }
+    native public fun from_bytes(bytes: vector<u8>): address;
 
 
     /// Convert `a` into BCS-encoded bytes.
@@ -66,4 +64,24 @@ module moveos_std::address {
         MAX
     }
 
+    #[test]
+    fun test_from_and_to_bytes() {
+        let addr = @0x42;
+        assert!(from_bytes(to_bytes(addr)) == addr, 1);
+        assert!(vector::length(&to_bytes(addr)) == LENGTH, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorAddressParseError, location = Self)]
+    fun test_from_bytes_invalid_length() {
+        from_bytes(x"0102");
+    }
+
+    #[test]
+    fun test_u256_conversion() {
+        assert!(to_u256(@0x1) == 1, 1);
+        assert!(from_u256(2) == @0x2, 2);
+        assert!(to_u256(from_u256(MAX)) == MAX, 3);
+        assert!(from_u256(to_u256(@0x42)) == @0x42, 4);
+    }
 }
@@ -6,7 +6,7 @@
 /// and let developers customize the storage
 module moveos_std::context {
 
-    use std::option::Option;
+    use std::option::{Self, Option};
     use std::string::String;
     use std::vector;
     use moveos_std::storage_context::{StorageContext};
@@ -25,6 +25,16 @@ module moveos_std::context {
     const ErrorObjectNotShared: u64 = 2;
     ///Can not take out the object which is bound to the account
     const ErrorObjectIsBound: u64 = 3;
+    /// The module publishing is not allowed by the module publish permission of the transaction
+    const ErrorModulePublishNotAllowed: u64 = 4;
+
+    /// The module publishing permission of the transaction, it is added to the context by the framework before
+    /// the transaction is executed, and checked by `publish_modules`, so it applies to the modules published by
+    /// any module or script. A value can only be added to the context once, so the transaction can not replace it.
+    /// The modules can be published if there is no permission in the context.
+    struct ModulePublishPermission has copy, drop, store {
+        allowed: bool,
+    }
 
     /// Information about the global context include TxContext and StorageContext
     /// We can not put the StorageContext to TxContext, because object module depends on tx_context module,
@@ -172,8 +182,19 @@ module moveos_std::context {
         }
     }
 
+    public fun new_module_publish_permission(allowed: bool): ModulePublishPermission {
+        ModulePublishPermission { allowed }
+    }
+
+    /// Check the module publish permission of the transaction allows publishing modules
+    public fun is_module_publish_allowed(self: &Context): bool {
+        let permission = tx_context::get<ModulePublishPermission>(&self.tx_context);
+        option::is_none(&permission) || option::destroy_some(permission).allowed
+    }
+
     /// Publish modules to the account's storage
     public fun publish_modules(self: &mut Context, account: &signer, modules: vector<MoveModule>) {
+        assert!(is_module_publish_allowed(self), ErrorModulePublishNotAllowed);
         let account_address = signer::address_of(account);
         ensure_account_storage(self, account_address);
         let account_storage = borrow_account_storage_mut(self, account_address);
@@ -494,4 +515,30 @@ module moveos_std::context {
         Self::drop_test_context(ctx);
     }
 
+    #[test(sender = @0x42)]
+    fun test_module_publish_permission(sender: address){
+        let ctx = new_test_context(sender);
+        assert!(is_module_publish_allowed(&ctx), 1000);
+        add(&mut ctx, new_module_publish_permission(false));
+        assert!(!is_module_publish_allowed(&ctx), 1001);
+        drop_test_context(ctx);
+    }
+
+    #[test(sender = @0x42)]
+    #[expected_failure(abort_code = ErrorModulePublishNotAllowed, location = Self)]
+    fun test_publish_modules_not_allowed(sender: signer){
+        let ctx = new_test_context(signer::address_of(&sender));
+        add(&mut ctx, new_module_publish_permission(false));
+        publish_modules(&mut ctx, &sender, vector::empty());
+        drop_test_context(ctx);
+    }
+
+    #[test(sender = @0x42)]
+    #[expected_failure(abort_code = 1, location = moveos_std::simple_map)]
+    fun test_replace_module_publish_permission(sender: address){
+        let ctx = new_test_context(sender);
+        add(&mut ctx, new_module_publish_permission(false));
+        add(&mut ctx, new_module_publish_permission(true));
+        drop_test_context(ctx);
+    }
 }
@@ -0,0 +1,23 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Controlled dynamic dispatch, call an entry function by the module and function name.
+/// The dispatched call is executed by the VM after the current function returns,
+/// every dispatched call gets its own `Context`, so it can not borrow the state of the caller.
+/// The dispatched function can not take the `signer` argument, and the reentrant dispatch,
+/// which calls back into a module in the active dispatch chain, is rejected by the VM.
+/// It is useful for the plugin-style architectures, such as hooks and callbacks.
+module moveos_std::dispatch {
+
+    use std::string::{Self, String};
+
+    #[private_generics(W)]
+    /// Request to call the entry function `module_address::module_name::function_name` with the BCS serialized `args`.
+    /// The `W` is a witness type of the caller module, only the module of `W` can dispatch in its name.
+    /// The generic functions can not be dispatched.
+    public fun dispatch<W>(module_address: address, module_name: String, function_name: String, args: vector<vector<u8>>) {
+        request_dispatch<W>(module_address, *string::bytes(&module_name), *string::bytes(&function_name), args);
+    }
+
+    native fun request_dispatch<W>(module_address: address, module_name: vector<u8>, function_name: vector<u8>, args: vector<vector<u8>>);
+}
@@ -0,0 +1,134 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The unsigned fixed-point number with 64 integer bits and 64 fractional bits.
+/// The sqrt, pow and logarithm are implemented by the natives, they are expensive and easy to overflow in Move.
+module moveos_std::fixed_point64 {
+
+    use moveos_std::math;
+
+    /// The raw value of 1.0
+    const ONE_RAW: u128 = 18446744073709551616;
+    const MAX_U64: u128 = 18446744073709551615;
+    /// The raw value of ln(2)
+    const LN2_RAW: u128 = 12786308645202655660;
+
+    const ErrorDenominatorZero: u64 = 1;
+    const ErrorOverflow: u64 = 2;
+    const ErrorLogOfZero: u64 = 3;
+
+    /// The raw `value` is `x * 2^64`
+    struct FixedPoint64 has copy, drop, store {
+        value: u128,
+    }
+
+    public fun create_from_raw_value(value: u128): FixedPoint64 {
+        FixedPoint64 { value }
+    }
+
+    public fun get_raw_value(num: FixedPoint64): u128 {
+        num.value
+    }
+
+    public fun create_from_u128(val: u128): FixedPoint64 {
+        assert!(val <= MAX_U64, ErrorOverflow);
+        FixedPoint64 { value: val << 64 }
+    }
+
+    /// Create a FixedPoint64 from `numerator / denominator`
+    public fun create_from_rational(numerator: u128, denominator: u128): FixedPoint64 {
+        assert!(denominator != 0, ErrorDenominatorZero);
+        FixedPoint64 { value: math::mul_div_u128(numerator, ONE_RAW, denominator) }
+    }
+
+    /// Return the integer part of `num`
+    public fun floor(num: FixedPoint64): u128 {
+        num.value >> 64
+    }
+
+    public fun add(x: FixedPoint64, y: FixedPoint64): FixedPoint64 {
+        FixedPoint64 { value: x.value + y.value }
+    }
+
+    public fun sub(x: FixedPoint64, y: FixedPoint64): FixedPoint64 {
+        FixedPoint64 { value: x.value - y.value }
+    }
+
+    public fun mul(x: FixedPoint64, y: FixedPoint64): FixedPoint64 {
+        FixedPoint64 { value: math::mul_div_u128(x.value, y.value, ONE_RAW) }
+    }
+
+    public fun div(x: FixedPoint64, y: FixedPoint64): FixedPoint64 {
+        assert!(y.value != 0, ErrorDenominatorZero);
+        FixedPoint64 { value: math::mul_div_u128(x.value, ONE_RAW, y.value) }
+    }
+
+    /// Return `val * multiplier` rounding down
+    public fun multiply_u128(val: u128, multiplier: FixedPoint64): u128 {
+        math::mul_div_u128(val, multiplier.value, ONE_RAW)
+    }
+
+    /// Return `val / divisor` rounding down
+    public fun divide_u128(val: u128, divisor: FixedPoint64): u128 {
+        assert!(divisor.value != 0, ErrorDenominatorZero);
+        math::mul_div_u128(val, ONE_RAW, divisor.value)
+    }
+
+    public fun sqrt(x: FixedPoint64): FixedPoint64 {
+        FixedPoint64 { value: sqrt_raw(x.value) }
+    }
+
+    public fun pow(x: FixedPoint64, n: u64): FixedPoint64 {
+        FixedPoint64 { value: pow_raw(x.value, n) }
+    }
+
+    /// Return `log2(x) + 64`, the offset keeps the result positive for `x` in (0, 1)
+    public fun log2_plus_64(x: FixedPoint64): FixedPoint64 {
+        FixedPoint64 { value: log2_plus_64_raw(x.value) }
+    }
+
+    /// Return `ln(x) + 64 * ln(2)`, the offset keeps the result positive for `x` in (0, 1)
+    public fun ln_plus_64ln2(x: FixedPoint64): FixedPoint64 {
+        let log2_value = log2_plus_64_raw(x.value);
+        FixedPoint64 { value: math::mul_div_u128(log2_value, LN2_RAW, ONE_RAW) }
+    }
+
+    native fun sqrt_raw(x: u128): u128;
+    native fun pow_raw(x: u128, n: u64): u128;
+    native fun log2_plus_64_raw(x: u128): u128;
+
+    #[test]
+    fun test_arithmetic() {
+        let half = create_from_rational(1, 2);
+        assert!(get_raw_value(half) == ONE_RAW / 2, 1);
+        let three = create_from_u128(3);
+        assert!(floor(mul(three, half)) == 1, 2);
+        assert!(get_raw_value(div(three, half)) == 6 * ONE_RAW, 3);
+        assert!(multiply_u128(100, half) == 50, 4);
+        assert!(divide_u128(100, half) == 200, 5);
+        assert!(get_raw_value(sub(add(three, half), half)) == 3 * ONE_RAW, 6);
+    }
+
+    #[test]
+    fun test_sqrt_pow_log() {
+        assert!(sqrt(create_from_u128(9)) == create_from_u128(3), 1);
+        assert!(sqrt(create_from_rational(1, 4)) == create_from_rational(1, 2), 2);
+        assert!(pow(create_from_u128(2), 10) == create_from_u128(1024), 3);
+        assert!(pow(create_from_rational(1, 2), 2) == create_from_rational(1, 4), 4);
+        assert!(log2_plus_64(create_from_u128(8)) == create_from_u128(67), 5);
+        assert!(log2_plus_64(create_from_rational(1, 2)) == create_from_u128(63), 6);
+        assert!(ln_plus_64ln2(create_from_u128(1)) == create_from_raw_value(64 * LN2_RAW), 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorLogOfZero, location = Self)]
+    fun test_log_of_zero() {
+        log2_plus_64(create_from_raw_value(0));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorOverflow, location = Self)]
+    fun test_pow_overflow() {
+        pow(create_from_u128(2), 64);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The safe math for the u64 and u128, the heavy operations are implemented by the natives.
+/// All the functions abort on overflow instead of wrapping.
+module moveos_std::math {
+
+    const MAX_U64: u128 = 18446744073709551615;
+
+    const ErrorDivideByZero: u64 = 1;
+    const ErrorOverflow: u64 = 2;
+
+    /// Return `a * b / c` rounding down, the intermediate product does not overflow
+    public fun mul_div_u64(a: u64, b: u64, c: u64): u64 {
+        assert!(c != 0, ErrorDivideByZero);
+        let result = (a as u128) * (b as u128) / (c as u128);
+        assert!(result <= MAX_U64, ErrorOverflow);
+        (result as u64)
+    }
+
+    /// Return `a * b / c` rounding down, the intermediate product is 256-bit, so it does not overflow
+    public native fun mul_div_u128(a: u128, b: u128, c: u128): u128;
+
+    /// Return the integer square root of `x`, rounding down
+    public fun sqrt_u64(x: u64): u64 {
+        (sqrt_u128((x as u128)) as u64)
+    }
+
+    /// Return the integer square root of `x`, rounding down
+    public native fun sqrt_u128(x: u128): u128;
+
+    /// Return `base ^ exp`
+    public fun pow_u64(base: u64, exp: u64): u64 {
+        let result = pow_u128((base as u128), exp);
+        assert!(result <= MAX_U64, ErrorOverflow);
+        (result as u64)
+    }
+
+    /// Return `base ^ exp`
+    public native fun pow_u128(base: u128, exp: u64): u128;
+
+    #[test]
+    fun test_mul_div() {
+        assert!(mul_div_u64(18446744073709551615, 18446744073709551615, 18446744073709551615) == 18446744073709551615, 1);
+        assert!(mul_div_u64(7, 3, 2) == 10, 2);
+        assert!(mul_div_u128(340282366920938463463374607431768211455, 3, 6) == 170141183460469231731687303715884105727, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorOverflow, location = Self)]
+    fun test_mul_div_overflow() {
+        mul_div_u128(340282366920938463463374607431768211455, 2, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorDivideByZero, location = Self)]
+    fun test_mul_div_by_zero() {
+        mul_div_u128(1, 1, 0);
+    }
+
+    #[test]
+    fun test_sqrt_and_pow() {
+        assert!(sqrt_u64(0) == 0, 1);
+        assert!(sqrt_u64(15) == 3, 2);
+        assert!(sqrt_u64(18446744073709551615) == 4294967295, 3);
+        assert!(sqrt_u128(340282366920938463463374607431768211455) == 18446744073709551615, 4);
+        assert!(pow_u64(0, 0) == 1, 5);
+        assert!(pow_u64(3, 5) == 243, 6);
+        assert!(pow_u128(2, 127) == 170141183460469231731687303715884105728, 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorOverflow, location = Self)]
+    fun test_pow_overflow() {
+        pow_u128(2, 128);
+    }
+}
@@ -19,10 +19,17 @@ module moveos_std::object {
     friend moveos_std::table;
     friend moveos_std::type_table;
     friend moveos_std::object_table;
+    friend moveos_std::object_permission;
 
     const ErrorObjectAlreadyExist: u64 = 1;
     const ErrorObjectFrozen: u64 = 2;
     const ErrorInvalidOwnerAddress:u64 = 3;
+    /// The VM aborts the transaction with this error if a new SystemOwned object is not shared, frozen or held by any `Object<T>`
+    const ErrorObjectLeaked: u64 = 4;
+    /// The VM aborts the transaction with this error if an `Object<T>` is stored but its object is removed or transferred
+    const ErrorDanglingObjectHandle: u64 = 5;
+    /// The VM aborts the transaction with this error if it declares the dependency hints but touches an undeclared object
+    const ErrorUndeclaredObjectAccess: u64 = 6;
 
     const SYSTEM_OWNER_ADDRESS: address = @0x0;
     
@@ -107,6 +114,18 @@ module moveos_std::object {
         )
     }
 
+    /// Derive an ObjectID from the `addr` and `seed`, the ObjectID is `sha3_256(addr || seed || 0xfd)`.
+    /// The same as the `derive_object_id` in Rust, so the ObjectID can be predicted off-chain.
+    native public fun derive_object_id(addr: address, seed: &vector<u8>): ObjectID;
+
+    public fun object_id_from_bytes(bytes: vector<u8>): ObjectID {
+        address_to_object_id(address::from_bytes(bytes))
+    }
+
+    public fun object_id_to_bytes(object_id: ObjectID): vector<u8> {
+        address::to_bytes(object_id.id)
+    }
+
     public fun custom_object_id<ID: drop, T>(id: ID): ObjectID {
         let bytes = bcs::to_bytes(&id);
         vector::append(&mut bytes, *std::string::bytes(&type_info::type_name<T>()));
@@ -224,6 +243,11 @@ module moveos_std::object {
         obj_entity.owner = new_owner;
     }
 
+    public(friend) fun to_user_owned_internal<T>(self: &mut ObjectEntity<T>, new_owner: address) {
+        assert!(new_owner != SYSTEM_OWNER_ADDRESS, ErrorInvalidOwnerAddress);
+        self.owner = new_owner;
+    }
+
     public(friend) fun to_system_owned<T: key>(self: &mut Object<T>) {
         let obj_entity = borrow_mut_from_global<T>(self.id);
         to_system_owned_internal(obj_entity);
@@ -473,4 +497,13 @@ module moveos_std::object {
         //ensure the object_id is the same as the object_id generated by the object.rs
         assert!(object_id.id == @0xaa825038ae811f5c94d20175699d808eae4c624fa85c81faad45de1145284e06, 1);
     }
+
+    #[test]
+    fun test_derive_object_id(){
+        let object_id = derive_object_id(@0x42, &b"seed");
+        //ensure the object_id is the same as the object_id generated by the object.rs
+        assert!(object_id.id == @0x7272e88771af9555aa226037ed8109eac67eda625d695be7a75be6407ae53dd2, 1);
+        assert!(object_id_from_bytes(object_id_to_bytes(object_id)) == object_id, 2);
+        assert!(derive_object_id(@0x42, &b"seed2") != object_id, 3);
+    }
 }
@@ -0,0 +1,251 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Capabilities granting scoped rights over an object without transferring the object.
+/// The owner grants an `ObjectCap` to another account or to a contract, for example, a marketplace lists an NFT
+/// with a transfer capability, and the NFT stays in the seller's account until it is sold.
+/// A capability only works while the object is still owned by the grantor, and the owner can revoke all the
+/// capabilities granted for the object.
+module moveos_std::object_permission {
+
+    use std::string::String;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object::{Self, Object, ObjectID};
+    use moveos_std::signer;
+    use moveos_std::table::{Self, Table};
+
+    const ErrorNotObjectOwner: u64 = 1;
+    const ErrorInvalidPermissions: u64 = 2;
+    const ErrorPermissionDenied: u64 = 3;
+    /// The object is not owned by the grantor anymore, or the capabilities of the object are revoked
+    const ErrorCapExpired: u64 = 4;
+    const ErrorObjectIsBound: u64 = 5;
+
+    /// Read the object with the capability, it proves the consent of the owner to a contract
+    const PERMISSION_READ: u8 = 1;
+    /// Transfer the object once, the capability is consumed by the transfer
+    const PERMISSION_TRANSFER: u8 = 1 << 1;
+    /// Mutate the fields of the object listed in the capability
+    const PERMISSION_MUTATE: u8 = 1 << 2;
+    const ALL_PERMISSIONS: u8 = 7;
+
+    struct ObjectCap has key, store {
+        object_id: ObjectID,
+        grantor: address,
+        permissions: u8,
+        /// The fields which can be mutated with the `PERMISSION_MUTATE`, empty means all the fields
+        fields: vector<String>,
+        /// The revocation epoch of the object when the capability is granted
+        epoch: u64,
+    }
+
+    /// The revocation epochs of the objects, revoking the capabilities increases the epoch of the object
+    struct RevocationEpochs has key {
+        epochs: Table<ObjectID, u64>,
+    }
+
+    public fun permission_read(): u8 {
+        PERMISSION_READ
+    }
+
+    public fun permission_transfer(): u8 {
+        PERMISSION_TRANSFER
+    }
+
+    public fun permission_mutate(): u8 {
+        PERMISSION_MUTATE
+    }
+
+    /// Grant a capability of the object `object_id` owned by `owner`.
+    /// The capability is system owned, the caller transfers it to the holder or stores it in a contract.
+    public fun grant<T: key>(ctx: &mut Context, owner: &signer, object_id: ObjectID, permissions: u8, fields: vector<String>): Object<ObjectCap> {
+        assert!(permissions != 0 && permissions & ALL_PERMISSIONS == permissions, ErrorInvalidPermissions);
+        let grantor = signer::address_of(owner);
+        let obj = context::borrow_object<T>(ctx, object_id);
+        assert!(object::owner(obj) == grantor, ErrorNotObjectOwner);
+        let epoch = revocation_epoch(ctx, object_id);
+        context::new_object(ctx, ObjectCap {
+            object_id,
+            grantor,
+            permissions,
+            fields,
+            epoch,
+        })
+    }
+
+    /// Grant a capability of the object and send it to the `holder`
+    public entry fun grant_entry<T: key>(ctx: &mut Context, owner: &signer, object_id: ObjectID, permissions: u8, fields: vector<String>, holder: address) {
+        let cap = grant<T>(ctx, owner, object_id, permissions, fields);
+        object::transfer(cap, holder);
+    }
+
+    /// Revoke all the capabilities granted for the object, include the capabilities held by the contracts
+    public entry fun revoke_all<T: key>(ctx: &mut Context, owner: &signer, object_id: ObjectID) {
+        let obj = context::borrow_object<T>(ctx, object_id);
+        assert!(object::owner(obj) == signer::address_of(owner), ErrorNotObjectOwner);
+        let epoch = revocation_epoch(ctx, object_id) + 1;
+        let epochs = &mut object::borrow_mut(borrow_mut_or_create_epochs(ctx)).epochs;
+        table::upsert(epochs, object_id, epoch);
+    }
+
+    /// Transfer the object to `new_owner` with the capability, the capability is consumed.
+    /// Same as `object::transfer`, only the objects with `store` can be transferred outside of the module of `T`.
+    public fun transfer_with_cap<T: key + store>(ctx: &mut Context, cap: Object<ObjectCap>, new_owner: address) {
+        assert_cap<T>(ctx, &cap, PERMISSION_TRANSFER);
+        let object_id = object::borrow(&cap).object_id;
+        let object_entity = object::borrow_mut_from_global<T>(object_id);
+        assert!(!object::is_bound_internal(object_entity), ErrorObjectIsBound);
+        object::to_user_owned_internal(object_entity, new_owner);
+        destroy(cap);
+    }
+
+    /// Borrow the object with the read capability
+    public fun borrow_with_cap<T: key>(ctx: &Context, cap: &Object<ObjectCap>): &Object<T> {
+        assert_cap<T>(ctx, cap, PERMISSION_READ);
+        context::borrow_object<T>(ctx, object::borrow(cap).object_id)
+    }
+
+    #[private_generics(T)]
+    /// Borrow the mutable object with the mutate capability for mutating the `field`.
+    /// Only the module of `T` can call this function, it must only mutate the `field` of the object.
+    public fun borrow_mut_with_cap<T: key>(ctx: &mut Context, cap: &Object<ObjectCap>, field: String): &mut Object<T> {
+        assert_cap<T>(ctx, cap, PERMISSION_MUTATE);
+        assert!(can_mutate_field(object::borrow(cap), &field), ErrorPermissionDenied);
+        let object_entity = object::borrow_mut_from_global<T>(object::borrow(cap).object_id);
+        object::as_mut_ref(object_entity)
+    }
+
+    /// Destroy the capability, the holder can give up the rights at any time
+    public fun destroy(cap: Object<ObjectCap>) {
+        let ObjectCap { object_id: _, grantor: _, permissions: _, fields: _, epoch: _ } = object::remove(cap);
+    }
+
+    /// Return true if the capability can be used, the object is owned by the grantor and the capability is not revoked
+    public fun is_valid<T: key>(ctx: &Context, cap: &Object<ObjectCap>): bool {
+        let cap_value = object::borrow(cap);
+        if (!context::exists_object<T>(ctx, cap_value.object_id)) {
+            return false
+        };
+        let obj = context::borrow_object<T>(ctx, cap_value.object_id);
+        object::owner(obj) == cap_value.grantor && revocation_epoch(ctx, cap_value.object_id) == cap_value.epoch
+    }
+
+    public fun object_id(self: &ObjectCap): ObjectID {
+        self.object_id
+    }
+
+    public fun grantor(self: &ObjectCap): address {
+        self.grantor
+    }
+
+    public fun permissions(self: &ObjectCap): u8 {
+        self.permissions
+    }
+
+    public fun has_permission(self: &ObjectCap, permission: u8): bool {
+        self.permissions & permission == permission
+    }
+
+    public fun fields(self: &ObjectCap): &vector<String> {
+        &self.fields
+    }
+
+    fun can_mutate_field(self: &ObjectCap, field: &String): bool {
+        vector::is_empty(&self.fields) || vector::contains(&self.fields, field)
+    }
+
+    fun assert_cap<T: key>(ctx: &Context, cap: &Object<ObjectCap>, permission: u8) {
+        assert!(has_permission(object::borrow(cap), permission), ErrorPermissionDenied);
+        assert!(is_valid<T>(ctx, cap), ErrorCapExpired);
+    }
+
+    fun revocation_epoch(ctx: &Context, object_id: ObjectID): u64 {
+        let epochs_id = object::named_object_id<RevocationEpochs>();
+        if (!context::exists_object<RevocationEpochs>(ctx, epochs_id)) {
+            return 0
+        };
+        let epochs = &object::borrow(context::borrow_object<RevocationEpochs>(ctx, epochs_id)).epochs;
+        *table::borrow_with_default(epochs, object_id, &0)
+    }
+
+    fun borrow_mut_or_create_epochs(ctx: &mut Context): &mut Object<RevocationEpochs> {
+        let epochs_id = object::named_object_id<RevocationEpochs>();
+        if (!context::exists_object<RevocationEpochs>(ctx, epochs_id)) {
+            let epochs = context::new_table(ctx);
+            let obj = context::new_named_object(ctx, RevocationEpochs { epochs });
+            object::transfer_extend(obj, @moveos_std);
+        };
+        context::borrow_mut_object_extend<RevocationEpochs>(ctx, epochs_id)
+    }
+
+    #[test_only]
+    struct TestNFT has key, store {
+        name: String,
+        price: u64,
+    }
+
+    #[test_only]
+    fun new_test_nft(ctx: &mut Context, owner: address): ObjectID {
+        let obj = context::new_object(ctx, TestNFT { name: std::string::utf8(b"nft"), price: 1 });
+        let object_id = object::id(&obj);
+        object::transfer(obj, owner);
+        object_id
+    }
+
+    #[test(alice = @0x42, market = @0x43, bob = @0x44)]
+    fun test_transfer_with_cap(alice: &signer, market: &signer, bob: address) {
+        let ctx = context::new_test_context(signer::address_of(alice));
+        let object_id = new_test_nft(&mut ctx, signer::address_of(alice));
+        let cap = grant<TestNFT>(&mut ctx, alice, object_id, PERMISSION_TRANSFER, vector::empty());
+        let cap_id = object::id(&cap);
+        object::transfer(cap, signer::address_of(market));
+
+        // the NFT stays in alice's account until the market transfers it
+        assert!(object::owner(context::borrow_object<TestNFT>(&ctx, object_id)) == signer::address_of(alice), 1000);
+        let cap = context::take_object<ObjectCap>(&mut ctx, market, cap_id);
+        transfer_with_cap<TestNFT>(&mut ctx, cap, bob);
+        assert!(object::owner(context::borrow_object<TestNFT>(&ctx, object_id)) == bob, 1001);
+        assert!(!context::exists_object<ObjectCap>(&ctx, cap_id), 1002);
+        context::drop_test_context(ctx);
+    }
+
+    #[test(alice = @0x42, bob = @0x44)]
+    #[expected_failure(abort_code = ErrorCapExpired, location = Self)]
+    fun test_transfer_with_revoked_cap(alice: &signer, bob: address) {
+        let ctx = context::new_test_context(signer::address_of(alice));
+        let object_id = new_test_nft(&mut ctx, signer::address_of(alice));
+        let cap = grant<TestNFT>(&mut ctx, alice, object_id, PERMISSION_TRANSFER, vector::empty());
+        revoke_all<TestNFT>(&mut ctx, alice, object_id);
+        transfer_with_cap<TestNFT>(&mut ctx, cap, bob);
+        context::drop_test_context(ctx);
+    }
+
+    #[test(alice = @0x42, bob = @0x44)]
+    #[expected_failure(abort_code = ErrorPermissionDenied, location = Self)]
+    fun test_transfer_with_read_only_cap(alice: &signer, bob: address) {
+        let ctx = context::new_test_context(signer::address_of(alice));
+        let object_id = new_test_nft(&mut ctx, signer::address_of(alice));
+        let cap = grant<TestNFT>(&mut ctx, alice, object_id, PERMISSION_READ, vector::empty());
+        let _obj = borrow_with_cap<TestNFT>(&ctx, &cap);
+        transfer_with_cap<TestNFT>(&mut ctx, cap, bob);
+        context::drop_test_context(ctx);
+    }
+
+    #[test(alice = @0x42)]
+    #[expected_failure(abort_code = ErrorPermissionDenied, location = Self)]
+    fun test_mutate_unlisted_field_with_cap(alice: &signer) {
+        let ctx = context::new_test_context(signer::address_of(alice));
+        let object_id = new_test_nft(&mut ctx, signer::address_of(alice));
+        let fields = vector::singleton(std::string::utf8(b"price"));
+        let cap = grant<TestNFT>(&mut ctx, alice, object_id, PERMISSION_MUTATE, fields);
+        {
+            let obj = borrow_mut_with_cap<TestNFT>(&mut ctx, &cap, std::string::utf8(b"price"));
+            object::borrow_mut(obj).price = 2;
+        };
+        assert!(object::borrow(context::borrow_object<TestNFT>(&ctx, object_id)).price == 2, 1000);
+        let _obj = borrow_mut_with_cap<TestNFT>(&mut ctx, &cap, std::string::utf8(b"name"));
+        destroy(cap);
+        context::drop_test_context(ctx);
+    }
+}
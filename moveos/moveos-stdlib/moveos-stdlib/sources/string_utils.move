@@ -7,21 +7,15 @@ module moveos_std::string_utils {
     use std::string::{Self,String};
 
     const ErrorInvalidStringNumber: u64 = 1;
+    const ErrorEmptyDelimiter: u64 = 2;
+    const ErrorInvalidIndex: u64 = 3;
 
     public fun parse_u8_option(s: &String):Option<u8>{
-        let bytes:&vector<u8> = string::bytes(s);
-        let i = 0;
-        let result = 0u8;
-        while (i < vector::length(bytes)) {
-            let c = *vector::borrow(bytes, i);
-            if (c >= 48 && c <= 57) {
-                result = result * 10 + (c - 48);
-            }else{
-                return option::none()
-            };
-            i = i + 1;
+        let (ok, result) = parse_u256_inner(string::bytes(s));
+        if (!ok || result > 255) {
+            return option::none()
         };
-        option::some(result)
+        option::some((result as u8))
     }
 
     public fun parse_u8(s: &String): u8 {
@@ -31,19 +25,11 @@ module moveos_std::string_utils {
     }
 
     public fun parse_u64_option(s: &String):Option<u64>{
-        let bytes:&vector<u8> = string::bytes(s);
-        let i = 0;
-        let result = 0u64;
-        while (i < vector::length(bytes)) {
-            let c = *vector::borrow(bytes, i);
-            if (c >= 48 && c <= 57) {
-                result = result * 10 + ((c - 48) as u64);
-            }else{
-                return option::none()
-            };
-            i = i + 1;
+        let (ok, result) = parse_u256_inner(string::bytes(s));
+        if (!ok || result > 18446744073709551615) {
+            return option::none()
         };
-        option::some(result)
+        option::some((result as u64))
     }
 
     public fun parse_u64(s: &String): u64 {
@@ -53,19 +39,11 @@ module moveos_std::string_utils {
     }
 
     public fun parse_u128_option(s: &String):Option<u128>{
-        let bytes:&vector<u8> = string::bytes(s);
-        let i = 0;
-        let result = 0u128;
-        while (i < vector::length(bytes)) {
-            let c = *vector::borrow(bytes, i);
-            if (c >= 48 && c <= 57) {
-                result = result * 10 + ((c - 48) as u128);
-            }else{
-                return option::none()
-            };
-            i = i + 1;
+        let (ok, result) = parse_u256_inner(string::bytes(s));
+        if (!ok || result > 340282366920938463463374607431768211455) {
+            return option::none()
         };
-        option::some(result)
+        option::some((result as u128))
     }
 
     public fun parse_u128(s: &String): u128 {
@@ -75,17 +53,9 @@ module moveos_std::string_utils {
     }
 
     public fun parse_u256_option(s: &String):Option<u256>{
-        let bytes:&vector<u8> = string::bytes(s);
-        let i = 0;
-        let result = 0u256;
-        while (i < vector::length(bytes)) {
-            let c = *vector::borrow(bytes, i);
-            if (c >= 48 && c <= 57) {
-                result = result * 10 + ((c - 48) as u256);
-            }else{
-                return option::none()
-            };
-            i = i + 1;
+        let (ok, result) = parse_u256_inner(string::bytes(s));
+        if (!ok) {
+            return option::none()
         };
         option::some(result)
     }
@@ -96,6 +66,50 @@ module moveos_std::string_utils {
         option::destroy_some(result)
     }
 
+    public fun u8_to_string(value: u8): String {
+        u256_to_string_inner((value as u256))
+    }
+
+    public fun u64_to_string(value: u64): String {
+        u256_to_string_inner((value as u256))
+    }
+
+    public fun u128_to_string(value: u128): String {
+        u256_to_string_inner((value as u256))
+    }
+
+    public fun u256_to_string(value: u256): String {
+        u256_to_string_inner(value)
+    }
+
+    /// Check if the `bytes` is a valid UTF-8 encoded string
+    public fun is_valid_utf8(bytes: &vector<u8>): bool {
+        check_utf8_inner(bytes)
+    }
+
+    /// Return the byte index of the first occurrence of `sub` in `s`, or none if not found
+    public fun find(s: &String, sub: &String): Option<u64> {
+        find_from(s, sub, 0)
+    }
+
+    /// Return the byte index of the first occurrence of `sub` in `s` at or after the byte index `from`
+    public fun find_from(s: &String, sub: &String, from: u64): Option<u64> {
+        let bytes = string::bytes(s);
+        let len = vector::length(bytes);
+        assert!(from <= len, ErrorInvalidIndex);
+        let index = index_of_inner(bytes, string::bytes(sub), from);
+        if (index == len && !string::is_empty(sub)) {
+            option::none()
+        } else {
+            option::some(index)
+        }
+    }
+
+    /// Split `s` by the `delimiter`, abort if the `delimiter` is empty
+    public fun split(s: &String, delimiter: &String): vector<String> {
+        split_inner(string::bytes(s), string::bytes(delimiter))
+    }
+
     //TODO introduce math module and replace this function
     fun pow10(n: u64): u256 {
         let i = 0;
@@ -167,6 +181,12 @@ module moveos_std::string_utils {
         });
         string::utf8(result)
     }
+
+    native fun check_utf8_inner(bytes: &vector<u8>): bool;
+    native fun index_of_inner(s: &vector<u8>, sub: &vector<u8>, from: u64): u64;
+    native fun split_inner(s: &vector<u8>, delimiter: &vector<u8>): vector<String>;
+    native fun parse_u256_inner(bytes: &vector<u8>): (bool, u256);
+    native fun u256_to_string_inner(value: u256): String;
     
     #[test]
     fun test_to_lower_case() {
@@ -278,4 +298,58 @@ module moveos_std::string_utils {
         let result = parse_decimal(&s, 1);
         std::debug::print(&result);
     }
+
+    #[test]
+    fun test_to_string(){
+        assert!(u8_to_string(0) == string::utf8(b"0"), 1);
+        assert!(u64_to_string(18446744073709551615) == string::utf8(b"18446744073709551615"), 2);
+        assert!(u128_to_string(1234567890) == string::utf8(b"1234567890"), 3);
+        let max = string::utf8(b"115792089237316195423570985008687907853269984665640564039457584007913129639935");
+        assert!(u256_to_string(parse_u256(&max)) == max, 4);
+    }
+
+    #[test]
+    fun test_parse_overflow_option(){
+        assert!(option::is_none(&parse_u64_option(&string::utf8(b"18446744073709551616"))), 1);
+        assert!(option::is_none(&parse_u256_option(&string::utf8(b"115792089237316195423570985008687907853269984665640564039457584007913129639936"))), 2);
+    }
+
+    #[test]
+    fun test_is_valid_utf8(){
+        assert!(is_valid_utf8(&b"hello"), 1);
+        assert!(is_valid_utf8(&x"e4bda0e5a5bd"), 2);
+        assert!(!is_valid_utf8(&x"e4bd"), 3);
+        assert!(!is_valid_utf8(&x"ff"), 4);
+    }
+
+    #[test]
+    fun test_find(){
+        let s = string::utf8(b"hello world");
+        assert!(find(&s, &string::utf8(b"o")) == option::some(4), 1);
+        assert!(find_from(&s, &string::utf8(b"o"), 5) == option::some(7), 2);
+        assert!(option::is_none(&find(&s, &string::utf8(b"x"))), 3);
+        assert!(find(&s, &string::utf8(b"")) == option::some(0), 4);
+        assert!(find_from(&s, &string::utf8(b""), 11) == option::some(11), 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code=ErrorInvalidIndex, location=moveos_std::string_utils)]
+    fun test_find_from_out_of_range(){
+        let s = string::utf8(b"hello");
+        find_from(&s, &string::utf8(b"o"), 6);
+    }
+
+    #[test]
+    fun test_split(){
+        let parts = split(&string::utf8(b"a::b::::c"), &string::utf8(b"::"));
+        assert!(parts == vector[string::utf8(b"a"), string::utf8(b"b"), string::utf8(b""), string::utf8(b"c")], 1);
+        let parts = split(&string::utf8(b"abc"), &string::utf8(b","));
+        assert!(parts == vector[string::utf8(b"abc")], 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code=ErrorEmptyDelimiter, location=moveos_std::string_utils)]
+    fun test_split_empty_delimiter(){
+        split(&string::utf8(b"abc"), &string::utf8(b""));
+    }
 }
\ No newline at end of file
@@ -3,6 +3,59 @@
 
 #[test_only]
 /// Module providing testing functionality. Only included for tests.
+/// The test environment keeps a deterministic clock, block height and random seed,
+/// they are only changed by the test code, so the time-dependent contracts can be tested deterministically.
 module moveos_std::test_helper {
     native public fun destroy<T>(x: T);
+
+    /// Set the timestamp in milliseconds of the test environment
+    native public fun set_timestamp_ms(timestamp_ms: u64);
+
+    /// Get the timestamp in milliseconds of the test environment, the default is 0
+    native public fun timestamp_ms(): u64;
+
+    /// Advance the timestamp of the test environment by `duration_ms` milliseconds
+    public fun advance_timestamp_ms(duration_ms: u64) {
+        set_timestamp_ms(timestamp_ms() + duration_ms);
+    }
+
+    /// Set the block height of the test environment
+    native public fun set_block_height(block_height: u64);
+
+    /// Get the block height of the test environment, the default is 0
+    native public fun block_height(): u64;
+
+    /// Advance the block height by `blocks`, and the timestamp by `blocks * block_interval_ms`
+    public fun advance_blocks(blocks: u64, block_interval_ms: u64) {
+        set_block_height(block_height() + blocks);
+        advance_timestamp_ms(blocks * block_interval_ms);
+    }
+
+    /// Set the random seed of the test environment, the random sequence is restarted
+    native public fun set_random_seed(seed: vector<u8>);
+
+    /// Get the next random u64 of the test environment, the same seed always generates the same sequence
+    native public fun random_u64(): u64;
+
+    #[test]
+    fun test_clock_and_block() {
+        set_timestamp_ms(1000);
+        advance_timestamp_ms(500);
+        assert!(timestamp_ms() == 1500, 1);
+        set_block_height(10);
+        advance_blocks(2, 3000);
+        assert!(block_height() == 12, 2);
+        assert!(timestamp_ms() == 7500, 3);
+    }
+
+    #[test]
+    fun test_random_seed() {
+        set_random_seed(b"seed");
+        let first = random_u64();
+        let second = random_u64();
+        assert!(first != second, 1);
+        set_random_seed(b"seed");
+        assert!(random_u64() == first, 2);
+        assert!(random_u64() == second, 3);
+    }
 }
@@ -0,0 +1,66 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The bulk operations of vector, implemented by the natives and charged per element.
+/// They are cheaper than the loops of `std::vector` functions in Move.
+module moveos_std::vector_utils {
+    use std::vector;
+
+    const ErrorIndexOutOfRange: u64 = 1;
+
+    /// Move all the elements of `other` to the end of `lhs`
+    public fun append<T>(lhs: &mut vector<T>, other: vector<T>) {
+        move_all_inner(lhs, &mut other);
+        vector::destroy_empty(other);
+    }
+
+    /// Reverse the order of the elements in `v` in place
+    native public fun reverse<T>(v: &mut vector<T>);
+
+    /// Copy the elements in the range `[start, end)` of `v` to a new vector
+    native public fun slice<T: copy>(v: &vector<T>, start: u64, end: u64): vector<T>;
+
+    native fun move_all_inner<T>(lhs: &mut vector<T>, other: &mut vector<T>);
+
+    #[test]
+    fun test_append() {
+        let v = vector[1u64, 2];
+        append(&mut v, vector[3, 4, 5]);
+        assert!(v == vector[1, 2, 3, 4, 5], 1);
+        append(&mut v, vector[]);
+        assert!(v == vector[1, 2, 3, 4, 5], 2);
+        let strs = vector<vector<u8>>[];
+        append(&mut strs, vector[b"a", b"b"]);
+        assert!(strs == vector[b"a", b"b"], 3);
+    }
+
+    #[test]
+    fun test_reverse() {
+        let v = vector[1u64, 2, 3, 4, 5];
+        reverse(&mut v);
+        assert!(v == vector[5, 4, 3, 2, 1], 1);
+        let v = vector[b"a", b"b"];
+        reverse(&mut v);
+        assert!(v == vector[b"b", b"a"], 2);
+        let v = vector<u8>[];
+        reverse(&mut v);
+        assert!(vector::is_empty(&v), 3);
+    }
+
+    #[test]
+    fun test_slice() {
+        let v = vector[1u64, 2, 3, 4, 5];
+        assert!(slice(&v, 1, 3) == vector[2, 3], 1);
+        assert!(slice(&v, 0, 5) == v, 2);
+        assert!(vector::is_empty(&slice(&v, 5, 5)), 3);
+        let v = vector[b"a", b"b", b"c"];
+        assert!(slice(&v, 2, 3) == vector[b"c"], 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorIndexOutOfRange, location = Self)]
+    fun test_slice_out_of_range() {
+        let v = vector[1u64, 2, 3];
+        slice(&v, 2, 4);
+    }
+}
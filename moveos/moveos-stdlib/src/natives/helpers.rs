@@ -6,6 +6,7 @@
 
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_core_types::account_address::AccountAddress;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
 use move_core_types::vm_status::StatusCode;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::values::{Reference, Struct, StructRef};
@@ -41,6 +42,31 @@ pub fn get_object_id_from_struct(raw_data: Struct) -> PartialVMResult<ObjectID>
     Ok(ObjectID::new(raw_object_id.into()))
 }
 
+/// The gas parameters of a native metered by the size of its input,
+/// such as the natives hashing, encoding, decoding or verifying a byte vector.
+#[derive(Debug, Clone)]
+pub struct InputGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+    /// Charged in addition when the input is malformed and the native fails
+    pub failure: InternalGas,
+}
+
+impl InputGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+            failure: 0.into(),
+        }
+    }
+
+    /// The cost of the native with an input of `bytes`
+    pub fn cost(&self, bytes: usize) -> InternalGas {
+        self.base + self.per_byte * NumBytes::new(bytes as u64)
+    }
+}
+
 pub fn make_module_natives(
     natives: impl IntoIterator<Item = (impl Into<String>, NativeFunction)>,
 ) -> impl Iterator<Item = (String, NativeFunction)> {
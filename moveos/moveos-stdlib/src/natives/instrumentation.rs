@@ -0,0 +1,209 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-native instrumentation for the gas calibration.
+//! When it is enabled, every native call records the wall-clock time, the memory allocations and the gas it charged,
+//! aggregated by the native function name.
+//! The allocations are only counted when the binary installs the `CountingAllocator` as the global allocator.
+
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::{NativeFunction, NativeFunctionTable};
+use move_vm_types::natives::function::NativeResult;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Set the env to `1` or `true` to enable the instrumentation at startup
+pub const NATIVE_INSTRUMENTATION_ENV: &str = "MOVEOS_NATIVE_INSTRUMENTATION";
+
+static ENABLED: Lazy<AtomicBool> = Lazy::new(|| {
+    let enabled = std::env::var(NATIVE_INSTRUMENTATION_ENV)
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    AtomicBool::new(enabled)
+});
+
+static NATIVE_STATS: Lazy<Mutex<BTreeMap<String, NativeStats>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The aggregated statistics of a native function
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeStats {
+    pub calls: u64,
+    /// The calls that returned an abort code or an error
+    pub failures: u64,
+    pub total_time_nanos: u64,
+    pub max_time_nanos: u64,
+    pub total_gas: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl NativeStats {
+    pub fn avg_time_nanos(&self) -> u64 {
+        self.total_time_nanos.checked_div(self.calls).unwrap_or(0)
+    }
+
+    pub fn avg_gas(&self) -> u64 {
+        self.total_gas.checked_div(self.calls).unwrap_or(0)
+    }
+
+    fn record(&mut self, time_nanos: u64, gas: u64, allocations: u64, bytes: u64, failed: bool) {
+        self.calls += 1;
+        if failed {
+            self.failures += 1;
+        }
+        self.total_time_nanos = self.total_time_nanos.saturating_add(time_nanos);
+        self.max_time_nanos = self.max_time_nanos.max(time_nanos);
+        self.total_gas = self.total_gas.saturating_add(gas);
+        self.allocations = self.allocations.saturating_add(allocations);
+        self.allocated_bytes = self.allocated_bytes.saturating_add(bytes);
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Return the statistics of all the called natives, keyed by `address::module::function`
+pub fn snapshot() -> BTreeMap<String, NativeStats> {
+    NATIVE_STATS.lock().clone()
+}
+
+pub fn reset() {
+    NATIVE_STATS.lock().clear();
+}
+
+/// Wrap every native in the table to record the statistics when the instrumentation is enabled.
+/// The overhead is a relaxed atomic load per call when it is disabled.
+pub fn instrument_natives(table: NativeFunctionTable) -> NativeFunctionTable {
+    table
+        .into_iter()
+        .map(|(address, module_name, func_name, func)| {
+            let name = format!(
+                "{}::{}::{}",
+                address.short_str_lossless(),
+                module_name,
+                func_name
+            );
+            (address, module_name, func_name, instrument(name, func))
+        })
+        .collect()
+}
+
+fn instrument(name: String, func: NativeFunction) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        if !is_enabled() {
+            return func(context, ty_args, args);
+        }
+        let (allocations_before, bytes_before) = thread_allocation_counters();
+        let start = Instant::now();
+        let result = func(context, ty_args, args);
+        let time_nanos = start.elapsed().as_nanos() as u64;
+        let (allocations_after, bytes_after) = thread_allocation_counters();
+        record(
+            &name,
+            &result,
+            time_nanos,
+            allocations_after - allocations_before,
+            bytes_after - bytes_before,
+        );
+        result
+    })
+}
+
+fn record(
+    name: &str,
+    result: &PartialVMResult<NativeResult>,
+    time_nanos: u64,
+    allocations: u64,
+    bytes: u64,
+) {
+    let (gas, failed) = match result {
+        Ok(native_result) => (u64::from(native_result.cost), native_result.result.is_err()),
+        Err(_) => (0, true),
+    };
+    let mut stats = NATIVE_STATS.lock();
+    stats
+        .entry(name.to_string())
+        .or_default()
+        .record(time_nanos, gas, allocations, bytes, failed);
+}
+
+/// Return the count and the bytes of the allocations of the current thread
+pub fn thread_allocation_counters() -> (u64, u64) {
+    (
+        ALLOCATIONS.with(|c| c.get()),
+        ALLOCATED_BYTES.with(|c| c.get()),
+    )
+}
+
+/// A global allocator that counts the allocations of each thread, delegating to the system allocator.
+/// Install it in a calibration binary with `#[global_allocator]`.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+fn count_allocation(size: usize) {
+    // The thread local may be destroyed when the thread exits, ignore the allocations after that
+    let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+    let _ = ALLOCATED_BYTES.try_with(|c| c.set(c.get() + size as u64));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_stats_record() {
+        let mut stats = NativeStats::default();
+        stats.record(100, 10, 2, 64, false);
+        stats.record(300, 30, 0, 0, true);
+        assert_eq!(stats.calls, 2);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.max_time_nanos, 300);
+        assert_eq!(stats.avg_time_nanos(), 200);
+        assert_eq!(stats.avg_gas(), 20);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.allocated_bytes, 64);
+        assert_eq!(NativeStats::default().avg_gas(), 0);
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The per-transaction memory accountant of the natives.
+//! The gas of a native is charged after it returns, so a native could allocate unbounded memory before
+//! the gas catches up. The natives allocating by the size of their input, such as the table, json and bcs natives,
+//! report the bytes to the accountant before allocating, and the transaction is aborted once the quota is exceeded.
+//! The quota is an entry of the gas schedule, so every node aborts the same transactions.
+
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::vm_status::StatusCode;
+use move_vm_runtime::native_functions::NativeContext;
+use std::cell::Cell;
+
+/// The default memory quota of the natives in a transaction, in bytes
+pub const DEFAULT_NATIVE_MEMORY_QUOTA: u64 = 64 * 1024 * 1024;
+
+/// The sub status of the OUT_OF_GAS error when the natives exceed the memory quota of the transaction
+pub const NATIVE_MEMORY_QUOTA_EXCEEDED_SUB_STATUS: u64 = 2;
+
+/// The memory accountant of a transaction, it is attached to the NativeContextExtensions of the session.
+/// The buffers a native frees before it returns are released back to the quota, the values returned to the VM
+/// and the states cached by the session are held until the session finishes.
+#[derive(Tid)]
+pub struct MemoryAccountant {
+    quota: u64,
+    allocated: Cell<u64>,
+    exempt: Cell<bool>,
+}
+
+impl MemoryAccountant {
+    pub fn new(quota: u64) -> Self {
+        Self {
+            quota,
+            allocated: Cell::new(0),
+            exempt: Cell::new(false),
+        }
+    }
+
+    /// The accountant of the sessions which are not limited, such as the genesis
+    pub fn unlimited() -> Self {
+        Self::new(u64::MAX)
+    }
+
+    pub fn quota(&self) -> u64 {
+        self.quota
+    }
+
+    /// The bytes allocated by the natives in the transaction
+    pub fn allocated(&self) -> u64 {
+        self.allocated.get()
+    }
+
+    /// Exempt the allocations from the quota, such as the allocations of the system functions,
+    /// they are executed without metering, so a transaction using up the quota can not make them fail.
+    pub fn set_exempt(&self, exempt: bool) {
+        self.exempt.set(exempt);
+    }
+
+    pub fn is_exempt(&self) -> bool {
+        self.exempt.get()
+    }
+
+    /// Account the `bytes` about to be allocated by a native, fail if the quota is exceeded
+    pub fn allocate(&self, bytes: u64) -> PartialVMResult<()> {
+        if self.exempt.get() {
+            return Ok(());
+        }
+        let allocated = self.allocated.get().saturating_add(bytes);
+        self.allocated.set(allocated);
+        if allocated > self.quota {
+            return Err(PartialVMError::new(StatusCode::OUT_OF_GAS)
+                .with_sub_status(NATIVE_MEMORY_QUOTA_EXCEEDED_SUB_STATUS)
+                .with_message(format!(
+                    "The natives allocated {} bytes, exceeding the memory quota {} of the transaction",
+                    allocated, self.quota
+                )));
+        }
+        Ok(())
+    }
+
+    /// Give the `bytes` freed by a native back to the quota
+    pub fn release(&self, bytes: u64) {
+        if self.exempt.get() {
+            return;
+        }
+        self.allocated
+            .set(self.allocated.get().saturating_sub(bytes));
+    }
+}
+
+impl Default for MemoryAccountant {
+    fn default() -> Self {
+        Self::new(DEFAULT_NATIVE_MEMORY_QUOTA)
+    }
+}
+
+/// Report the `bytes` about to be allocated by the native to the memory accountant of the transaction
+pub fn allocate(context: &NativeContext, bytes: u64) -> PartialVMResult<()> {
+    context
+        .extensions()
+        .get::<MemoryAccountant>()
+        .allocate(bytes)
+}
+
+/// Release the `bytes` allocated by the native and freed before it returns, such as the input it parsed
+pub fn release(context: &NativeContext, bytes: u64) {
+    context
+        .extensions()
+        .get::<MemoryAccountant>()
+        .release(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_quota() {
+        let accountant = MemoryAccountant::new(100);
+        accountant.allocate(60).unwrap();
+        accountant.allocate(40).unwrap();
+        assert_eq!(accountant.allocated(), 100);
+        let err = accountant.allocate(1).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(
+            err.sub_status(),
+            Some(NATIVE_MEMORY_QUOTA_EXCEEDED_SUB_STATUS)
+        );
+        assert!(MemoryAccountant::unlimited().allocate(u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_release_and_exempt() {
+        let accountant = MemoryAccountant::new(100);
+        accountant.allocate(80).unwrap();
+        accountant.release(50);
+        assert_eq!(accountant.allocated(), 30);
+        accountant.allocate(70).unwrap();
+
+        // The exempted allocations are neither counted nor released
+        accountant.set_exempt(true);
+        accountant.allocate(1000).unwrap();
+        accountant.release(1000);
+        assert_eq!(accountant.allocated(), 100);
+        accountant.set_exempt(false);
+        assert!(accountant.allocate(1).is_err());
+    }
+}
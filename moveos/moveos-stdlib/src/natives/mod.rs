@@ -7,6 +7,8 @@ use move_vm_runtime::native_functions::{make_table_from_iter, NativeFunctionTabl
 use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
 
 pub mod helpers;
+pub mod instrumentation;
+pub mod memory_accountant;
 pub mod moveos_stdlib;
 
 #[derive(Debug, Clone)]
@@ -23,6 +25,12 @@ pub struct GasParameters {
     pub move_module: moveos_stdlib::move_module::GasParameters,
     pub object: moveos_stdlib::object::GasParameters,
     pub json: moveos_stdlib::json::GasParameters,
+    pub dispatch: moveos_stdlib::dispatch::GasParameters,
+    pub math: moveos_stdlib::math::GasParameters,
+    pub fixed_point64: moveos_stdlib::fixed_point64::GasParameters,
+    pub string_utils: moveos_stdlib::string_utils::GasParameters,
+    pub vector_utils: moveos_stdlib::vector_utils::GasParameters,
+    pub address: moveos_stdlib::address::GasParameters,
 }
 
 impl GasParameters {
@@ -40,6 +48,12 @@ impl GasParameters {
             move_module: moveos_stdlib::move_module::GasParameters::zeros(),
             object: moveos_stdlib::object::GasParameters::zeros(),
             json: moveos_stdlib::json::GasParameters::zeros(),
+            dispatch: moveos_stdlib::dispatch::GasParameters::zeros(),
+            math: moveos_stdlib::math::GasParameters::zeros(),
+            fixed_point64: moveos_stdlib::fixed_point64::GasParameters::zeros(),
+            string_utils: moveos_stdlib::string_utils::GasParameters::zeros(),
+            vector_utils: moveos_stdlib::vector_utils::GasParameters::zeros(),
+            address: moveos_stdlib::address::GasParameters::zeros(),
         }
     }
 }
@@ -96,6 +110,27 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
     );
     add_natives!("object", moveos_stdlib::object::make_all(gas_params.object));
     add_natives!("json", moveos_stdlib::json::make_all(gas_params.json));
+    add_natives!(
+        "dispatch",
+        moveos_stdlib::dispatch::make_all(gas_params.dispatch)
+    );
+    add_natives!("math", moveos_stdlib::math::make_all(gas_params.math));
+    add_natives!(
+        "fixed_point64",
+        moveos_stdlib::fixed_point64::make_all(gas_params.fixed_point64)
+    );
+    add_natives!(
+        "string_utils",
+        moveos_stdlib::string_utils::make_all(gas_params.string_utils)
+    );
+    add_natives!(
+        "vector_utils",
+        moveos_stdlib::vector_utils::make_all(gas_params.vector_utils)
+    );
+    add_natives!(
+        "address",
+        moveos_stdlib::address::make_all(gas_params.address)
+    );
 
     let moveos_native_fun_table = make_table_from_iter(MOVEOS_STD_ADDRESS, natives);
     native_fun_table.extend(moveos_native_fun_table);
@@ -104,5 +139,5 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
         raw_table::table_natives(MOVEOS_STD_ADDRESS, gas_params.table_extension);
     native_fun_table.extend(raw_table_fun_table);
 
-    native_fun_table
+    instrumentation::instrument_natives(native_fun_table)
 }
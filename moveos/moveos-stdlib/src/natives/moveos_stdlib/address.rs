@@ -0,0 +1,152 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::BaseGasParameter;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{account_address::AccountAddress, u256::U256};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_ADDRESS_PARSE_ERROR: u64 = 1;
+
+/// Interpret the address as the bytes of a big-endian integer
+pub fn address_to_u256(address: AccountAddress) -> U256 {
+    let mut bytes = address.into_bytes();
+    bytes.reverse();
+    U256::from_le_bytes(&bytes)
+}
+
+/// Encode the u256 as a big-endian integer
+pub fn u256_to_address(value: U256) -> AccountAddress {
+    let mut bytes = value.to_le_bytes();
+    bytes.reverse();
+    AccountAddress::new(bytes)
+}
+
+/***************************************************************************************************
+ * native public fun from_bytes(bytes: vector<u8>): address;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_from_bytes(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    match AccountAddress::from_bytes(bytes) {
+        Ok(address) => Ok(NativeResult::ok(
+            gas_params.base,
+            smallvec![Value::address(address)],
+        )),
+        Err(_) => Ok(NativeResult::err(gas_params.base, E_ADDRESS_PARSE_ERROR)),
+    }
+}
+
+/***************************************************************************************************
+ * native public fun to_u256(a: address): u256;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_to_u256(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let address = pop_arg!(args, AccountAddress);
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u256(address_to_u256(address))],
+    ))
+}
+
+/***************************************************************************************************
+ * native public fun from_u256(n: u256): address;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_from_u256(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let value = pop_arg!(args, U256);
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::address(u256_to_address(value))],
+    ))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub from_bytes: BaseGasParameter,
+    pub to_u256: BaseGasParameter,
+    pub from_u256: BaseGasParameter,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            from_bytes: BaseGasParameter::zeros(),
+            to_u256: BaseGasParameter::zeros(),
+            from_u256: BaseGasParameter::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "from_bytes",
+            make_native(gas_params.from_bytes, native_from_bytes),
+        ),
+        ("to_u256", make_native(gas_params.to_u256, native_to_u256)),
+        (
+            "from_u256",
+            make_native(gas_params.from_u256, native_from_u256),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_u256_roundtrip() {
+        assert_eq!(address_to_u256(AccountAddress::ONE), U256::one());
+        assert_eq!(u256_to_address(U256::from(2u64)), AccountAddress::TWO);
+        let address = AccountAddress::random();
+        assert_eq!(u256_to_address(address_to_u256(address)), address);
+        assert_eq!(
+            u256_to_address(U256::max_value()),
+            AccountAddress::new([0xff; AccountAddress::LENGTH])
+        );
+    }
+}
@@ -1,29 +1,21 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
+use crate::natives::memory_accountant;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
-use move_core_types::gas_algebra::InternalGas;
 use move_core_types::vm_status::StatusCode;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    views::ValueView,
 };
 use smallvec::smallvec;
 use std::collections::VecDeque;
 
 const E_TYPE_NOT_MATCH: u64 = 1;
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
-
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /// Rust implementation of Move's `native public(friend) fun from_bytes<T>(vector<u8>): T in bcs module`
 /// Bytes are in BCS (Binary Canonical Serialization) format.
@@ -37,8 +29,6 @@ fn native_from_bytes(
     debug_assert_eq!(ty_args.len(), 1);
     debug_assert_eq!(args.len(), 1);
 
-    let cost = gas_params.base;
-
     // TODO(Gas): charge for getting the layout
     let layout = context.type_to_type_layout(&ty_args[0])?.ok_or_else(|| {
         PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR).with_message(format!(
@@ -48,13 +38,21 @@ fn native_from_bytes(
     })?;
 
     let bytes = pop_arg!(args, Vec<u8>);
-    let val = match Value::simple_deserialize(&bytes, &layout) {
+    let cost = gas_params.cost(bytes.len());
+    memory_accountant::allocate(context, bytes.len() as u64)?;
+    let val = Value::simple_deserialize(&bytes, &layout);
+    // The input is dropped after deserializing
+    memory_accountant::release(context, bytes.len() as u64);
+    let val = match val {
         Some(val) => val,
         None => {
-            return Ok(NativeResult::err(cost, E_TYPE_NOT_MATCH));
+            return Ok(NativeResult::err(
+                cost + gas_params.failure,
+                E_TYPE_NOT_MATCH,
+            ));
         }
     };
-    // TODO(gas): charge gas for deserialization
+    memory_accountant::allocate(context, val.legacy_abstract_memory_size().into())?;
 
     Ok(NativeResult::ok(cost, smallvec![val]))
 }
@@ -0,0 +1,140 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    vm_status::StatusCode,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use moveos_types::{move_types::FunctionId, transaction::FunctionCall};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// A dispatched call requested by the `caller` module.
+#[derive(Debug, Clone)]
+pub struct DispatchCall {
+    pub caller: ModuleId,
+    pub call: FunctionCall,
+}
+
+/// The native dispatch context.
+/// The dispatched calls are collected here, and executed by the executor after the caller returns.
+#[derive(Default, Tid)]
+pub struct NativeDispatchContext {
+    pending_calls: Vec<DispatchCall>,
+}
+
+impl NativeDispatchContext {
+    /// Take the pending dispatched calls, the context is cleared.
+    pub fn take_pending_calls(&mut self) -> Vec<DispatchCall> {
+        std::mem::take(&mut self.pending_calls)
+    }
+}
+
+/***************************************************************************************************
+ * native fun request_dispatch<W>(
+ *      module_address: address,
+ *      module_name: vector<u8>,
+ *      function_name: vector<u8>,
+ *      args: vector<vector<u8>>
+ * );
+ * The module of `W` is the caller of the dispatched call.
+ *
+ *   gas cost: base_cost + per_byte * (bytes of names and args)
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct RequestDispatchGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl RequestDispatchGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
+fn native_request_dispatch(
+    gas_params: &RequestDispatchGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 4);
+
+    let caller = match context.type_to_type_tag(&ty_args[0])? {
+        TypeTag::Struct(struct_tag) => struct_tag.module_id(),
+        _ => {
+            return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                .with_message("Expected a struct type as the dispatch witness".to_owned()))
+        }
+    };
+
+    let call_args = pop_arg!(args, Vec<Value>)
+        .into_iter()
+        .map(|arg| arg.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<Vec<u8>>>>()?;
+    let function_name = pop_arg!(args, Vec<u8>);
+    let module_name = pop_arg!(args, Vec<u8>);
+    let module_address = pop_arg!(args, AccountAddress);
+
+    let total_bytes = call_args.iter().map(|arg| arg.len()).sum::<usize>()
+        + function_name.len()
+        + module_name.len();
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(total_bytes as u64);
+
+    let to_identifier = |bytes: Vec<u8>| {
+        Identifier::from_utf8(bytes).map_err(|e| {
+            PartialVMError::new(StatusCode::TYPE_RESOLUTION_FAILURE).with_message(e.to_string())
+        })
+    };
+    let module_id = ModuleId::new(module_address, to_identifier(module_name)?);
+    let function_id = FunctionId::new(module_id, to_identifier(function_name)?);
+
+    let dispatch_context = context.extensions_mut().get_mut::<NativeDispatchContext>();
+    dispatch_context.pending_calls.push(DispatchCall {
+        caller,
+        call: FunctionCall::new(function_id, vec![], call_args),
+    });
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub request_dispatch: RequestDispatchGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            request_dispatch: RequestDispatchGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "request_dispatch",
+        make_native(gas_params.request_dispatch, native_request_dispatch),
+    )];
+
+    make_module_natives(natives)
+}
@@ -22,6 +22,11 @@ pub struct NativeEventContext {
 }
 
 impl NativeEventContext {
+    /// The events emitted so far in the session
+    pub fn events(&self) -> &[(StructTag, Vec<u8>)] {
+        &self.events
+    }
+
     pub fn into_events(self) -> Vec<(StructTag, Vec<u8>)> {
         self.events
     }
@@ -0,0 +1,204 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::math::{sqrt_u256, u256_to_u128, E_OVERFLOW};
+use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::BaseGasParameter;
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use primitive_types::U256;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_LOG_OF_ZERO: u64 = 3;
+
+/// The fractional bits of the FixedPoint64, the raw value is `x * 2^64`.
+const FRACTIONAL_BITS: usize = 64;
+
+/// The raw value of `sqrt(x)`
+pub fn sqrt_raw(x: u128) -> u128 {
+    // sqrt(x * 2^64) = sqrt(x) * 2^32, shift 64 bits more to keep the fractional bits
+    sqrt_u256(U256::from(x) << FRACTIONAL_BITS).low_u128()
+}
+
+/// The raw value of `x ^ n`, returns `Err(E_OVERFLOW)` if the result does not fit in FixedPoint64.
+pub fn pow_raw(x: u128, n: u64) -> Result<u128, u64> {
+    let mut result = U256::one() << FRACTIONAL_BITS;
+    let mut base = U256::from(x);
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = U256::from(u256_to_u128((result * base) >> FRACTIONAL_BITS)?);
+        }
+        n >>= 1;
+        if n > 0 {
+            base = U256::from(u256_to_u128((base * base) >> FRACTIONAL_BITS)?);
+        }
+    }
+    Ok(result.low_u128())
+}
+
+/// The raw value of `log2(x) + 64`, the offset keeps the result positive for `x` in `(0, 1)`.
+/// As the raw value of `x` is `x * 2^64`, it is the `log2` of the raw value.
+pub fn log2_plus_64_raw(x: u128) -> Result<u128, u64> {
+    if x == 0 {
+        return Err(E_LOG_OF_ZERO);
+    }
+    let integer_part = (127 - x.leading_zeros()) as usize;
+    // Normalize `x` to `y` in [1, 2), then get the fractional bits one by one by squaring `y`
+    let mut y = if integer_part >= FRACTIONAL_BITS {
+        U256::from(x) >> (integer_part - FRACTIONAL_BITS)
+    } else {
+        U256::from(x) << (FRACTIONAL_BITS - integer_part)
+    };
+    let two = U256::from(2u8) << FRACTIONAL_BITS;
+    let mut fractional_part: u128 = 0;
+    for bit in (0..FRACTIONAL_BITS).rev() {
+        y = (y * y) >> FRACTIONAL_BITS;
+        if y >= two {
+            fractional_part |= 1u128 << bit;
+            y >>= 1;
+        }
+    }
+    Ok(((integer_part as u128) << FRACTIONAL_BITS) | fractional_part)
+}
+
+/***************************************************************************************************
+ * native fun sqrt_raw(x: u128): u128;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_sqrt_raw(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let x = pop_arg!(args, u128);
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u128(sqrt_raw(x))],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun pow_raw(x: u128, n: u64): u128;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_pow_raw(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let n = pop_arg!(args, u64);
+    let x = pop_arg!(args, u128);
+    match pow_raw(x, n) {
+        Ok(result) => Ok(NativeResult::ok(
+            gas_params.base,
+            smallvec![Value::u128(result)],
+        )),
+        Err(code) => Ok(NativeResult::err(gas_params.base, code)),
+    }
+}
+
+/***************************************************************************************************
+ * native fun log2_plus_64_raw(x: u128): u128;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_log2_plus_64_raw(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let x = pop_arg!(args, u128);
+    match log2_plus_64_raw(x) {
+        Ok(result) => Ok(NativeResult::ok(
+            gas_params.base,
+            smallvec![Value::u128(result)],
+        )),
+        Err(code) => Ok(NativeResult::err(gas_params.base, code)),
+    }
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub sqrt_raw: BaseGasParameter,
+    pub pow_raw: BaseGasParameter,
+    pub log2_plus_64_raw: BaseGasParameter,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            sqrt_raw: BaseGasParameter::zeros(),
+            pow_raw: BaseGasParameter::zeros(),
+            log2_plus_64_raw: BaseGasParameter::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "sqrt_raw",
+            make_native(gas_params.sqrt_raw, native_sqrt_raw),
+        ),
+        ("pow_raw", make_native(gas_params.pow_raw, native_pow_raw)),
+        (
+            "log2_plus_64_raw",
+            make_native(gas_params.log2_plus_64_raw, native_log2_plus_64_raw),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u128 = 1 << 64;
+
+    #[test]
+    fn test_log2_plus_64_raw() {
+        assert_eq!(log2_plus_64_raw(ONE).unwrap(), 64 * ONE);
+        assert_eq!(log2_plus_64_raw(8 * ONE).unwrap(), 67 * ONE);
+        assert_eq!(log2_plus_64_raw(ONE / 2).unwrap(), 63 * ONE);
+        // log2(3) = 1.584962500721156
+        let log2_3 = log2_plus_64_raw(3 * ONE).unwrap() - 64 * ONE;
+        assert!((log2_3 as f64 / ONE as f64 - 1.584962500721156).abs() < 1e-15);
+        assert_eq!(log2_plus_64_raw(0), Err(E_LOG_OF_ZERO));
+    }
+
+    #[test]
+    fn test_sqrt_and_pow_raw() {
+        assert_eq!(sqrt_raw(4 * ONE), 2 * ONE);
+        assert_eq!(sqrt_raw(ONE / 4), ONE / 2);
+        assert_eq!(pow_raw(2 * ONE, 10).unwrap(), 1024 * ONE);
+        assert_eq!(pow_raw(ONE / 2, 3).unwrap(), ONE / 8);
+        assert_eq!(pow_raw(2 * ONE, 64), Err(E_OVERFLOW));
+    }
+}
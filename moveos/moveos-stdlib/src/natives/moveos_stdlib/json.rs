@@ -1,7 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::helpers::{make_module_natives, make_native, InputGasParameters};
+use crate::natives::memory_accountant;
 use anyhow::Result;
 use log::debug;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
@@ -9,14 +10,15 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
 use move_core_types::u256::U256;
 use move_core_types::value::MoveStructLayout;
+use move_core_types::value::MoveTypeLayout;
 use move_core_types::vm_status::StatusCode;
-use move_core_types::{gas_algebra::InternalGas, value::MoveTypeLayout};
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
     values::{Struct, Value, Vector},
+    views::ValueView,
 };
 use moveos_types::addresses::MOVE_STD_ADDRESS;
 use moveos_types::move_std::string::MoveString;
@@ -207,16 +209,7 @@ fn json_obj_to_key_value_pairs(json_obj: &serde_json::Value) -> Result<Vec<(Stri
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct FromBytesGasParameters {
-    pub base: InternalGas,
-}
-
-impl FromBytesGasParameters {
-    pub fn zeros() -> Self {
-        Self { base: 0.into() }
-    }
-}
+pub type FromBytesGasParameters = InputGasParameters;
 
 /// Rust implementation of Move's `native fun native_from_json<T>(json_str: vector<u8>): T` in json module
 /// Input arguments:
@@ -231,7 +224,6 @@ fn native_from_json(
     debug_assert_eq!(ty_args.len(), 1);
     debug_assert_eq!(args.len(), 1);
 
-    let cost = gas_params.base;
     let type_param = &ty_args[0];
     // TODO(Gas): charge for getting the layout
     let layout = context
@@ -246,30 +238,42 @@ fn native_from_json(
         })?;
 
     let bytes = pop_arg!(args, Vec<u8>);
+    let cost = gas_params.cost(bytes.len());
+    let failure_cost = cost + gas_params.failure;
+    let bytes_len = bytes.len() as u64;
+    memory_accountant::allocate(context, bytes_len)?;
 
     // If layout is not MoveTypeLayout::MoveStructLayout, return error
     if let MoveTypeLayout::Struct(struct_layout) = layout {
-        let result = match parse_struct_value_from_bytes(&struct_layout, bytes, context) {
+        let parse_result = parse_struct_value_from_bytes(&struct_layout, bytes, context);
+        // The input is dropped after parsing
+        memory_accountant::release(context, bytes_len);
+        let (result, cost) = match parse_result {
             Ok(val) => {
                 //Pack the MoveOption Some
-                Struct::pack(vec![Vector::pack(type_param, vec![Value::struct_(val)])
-                    .map_err(|e| {
-                        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
-                            .with_message(format!("Failed to pack MoveOption: {:?}", e))
-                    })?])
+                let result =
+                    Struct::pack(vec![Vector::pack(type_param, vec![Value::struct_(val)])
+                        .map_err(|e| {
+                            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                                .with_message(format!("Failed to pack MoveOption: {:?}", e))
+                        })?]);
+                (result, cost)
             }
             Err(e) => {
                 debug!("Failed to parse struct_value: {:?}", e);
                 //Pack the MoveOption None
-                Struct::pack(vec![Vector::pack(type_param, vec![]).map_err(|e| {
+                let result = Struct::pack(vec![Vector::pack(type_param, vec![]).map_err(|e| {
                     PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
                         .with_message(format!("Failed to pack MoveOption: {:?}", e))
-                })?])
+                })?]);
+                (result, failure_cost)
             }
         };
-        Ok(NativeResult::ok(cost, smallvec![Value::struct_(result)]))
+        let result = Value::struct_(result);
+        memory_accountant::allocate(context, result.legacy_abstract_memory_size().into())?;
+        Ok(NativeResult::ok(cost, smallvec![result]))
     } else {
-        Ok(NativeResult::err(cost, E_TYPE_NOT_MATCH))
+        Ok(NativeResult::err(failure_cost, E_TYPE_NOT_MATCH))
     }
 }
 
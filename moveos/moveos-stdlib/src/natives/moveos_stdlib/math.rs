@@ -0,0 +1,185 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use crate::natives::BaseGasParameter;
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use primitive_types::U256;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_DIVIDE_BY_ZERO: u64 = 1;
+pub const E_OVERFLOW: u64 = 2;
+
+/// `a * b / c` rounding down, the intermediate product is 256-bit so it never overflows.
+/// Returns `Err(E_DIVIDE_BY_ZERO)` or `Err(E_OVERFLOW)` if the result does not fit in u128.
+pub fn mul_div_u128(a: u128, b: u128, c: u128) -> Result<u128, u64> {
+    if c == 0 {
+        return Err(E_DIVIDE_BY_ZERO);
+    }
+    u256_to_u128(U256::from(a) * U256::from(b) / U256::from(c))
+}
+
+/// The integer square root, rounding down.
+pub fn sqrt_u128(x: u128) -> u128 {
+    sqrt_u256(U256::from(x)).low_u128()
+}
+
+/// `base ^ exp`, returns `Err(E_OVERFLOW)` if the result does not fit in u128.
+pub fn pow_u128(base: u128, exp: u64) -> Result<u128, u64> {
+    let mut result: u128 = 1;
+    let mut base = base;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.checked_mul(base).ok_or(E_OVERFLOW)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base).ok_or(E_OVERFLOW)?;
+        }
+    }
+    Ok(result)
+}
+
+pub(crate) fn sqrt_u256(x: U256) -> U256 {
+    if x.is_zero() {
+        return x;
+    }
+    // The Newton's method, start from a value not less than the root, so the sequence is decreasing.
+    let mut root = U256::one() << ((x.bits() + 1) / 2);
+    loop {
+        let next = (root + x / root) >> 1;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+pub(crate) fn u256_to_u128(x: U256) -> Result<u128, u64> {
+    if x > U256::from(u128::MAX) {
+        Err(E_OVERFLOW)
+    } else {
+        Ok(x.low_u128())
+    }
+}
+
+/***************************************************************************************************
+ * native fun mul_div_u128(a: u128, b: u128, c: u128): u128;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_mul_div_u128(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let c = pop_arg!(args, u128);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+    match mul_div_u128(a, b, c) {
+        Ok(result) => Ok(NativeResult::ok(
+            gas_params.base,
+            smallvec![Value::u128(result)],
+        )),
+        Err(code) => Ok(NativeResult::err(gas_params.base, code)),
+    }
+}
+
+/***************************************************************************************************
+ * native fun sqrt_u128(x: u128): u128;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_sqrt_u128(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let x = pop_arg!(args, u128);
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u128(sqrt_u128(x))],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun pow_u128(base: u128, exp: u64): u128;
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_pow_u128(
+    gas_params: &BaseGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let exp = pop_arg!(args, u64);
+    let base = pop_arg!(args, u128);
+    match pow_u128(base, exp) {
+        Ok(result) => Ok(NativeResult::ok(
+            gas_params.base,
+            smallvec![Value::u128(result)],
+        )),
+        Err(code) => Ok(NativeResult::err(gas_params.base, code)),
+    }
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub mul_div_u128: BaseGasParameter,
+    pub sqrt_u128: BaseGasParameter,
+    pub pow_u128: BaseGasParameter,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            mul_div_u128: BaseGasParameter::zeros(),
+            sqrt_u128: BaseGasParameter::zeros(),
+            pow_u128: BaseGasParameter::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "mul_div_u128",
+            make_native(gas_params.mul_div_u128, native_mul_div_u128),
+        ),
+        (
+            "sqrt_u128",
+            make_native(gas_params.sqrt_u128, native_sqrt_u128),
+        ),
+        (
+            "pow_u128",
+            make_native(gas_params.pow_u128, native_pow_u128),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
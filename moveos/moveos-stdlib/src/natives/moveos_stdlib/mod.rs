@@ -1,13 +1,19 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod address;
 pub mod bcs;
+pub mod dispatch;
 pub mod event;
+pub mod fixed_point64;
 pub mod json;
+pub mod math;
 pub mod move_module;
 pub mod object;
 pub mod raw_table;
 pub mod rlp;
 pub mod signer;
+pub mod string_utils;
 pub mod test_helper;
 pub mod type_info;
+pub mod vector_utils;
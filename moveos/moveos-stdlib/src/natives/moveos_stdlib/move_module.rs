@@ -28,8 +28,7 @@ use move_vm_types::{
 };
 use moveos_stdlib_builder::dependency_order::sort_by_dependency_order;
 use smallvec::smallvec;
-use std::collections::{BTreeSet, HashMap, VecDeque};
-use std::hash::Hash;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
 // ========================================================================================
 
@@ -286,8 +285,8 @@ fn module_replace_template<C, F, TF>(
     type_cast_fn: TF,
 ) -> PartialVMResult<NativeResult>
 where
-    C: PartialEq + Clone + Eq + std::fmt::Debug + Hash,
-    F: Fn(&mut CompiledModule, &HashMap<C, C>) -> PartialVMResult<()>,
+    C: Clone + Ord + std::fmt::Debug,
+    F: Fn(&mut CompiledModule, &BTreeMap<C, C>) -> PartialVMResult<()>,
     TF: Fn(Value) -> PartialVMResult<C>,
 {
     debug_assert!(args.len() == 3, "Wrong number of arguments");
@@ -302,7 +301,7 @@ where
     let new_values = new_vec.unpack(&element_type, vec_len)?;
     let old_values = old_vec.unpack(&element_type, vec_len)?;
 
-    let address_mapping: HashMap<C, C> = zip_eq(old_values, new_values)
+    let address_mapping: BTreeMap<C, C> = zip_eq(old_values, new_values)
         .map(|(a, b)| Ok((type_cast_fn(a)?, type_cast_fn(b)?)))
         .collect::<PartialVMResult<_>>()?;
 
@@ -529,7 +528,7 @@ fn movevalue_replace_addresses(value: &mut MoveValue, f: &dyn Fn(&mut AccountAdd
 
 fn module_replace_identifiers(
     module: &mut CompiledModule,
-    identifier_mapping: &HashMap<Identifier, Identifier>,
+    identifier_mapping: &BTreeMap<Identifier, Identifier>,
 ) -> PartialVMResult<()> {
     for i in 0..module.identifiers.len() {
         if let Some(new_ident) = identifier_mapping.get(&module.identifiers[i]) {
@@ -543,7 +542,7 @@ fn module_replace_identifiers(
 
 fn module_replace_address_identifiers(
     module: &mut CompiledModule,
-    address_mapping: &HashMap<AccountAddress, AccountAddress>,
+    address_mapping: &BTreeMap<AccountAddress, AccountAddress>,
 ) -> PartialVMResult<()> {
     // replace addresses in address identifiers.
     for addr in module.address_identifiers.iter_mut() {
@@ -556,7 +555,7 @@ fn module_replace_address_identifiers(
 
 fn module_replace_constant_addresses(
     module: &mut CompiledModule,
-    address_mapping: &HashMap<AccountAddress, AccountAddress>,
+    address_mapping: &BTreeMap<AccountAddress, AccountAddress>,
 ) -> PartialVMResult<()> {
     // replace addresses in constant.
     for constant in module.constant_pool.iter_mut() {
@@ -653,10 +652,10 @@ impl ConstantOps for U256 {
 
 fn module_replace_constants<T>(
     module: &mut CompiledModule,
-    mapping: &HashMap<T, T>,
+    mapping: &BTreeMap<T, T>,
 ) -> PartialVMResult<()>
 where
-    T: ConstantOps + Clone + Hash + Eq,
+    T: ConstantOps + Clone + Ord,
 {
     // replace T type in constants.
     for constant in module.constant_pool.iter_mut() {
@@ -2,15 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::gas_algebra::InternalGas;
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+};
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
-    values::{GlobalValue, Struct, Value},
+    pop_arg,
+    values::{GlobalValue, Struct, Value, VectorRef},
 };
 use moveos_types::{
-    moveos_std::object::Object,
+    moveos_std::object::{derive_object_id, Object},
     state::{MoveState, PlaceholderStruct},
 };
 use smallvec::smallvec;
@@ -96,6 +100,52 @@ pub fn make_native_as_mut_ref_inner(gas_params: AsMutRefGasParameters) -> Native
     })
 }
 
+#[derive(Debug, Clone)]
+pub struct DeriveObjectIdGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl DeriveObjectIdGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: InternalGas::zero(),
+            per_byte: InternalGasPerByte::zero(),
+        }
+    }
+}
+
+/***************************************************************************************************
+ * native public fun derive_object_id(addr: address, seed: &vector<u8>): ObjectID;
+ *
+ *   gas cost: base_cost + per_byte * len(seed)
+ *
+ **************************************************************************************************/
+#[inline]
+fn native_derive_object_id(
+    gas_params: &DeriveObjectIdGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let seed = pop_arg!(arguments, VectorRef);
+    let address = pop_arg!(arguments, AccountAddress);
+    let seed_ref = seed.as_bytes_ref();
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(seed_ref.len() as u64);
+    let object_id = derive_object_id(address, &seed_ref);
+    let object_id_value = Value::struct_(Struct::pack(vec![Value::address(object_id.into())]));
+    Ok(NativeResult::ok(cost, smallvec![object_id_value]))
+}
+
+pub fn make_native_derive_object_id(gas_params: DeriveObjectIdGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_derive_object_id(&gas_params, context, ty_args, args)
+    })
+}
+
 fn borrow_object_reference(
     context: &mut NativeContext,
     object_id: Value,
@@ -121,6 +171,7 @@ fn borrow_object_reference(
 pub struct GasParameters {
     pub as_ref_inner: AsRefGasParameters,
     pub as_mut_ref_inner: AsMutRefGasParameters,
+    pub derive_object_id: DeriveObjectIdGasParameters,
 }
 
 impl GasParameters {
@@ -128,6 +179,7 @@ impl GasParameters {
         Self {
             as_ref_inner: AsRefGasParameters::zeros(),
             as_mut_ref_inner: AsMutRefGasParameters::zeros(),
+            derive_object_id: DeriveObjectIdGasParameters::zeros(),
         }
     }
 }
@@ -142,6 +194,10 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "as_mut_ref_inner",
             make_native_as_mut_ref_inner(gas_params.as_mut_ref_inner),
         ),
+        (
+            "derive_object_id",
+            make_native_derive_object_id(gas_params.derive_object_id),
+        ),
     ];
 
     make_module_natives(natives)
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::natives::helpers;
+use crate::natives::memory_accountant;
 /// A native Table implementation for save any type of value.
 /// Refactor from https://github.com/rooch-network/move/blob/c7d8c2b0cdd06dbd90e0ab306932356620b5648a/language/extensions/move-table-extension/src/lib.rs#L4
 use better_any::{Tid, TidAble};
@@ -24,7 +25,7 @@ use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
-    values::{GlobalValue, Struct, Value},
+    values::{GlobalValue, Reference, Struct, Value},
 };
 use moveos_types::{
     moveos_std::{object::ObjectID, raw_table::TableInfo},
@@ -61,6 +62,7 @@ const _E_TABLE_ALREADY_EXISTS: u64 = 5;
 
 /// A structure representing mutable data of the NativeTableContext. This is in a RefCell
 /// of the overall context so we can mutate while still accessing the overall context.
+/// The tables and their contents are ordered maps, so the change set is assembled in a canonical order.
 #[derive(Default)]
 pub struct TableData {
     new_tables: BTreeMap<ObjectID, TableTypeInfo>,
@@ -164,6 +166,34 @@ impl TableRuntimeValue {
         Ok(value)
     }
 
+    pub fn value_type(&self) -> Option<&TypeTag> {
+        self.value_layout_and_type
+            .as_ref()
+            .map(|(_value_layout, value_type)| value_type)
+    }
+
+    /// Serialize the current value V of the Box<V>, return None if the value does not exist.
+    pub fn serialize_value(&self) -> PartialVMResult<Option<Vec<u8>>> {
+        if !self.exists()? {
+            return Ok(None);
+        }
+        let (value_layout, _value_type) = self
+            .value_layout_and_type
+            .as_ref()
+            .expect("The layout of an existing value should be known");
+        let box_value = self
+            .box_value
+            .borrow_global()?
+            .value_as::<Reference>()?
+            .read_ref()?
+            .value_as::<Struct>()?;
+        let val = box_value.unpack()?.next().ok_or_else(|| {
+            PartialVMError::new(StatusCode::VM_EXTENSION_ERROR)
+                .with_message("Box<V> should have one field of type V".to_owned())
+        })?;
+        serialize(value_layout, &val).map(Some)
+    }
+
     pub fn into_effect(self) -> Option<(MoveTypeLayout, TypeTag, Op<Value>)> {
         let op_opt = self.box_value.into_effect();
         match (op_opt, self.value_layout_and_type) {
@@ -266,6 +296,11 @@ impl TableData {
         self.tables.contains_key(handle)
     }
 
+    /// The tables loaded or created in the current session
+    pub fn tables(&self) -> impl Iterator<Item = &Table> {
+        self.tables.values()
+    }
+
     /// into inner
     pub fn into_inner(
         self,
@@ -299,6 +334,11 @@ impl Table {
                         partial_extension_error(format!("remote table resolver failure: {}", err))
                     })? {
                     Some(value_box) => {
+                        // The key and the value are cached in the table until the session finishes
+                        memory_accountant::allocate(
+                            native_context,
+                            (entry.key().len() + value_box.value.len()) as u64,
+                        )?;
                         let value_layout = get_type_layout(native_context, &value_box.value_type)?;
 
                         let val = deserialize_and_box(&value_layout, &value_box.value)?;
@@ -361,6 +401,19 @@ impl Table {
         self.content.contains_key(key)
     }
 
+    pub fn handle(&self) -> &ObjectID {
+        &self.handle
+    }
+
+    pub fn key_type(&self) -> &TypeTag {
+        &self.key_type
+    }
+
+    /// The values loaded or created in the current session
+    pub fn content(&self) -> impl Iterator<Item = (&Vec<u8>, &TableRuntimeValue)> {
+        self.content.iter()
+    }
+
     pub fn into_inner(
         self,
     ) -> (
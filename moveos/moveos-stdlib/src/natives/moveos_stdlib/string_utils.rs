@@ -0,0 +1,293 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::u256::U256;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::{CachedStructIndex, Type},
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, Vector, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+pub const E_EMPTY_DELIMITER: u64 = 2;
+
+#[derive(Debug, Clone)]
+pub struct PerByteGasParameter {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl PerByteGasParameter {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+
+    fn cost(&self, bytes: usize) -> InternalGas {
+        self.base + self.per_byte * NumBytes::new(bytes as u64)
+    }
+}
+
+/// Return the byte index of the first occurrence of `needle` in `haystack` at or after `from`.
+pub fn index_of(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if from > haystack.len() {
+        return None;
+    }
+    if needle.is_empty() {
+        return Some(from);
+    }
+    haystack[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|pos| pos + from)
+}
+
+/// Split `s` by the non-empty `delimiter`, the result always contains at least one element.
+pub fn split<'a>(s: &'a [u8], delimiter: &[u8]) -> Vec<&'a [u8]> {
+    debug_assert!(!delimiter.is_empty());
+    let mut parts = vec![];
+    let mut start = 0;
+    while let Some(pos) = index_of(s, delimiter, start) {
+        parts.push(&s[start..pos]);
+        start = pos + delimiter.len();
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Parse the decimal digits to u256, the empty string is parsed to zero.
+/// Returns `None` if there is a non-digit character or the number overflows.
+pub fn parse_u256(bytes: &[u8]) -> Option<U256> {
+    if bytes.is_empty() {
+        return Some(U256::zero());
+    }
+    if !bytes.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    // All the bytes are ASCII digits, so it is valid UTF-8
+    let s = std::str::from_utf8(bytes).ok()?;
+    U256::from_str(s).ok()
+}
+
+/***************************************************************************************************
+ * native fun check_utf8_inner(bytes: &vector<u8>): bool;
+ *
+ *   gas cost: base_cost + per_byte * len(bytes)
+ *
+ **************************************************************************************************/
+fn native_check_utf8_inner(
+    gas_params: &PerByteGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes_ref = bytes.as_bytes_ref();
+    let cost = gas_params.cost(bytes_ref.len());
+    let valid = std::str::from_utf8(&bytes_ref).is_ok();
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+/***************************************************************************************************
+ * native fun index_of_inner(s: &vector<u8>, sub: &vector<u8>, from: u64): u64;
+ *
+ *   Return the length of `s` if `sub` is not found.
+ *   gas cost: base_cost + per_byte * (len(s) + len(sub))
+ *
+ **************************************************************************************************/
+fn native_index_of_inner(
+    gas_params: &PerByteGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let from = pop_arg!(args, u64) as usize;
+    let sub = pop_arg!(args, VectorRef);
+    let s = pop_arg!(args, VectorRef);
+    let sub_ref = sub.as_bytes_ref();
+    let s_ref = s.as_bytes_ref();
+    let cost = gas_params.cost(s_ref.len() + sub_ref.len());
+    let index = index_of(&s_ref, &sub_ref, from).unwrap_or(s_ref.len());
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(index as u64)]))
+}
+
+/***************************************************************************************************
+ * native fun split_inner(s: &vector<u8>, delimiter: &vector<u8>): vector<String>;
+ *
+ *   gas cost: base_cost + per_byte * (len(s) + len(delimiter))
+ *
+ **************************************************************************************************/
+fn native_split_inner(
+    gas_params: &PerByteGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let delimiter = pop_arg!(args, VectorRef);
+    let s = pop_arg!(args, VectorRef);
+    let delimiter_ref = delimiter.as_bytes_ref();
+    let s_ref = s.as_bytes_ref();
+    let cost = gas_params.cost(s_ref.len() + delimiter_ref.len());
+    if delimiter_ref.is_empty() {
+        return Ok(NativeResult::err(cost, E_EMPTY_DELIMITER));
+    }
+
+    let parts: Vec<Value> = split(&s_ref, &delimiter_ref)
+        .into_iter()
+        .map(|part| Value::struct_(Struct::pack(vec![Value::vector_u8(part.to_vec())])))
+        .collect();
+    let parts = Vector::pack(&Type::Struct(CachedStructIndex(0)), parts)?;
+    Ok(NativeResult::ok(cost, smallvec![parts]))
+}
+
+/***************************************************************************************************
+ * native fun parse_u256_inner(bytes: &vector<u8>): (bool, u256);
+ *
+ *   Return `(false, 0)` if the bytes are not a valid u256 number.
+ *   gas cost: base_cost + per_byte * len(bytes)
+ *
+ **************************************************************************************************/
+fn native_parse_u256_inner(
+    gas_params: &PerByteGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes_ref = bytes.as_bytes_ref();
+    let cost = gas_params.cost(bytes_ref.len());
+    let (ok, value) = match parse_u256(&bytes_ref) {
+        Some(value) => (true, value),
+        None => (false, U256::zero()),
+    };
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(ok), Value::u256(value)],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun u256_to_string_inner(value: u256): String;
+ *
+ *   gas cost: base_cost + per_byte * len(result)
+ *
+ **************************************************************************************************/
+fn native_u256_to_string_inner(
+    gas_params: &PerByteGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let value = pop_arg!(args, U256);
+    let s = value.to_string();
+    let cost = gas_params.cost(s.len());
+    let output = Struct::pack(vec![Value::vector_u8(s.into_bytes())]);
+    Ok(NativeResult::ok(cost, smallvec![Value::struct_(output)]))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub check_utf8_inner: PerByteGasParameter,
+    pub index_of_inner: PerByteGasParameter,
+    pub split_inner: PerByteGasParameter,
+    pub parse_u256_inner: PerByteGasParameter,
+    pub u256_to_string_inner: PerByteGasParameter,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            check_utf8_inner: PerByteGasParameter::zeros(),
+            index_of_inner: PerByteGasParameter::zeros(),
+            split_inner: PerByteGasParameter::zeros(),
+            parse_u256_inner: PerByteGasParameter::zeros(),
+            u256_to_string_inner: PerByteGasParameter::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "check_utf8_inner",
+            make_native(gas_params.check_utf8_inner, native_check_utf8_inner),
+        ),
+        (
+            "index_of_inner",
+            make_native(gas_params.index_of_inner, native_index_of_inner),
+        ),
+        (
+            "split_inner",
+            make_native(gas_params.split_inner, native_split_inner),
+        ),
+        (
+            "parse_u256_inner",
+            make_native(gas_params.parse_u256_inner, native_parse_u256_inner),
+        ),
+        (
+            "u256_to_string_inner",
+            make_native(gas_params.u256_to_string_inner, native_u256_to_string_inner),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_of_and_split() {
+        assert_eq!(index_of(b"hello world", b"o", 0), Some(4));
+        assert_eq!(index_of(b"hello world", b"o", 5), Some(7));
+        assert_eq!(index_of(b"hello world", b"x", 0), None);
+        assert_eq!(index_of(b"hello", b"", 2), Some(2));
+        assert_eq!(index_of(b"hello", b"o", 6), None);
+
+        let parts: Vec<&[u8]> = vec![b"a", b"", b"b", b""];
+        assert_eq!(split(b"a,,b,", b","), parts);
+        let parts: Vec<&[u8]> = vec![b""];
+        assert_eq!(split(b"", b"::"), parts);
+    }
+
+    #[test]
+    fn test_parse_u256() {
+        assert_eq!(parse_u256(b""), Some(U256::zero()));
+        assert_eq!(parse_u256(b"123"), Some(U256::from(123u64)));
+        assert_eq!(parse_u256(b"+1"), None);
+        assert_eq!(parse_u256(b"1a"), None);
+        let max = U256::max_value().to_string();
+        assert_eq!(parse_u256(max.as_bytes()), Some(U256::max_value()));
+        let overflow = format!("{}0", max);
+        assert_eq!(parse_u256(overflow.as_bytes()), None);
+    }
+}
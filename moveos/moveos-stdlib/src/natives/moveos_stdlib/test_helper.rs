@@ -1,17 +1,52 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::make_module_natives;
+use crate::natives::helpers::{make_module_natives, make_native};
 
+use better_any::{Tid, TidAble};
 use move_binary_format::errors::PartialVMResult;
 use move_core_types::gas_algebra::InternalGas;
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 use move_vm_types::{
-    loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
 };
+use sha3::{Digest, Sha3_256};
 use smallvec::smallvec;
 use std::{collections::VecDeque, sync::Arc};
 
+/// The deterministic environment of the Move unit tests.
+/// The clock, block height and random seed are only controlled by the test code,
+/// so the time-dependent contracts can be tested deterministically.
+#[derive(Default, Tid)]
+pub struct NativeTestEnvContext {
+    timestamp_ms: u64,
+    block_height: u64,
+    seed: Vec<u8>,
+    random_counter: u64,
+}
+
+impl NativeTestEnvContext {
+    pub fn timestamp_ms(&self) -> u64 {
+        self.timestamp_ms
+    }
+
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    /// Generate the next random u64, derived from the seed and the count of the generated random numbers.
+    fn next_random_u64(&mut self) -> u64 {
+        let mut hasher = Sha3_256::new();
+        hasher.update(&self.seed);
+        hasher.update(self.random_counter.to_le_bytes());
+        self.random_counter += 1;
+        let hash = hasher.finalize();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&hash[..8]);
+        u64::from_le_bytes(bytes)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DestroyGasParameters {
     pub base: InternalGas,
@@ -38,6 +73,111 @@ pub fn make_native_destroy(gas_params: DestroyGasParameters) -> NativeFunction {
     )
 }
 
+/***************************************************************************************************
+ * native fun set_timestamp_ms(timestamp_ms: u64);
+ * native fun timestamp_ms(): u64;
+ * native fun set_block_height(block_height: u64);
+ * native fun block_height(): u64;
+ * native fun set_random_seed(seed: vector<u8>);
+ * native fun random_u64(): u64;
+ *
+ * The test environment natives, only available in the Move unit tests.
+ *   gas cost: base_cost
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct TestEnvGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_set_timestamp_ms(
+    gas_params: &TestEnvGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let timestamp_ms = pop_arg!(args, u64);
+    context
+        .extensions_mut()
+        .get_mut::<NativeTestEnvContext>()
+        .timestamp_ms = timestamp_ms;
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+fn native_timestamp_ms(
+    gas_params: &TestEnvGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let timestamp_ms = context
+        .extensions()
+        .get::<NativeTestEnvContext>()
+        .timestamp_ms();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(timestamp_ms)],
+    ))
+}
+
+fn native_set_block_height(
+    gas_params: &TestEnvGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let block_height = pop_arg!(args, u64);
+    context
+        .extensions_mut()
+        .get_mut::<NativeTestEnvContext>()
+        .block_height = block_height;
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+fn native_block_height(
+    gas_params: &TestEnvGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let block_height = context
+        .extensions()
+        .get::<NativeTestEnvContext>()
+        .block_height();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(block_height)],
+    ))
+}
+
+fn native_set_random_seed(
+    gas_params: &TestEnvGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let seed = pop_arg!(args, Vec<u8>);
+    let test_env = context.extensions_mut().get_mut::<NativeTestEnvContext>();
+    test_env.seed = seed;
+    test_env.random_counter = 0;
+    Ok(NativeResult::ok(gas_params.base, smallvec![]))
+}
+
+fn native_random_u64(
+    gas_params: &TestEnvGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let random = context
+        .extensions_mut()
+        .get_mut::<NativeTestEnvContext>()
+        .next_random_u64();
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u64(random)],
+    ))
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -45,18 +185,46 @@ pub fn make_native_destroy(gas_params: DestroyGasParameters) -> NativeFunction {
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub destroy: DestroyGasParameters,
+    pub test_env: TestEnvGasParameters,
 }
 
 impl GasParameters {
     pub fn zeros() -> Self {
         Self {
             destroy: DestroyGasParameters { base: 0.into() },
+            test_env: TestEnvGasParameters { base: 0.into() },
         }
     }
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [("destroy", make_native_destroy(gas_params.destroy))];
+    let natives = [
+        ("destroy", make_native_destroy(gas_params.destroy)),
+        (
+            "set_timestamp_ms",
+            make_native(gas_params.test_env.clone(), native_set_timestamp_ms),
+        ),
+        (
+            "timestamp_ms",
+            make_native(gas_params.test_env.clone(), native_timestamp_ms),
+        ),
+        (
+            "set_block_height",
+            make_native(gas_params.test_env.clone(), native_set_block_height),
+        ),
+        (
+            "block_height",
+            make_native(gas_params.test_env.clone(), native_block_height),
+        ),
+        (
+            "set_random_seed",
+            make_native(gas_params.test_env.clone(), native_set_random_seed),
+        ),
+        (
+            "random_u64",
+            make_native(gas_params.test_env, native_random_u64),
+        ),
+    ];
 
     make_module_natives(natives)
 }
@@ -0,0 +1,171 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerArg, NumArgs};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, Value, Vector, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INDEX_OUT_OF_RANGE: u64 = 1;
+
+#[derive(Debug, Clone)]
+pub struct PerElementGasParameter {
+    pub base: InternalGas,
+    pub per_element: InternalGasPerArg,
+}
+
+impl PerElementGasParameter {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_element: 0.into(),
+        }
+    }
+
+    fn cost(&self, elements: u64) -> InternalGas {
+        self.base + self.per_element * NumArgs::new(elements)
+    }
+}
+
+fn vector_len(v: &VectorRef, ty: &Type) -> PartialVMResult<u64> {
+    v.len(ty)?.value_as::<u64>()
+}
+
+/***************************************************************************************************
+ * native fun move_all_inner<T>(lhs: &mut vector<T>, other: &mut vector<T>);
+ *
+ *   Move all the elements of `other` to the end of `lhs` in the same order, `other` is empty after.
+ *   gas cost: base_cost + per_element * len(other)
+ *
+ **************************************************************************************************/
+fn native_move_all_inner(
+    gas_params: &PerElementGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let other = pop_arg!(args, VectorRef);
+    let lhs = pop_arg!(args, VectorRef);
+    let ty = &ty_args[0];
+    let len = vector_len(&other, ty)?;
+    let cost = gas_params.cost(len);
+
+    let mut elements = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        elements.push(other.pop(ty)?);
+    }
+    for element in elements.into_iter().rev() {
+        lhs.push_back(element, ty)?;
+    }
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+/***************************************************************************************************
+ * native public fun reverse<T>(v: &mut vector<T>);
+ *
+ *   gas cost: base_cost + per_element * len(v)
+ *
+ **************************************************************************************************/
+fn native_reverse(
+    gas_params: &PerElementGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let v = pop_arg!(args, VectorRef);
+    let ty = &ty_args[0];
+    let len = vector_len(&v, ty)?;
+    let cost = gas_params.cost(len);
+
+    let len = len as usize;
+    for i in 0..len / 2 {
+        v.swap(i, len - 1 - i, ty)?;
+    }
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+/***************************************************************************************************
+ * native public fun slice<T: copy>(v: &vector<T>, start: u64, end: u64): vector<T>;
+ *
+ *   Copy the elements in the range `[start, end)` to a new vector.
+ *   gas cost: base_cost + per_element * (end - start)
+ *
+ **************************************************************************************************/
+fn native_slice(
+    gas_params: &PerElementGasParameter,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 3);
+
+    let end = pop_arg!(args, u64);
+    let start = pop_arg!(args, u64);
+    let v = pop_arg!(args, VectorRef);
+    let ty = &ty_args[0];
+    let len = vector_len(&v, ty)?;
+    if start > end || end > len {
+        return Ok(NativeResult::err(gas_params.base, E_INDEX_OUT_OF_RANGE));
+    }
+    let cost = gas_params.cost(end - start);
+
+    let mut elements = Vec::with_capacity((end - start) as usize);
+    for i in start..end {
+        let element = v
+            .borrow_elem(i as usize, ty)?
+            .value_as::<Reference>()?
+            .read_ref()?;
+        elements.push(element);
+    }
+    let result = Vector::pack(ty, elements)?;
+    Ok(NativeResult::ok(cost, smallvec![result]))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub move_all_inner: PerElementGasParameter,
+    pub reverse: PerElementGasParameter,
+    pub slice: PerElementGasParameter,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            move_all_inner: PerElementGasParameter::zeros(),
+            reverse: PerElementGasParameter::zeros(),
+            slice: PerElementGasParameter::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "move_all_inner",
+            make_native(gas_params.move_all_inner, native_move_all_inner),
+        ),
+        ("reverse", make_native(gas_params.reverse, native_reverse)),
+        ("slice", make_native(gas_params.slice, native_slice)),
+    ];
+
+    make_module_natives(natives)
+}
@@ -1,14 +1,18 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EVENT_HANDLE_PREFIX_NAME, EVENT_PREFIX_NAME};
-use anyhow::{anyhow, Result};
+use crate::{
+    EVENT_HANDLE_PREFIX_NAME, EVENT_HANDLE_PRUNED_PREFIX_NAME, EVENT_PREFIX_NAME,
+    EVENT_TX_HASH_PREFIX_NAME, TX_EVENT_IDS_PREFIX_NAME,
+};
+use anyhow::{anyhow, ensure, Result};
 use move_core_types::language_storage::StructTag;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{Event, EventHandle, EventID, TransactionEvent};
 use moveos_types::moveos_std::object::ObjectID;
 use raw_store::{derive_store, CodecKVStore, StoreInstance};
 use std::cmp::min;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 
 derive_store!(EventDBBaseStore, (ObjectID, u64), Event, EVENT_PREFIX_NAME);
 
@@ -19,6 +23,28 @@ derive_store!(
     EVENT_HANDLE_PREFIX_NAME
 );
 
+derive_store!(
+    TxEventIDsDBStore,
+    H256,
+    Vec<EventID>,
+    TX_EVENT_IDS_PREFIX_NAME
+);
+
+derive_store!(
+    EventTxHashDBStore,
+    (ObjectID, u64),
+    H256,
+    EVENT_TX_HASH_PREFIX_NAME
+);
+
+// The seq of the first event of the handle which is not pruned, the events before it are pruned.
+derive_store!(
+    EventHandlePrunedDBStore,
+    ObjectID,
+    u64,
+    EVENT_HANDLE_PRUNED_PREFIX_NAME
+);
+
 pub trait EventStore {
     fn save_events(&self, events: Vec<TransactionEvent>) -> Result<Vec<EventID>>;
 
@@ -39,19 +65,38 @@ pub trait EventStore {
         cursor: Option<u64>,
         limit: u64,
     ) -> Result<Vec<Event>>;
+
+    /// Save the event ids of the transaction, ordered by the event index.
+    fn save_tx_event_ids(&self, tx_hash: H256, event_ids: Vec<EventID>) -> Result<()>;
+
+    fn get_tx_event_ids(&self, tx_hash: H256) -> Result<Option<Vec<EventID>>>;
+
+    fn get_event_tx_hash(&self, event_id: EventID) -> Result<Option<H256>>;
+
+    /// Delete the events pruned by the retention policy.
+    fn delete_events(&self, event_ids: Vec<EventID>) -> Result<()>;
+
+    /// Check the event is pruned by the retention policy.
+    fn is_event_pruned(&self, event_id: EventID) -> Result<bool>;
 }
 
 #[derive(Clone)]
 pub struct EventDBStore {
     event_store: EventDBBaseStore,
     event_handle_store: EventHandleDBStore,
+    tx_event_ids_store: TxEventIDsDBStore,
+    event_tx_hash_store: EventTxHashDBStore,
+    event_handle_pruned_store: EventHandlePrunedDBStore,
 }
 
 impl EventDBStore {
     pub fn new(instance: StoreInstance) -> Self {
         EventDBStore {
             event_store: EventDBBaseStore::new(instance.clone()),
-            event_handle_store: EventHandleDBStore::new(instance),
+            event_handle_store: EventHandleDBStore::new(instance.clone()),
+            tx_event_ids_store: TxEventIDsDBStore::new(instance.clone()),
+            event_tx_hash_store: EventTxHashDBStore::new(instance.clone()),
+            event_handle_pruned_store: EventHandlePrunedDBStore::new(instance),
         }
     }
 
@@ -76,17 +121,25 @@ impl EventDBStore {
     }
 
     pub fn save_events(&self, tx_events: Vec<TransactionEvent>) -> Result<Vec<EventID>> {
+        // The event index should be strictly increasing, so the events are ordered deterministically
+        ensure!(
+            tx_events
+                .windows(2)
+                .all(|pair| pair[0].event_index < pair[1].event_index),
+            "The event index of the transaction events should be strictly increasing"
+        );
+        // The event handles are created in the order of the event types, not in a hash order
         let event_types = tx_events
             .iter()
             .map(|event| event.event_type.clone())
-            .collect::<HashSet<_>>();
+            .collect::<BTreeSet<_>>();
         let mut event_handles = event_types
             .into_iter()
             .map(|event_type| {
                 let handle = self.get_or_create_event_handle(&event_type)?;
                 Ok((event_type, handle))
             })
-            .collect::<Result<HashMap<_, _>>>()?;
+            .collect::<Result<BTreeMap<_, _>>>()?;
         let mut event_ids = vec![];
         let events = tx_events
             .into_iter()
@@ -145,12 +198,19 @@ impl EventDBStore {
             )
         })?;
         let last_seq = event_handle.count;
+        let pruned_seq = self.get_pruned_seq(*event_handle_id)?;
         let start = match cursor {
             //The cursor do not include the result
             Some(cursor) => cursor + 1,
-            //None means start from -1
-            None => 0,
+            //None means start from the first event which is not pruned
+            None => pruned_seq,
         };
+        ensure!(
+            start >= pruned_seq || start >= last_seq,
+            "The events of the event handle {} before the seq {} are pruned",
+            event_handle_id,
+            pruned_seq
+        );
         let end = min(start + limit, last_seq);
         let event_ids = (start..end)
             .map(|v| (EventID::new(*event_handle_id, v)))
@@ -171,4 +231,65 @@ impl EventDBStore {
         let event_handle_id = EventHandle::derive_event_handle_id(event_handle_type);
         self.get_events_by_event_handle_id(&event_handle_id, cursor, limit)
     }
+
+    /// Save the mapping between the transaction and its events,
+    /// the event ids are the leaves of the transaction event accumulator, so they must be ordered by the event index.
+    pub fn save_tx_event_ids(&self, tx_hash: H256, event_ids: Vec<EventID>) -> Result<()> {
+        self.event_tx_hash_store.put_all(
+            event_ids
+                .iter()
+                .map(|event_id| ((event_id.event_handle_id, event_id.event_seq), tx_hash))
+                .collect::<Vec<_>>(),
+        )?;
+        self.tx_event_ids_store.kv_put(tx_hash, event_ids)
+    }
+
+    pub fn get_tx_event_ids(&self, tx_hash: H256) -> Result<Option<Vec<EventID>>> {
+        self.tx_event_ids_store.kv_get(tx_hash)
+    }
+
+    pub fn get_event_tx_hash(&self, event_id: EventID) -> Result<Option<H256>> {
+        self.event_tx_hash_store
+            .kv_get((event_id.event_handle_id, event_id.event_seq))
+    }
+
+    /// Delete the events, the event handles and the mappings between the transactions and the event ids are kept,
+    /// so the event sequence keeps increasing. The events are pruned in the tx order, so the pruned events of an
+    /// event handle are the ones before the first not pruned seq, which is recorded to report the pruned events
+    /// instead of skipping them. The pruned events are not provable by the node anymore, the event archive keeps
+    /// all the events of the pruned transactions, so their proofs are built from the archive.
+    pub fn delete_events(&self, event_ids: Vec<EventID>) -> Result<()> {
+        let mut pruned_seqs = BTreeMap::new();
+        for event_id in &event_ids {
+            let pruned_seq = pruned_seqs
+                .entry(event_id.event_handle_id)
+                .or_insert(event_id.event_seq + 1);
+            *pruned_seq = (*pruned_seq).max(event_id.event_seq + 1);
+        }
+        self.event_store.delete_all(
+            event_ids
+                .into_iter()
+                .map(|event_id| (event_id.event_handle_id, event_id.event_seq))
+                .collect(),
+        )?;
+        let mut updates = vec![];
+        for (event_handle_id, pruned_seq) in pruned_seqs {
+            if pruned_seq > self.get_pruned_seq(event_handle_id)? {
+                updates.push((event_handle_id, pruned_seq));
+            }
+        }
+        self.event_handle_pruned_store.put_all(updates)
+    }
+
+    /// The seq of the first event of the handle which is not pruned
+    fn get_pruned_seq(&self, event_handle_id: ObjectID) -> Result<u64> {
+        Ok(self
+            .event_handle_pruned_store
+            .kv_get(event_handle_id)?
+            .unwrap_or_default())
+    }
+
+    pub fn is_event_pruned(&self, event_id: EventID) -> Result<bool> {
+        Ok(event_id.event_seq < self.get_pruned_seq(event_id.event_handle_id)?)
+    }
 }
@@ -42,6 +42,9 @@ pub const STATE_NODE_PREFIX_NAME: ColumnFamilyName = "state_node";
 pub const TRANSACTION_PREFIX_NAME: ColumnFamilyName = "transaction";
 pub const EVENT_PREFIX_NAME: ColumnFamilyName = "event";
 pub const EVENT_HANDLE_PREFIX_NAME: ColumnFamilyName = "event_handle";
+pub const TX_EVENT_IDS_PREFIX_NAME: ColumnFamilyName = "tx_event_ids";
+pub const EVENT_TX_HASH_PREFIX_NAME: ColumnFamilyName = "event_tx_hash";
+pub const EVENT_HANDLE_PRUNED_PREFIX_NAME: ColumnFamilyName = "event_handle_pruned";
 pub const CONFIG_STARTUP_INFO_PREFIX_NAME: ColumnFamilyName = "config_startup_info";
 pub const CONFIG_GENESIS_PREFIX_NAME: ColumnFamilyName = "config_genesis";
 
@@ -53,6 +56,9 @@ static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         TRANSACTION_PREFIX_NAME,
         EVENT_PREFIX_NAME,
         EVENT_HANDLE_PREFIX_NAME,
+        TX_EVENT_IDS_PREFIX_NAME,
+        EVENT_TX_HASH_PREFIX_NAME,
+        EVENT_HANDLE_PRUNED_PREFIX_NAME,
         CONFIG_STARTUP_INFO_PREFIX_NAME,
         CONFIG_GENESIS_PREFIX_NAME,
     ]
@@ -215,6 +221,26 @@ impl EventStore for MoveOSStore {
         self.get_event_store()
             .get_events_by_event_handle_type(event_handle_type, cursor, limit)
     }
+
+    fn save_tx_event_ids(&self, tx_hash: H256, event_ids: Vec<EventID>) -> Result<()> {
+        self.get_event_store().save_tx_event_ids(tx_hash, event_ids)
+    }
+
+    fn get_tx_event_ids(&self, tx_hash: H256) -> Result<Option<Vec<EventID>>> {
+        self.get_event_store().get_tx_event_ids(tx_hash)
+    }
+
+    fn get_event_tx_hash(&self, event_id: EventID) -> Result<Option<H256>> {
+        self.get_event_store().get_event_tx_hash(event_id)
+    }
+
+    fn delete_events(&self, event_ids: Vec<EventID>) -> Result<()> {
+        self.get_event_store().delete_events(event_ids)
+    }
+
+    fn is_event_pruned(&self, event_id: EventID) -> Result<bool> {
+        self.get_event_store().is_event_pruned(event_id)
+    }
 }
 
 impl TransactionStore for MoveOSStore {
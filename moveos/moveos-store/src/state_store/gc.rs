@@ -0,0 +1,123 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Garbage collection of the state nodes.
+//! The state nodes are content addressed and never deleted when the states are changed, so the nodes of the deleted
+//! objects, the tombstones, and the nodes of the child fields of the deleted tables are kept forever.
+//! The GC marks all the nodes reachable from the state roots in the pruning window, include the nodes of the child
+//! tables, then removes the other nodes. The states and the proofs of the retained roots are not affected,
+//! the states of the roots older than the pruning window can not be read after the GC.
+//! The GC must not run when the node is running, the new written nodes are not marked.
+
+use crate::state_store::statedb::TreeTable;
+use crate::state_store::NodeDBStore;
+use anyhow::Result;
+use metrics::{register, Opts, PrometheusError, Registry, UIntCounter};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::raw_table::TableInfo;
+use moveos_types::state::MoveStructType;
+use once_cell::sync::Lazy;
+use raw_store::{CodecKVStore, CodecWriteBatch};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const DELETE_BATCH_SIZE: usize = 1000;
+
+pub static STATE_GC_METRICS: Lazy<StateGCMetrics> = Lazy::new(|| {
+    StateGCMetrics::register(metrics::default_registry()).expect("Register state gc metrics failed")
+});
+
+#[derive(Clone)]
+pub struct StateGCMetrics {
+    pub state_gc_removed_nodes_total: UIntCounter,
+    pub state_gc_reclaimed_bytes_total: UIntCounter,
+}
+
+impl StateGCMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let state_gc_removed_nodes_total = register(
+            UIntCounter::with_opts(Opts::new(
+                "state_gc_removed_nodes_total",
+                "Counters of how many state nodes are removed by the state gc",
+            ))?,
+            registry,
+        )?;
+        let state_gc_reclaimed_bytes_total = register(
+            UIntCounter::with_opts(Opts::new(
+                "state_gc_reclaimed_bytes_total",
+                "Cumulative bytes of the keys and values of the state nodes removed by the state gc",
+            ))?,
+            registry,
+        )?;
+        Ok(Self {
+            state_gc_removed_nodes_total,
+            state_gc_reclaimed_bytes_total,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateGCReport {
+    pub retained_roots: usize,
+    pub live_nodes: u64,
+    pub removed_nodes: u64,
+    pub reclaimed_bytes: u64,
+}
+
+/// Return the nodes reachable from the `state_roots`, include the nodes of the child tables.
+pub fn mark_live_nodes(node_store: &NodeDBStore, state_roots: &[H256]) -> Result<HashSet<H256>> {
+    let table_info_tag = TableInfo::struct_tag();
+    let mut live_nodes = HashSet::new();
+    let mut pending_roots = state_roots.to_vec();
+    while let Some(root) = pending_roots.pop() {
+        let table = TreeTable::new_with_root(node_store.clone(), Some(root));
+        table.walk_nodes(&mut live_nodes, |_key, state| {
+            if state.get_object_struct_tag().as_ref() == Some(&table_info_tag) {
+                let table_info = state.as_object::<TableInfo>()?.value;
+                pending_roots.push(H256(table_info.state_root.into()));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(live_nodes)
+}
+
+/// Remove the state nodes which are not reachable from the `retained_roots`.
+/// With `dry_run`, the nodes are only counted, nothing is removed.
+pub fn gc_state_nodes(
+    node_store: &NodeDBStore,
+    retained_roots: &[H256],
+    dry_run: bool,
+) -> Result<StateGCReport> {
+    let live_nodes = mark_live_nodes(node_store, retained_roots)?;
+    let mut report = StateGCReport {
+        retained_roots: retained_roots.len(),
+        live_nodes: live_nodes.len() as u64,
+        ..Default::default()
+    };
+
+    let mut dead_nodes = vec![];
+    let mut iter = node_store.iter()?;
+    iter.seek_to_first();
+    for item in iter {
+        let (key, value) = item?;
+        if !live_nodes.contains(&key) {
+            report.removed_nodes += 1;
+            report.reclaimed_bytes += (H256::len_bytes() + value.len()) as u64;
+            dead_nodes.push(key);
+        }
+    }
+    if dry_run {
+        return Ok(report);
+    }
+    for keys in dead_nodes.chunks(DELETE_BATCH_SIZE) {
+        node_store.write_batch(CodecWriteBatch::new_deletes(keys.to_vec()))?;
+    }
+    STATE_GC_METRICS
+        .state_gc_removed_nodes_total
+        .inc_by(report.removed_nodes);
+    STATE_GC_METRICS
+        .state_gc_reclaimed_bytes_total
+        .inc_by(report.reclaimed_bytes);
+    Ok(report)
+}
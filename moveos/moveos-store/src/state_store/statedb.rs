@@ -29,7 +29,7 @@ use moveos_types::{
     state_resolver::{self, module_name_to_key, resource_tag_to_key, StateResolver},
 };
 use smt::{NodeStore, SMTIterator, SMTree, UpdateSet};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
 use crate::state_store::NodeDBStore;
 
@@ -124,6 +124,13 @@ where
     pub fn iter(&self) -> Result<SMTIterator<Vec<u8>, State, NS>> {
         self.smt.iter(None)
     }
+
+    pub fn walk_nodes<F>(&self, visited: &mut HashSet<H256>, on_leaf: F) -> Result<()>
+    where
+        F: FnMut(Vec<u8>, State) -> Result<()>,
+    {
+        self.smt.walk_nodes(visited, on_leaf)
+    }
 }
 
 /// StateDB provide state storage and state proof
@@ -305,6 +312,10 @@ impl StateDBStore {
         self.global_table.smt.is_genesis()
     }
 
+    pub fn state_root(&self) -> H256 {
+        self.global_table.state_root()
+    }
+
     //Only for unit test and integration test runner
     pub fn create_account_storage(&self, account: AccountAddress) -> Result<()> {
         let account_storage = ObjectEntity::new_account_storage_object(account);
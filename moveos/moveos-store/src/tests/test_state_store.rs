@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_store::gc::gc_state_nodes;
+use crate::state_store::statedb::StateDBStore;
 use crate::MoveOSStore;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
@@ -197,3 +199,43 @@ fn test_state_db_dump_and_apply() -> Result<()> {
     assert_eq!(global_state_set, global_state_set2);
     Ok(())
 }
+
+#[test]
+fn test_gc_state_nodes() -> Result<()> {
+    let moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let state_store = moveos_store.get_state_store();
+    let table_handle = ObjectID::ONE;
+    let key = MoveString::from_str("test_key").unwrap();
+
+    let mut state_roots = vec![];
+    for value in ["value1", "value2"] {
+        let mut table_change = TableChange::new(random_type_tag());
+        table_change.entries.insert(
+            key.to_bytes(),
+            Op::New(MoveString::from_str(value).unwrap().into()),
+        );
+        let mut state_change_set = StateChangeSet::default();
+        state_change_set.changes.insert(table_handle, table_change);
+        state_roots.push(state_store.apply_change_set(ChangeSet::new(), state_change_set)?);
+    }
+
+    let node_store = moveos_store.get_state_node_store();
+    let dry_run_report = gc_state_nodes(node_store, &state_roots[1..], true)?;
+    assert!(dry_run_report.removed_nodes > 0);
+    let report = gc_state_nodes(node_store, &state_roots[1..], false)?;
+    assert_eq!(report, dry_run_report);
+
+    // The states of the retained root are not affected, the states of the old root are removed.
+    let latest = StateDBStore::new_with_root(node_store.clone(), Some(state_roots[1]));
+    assert_eq!(
+        latest.resolve_state(&table_handle, &key.to_bytes())?,
+        Some(MoveString::from_str("value2").unwrap().into())
+    );
+    let old = StateDBStore::new_with_root(node_store.clone(), Some(state_roots[0]));
+    assert!(old.resolve_state(&table_handle, &key.to_bytes()).is_err());
+
+    let report = gc_state_nodes(node_store, &state_roots[1..], false)?;
+    assert_eq!(report.removed_nodes, 0);
+    assert_eq!(report.live_nodes, dry_run_report.live_nodes);
+    Ok(())
+}
@@ -4,6 +4,7 @@
 extern crate chrono;
 
 use crate::event_store::EventStore;
+use crate::transaction_store::TransactionStore;
 use crate::MoveOSStore;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
@@ -12,7 +13,7 @@ use move_core_types::vm_status::KeptVMStatus;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::TransactionEvent;
-use moveos_types::transaction::TransactionExecutionInfo;
+use moveos_types::transaction::{GasBreakdown, TransactionExecutionInfo};
 use raw_store::rocks::{RocksDB, DEFAULT_PREFIX_NAME};
 use raw_store::traits::DBStore;
 use raw_store::CodecKVStore;
@@ -84,6 +85,8 @@ fn test_store() {
         H256::random(),
         rand::random(),
         KeptVMStatus::Executed,
+        None,
+        GasBreakdown::default(),
     );
     let id = transaction_info1.tx_hash;
     store
@@ -95,6 +98,59 @@ fn test_store() {
     assert_eq!(transaction_info1, transaction_info2.unwrap());
 }
 
+#[test]
+fn test_legacy_transaction_store() {
+    let store = MoveOSStore::mock_moveos_store().unwrap();
+
+    let transaction_info1 = TransactionExecutionInfo::new(
+        H256::random(),
+        H256::random(),
+        H256::random(),
+        rand::random(),
+        KeptVMStatus::Executed,
+        None,
+        GasBreakdown::default(),
+    );
+    // The execution info stored before the out of gas instructions and the gas breakdown were recorded
+    let legacy_tx_hash = H256::random();
+    let legacy_bytes = bcs::to_bytes(&(
+        legacy_tx_hash,
+        H256::random(),
+        H256::random(),
+        100u64,
+        KeptVMStatus::OutOfGas,
+    ))
+    .unwrap();
+    let transaction_store = store.get_transaction_store();
+    transaction_store
+        .save_tx_execution_info(transaction_info1.clone())
+        .unwrap();
+    transaction_store
+        .put_raw(legacy_tx_hash, legacy_bytes)
+        .unwrap();
+
+    let legacy_info = transaction_store
+        .get_tx_execution_info(legacy_tx_hash)
+        .unwrap()
+        .unwrap();
+    assert_eq!(legacy_info.tx_hash, legacy_tx_hash);
+    assert_eq!(legacy_info.gas_used, 100);
+    assert_eq!(legacy_info.status, KeptVMStatus::OutOfGas);
+    assert_eq!(legacy_info.out_of_gas_instructions, None);
+    assert_eq!(legacy_info.gas_breakdown, None);
+
+    let infos = transaction_store
+        .multi_get_tx_execution_infos(vec![
+            transaction_info1.tx_hash,
+            legacy_tx_hash,
+            H256::random(),
+        ])
+        .unwrap();
+    assert_eq!(infos[0], Some(transaction_info1));
+    assert_eq!(infos[1], Some(legacy_info));
+    assert_eq!(infos[2], None);
+}
+
 #[test]
 fn test_event_store() {
     let store = MoveOSStore::mock_moveos_store().unwrap();
@@ -125,6 +181,127 @@ fn test_event_store() {
     assert_eq!(event1.event_id.event_seq, 1);
 }
 
+#[test]
+fn test_event_index_should_be_strictly_increasing() {
+    let store = MoveOSStore::mock_moveos_store().unwrap();
+
+    let test_struct_tag = StructTag {
+        address: AccountAddress::random(),
+        module: Identifier::new("Module").unwrap(),
+        name: Identifier::new("Name").unwrap(),
+        type_params: vec![],
+    };
+    let tx_events = vec![
+        TransactionEvent::new(test_struct_tag.clone(), b"data0".to_vec(), 1),
+        TransactionEvent::new(test_struct_tag, b"data1".to_vec(), 1),
+    ];
+    assert!(store.save_events(tx_events).is_err());
+}
+
+#[test]
+fn test_tx_event_ids() {
+    let store = MoveOSStore::mock_moveos_store().unwrap();
+
+    let test_struct_tag = StructTag {
+        address: AccountAddress::random(),
+        module: Identifier::new("Module").unwrap(),
+        name: Identifier::new("Name").unwrap(),
+        type_params: vec![],
+    };
+    let tx_events = vec![
+        TransactionEvent::new(test_struct_tag.clone(), b"data0".to_vec(), 0),
+        TransactionEvent::new(test_struct_tag, b"data1".to_vec(), 1),
+    ];
+    let event_ids = store.save_events(tx_events).unwrap();
+    let tx_hash = H256::random();
+    store.save_tx_event_ids(tx_hash, event_ids.clone()).unwrap();
+
+    assert_eq!(
+        store.get_tx_event_ids(tx_hash).unwrap(),
+        Some(event_ids.clone())
+    );
+    for event_id in event_ids {
+        assert_eq!(store.get_event_tx_hash(event_id).unwrap(), Some(tx_hash));
+    }
+    assert!(store.get_tx_event_ids(H256::random()).unwrap().is_none());
+}
+
+#[test]
+fn test_delete_events() {
+    let store = MoveOSStore::mock_moveos_store().unwrap();
+
+    let test_struct_tag = StructTag {
+        address: AccountAddress::random(),
+        module: Identifier::new("Module").unwrap(),
+        name: Identifier::new("Name").unwrap(),
+        type_params: vec![],
+    };
+    let event_ids = store
+        .save_events(vec![TransactionEvent::new(
+            test_struct_tag.clone(),
+            b"data0".to_vec(),
+            0,
+        )])
+        .unwrap();
+    let tx_hash = H256::random();
+    store.save_tx_event_ids(tx_hash, event_ids.clone()).unwrap();
+    store.delete_events(event_ids.clone()).unwrap();
+    assert!(store.get_event(event_ids[0]).unwrap().is_none());
+    // The mapping of the pruned events is kept
+    assert_eq!(store.get_tx_event_ids(tx_hash).unwrap(), Some(event_ids));
+
+    // The event sequence keeps increasing after the pruning
+    let new_event_ids = store
+        .save_events(vec![TransactionEvent::new(
+            test_struct_tag.clone(),
+            b"data1".to_vec(),
+            0,
+        )])
+        .unwrap();
+    assert_eq!(new_event_ids[0].event_seq, 1);
+
+    // The pruned events are reported instead of skipped
+    assert!(store.is_event_pruned(event_ids[0]).unwrap());
+    assert!(!store.is_event_pruned(new_event_ids[0]).unwrap());
+    let event_handle_id = event_ids[0].event_handle_id;
+    let events = store
+        .get_events_by_event_handle_id(&event_handle_id, None, 10)
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_id, new_event_ids[0]);
+    let events = store
+        .get_events_by_event_handle_id(&event_handle_id, Some(0), 10)
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    // The query from a cursor before the pruned events fails
+    let mut more_event_ids = vec![];
+    for i in 0..2u64 {
+        more_event_ids.extend(
+            store
+                .save_events(vec![TransactionEvent::new(
+                    test_struct_tag.clone(),
+                    vec![i as u8],
+                    0,
+                )])
+                .unwrap(),
+        );
+    }
+    store.delete_events(new_event_ids).unwrap();
+    assert!(store
+        .get_events_by_event_handle_id(&event_handle_id, Some(0), 10)
+        .is_err());
+    let events = store
+        .get_events_by_event_handle_id(&event_handle_id, Some(1), 10)
+        .unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| event.event_id)
+            .collect::<Vec<_>>(),
+        more_event_ids
+    );
+}
+
 #[test]
 fn test_iter() {
     let store = MoveOSStore::mock_moveos_store().unwrap();
@@ -134,6 +311,8 @@ fn test_iter() {
         H256::random(),
         rand::random(),
         KeptVMStatus::Executed,
+        None,
+        GasBreakdown::default(),
     );
     let id = transaction_info1.tx_hash;
     store
@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use moveos_common::utils::to_bytes;
 use moveos_types::h256::H256;
 use moveos_types::transaction::TransactionExecutionInfo;
-use raw_store::CodecKVStore;
+use raw_store::traits::KVStore;
+use raw_store::{CodecKVStore, SchemaStore};
 
 use crate::TRANSACTION_PREFIX_NAME;
 use raw_store::derive_store;
@@ -30,14 +32,26 @@ impl TransactionStore for TransactionDBStore {
         self.kv_put(tx_execution_info.tx_hash, tx_execution_info)
     }
 
+    // The execution infos are decoded by `TransactionExecutionInfo::decode`, which also decodes the ones
+    // stored in the legacy layout, so the existing databases are read without a migration.
     fn get_tx_execution_info(&self, tx_hash: H256) -> Result<Option<TransactionExecutionInfo>> {
-        self.kv_get(tx_hash)
+        self.get_raw(tx_hash)?
+            .map(|bytes| TransactionExecutionInfo::decode(&bytes))
+            .transpose()
     }
 
     fn multi_get_tx_execution_infos(
         &self,
         tx_hashes: Vec<H256>,
     ) -> Result<Vec<Option<TransactionExecutionInfo>>> {
-        self.multiple_get(tx_hashes)
+        let keys = tx_hashes.iter().map(to_bytes).collect::<Result<Vec<_>>>()?;
+        KVStore::multiple_get(self.get_store(), keys)?
+            .into_iter()
+            .map(|value| {
+                value
+                    .map(|bytes| TransactionExecutionInfo::decode(&bytes))
+                    .transpose()
+            })
+            .collect()
     }
 }
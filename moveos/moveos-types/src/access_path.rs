@@ -10,6 +10,8 @@ use crate::{
 use move_core_types::{
     account_address::AccountAddress, identifier::Identifier, language_storage::StructTag,
 };
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::{collection::vec, prelude::*};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::str::FromStr;
 
@@ -327,6 +329,46 @@ impl FromStr for AccessPath {
     }
 }
 
+// Only generates the canonical paths which survive the string form:
+// the lists are not empty, and the struct tags have no type params, as the `,` is the list separator.
+#[cfg(any(test, feature = "fuzzing"))]
+impl Arbitrary for AccessPath {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let account = any::<[u8; AccountAddress::LENGTH]>().prop_map(AccountAddress::new);
+        let resource_type =
+            crate::move_types::struct_tag_prop_strategy().prop_map(|struct_tag| StructTag {
+                type_params: vec![],
+                ..struct_tag
+            });
+        prop_oneof![
+            vec(any::<ObjectID>(), 1..5).prop_map(|object_ids| Path::Object { object_ids }),
+            (account.clone(), vec(resource_type, 1..5)).prop_map(|(account, resource_types)| {
+                Path::Resource {
+                    account,
+                    resource_types: Some(resource_types),
+                }
+            }),
+            (account, vec(any::<Identifier>(), 1..5)).prop_map(|(account, module_names)| {
+                Path::Module {
+                    account,
+                    module_names: Some(module_names),
+                }
+            }),
+            (any::<ObjectID>(), vec(vec(any::<u8>(), 0..64), 1..5)).prop_map(
+                |(table_handle, keys)| Path::Table {
+                    table_handle,
+                    keys: Some(keys),
+                }
+            ),
+        ]
+        .prop_map(AccessPath)
+        .boxed()
+    }
+}
+
 // AccessPath always serialize and deserilaize as string
 impl Serialize for AccessPath {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -370,4 +412,13 @@ mod tests {
         test_path_roundtrip("/table/0x1/0x12,0x13");
         test_path_roundtrip("/table/0x1/key1,key2");
     }
+
+    proptest! {
+        #[test]
+        fn test_access_path_bcs_serde(input in any::<AccessPath>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: AccessPath = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+    }
 }
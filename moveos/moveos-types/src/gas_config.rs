@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use serde::{Deserialize, Serialize};
+
 //TODO this config should be an on-chain config
 pub struct GasConfig {
     pub max_gas_amount: u64,
@@ -9,3 +11,89 @@ pub struct GasConfig {
 impl GasConfig {
     pub const DEFAULT_MAX_GAS_AMOUNT: u64 = 1000000000u64;
 }
+
+/// The limits of the view function execution, separate from the transaction gas limit,
+/// so the read endpoints can not be abused as free compute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewFunctionLimits {
+    pub max_gas_amount: u64,
+    pub timeout_milliseconds: u64,
+}
+
+/// The gas meter metering the transactions, the gas meter is selected by the node config,
+/// the alternative meters are for the development and testing, such as a predictable gas fee.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GasMeterKind {
+    /// Meter the transactions by the gas schedule, the only meter for the production networks
+    #[default]
+    Standard,
+    /// Charge a flat rate per bytecode instruction and native function call, the storage is charged as usual
+    FlatRate,
+}
+
+impl std::fmt::Display for GasMeterKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GasMeterKind::Standard => write!(f, "standard"),
+            GasMeterKind::FlatRate => write!(f, "flat-rate"),
+        }
+    }
+}
+
+impl std::str::FromStr for GasMeterKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(GasMeterKind::Standard),
+            "flat-rate" => Ok(GasMeterKind::FlatRate),
+            _ => anyhow::bail!(
+                "Invalid gas meter kind: {}, expected standard or flat-rate",
+                s
+            ),
+        }
+    }
+}
+
+/// The format of the gas traces written by the node
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GasTraceFormat {
+    /// The collapsed stacks of the call tree, rendered by the flamegraph tools, such as inferno and speedscope
+    #[default]
+    Folded,
+    /// The gas heatmap keyed by `module::function`, with the self and total gas of every function
+    Json,
+}
+
+impl GasTraceFormat {
+    /// The extension of the gas trace files
+    pub fn extension(&self) -> &'static str {
+        match self {
+            GasTraceFormat::Folded => "folded",
+            GasTraceFormat::Json => "json",
+        }
+    }
+}
+
+impl std::fmt::Display for GasTraceFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GasTraceFormat::Folded => write!(f, "folded"),
+            GasTraceFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl std::str::FromStr for GasTraceFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "folded" => Ok(GasTraceFormat::Folded),
+            "json" => Ok(GasTraceFormat::Json),
+            _ => anyhow::bail!("Invalid gas trace format: {}, expected folded or json", s),
+        }
+    }
+}
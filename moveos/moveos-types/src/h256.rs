@@ -2,10 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use once_cell::sync::Lazy;
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::prelude::*;
 use std::str::FromStr;
 
 pub use primitive_types::H256;
-use serde::{Deserialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
 use tiny_keccak::{Hasher, Sha3};
 
 pub const LENGTH: usize = 32;
@@ -18,6 +20,53 @@ pub fn sha3_256_of(buffer: &[u8]) -> H256 {
     H256(hash)
 }
 
+#[cfg(any(test, feature = "fuzzing"))]
+pub fn h256_prop_strategy() -> impl Strategy<Value = H256> {
+    any::<[u8; LENGTH]>().prop_map(H256)
+}
+
+/// A `std::io::Write` which feeds the written bytes to a sha3-256 hasher,
+/// so large values can be hashed without buffering the whole encoded bytes.
+pub struct Sha3Writer {
+    sha3: Sha3,
+}
+
+impl Sha3Writer {
+    pub fn new() -> Self {
+        Self { sha3: Sha3::v256() }
+    }
+
+    pub fn finish(self) -> H256 {
+        let mut hash = [0u8; LENGTH];
+        self.sha3.finalize(&mut hash);
+        H256(hash)
+    }
+}
+
+impl Default for Sha3Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::io::Write for Sha3Writer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.sha3.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Streamed version of `sha3_256_of(bcs::to_bytes(value))`
+pub fn sha3_256_of_bcs<T: Serialize + ?Sized>(value: &T) -> anyhow::Result<H256> {
+    let mut writer = Sha3Writer::new();
+    bcs::serialize_into(&mut writer, value)?;
+    Ok(writer.finish())
+}
+
 pub fn serialize<S>(hash: &H256, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -56,3 +105,15 @@ pub fn create_literal_hash(word: &str) -> H256 {
 /// Placeholder hash of `Accumulator`.
 pub static ACCUMULATOR_PLACEHOLDER_HASH: Lazy<H256> =
     Lazy::new(|| create_literal_hash("ACCUMULATOR_PLACEHOLDER_HASH"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha3_256_of_bcs() {
+        let value = (vec![1u8; 1024], "hello".to_string(), 42u64);
+        let expect = sha3_256_of(bcs::to_bytes(&value).unwrap().as_slice());
+        assert_eq!(sha3_256_of_bcs(&value).unwrap(), expect);
+    }
+}
@@ -16,3 +16,4 @@ pub mod startup_info;
 pub mod state;
 pub mod state_resolver;
 pub mod transaction;
+pub mod value_decoder;
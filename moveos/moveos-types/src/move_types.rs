@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::{anyhow, bail, Result};
+use move_binary_format::{file_format::Visibility, CompiledModule};
 use move_core_types::{
-    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
-    language_storage::StructTag, language_storage::TypeTag,
+    account_address::AccountAddress,
+    identifier::{IdentStr, Identifier},
+    language_storage::ModuleId,
+    language_storage::StructTag,
+    language_storage::TypeTag,
 };
 #[cfg(any(test, feature = "fuzzing"))]
 use proptest::prelude::*;
@@ -94,6 +98,59 @@ impl FromStr for StructId {
     }
 }
 
+/// The entry visibility of a function, decides whether the function can be called by transactions directly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum EntryFunctionVisibility {
+    /// `public entry fun`, can be called by transactions and other modules
+    PublicEntry,
+    /// `entry fun`, can be called by transactions only
+    PrivateEntry,
+    /// `public(friend) entry fun`, only for the friend modules, can not be called by transactions
+    FriendEntry,
+    /// The function is not an entry function, can not be called by transactions
+    NotEntry,
+}
+
+impl EntryFunctionVisibility {
+    pub fn new(visibility: Visibility, is_entry: bool) -> Self {
+        match (visibility, is_entry) {
+            (_, false) => Self::NotEntry,
+            (Visibility::Public, true) => Self::PublicEntry,
+            (Visibility::Private, true) => Self::PrivateEntry,
+            (Visibility::Friend, true) => Self::FriendEntry,
+        }
+    }
+
+    /// Get the entry visibility of the function in the module, return None if the function does not exist.
+    pub fn of_function(module: &CompiledModule, function_name: &IdentStr) -> Option<Self> {
+        module.function_defs().iter().find_map(|function_def| {
+            let handle = module.function_handle_at(function_def.function);
+            if module.identifier_at(handle.name) == function_name {
+                Some(Self::new(function_def.visibility, function_def.is_entry))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Only the `public entry` and `entry` functions can be called by transactions directly.
+    pub fn is_externally_callable(&self) -> bool {
+        matches!(self, Self::PublicEntry | Self::PrivateEntry)
+    }
+}
+
+impl std::fmt::Display for EntryFunctionVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::PublicEntry => "public entry",
+            Self::PrivateEntry => "entry",
+            Self::FriendEntry => "public(friend) entry",
+            Self::NotEntry => "non-entry",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 fn parse_struct_or_function_id(function_or_struct_id: &str) -> Result<(ModuleId, Identifier)> {
     let ids: Vec<&str> = function_or_struct_id.split_terminator("::").collect();
     if ids.len() != 3 {
@@ -206,6 +263,22 @@ pub fn type_tag_prop_strategy() -> impl Strategy<Value = TypeTag> {
     type_tag_strategy
 }
 
+#[cfg(any(test, feature = "fuzzing"))]
+pub fn struct_tag_prop_strategy() -> impl Strategy<Value = StructTag> {
+    (
+        any::<[u8; AccountAddress::LENGTH]>(),
+        any::<Identifier>(),
+        any::<Identifier>(),
+        prop::collection::vec(type_tag_prop_strategy(), 0..4),
+    )
+        .prop_map(|(address, module, name, type_params)| StructTag {
+            address: AccountAddress::new(address),
+            module,
+            name,
+            type_params,
+        })
+}
+
 struct IdentifierSymbols;
 
 impl Distribution<char> for IdentifierSymbols {
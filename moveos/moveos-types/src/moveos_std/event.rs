@@ -6,6 +6,8 @@
 
 use crate::addresses::MOVEOS_STD_ADDRESS;
 use crate::h256;
+#[cfg(any(test, feature = "fuzzing"))]
+use crate::move_types::struct_tag_prop_strategy;
 use crate::moveos_std::object::ObjectID;
 use crate::state::MoveStructType;
 use anyhow::{ensure, Error, Result};
@@ -13,6 +15,10 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::IdentStr;
 use move_core_types::{ident_str, language_storage::StructTag, language_storage::TypeTag};
 use move_resource_viewer::AnnotatedMoveStruct;
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::prelude::*;
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest_derive::Arbitrary;
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -86,6 +92,7 @@ impl<'a> ModuleBinding<'a> for EventModule<'a> {
 #[derive(
     Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize, JsonSchema,
 )]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct EventID {
     /// each event handle corresponds to a unique event handle id. event handler id equal to guid.
     pub event_handle_id: ObjectID,
@@ -148,13 +155,19 @@ impl EventID {
 
 /// Entry produced via a call to the `emit_event` builtin.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct TransactionEvent {
     /// The type of the data
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "struct_tag_prop_strategy()")
+    )]
     pub event_type: StructTag,
     /// The data payload of the event
     #[serde(with = "serde_bytes")]
     pub event_data: Vec<u8>,
     /// event index in the transaction events.
+    /// The VM assigns it from 0 in the emission order, it is strictly increasing, so the events are ordered deterministically across nodes.
     pub event_index: u64,
 }
 
@@ -171,10 +184,15 @@ impl TransactionEvent {
 /// The Event type in the event store
 /// We generate the EventID in the event store, not in the event module.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct Event {
     /// The unique event_id that the event was emitted to
     pub event_id: EventID,
     /// The type of the data
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "struct_tag_prop_strategy()")
+    )]
     pub event_type: StructTag,
     /// The data payload of the event
     #[serde(with = "serde_bytes")]
@@ -228,9 +246,14 @@ impl Event {
     }
 
     /// The event hashs of the transaction will be collect to build the transaction merkle tree root.
-    /// The event hash is the hash of the event data, does not include other fields.
+    /// The event hash commits to the event id, the event type and the event data, so the proof of the event
+    /// also proves its id and type. The event index is committed by the position of the leaf.
     pub fn hash(&self) -> H256 {
-        h256::sha3_256_of(&self.event_data)
+        h256::sha3_256_of(
+            bcs::to_bytes(&(&self.event_id, &self.event_type, &self.event_data))
+                .unwrap()
+                .as_slice(),
+        )
     }
 }
 
@@ -299,3 +322,31 @@ impl AnnotatedEvent {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_event_id_bcs_serde(input in any::<EventID>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: EventID = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+
+        #[test]
+        fn test_transaction_event_bcs_serde(input in any::<TransactionEvent>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: TransactionEvent = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+
+        #[test]
+        fn test_event_bcs_serde(input in any::<Event>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: Event = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+    }
+}
@@ -16,6 +16,8 @@ use move_core_types::{
     value::{MoveStructLayout, MoveTypeLayout},
 };
 use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::prelude::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -104,6 +106,18 @@ impl ObjectID {
     }
 }
 
+#[cfg(any(test, feature = "fuzzing"))]
+impl Arbitrary for ObjectID {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        any::<[u8; ObjectID::LENGTH]>()
+            .prop_map(ObjectID::new)
+            .boxed()
+    }
+}
+
 impl std::fmt::Display for ObjectID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // The AccountAddress display has no prefix, so we add it here
@@ -498,6 +512,19 @@ pub fn account_named_object_id(account: AccountAddress, struct_tag: &StructTag)
     AccountAddress::new(struct_tag_hash.0).into()
 }
 
+/// The scheme byte appended to the seed when deriving an ObjectID, a valid UTF-8 type name never contains 0xfd,
+/// so the derived ObjectID does not collide with the `account_named_object_id`.
+pub const DERIVE_OBJECT_ID_SCHEME: u8 = 0xfd;
+
+/// Derive an ObjectID from `address` and `seed`, keep in sync with `moveos_std::object::derive_object_id`
+pub fn derive_object_id(address: AccountAddress, seed: &[u8]) -> ObjectID {
+    let mut buffer = address.to_vec();
+    buffer.extend_from_slice(seed);
+    buffer.push(DERIVE_OBJECT_ID_SCHEME);
+    let hash = h256::sha3_256_of(&buffer);
+    AccountAddress::new(hash.0).into()
+}
+
 pub fn custom_object_id<ID: Serialize>(id: ID, struct_tag: &StructTag) -> ObjectID {
     let mut buffer = bcs::to_bytes(&id).expect("ID to bcs should success");
     buffer.extend_from_slice(struct_tag.to_canonical_string().as_bytes());
@@ -525,6 +552,20 @@ mod tests {
         assert_eq!(object_id, object_id2);
     }
 
+    #[test]
+    fn test_derive_object_id() {
+        //ensure the derived object id is same as the `derive_object_id` in move
+        let object_id =
+            derive_object_id(AccountAddress::from_hex_literal("0x42").unwrap(), b"seed");
+        assert_eq!(
+            object_id,
+            ObjectID::from_str(
+                "0x7272e88771af9555aa226037ed8109eac67eda625d695be7a75be6407ae53dd2"
+            )
+            .unwrap()
+        );
+    }
+
     #[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize)]
     struct TestStruct {
         count: u64,
@@ -594,6 +635,13 @@ mod tests {
         test_object_id_roundtrip(ObjectID::new(crate::h256::H256::random().into()));
     }
 
+    proptest! {
+        #[test]
+        fn test_object_id_serde_roundtrip(object_id in any::<ObjectID>()) {
+            test_object_id_roundtrip(object_id);
+        }
+    }
+
     #[test]
     fn test_named_object_id() {
         let struct_tag = StructTag {
@@ -499,7 +499,7 @@ impl AnnotatedKeyState {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TableTypeInfo {
     pub key_type: TypeTag,
 }
@@ -517,7 +517,9 @@ impl std::fmt::Display for TableTypeInfo {
 }
 
 /// Global State change set.
-#[derive(Default, Clone, Debug)]
+/// All the collections in the change set are ordered by the key, so the iteration order is canonical,
+/// the same changes are always applied, charged and hashed in the same order on every node.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub struct StateChangeSet {
     pub new_tables: BTreeMap<ObjectID, TableTypeInfo>,
     pub removed_tables: BTreeSet<ObjectID>,
@@ -542,8 +544,8 @@ impl StateChangeSet {
     }
 }
 
-/// A change of a single table.
-#[derive(Clone, Debug)]
+/// A change of a single table, the entries are ordered by the key bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TableChange {
     pub entries: BTreeMap<Vec<u8>, Op<State>>,
     /// The size increment of the table, may be negtive which means more deleting than inserting.
@@ -3,8 +3,8 @@
 
 use crate::{
     gas_config::GasConfig, h256, h256::H256, move_types::FunctionId,
-    moveos_std::event::TransactionEvent, moveos_std::tx_context::TxContext,
-    moveos_std::tx_meta::TxMeta, state::StateChangeSet,
+    moveos_std::event::TransactionEvent, moveos_std::object::ObjectID,
+    moveos_std::tx_context::TxContext, moveos_std::tx_meta::TxMeta, state::StateChangeSet,
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -15,12 +15,16 @@ use move_core_types::{
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
+#[cfg(any(test, feature = "fuzzing"))]
+use crate::h256::h256_prop_strategy;
 #[cfg(any(test, feature = "fuzzing"))]
 use crate::move_types::type_tag_prop_strategy;
 use crate::moveos_std::event::{Event, EventID};
 #[cfg(any(test, feature = "fuzzing"))]
 use move_core_types::identifier::Identifier;
 #[cfg(any(test, feature = "fuzzing"))]
+use move_core_types::vm_status::AbortLocation;
+#[cfg(any(test, feature = "fuzzing"))]
 use proptest::prelude::*;
 #[cfg(any(test, feature = "fuzzing"))]
 use proptest_derive::Arbitrary;
@@ -212,6 +216,24 @@ impl Display for VerifiedMoveAction {
     }
 }
 
+/// The objects and the accounts a transaction declares to touch.
+/// The declarations are checked at execution, the transaction touching an undeclared object aborts.
+/// They are only checked, the transactions are still executed one by one in the sequenced order.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub struct TxDependencyHints {
+    /// The objects touched by the transaction, the shared objects must be declared here
+    pub objects: Vec<ObjectID>,
+    /// The accounts whose objects are touched by the transaction, the sender is always declared
+    pub accounts: Vec<AccountAddress>,
+}
+
+impl TxDependencyHints {
+    pub fn new(objects: Vec<ObjectID>, accounts: Vec<AccountAddress>) -> Self {
+        Self { objects, accounts }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct MoveOSTransaction {
     pub ctx: TxContext,
@@ -220,6 +242,10 @@ pub struct MoveOSTransaction {
     pub pre_execute_functions: Vec<FunctionCall>,
     /// if the post_execute_functions is not empty, the MoveOS will call the functions after the transaction is executed.
     pub post_execute_functions: Vec<FunctionCall>,
+    /// if the dependency_hints is not none, the MoveOS aborts the transaction touching an undeclared object.
+    pub dependency_hints: Option<TxDependencyHints>,
+    /// if the gas_budget is not none, the compute and storage gas are limited by their own budgets.
+    pub gas_budget: Option<GasBudget>,
 }
 
 impl MoveOSTransaction {
@@ -247,9 +273,21 @@ impl MoveOSTransaction {
             action,
             pre_execute_functions: vec![],
             post_execute_functions: vec![],
+            dependency_hints: None,
+            gas_budget: None,
         }
     }
 
+    pub fn with_dependency_hints(mut self, dependency_hints: Option<TxDependencyHints>) -> Self {
+        self.dependency_hints = dependency_hints;
+        self
+    }
+
+    pub fn with_gas_budget(mut self, gas_budget: Option<GasBudget>) -> Self {
+        self.gas_budget = gas_budget;
+        self
+    }
+
     pub fn append_pre_execute_functions(&mut self, functions: Vec<FunctionCall>) {
         self.pre_execute_functions.extend(functions);
     }
@@ -259,10 +297,62 @@ impl MoveOSTransaction {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GasStatement {
+    /// The gas used by the execution, the sum of the instruction, stack and native gas
     pub execution_gas_used: u64,
+    /// The gas charged per bytecode instruction
+    pub instruction_gas_used: u64,
+    /// The gas charged by the stack height and the stack size of the bytecode instructions
+    pub stack_gas_used: u64,
+    /// The gas charged by the native functions
+    pub native_gas_used: u64,
     pub storage_gas_used: u64,
+    /// The gas used by module publishing, charged by bytecode size, functions and verification units
+    pub publish_gas_used: u64,
+    /// The gas refunded by deleting states, it is capped at a percentage of the gas charged,
+    /// and it is deducted from the gas used of the transaction
+    pub storage_rebate: u64,
+    /// The bytes written to the store by the transaction, the transaction itself and the states it writes
+    pub io_bytes_written: u64,
+    /// The gas charged by the bytes written to the store, it is a part of the storage gas
+    pub io_gas_used: u64,
+    /// The gas of the system code executed when the metering is stopped, such as the pre_execute and
+    /// post_execute functions, it is not charged and not a part of the gas used
+    pub system_gas_used: u64,
+}
+
+impl GasStatement {
+    /// The gas charged since the `earlier` statement of the same gas meter, category by category
+    pub fn delta_since(&self, earlier: &GasStatement) -> GasStatement {
+        GasStatement {
+            execution_gas_used: self
+                .execution_gas_used
+                .saturating_sub(earlier.execution_gas_used),
+            instruction_gas_used: self
+                .instruction_gas_used
+                .saturating_sub(earlier.instruction_gas_used),
+            stack_gas_used: self.stack_gas_used.saturating_sub(earlier.stack_gas_used),
+            native_gas_used: self.native_gas_used.saturating_sub(earlier.native_gas_used),
+            storage_gas_used: self
+                .storage_gas_used
+                .saturating_sub(earlier.storage_gas_used),
+            publish_gas_used: self
+                .publish_gas_used
+                .saturating_sub(earlier.publish_gas_used),
+            storage_rebate: self.storage_rebate.saturating_sub(earlier.storage_rebate),
+            io_bytes_written: self
+                .io_bytes_written
+                .saturating_sub(earlier.io_bytes_written),
+            io_gas_used: self.io_gas_used.saturating_sub(earlier.io_gas_used),
+            system_gas_used: self.system_gas_used.saturating_sub(earlier.system_gas_used),
+        }
+    }
+
+    /// The gas charged by the compute budget, the execution and the module publishing
+    pub fn compute_gas_used(&self) -> u64 {
+        self.execution_gas_used + self.publish_gas_used
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -271,6 +361,90 @@ pub struct VerifiedMoveOSTransaction {
     pub action: VerifiedMoveAction,
     pub pre_execute_functions: Vec<FunctionCall>,
     pub post_execute_functions: Vec<FunctionCall>,
+    pub dependency_hints: Option<TxDependencyHints>,
+    pub gas_budget: Option<GasBudget>,
+}
+
+/// The gas used by a transaction by category, recorded in the execution info of every transaction.
+/// The gas used of the transaction is the sum of the charged categories minus the storage rebate.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub struct GasBreakdown {
+    pub instruction_gas: u64,
+    pub stack_gas: u64,
+    pub native_gas: u64,
+    /// The gas charged by writing the transaction, the states and the events to the storage
+    pub storage_gas: u64,
+    pub publish_gas: u64,
+    pub storage_rebate: u64,
+    /// The gas of the system code which is not charged, it is only recorded for observability
+    pub system_gas: u64,
+}
+
+impl From<&GasStatement> for GasBreakdown {
+    fn from(gas_statement: &GasStatement) -> Self {
+        Self {
+            instruction_gas: gas_statement.instruction_gas_used,
+            stack_gas: gas_statement.stack_gas_used,
+            native_gas: gas_statement.native_gas_used,
+            storage_gas: gas_statement.storage_gas_used,
+            publish_gas: gas_statement.publish_gas_used,
+            storage_rebate: gas_statement.storage_rebate,
+            system_gas: gas_statement.system_gas_used,
+        }
+    }
+}
+
+impl GasBreakdown {
+    /// The gas charged by the compute budget, the execution and the module publishing
+    pub fn compute_gas(&self) -> u64 {
+        self.instruction_gas + self.stack_gas + self.native_gas + self.publish_gas
+    }
+}
+
+/// The dimensions of the gas, each dimension has its own budget and price
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub enum GasDimension {
+    Compute,
+    Storage,
+}
+
+impl Display for GasDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GasDimension::Compute => write!(f, "compute"),
+            GasDimension::Storage => write!(f, "storage"),
+        }
+    }
+}
+
+/// The compute and storage budgets of a transaction, in the gas of each dimension before it is priced.
+/// A transaction runs out of the gas of a dimension when its budget is exhausted, even if the other dimension
+/// has gas left, and the priced gas of both dimensions is still limited by the max gas amount.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub struct GasBudget {
+    pub compute: u64,
+    pub storage: u64,
+}
+
+impl GasBudget {
+    pub fn new(compute: u64, storage: u64) -> Self {
+        Self { compute, storage }
+    }
+
+    /// The budget of a transaction without the dimension budgets, both dimensions are limited by the max gas amount
+    pub fn shared(max_gas_amount: u64) -> Self {
+        Self::new(max_gas_amount, max_gas_amount)
+    }
+
+    pub fn dimension(&self, dimension: GasDimension) -> u64 {
+        match dimension {
+            GasDimension::Compute => self.compute,
+            GasDimension::Storage => self.storage,
+        }
+    }
 }
 
 /// RawTransactionOutput is the execution result of a MoveOS transaction
@@ -284,6 +458,13 @@ pub struct RawTransactionOutput {
     pub gas_used: u64,
     pub is_upgrade: bool,
     pub gas_statement: GasStatement,
+    /// The instruction count reached when the transaction ran out of gas, only set for the `OutOfGas` status.
+    pub out_of_gas_instructions: Option<u64>,
+    /// The simulation is aborted because it exceeded the wall-clock timeout, the status is `OutOfGas`.
+    /// The committed execution is limited by the gas only, so it is never set for the committed transactions.
+    pub execution_timed_out: bool,
+    /// The dimension of the charge exhausting the gas budget, only set for the `OutOfGas` status.
+    pub out_of_gas_dimension: Option<GasDimension>,
 }
 
 /// TransactionOutput is the execution result of a MoveOS transaction, and pack TransactionEvent to Event
@@ -296,6 +477,14 @@ pub struct TransactionOutput {
     pub events: Vec<Event>,
     pub gas_used: u64,
     pub is_upgrade: bool,
+    /// The instruction count reached when the transaction ran out of gas, only set for the `OutOfGas` status.
+    pub out_of_gas_instructions: Option<u64>,
+    /// The simulation is aborted because it exceeded the wall-clock timeout, the status is `OutOfGas`.
+    /// The committed execution is limited by the gas only, so it is never set for the committed transactions.
+    pub execution_timed_out: bool,
+    /// The dimension of the charge exhausting the gas budget, only set for the `OutOfGas` status.
+    pub out_of_gas_dimension: Option<GasDimension>,
+    pub gas_breakdown: GasBreakdown,
 }
 
 impl TransactionOutput {
@@ -320,21 +509,38 @@ impl TransactionOutput {
             events,
             gas_used: transaction_output.gas_used,
             is_upgrade: transaction_output.is_upgrade,
+            out_of_gas_instructions: transaction_output.out_of_gas_instructions,
+            execution_timed_out: transaction_output.execution_timed_out,
+            out_of_gas_dimension: transaction_output.out_of_gas_dimension,
+            gas_breakdown: GasBreakdown::from(&transaction_output.gas_statement),
         }
     }
 }
 
 /// `TransactionExecutionInfo` represents the result of executing a transaction.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub struct TransactionExecutionInfo {
     /// The hash of this transaction.
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "h256_prop_strategy()")
+    )]
     pub tx_hash: H256,
 
     /// The root hash of Sparse Merkle Tree describing the world state at the end of this
     /// transaction.
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "h256_prop_strategy()")
+    )]
     pub state_root: H256,
 
     /// The root hash of Merkle Accumulator storing all events emitted during this transaction.
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "h256_prop_strategy()")
+    )]
     pub event_root: H256,
 
     /// The amount of gas used.
@@ -343,7 +549,66 @@ pub struct TransactionExecutionInfo {
     /// The vm status. If it is not `Executed`, this will provide the general error class. Execution
     /// failures and Move abort's receive more detailed information. But other errors are generally
     /// categorized with no status code or other information
+    #[cfg_attr(
+        any(test, feature = "fuzzing"),
+        proptest(strategy = "kept_vm_status_prop_strategy()")
+    )]
     pub status: KeptVMStatus,
+
+    /// The instruction count reached when the transaction ran out of gas, only set for the `OutOfGas` status.
+    /// The changes of the transaction are discarded and the full budget is charged.
+    pub out_of_gas_instructions: Option<u64>,
+
+    /// The gas used of the transaction by category.
+    /// It is `None` for the transactions executed before the breakdown was recorded.
+    pub gas_breakdown: Option<GasBreakdown>,
+}
+
+/// The layout of the `TransactionExecutionInfo` stored before the out of gas instructions and the gas breakdown
+/// were recorded, it is only used to decode the execution infos stored in that layout.
+#[derive(Deserialize)]
+struct TransactionExecutionInfoV0 {
+    tx_hash: H256,
+    state_root: H256,
+    event_root: H256,
+    gas_used: u64,
+    status: KeptVMStatus,
+}
+
+impl From<TransactionExecutionInfoV0> for TransactionExecutionInfo {
+    fn from(info: TransactionExecutionInfoV0) -> Self {
+        TransactionExecutionInfo {
+            tx_hash: info.tx_hash,
+            state_root: info.state_root,
+            event_root: info.event_root,
+            gas_used: info.gas_used,
+            status: info.status,
+            out_of_gas_instructions: None,
+            gas_breakdown: None,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+fn kept_vm_status_prop_strategy() -> impl Strategy<Value = KeptVMStatus> {
+    let location = prop_oneof![
+        Just(AbortLocation::Script),
+        any::<ModuleId>().prop_map(AbortLocation::Module),
+    ];
+    prop_oneof![
+        Just(KeptVMStatus::Executed),
+        Just(KeptVMStatus::OutOfGas),
+        (location.clone(), any::<u64>())
+            .prop_map(|(location, code)| KeptVMStatus::MoveAbort(location, code)),
+        (location, any::<u16>(), any::<u16>()).prop_map(|(location, function, code_offset)| {
+            KeptVMStatus::ExecutionFailure {
+                location,
+                function,
+                code_offset,
+            }
+        }),
+        Just(KeptVMStatus::MiscellaneousError),
+    ]
 }
 
 impl TransactionExecutionInfo {
@@ -353,6 +618,8 @@ impl TransactionExecutionInfo {
         event_root: H256,
         gas_used: u64,
         status: KeptVMStatus,
+        out_of_gas_instructions: Option<u64>,
+        gas_breakdown: GasBreakdown,
     ) -> TransactionExecutionInfo {
         TransactionExecutionInfo {
             tx_hash,
@@ -360,6 +627,20 @@ impl TransactionExecutionInfo {
             event_root,
             gas_used,
             status,
+            out_of_gas_instructions,
+            gas_breakdown: Some(gas_breakdown),
+        }
+    }
+
+    /// Decode the execution info stored in the current layout, or in the layout before the out of gas
+    /// instructions and the gas breakdown were recorded. The BCS decoding fails on both the missing and the
+    /// remaining bytes, so a stored execution info is decoded by exactly one of the layouts.
+    pub fn decode(bytes: &[u8]) -> Result<Self, anyhow::Error> {
+        match bcs::from_bytes::<Self>(bytes) {
+            Ok(info) => Ok(info),
+            Err(e) => bcs::from_bytes::<TransactionExecutionInfoV0>(bytes)
+                .map(Self::from)
+                .map_err(|_| e.into()),
         }
     }
 
@@ -370,8 +651,41 @@ impl TransactionExecutionInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::MoveAction;
+    use super::{MoveAction, TransactionExecutionInfo};
+    use crate::h256::H256;
+    use move_core_types::vm_status::KeptVMStatus;
     use proptest::prelude::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct LegacyTransactionExecutionInfo {
+        tx_hash: H256,
+        state_root: H256,
+        event_root: H256,
+        gas_used: u64,
+        status: KeptVMStatus,
+    }
+
+    #[test]
+    fn test_decode_legacy_transaction_execution_info() {
+        let legacy = LegacyTransactionExecutionInfo {
+            tx_hash: H256::from_low_u64_be(1),
+            state_root: H256::from_low_u64_be(2),
+            event_root: H256::from_low_u64_be(3),
+            gas_used: 100,
+            status: KeptVMStatus::Executed,
+        };
+        let bytes = bcs::to_bytes(&legacy).unwrap();
+        assert!(bcs::from_bytes::<TransactionExecutionInfo>(&bytes).is_err());
+        let info = TransactionExecutionInfo::decode(&bytes).unwrap();
+        assert_eq!(info.tx_hash, legacy.tx_hash);
+        assert_eq!(info.state_root, legacy.state_root);
+        assert_eq!(info.event_root, legacy.event_root);
+        assert_eq!(info.gas_used, legacy.gas_used);
+        assert_eq!(info.status, legacy.status);
+        assert_eq!(info.out_of_gas_instructions, None);
+        assert_eq!(info.gas_breakdown, None);
+    }
 
     proptest! {
         #[test]
@@ -380,5 +694,14 @@ mod tests {
             let deserialized: MoveAction = bcs::from_bytes(&serialized).unwrap();
             assert_eq!(input, deserialized);
         }
+
+        #[test]
+        fn test_transaction_execution_info_bcs_serde(input in any::<TransactionExecutionInfo>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: TransactionExecutionInfo = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+            let decoded = TransactionExecutionInfo::decode(&serialized).unwrap();
+            assert_eq!(input, decoded);
+        }
     }
 }
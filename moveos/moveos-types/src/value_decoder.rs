@@ -0,0 +1,133 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Error, Result};
+use move_binary_format::CompiledModule;
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{ModuleId, StructTag, TypeTag},
+    metadata::Metadata,
+    resolver::{ModuleResolver, ResourceResolver},
+};
+use move_resource_viewer::{AnnotatedMoveValue, MoveValueAnnotator};
+use std::collections::BTreeMap;
+
+/// A resolver without any module, for decoding with the local modules only.
+pub struct EmptyModuleResolver;
+
+impl ModuleResolver for EmptyModuleResolver {
+    fn get_module_metadata(&self, _module_id: &ModuleId) -> Vec<Metadata> {
+        vec![]
+    }
+
+    fn get_module(&self, _module_id: &ModuleId) -> Result<Option<Vec<u8>>, Error> {
+        Ok(None)
+    }
+}
+
+/// Decode the BCS bytes of arbitrary Move values into the annotated values.
+/// The struct layouts are resolved from the local modules first, such as the modules of a local package build,
+/// and then from the `resolver`, such as the on-chain modules.
+pub struct ValueDecoder<R> {
+    local_modules: BTreeMap<ModuleId, Vec<u8>>,
+    resolver: R,
+}
+
+impl<R: ModuleResolver> ValueDecoder<R> {
+    pub fn new(resolver: R) -> Self {
+        Self {
+            local_modules: BTreeMap::new(),
+            resolver,
+        }
+    }
+
+    /// The local module overrides the module with the same id in the resolver.
+    pub fn add_module(&mut self, module: &CompiledModule) -> Result<()> {
+        let mut bytes = vec![];
+        module.serialize(&mut bytes)?;
+        self.local_modules.insert(module.self_id(), bytes);
+        Ok(())
+    }
+
+    pub fn with_modules<'a>(
+        mut self,
+        modules: impl IntoIterator<Item = &'a CompiledModule>,
+    ) -> Result<Self> {
+        for module in modules {
+            self.add_module(module)?;
+        }
+        Ok(self)
+    }
+
+    pub fn decode(&self, type_tag: &TypeTag, bytes: &[u8]) -> Result<AnnotatedMoveValue> {
+        MoveValueAnnotator::new(self).view_value(type_tag, bytes)
+    }
+}
+
+impl<R: ModuleResolver> ModuleResolver for ValueDecoder<R> {
+    fn get_module_metadata(&self, module_id: &ModuleId) -> Vec<Metadata> {
+        if self.local_modules.contains_key(module_id) {
+            vec![]
+        } else {
+            self.resolver.get_module_metadata(module_id)
+        }
+    }
+
+    fn get_module(&self, module_id: &ModuleId) -> Result<Option<Vec<u8>>, Error> {
+        match self.local_modules.get(module_id) {
+            Some(bytes) => Ok(Some(bytes.clone())),
+            None => self.resolver.get_module(module_id),
+        }
+    }
+}
+
+/// The annotator only resolves the modules, the resources are never read.
+impl<R> ResourceResolver for ValueDecoder<R> {
+    fn get_resource_with_metadata(
+        &self,
+        _address: &AccountAddress,
+        _tag: &StructTag,
+        _metadata: &[Metadata],
+    ) -> Result<(Option<Vec<u8>>, usize), Error> {
+        Ok((None, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::u256::U256;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_decode_primitive_values() {
+        let decoder = ValueDecoder::new(EmptyModuleResolver);
+
+        let value = decoder
+            .decode(
+                &TypeTag::Vector(Box::new(TypeTag::U64)),
+                &bcs::to_bytes(&vec![1u64, 2u64]).unwrap(),
+            )
+            .unwrap();
+        assert!(matches!(
+            value,
+            AnnotatedMoveValue::Vector(TypeTag::U64, values)
+                if matches!(values.as_slice(), [AnnotatedMoveValue::U64(1), AnnotatedMoveValue::U64(2)])
+        ));
+
+        let value = decoder
+            .decode(&TypeTag::U256, &bcs::to_bytes(&U256::from(42u64)).unwrap())
+            .unwrap();
+        assert!(matches!(value, AnnotatedMoveValue::U256(u) if u == U256::from(42u64)));
+
+        // The bytes are not a u64
+        assert!(decoder.decode(&TypeTag::U64, &[1u8]).is_err());
+        // The module of the struct can not be resolved
+        assert!(decoder
+            .decode(
+                &TypeTag::from_str("0x42::test::Test").unwrap(),
+                &bcs::to_bytes(&1u64).unwrap()
+            )
+            .is_err());
+    }
+}
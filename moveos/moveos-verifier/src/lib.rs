@@ -3,4 +3,5 @@
 
 pub mod build;
 pub mod metadata;
+pub mod static_analysis;
 pub mod verifier;
@@ -0,0 +1,483 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The static analyses of the modules before they are published, they report the code patterns which are valid
+//! but likely to be bugs on chain. Unlike the verifier, the warnings never reject the modules.
+
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::binary_views::BinaryIndexedView;
+use move_binary_format::file_format::{
+    Bytecode, FunctionDefinition, FunctionHandleIndex, SignatureToken, StructHandleIndex,
+    Visibility,
+};
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
+use moveos_types::move_types::FunctionId;
+use moveos_types::transaction::MoveAction;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaticAnalysisWarningKind {
+    /// A public or entry function loops over the length of a vector argument
+    UnboundedLoop,
+    /// A function subtracts without comparing the operands first, an underflow aborts with an arithmetic error
+    UncheckedArithmetic,
+    /// A public function takes a mutable reference of an object, which may be a shared object
+    MutableObjectParameter,
+}
+
+impl StaticAnalysisWarningKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StaticAnalysisWarningKind::UnboundedLoop => "unbounded_loop",
+            StaticAnalysisWarningKind::UncheckedArithmetic => "unchecked_arithmetic",
+            StaticAnalysisWarningKind::MutableObjectParameter => "mutable_object_parameter",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticAnalysisWarning {
+    pub module: String,
+    pub function: String,
+    pub kind: StaticAnalysisWarningKind,
+    pub message: String,
+}
+
+impl fmt::Display for StaticAnalysisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}::{}] {}", self.module, self.function, self.message)
+    }
+}
+
+/// Analyze the functions of the `module`, and return the warnings in the order of the functions
+pub fn analyze_module(module: &CompiledModule) -> Vec<StaticAnalysisWarning> {
+    let view = BinaryIndexedView::Module(module);
+    let module_name = module.self_id().short_str_lossless();
+    let mut warnings = vec![];
+    for fdef in module.function_defs.iter() {
+        let function_handle = view.function_handle_at(fdef.function);
+        let function_name = view.identifier_at(function_handle.name).to_string();
+        let mut warn = |kind, message: &str| {
+            warnings.push(StaticAnalysisWarning {
+                module: module_name.clone(),
+                function: function_name.clone(),
+                kind,
+                message: message.to_owned(),
+            })
+        };
+        if has_unbounded_loop(&view, fdef) {
+            warn(
+                StaticAnalysisWarningKind::UnboundedLoop,
+                "The function loops over the length of a vector argument, a long vector may run out of gas, \
+                 limit the length of the argument",
+            );
+        }
+        if has_unchecked_arithmetic(fdef) {
+            warn(
+                StaticAnalysisWarningKind::UncheckedArithmetic,
+                "The function subtracts without comparing the operands first, an underflow aborts with \
+                 an arithmetic error instead of an error code, assert the operands first",
+            );
+        }
+        if has_mutable_object_parameter(&view, fdef) {
+            warn(
+                StaticAnalysisWarningKind::MutableObjectParameter,
+                "The public function takes a mutable reference of an object, any module can call it \
+                 with a shared object, check the object or make the function friend",
+            );
+        }
+    }
+    warnings
+}
+
+/// Analyze the modules published by the `action`, a `MoveAction::ModuleBundle` or a call to
+/// `moveos_std::context::publish_modules_entry`, other actions have no warnings.
+/// The modules failing to deserialize are skipped, the execution reports the error.
+pub fn analyze_action(action: &MoveAction) -> Vec<StaticAnalysisWarning> {
+    let decoded;
+    let bundles = match action {
+        MoveAction::ModuleBundle(bundles) => bundles,
+        MoveAction::Function(call) if is_publish_modules_entry(&call.function_id) => match call
+            .args
+            .first()
+            .and_then(|arg| bcs::from_bytes::<Vec<Vec<u8>>>(arg).ok())
+        {
+            Some(bundles) => {
+                decoded = bundles;
+                &decoded
+            }
+            None => return vec![],
+        },
+        _ => return vec![],
+    };
+    bundles
+        .iter()
+        .filter_map(|bundle| CompiledModule::deserialize(bundle).ok())
+        .flat_map(|module| analyze_module(&module))
+        .collect()
+}
+
+fn is_publish_modules_entry(function_id: &FunctionId) -> bool {
+    *function_id.module_id.address() == MOVEOS_STD_ADDRESS
+        && function_id.module_id.name().as_str() == "context"
+        && function_id.function_name.as_str() == "publish_modules_entry"
+}
+
+fn is_callable_by_users(fdef: &FunctionDefinition) -> bool {
+    fdef.is_entry || fdef.visibility == Visibility::Public
+}
+
+fn parameters<'a>(view: &'a BinaryIndexedView, fdef: &FunctionDefinition) -> &'a [SignatureToken] {
+    let function_handle = view.function_handle_at(fdef.function);
+    &view.signature_at(function_handle.parameters).0
+}
+
+fn is_vector(token: &SignatureToken) -> bool {
+    match token {
+        SignatureToken::Vector(_) => true,
+        SignatureToken::Reference(inner) | SignatureToken::MutableReference(inner) => {
+            matches!(inner.as_ref(), SignatureToken::Vector(_))
+        }
+        _ => false,
+    }
+}
+
+fn is_function(
+    view: &BinaryIndexedView,
+    handle: FunctionHandleIndex,
+    address: AccountAddress,
+    module: &str,
+    name: &str,
+) -> bool {
+    let function_handle = view.function_handle_at(handle);
+    let module_handle = view.module_handle_at(function_handle.module);
+    *view.address_identifier_at(module_handle.address) == address
+        && view.identifier_at(module_handle.name).as_str() == module
+        && view.identifier_at(function_handle.name).as_str() == name
+}
+
+/// The function is callable by the users with a vector argument, and it has a loop reading the length of a vector.
+/// The vector of the loop is not tracked, so it may be a false positive.
+fn has_unbounded_loop(view: &BinaryIndexedView, fdef: &FunctionDefinition) -> bool {
+    if !is_callable_by_users(fdef) || !parameters(view, fdef).iter().any(is_vector) {
+        return false;
+    }
+    let code = match &fdef.code {
+        Some(code) => &code.code,
+        None => return false,
+    };
+    let has_loop = code.iter().enumerate().any(|(offset, instr)| match instr {
+        Bytecode::Branch(target) | Bytecode::BrTrue(target) | Bytecode::BrFalse(target) => {
+            (*target as usize) <= offset
+        }
+        _ => false,
+    });
+    let reads_length = code.iter().any(|instr| match instr {
+        Bytecode::VecLen(_) => true,
+        Bytecode::Call(handle) => is_function(view, *handle, MOVE_STD_ADDRESS, "vector", "length"),
+        Bytecode::CallGeneric(inst) => is_function(
+            view,
+            view.function_instantiation_at(*inst).handle,
+            MOVE_STD_ADDRESS,
+            "vector",
+            "length",
+        ),
+        _ => false,
+    });
+    has_loop && reads_length
+}
+
+/// Every subtraction is expected to follow a comparison in the code order, such as the `assert!(a >= b, ..)`.
+/// The operands of the comparison are not tracked, a comparison guards the next subtraction whatever it compares.
+fn has_unchecked_arithmetic(fdef: &FunctionDefinition) -> bool {
+    let code = match &fdef.code {
+        Some(code) => &code.code,
+        None => return false,
+    };
+    let mut comparisons = 0usize;
+    for instr in code {
+        match instr {
+            Bytecode::Lt | Bytecode::Le | Bytecode::Gt | Bytecode::Ge => comparisons += 1,
+            Bytecode::Sub if comparisons == 0 => return true,
+            Bytecode::Sub => comparisons -= 1,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn is_object(view: &BinaryIndexedView, handle: StructHandleIndex) -> bool {
+    let struct_handle = view.struct_handle_at(handle);
+    let module_handle = view.module_handle_at(struct_handle.module);
+    *view.address_identifier_at(module_handle.address) == MOVEOS_STD_ADDRESS
+        && view.identifier_at(module_handle.name).as_str() == "object"
+        && view.identifier_at(struct_handle.name).as_str() == "Object"
+}
+
+fn has_mutable_object_parameter(view: &BinaryIndexedView, fdef: &FunctionDefinition) -> bool {
+    if fdef.visibility != Visibility::Public {
+        return false;
+    }
+    parameters(view, fdef).iter().any(|token| match token {
+        SignatureToken::MutableReference(inner) => match inner.as_ref() {
+            SignatureToken::Struct(handle) => is_object(view, *handle),
+            SignatureToken::StructInstantiation(handle, _) => is_object(view, *handle),
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::file_format::{
+        empty_module, AbilitySet, AddressIdentifierIndex, CodeUnit, FunctionHandle,
+        IdentifierIndex, ModuleHandle, ModuleHandleIndex, Signature, SignatureIndex, StructHandle,
+    };
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::ModuleId;
+
+    struct TestModule(CompiledModule);
+
+    impl TestModule {
+        fn new() -> Self {
+            Self(empty_module())
+        }
+
+        fn identifier(&mut self, name: &str) -> IdentifierIndex {
+            self.0.identifiers.push(Identifier::new(name).unwrap());
+            IdentifierIndex((self.0.identifiers.len() - 1) as u16)
+        }
+
+        fn module_handle(&mut self, address: AccountAddress, name: &str) -> ModuleHandleIndex {
+            self.0.address_identifiers.push(address);
+            let address = AddressIdentifierIndex((self.0.address_identifiers.len() - 1) as u16);
+            let name = self.identifier(name);
+            self.0.module_handles.push(ModuleHandle { address, name });
+            ModuleHandleIndex((self.0.module_handles.len() - 1) as u16)
+        }
+
+        fn signature(&mut self, tokens: Vec<SignatureToken>) -> SignatureIndex {
+            self.0.signatures.push(Signature(tokens));
+            SignatureIndex((self.0.signatures.len() - 1) as u16)
+        }
+
+        fn struct_handle(&mut self, module: ModuleHandleIndex, name: &str) -> StructHandleIndex {
+            let name = self.identifier(name);
+            self.0.struct_handles.push(StructHandle {
+                module,
+                name,
+                abilities: AbilitySet::EMPTY,
+                type_parameters: vec![],
+            });
+            StructHandleIndex((self.0.struct_handles.len() - 1) as u16)
+        }
+
+        fn function_handle(
+            &mut self,
+            module: ModuleHandleIndex,
+            name: &str,
+            parameters: Vec<SignatureToken>,
+        ) -> FunctionHandleIndex {
+            let name = self.identifier(name);
+            let parameters = self.signature(parameters);
+            self.0.function_handles.push(FunctionHandle {
+                module,
+                name,
+                parameters,
+                return_: SignatureIndex(0),
+                type_parameters: vec![],
+            });
+            FunctionHandleIndex((self.0.function_handles.len() - 1) as u16)
+        }
+
+        fn function(
+            &mut self,
+            name: &str,
+            visibility: Visibility,
+            parameters: Vec<SignatureToken>,
+            code: Vec<Bytecode>,
+        ) {
+            let self_handle = self.0.self_module_handle_idx;
+            let function = self.function_handle(self_handle, name, parameters);
+            self.0.function_defs.push(FunctionDefinition {
+                function,
+                visibility,
+                is_entry: false,
+                acquires_global_resources: vec![],
+                code: Some(CodeUnit {
+                    locals: SignatureIndex(0),
+                    code,
+                }),
+            });
+        }
+
+        fn kinds(&self) -> Vec<StaticAnalysisWarningKind> {
+            analyze_module(&self.0)
+                .into_iter()
+                .map(|warning| warning.kind)
+                .collect()
+        }
+    }
+
+    fn vector_u8() -> SignatureToken {
+        SignatureToken::Vector(Box::new(SignatureToken::U8))
+    }
+
+    #[test]
+    fn test_unchecked_arithmetic() {
+        let mut module = TestModule::new();
+        // An abort elsewhere in the function does not guard the subtraction
+        module.function(
+            "f",
+            Visibility::Private,
+            vec![],
+            vec![Bytecode::Sub, Bytecode::LdU64(1), Bytecode::Abort],
+        );
+        assert_eq!(
+            module.kinds(),
+            vec![StaticAnalysisWarningKind::UncheckedArithmetic]
+        );
+
+        let mut module = TestModule::new();
+        module.function(
+            "f",
+            Visibility::Private,
+            vec![],
+            vec![
+                Bytecode::Ge,
+                Bytecode::BrTrue(3),
+                Bytecode::Abort,
+                Bytecode::Sub,
+                Bytecode::Ret,
+            ],
+        );
+        assert!(module.kinds().is_empty());
+
+        // A comparison guards only one subtraction
+        let mut module = TestModule::new();
+        module.function(
+            "f",
+            Visibility::Private,
+            vec![],
+            vec![Bytecode::Lt, Bytecode::Sub, Bytecode::Sub, Bytecode::Ret],
+        );
+        assert_eq!(
+            module.kinds(),
+            vec![StaticAnalysisWarningKind::UncheckedArithmetic]
+        );
+    }
+
+    #[test]
+    fn test_unbounded_loop() {
+        let mut module = TestModule::new();
+        module.function(
+            "f",
+            Visibility::Public,
+            vec![vector_u8()],
+            vec![Bytecode::VecLen(SignatureIndex(0)), Bytecode::Branch(0)],
+        );
+        // The same loop in a private function is not callable by the users
+        module.function(
+            "g",
+            Visibility::Private,
+            vec![vector_u8()],
+            vec![Bytecode::VecLen(SignatureIndex(0)), Bytecode::Branch(0)],
+        );
+        assert_eq!(
+            module.kinds(),
+            vec![StaticAnalysisWarningKind::UnboundedLoop]
+        );
+
+        let mut module = TestModule::new();
+        let std_vector = module.module_handle(MOVE_STD_ADDRESS, "vector");
+        let std_length = module.function_handle(std_vector, "length", vec![]);
+        let other_vector =
+            module.module_handle(AccountAddress::from_hex_literal("0x42").unwrap(), "vector");
+        let other_length = module.function_handle(other_vector, "length", vec![]);
+        module.function(
+            "f",
+            Visibility::Public,
+            vec![vector_u8()],
+            vec![Bytecode::Call(std_length), Bytecode::BrTrue(0)],
+        );
+        // A `vector::length` of another address does not read the length of a vector
+        module.function(
+            "g",
+            Visibility::Public,
+            vec![vector_u8()],
+            vec![Bytecode::Call(other_length), Bytecode::BrTrue(0)],
+        );
+        assert_eq!(
+            module.kinds(),
+            vec![StaticAnalysisWarningKind::UnboundedLoop]
+        );
+    }
+
+    #[test]
+    fn test_mutable_object_parameter() {
+        let mut module = TestModule::new();
+        let object_module = module.module_handle(MOVEOS_STD_ADDRESS, "object");
+        let object = module.struct_handle(object_module, "Object");
+        let other_module =
+            module.module_handle(AccountAddress::from_hex_literal("0x42").unwrap(), "object");
+        let other_object = module.struct_handle(other_module, "Object");
+        let mut_object = SignatureToken::MutableReference(Box::new(
+            SignatureToken::StructInstantiation(object, vec![SignatureToken::U8]),
+        ));
+        module.function(
+            "f",
+            Visibility::Public,
+            vec![mut_object.clone()],
+            vec![Bytecode::Ret],
+        );
+        module.function(
+            "g",
+            Visibility::Friend,
+            vec![mut_object],
+            vec![Bytecode::Ret],
+        );
+        module.function(
+            "h",
+            Visibility::Public,
+            vec![SignatureToken::MutableReference(Box::new(
+                SignatureToken::Struct(other_object),
+            ))],
+            vec![Bytecode::Ret],
+        );
+        let warnings = analyze_module(&module.0);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].function, "f");
+        assert_eq!(
+            warnings[0].kind,
+            StaticAnalysisWarningKind::MutableObjectParameter
+        );
+    }
+
+    #[test]
+    fn test_analyze_action() {
+        let mut module = TestModule::new();
+        module.function("f", Visibility::Private, vec![], vec![Bytecode::Sub]);
+        let mut bytes = vec![];
+        module.0.serialize(&mut bytes).unwrap();
+
+        let bundle = MoveAction::ModuleBundle(vec![bytes.clone()]);
+        assert_eq!(analyze_action(&bundle).len(), 1);
+        let entry = MoveAction::new_function_call(
+            FunctionId::new(
+                ModuleId::new(MOVEOS_STD_ADDRESS, Identifier::new("context").unwrap()),
+                Identifier::new("publish_modules_entry").unwrap(),
+            ),
+            vec![],
+            vec![bcs::to_bytes(&vec![bytes]).unwrap()],
+        );
+        assert_eq!(analyze_action(&entry).len(), 1);
+        // The invalid modules are left to the execution
+        assert!(analyze_action(&MoveAction::ModuleBundle(vec![vec![0u8; 4]])).is_empty());
+    }
+}
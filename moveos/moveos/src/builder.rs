@@ -0,0 +1,195 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Build a MoveOS embedded in a Rust application without the node, such as the simulation and research tools.
+//!
+//! ```ignore
+//! let mut moveos = MoveOSBuilder::new()
+//!     .natives(rooch_framework::natives::all_natives(GasParameters::initial())?)
+//!     .genesis_modules(Stdlib::load_from_file(stdlib_file)?.module_bundles()?)
+//!     .add_natives(custom_natives)
+//!     .cost_table(cost_table)
+//!     .build()?;
+//! moveos.publish_modules(sender, modules)?;
+//! let output = moveos.execute_function(sender, function_call)?;
+//! let result = moveos.execute_view_function(view_function_call);
+//! ```
+
+use crate::gas::table::CostTable;
+use crate::moveos::{MoveOS, MoveOSConfig};
+use anyhow::{bail, Result};
+use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_vm_runtime::config::VMConfig;
+use move_vm_runtime::native_functions::NativeFunction;
+use moveos_store::MoveOSStore;
+use moveos_types::gas_config::GasMeterKind;
+use moveos_types::transaction::FunctionCall;
+
+type Native = (AccountAddress, Identifier, Identifier, NativeFunction);
+
+/// The builder of the MoveOS, the MoveOS is built with the in-memory storage and the `initial_cost_schedule` by default.
+/// The natives must be set with their gas parameters, the gas schedule of the natives is defined by the framework
+/// built on the moveos stdlib, so the natives are never free by default.
+pub struct MoveOSBuilder {
+    store: Option<MoveOSStore>,
+    natives: Option<Vec<Native>>,
+    custom_natives: Vec<Native>,
+    config: MoveOSConfig,
+    system_pre_execute_functions: Vec<FunctionCall>,
+    system_post_execute_functions: Vec<FunctionCall>,
+    cost_table: Option<CostTable>,
+    gas_meter_kind: Option<GasMeterKind>,
+    genesis_modules: Vec<(AccountAddress, Vec<Vec<u8>>)>,
+}
+
+impl MoveOSBuilder {
+    pub fn new() -> Self {
+        Self {
+            store: None,
+            natives: None,
+            custom_natives: vec![],
+            config: MoveOSConfig {
+                vm_config: VMConfig::default(),
+            },
+            system_pre_execute_functions: vec![],
+            system_post_execute_functions: vec![],
+            cost_table: None,
+            gas_meter_kind: None,
+            genesis_modules: vec![],
+        }
+    }
+
+    /// The storage of the MoveOS, the default is the in-memory `MoveOSStore::mock_moveos_store`.
+    pub fn store(mut self, store: MoveOSStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// The natives of the framework built on the moveos stdlib with its gas schedule,
+    /// such as `rooch_framework::natives::all_natives(GasParameters::initial())`.
+    pub fn natives(mut self, natives: impl IntoIterator<Item = Native>) -> Self {
+        self.natives = Some(natives.into_iter().collect());
+        self
+    }
+
+    /// The natives of the moveos stdlib metered by the `gas_parameters`, without the natives of a framework.
+    pub fn stdlib_natives(self, gas_parameters: moveos_stdlib::natives::GasParameters) -> Self {
+        self.natives(moveos_stdlib::natives::all_natives(gas_parameters))
+    }
+
+    /// Add the custom natives, the name of a native must not be registered twice.
+    pub fn add_natives(mut self, natives: impl IntoIterator<Item = Native>) -> Self {
+        self.custom_natives.extend(natives);
+        self
+    }
+
+    pub fn config(mut self, config: MoveOSConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn system_functions(
+        mut self,
+        system_pre_execute_functions: Vec<FunctionCall>,
+        system_post_execute_functions: Vec<FunctionCall>,
+    ) -> Self {
+        self.system_pre_execute_functions = system_pre_execute_functions;
+        self.system_post_execute_functions = system_post_execute_functions;
+        self
+    }
+
+    /// The cost table metering the transactions, the default is the `initial_cost_schedule`.
+    /// It also limits the memory the natives can allocate in a transaction.
+    pub fn cost_table(mut self, cost_table: CostTable) -> Self {
+        self.cost_table = Some(cost_table);
+        self
+    }
+
+    pub fn gas_meter_kind(mut self, gas_meter_kind: GasMeterKind) -> Self {
+        self.gas_meter_kind = Some(gas_meter_kind);
+        self
+    }
+
+    /// The module bundles published at genesis in order, such as the `Stdlib::module_bundles`.
+    /// The genesis is skipped if the store is already initialized.
+    pub fn genesis_modules(mut self, bundles: Vec<(AccountAddress, Vec<Vec<u8>>)>) -> Self {
+        self.genesis_modules = bundles;
+        self
+    }
+
+    pub fn build(self) -> Result<MoveOS> {
+        let store = match self.store {
+            Some(store) => store,
+            None => MoveOSStore::mock_moveos_store()?,
+        };
+        let Some(mut natives) = self.natives else {
+            bail!("The natives of the MoveOS are not set, set them with their gas schedule by `natives` or `stdlib_natives`");
+        };
+        natives.extend(self.custom_natives);
+        let mut moveos = MoveOS::new(
+            store,
+            natives,
+            self.config,
+            self.system_pre_execute_functions,
+            self.system_post_execute_functions,
+        )?;
+        if let Some(cost_table) = self.cost_table {
+            moveos.set_cost_table(cost_table);
+        }
+        if let Some(gas_meter_kind) = self.gas_meter_kind {
+            moveos.set_gas_meter_kind(gas_meter_kind);
+        }
+        if !self.genesis_modules.is_empty() && moveos.state().is_genesis() {
+            moveos.init_genesis_modules(self.genesis_modules)?;
+        }
+        Ok(moveos)
+    }
+}
+
+impl Default for MoveOSBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gas::table::initial_cost_schedule;
+    use moveos_stdlib::natives::GasParameters;
+
+    #[test]
+    fn test_build_without_natives() {
+        assert!(MoveOSBuilder::new().build().is_err());
+        // The custom natives do not replace the natives of the framework
+        assert!(MoveOSBuilder::new()
+            .add_natives(moveos_stdlib::natives::all_natives(GasParameters::zeros()))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_build_defaults() {
+        let moveos = MoveOSBuilder::new()
+            .stdlib_natives(GasParameters::zeros())
+            .build()
+            .unwrap();
+        assert_eq!(moveos.cost_table(), &initial_cost_schedule());
+        assert_eq!(moveos.gas_meter_kind(), GasMeterKind::default());
+        assert!(moveos.state().is_genesis());
+    }
+
+    #[test]
+    fn test_build_with_options() {
+        let mut cost_table = initial_cost_schedule();
+        cost_table.instruction_tiers = [(0, 1)].into_iter().collect();
+        let moveos = MoveOSBuilder::new()
+            .stdlib_natives(GasParameters::zeros())
+            .cost_table(cost_table.clone())
+            .gas_meter_kind(GasMeterKind::FlatRate)
+            .build()
+            .unwrap();
+        assert_eq!(moveos.cost_table(), &cost_table);
+        assert_eq!(moveos.gas_meter_kind(), GasMeterKind::FlatRate);
+    }
+}
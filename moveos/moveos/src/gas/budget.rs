@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::transaction::GasDimension;
+use serde::{Deserialize, Serialize};
+
+/// The sub status of the OUT_OF_GAS error when the transaction runs out of gas by a compute charge,
+/// the gas of the bytecode instructions, the native functions and the module publishing.
+pub const OUT_OF_COMPUTE_GAS_SUB_STATUS: u64 = 3;
+
+/// The sub status of the OUT_OF_GAS error when the transaction runs out of gas by a storage charge,
+/// the gas of the bytes written to the store, the states and the events.
+pub const OUT_OF_STORAGE_GAS_SUB_STATUS: u64 = 4;
+
+/// The prices of a gas unit in each dimension, the gas charged by a dimension is priced before it is
+/// deducted from the max gas amount, so the gas used of a transaction is the priced gas of both dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasPrices {
+    pub compute: u64,
+    pub storage: u64,
+}
+
+impl Default for GasPrices {
+    fn default() -> Self {
+        Self {
+            compute: 1,
+            storage: 1,
+        }
+    }
+}
+
+impl GasPrices {
+    pub fn price(&self, dimension: GasDimension) -> u64 {
+        match dimension {
+            GasDimension::Compute => self.compute,
+            GasDimension::Storage => self.storage,
+        }
+    }
+
+    /// The priced gas of the `amount` of gas of the `dimension`, none if it overflows
+    pub fn priced(&self, dimension: GasDimension, amount: u64) -> Option<u64> {
+        amount.checked_mul(self.price(dimension))
+    }
+
+    /// The priced gas of the compute and storage gas used
+    pub fn priced_gas_used(&self, compute_gas_used: u64, storage_gas_used: u64) -> u64 {
+        compute_gas_used
+            .saturating_mul(self.compute)
+            .saturating_add(storage_gas_used.saturating_mul(self.storage))
+    }
+}
+
+/// The sub status of the OUT_OF_GAS error when a charge of the `dimension` exhausts the gas budget
+pub fn out_of_gas_sub_status(dimension: GasDimension) -> u64 {
+    match dimension {
+        GasDimension::Compute => OUT_OF_COMPUTE_GAS_SUB_STATUS,
+        GasDimension::Storage => OUT_OF_STORAGE_GAS_SUB_STATUS,
+    }
+}
+
+/// The dimension of the charge exhausting the gas budget by the sub status of an OUT_OF_GAS error
+pub fn out_of_gas_dimension(sub_status: Option<u64>) -> Option<GasDimension> {
+    match sub_status {
+        Some(OUT_OF_COMPUTE_GAS_SUB_STATUS) => Some(GasDimension::Compute),
+        Some(OUT_OF_STORAGE_GAS_SUB_STATUS) => Some(GasDimension::Storage),
+        _ => None,
+    }
+}
@@ -0,0 +1,415 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::budget::GasPrices;
+use super::table::{ClassifiedGasMeter, CostTable, GasSnapshot, MoveOSGasMeter};
+use super::{SwitchableGasMeter, TransactionGasMeter};
+use move_binary_format::errors::PartialVMResult;
+use move_binary_format::file_format::CodeOffset;
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::gas_algebra::{InternalGas, NumArgs, NumBytes};
+use move_core_types::language_storage::ModuleId;
+use move_vm_types::gas::{GasMeter, SimpleInstruction};
+use move_vm_types::views::{TypeView, ValueView};
+use moveos_types::moveos_std::event::TransactionEvent;
+use moveos_types::state::StateChangeSet;
+use moveos_types::transaction::{GasBudget, GasStatement};
+use std::time::Instant;
+
+/// The gas charged per bytecode instruction and native function call by the `FlatRateGasMeter`
+pub const FLAT_RATE_INSTRUCTION_COST: u64 = 1;
+
+/// A gas meter for the development, every bytecode instruction and native function call costs the same,
+/// so the execution gas only depends on the number of the instructions executed.
+/// The storage and publish fees are charged by the cost table as the `MoveOSGasMeter` does.
+#[derive(Debug, Clone)]
+pub struct FlatRateGasMeter {
+    inner: MoveOSGasMeter,
+}
+
+impl FlatRateGasMeter {
+    pub fn new(cost_table: CostTable, budget: u64) -> Self {
+        Self::new_with_dimensions(cost_table, budget, GasBudget::shared(budget))
+    }
+
+    /// The instructions are charged to the compute budget, the storage fees to the storage budget
+    pub fn new_with_dimensions(
+        cost_table: CostTable,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+    ) -> Self {
+        Self {
+            inner: MoveOSGasMeter::new_with_dimensions(cost_table, max_gas_amount, gas_budget),
+        }
+    }
+
+    fn charge_instruction(&mut self) -> PartialVMResult<()> {
+        if !self.inner.is_metering() {
+            return Ok(());
+        }
+        self.inner.increase_instruction_count(1)?;
+        self.inner
+            .charge_instruction_gas(FLAT_RATE_INSTRUCTION_COST)
+    }
+}
+
+impl GasMeter for FlatRateGasMeter {
+    fn balance_internal(&self) -> InternalGas {
+        self.inner.balance_internal()
+    }
+
+    fn charge_simple_instr(&mut self, _instr: SimpleInstruction) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_br_true(&mut self, _target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_br_false(&mut self, _target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_branch(&mut self, _target_offset: CodeOffset) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_pop(&mut self, _popped_val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_call(
+        &mut self,
+        _module_id: &ModuleId,
+        _func_name: &str,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+        _num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_call_generic(
+        &mut self,
+        _module_id: &ModuleId,
+        _func_name: &str,
+        _ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+        _num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_ld_const(&mut self, _size: NumBytes) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_ld_const_after_deserialization(
+        &mut self,
+        _val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_copy_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_move_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_store_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_pack(
+        &mut self,
+        _is_generic: bool,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_unpack(
+        &mut self,
+        _is_generic: bool,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_read_ref(&mut self, _ref_val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_write_ref(
+        &mut self,
+        _new_val: impl ValueView,
+        _old_val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_eq(&mut self, _lhs: impl ValueView, _rhs: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_neq(&mut self, _lhs: impl ValueView, _rhs: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_borrow_global(
+        &mut self,
+        _is_mut: bool,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_exists(
+        &mut self,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _exists: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_move_from(
+        &mut self,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_move_to(
+        &mut self,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _val: impl ValueView,
+        _is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_pack<'a>(
+        &mut self,
+        _ty: impl TypeView + 'a,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_len(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_borrow(
+        &mut self,
+        _is_mut: bool,
+        _ty: impl TypeView,
+        _is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_push_back(
+        &mut self,
+        _ty: impl TypeView,
+        _val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_pop_back(
+        &mut self,
+        _ty: impl TypeView,
+        _val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_unpack(
+        &mut self,
+        _ty: impl TypeView,
+        _expect_num_elements: NumArgs,
+        _elems: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_vec_swap(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_load_resource(
+        &mut self,
+        _addr: AccountAddress,
+        _ty: impl TypeView,
+        _val: Option<impl ValueView>,
+        _bytes_loaded: NumBytes,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    // The gas reported by the native function is ignored, the call is charged in
+    // `charge_native_function_before_execution` as an instruction.
+    fn charge_native_function(
+        &mut self,
+        _amount: InternalGas,
+        _ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView>>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_native_function_before_execution(
+        &mut self,
+        _ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instruction()
+    }
+
+    fn charge_drop_frame(
+        &mut self,
+        _locals: impl Iterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+}
+
+impl ClassifiedGasMeter for FlatRateGasMeter {
+    fn feature_version(&self) -> u64 {
+        self.inner.feature_version()
+    }
+
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.inner.charge_execution(gas_cost)
+    }
+
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        self.inner.charge_io_write(data_size)
+    }
+
+    fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()> {
+        self.inner.charge_event(events)
+    }
+
+    fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()> {
+        self.inner.charge_change_set(change_set)
+    }
+
+    fn charge_module_publish(
+        &mut self,
+        modules: &[CompiledModule],
+        bytecode_size: u64,
+    ) -> PartialVMResult<()> {
+        self.inner.charge_module_publish(modules, bytecode_size)
+    }
+
+    fn refund_storage_deposit(&mut self, deleted_bytes: u64) -> PartialVMResult<()> {
+        self.inner.refund_storage_deposit(deleted_bytes)
+    }
+
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.inner.check_constrains(max_gas_amount)
+    }
+
+    fn gas_statement(&self) -> GasStatement {
+        self.inner.gas_statement()
+    }
+
+    fn gas_prices(&self) -> GasPrices {
+        self.inner.gas_prices()
+    }
+
+    fn instructions_executed(&self) -> u64 {
+        self.inner.instructions_executed()
+    }
+
+    fn charge_full_budget(&mut self, max_gas_amount: u64) {
+        self.inner.charge_full_budget(max_gas_amount)
+    }
+
+    fn snapshot(&self) -> GasSnapshot {
+        self.inner.snapshot()
+    }
+}
+
+impl SwitchableGasMeter for FlatRateGasMeter {
+    fn stop_metering(&mut self) {
+        self.inner.stop_metering()
+    }
+
+    fn start_metering(&mut self) {
+        self.inner.start_metering()
+    }
+
+    fn is_metering(&self) -> bool {
+        self.inner.is_metering()
+    }
+}
+
+impl TransactionGasMeter for FlatRateGasMeter {
+    fn new_with_gas_budget(
+        cost_table: CostTable,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+    ) -> Self {
+        Self::new_with_dimensions(cost_table, max_gas_amount, gas_budget)
+    }
+
+    fn set_deadline(&mut self, deadline: Instant) {
+        self.inner.set_deadline(deadline)
+    }
+
+    fn set_simulation(&mut self, simulation: bool) {
+        self.inner.set_simulation(simulation)
+    }
+
+    fn is_simulation(&self) -> bool {
+        self.inner.is_simulation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gas::table::initial_cost_schedule;
+
+    #[test]
+    fn test_flat_rate_gas_meter() {
+        let max_gas_amount = 100;
+        let mut gas_meter = FlatRateGasMeter::new(initial_cost_schedule(), max_gas_amount);
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Add)
+            .unwrap();
+        gas_meter.charge_branch(0).unwrap();
+        assert_eq!(gas_meter.instructions_executed(), 3);
+        assert_eq!(
+            u64::from(gas_meter.balance_internal()),
+            max_gas_amount - 3 * FLAT_RATE_INSTRUCTION_COST
+        );
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+        let gas_statement = gas_meter.gas_statement();
+        assert_eq!(
+            gas_statement.instruction_gas_used,
+            gas_statement.execution_gas_used
+        );
+        assert_eq!(gas_statement.stack_gas_used, 0);
+
+        gas_meter.stop_metering();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        assert_eq!(gas_meter.instructions_executed(), 3);
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::tracing::GasTrace;
+use moveos_types::gas_config::GasTraceFormat;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The gas charged by a function in a transaction, merged from all the frames of the function in the call tree
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasHeatmapEntry {
+    /// The gas charged in the function itself, excluding the gas of the callees
+    pub self_gas: u64,
+    /// The gas charged in the function and its callees, the recursive calls are counted once
+    pub total_gas: u64,
+    /// The number of the distinct call paths reaching the function
+    pub call_paths: u64,
+}
+
+/// The gas of a transaction keyed by `module::function`, it is the flat view of the gas call tree,
+/// so the hot functions are found without walking the tree. The storage, event, io and publish fees
+/// are keyed by their dedicated frames, such as `[storage]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasHeatmap {
+    /// The gas charged by the whole trace
+    pub total_gas: u64,
+    /// The storage deposit refunded by deleting states, it is deducted from the total gas by the gas used
+    #[serde(default)]
+    pub storage_rebate: u64,
+    pub functions: BTreeMap<String, GasHeatmapEntry>,
+}
+
+impl GasHeatmap {
+    pub fn from_trace(trace: &GasTrace) -> Self {
+        let mut heatmap = GasHeatmap {
+            total_gas: trace.total_gas(GasTrace::ROOT),
+            storage_rebate: trace.storage_rebate(),
+            functions: BTreeMap::new(),
+        };
+        let mut stack = vec![];
+        heatmap.add_frame(trace, GasTrace::ROOT, &mut stack);
+        heatmap
+    }
+
+    fn add_frame<'a>(&mut self, trace: &'a GasTrace, index: usize, stack: &mut Vec<&'a str>) {
+        let frame = trace.frame(index);
+        let recursive = stack.contains(&frame.name.as_str());
+        let entry = self.functions.entry(frame.name.clone()).or_default();
+        entry.self_gas += frame.self_gas;
+        entry.call_paths += 1;
+        // The gas of a recursive call is in the total gas of its outermost frame already
+        if !recursive {
+            entry.total_gas += trace.total_gas(index);
+        }
+        stack.push(frame.name.as_str());
+        for child in frame.children.iter() {
+            self.add_frame(trace, *child, stack);
+        }
+        stack.pop();
+    }
+
+    /// The functions sorted by the self gas in the descending order
+    pub fn hottest(&self) -> Vec<(&String, &GasHeatmapEntry)> {
+        let mut functions: Vec<_> = self.functions.iter().collect();
+        functions.sort_by(|a, b| b.1.self_gas.cmp(&a.1.self_gas).then(a.0.cmp(b.0)));
+        functions
+    }
+}
+
+impl GasTrace {
+    pub fn to_heatmap(&self) -> GasHeatmap {
+        GasHeatmap::from_trace(self)
+    }
+
+    /// Render the trace in the `format`, the file extension of the format is `GasTraceFormat::extension`
+    pub fn export(&self, format: GasTraceFormat) -> String {
+        match format {
+            GasTraceFormat::Folded => self.to_folded(),
+            GasTraceFormat::Json => serde_json::to_string_pretty(&self.to_heatmap())
+                .expect("Serialize the gas heatmap should success"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gas::table::{initial_cost_schedule, ClassifiedGasMeter, MoveOSGasMeter};
+    use crate::gas::tracing::TracingGasMeter;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::gas_algebra::NumArgs;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::ModuleId;
+    use move_vm_types::gas::{GasMeter, SimpleInstruction};
+    use move_vm_types::values::Value;
+
+    fn call(gas_meter: &mut TracingGasMeter<MoveOSGasMeter>, module_id: &ModuleId, name: &str) {
+        gas_meter
+            .charge_call(
+                module_id,
+                name,
+                std::iter::empty::<Value>(),
+                NumArgs::new(0),
+            )
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+    }
+
+    fn ret(gas_meter: &mut TracingGasMeter<MoveOSGasMeter>) {
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Ret)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_gas_heatmap() {
+        let mut gas_meter =
+            TracingGasMeter::new(MoveOSGasMeter::new(initial_cost_schedule(), 1_000_000));
+        let module_id = ModuleId::new(AccountAddress::ONE, Identifier::new("m").unwrap());
+        // f calls g, and g calls itself
+        call(&mut gas_meter, &module_id, "f");
+        call(&mut gas_meter, &module_id, "g");
+        call(&mut gas_meter, &module_id, "g");
+        ret(&mut gas_meter);
+        ret(&mut gas_meter);
+        ret(&mut gas_meter);
+        gas_meter.charge_io_write(10).unwrap();
+
+        let trace = gas_meter.trace();
+        let heatmap = trace.to_heatmap();
+        assert_eq!(heatmap.total_gas, trace.total_gas(GasTrace::ROOT));
+        assert_eq!(
+            heatmap
+                .functions
+                .values()
+                .map(|entry| entry.self_gas)
+                .sum::<u64>(),
+            heatmap.total_gas
+        );
+
+        let f = &heatmap.functions[&format!("{}::f", module_id.short_str_lossless())];
+        let g = &heatmap.functions[&format!("{}::g", module_id.short_str_lossless())];
+        assert_eq!(g.call_paths, 2);
+        // The recursive call of g is not counted twice
+        assert_eq!(g.total_gas, g.self_gas);
+        assert_eq!(f.total_gas, f.self_gas + g.self_gas);
+        assert!(heatmap.functions["[io]"].self_gas > 0);
+        assert_eq!(heatmap.hottest().len(), heatmap.functions.len());
+
+        let json = trace.export(GasTraceFormat::Json);
+        assert_eq!(serde_json::from_str::<GasHeatmap>(&json).unwrap(), heatmap);
+        assert_eq!(trace.export(GasTraceFormat::Folded), trace.to_folded());
+    }
+}
@@ -2,9 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_vm_types::gas::{GasMeter, UnmeteredGasMeter};
+pub use moveos_stdlib::natives::memory_accountant::{
+    MemoryAccountant, DEFAULT_NATIVE_MEMORY_QUOTA, NATIVE_MEMORY_QUOTA_EXCEEDED_SUB_STATUS,
+};
+use moveos_types::transaction::GasBudget;
+use std::time::Instant;
+use table::{ClassifiedGasMeter, CostTable};
 
+pub mod budget;
+pub mod flat_rate;
+pub mod heatmap;
 pub mod parameter;
 pub mod table;
+pub mod tracing;
 
 pub trait SwitchableGasMeter: GasMeter {
     fn stop_metering(&mut self);
@@ -21,3 +31,23 @@ impl SwitchableGasMeter for UnmeteredGasMeter {
         false
     }
 }
+
+/// The gas meter of the transaction execution, MoveOS executes the transactions with any meter implementing it,
+/// so an alternative metering strategy does not need to patch the `MoveOSGasMeter`.
+pub trait TransactionGasMeter: SwitchableGasMeter + ClassifiedGasMeter + Clone {
+    /// Create the gas meter of a transaction with the gas `budget`, the compute and storage gas share the budget
+    fn new_with_budget(cost_table: CostTable, budget: u64) -> Self {
+        Self::new_with_gas_budget(cost_table, budget, GasBudget::shared(budget))
+    }
+    /// Create the gas meter of a transaction with the compute and storage budgets,
+    /// their gas priced by the cost table is limited by the `max_gas_amount`
+    fn new_with_gas_budget(
+        cost_table: CostTable,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+    ) -> Self;
+    /// Abort the execution as out of gas after the wall-clock deadline
+    fn set_deadline(&mut self, deadline: Instant);
+    fn set_simulation(&mut self, simulation: bool);
+    fn is_simulation(&self) -> bool;
+}
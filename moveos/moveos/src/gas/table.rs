@@ -1,7 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
+use anyhow::{anyhow, bail};
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_binary_format::file_format::CodeOffset;
+use move_binary_format::CompiledModule;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
 use move_core_types::gas_algebra::{
@@ -14,15 +16,19 @@ use move_vm_types::loaded_data::runtime_types::Type;
 use move_vm_types::views::{TypeView, ValueView};
 use moveos_types::moveos_std::event::TransactionEvent;
 use moveos_types::state::StateChangeSet;
-use moveos_types::transaction::GasStatement;
+use moveos_types::transaction::{GasBudget, GasDimension, GasStatement};
 use once_cell::sync::Lazy;
+#[cfg(test)]
+use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::ops::{Add, Bound};
 use std::rc::Rc;
+use std::time::Instant;
 
-use super::SwitchableGasMeter;
+use super::budget::{out_of_gas_sub_status, GasPrices};
+use super::{SwitchableGasMeter, TransactionGasMeter, DEFAULT_NATIVE_MEMORY_QUOTA};
 
 /// The size in bytes for a reference on the stack
 pub const REFERENCE_SIZE: AbstractMemorySize = AbstractMemorySize::new(8);
@@ -33,23 +39,93 @@ pub const STRUCT_SIZE: AbstractMemorySize = AbstractMemorySize::new(2);
 /// The size of a vector (without its containing data) in bytes
 pub const VEC_SIZE: AbstractMemorySize = AbstractMemorySize::new(8);
 
+/// The sub status of the OUT_OF_GAS error when the execution exceeds the wall-clock deadline,
+/// it distinguishes the watchdog timeout from running out of the gas budget.
+pub const EXECUTION_TIMEOUT_SUB_STATUS: u64 = 1;
+
+pub const INSTRUCTION_TIERS_KEY: &str = "instruction_tiers";
+pub const STACK_HEIGHT_TIERS_KEY: &str = "stack_height_tiers";
+pub const STACK_SIZE_TIERS_KEY: &str = "stack_size_tiers";
+pub const EXTRA_GAS_PARAMETER_KEY: &str = "extra_gas_parameter";
+
 pub const INSTRUCTION_TIER_DEFAULT: u64 = 1;
 pub const STACK_HEIGHT_TIER_DEFAULT: u64 = 1;
 pub const STACK_SIZE_TIER_DEFAULT: u64 = 1;
 
+pub const DEFAULT_STORAGE_REFUND_MAX_PERCENTAGE: u64 = 50;
+
+/// The price of a compute or storage gas unit of the schedules without the price entries
+pub const DEFAULT_GAS_PRICE: u64 = 1;
+
+/// The gas feature version of the schedules without the `feature_version` entry
+pub const GAS_FEATURE_VERSION_LEGACY: u64 = 0;
+/// The natives are limited by the `native_memory_quota` of the schedule
+pub const GAS_FEATURE_VERSION_NATIVE_MEMORY_QUOTA: u64 = 1;
+/// The per-op storage fees are charged, the storage deposit of the deleted states is refunded,
+/// and the storage gas and the refund are charged before the gas used is paid
+pub const GAS_FEATURE_VERSION_STORAGE_FEE: u64 = 2;
+/// The hash, crypto, encoding and deserialization natives charge by the input size and on the failure paths
+pub const GAS_FEATURE_VERSION_NATIVE_INPUT_COST: u64 = 3;
+/// The gas feature version of the initial cost schedule
+pub const LATEST_GAS_FEATURE_VERSION: u64 = GAS_FEATURE_VERSION_NATIVE_INPUT_COST;
+
 pub static ZERO_COST_SCHEDULE: Lazy<CostTable> = Lazy::new(zero_cost_schedule);
 
+fn default_storage_refund_max_percentage() -> u64 {
+    DEFAULT_STORAGE_REFUND_MAX_PERCENTAGE
+}
+
+fn default_native_memory_quota() -> u64 {
+    DEFAULT_NATIVE_MEMORY_QUOTA
+}
+
+fn default_gas_price() -> u64 {
+    DEFAULT_GAS_PRICE
+}
+
 #[derive(Clone, Debug, Default, Serialize, PartialEq, Eq, Deserialize)]
+#[cfg_attr(test, derive(Arbitrary))]
 pub struct ExtraGasParameter {
     pub io_read_price: u64,
     pub storage_fee_per_transaction_byte: u64,
     pub storage_fee_per_event_byte: u64,
     pub storage_fee_per_op_new_byte: u64,
     pub storage_fee_per_op_modify_byte: u64,
+    /// The storage fee per new state, it is charged in addition to the per byte fee since `GAS_FEATURE_VERSION_STORAGE_FEE`
+    #[serde(default)]
+    pub storage_fee_per_op_new: u64,
+    /// The storage fee per modified state, it is charged in addition to the per byte fee since `GAS_FEATURE_VERSION_STORAGE_FEE`
+    #[serde(default)]
+    pub storage_fee_per_op_modify: u64,
     pub storage_fee_per_op_delete: u64,
+    /// The storage deposit refunded per byte of the deleted state, the deposit is charged by `storage_fee_per_op_new_byte`
+    pub storage_rebate_per_deleted_byte: u64,
+    /// The max storage refund of a transaction, in percentage of the gas charged by the transaction
+    #[serde(default = "default_storage_refund_max_percentage")]
+    pub storage_refund_max_percentage: u64,
+    /// The publish fee per byte of the module bytecode
+    pub publish_fee_per_bytecode_byte: u64,
+    /// The publish fee per function definition, each function needs to be verified and loaded
+    pub publish_fee_per_function: u64,
+    /// The publish fee per verification unit, a verification unit is a bytecode instruction in the function bodies
+    pub publish_fee_per_verification_unit: u64,
+    /// The gas feature version of the schedule, the charges introduced by a later version are not applied,
+    /// so a node upgrade does not change the gas used of the transactions metered by the schedule.
+    #[serde(default)]
+    pub feature_version: u64,
+    /// The bytes the natives can allocate in a transaction, since `GAS_FEATURE_VERSION_NATIVE_MEMORY_QUOTA`
+    #[serde(default = "default_native_memory_quota")]
+    pub native_memory_quota: u64,
+    /// The price of a compute gas unit, the gas of the instructions, the natives and the module publishing
+    #[serde(default = "default_gas_price")]
+    pub compute_gas_price: u64,
+    /// The price of a storage gas unit, the gas of the bytes written to the store, the states and the events
+    #[serde(default = "default_gas_price")]
+    pub storage_gas_price: u64,
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+#[cfg_attr(test, derive(Arbitrary))]
 pub struct CostTable {
     pub instruction_tiers: BTreeMap<u64, u64>,
     pub stack_height_tiers: BTreeMap<u64, u64>,
@@ -97,6 +173,111 @@ impl CostTable {
             STACK_SIZE_TIER_DEFAULT,
         )
     }
+
+    pub fn feature_version(&self) -> u64 {
+        self.extra_gas_parameter.feature_version
+    }
+
+    /// The bytes the natives can allocate in a transaction, the natives are not limited by the legacy schedules
+    pub fn native_memory_quota(&self) -> u64 {
+        if self.feature_version() >= GAS_FEATURE_VERSION_NATIVE_MEMORY_QUOTA {
+            self.extra_gas_parameter.native_memory_quota
+        } else {
+            u64::MAX
+        }
+    }
+
+    /// The prices of the compute and storage gas, a zero price is charged as the default price,
+    /// so a dimension can not be used for free.
+    pub fn gas_prices(&self) -> GasPrices {
+        let price = |price: u64| if price == 0 { DEFAULT_GAS_PRICE } else { price };
+        GasPrices {
+            compute: price(self.extra_gas_parameter.compute_gas_price),
+            storage: price(self.extra_gas_parameter.storage_gas_price),
+        }
+    }
+
+    /// Flatten the cost table into the gas schedule entries, the same form as the gas parameters of the natives.
+    /// The tier keys are `<tiers>.<tier start>`, such as `instruction_tiers.3000`,
+    /// the keys of the extra gas parameters are `extra_gas_parameter.<name>`.
+    pub fn to_entries(&self) -> BTreeMap<String, u64> {
+        let mut entries = BTreeMap::new();
+        for (name, tiers) in [
+            (INSTRUCTION_TIERS_KEY, &self.instruction_tiers),
+            (STACK_HEIGHT_TIERS_KEY, &self.stack_height_tiers),
+            (STACK_SIZE_TIERS_KEY, &self.stack_size_tiers),
+        ] {
+            for (start, cost) in tiers {
+                entries.insert(format!("{}.{}", name, start), *cost);
+            }
+        }
+        if let Ok(serde_json::Value::Object(params)) =
+            serde_json::to_value(&self.extra_gas_parameter)
+        {
+            for (name, value) in params {
+                if let Some(value) = value.as_u64() {
+                    entries.insert(format!("{}.{}", EXTRA_GAS_PARAMETER_KEY, name), value);
+                }
+            }
+        }
+        entries
+    }
+
+    /// Whether the gas schedule entry key is a key of the cost table, the other keys are for the natives
+    pub fn is_entry_key(key: &str) -> bool {
+        key.split_once('.').map_or(false, |(name, _)| {
+            matches!(
+                name,
+                INSTRUCTION_TIERS_KEY
+                    | STACK_HEIGHT_TIERS_KEY
+                    | STACK_SIZE_TIERS_KEY
+                    | EXTRA_GAS_PARAMETER_KEY
+            )
+        })
+    }
+
+    /// Return a copy of the cost table with the gas schedule `entries` overridden.
+    /// A tier entry with a new tier start adds the tier, the unknown keys are rejected.
+    pub fn with_entries(&self, entries: &BTreeMap<String, u64>) -> anyhow::Result<CostTable> {
+        let mut cost_table = self.clone();
+        let mut extra_params = match serde_json::to_value(&self.extra_gas_parameter)? {
+            serde_json::Value::Object(params) => params,
+            _ => unreachable!("The extra gas parameter is a struct"),
+        };
+        for (key, value) in entries {
+            let (name, field) = key
+                .split_once('.')
+                .ok_or_else(|| anyhow!("Invalid gas schedule entry key {}", key))?;
+            let tiers = match name {
+                INSTRUCTION_TIERS_KEY => Some(&mut cost_table.instruction_tiers),
+                STACK_HEIGHT_TIERS_KEY => Some(&mut cost_table.stack_height_tiers),
+                STACK_SIZE_TIERS_KEY => Some(&mut cost_table.stack_size_tiers),
+                EXTRA_GAS_PARAMETER_KEY => None,
+                _ => bail!("Unknown gas schedule entry {}", key),
+            };
+            match tiers {
+                Some(tiers) => {
+                    let start = field.parse::<u64>().map_err(|e| {
+                        anyhow!(
+                            "Invalid tier start of the gas schedule entry {}: {}",
+                            key,
+                            e
+                        )
+                    })?;
+                    tiers.insert(start, *value);
+                }
+                None => {
+                    let param = extra_params
+                        .get_mut(field)
+                        .ok_or_else(|| anyhow!("Unknown gas schedule entry {}", key))?;
+                    *param = serde_json::Value::from(*value);
+                }
+            }
+        }
+        cost_table.extra_gas_parameter =
+            serde_json::from_value(serde_json::Value::Object(extra_params))?;
+        Ok(cost_table)
+    }
 }
 
 /// The  `GasCost` tracks:
@@ -104,6 +285,7 @@ impl CostTable {
 /// - memory cost: how much memory is required for the instruction, and storage overhead
 /// - stack height: how high is the stack growing (regardless of size in bytes)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(test, derive(Arbitrary))]
 pub struct GasCost {
     pub instruction_gas: u64,
     pub memory_gas: u64,
@@ -159,7 +341,18 @@ pub fn initial_cost_schedule() -> CostTable {
         storage_fee_per_event_byte: 20,
         storage_fee_per_op_new_byte: 51,
         storage_fee_per_op_modify_byte: 30,
+        storage_fee_per_op_new: 100,
+        storage_fee_per_op_modify: 50,
         storage_fee_per_op_delete: 10,
+        storage_rebate_per_deleted_byte: 40,
+        storage_refund_max_percentage: DEFAULT_STORAGE_REFUND_MAX_PERCENTAGE,
+        publish_fee_per_bytecode_byte: 10,
+        publish_fee_per_function: 500,
+        publish_fee_per_verification_unit: 5,
+        feature_version: LATEST_GAS_FEATURE_VERSION,
+        native_memory_quota: DEFAULT_NATIVE_MEMORY_QUOTA,
+        compute_gas_price: DEFAULT_GAS_PRICE,
+        storage_gas_price: DEFAULT_GAS_PRICE,
     };
 
     CostTable {
@@ -212,12 +405,28 @@ impl GasCost {
 #[derive(Debug, Clone)]
 pub struct MoveOSGasMeter {
     cost_table: CostTable,
+    // The priced gas left of the max gas amount, and the gas left of the budget of each dimension,
+    // a charge is deducted from its dimension budget and its priced gas from the gas left.
     gas_left: u64,
+    compute_gas_left: u64,
+    storage_gas_left: u64,
+    prices: GasPrices,
     //TODO we do not need to use gas_price in gas meter.
     charge: bool,
 
     execution_gas_used: Rc<RefCell<u64>>,
+    // The execution gas by category, the execution gas used is the sum of them
+    instruction_gas_used: Rc<RefCell<u64>>,
+    stack_gas_used: Rc<RefCell<u64>>,
+    native_gas_used: Rc<RefCell<u64>>,
     storage_gas_used: Rc<RefCell<u64>>,
+    publish_gas_used: Rc<RefCell<u64>>,
+    storage_rebate: Rc<RefCell<u64>>,
+    // The bytes written to the store and the storage gas charged by them
+    io_bytes_written: Rc<RefCell<u64>>,
+    io_gas_used: Rc<RefCell<u64>>,
+    // The gas which would be charged when the metering is stopped, it is recorded but not deducted
+    system_gas_used: Rc<RefCell<u64>>,
 
     // The current height of the operand stack, and the maximal height that it has reached.
     stack_height_high_water_mark: u64,
@@ -235,6 +444,12 @@ pub struct MoveOSGasMeter {
     instructions_executed: u64,
     instructions_next_tier_start: Option<u64>,
     instructions_current_tier_mult: u64,
+
+    // The wall-clock deadline of the execution, the execution fails with OUT_OF_GAS after it.
+    deadline: Option<Instant>,
+
+    // The gas meter of a simulated execution whose output is discarded, such as the gas estimation.
+    simulation: bool,
 }
 
 impl MoveOSGasMeter {
@@ -242,7 +457,19 @@ impl MoveOSGasMeter {
     ///
     /// Charge for every operation and fail when there is no more gas to pay for operations.
     /// This is the instantiation that must be used when executing a user function.
+    /// The compute and storage gas share the `budget`.
     pub fn new(cost_table: CostTable, budget: u64) -> Self {
+        Self::new_with_dimensions(cost_table, budget, GasBudget::shared(budget))
+    }
+
+    /// Initialize the gas state with metering enabled, the compute and storage gas are limited by their own
+    /// budgets, and their priced gas is limited by the `max_gas_amount`. The transaction runs out of the gas
+    /// of a dimension when its budget is exhausted, even if the other dimension has gas left.
+    pub fn new_with_dimensions(
+        cost_table: CostTable,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+    ) -> Self {
         //assert!(gas_price > 0, "gas price cannot be 0");
         //let budget_in_unit = budget / gas_price;
         // let gas_left = Self::to_internal_units(budget_in_unit);
@@ -253,11 +480,22 @@ impl MoveOSGasMeter {
         let (instructions_current_tier_mult, instructions_next_tier_start) =
             cost_table.instruction_tier(0);
         Self {
-            gas_left: budget,
+            gas_left: max_gas_amount,
+            compute_gas_left: gas_budget.compute,
+            storage_gas_left: gas_budget.storage,
+            prices: cost_table.gas_prices(),
             cost_table,
             charge: true,
             execution_gas_used: Rc::new(RefCell::new(0)),
+            instruction_gas_used: Rc::new(RefCell::new(0)),
+            stack_gas_used: Rc::new(RefCell::new(0)),
+            native_gas_used: Rc::new(RefCell::new(0)),
             storage_gas_used: Rc::new(RefCell::new(0)),
+            publish_gas_used: Rc::new(RefCell::new(0)),
+            storage_rebate: Rc::new(RefCell::new(0)),
+            io_bytes_written: Rc::new(RefCell::new(0)),
+            io_gas_used: Rc::new(RefCell::new(0)),
+            system_gas_used: Rc::new(RefCell::new(0)),
             stack_height_high_water_mark: 0,
             stack_height_current: 0,
             stack_size_high_water_mark: 0,
@@ -269,6 +507,8 @@ impl MoveOSGasMeter {
             stack_height_next_tier_start,
             stack_size_next_tier_start,
             instructions_next_tier_start,
+            deadline: None,
+            simulation: false,
         }
     }
 
@@ -276,24 +516,45 @@ impl MoveOSGasMeter {
     ///
     /// It should be used by clients in very specific cases and when executing system
     /// code that does not have to charge the user.
+    /// The gas is not charged, but recorded as the system gas by the initial cost schedule.
     pub fn new_unmetered() -> Self {
+        let cost_table = initial_cost_schedule();
+        let (stack_height_current_tier_mult, stack_height_next_tier_start) =
+            cost_table.stack_height_tier(0);
+        let (stack_size_current_tier_mult, stack_size_next_tier_start) =
+            cost_table.stack_size_tier(0);
+        let (instructions_current_tier_mult, instructions_next_tier_start) =
+            cost_table.instruction_tier(0);
         Self {
-            cost_table: ZERO_COST_SCHEDULE.clone(),
+            prices: cost_table.gas_prices(),
+            cost_table,
             gas_left: 0,
+            compute_gas_left: 0,
+            storage_gas_left: 0,
             charge: false,
             execution_gas_used: Rc::new(RefCell::new(0)),
+            instruction_gas_used: Rc::new(RefCell::new(0)),
+            stack_gas_used: Rc::new(RefCell::new(0)),
+            native_gas_used: Rc::new(RefCell::new(0)),
             storage_gas_used: Rc::new(RefCell::new(0)),
+            publish_gas_used: Rc::new(RefCell::new(0)),
+            storage_rebate: Rc::new(RefCell::new(0)),
+            io_bytes_written: Rc::new(RefCell::new(0)),
+            io_gas_used: Rc::new(RefCell::new(0)),
+            system_gas_used: Rc::new(RefCell::new(0)),
             stack_height_high_water_mark: 0,
             stack_height_current: 0,
-            stack_height_next_tier_start: None,
-            stack_height_current_tier_mult: 0,
+            stack_height_next_tier_start,
+            stack_height_current_tier_mult,
             stack_size_high_water_mark: 0,
             stack_size_current: 0,
-            stack_size_next_tier_start: None,
-            stack_size_current_tier_mult: 0,
+            stack_size_next_tier_start,
+            stack_size_current_tier_mult,
             instructions_executed: 0,
-            instructions_next_tier_start: None,
-            instructions_current_tier_mult: 0,
+            instructions_next_tier_start,
+            instructions_current_tier_mult,
+            deadline: None,
+            simulation: false,
         }
     }
 
@@ -381,59 +642,224 @@ impl MoveOSGasMeter {
         self.increase_stack_size(incr_size)?;
 
         let gas_cost = GasCost::new(
-            self.instructions_current_tier_mult
-                .checked_mul(num_instructions)
-                .ok_or_else(|| PartialVMError::new(StatusCode::ARITHMETIC_ERROR))?,
-            self.stack_size_current_tier_mult
-                .checked_mul(incr_size)
-                .ok_or_else(|| PartialVMError::new(StatusCode::ARITHMETIC_ERROR))?,
-            self.stack_height_current_tier_mult
-                .checked_mul(pushes)
-                .ok_or_else(|| PartialVMError::new(StatusCode::ARITHMETIC_ERROR))?,
-        )
-        .total_internal()
-        .into();
-        self.deduct_gas(gas_cost)?;
+            self.checked_fee(
+                self.instructions_current_tier_mult
+                    .checked_mul(num_instructions),
+            )?,
+            self.checked_fee(self.stack_size_current_tier_mult.checked_mul(incr_size))?,
+            self.checked_fee(self.stack_height_current_tier_mult.checked_mul(pushes))?,
+        );
+        let total_gas_cost: u64 = gas_cost.total_internal().into();
+        self.deduct_gas(total_gas_cost)?;
+        if self.charge {
+            Self::add_gas(&self.instruction_gas_used, gas_cost.instruction_gas);
+            Self::add_gas(
+                &self.stack_gas_used,
+                gas_cost.memory_gas + gas_cost.stack_height_gas,
+            );
+        }
 
         // self.decrease_stack_size(decr_size);
         self.pop_stack(pops);
 
-        Ok(gas_cost)
+        Ok(total_gas_cost)
+    }
+
+    /// Charge the gas of the bytecode instructions not metered by the cost table, such as a flat rate per instruction
+    pub fn charge_instruction_gas(&mut self, amount: u64) -> PartialVMResult<()> {
+        self.deduct_gas(amount)?;
+        if !self.charge {
+            return Ok(());
+        }
+        Self::add_gas(&self.instruction_gas_used, amount);
+        self.charge_execution(amount)
+    }
+
+    fn add_gas(gas_used: &Rc<RefCell<u64>>, amount: u64) {
+        let new_value = gas_used.borrow().saturating_add(amount);
+        *gas_used.borrow_mut() = new_value;
+    }
+
+    /// The fee of the system code is only recorded as the system gas, so an overflow saturates
+    /// instead of failing the system code, the overflow fails a metered execution.
+    fn checked_fee(&self, fee: Option<u64>) -> PartialVMResult<u64> {
+        match fee {
+            Some(fee) => Ok(fee),
+            None if !self.charge => Ok(u64::MAX),
+            None => Err(PartialVMError::new(StatusCode::ARITHMETIC_ERROR)),
+        }
     }
 
+    /// Deduct the `amount` of compute gas from the gas left, it is recorded as the system gas when the metering is stopped.
     pub fn deduct_gas(&mut self, amount: u64) -> PartialVMResult<()> {
+        self.deduct_dimension_gas(GasDimension::Compute, amount)
+    }
+
+    /// Deduct the `amount` of storage gas from the gas left, it is recorded as the system gas when the metering is stopped.
+    pub fn deduct_storage_gas(&mut self, amount: u64) -> PartialVMResult<()> {
+        self.deduct_dimension_gas(GasDimension::Storage, amount)
+    }
+
+    fn deduct_dimension_gas(
+        &mut self,
+        dimension: GasDimension,
+        amount: u64,
+    ) -> PartialVMResult<()> {
         if !self.charge {
+            Self::add_gas(&self.system_gas_used, amount);
             return Ok(());
         }
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                self.gas_left = 0;
+                return Err(PartialVMError::new(StatusCode::OUT_OF_GAS)
+                    .with_sub_status(EXECUTION_TIMEOUT_SUB_STATUS)
+                    .with_message("Execution deadline exceeded".to_string()));
+            }
+        }
 
-        match self.gas_left.checked_sub(amount) {
+        let dimension_gas_left = match dimension {
+            GasDimension::Compute => &mut self.compute_gas_left,
+            GasDimension::Storage => &mut self.storage_gas_left,
+        };
+        let Some(left) = dimension_gas_left.checked_sub(amount) else {
+            *dimension_gas_left = 0;
+            self.gas_left = 0;
+            return Err(PartialVMError::new(StatusCode::OUT_OF_GAS)
+                .with_sub_status(out_of_gas_sub_status(dimension))
+                .with_message(format!("Out of {} gas", dimension)));
+        };
+        *dimension_gas_left = left;
+
+        // The priced gas of both dimensions is limited by the max gas amount,
+        // the out of gas error reports the dimension of the charge exhausting it.
+        match self
+            .prices
+            .priced(dimension, amount)
+            .and_then(|priced| self.gas_left.checked_sub(priced))
+        {
             Some(gas_left) => {
                 self.gas_left = gas_left;
                 Ok(())
             }
             None => {
                 self.gas_left = 0;
-                Err(PartialVMError::new(StatusCode::OUT_OF_GAS))
+                Err(PartialVMError::new(StatusCode::OUT_OF_GAS)
+                    .with_sub_status(out_of_gas_sub_status(dimension))
+                    .with_message(format!("Out of {} gas", dimension)))
             }
         }
     }
 
+    /// The priced gas of the execution, storage and publish gas charged so far
+    fn priced_gas_charged(&self) -> u64 {
+        self.prices.priced_gas_used(
+            self.execution_gas_used
+                .borrow()
+                .saturating_add(*self.publish_gas_used.borrow()),
+            *self.storage_gas_used.borrow(),
+        )
+    }
+
+    /// The gas left of the budget of the `dimension`, before it is priced
+    pub fn dimension_gas_left(&self, dimension: GasDimension) -> u64 {
+        match dimension {
+            GasDimension::Compute => self.compute_gas_left,
+            GasDimension::Storage => self.storage_gas_left,
+        }
+    }
+
     pub fn set_metering(&mut self, enabled: bool) {
         self.charge = enabled;
     }
+
+    /// Set the wall-clock deadline of the execution, it only works when the metering is enabled.
+    pub fn set_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(deadline);
+    }
+
+    /// Meter a simulated execution, the gas is charged as usual, but the transaction is not validated,
+    /// so the failures of the system functions are reported as errors instead of panicking.
+    pub fn set_simulation(&mut self, simulation: bool) {
+        self.simulation = simulation;
+    }
+
+    pub fn is_simulation(&self) -> bool {
+        self.simulation
+    }
 }
 
 pub trait ClassifiedGasMeter {
+    /// The gas feature version of the cost table, the charges introduced by a later version are not applied
+    fn feature_version(&self) -> u64;
     fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()>;
     // fn charge_io_read(&mut self);
+    /// Charge the bytes written to the store, the transaction and the states written by the transaction.
     fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()>;
     fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()>;
     fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()>;
+    fn charge_module_publish(
+        &mut self,
+        modules: &[CompiledModule],
+        bytecode_size: u64,
+    ) -> PartialVMResult<()>;
+    /// Refund the storage deposit of the deleted states, capped at a percentage of the gas charged.
+    fn refund_storage_deposit(&mut self, deleted_bytes: u64) -> PartialVMResult<()>;
     fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()>;
     fn gas_statement(&self) -> GasStatement;
+    /// The prices of the compute and storage gas, the gas used is the priced gas of the gas statement
+    fn gas_prices(&self) -> GasPrices;
+    /// The total number of bytecode instructions that have been executed in the transaction.
+    fn instructions_executed(&self) -> u64;
+    /// Charge the full budget when the transaction runs out of gas.
+    fn charge_full_budget(&mut self, max_gas_amount: u64);
+    /// Take a checkpoint of the gas charged so far.
+    fn snapshot(&self) -> GasSnapshot;
+    /// The gas charged since the `snapshot` of this gas meter was taken.
+    fn delta_since(&self, snapshot: &GasSnapshot) -> GasDelta {
+        self.snapshot().delta_since(snapshot)
+    }
+    /// Notify the meter that the function is called by the transaction directly, such as the entry function,
+    /// it is not charged by a `charge_call` of the VM. It returns by a `Ret` as the functions called by the VM.
+    fn enter_function(&mut self, _module_id: &ModuleId, _func_name: &str) {}
+}
+
+/// A checkpoint of a gas meter, the gas charged between two checkpoints is attributed to the execution between them,
+/// such as an action of the transaction or its pre_execute and post_execute functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasSnapshot {
+    pub gas_left: u64,
+    pub instructions_executed: u64,
+    pub gas_statement: GasStatement,
+}
+
+impl GasSnapshot {
+    /// The gas charged from the `earlier` snapshot of the same gas meter to this one
+    pub fn delta_since(&self, earlier: &GasSnapshot) -> GasDelta {
+        GasDelta {
+            gas_used: earlier.gas_left.saturating_sub(self.gas_left),
+            instructions_executed: self
+                .instructions_executed
+                .saturating_sub(earlier.instructions_executed),
+            gas_statement: self.gas_statement.delta_since(&earlier.gas_statement),
+        }
+    }
+}
+
+/// The gas charged between two snapshots of a gas meter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasDelta {
+    /// The gas deducted from the gas left, the storage rebate is not deducted
+    pub gas_used: u64,
+    pub instructions_executed: u64,
+    pub gas_statement: GasStatement,
 }
 
 impl ClassifiedGasMeter for MoveOSGasMeter {
+    fn feature_version(&self) -> u64 {
+        self.cost_table.feature_version()
+    }
+
     fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
         if !self.charge {
             return Ok(());
@@ -447,76 +873,157 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
     // fn charge_io_read(&mut self) {}
 
     fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        let fee = self.checked_fee(
+            self.cost_table
+                .extra_gas_parameter
+                .storage_fee_per_transaction_byte
+                .checked_mul(data_size),
+        )?;
         if !self.charge {
+            Self::add_gas(&self.system_gas_used, fee);
             return Ok(());
         }
-
-        let fee = self
-            .cost_table
-            .extra_gas_parameter
-            .storage_fee_per_transaction_byte
-            * data_size;
+        Self::add_gas(&self.io_bytes_written, data_size);
+        Self::add_gas(&self.io_gas_used, fee);
         let new_value = self.storage_gas_used.borrow().add(fee);
         *self.storage_gas_used.borrow_mut() = new_value;
-        self.deduct_gas(fee)
+        self.deduct_storage_gas(fee)
     }
 
     fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()> {
+        let fee_per_byte = self
+            .cost_table
+            .extra_gas_parameter
+            .storage_fee_per_event_byte;
+        let total_event_fee = self.checked_fee(events.iter().try_fold(0u64, |total, event| {
+            (event.event_data.len() as u64)
+                .checked_mul(fee_per_byte)
+                .and_then(|fee| total.checked_add(fee))
+        }))?;
         if !self.charge {
+            Self::add_gas(&self.system_gas_used, total_event_fee);
             return Ok(());
         }
+        Self::add_gas(&self.storage_gas_used, total_event_fee);
+        self.deduct_storage_gas(total_event_fee)
+    }
 
-        let mut total_event_fee = 0;
-        for event in events {
-            let fee = event.event_data.len() as u64
-                * self
-                    .cost_table
-                    .extra_gas_parameter
-                    .storage_fee_per_event_byte;
-            let new_value = self.storage_gas_used.borrow().add(fee);
-            *self.storage_gas_used.borrow_mut() = new_value;
-            total_event_fee += fee;
+    // The resources and modules are stored as the table entries of the account storage,
+    // so the table changes cover all the states written by the transaction.
+    fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()> {
+        let extra_gas_parameter = &self.cost_table.extra_gas_parameter;
+        let (fee_per_op_new, fee_per_op_modify) =
+            if self.cost_table.feature_version() >= GAS_FEATURE_VERSION_STORAGE_FEE {
+                (
+                    extra_gas_parameter.storage_fee_per_op_new,
+                    extra_gas_parameter.storage_fee_per_op_modify,
+                )
+            } else {
+                (0, 0)
+            };
+        // The bytes written by the states are reported as the io of the transaction, they are charged by the per byte fees
+        let mut total_change_set_fee = 0u64;
+        let mut bytes_written = 0u64;
+        let mut bytes_fee = 0u64;
+        for table_change in change_set.changes.values() {
+            for (key, op) in table_change.entries.iter() {
+                let (size, fee_per_byte, fee_per_op) = match op {
+                    Op::New(value) => (
+                        key.len() + value.value.len(),
+                        extra_gas_parameter.storage_fee_per_op_new_byte,
+                        fee_per_op_new,
+                    ),
+                    Op::Modify(value) => (
+                        key.len() + value.value.len(),
+                        extra_gas_parameter.storage_fee_per_op_modify_byte,
+                        fee_per_op_modify,
+                    ),
+                    Op::Delete => (key.len(), 0, extra_gas_parameter.storage_fee_per_op_delete),
+                };
+                let size_fee = self.checked_fee((size as u64).checked_mul(fee_per_byte))?;
+                total_change_set_fee = self.checked_fee(
+                    size_fee
+                        .checked_add(fee_per_op)
+                        .and_then(|fee| total_change_set_fee.checked_add(fee)),
+                )?;
+                bytes_written = bytes_written.saturating_add(size as u64);
+                bytes_fee = bytes_fee.saturating_add(size_fee);
+            }
+        }
+        if !self.charge {
+            Self::add_gas(&self.system_gas_used, total_change_set_fee);
+            return Ok(());
         }
-        self.deduct_gas(total_event_fee)
+        self.deduct_storage_gas(total_change_set_fee)?;
+        let new_value = self.storage_gas_used.borrow().add(total_change_set_fee);
+        *self.storage_gas_used.borrow_mut() = new_value;
+        Self::add_gas(&self.io_bytes_written, bytes_written);
+        Self::add_gas(&self.io_gas_used, bytes_fee);
+        Ok(())
     }
 
-    fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()> {
+    fn charge_module_publish(
+        &mut self,
+        modules: &[CompiledModule],
+        bytecode_size: u64,
+    ) -> PartialVMResult<()> {
+        let extra_gas_parameter = &self.cost_table.extra_gas_parameter;
+        let mut function_count = 0u64;
+        let mut verification_units = 0u64;
+        for module in modules {
+            function_count += module.function_defs.len() as u64;
+            verification_units += module
+                .function_defs
+                .iter()
+                .filter_map(|function_def| function_def.code.as_ref())
+                .map(|code_unit| code_unit.code.len() as u64)
+                .sum::<u64>();
+        }
+
+        let fee = self.checked_fee(
+            bytecode_size
+                .checked_mul(extra_gas_parameter.publish_fee_per_bytecode_byte)
+                .and_then(|bytecode_fee| {
+                    function_count
+                        .checked_mul(extra_gas_parameter.publish_fee_per_function)
+                        .and_then(|function_fee| bytecode_fee.checked_add(function_fee))
+                })
+                .and_then(|fee| {
+                    verification_units
+                        .checked_mul(extra_gas_parameter.publish_fee_per_verification_unit)
+                        .and_then(|verification_fee| fee.checked_add(verification_fee))
+                }),
+        )?;
         if !self.charge {
+            Self::add_gas(&self.system_gas_used, fee);
             return Ok(());
         }
+        let new_value = self.publish_gas_used.borrow().add(fee);
+        *self.publish_gas_used.borrow_mut() = new_value;
+        self.deduct_gas(fee)
+    }
 
-        let mut total_change_set_fee = 0;
-        for (_, table_change) in change_set.changes.iter() {
-            for (key, op) in table_change.entries.iter() {
-                let fee = {
-                    match op {
-                        Op::Modify(value) => {
-                            (key.len() + value.value.len()) as u64
-                                * self
-                                    .cost_table
-                                    .extra_gas_parameter
-                                    .storage_fee_per_op_modify_byte
-                        }
-                        Op::Delete => {
-                            self.cost_table
-                                .extra_gas_parameter
-                                .storage_fee_per_op_delete
-                        }
-                        Op::New(value) => {
-                            (key.len() + value.value.len()) as u64
-                                * self
-                                    .cost_table
-                                    .extra_gas_parameter
-                                    .storage_fee_per_op_new_byte
-                        }
-                    }
-                };
-                let new_value = self.storage_gas_used.borrow().add(fee);
-                *self.storage_gas_used.borrow_mut() = new_value;
-                total_change_set_fee += fee;
-            }
+    fn refund_storage_deposit(&mut self, deleted_bytes: u64) -> PartialVMResult<()> {
+        if !self.charge || self.cost_table.feature_version() < GAS_FEATURE_VERSION_STORAGE_FEE {
+            return Ok(());
         }
-        self.deduct_gas(total_change_set_fee)
+
+        // The refund is capped at a percentage of the gas charged, so it should be called after all the charges.
+        // It is deducted from the gas used of the transaction, but the gas left is not increased.
+        let extra_gas_parameter = &self.cost_table.extra_gas_parameter;
+        let rebate = deleted_bytes
+            .checked_mul(extra_gas_parameter.storage_rebate_per_deleted_byte)
+            .ok_or_else(|| PartialVMError::new(StatusCode::ARITHMETIC_ERROR))?;
+        let gas_charged = self.priced_gas_charged();
+        // The refund is deducted from the priced gas used, so the cap is converted to the storage gas
+        let max_refund = (gas_charged as u128
+            * extra_gas_parameter.storage_refund_max_percentage.min(100) as u128
+            / 100
+            / self.prices.storage as u128) as u64;
+        let refund = rebate.min(max_refund.saturating_sub(*self.storage_rebate.borrow()));
+        let new_value = self.storage_rebate.borrow().add(refund);
+        *self.storage_rebate.borrow_mut() = new_value;
+        Ok(())
     }
 
     fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
@@ -529,9 +1036,8 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
             return Ok(());
         }
 
-        let execution_gas_used = *self.execution_gas_used.borrow();
-        let storage_gas_used = *self.storage_gas_used.borrow();
-        if gas_used != execution_gas_used + storage_gas_used {
+        // The unaccounted gas of the full budget is recorded as the compute gas, rounded up by the compute price
+        if gas_used != self.priced_gas_charged().min(max_gas_amount) {
             return Err(PartialVMError::new(StatusCode::ABORTED)
                 .with_message("Failed to check the constraints of the gas_used.".to_owned()));
         }
@@ -541,7 +1047,45 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
     fn gas_statement(&self) -> GasStatement {
         GasStatement {
             execution_gas_used: *self.execution_gas_used.borrow(),
+            instruction_gas_used: *self.instruction_gas_used.borrow(),
+            stack_gas_used: *self.stack_gas_used.borrow(),
+            native_gas_used: *self.native_gas_used.borrow(),
             storage_gas_used: *self.storage_gas_used.borrow(),
+            publish_gas_used: *self.publish_gas_used.borrow(),
+            storage_rebate: *self.storage_rebate.borrow(),
+            io_bytes_written: *self.io_bytes_written.borrow(),
+            io_gas_used: *self.io_gas_used.borrow(),
+            system_gas_used: *self.system_gas_used.borrow(),
+        }
+    }
+
+    fn gas_prices(&self) -> GasPrices {
+        self.prices
+    }
+
+    fn instructions_executed(&self) -> u64 {
+        self.instructions_executed
+    }
+
+    // The charge that exceeds the gas left is not recorded, so the unaccounted gas is recorded as the execution gas,
+    // to keep the gas statement consistent with the gas used.
+    fn charge_full_budget(&mut self, max_gas_amount: u64) {
+        let accounted = self.priced_gas_charged();
+        let unaccounted = max_gas_amount
+            .saturating_sub(accounted)
+            .div_ceil(self.prices.compute);
+        let new_value = self.execution_gas_used.borrow().add(unaccounted);
+        *self.execution_gas_used.borrow_mut() = new_value;
+        // The unaccounted gas is the instruction which runs out of gas
+        Self::add_gas(&self.instruction_gas_used, unaccounted);
+        self.gas_left = 0;
+    }
+
+    fn snapshot(&self) -> GasSnapshot {
+        GasSnapshot {
+            gas_left: self.gas_left,
+            instructions_executed: self.instructions_executed,
+            gas_statement: self.gas_statement(),
         }
     }
 }
@@ -923,6 +1467,9 @@ impl GasMeter for MoveOSGasMeter {
         //self.deduct_gas(amount.into())
 
         self.charge_execution(amount.into())?;
+        if self.charge {
+            Self::add_gas(&self.native_gas_used, amount.into());
+        }
         self.deduct_gas(amount.into())?;
         self.charge_internal_execution(0, pushes, 0, size_increase.into(), 0)
     }
@@ -966,3 +1513,507 @@ impl SwitchableGasMeter for MoveOSGasMeter {
         self.charge
     }
 }
+
+impl TransactionGasMeter for MoveOSGasMeter {
+    fn new_with_gas_budget(
+        cost_table: CostTable,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+    ) -> Self {
+        Self::new_with_dimensions(cost_table, max_gas_amount, gas_budget)
+    }
+
+    fn set_deadline(&mut self, deadline: Instant) {
+        MoveOSGasMeter::set_deadline(self, deadline)
+    }
+
+    fn set_simulation(&mut self, simulation: bool) {
+        MoveOSGasMeter::set_simulation(self, simulation)
+    }
+
+    fn is_simulation(&self) -> bool {
+        MoveOSGasMeter::is_simulation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gas::budget::{OUT_OF_COMPUTE_GAS_SUB_STATUS, OUT_OF_STORAGE_GAS_SUB_STATUS};
+    use move_core_types::language_storage::TypeTag;
+    use moveos_types::moveos_std::object::ObjectID;
+    use moveos_types::state::State;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_charge_full_budget_when_out_of_gas() {
+        // Each LdU64 costs 10, the budget is not a multiple of it
+        let max_gas_amount = 105;
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), max_gas_amount);
+        let mut result = Ok(());
+        while result.is_ok() {
+            result = gas_meter.charge_simple_instr(SimpleInstruction::LdU64);
+        }
+        assert_eq!(result.unwrap_err().major_status(), StatusCode::OUT_OF_GAS);
+        // The last charge which runs out of gas is not recorded
+        assert!(gas_meter.check_constrains(max_gas_amount).is_err());
+        assert!(gas_meter.instructions_executed() > 0);
+
+        gas_meter.charge_full_budget(max_gas_amount);
+        assert_eq!(u64::from(gas_meter.balance_internal()), 0);
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+        let gas_statement = gas_meter.gas_statement();
+        assert_eq!(gas_statement.execution_gas_used, max_gas_amount);
+        assert_eq!(
+            gas_statement.instruction_gas_used
+                + gas_statement.stack_gas_used
+                + gas_statement.native_gas_used,
+            max_gas_amount
+        );
+    }
+
+    #[test]
+    fn test_execution_gas_breakdown() {
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), 10000);
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Add)
+            .unwrap();
+        let gas_statement = gas_meter.gas_statement();
+        assert!(gas_statement.instruction_gas_used > 0);
+        assert!(gas_statement.stack_gas_used > 0);
+        assert_eq!(gas_statement.native_gas_used, 0);
+        assert_eq!(
+            gas_statement.instruction_gas_used
+                + gas_statement.stack_gas_used
+                + gas_statement.native_gas_used,
+            gas_statement.execution_gas_used
+        );
+    }
+
+    #[test]
+    fn test_execution_deadline_exceeded() {
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), 1_000_000);
+        gas_meter.set_deadline(Instant::now());
+        let err = gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(err.sub_status(), Some(EXECUTION_TIMEOUT_SUB_STATUS));
+
+        // The deadline does not apply when the metering is stopped, such as the system functions
+        gas_meter.set_metering(false);
+        assert!(gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_charge_change_set() {
+        let cost_table = initial_cost_schedule();
+        let extra_gas_parameter = cost_table.extra_gas_parameter.clone();
+        let mut change_set = StateChangeSet::default();
+        let table_change = change_set
+            .get_or_insert_table_change(ObjectID::from(AccountAddress::ONE), TypeTag::U64);
+        table_change
+            .entries
+            .insert(vec![1], Op::New(State::new(vec![0; 9], TypeTag::U8)));
+        table_change
+            .entries
+            .insert(vec![2, 2], Op::Modify(State::new(vec![0; 3], TypeTag::U8)));
+        table_change.entries.insert(vec![3], Op::Delete);
+        let expected_fee = 10 * extra_gas_parameter.storage_fee_per_op_new_byte
+            + extra_gas_parameter.storage_fee_per_op_new
+            + 5 * extra_gas_parameter.storage_fee_per_op_modify_byte
+            + extra_gas_parameter.storage_fee_per_op_modify
+            + extra_gas_parameter.storage_fee_per_op_delete;
+
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter = MoveOSGasMeter::new(cost_table.clone(), max_gas_amount);
+        gas_meter.charge_change_set(&change_set).unwrap();
+        assert_eq!(gas_meter.gas_statement().storage_gas_used, expected_fee);
+        // The bytes written are reported, but not charged again
+        assert_eq!(gas_meter.gas_statement().io_bytes_written, 10 + 5 + 1);
+        assert_eq!(
+            gas_meter.gas_statement().io_gas_used,
+            10 * extra_gas_parameter.storage_fee_per_op_new_byte
+                + 5 * extra_gas_parameter.storage_fee_per_op_modify_byte
+        );
+        assert_eq!(
+            u64::from(gas_meter.balance_internal()),
+            max_gas_amount - expected_fee
+        );
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+
+        // The storage gas is not recorded if the gas left is insufficient
+        let mut gas_meter = MoveOSGasMeter::new(cost_table.clone(), expected_fee - 1);
+        let err = gas_meter.charge_change_set(&change_set).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(gas_meter.gas_statement().storage_gas_used, 0);
+
+        // The per-op fees are not charged by a legacy schedule
+        let mut entries = BTreeMap::new();
+        entries.insert(
+            "extra_gas_parameter.feature_version".to_owned(),
+            GAS_FEATURE_VERSION_LEGACY,
+        );
+        let mut gas_meter =
+            MoveOSGasMeter::new(cost_table.with_entries(&entries).unwrap(), max_gas_amount);
+        gas_meter.charge_change_set(&change_set).unwrap();
+        assert_eq!(
+            gas_meter.gas_statement().storage_gas_used,
+            expected_fee
+                - extra_gas_parameter.storage_fee_per_op_new
+                - extra_gas_parameter.storage_fee_per_op_modify
+        );
+    }
+
+    #[test]
+    fn test_charge_io_write() {
+        let cost_table = initial_cost_schedule();
+        let fee_per_byte = cost_table
+            .extra_gas_parameter
+            .storage_fee_per_transaction_byte;
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, max_gas_amount);
+        gas_meter.charge_io_write(100).unwrap();
+        gas_meter.charge_io_write(50).unwrap();
+        let gas_statement = gas_meter.gas_statement();
+        assert_eq!(gas_statement.io_bytes_written, 150);
+        assert_eq!(gas_statement.io_gas_used, 150 * fee_per_byte);
+        assert_eq!(gas_statement.storage_gas_used, gas_statement.io_gas_used);
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+
+        let mut unmetered = MoveOSGasMeter::new_unmetered();
+        unmetered.charge_io_write(100).unwrap();
+        assert_eq!(unmetered.gas_statement().io_bytes_written, 0);
+    }
+
+    #[test]
+    fn test_out_of_compute_gas() {
+        // Each LdU64 costs 10, the second one exhausts the budget
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), 15);
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        assert_eq!(u64::from(gas_meter.balance_internal()), 5);
+        let err = gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(err.sub_status(), Some(OUT_OF_COMPUTE_GAS_SUB_STATUS));
+        assert_eq!(u64::from(gas_meter.balance_internal()), 0);
+    }
+
+    #[test]
+    fn test_out_of_storage_gas() {
+        let cost_table = initial_cost_schedule();
+        let fee_per_byte = cost_table
+            .extra_gas_parameter
+            .storage_fee_per_transaction_byte;
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, 10 + 10 * fee_per_byte);
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter.charge_io_write(10).unwrap();
+        assert_eq!(u64::from(gas_meter.balance_internal()), 0);
+
+        let err = gas_meter.charge_io_write(1).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(err.sub_status(), Some(OUT_OF_STORAGE_GAS_SUB_STATUS));
+    }
+
+    #[test]
+    fn test_shared_budget_out_of_gas_dimension() {
+        // The dimension charged when the shared budget is exhausted runs out of gas
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), 15);
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        let err = gas_meter.charge_io_write(100).unwrap_err();
+        assert_eq!(err.sub_status(), Some(OUT_OF_STORAGE_GAS_SUB_STATUS));
+    }
+
+    #[test]
+    fn test_out_of_compute_budget() {
+        // Each LdU64 costs 10, the storage budget is not used by the execution
+        let mut gas_meter = MoveOSGasMeter::new_with_dimensions(
+            initial_cost_schedule(),
+            1_000_000,
+            GasBudget::new(15, 1_000_000),
+        );
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        assert_eq!(gas_meter.dimension_gas_left(GasDimension::Compute), 5);
+        let err = gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(err.sub_status(), Some(OUT_OF_COMPUTE_GAS_SUB_STATUS));
+    }
+
+    #[test]
+    fn test_out_of_storage_budget_with_compute_gas_left() {
+        let cost_table = initial_cost_schedule();
+        let fee_per_byte = cost_table
+            .extra_gas_parameter
+            .storage_fee_per_transaction_byte;
+        let mut gas_meter = MoveOSGasMeter::new_with_dimensions(
+            cost_table,
+            1_000_000,
+            GasBudget::new(1_000_000, 10 * fee_per_byte),
+        );
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter.charge_io_write(10).unwrap();
+        assert_eq!(gas_meter.dimension_gas_left(GasDimension::Storage), 0);
+        assert!(gas_meter.dimension_gas_left(GasDimension::Compute) > 0);
+        assert!(u64::from(gas_meter.balance_internal()) > 0);
+
+        // The storage runs out of gas while the compute gas and the max gas amount are left
+        let err = gas_meter.charge_io_write(1).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+        assert_eq!(err.sub_status(), Some(OUT_OF_STORAGE_GAS_SUB_STATUS));
+    }
+
+    #[test]
+    fn test_gas_prices() {
+        let mut entries = BTreeMap::new();
+        entries.insert("extra_gas_parameter.storage_gas_price".to_owned(), 3);
+        let cost_table = initial_cost_schedule().with_entries(&entries).unwrap();
+        let prices = cost_table.gas_prices();
+        assert_eq!(prices.compute, DEFAULT_GAS_PRICE);
+        assert_eq!(prices.storage, 3);
+        let fee_per_byte = cost_table
+            .extra_gas_parameter
+            .storage_fee_per_transaction_byte;
+
+        // The budgets limit the gas of each dimension, the max gas amount limits their priced gas
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter = MoveOSGasMeter::new_with_dimensions(
+            cost_table,
+            max_gas_amount,
+            GasBudget::shared(max_gas_amount),
+        );
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter.charge_io_write(10).unwrap();
+        let gas_statement = gas_meter.gas_statement();
+        assert_eq!(gas_statement.storage_gas_used, 10 * fee_per_byte);
+        assert_eq!(
+            max_gas_amount - u64::from(gas_meter.balance_internal()),
+            prices.priced_gas_used(
+                gas_statement.compute_gas_used(),
+                gas_statement.storage_gas_used
+            )
+        );
+        assert_eq!(
+            gas_meter.dimension_gas_left(GasDimension::Storage),
+            max_gas_amount - 10 * fee_per_byte
+        );
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+
+        // The zero prices of the legacy schedules are charged as the default price
+        assert_eq!(zero_cost_schedule().gas_prices(), GasPrices::default());
+    }
+
+    #[test]
+    fn test_system_gas_is_not_charged() {
+        let cost_table = initial_cost_schedule();
+        let fee_per_byte = cost_table
+            .extra_gas_parameter
+            .storage_fee_per_transaction_byte;
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, max_gas_amount);
+        gas_meter.stop_metering();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter.charge_io_write(100).unwrap();
+        gas_meter.start_metering();
+        let gas_statement = gas_meter.gas_statement();
+        assert!(gas_statement.system_gas_used > 100 * fee_per_byte);
+        assert_eq!(gas_statement.execution_gas_used, 0);
+        assert_eq!(gas_statement.storage_gas_used, 0);
+        assert_eq!(u64::from(gas_meter.balance_internal()), max_gas_amount);
+
+        // The system gas is not recorded when the metering is enabled
+        gas_meter.charge_io_write(100).unwrap();
+        assert_eq!(
+            gas_meter.gas_statement().system_gas_used,
+            gas_statement.system_gas_used
+        );
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+    }
+
+    #[test]
+    fn test_unmetered_system_gas() {
+        // The unmetered gas meter records the system gas by the real schedule
+        let mut unmetered = MoveOSGasMeter::new_unmetered();
+        unmetered
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        assert!(unmetered.gas_statement().system_gas_used > 0);
+
+        // An overflowed fee saturates the system gas instead of failing the system code
+        unmetered.charge_io_write(u64::MAX).unwrap();
+        assert_eq!(unmetered.gas_statement().system_gas_used, u64::MAX);
+        unmetered.charge_io_write(1).unwrap();
+        assert_eq!(unmetered.gas_statement().system_gas_used, u64::MAX);
+
+        // The overflow fails a metered execution
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), 1_000_000);
+        let err = gas_meter.charge_io_write(u64::MAX).unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::ARITHMETIC_ERROR);
+    }
+
+    #[test]
+    fn test_gas_snapshot_delta() {
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter = MoveOSGasMeter::new(initial_cost_schedule(), max_gas_amount);
+        let start = gas_meter.snapshot();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        let snapshot = gas_meter.snapshot();
+        let empty_delta = gas_meter.delta_since(&snapshot);
+        assert_eq!(empty_delta.gas_used, 0);
+        assert_eq!(empty_delta.instructions_executed, 0);
+
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter.charge_io_write(10).unwrap();
+        let delta = gas_meter.delta_since(&snapshot);
+        assert_eq!(delta.instructions_executed, 1);
+        assert_eq!(delta.gas_statement.io_bytes_written, 10);
+        assert_eq!(
+            delta.gas_used,
+            delta.gas_statement.execution_gas_used + delta.gas_statement.storage_gas_used
+        );
+
+        // The deltas of the consecutive checkpoints add up to the gas used of the transaction
+        let total = gas_meter.delta_since(&start);
+        assert_eq!(
+            total.gas_used,
+            snapshot.delta_since(&start).gas_used + delta.gas_used
+        );
+        assert_eq!(total.gas_statement, gas_meter.gas_statement());
+    }
+
+    #[test]
+    fn test_storage_refund_is_capped() {
+        let cost_table = initial_cost_schedule();
+        let extra_gas_parameter = cost_table.extra_gas_parameter.clone();
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter = MoveOSGasMeter::new(cost_table.clone(), max_gas_amount);
+        gas_meter.charge_io_write(100).unwrap();
+        let gas_charged = 100 * extra_gas_parameter.storage_fee_per_transaction_byte;
+
+        gas_meter.refund_storage_deposit(1).unwrap();
+        assert_eq!(
+            gas_meter.gas_statement().storage_rebate,
+            extra_gas_parameter.storage_rebate_per_deleted_byte
+        );
+        gas_meter.refund_storage_deposit(1_000_000).unwrap();
+        assert_eq!(
+            gas_meter.gas_statement().storage_rebate,
+            gas_charged * extra_gas_parameter.storage_refund_max_percentage / 100
+        );
+        // The refund does not change the gas left
+        assert!(gas_meter.check_constrains(max_gas_amount).is_ok());
+
+        let mut unmetered = MoveOSGasMeter::new_unmetered();
+        unmetered.refund_storage_deposit(1_000_000).unwrap();
+        assert_eq!(unmetered.gas_statement().storage_rebate, 0);
+
+        // The storage deposit is not refunded by a legacy schedule
+        let mut entries = BTreeMap::new();
+        entries.insert(
+            "extra_gas_parameter.feature_version".to_owned(),
+            GAS_FEATURE_VERSION_LEGACY,
+        );
+        let mut legacy =
+            MoveOSGasMeter::new(cost_table.with_entries(&entries).unwrap(), max_gas_amount);
+        legacy.charge_io_write(100).unwrap();
+        legacy.refund_storage_deposit(1).unwrap();
+        assert_eq!(legacy.gas_statement().storage_rebate, 0);
+    }
+
+    #[test]
+    fn test_cost_table_entries() {
+        let cost_table = initial_cost_schedule();
+        let entries = cost_table.to_entries();
+        assert_eq!(entries.get("instruction_tiers.3000"), Some(&2));
+        assert_eq!(entries.get("extra_gas_parameter.io_read_price"), Some(&1));
+        assert_eq!(cost_table.with_entries(&entries).unwrap(), cost_table);
+
+        let mut new_entries = BTreeMap::new();
+        new_entries.insert("instruction_tiers.3000".to_owned(), 4);
+        new_entries.insert("stack_size_tiers.30000".to_owned(), 200);
+        new_entries.insert("extra_gas_parameter.io_read_price".to_owned(), 2);
+        let new_cost_table = cost_table.with_entries(&new_entries).unwrap();
+        assert_eq!(new_cost_table.instruction_tier(3000), (4, Some(6000)));
+        assert_eq!(new_cost_table.stack_size_tier(30000), (200, None));
+        assert_eq!(new_cost_table.extra_gas_parameter.io_read_price, 2);
+
+        let mut unknown_entries = BTreeMap::new();
+        unknown_entries.insert("extra_gas_parameter.unknown".to_owned(), 1);
+        assert!(cost_table.with_entries(&unknown_entries).is_err());
+        unknown_entries.clear();
+        unknown_entries.insert("unknown_tiers.0".to_owned(), 1);
+        assert!(cost_table.with_entries(&unknown_entries).is_err());
+    }
+
+    #[test]
+    fn test_native_memory_quota_feature_version() {
+        let cost_table = initial_cost_schedule();
+        assert_eq!(
+            cost_table.native_memory_quota(),
+            DEFAULT_NATIVE_MEMORY_QUOTA
+        );
+
+        let mut entries = BTreeMap::new();
+        entries.insert("extra_gas_parameter.native_memory_quota".to_owned(), 1024);
+        assert_eq!(
+            cost_table
+                .with_entries(&entries)
+                .unwrap()
+                .native_memory_quota(),
+            1024
+        );
+        // The natives are not limited by a legacy schedule
+        entries.insert(
+            "extra_gas_parameter.feature_version".to_owned(),
+            GAS_FEATURE_VERSION_LEGACY,
+        );
+        assert_eq!(
+            cost_table
+                .with_entries(&entries)
+                .unwrap()
+                .native_memory_quota(),
+            u64::MAX
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_cost_table_bcs_serde(input in any::<CostTable>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: CostTable = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+
+        #[test]
+        fn test_gas_cost_bcs_serde(input in any::<GasCost>()) {
+            let serialized = bcs::to_bytes(&input).unwrap();
+            let deserialized: GasCost = bcs::from_bytes(&serialized).unwrap();
+            assert_eq!(input, deserialized);
+        }
+    }
+}
@@ -0,0 +1,718 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::budget::GasPrices;
+use super::table::{ClassifiedGasMeter, CostTable, GasSnapshot};
+use super::{SwitchableGasMeter, TransactionGasMeter};
+use move_binary_format::errors::PartialVMResult;
+use move_binary_format::file_format::CodeOffset;
+use move_binary_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::gas_algebra::{InternalGas, NumArgs, NumBytes};
+use move_core_types::language_storage::ModuleId;
+use move_vm_types::gas::{GasMeter, SimpleInstruction};
+use move_vm_types::views::{TypeView, ValueView};
+use moveos_types::moveos_std::event::TransactionEvent;
+use moveos_types::state::StateChangeSet;
+use moveos_types::transaction::{GasBudget, GasStatement};
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// The root frame of a gas trace, the gas charged outside of the called functions is recorded in it
+pub const GAS_TRACE_ROOT: &str = "transaction";
+/// The frame of a native function called without a `charge_call`
+const NATIVE_FRAME: &str = "[native]";
+const IO_FRAME: &str = "[io]";
+const EVENT_FRAME: &str = "[event]";
+const STORAGE_FRAME: &str = "[storage]";
+const PUBLISH_FRAME: &str = "[publish]";
+
+/// A frame of the gas call tree, the calls to the same function from the same caller are merged into one frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasFrame {
+    pub name: String,
+    /// The gas charged in the frame, excluding the gas of the callees
+    pub self_gas: u64,
+    pub children: Vec<usize>,
+}
+
+impl GasFrame {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            self_gas: 0,
+            children: vec![],
+        }
+    }
+}
+
+/// The call tree of a transaction with the gas costs, recorded by the `TracingGasMeter`.
+/// The storage, event, io and publish fees are recorded in the dedicated frames under the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasTrace {
+    frames: Vec<GasFrame>,
+    /// The storage deposit refunded by deleting states, it is not a charge of a frame,
+    /// the gas used of the transaction is the total gas of the root minus it
+    storage_rebate: u64,
+}
+
+impl Default for GasTrace {
+    fn default() -> Self {
+        Self {
+            frames: vec![GasFrame::new(GAS_TRACE_ROOT.to_owned())],
+            storage_rebate: 0,
+        }
+    }
+}
+
+impl GasTrace {
+    pub const ROOT: usize = 0;
+
+    pub fn frame(&self, index: usize) -> &GasFrame {
+        &self.frames[index]
+    }
+
+    pub fn root(&self) -> &GasFrame {
+        self.frame(Self::ROOT)
+    }
+
+    pub fn storage_rebate(&self) -> u64 {
+        self.storage_rebate
+    }
+
+    /// The child frame of the `parent` by the name
+    pub fn find_child(&self, parent: usize, name: &str) -> Option<usize> {
+        self.frames[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.frames[*child].name == name)
+    }
+
+    /// The gas of the frame including its callees
+    pub fn total_gas(&self, index: usize) -> u64 {
+        let frame = &self.frames[index];
+        frame.self_gas
+            + frame
+                .children
+                .iter()
+                .map(|child| self.total_gas(*child))
+                .sum::<u64>()
+    }
+
+    fn child(&mut self, parent: usize, name: &str) -> usize {
+        if let Some(child) = self.find_child(parent, name) {
+            return child;
+        }
+        self.frames.push(GasFrame::new(name.to_owned()));
+        let child = self.frames.len() - 1;
+        self.frames[parent].children.push(child);
+        child
+    }
+
+    /// Render the trace in the folded stack format, one `frame;frame;frame gas` line per frame charging gas.
+    /// It is consumed by the flamegraph tools, such as `inferno-flamegraph` and speedscope.
+    pub fn to_folded(&self) -> String {
+        let mut output = String::new();
+        let mut stack = vec![];
+        self.write_folded(Self::ROOT, &mut stack, &mut output);
+        output
+    }
+
+    fn write_folded<'a>(&'a self, index: usize, stack: &mut Vec<&'a str>, output: &mut String) {
+        let frame = &self.frames[index];
+        stack.push(frame.name.as_str());
+        if frame.self_gas > 0 {
+            writeln!(output, "{} {}", stack.join(";"), frame.self_gas).unwrap();
+        }
+        for child in frame.children.iter() {
+            self.write_folded(*child, stack, output);
+        }
+        stack.pop();
+    }
+}
+
+#[derive(Debug, Default)]
+struct TraceState {
+    trace: GasTrace,
+    /// The frames of the calls in progress, the root frame is not in the stack
+    stack: Vec<usize>,
+    /// The last charge is a `charge_call`, the native function called next is its frame
+    after_call: bool,
+    /// The top frame is a native function, it returns without a `Ret` instruction
+    in_native: bool,
+}
+
+impl TraceState {
+    fn top(&self) -> usize {
+        self.stack.last().copied().unwrap_or(GasTrace::ROOT)
+    }
+
+    fn push(&mut self, name: &str) {
+        let frame = self.trace.child(self.top(), name);
+        self.stack.push(frame);
+    }
+
+    fn pop(&mut self) {
+        self.stack.pop();
+    }
+
+    /// Close the frame of the native function which aborted before its gas is charged
+    fn close_native(&mut self) {
+        if self.in_native {
+            self.in_native = false;
+            self.pop();
+        }
+    }
+
+    fn record(&mut self, frame: usize, gas: u64) {
+        self.trace.frames[frame].self_gas += gas;
+    }
+}
+
+/// A gas meter decorator recording the call tree of the frames with the gas costs of a transaction,
+/// the gas is charged by the inner meter, so the gas used is the same as the inner meter's.
+/// The frames are pushed by the calls and popped by the returns, the frames of an aborted call are not popped,
+/// so the gas charged after an abort is attributed to the aborted frame.
+#[derive(Debug, Clone)]
+pub struct TracingGasMeter<G> {
+    inner: G,
+    state: Rc<RefCell<TraceState>>,
+}
+
+impl<G: GasMeter> TracingGasMeter<G> {
+    pub fn new(inner: G) -> Self {
+        Self {
+            inner,
+            state: Rc::new(RefCell::new(TraceState::default())),
+        }
+    }
+
+    /// The trace recorded so far, the clones of the meter share the same trace
+    pub fn trace(&self) -> GasTrace {
+        self.state.borrow().trace.clone()
+    }
+
+    fn gas_left(&self) -> u64 {
+        self.inner.balance_internal().into()
+    }
+
+    /// Charge by the inner meter, and record the gas charged in the `frame`, or the top frame if it is `None`
+    fn charge_in<R>(
+        &mut self,
+        frame: Option<&str>,
+        charge: impl FnOnce(&mut G) -> PartialVMResult<R>,
+    ) -> PartialVMResult<R> {
+        let gas_left = self.gas_left();
+        let result = charge(&mut self.inner);
+        let gas = gas_left.saturating_sub(self.gas_left());
+        let mut state = self.state.borrow_mut();
+        let frame = match frame {
+            Some(name) => state.trace.child(GasTrace::ROOT, name),
+            None => state.top(),
+        };
+        state.record(frame, gas);
+        result
+    }
+
+    /// Charge an instruction of the current frame
+    fn charge_instr<R>(
+        &mut self,
+        charge: impl FnOnce(&mut G) -> PartialVMResult<R>,
+    ) -> PartialVMResult<R> {
+        {
+            let mut state = self.state.borrow_mut();
+            state.close_native();
+            state.after_call = false;
+        }
+        self.charge_in(None, charge)
+    }
+
+    fn enter_call(&mut self, module_id: &ModuleId, func_name: &str) {
+        let mut state = self.state.borrow_mut();
+        state.close_native();
+        state.push(&format!(
+            "{}::{}",
+            module_id.short_str_lossless(),
+            func_name
+        ));
+        state.after_call = true;
+    }
+}
+
+impl<G: GasMeter> GasMeter for TracingGasMeter<G> {
+    fn balance_internal(&self) -> InternalGas {
+        self.inner.balance_internal()
+    }
+
+    fn charge_simple_instr(&mut self, instr: SimpleInstruction) -> PartialVMResult<()> {
+        let result = self.charge_instr(|inner| inner.charge_simple_instr(instr));
+        if matches!(instr, SimpleInstruction::Ret) {
+            self.state.borrow_mut().pop();
+        }
+        result
+    }
+
+    fn charge_br_true(&mut self, target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_br_true(target_offset))
+    }
+
+    fn charge_br_false(&mut self, target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_br_false(target_offset))
+    }
+
+    fn charge_branch(&mut self, target_offset: CodeOffset) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_branch(target_offset))
+    }
+
+    fn charge_pop(&mut self, popped_val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_pop(popped_val))
+    }
+
+    fn charge_call(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+        num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        self.enter_call(module_id, func_name);
+        self.charge_in(None, |inner| {
+            inner.charge_call(module_id, func_name, args, num_locals)
+        })
+    }
+
+    fn charge_call_generic(
+        &mut self,
+        module_id: &ModuleId,
+        func_name: &str,
+        ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+        num_locals: NumArgs,
+    ) -> PartialVMResult<()> {
+        self.enter_call(module_id, func_name);
+        self.charge_in(None, |inner| {
+            inner.charge_call_generic(module_id, func_name, ty_args, args, num_locals)
+        })
+    }
+
+    fn charge_ld_const(&mut self, size: NumBytes) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_ld_const(size))
+    }
+
+    fn charge_ld_const_after_deserialization(
+        &mut self,
+        val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_ld_const_after_deserialization(val))
+    }
+
+    fn charge_copy_loc(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_copy_loc(val))
+    }
+
+    fn charge_move_loc(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_move_loc(val))
+    }
+
+    fn charge_store_loc(&mut self, val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_store_loc(val))
+    }
+
+    fn charge_pack(
+        &mut self,
+        is_generic: bool,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_pack(is_generic, args))
+    }
+
+    fn charge_unpack(
+        &mut self,
+        is_generic: bool,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_unpack(is_generic, args))
+    }
+
+    fn charge_read_ref(&mut self, ref_val: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_read_ref(ref_val))
+    }
+
+    fn charge_write_ref(
+        &mut self,
+        new_val: impl ValueView,
+        old_val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_write_ref(new_val, old_val))
+    }
+
+    fn charge_eq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_eq(lhs, rhs))
+    }
+
+    fn charge_neq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_neq(lhs, rhs))
+    }
+
+    fn charge_borrow_global(
+        &mut self,
+        is_mut: bool,
+        is_generic: bool,
+        ty: impl TypeView,
+        is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_borrow_global(is_mut, is_generic, ty, is_success))
+    }
+
+    fn charge_exists(
+        &mut self,
+        is_generic: bool,
+        ty: impl TypeView,
+        exists: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_exists(is_generic, ty, exists))
+    }
+
+    fn charge_move_from(
+        &mut self,
+        is_generic: bool,
+        ty: impl TypeView,
+        val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_move_from(is_generic, ty, val))
+    }
+
+    fn charge_move_to(
+        &mut self,
+        is_generic: bool,
+        ty: impl TypeView,
+        val: impl ValueView,
+        is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_move_to(is_generic, ty, val, is_success))
+    }
+
+    fn charge_vec_pack<'a>(
+        &mut self,
+        ty: impl TypeView + 'a,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_pack(ty, args))
+    }
+
+    fn charge_vec_len(&mut self, ty: impl TypeView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_len(ty))
+    }
+
+    fn charge_vec_borrow(
+        &mut self,
+        is_mut: bool,
+        ty: impl TypeView,
+        is_success: bool,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_borrow(is_mut, ty, is_success))
+    }
+
+    fn charge_vec_push_back(
+        &mut self,
+        ty: impl TypeView,
+        val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_push_back(ty, val))
+    }
+
+    fn charge_vec_pop_back(
+        &mut self,
+        ty: impl TypeView,
+        val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_pop_back(ty, val))
+    }
+
+    fn charge_vec_unpack(
+        &mut self,
+        ty: impl TypeView,
+        expect_num_elements: NumArgs,
+        elems: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_unpack(ty, expect_num_elements, elems))
+    }
+
+    fn charge_vec_swap(&mut self, ty: impl TypeView) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_vec_swap(ty))
+    }
+
+    fn charge_load_resource(
+        &mut self,
+        addr: AccountAddress,
+        ty: impl TypeView,
+        val: Option<impl ValueView>,
+        bytes_loaded: NumBytes,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_load_resource(addr, ty, val, bytes_loaded))
+    }
+
+    // The native function returns without a `Ret` instruction, its frame is popped after its gas is charged
+    fn charge_native_function(
+        &mut self,
+        amount: InternalGas,
+        ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView>>,
+    ) -> PartialVMResult<()> {
+        let result = self.charge_in(None, |inner| inner.charge_native_function(amount, ret_vals));
+        self.state.borrow_mut().close_native();
+        result
+    }
+
+    fn charge_native_function_before_execution(
+        &mut self,
+        ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        {
+            let mut state = self.state.borrow_mut();
+            if !state.after_call {
+                state.close_native();
+                state.push(NATIVE_FRAME);
+            }
+            state.after_call = false;
+            state.in_native = true;
+        }
+        self.charge_in(None, |inner| {
+            inner.charge_native_function_before_execution(ty_args, args)
+        })
+    }
+
+    fn charge_drop_frame(
+        &mut self,
+        locals: impl Iterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        self.charge_instr(|inner| inner.charge_drop_frame(locals))
+    }
+}
+
+impl<G: GasMeter + ClassifiedGasMeter> ClassifiedGasMeter for TracingGasMeter<G> {
+    fn feature_version(&self) -> u64 {
+        self.inner.feature_version()
+    }
+
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.charge_in(None, |inner| inner.charge_execution(gas_cost))
+    }
+
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        self.charge_in(Some(IO_FRAME), |inner| inner.charge_io_write(data_size))
+    }
+
+    fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()> {
+        self.charge_in(Some(EVENT_FRAME), |inner| inner.charge_event(events))
+    }
+
+    fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()> {
+        self.charge_in(Some(STORAGE_FRAME), |inner| {
+            inner.charge_change_set(change_set)
+        })
+    }
+
+    fn charge_module_publish(
+        &mut self,
+        modules: &[CompiledModule],
+        bytecode_size: u64,
+    ) -> PartialVMResult<()> {
+        self.charge_in(Some(PUBLISH_FRAME), |inner| {
+            inner.charge_module_publish(modules, bytecode_size)
+        })
+    }
+
+    // The refund does not change the gas left, it is recorded as the storage rebate of the trace
+    fn refund_storage_deposit(&mut self, deleted_bytes: u64) -> PartialVMResult<()> {
+        let rebate = self.inner.gas_statement().storage_rebate;
+        let result = self.inner.refund_storage_deposit(deleted_bytes);
+        let refunded = self
+            .inner
+            .gas_statement()
+            .storage_rebate
+            .saturating_sub(rebate);
+        self.state.borrow_mut().trace.storage_rebate += refunded;
+        result
+    }
+
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.inner.check_constrains(max_gas_amount)
+    }
+
+    fn gas_statement(&self) -> GasStatement {
+        self.inner.gas_statement()
+    }
+
+    fn gas_prices(&self) -> GasPrices {
+        self.inner.gas_prices()
+    }
+
+    fn instructions_executed(&self) -> u64 {
+        self.inner.instructions_executed()
+    }
+
+    // The gas left of the frame running out of gas is charged to it
+    fn charge_full_budget(&mut self, max_gas_amount: u64) {
+        let _ = self.charge_in(None, |inner| {
+            inner.charge_full_budget(max_gas_amount);
+            Ok(())
+        });
+    }
+
+    fn snapshot(&self) -> GasSnapshot {
+        self.inner.snapshot()
+    }
+
+    fn enter_function(&mut self, module_id: &ModuleId, func_name: &str) {
+        self.inner.enter_function(module_id, func_name);
+        self.enter_call(module_id, func_name);
+    }
+}
+
+impl<G: SwitchableGasMeter> SwitchableGasMeter for TracingGasMeter<G> {
+    fn stop_metering(&mut self) {
+        self.inner.stop_metering()
+    }
+
+    fn start_metering(&mut self) {
+        self.inner.start_metering()
+    }
+
+    fn is_metering(&self) -> bool {
+        self.inner.is_metering()
+    }
+}
+
+impl<G: TransactionGasMeter> TransactionGasMeter for TracingGasMeter<G> {
+    fn new_with_gas_budget(
+        cost_table: CostTable,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+    ) -> Self {
+        Self::new(G::new_with_gas_budget(
+            cost_table,
+            max_gas_amount,
+            gas_budget,
+        ))
+    }
+
+    fn set_deadline(&mut self, deadline: Instant) {
+        self.inner.set_deadline(deadline)
+    }
+
+    fn set_simulation(&mut self, simulation: bool) {
+        self.inner.set_simulation(simulation)
+    }
+
+    fn is_simulation(&self) -> bool {
+        self.inner.is_simulation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gas::table::{initial_cost_schedule, MoveOSGasMeter};
+    use move_core_types::identifier::Identifier;
+    use move_vm_types::values::Value;
+
+    #[test]
+    fn test_tracing_gas_meter() {
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter =
+            TracingGasMeter::new(MoveOSGasMeter::new(initial_cost_schedule(), max_gas_amount));
+        let module_id = ModuleId::new(AccountAddress::ONE, Identifier::new("m").unwrap());
+
+        gas_meter.charge_io_write(10).unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter
+            .charge_call(
+                &module_id,
+                "f",
+                std::iter::empty::<Value>(),
+                NumArgs::new(0),
+            )
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Add)
+            .unwrap();
+        gas_meter
+            .charge_call(
+                &module_id,
+                "g",
+                std::iter::empty::<Value>(),
+                NumArgs::new(0),
+            )
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Ret)
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Ret)
+            .unwrap();
+        // The return of the entry function does not pop the root
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Ret)
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+
+        let trace = gas_meter.trace();
+        let gas_used = max_gas_amount - u64::from(gas_meter.balance_internal());
+        assert_eq!(trace.total_gas(GasTrace::ROOT), gas_used);
+
+        let f = trace.find_child(GasTrace::ROOT, "0x1::m::f").unwrap();
+        let g = trace.find_child(f, "0x1::m::g").unwrap();
+        assert!(trace.frame(f).self_gas > 0);
+        assert!(trace.frame(g).self_gas > 0);
+        assert!(trace.find_child(GasTrace::ROOT, IO_FRAME).is_some());
+
+        let folded = trace.to_folded();
+        assert!(folded.contains(&format!(
+            "transaction;0x1::m::f;0x1::m::g {}\n",
+            trace.frame(g).self_gas
+        )));
+        assert!(folded.contains("transaction;[io] "));
+    }
+
+    #[test]
+    fn test_tracing_entry_function_and_refund() {
+        let max_gas_amount = 1_000_000;
+        let mut gas_meter =
+            TracingGasMeter::new(MoveOSGasMeter::new(initial_cost_schedule(), max_gas_amount));
+        let module_id = ModuleId::new(AccountAddress::ONE, Identifier::new("m").unwrap());
+
+        gas_meter.enter_function(&module_id, "entry");
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::Ret)
+            .unwrap();
+        // The instructions after the entry function returns are charged in the root
+        gas_meter
+            .charge_simple_instr(SimpleInstruction::LdU64)
+            .unwrap();
+        gas_meter.charge_io_write(1000).unwrap();
+        gas_meter.refund_storage_deposit(100).unwrap();
+
+        let trace = gas_meter.trace();
+        let entry = trace.find_child(GasTrace::ROOT, "0x1::m::entry").unwrap();
+        assert!(trace.frame(entry).self_gas > 0);
+        assert!(trace.root().self_gas > 0);
+
+        let statement = gas_meter.gas_statement();
+        assert!(statement.storage_rebate > 0);
+        assert_eq!(trace.storage_rebate(), statement.storage_rebate);
+        let gas_used = max_gas_amount - u64::from(gas_meter.balance_internal());
+        assert_eq!(trace.total_gas(GasTrace::ROOT), gas_used);
+        assert_eq!(trace.to_heatmap().storage_rebate, statement.storage_rebate);
+    }
+}
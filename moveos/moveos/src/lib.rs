@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod builder;
 pub mod gas;
 pub mod moveos;
 pub mod moveos_test_model_builder;
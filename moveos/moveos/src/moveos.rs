@@ -1,13 +1,20 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::gas::table::{initial_cost_schedule, ClassifiedGasMeter, MoveOSGasMeter};
+use crate::gas::budget::out_of_gas_dimension;
+use crate::gas::flat_rate::FlatRateGasMeter;
+use crate::gas::table::{
+    initial_cost_schedule, ClassifiedGasMeter, CostTable, MoveOSGasMeter,
+    EXECUTION_TIMEOUT_SUB_STATUS,
+};
+use crate::gas::tracing::{GasTrace, TracingGasMeter};
+use crate::gas::TransactionGasMeter;
 use crate::vm::moveos_vm::{MoveOSSession, MoveOSVM};
-use anyhow::{bail, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use backtrace::Backtrace;
 use itertools::Itertools;
-use move_binary_format::errors::VMError;
 use move_binary_format::errors::{vm_status_of_result, Location, PartialVMError, VMResult};
+use move_core_types::effects::ChangeSet;
 use move_core_types::identifier::IdentStr;
 use move_core_types::value::MoveValue;
 use move_core_types::vm_status::{KeptVMStatus, VMStatus};
@@ -23,21 +30,26 @@ use moveos_store::transaction_store::TransactionDBStore;
 use moveos_store::MoveOSStore;
 use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
+use moveos_types::gas_config::{GasConfig, GasMeterKind, ViewFunctionLimits};
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_types::FunctionId;
-use moveos_types::moveos_std::event::EventID;
+use moveos_types::moveos_std::event::{EventID, TransactionEvent};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
 use moveos_types::startup_info::StartupInfo;
-use moveos_types::state::{MoveState, MoveStructState, MoveStructType};
+use moveos_types::state::{MoveState, MoveStructState, MoveStructType, StateChangeSet};
 use moveos_types::state_resolver::MoveOSResolverProxy;
 use moveos_types::transaction::{
-    MoveOSTransaction, RawTransactionOutput, TransactionOutput, VerifiedMoveAction,
-    VerifiedMoveOSTransaction,
+    GasBudget, MoveAction, MoveOSTransaction, RawTransactionOutput, TransactionOutput,
+    TxDependencyHints, VerifiedMoveAction, VerifiedMoveOSTransaction,
+};
+use moveos_types::{
+    h256::{self, H256},
+    transaction::FunctionCall,
 };
-use moveos_types::{h256::H256, transaction::FunctionCall};
 use moveos_verifier::metadata::load_module_metadata;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct GasPaymentAccount {
@@ -95,11 +107,22 @@ impl Clone for MoveOSConfig {
     }
 }
 
+/// Set the env to `1` or `true` to enable the determinism check at startup.
+/// Every transaction is executed twice and the outputs must be identical, it detects the nondeterministic
+/// execution, such as iterating a hash based collection, the nondeterministic transaction fails with an error.
+/// It doubles the execution time, only for CI and testing.
+pub const DETERMINISM_CHECK_ENV: &str = "MOVEOS_DETERMINISM_CHECK";
+
 pub struct MoveOS {
     vm: MoveOSVM,
+    config: MoveOSConfig,
     db: MoveOSResolverProxy<MoveOSStore>,
     system_pre_execute_functions: Vec<FunctionCall>,
     system_post_execute_functions: Vec<FunctionCall>,
+    cost_table: CostTable,
+    determinism_check: bool,
+    /// The gas meter metering the transactions
+    gas_meter_kind: GasMeterKind,
 }
 
 impl MoveOS {
@@ -110,15 +133,72 @@ impl MoveOS {
         system_pre_execute_functions: Vec<FunctionCall>,
         system_post_execute_functions: Vec<FunctionCall>,
     ) -> Result<Self> {
-        let vm = MoveOSVM::new(natives, config.vm_config)?;
+        let mut vm = MoveOSVM::new(natives, config.clone().vm_config)?;
+        let cost_table = initial_cost_schedule();
+        vm.set_native_memory_quota(cost_table.native_memory_quota());
         Ok(Self {
             vm,
+            config,
             db: MoveOSResolverProxy(db),
             system_pre_execute_functions,
             system_post_execute_functions,
+            cost_table,
+            determinism_check: std::env::var(DETERMINISM_CHECK_ENV)
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            gas_meter_kind: GasMeterKind::default(),
         })
     }
 
+    /// Replace the system pre_execute and post_execute functions, they are called around every transaction.
+    pub fn set_system_functions(
+        &mut self,
+        system_pre_execute_functions: Vec<FunctionCall>,
+        system_post_execute_functions: Vec<FunctionCall>,
+    ) {
+        self.system_pre_execute_functions = system_pre_execute_functions;
+        self.system_post_execute_functions = system_post_execute_functions;
+    }
+
+    pub fn cost_table(&self) -> &CostTable {
+        &self.cost_table
+    }
+
+    /// Replace the cost table used to meter the transactions, the default is the `initial_cost_schedule`.
+    /// The natives are limited by the memory quota of the cost table.
+    pub fn set_cost_table(&mut self, cost_table: CostTable) {
+        self.vm
+            .set_native_memory_quota(cost_table.native_memory_quota());
+        self.cost_table = cost_table;
+    }
+
+    /// Rebuild the VM with the natives, such as the natives metered by a new gas schedule.
+    /// The loaded modules are cached by the VM, so they are loaded again by the new VM.
+    pub fn set_natives(
+        &mut self,
+        natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
+    ) -> Result<()> {
+        self.vm = MoveOSVM::new(natives, self.config.clone().vm_config)?;
+        self.vm
+            .set_native_memory_quota(self.cost_table.native_memory_quota());
+        Ok(())
+    }
+
+    /// Enable or disable the determinism check, see `DETERMINISM_CHECK_ENV`.
+    pub fn set_determinism_check(&mut self, enabled: bool) {
+        self.determinism_check = enabled;
+    }
+
+    pub fn gas_meter_kind(&self) -> GasMeterKind {
+        self.gas_meter_kind
+    }
+
+    /// Replace the gas meter of the transactions, the view functions are always metered by the `MoveOSGasMeter`.
+    /// The gas used depends on the meter, so all the nodes replaying the transactions should use the same meter.
+    pub fn set_gas_meter_kind(&mut self, gas_meter_kind: GasMeterKind) {
+        self.gas_meter_kind = gas_meter_kind;
+    }
+
     pub fn init_genesis<
         T: Into<MoveOSTransaction>,
         GT: MoveState + Clone,
@@ -145,21 +225,48 @@ impl MoveOS {
             .collect::<Result<Vec<_>>>()
     }
 
+    /// Initialize the genesis by publishing the module `bundles` in order, such as the `Stdlib::module_bundles`.
+    /// It is the genesis of the MoveOS embedded without the Rooch framework, no genesis context is added.
+    pub fn init_genesis_modules(
+        &mut self,
+        bundles: Vec<(AccountAddress, Vec<Vec<u8>>)>,
+    ) -> Result<Vec<(H256, TransactionOutput)>> {
+        ensure!(
+            self.db.0.get_state_store().is_genesis(),
+            "genesis already initialized"
+        );
+        bundles
+            .into_iter()
+            .map(|(genesis_account, bundle)| {
+                let tx = self.new_transaction(genesis_account, MoveAction::ModuleBundle(bundle));
+                self.execute_genesis_tx(tx)
+            })
+            .collect::<Result<Vec<_>>>()
+    }
+
     fn verify_and_execute_genesis_tx<GT: MoveState, BGT: MoveState>(
         &mut self,
-        tx: MoveOSTransaction,
+        mut tx: MoveOSTransaction,
         genesis_ctx: GT,
         bitcoin_genesis_ctx: BGT,
     ) -> Result<(H256, TransactionOutput)> {
+        tx.ctx.add(genesis_ctx)?;
+        tx.ctx.add(bitcoin_genesis_ctx)?;
+        self.execute_genesis_tx(tx)
+    }
+
+    fn execute_genesis_tx(&mut self, tx: MoveOSTransaction) -> Result<(H256, TransactionOutput)> {
         let MoveOSTransaction {
-            mut ctx,
+            ctx,
             action,
             pre_execute_functions: _,
             post_execute_functions: _,
+            dependency_hints: _,
+            gas_budget: _,
         } = tx;
-        ctx.add(genesis_ctx)?;
-        ctx.add(bitcoin_genesis_ctx)?;
-        let mut session = self.vm.new_genesis_session(&self.db, ctx);
+        let mut session = self
+            .vm
+            .new_genesis_session(&self.db, ctx, self.cost_table.clone());
         let verified_action = session.verify_move_action(action)?;
 
         // execute main tx
@@ -206,9 +313,11 @@ impl MoveOS {
             action,
             pre_execute_functions,
             post_execute_functions,
+            dependency_hints,
+            gas_budget,
         } = tx;
 
-        let cost_table = initial_cost_schedule();
+        let cost_table = self.cost_table.clone();
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
         let session = self
@@ -222,15 +331,161 @@ impl MoveOS {
             action: verified_action,
             pre_execute_functions,
             post_execute_functions,
+            dependency_hints,
+            gas_budget,
         })
     }
 
     pub fn execute(&self, tx: VerifiedMoveOSTransaction) -> Result<RawTransactionOutput> {
+        if !self.determinism_check {
+            return self.execute_once(tx);
+        }
+        let tx_hash = tx.ctx.tx_hash();
+        let output = self.execute_once(tx.clone())?;
+        // The module upgrade invalidates the loader cache, the transaction can not be executed again.
+        if output.is_upgrade {
+            return Ok(output);
+        }
+        let replayed_output = self.execute_once(tx)?;
+        check_deterministic(tx_hash, &output, &replayed_output)?;
+        Ok(output)
+    }
+
+    /// The committed execution is limited by the gas only, it never depends on the wall-clock,
+    /// so every node executing the sequenced transaction gets the same output.
+    fn execute_once(&self, tx: VerifiedMoveOSTransaction) -> Result<RawTransactionOutput> {
+        self.execute_metered(tx, None, false)
+    }
+
+    /// Simulate the transaction on the latest state, the output is returned without being applied.
+    /// The transaction is not validated, such as an unsigned transaction for the gas estimation,
+    /// and it is aborted as out of gas when it exceeds the wall-clock `timeout`.
+    pub fn simulate(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        timeout: Duration,
+    ) -> Result<RawTransactionOutput> {
+        self.execute_metered(tx, Some(Instant::now() + timeout), true)
+    }
+
+    /// Execute the transaction with the gas meter of the `gas_meter_kind`
+    fn execute_metered(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        deadline: Option<Instant>,
+        simulation: bool,
+    ) -> Result<RawTransactionOutput> {
+        let (max_gas_amount, gas_budget) = Self::gas_budget_of(&tx);
+        match self.gas_meter_kind {
+            GasMeterKind::Standard => self.execute_with_gas_meter(
+                tx,
+                self.new_gas_meter::<MoveOSGasMeter>(
+                    max_gas_amount,
+                    gas_budget,
+                    deadline,
+                    simulation,
+                ),
+            ),
+            GasMeterKind::FlatRate => self.execute_with_gas_meter(
+                tx,
+                self.new_gas_meter::<FlatRateGasMeter>(
+                    max_gas_amount,
+                    gas_budget,
+                    deadline,
+                    simulation,
+                ),
+            ),
+        }
+    }
+
+    /// Execute the transaction with the gas meter traced, and return the call tree of the gas costs with the output.
+    /// The trace is for the profiling, the output is the same as the output of `execute`.
+    pub fn execute_with_gas_trace(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(RawTransactionOutput, GasTrace)> {
+        let (max_gas_amount, gas_budget) = Self::gas_budget_of(&tx);
+        match self.gas_meter_kind {
+            GasMeterKind::Standard => self.execute_traced(
+                tx,
+                self.new_gas_meter::<TracingGasMeter<MoveOSGasMeter>>(
+                    max_gas_amount,
+                    gas_budget,
+                    None,
+                    false,
+                ),
+            ),
+            GasMeterKind::FlatRate => self.execute_traced(
+                tx,
+                self.new_gas_meter::<TracingGasMeter<FlatRateGasMeter>>(
+                    max_gas_amount,
+                    gas_budget,
+                    None,
+                    false,
+                ),
+            ),
+        }
+    }
+
+    fn execute_traced<G: TransactionGasMeter>(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        gas_meter: TracingGasMeter<G>,
+    ) -> Result<(RawTransactionOutput, GasTrace)> {
+        let tracer = gas_meter.clone();
+        let output = self.execute_with_gas_meter(tx, gas_meter)?;
+        Ok((output, tracer.trace()))
+    }
+
+    /// Execute the transaction with the gas meter traced and apply the output, see `execute_with_gas_trace`.
+    pub fn execute_and_apply_with_gas_trace(
+        &mut self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(H256, TransactionOutput, GasTrace)> {
+        let (raw_output, gas_trace) = self.execute_with_gas_trace(tx)?;
+        let (state_root, event_ids) = self.apply_transaction_output(raw_output.clone())?;
+        let output = TransactionOutput::new(raw_output, event_ids);
+        Ok((state_root, output, gas_trace))
+    }
+
+    /// The max gas amount of the transaction and its compute and storage budgets,
+    /// both dimensions share the max gas amount if the transaction does not set the budgets.
+    fn gas_budget_of(tx: &VerifiedMoveOSTransaction) -> (u64, GasBudget) {
+        let max_gas_amount = tx.ctx.max_gas_amount;
+        let gas_budget = tx
+            .gas_budget
+            .unwrap_or_else(|| GasBudget::shared(max_gas_amount));
+        (max_gas_amount, gas_budget)
+    }
+
+    fn new_gas_meter<G: TransactionGasMeter>(
+        &self,
+        max_gas_amount: u64,
+        gas_budget: GasBudget,
+        deadline: Option<Instant>,
+        simulation: bool,
+    ) -> G {
+        let mut gas_meter =
+            G::new_with_gas_budget(self.cost_table.clone(), max_gas_amount, gas_budget);
+        if let Some(deadline) = deadline {
+            gas_meter.set_deadline(deadline);
+        }
+        gas_meter.set_simulation(simulation);
+        gas_meter
+    }
+
+    fn execute_with_gas_meter<G: TransactionGasMeter>(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        mut gas_meter: G,
+    ) -> Result<RawTransactionOutput> {
         let VerifiedMoveOSTransaction {
             ctx,
             action,
             pre_execute_functions,
             post_execute_functions,
+            dependency_hints,
+            gas_budget: _,
         } = tx;
         let tx_hash = ctx.tx_hash();
         if log::log_enabled!(log::Level::Debug) {
@@ -247,24 +502,24 @@ impl MoveOS {
         // So we keep a backup here, and then insert to the TxContext kv store when session respawed.
         let system_env = ctx.map.clone();
 
-        let cost_table = initial_cost_schedule();
-        let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
-
         gas_meter.charge_io_write(ctx.tx_size)?;
 
         let mut session = self.vm.new_session(&self.db, ctx, gas_meter);
 
         // system pre_execute
         // we do not charge gas for system_pre_execute function
-        session
-            .execute_function_call(self.system_pre_execute_functions.clone(), false)
-            .expect("system_pre_execute should not fail.");
+        Self::execute_system_functions(
+            &mut session,
+            self.system_pre_execute_functions.clone(),
+            "system_pre_execute",
+        )?;
 
         match self.execute_user_action(
             &mut session,
             action.clone(),
             pre_execute_functions.clone(),
             post_execute_functions.clone(),
+            dependency_hints.as_ref(),
         ) {
             Ok(status) => {
                 if log::log_enabled!(log::Level::Debug) {
@@ -276,40 +531,74 @@ impl MoveOS {
                 }
                 self.execution_cleanup(session, status, Some(action))
             }
-            Err((vm_err, need_respawn)) => {
+            Err(vm_err) => {
                 if log::log_enabled!(log::Level::Warn) {
                     log::warn!(
-                        "execute_user_action error tx(hash:{}) vm_err:{:?} need_respawn:{}",
+                        "execute_user_action error tx(hash:{}) vm_err:{:?}",
                         tx_hash,
-                        vm_err,
-                        need_respawn
+                        vm_err
                     );
                 }
-                if need_respawn {
-                    let mut s = session.respawn(system_env);
-                    //Because the session is respawned, the pre_execute function should be called again.
-                    s.execute_function_call(self.system_pre_execute_functions.clone(), false)
-                        .expect("system_pre_execute should not fail.");
-                    let _ = self.execute_pre_and_post(
-                        &mut s,
-                        pre_execute_functions,
-                        post_execute_functions,
-                    );
-                    // when respawn session, VM error occurs in user move action or post execution.
-                    // We just cleanup with the VM error return by `execute_user_action`, ignore
-                    // the result of `execute_pre_and_post`
-                    // TODO: do we need to handle the result of `execute_pre_and_post` after respawn?
-                    self.execution_cleanup(s, vm_err.into_vm_status(), None)
+                // If the transaction runs out of gas, the full budget is charged,
+                // and the instruction count reached is recorded in the output.
+                let out_of_gas = vm_err.major_status() == StatusCode::OUT_OF_GAS;
+                let execution_timed_out =
+                    out_of_gas && vm_err.sub_status() == Some(EXECUTION_TIMEOUT_SUB_STATUS);
+                let out_of_gas_dimension = if out_of_gas {
+                    out_of_gas_dimension(vm_err.sub_status())
                 } else {
-                    self.execution_cleanup(session, vm_err.into_vm_status(), None)
+                    None
+                };
+                let out_of_gas_instructions = if out_of_gas {
+                    session.charge_full_budget();
+                    Some(session.instructions_executed())
+                } else {
+                    None
+                };
+                // Discard all the changes of the failed transaction by respawning the session,
+                // the gas meter is kept, so the gas used is still charged in the system post_execute.
+                let mut s = session.respawn(system_env.clone());
+                //Because the session is respawned, the pre_execute function should be called again.
+                Self::execute_system_functions(
+                    &mut s,
+                    self.system_pre_execute_functions.clone(),
+                    "system_pre_execute",
+                )?;
+                // We just cleanup with the VM error return by `execute_user_action`,
+                // if the user pre_execute or post_execute fails again, their changes are discarded too.
+                // There is no gas left to execute them if the transaction runs out of gas.
+                let pre_and_post_result = if out_of_gas {
+                    Ok(())
+                } else {
+                    self.execute_pre_and_post(&mut s, pre_execute_functions, post_execute_functions)
+                };
+                if let Err(e) = pre_and_post_result {
+                    if log::log_enabled!(log::Level::Warn) {
+                        log::warn!(
+                            "execute_pre_and_post error after respawn tx(hash:{}) vm_err:{:?}",
+                            tx_hash,
+                            e
+                        );
+                    }
+                    s = s.respawn(system_env);
+                    Self::execute_system_functions(
+                        &mut s,
+                        self.system_pre_execute_functions.clone(),
+                        "system_pre_execute",
+                    )?;
                 }
+                let mut output = self.execution_cleanup(s, vm_err.into_vm_status(), None)?;
+                output.out_of_gas_instructions = out_of_gas_instructions;
+                output.execution_timed_out = execution_timed_out;
+                output.out_of_gas_dimension = out_of_gas_dimension;
+                Ok(output)
             }
         }
     }
 
-    fn execute_gas_charge_post(
+    fn execute_gas_charge_post<G: TransactionGasMeter>(
         &self,
-        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         action: &VerifiedMoveAction,
     ) -> VMResult<Option<bool>> {
         match action {
@@ -404,6 +693,54 @@ impl MoveOS {
         Ok((state_root, output))
     }
 
+    /// Publish the `modules` as the `sender` and apply the output, see `execute_action`.
+    pub fn publish_modules(
+        &mut self,
+        sender: AccountAddress,
+        modules: Vec<Vec<u8>>,
+    ) -> Result<TransactionOutput> {
+        self.execute_action(sender, MoveAction::new_module_bundle(modules))
+    }
+
+    /// Call the entry `function_call` as the `sender` and apply the output, see `execute_action`.
+    pub fn execute_function(
+        &mut self,
+        sender: AccountAddress,
+        function_call: FunctionCall,
+    ) -> Result<TransactionOutput> {
+        self.execute_action(sender, MoveAction::Function(function_call))
+    }
+
+    /// Execute the `action` as the `sender` and apply the output, without a signed transaction.
+    /// It is for the embedded MoveOS, such as the simulation tools, the aborted action is returned
+    /// as the status of the output rather than an error.
+    pub fn execute_action(
+        &mut self,
+        sender: AccountAddress,
+        action: MoveAction,
+    ) -> Result<TransactionOutput> {
+        let tx = self.new_transaction(sender, action);
+        let verified_tx = self.verify(tx)?;
+        let (_state_root, output) = self.execute_and_apply(verified_tx)?;
+        Ok(output)
+    }
+
+    /// The transaction of the `action` without a signed transaction, the tx hash is derived from the
+    /// current state root, so the objects created by the same action executed twice have different ids.
+    fn new_transaction(&self, sender: AccountAddress, action: MoveAction) -> MoveOSTransaction {
+        let state_root = self.db.0.get_state_store().state_root();
+        let tx_bytes = bcs::to_bytes(&(state_root, sender, &action))
+            .expect("serialize the transaction should success");
+        let ctx = TxContext::new(
+            sender,
+            0,
+            GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+            h256::sha3_256_of(tx_bytes.as_slice()),
+            tx_bytes.len() as u64,
+        );
+        MoveOSTransaction::new(ctx, action)
+    }
+
     fn apply_transaction_output(
         &mut self,
         output: RawTransactionOutput,
@@ -417,6 +754,9 @@ impl MoveOS {
             gas_used: _,
             is_upgrade: _,
             gas_statement: _,
+            out_of_gas_instructions: _,
+            execution_timed_out: _,
+            out_of_gas_dimension: _,
         } = output;
         let new_state_root = self
             .db
@@ -450,6 +790,33 @@ impl MoveOS {
         Ok((new_state_root, event_ids))
     }
 
+    /// Apply the changes replicated from another node without executing the transaction.
+    /// The changes are rolled back if the new state root mismatches the `expected_state_root`.
+    pub fn apply_replicated_changes(
+        &mut self,
+        changeset: ChangeSet,
+        state_changeset: StateChangeSet,
+        events: Vec<TransactionEvent>,
+        expected_state_root: H256,
+    ) -> Result<Vec<EventID>> {
+        let prev_state_root = self.state().state_root();
+        let new_state_root = self.state().apply_change_set(changeset, state_changeset)?;
+        if new_state_root != expected_state_root {
+            self.state().update_state_root(prev_state_root)?;
+            bail!(
+                "The state root {:?} of the replicated changes mismatches the expected state root {:?}",
+                new_state_root,
+                expected_state_root
+            );
+        }
+        let event_ids = self.db.0.get_event_store().save_events(events)?;
+        self.db
+            .0
+            .get_config_store()
+            .save_startup_info(StartupInfo::new(new_state_root))?;
+        Ok(event_ids)
+    }
+
     /// Execute readonly view function
     pub fn execute_view_function(&self, function_call: FunctionCall) -> FunctionResult {
         //TODO allow user to specify the sender
@@ -458,15 +825,37 @@ impl MoveOS {
         self.execute_readonly_function(&tx_context, function_call)
     }
 
+    /// Execute the view function with the gas budget and the wall-clock timeout of the `limits`,
+    /// the execution fails with OUT_OF_GAS if any limit is exceeded.
+    pub fn execute_view_function_with_limits(
+        &self,
+        function_call: FunctionCall,
+        limits: ViewFunctionLimits,
+    ) -> FunctionResult {
+        let tx_context = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let cost_table = self.cost_table.clone();
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, limits.max_gas_amount);
+        gas_meter.set_deadline(Instant::now() + Duration::from_millis(limits.timeout_milliseconds));
+        self.execute_readonly_function_with_gas_meter(&tx_context, function_call, gas_meter)
+    }
+
     pub fn execute_readonly_function(
         &self,
         tx_context: &TxContext,
         function_call: FunctionCall,
     ) -> FunctionResult {
-        //TODO limit the view function max gas usage
-        let cost_table = initial_cost_schedule();
+        let cost_table = self.cost_table.clone();
         let mut gas_meter = MoveOSGasMeter::new(cost_table, tx_context.max_gas_amount);
         gas_meter.set_metering(false);
+        self.execute_readonly_function_with_gas_meter(tx_context, function_call, gas_meter)
+    }
+
+    fn execute_readonly_function_with_gas_meter(
+        &self,
+        tx_context: &TxContext,
+        function_call: FunctionCall,
+        gas_meter: MoveOSGasMeter,
+    ) -> FunctionResult {
         let mut session = self
             .vm
             .new_readonly_session(&self.db, tx_context.clone(), gas_meter);
@@ -486,35 +875,50 @@ impl MoveOS {
 
     // Execute use action with pre_execute and post_execute.
     // Return the user action execution status if success,
-    // else return VMError and a bool which indicate if we should respawn the session.
-    fn execute_user_action(
+    // else return the VMError, the caller should respawn the session to discard the changes.
+    fn execute_user_action<G: TransactionGasMeter>(
         &self,
-        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         action: VerifiedMoveAction,
         pre_execute_functions: Vec<FunctionCall>,
         post_execute_functions: Vec<FunctionCall>,
-    ) -> Result<VMStatus, (VMError, bool)> {
+        dependency_hints: Option<&TxDependencyHints>,
+    ) -> VMResult<VMStatus> {
         // user pre_execute
-        // If the pre_execute failed, we finish the session directly and return the TransactionOutput.
-        session
-            .execute_function_call(pre_execute_functions, true)
-            .map_err(|e| (e, false))?;
+        let pre_execute_snapshot = session.gas_snapshot();
+        session.execute_function_call(pre_execute_functions, true)?;
+        let pre_execute_gas = session.gas_delta_since(&pre_execute_snapshot);
 
         // execute main tx
-        let execute_result = session.execute_move_action(action);
+        let action_snapshot = session.gas_snapshot();
+        let execute_result = session.execute_move_action(action).and_then(|_| {
+            dependency_hints.map_or(Ok(()), |hints| session.check_dependency_hints(hints))
+        });
+        let action_gas = session.gas_delta_since(&action_snapshot);
         let vm_status = vm_status_of_result(execute_result.clone());
 
-        // If the user action or post_execute failed, we need respawn the session,
+        // If the user pre_execute, action or post_execute failed, the caller respawns the session to discard the changes,
         // and execute system_pre_execute, system_post_execute and user pre_execute, user post_execute.
         let status = match vm_status.clone().keep_or_discard() {
             Ok(status) => {
                 if status != KeptVMStatus::Executed {
                     debug_assert!(execute_result.is_err());
-                    return Err((execute_result.unwrap_err(), true));
+                    return Err(execute_result.unwrap_err());
                 }
-                session
-                    .execute_function_call(post_execute_functions, true)
-                    .map_err(|e| (e, true))?;
+                let post_execute_snapshot = session.gas_snapshot();
+                session.execute_function_call(post_execute_functions, true)?;
+                if log::log_enabled!(log::Level::Debug) {
+                    log::debug!(
+                        "gas attribution(pre_execute:{:?}, action:{:?}, post_execute:{:?})",
+                        pre_execute_gas,
+                        action_gas,
+                        session.gas_delta_since(&post_execute_snapshot)
+                    );
+                }
+                session.check_objects()?;
+                // The storage is charged before the gas used is paid, a transaction which can not pay for it
+                // runs out of gas, and its changes are discarded.
+                session.charge_storage()?;
                 vm_status
             }
             Err(discard_status) => {
@@ -530,9 +934,9 @@ impl MoveOS {
     }
 
     // Execute pre_execute and post_execute only.
-    fn execute_pre_and_post(
+    fn execute_pre_and_post<G: TransactionGasMeter>(
         &self,
-        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         pre_execute_functions: Vec<FunctionCall>,
         post_execute_functions: Vec<FunctionCall>,
     ) -> VMResult<()> {
@@ -541,9 +945,32 @@ impl MoveOS {
         Ok(())
     }
 
-    fn execution_cleanup(
+    /// Execute the system functions without charging gas, they never fail for a validated transaction.
+    /// A simulated transaction is not validated, such as the sender can not pay the gas,
+    /// so the failure is returned as an error.
+    fn execute_system_functions<G: TransactionGasMeter>(
+        session: &mut MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
+        functions: Vec<FunctionCall>,
+        name: &str,
+    ) -> Result<()> {
+        let result = session.execute_function_call(functions, false);
+        if session.gas_meter.is_simulation() {
+            result.map_err(|e| {
+                anyhow!(
+                    "{} failed in the simulated transaction: {:?}",
+                    name,
+                    e.into_vm_status()
+                )
+            })
+        } else {
+            result.unwrap_or_else(|e| panic!("{} should not fail: {:?}", name, e));
+            Ok(())
+        }
+    }
+
+    fn execution_cleanup<G: TransactionGasMeter>(
         &self,
-        mut session: MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, MoveOSGasMeter>,
+        mut session: MoveOSSession<'_, '_, MoveOSResolverProxy<MoveOSStore>, G>,
         status: VMStatus,
         action_opt: Option<VerifiedMoveAction>,
     ) -> Result<RawTransactionOutput> {
@@ -566,7 +993,7 @@ impl MoveOS {
             pay_gas = gas_payment_account.pay_gas_by_module_account;
         }
 
-        // update txn result to TxContext
+        // update txn result to TxContext, the gas used includes the storage gas charged by the user action
         let gas_used = session.query_gas_used();
         //TODO is it a good approach to add tx_result to TxContext?
         let tx_result = TxResult::new(&kept_status, gas_used);
@@ -578,12 +1005,21 @@ impl MoveOS {
 
         // system post_execute
         // we do not charge gas for system_post_execute function
-        session
-            .execute_function_call(self.system_post_execute_functions.clone(), false)
-            .expect("system_post_execute should not fail.");
+        Self::execute_system_functions(
+            &mut session,
+            self.system_post_execute_functions.clone(),
+            "system_post_execute",
+        )?;
+
+        // The action is None if the transaction failed, the gas_charge_post function is only called for the executed action.
+        if let (true, Some(action)) = (pay_gas, action_opt.as_ref()) {
+            self.execute_gas_charge_post(&mut session, action)?;
+        }
 
-        if pay_gas {
-            self.execute_gas_charge_post(&mut session, &action_opt.unwrap())?;
+        if kept_status != KeptVMStatus::Executed {
+            // The changes of the failed transaction are discarded, the remaining changes are made by the system functions,
+            // they are not charged, and a transaction which runs out of gas has no gas left to pay for them.
+            session.stop_metering();
         }
 
         let (_ctx, output) = session.finish_with_extensions(kept_status)?;
@@ -610,3 +1046,45 @@ impl MoveFunctionCaller for MoveOS {
         Ok(result)
     }
 }
+
+/// Return an error if the two outputs of the same transaction are different, the execution is nondeterministic.
+fn check_deterministic(
+    tx_hash: H256,
+    output: &RawTransactionOutput,
+    replayed: &RawTransactionOutput,
+) -> Result<()> {
+    let mut mismatches = vec![];
+    if output.status != replayed.status {
+        mismatches.push("status");
+    }
+    if output.gas_used != replayed.gas_used {
+        mismatches.push("gas_used");
+    }
+    if output.gas_statement != replayed.gas_statement {
+        mismatches.push("gas_statement");
+    }
+    if output.changeset != replayed.changeset {
+        mismatches.push("changeset");
+    }
+    if output.state_changeset != replayed.state_changeset {
+        mismatches.push("state_changeset");
+    }
+    if output.events != replayed.events {
+        mismatches.push("events");
+    }
+    if output.out_of_gas_instructions != replayed.out_of_gas_instructions {
+        mismatches.push("out_of_gas_instructions");
+    }
+    if !mismatches.is_empty() {
+        log::error!(
+            "Nondeterministic execution of tx(hash:{}), mismatched: {:?}\noutput: {:?}\nreplayed output: {:?}",
+            tx_hash, mismatches, output, replayed
+        );
+        bail!(
+            "Nondeterministic execution of tx(hash:{}), mismatched: {:?}",
+            tx_hash,
+            mismatches
+        );
+    }
+    Ok(())
+}
@@ -322,6 +322,43 @@ pub fn into_change_set(table_data: Arc<RwLock<TableData>>) -> PartialVMResult<St
     })
 }
 
+/// The table changes made so far in the session, without finishing the session.
+/// A value is compared with its state in the `remote`, so a value written back unchanged is not a change,
+/// the new and removed tables and the size increments are not collected.
+pub fn peek_change_set<S: MoveOSResolver>(
+    remote: &S,
+    table_data: &TableData,
+) -> PartialVMResult<StateChangeSet> {
+    let mut change_set = StateChangeSet::default();
+    for table in table_data.tables() {
+        let handle = *table.handle();
+        for (key, runtime_value) in table.content() {
+            let previous = remote.resolve_table_item(&handle, key).map_err(|e| {
+                PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(e.to_string())
+            })?;
+            let op = match (
+                previous,
+                runtime_value.serialize_value()?,
+                runtime_value.value_type(),
+            ) {
+                (None, Some(bytes), Some(value_type)) => {
+                    Op::New(State::new(bytes, value_type.clone()))
+                }
+                (Some(previous), Some(bytes), Some(value_type)) if previous.value != bytes => {
+                    Op::Modify(State::new(bytes, value_type.clone()))
+                }
+                (Some(_), None, _) => Op::Delete,
+                _ => continue,
+            };
+            change_set
+                .get_or_insert_table_change(handle, table.key_type().clone())
+                .entries
+                .insert(key.clone(), op);
+        }
+    }
+    Ok(change_set)
+}
+
 // Unbox a value of `moveos_std::raw_table::Box<V>` to V and serialize it.
 fn unbox_and_serialize(layout: &MoveTypeLayout, box_val: Value) -> PartialVMResult<Vec<u8>> {
     let mut fields = box_val.value_as::<Struct>()?.unpack()?;
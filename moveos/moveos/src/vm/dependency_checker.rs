@@ -0,0 +1,146 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Check the objects touched by a transaction against its dependency hints.
+//! A transaction touching an object it did not declare aborts with `ErrorUndeclaredObjectAccess`
+//! at `moveos_std::object`. The hints are only checked, they do not change how the transactions are
+//! sequenced or executed.
+//!
+//! An object touched by the transaction is declared if:
+//!   1. It is created in the transaction, or it is frozen, nobody else can modify it.
+//!   2. Its id is in the declared objects.
+//!   3. It is not shared, and its owner is a declared account, the sender or a system account.
+//! The shared objects are only declared by their ids.
+
+use super::object_checker::{
+    parse_owner_and_flag, storage_error, FROZEN_OBJECT_FLAG_MASK, SHARED_OBJECT_FLAG_MASK,
+};
+use move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMError, VMResult};
+use move_core_types::{
+    account_address::AccountAddress, language_storage::ModuleId, vm_status::StatusCode,
+};
+use moveos_stdlib::natives::moveos_stdlib::raw_table::TableData;
+use moveos_types::{
+    addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS},
+    moveos_std::object::{self, ObjectID},
+    state_resolver::{StateResolver, GLOBAL_OBJECT_STORAGE_HANDLE},
+    transaction::TxDependencyHints,
+};
+use parking_lot::RwLock;
+use std::collections::BTreeSet;
+
+/// Ensure the error code is consistent with the error code in object.move
+pub const ERROR_UNDECLARED_OBJECT_ACCESS: u64 = 6;
+
+/// The owners of the system objects, the system objects are touched by every transaction, so they are always declared
+const SYSTEM_ACCOUNTS: [AccountAddress; 3] =
+    [AccountAddress::ZERO, MOVE_STD_ADDRESS, MOVEOS_STD_ADDRESS];
+
+/// Check the objects touched in the session are declared by the `hints` of the `sender`.
+pub fn check_dependency_hints<S>(
+    remote: &S,
+    table_data: &RwLock<TableData>,
+    sender: AccountAddress,
+    hints: &TxDependencyHints,
+) -> VMResult<()>
+where
+    S: StateResolver + ?Sized,
+{
+    let declared_objects: BTreeSet<ObjectID> = hints.objects.iter().copied().collect();
+    let declared_accounts: BTreeSet<AccountAddress> = hints
+        .accounts
+        .iter()
+        .copied()
+        .chain(SYSTEM_ACCOUNTS)
+        .chain(std::iter::once(sender))
+        .collect();
+    for object_id in
+        touched_objects(&table_data.read()).map_err(|e| e.finish(Location::Undefined))?
+    {
+        if declared_objects.contains(&object_id) {
+            continue;
+        }
+        let Some(state) = remote
+            .resolve_table_item(&GLOBAL_OBJECT_STORAGE_HANDLE, &object_id.to_bytes())
+            .map_err(|e| storage_error(e).finish(Location::Undefined))?
+        else {
+            // The object is created in the transaction
+            continue;
+        };
+        let (owner, flag) =
+            parse_owner_and_flag(&state.value).map_err(|e| e.finish(Location::Undefined))?;
+        if !is_declared(owner, flag, &declared_accounts) {
+            return Err(undeclared_error(format!(
+                "Object {} owned by {} is touched but not declared in the dependency hints",
+                object_id, owner
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The objects loaded in the session, and the objects whose fields are accessed
+fn touched_objects(table_data: &TableData) -> PartialVMResult<BTreeSet<ObjectID>> {
+    let mut objects = BTreeSet::new();
+    for table in table_data.tables() {
+        let handle = *table.handle();
+        if handle == GLOBAL_OBJECT_STORAGE_HANDLE {
+            for (key, _) in table.content() {
+                objects.insert(ObjectID::from_bytes(key).map_err(storage_error)?);
+            }
+        } else {
+            objects.insert(handle);
+        }
+    }
+    Ok(objects)
+}
+
+/// Whether the object not declared by its id is declared by its owner
+fn is_declared(
+    owner: AccountAddress,
+    flag: u8,
+    declared_accounts: &BTreeSet<AccountAddress>,
+) -> bool {
+    if flag & FROZEN_OBJECT_FLAG_MASK != 0 {
+        return true;
+    }
+    flag & SHARED_OBJECT_FLAG_MASK == 0 && declared_accounts.contains(&owner)
+}
+
+fn undeclared_error(message: String) -> VMError {
+    PartialVMError::new(StatusCode::ABORTED)
+        .with_sub_status(ERROR_UNDECLARED_OBJECT_ACCESS)
+        .with_message(message)
+        .finish(Location::Module(ModuleId::new(
+            MOVEOS_STD_ADDRESS,
+            object::MODULE_NAME.to_owned(),
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_declared() {
+        let sender = AccountAddress::random();
+        let other = AccountAddress::random();
+        let declared_accounts: BTreeSet<AccountAddress> =
+            SYSTEM_ACCOUNTS.into_iter().chain([sender]).collect();
+
+        assert!(is_declared(sender, 0, &declared_accounts));
+        assert!(is_declared(AccountAddress::ZERO, 0, &declared_accounts));
+        assert!(!is_declared(other, 0, &declared_accounts));
+        // The shared objects are only declared by their ids
+        assert!(!is_declared(
+            AccountAddress::ZERO,
+            SHARED_OBJECT_FLAG_MASK,
+            &declared_accounts
+        ));
+        assert!(is_declared(
+            other,
+            FROZEN_OBJECT_FLAG_MASK,
+            &declared_accounts
+        ));
+    }
+}
@@ -7,7 +7,9 @@ pub mod dependency_order {
 }
 #[allow(dead_code)]
 pub mod data_cache;
+pub mod dependency_checker;
 pub mod moveos_vm;
+pub mod object_checker;
 pub mod tx_argument_resolver;
 pub mod vm_status_explainer;
 
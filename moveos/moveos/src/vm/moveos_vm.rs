@@ -5,12 +5,13 @@ use std::{borrow::Borrow, sync::Arc};
 
 use move_binary_format::{
     compatibility::Compatibility,
-    errors::{Location, PartialVMError, VMError, VMResult},
+    errors::{Location, PartialVMError, PartialVMResult, VMError, VMResult},
     file_format::AbilitySet,
     CompiledModule,
 };
 use move_core_types::{
     account_address::AccountAddress,
+    effects::Op,
     identifier::Identifier,
     language_storage::{ModuleId, TypeTag},
     value::MoveTypeLayout,
@@ -27,7 +28,9 @@ use move_vm_runtime::{
 use move_vm_types::loaded_data::runtime_types::{CachedStructIndex, StructType, Type};
 use parking_lot::RwLock;
 
+use moveos_stdlib::natives::memory_accountant::{MemoryAccountant, DEFAULT_NATIVE_MEMORY_QUOTA};
 use moveos_stdlib::natives::moveos_stdlib::{
+    dispatch::{DispatchCall, NativeDispatchContext},
     event::NativeEventContext,
     move_module::NativeModuleContext,
     raw_table::{NativeTableContext, TableData},
@@ -36,26 +39,34 @@ use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::{
     function_return_value::FunctionReturnValue,
     move_std::string::MoveString,
-    move_types::FunctionId,
+    move_types::{EntryFunctionVisibility, FunctionId},
     moveos_std::context::Context,
     moveos_std::copyable_any::Any,
     moveos_std::simple_map::SimpleMap,
     moveos_std::tx_context::TxContext,
     moveos_std::{event::TransactionEvent, module_upgrade_flag::ModuleUpgradeFlag},
+    state::StateChangeSet,
     state_resolver::MoveOSResolver,
-    transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
+    transaction::{FunctionCall, MoveAction, TxDependencyHints, VerifiedMoveAction},
 };
 use moveos_verifier::verifier::INIT_FN_NAME_IDENTIFIER;
 
-use crate::gas::table::{initial_cost_schedule, ClassifiedGasMeter};
+use crate::gas::table::{
+    ClassifiedGasMeter, CostTable, GasDelta, GasSnapshot, GAS_FEATURE_VERSION_STORAGE_FEE,
+};
 use crate::gas::{table::MoveOSGasMeter, SwitchableGasMeter};
-use crate::vm::tx_argument_resolver;
+use crate::vm::{dependency_checker, object_checker, tx_argument_resolver};
+
+use super::data_cache::{into_change_set, peek_change_set, MoveosDataCache};
 
-use super::data_cache::{into_change_set, MoveosDataCache};
+/// The max depth of the nested dispatched calls
+pub const MAX_DISPATCH_DEPTH: usize = 8;
 
 /// MoveOSVM is a wrapper of MoveVM with MoveOS specific features.
 pub struct MoveOSVM {
     inner: MoveVM,
+    /// The memory quota of the natives in a transaction session
+    native_memory_quota: u64,
 }
 
 impl MoveOSVM {
@@ -65,9 +76,14 @@ impl MoveOSVM {
     ) -> VMResult<Self> {
         Ok(Self {
             inner: MoveVM::new_with_config(natives, vm_config)?,
+            native_memory_quota: DEFAULT_NATIVE_MEMORY_QUOTA,
         })
     }
 
+    pub fn set_native_memory_quota(&mut self, native_memory_quota: u64) {
+        self.native_memory_quota = native_memory_quota;
+    }
+
     pub fn new_session<
         'r,
         S: MoveOSResolver,
@@ -78,20 +94,28 @@ impl MoveOSVM {
         ctx: TxContext,
         gas_meter: G,
     ) -> MoveOSSession<'r, '_, S, G> {
-        MoveOSSession::new(&self.inner, remote, ctx, gas_meter, false)
+        MoveOSSession::new(
+            &self.inner,
+            remote,
+            ctx,
+            gas_meter,
+            false,
+            self.native_memory_quota,
+        )
     }
 
     pub fn new_genesis_session<'r, S: MoveOSResolver>(
         &self,
         remote: &'r S,
         ctx: TxContext,
+        cost_table: CostTable,
     ) -> MoveOSSession<'r, '_, S, MoveOSGasMeter> {
-        //Do not charge gas for genesis session
-        let cost_table = initial_cost_schedule();
+        //Do not charge gas for genesis session, the gas is recorded as the system gas by the `cost_table`
         let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
         gas_meter.set_metering(false);
         // Genesis session do not need to execute pre_execute and post_execute function
-        MoveOSSession::new(&self.inner, remote, ctx, gas_meter, false)
+        // The genesis publishes the framework, so the natives are not limited by the memory quota
+        MoveOSSession::new(&self.inner, remote, ctx, gas_meter, false, u64::MAX)
     }
 
     pub fn new_readonly_session<
@@ -104,7 +128,14 @@ impl MoveOSVM {
         ctx: TxContext,
         gas_meter: G,
     ) -> MoveOSSession<'r, '_, S, G> {
-        MoveOSSession::new(&self.inner, remote, ctx, gas_meter, true)
+        MoveOSSession::new(
+            &self.inner,
+            remote,
+            ctx,
+            gas_meter,
+            true,
+            self.native_memory_quota,
+        )
     }
 
     pub fn mark_loader_cache_as_invalid(&self) {
@@ -123,6 +154,9 @@ pub struct MoveOSSession<'r, 'l, S, G> {
     pub(crate) table_data: Arc<RwLock<TableData>>,
     pub(crate) gas_meter: G,
     pub(crate) read_only: bool,
+    pub(crate) native_memory_quota: u64,
+    // The storage of the states and the events is charged before the session finishes
+    pub(crate) storage_charged: bool,
 }
 
 #[allow(clippy::arc_with_non_send_sync)]
@@ -137,18 +171,23 @@ where
         ctx: TxContext,
         gas_meter: G,
         read_only: bool,
+        native_memory_quota: u64,
     ) -> Self {
         let ctx = Context::new(ctx);
         let table_data = Arc::new(RwLock::new(TableData::default()));
-        Self {
+        let session = Self {
             vm,
             remote,
-            session: Self::new_inner_session(vm, remote, table_data.clone()),
+            session: Self::new_inner_session(vm, remote, table_data.clone(), native_memory_quota),
             ctx,
             table_data,
             gas_meter,
             read_only,
-        }
+            native_memory_quota,
+            storage_charged: false,
+        };
+        session.sync_memory_accountant();
+        session
     }
 
     /// Re spawn a new session with the same context.
@@ -159,24 +198,35 @@ where
         //We need to find a solution.
         let ctx = Context::new(self.ctx.tx_context.spawn(env));
         let table_data = Arc::new(RwLock::new(TableData::default()));
-        Self {
-            session: Self::new_inner_session(self.vm, self.remote, table_data.clone()),
+        let session = Self {
+            session: Self::new_inner_session(
+                self.vm,
+                self.remote,
+                table_data.clone(),
+                self.native_memory_quota,
+            ),
             ctx,
             table_data,
+            storage_charged: false,
             ..self
-        }
+        };
+        session.sync_memory_accountant();
+        session
     }
 
     fn new_inner_session(
         vm: &'l MoveVM,
         remote: &'r S,
         table_data: Arc<RwLock<TableData>>,
+        native_memory_quota: u64,
     ) -> Session<'r, 'l, MoveosDataCache<'r, 'l, S>> {
         let mut extensions = NativeContextExtensions::default();
 
         extensions.add(NativeTableContext::new(remote, table_data.clone()));
         extensions.add(NativeModuleContext::new(remote));
         extensions.add(NativeEventContext::default());
+        extensions.add(NativeDispatchContext::default());
+        extensions.add(MemoryAccountant::new(native_memory_quota));
 
         // The VM code loader has bugs around module upgrade. After a module upgrade, the internal
         // cache needs to be flushed to work around those bugs.
@@ -203,6 +253,7 @@ where
                 Ok(VerifiedMoveAction::Script { call })
             }
             MoveAction::Function(call) => {
+                self.verify_entry_function_visibility(&call.function_id)?;
                 let loaded_function = self.session.load_function(
                     &call.function_id.module_id,
                     &call.function_id.function_name,
@@ -238,6 +289,45 @@ where
         }
     }
 
+    /// Verify the function can be called by the transaction directly.
+    /// Only the `public entry` and `entry` functions are externally callable,
+    /// the `public(friend) entry` and non-entry functions are rejected before execution.
+    fn verify_entry_function_visibility(&self, function_id: &FunctionId) -> VMResult<()> {
+        let module_id = &function_id.module_id;
+        let module_bytes = self
+            .remote
+            .get_module(module_id)
+            .map_err(|e| {
+                PartialVMError::new(StatusCode::STORAGE_ERROR)
+                    .with_message(format!("{:?}", e))
+                    .finish(Location::Undefined)
+            })?
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::LINKER_ERROR)
+                    .with_message(format!("Module {} not found", module_id))
+                    .finish(Location::Undefined)
+            })?;
+        let module = CompiledModule::deserialize(&module_bytes)
+            .map_err(|e| e.finish(Location::Module(module_id.clone())))?;
+        let visibility = EntryFunctionVisibility::of_function(&module, &function_id.function_name)
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::FUNCTION_RESOLUTION_FAILURE)
+                    .with_message(format!("Function {} not found", function_id))
+                    .finish(Location::Module(module_id.clone()))
+            })?;
+        if !visibility.is_externally_callable() {
+            return Err(PartialVMError::new(
+                StatusCode::EXECUTE_ENTRY_FUNCTION_CALLED_ON_NON_ENTRY_FUNCTION,
+            )
+            .with_message(format!(
+                "The {} function {} can not be called by transactions",
+                visibility, function_id
+            ))
+            .finish(Location::Module(module_id.clone())));
+        }
+        Ok(())
+    }
+
     /// Execute a move action.
     /// The caller should ensure call verify_move_action before execute.
     /// Once we start executing transactions, we must ensure that the transaction execution has a result, regardless of success or failure,
@@ -270,6 +360,10 @@ where
 
                 let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
                 self.load_argument(&loaded_function, &resolved_args);
+                self.gas_meter.enter_function(
+                    &call.function_id.module_id,
+                    call.function_id.function_name.as_str(),
+                );
                 self.session
                     .execute_entry_function(
                         &call.function_id.module_id,
@@ -300,6 +394,16 @@ where
                 //TODO check the compatiblity
                 let compat_config = Compatibility::full_check();
 
+                // Charge the publish gas by the bytecode size and the verification complexity of the modules
+                let compiled_modules = deserialize_modules(&module_bundle)?;
+                let bytecode_size = module_bundle
+                    .iter()
+                    .map(|module| module.len() as u64)
+                    .sum::<u64>();
+                self.gas_meter
+                    .charge_module_publish(&compiled_modules, bytecode_size)
+                    .map_err(|e| e.finish(Location::Undefined))?;
+
                 self.session.publish_module_bundle_with_compat_config(
                     module_bundle,
                     sender,
@@ -312,6 +416,7 @@ where
         };
 
         self.resolve_pending_init_functions()?;
+        self.resolve_pending_dispatch_calls()?;
 
         // Check if there are modules upgrading
         let module_flag = self
@@ -345,6 +450,93 @@ where
         }
     }
 
+    /// Resolve pending dispatch calls requested via the NativeDispatchContext.
+    fn resolve_pending_dispatch_calls(&mut self) -> VMResult<()> {
+        let calls = self.take_pending_dispatch_calls();
+        self.execute_dispatch_calls(calls, &[])
+    }
+
+    fn take_pending_dispatch_calls(&mut self) -> Vec<DispatchCall> {
+        self.session
+            .get_native_extensions_mut()
+            .get_mut::<NativeDispatchContext>()
+            .take_pending_calls()
+    }
+
+    /// Execute the dispatched calls after the caller returned.
+    /// Every dispatched call is executed as an entry function with its own Context argument, so it can not
+    /// borrow the caller's state. The `dispatch_chain` is the modules of the active dispatch, the dispatched
+    /// call to a module in the chain is rejected as a reentrant call.
+    fn execute_dispatch_calls(
+        &mut self,
+        calls: Vec<DispatchCall>,
+        dispatch_chain: &[ModuleId],
+    ) -> VMResult<()> {
+        for DispatchCall { caller, call } in calls {
+            let mut call_chain = dispatch_chain.to_vec();
+            if call_chain.last() != Some(&caller) {
+                call_chain.push(caller);
+            }
+            let target = call.function_id.module_id.clone();
+            if call_chain.contains(&target) {
+                return Err(PartialVMError::new(StatusCode::ABORTED)
+                    .with_message(format!(
+                        "Reentrant dispatch to module {} is not allowed, dispatch chain: {:?}",
+                        target, call_chain
+                    ))
+                    .finish(Location::Module(target)));
+            }
+            if call_chain.len() > MAX_DISPATCH_DEPTH {
+                return Err(PartialVMError::new(StatusCode::CALL_STACK_OVERFLOW)
+                    .with_message(format!(
+                        "The dispatch depth exceeds the limit {}",
+                        MAX_DISPATCH_DEPTH
+                    ))
+                    .finish(Location::Module(target)));
+            }
+
+            let loaded_function = self.session.load_function(
+                &call.function_id.module_id,
+                &call.function_id.function_name,
+                call.ty_args.as_slice(),
+            )?;
+            // The dispatched function runs with restricted capabilities, it can not get the signer of the sender.
+            if loaded_function.parameters.iter().any(|ty| match ty {
+                Type::Reference(ty) => matches!(ty.as_ref(), Type::Signer),
+                ty => matches!(ty, Type::Signer),
+            }) {
+                return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                    .with_message(format!(
+                        "The dispatched function {} can not take signer arguments",
+                        call.function_id
+                    ))
+                    .finish(Location::Module(target)));
+            }
+
+            let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
+            self.load_argument(&loaded_function, &resolved_args);
+            self.gas_meter.enter_function(
+                &call.function_id.module_id,
+                call.function_id.function_name.as_str(),
+            );
+            let return_values = self.session.execute_entry_function(
+                &call.function_id.module_id,
+                &call.function_id.function_name,
+                call.ty_args.clone(),
+                resolved_args,
+                &mut self.gas_meter,
+            )?;
+            self.update_storage_context_via_return_values(&loaded_function, &return_values);
+
+            let nested_calls = self.take_pending_dispatch_calls();
+            if !nested_calls.is_empty() {
+                call_chain.push(target);
+                self.execute_dispatch_calls(nested_calls, &call_chain)?;
+            }
+        }
+        Ok(())
+    }
+
     // Because the Context can be mut argument, if the function change the Context,
     // we need to update the Context via return values, and pass the updated Context to the next function.
     fn update_storage_context_via_return_values(
@@ -404,6 +596,10 @@ where
         )?;
         let resolved_args = self.resolve_argument(&loaded_function, call.args)?;
         self.load_argument(&loaded_function, &resolved_args);
+        self.gas_meter.enter_function(
+            &call.function_id.module_id,
+            call.function_id.function_name.as_str(),
+        );
         let return_values = self.session.execute_function_bypass_visibility(
             &call.function_id.module_id,
             &call.function_id.function_name,
@@ -455,16 +651,19 @@ where
         self,
         status: KeptVMStatus,
     ) -> VMResult<(TxContext, RawTransactionOutput)> {
-        let gas_used = self.query_gas_used();
         let is_read_only_execution = self.read_only;
+        // The legacy schedules do not count the storage gas charged by the finishing session in the gas used
+        let gas_used_before_storage = self.query_gas_used();
         let MoveOSSession {
             vm: _,
-            remote: _,
+            remote,
             session,
             ctx,
             table_data,
             mut gas_meter,
             read_only,
+            native_memory_quota: _,
+            storage_charged,
         } = self;
         let (changeset, raw_events, mut extensions) = session.finish_with_extensions()?;
         //We do not use the event API from data_cache. Instead, we use the NativeEventContext
@@ -513,23 +712,9 @@ where
         })?;
         let is_upgrade = module_flag.map_or(false, |flag| flag.is_upgrade);
 
-        match gas_meter.charge_change_set(&state_changeset) {
-            Ok(_) => {}
-            Err(partial_vm_error) => {
-                return Err(partial_vm_error
-                    .with_message(
-                        "An error occurred during the charging of the change set".to_owned(),
-                    )
-                    .finish(Location::Undefined));
-            }
-        }
-        match gas_meter.charge_event(events.as_slice()) {
-            Ok(_) => {}
-            Err(partial_vm_error) => {
-                return Err(partial_vm_error
-                    .with_message("An error occurred during the charging of the events".to_owned())
-                    .finish(Location::Undefined));
-            }
+        if !storage_charged {
+            charge_storage(&mut gas_meter, remote, &state_changeset, &events)
+                .map_err(|e| e.finish(Location::Undefined))?;
         }
 
         match gas_meter.check_constrains(ctx.tx_context.max_gas_amount) {
@@ -540,9 +725,34 @@ where
         };
 
         let mut gas_statement = gas_meter.gas_statement();
+        // The gas used includes the storage gas of the change set and the events, minus the storage refund
+        let gas_used = if is_read_only_execution {
+            0
+        } else if gas_meter.feature_version() < GAS_FEATURE_VERSION_STORAGE_FEE {
+            gas_used_before_storage
+        } else {
+            let max_gas_amount = ctx.tx_context.max_gas_amount;
+            let gas_left: u64 = gas_meter.balance_internal().into();
+            max_gas_amount.checked_sub(gas_left).unwrap_or_else(
+                || panic!("gas_left({gas_left}) should always be less than or equal to max gas amount({max_gas_amount})")
+            )
+            .saturating_sub(
+                gas_meter
+                    .gas_prices()
+                    .priced_gas_used(0, gas_statement.storage_rebate),
+            )
+        };
         if is_read_only_execution {
             gas_statement.execution_gas_used = 0;
+            gas_statement.instruction_gas_used = 0;
+            gas_statement.stack_gas_used = 0;
+            gas_statement.native_gas_used = 0;
             gas_statement.storage_gas_used = 0;
+            gas_statement.publish_gas_used = 0;
+            gas_statement.storage_rebate = 0;
+            gas_statement.io_bytes_written = 0;
+            gas_statement.io_gas_used = 0;
+            gas_statement.system_gas_used = 0;
         }
 
         Ok((
@@ -555,10 +765,40 @@ where
                 gas_used,
                 is_upgrade,
                 gas_statement,
+                out_of_gas_instructions: None,
+                execution_timed_out: false,
+                out_of_gas_dimension: None,
             },
         ))
     }
 
+    /// Charge the storage of the states written and the events emitted so far, so the gas used paid by
+    /// the system post_execute functions includes the storage gas, since `GAS_FEATURE_VERSION_STORAGE_FEE`.
+    /// It should be called after the user action and the post_execute functions,
+    /// the changes made by the system functions after it are not charged.
+    pub(crate) fn charge_storage(&mut self) -> VMResult<()> {
+        if self.read_only || self.gas_meter.feature_version() < GAS_FEATURE_VERSION_STORAGE_FEE {
+            return Ok(());
+        }
+        let state_changeset = peek_change_set(self.remote, &self.table_data.read())
+            .map_err(|e| e.finish(Location::Undefined))?;
+        let events: Vec<_> = self
+            .session
+            .get_native_extensions()
+            .get::<NativeEventContext>()
+            .events()
+            .iter()
+            .enumerate()
+            .map(|(i, (struct_tag, event_data))| {
+                TransactionEvent::new(struct_tag.clone(), event_data.clone(), i as u64)
+            })
+            .collect();
+        charge_storage(&mut self.gas_meter, self.remote, &state_changeset, &events)
+            .map_err(|e| e.finish(Location::Undefined))?;
+        self.storage_charged = true;
+        Ok(())
+    }
+
     pub(crate) fn execute_function_call(
         &mut self,
         functions: Vec<FunctionCall>,
@@ -566,12 +806,14 @@ where
     ) -> VMResult<()> {
         if !meter_gas {
             self.gas_meter.stop_metering();
+            self.sync_memory_accountant();
         }
         for function_call in functions {
             let result = self.execute_function_bypass_visibility(function_call);
             if let Err(e) = result {
                 if !meter_gas {
                     self.gas_meter.start_metering();
+                    self.sync_memory_accountant();
                 }
                 return Err(e);
             }
@@ -579,10 +821,67 @@ where
         }
         if !meter_gas {
             self.gas_meter.start_metering();
+            self.sync_memory_accountant();
         }
         Ok(())
     }
 
+    /// The natives executed without metering, such as in the system functions, are exempted from the memory quota,
+    /// so a transaction using up the quota can not make the system functions fail.
+    fn sync_memory_accountant(&self) {
+        self.session
+            .get_native_extensions()
+            .get::<MemoryAccountant>()
+            .set_exempt(!self.gas_meter.is_metering());
+    }
+
+    /// Check there is no leaked object or dangling object handle in the session,
+    /// it should be called after the user action and the post_execute functions.
+    pub(crate) fn check_objects(&self) -> VMResult<()> {
+        object_checker::check_objects(self.remote, &self.table_data, |type_tag| {
+            self.get_fully_annotated_type_layout(type_tag)
+        })
+    }
+
+    /// Check the objects touched by the user action are declared by the dependency hints,
+    /// it should be called after the user action.
+    pub(crate) fn check_dependency_hints(&self, hints: &TxDependencyHints) -> VMResult<()> {
+        dependency_checker::check_dependency_hints(
+            self.remote,
+            &self.table_data,
+            self.ctx.tx_context.sender(),
+            hints,
+        )
+    }
+
+    /// The total number of bytecode instructions executed in the transaction, the respawned session keeps counting.
+    pub(crate) fn instructions_executed(&self) -> u64 {
+        self.gas_meter.instructions_executed()
+    }
+
+    /// Charge the full budget of the transaction, it is called when the transaction runs out of gas.
+    pub(crate) fn charge_full_budget(&mut self) {
+        if !self.read_only {
+            self.gas_meter
+                .charge_full_budget(self.ctx.tx_context.max_gas_amount);
+        }
+    }
+
+    /// Take a checkpoint of the gas meter, the gas charged after it is `gas_delta_since` the snapshot.
+    pub(crate) fn gas_snapshot(&self) -> GasSnapshot {
+        self.gas_meter.snapshot()
+    }
+
+    pub(crate) fn gas_delta_since(&self, snapshot: &GasSnapshot) -> GasDelta {
+        self.gas_meter.delta_since(snapshot)
+    }
+
+    /// Stop charging the gas, the gas used of the transaction does not change after it.
+    pub(crate) fn stop_metering(&mut self) {
+        self.gas_meter.stop_metering();
+        self.sync_memory_accountant();
+    }
+
     pub(crate) fn query_gas_used(&self) -> u64 {
         if self.read_only {
             //TODO calculate readonly function gas usage
@@ -590,9 +889,20 @@ where
         } else {
             let max_gas_amount = self.ctx.tx_context.max_gas_amount;
             let gas_left: u64 = self.gas_meter.balance_internal().into();
-            max_gas_amount.checked_sub(gas_left).unwrap_or_else(
+            let gas_used = max_gas_amount.checked_sub(gas_left).unwrap_or_else(
                 || panic!("gas_left({gas_left}) should always be less than or equal to max gas amount({max_gas_amount})")
-            )
+            );
+            // The storage refund is charged before the gas used is paid, so the fee is reduced by the refund
+            if self.storage_charged {
+                let storage_rebate = self.gas_meter.gas_statement().storage_rebate;
+                gas_used.saturating_sub(
+                    self.gas_meter
+                        .gas_prices()
+                        .priced_gas_used(0, storage_rebate),
+                )
+            } else {
+                gas_used
+            }
         }
     }
 
@@ -671,3 +981,48 @@ fn deserialize_modules(module_bytes: &[Vec<u8>]) -> Result<Vec<CompiledModule>,
         .map(|b| CompiledModule::deserialize(b).map_err(|e| e.finish(Location::Undefined)))
         .collect::<VMResult<Vec<CompiledModule>>>()
 }
+
+/// Sum the size of the states deleted by the change set, resolved from the remote storage.
+/// Charge the storage of the state change set and the events, the storage refund is the last one,
+/// as it is capped at a percentage of the gas charged.
+fn charge_storage<S: MoveOSResolver, G: ClassifiedGasMeter>(
+    gas_meter: &mut G,
+    remote: &S,
+    state_changeset: &StateChangeSet,
+    events: &[TransactionEvent],
+) -> PartialVMResult<()> {
+    let deleted_bytes = deleted_states_size(remote, state_changeset)?;
+    gas_meter.charge_change_set(state_changeset).map_err(|e| {
+        e.with_message("An error occurred during the charging of the change set".to_owned())
+    })?;
+    gas_meter.charge_event(events).map_err(|e| {
+        e.with_message("An error occurred during the charging of the events".to_owned())
+    })?;
+    gas_meter
+        .refund_storage_deposit(deleted_bytes)
+        .map_err(|e| {
+            e.with_message(
+                "An error occurred during the refunding of the storage deposit".to_owned(),
+            )
+        })
+}
+
+fn deleted_states_size<S: MoveOSResolver>(
+    remote: &S,
+    state_changeset: &StateChangeSet,
+) -> PartialVMResult<u64> {
+    let mut deleted_size = 0u64;
+    for (handle, table_change) in state_changeset.changes.iter() {
+        for (key, op) in table_change.entries.iter() {
+            if let Op::Delete = op {
+                let state = remote.resolve_table_item(handle, key).map_err(|e| {
+                    PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(e.to_string())
+                })?;
+                if let Some(state) = state {
+                    deleted_size += (key.len() + state.value.len()) as u64;
+                }
+            }
+        }
+    }
+    Ok(deleted_size)
+}
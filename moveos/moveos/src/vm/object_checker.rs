@@ -0,0 +1,350 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Check the objects at the end of a transaction, to catch the object-model bugs at execution time.
+//! The `Object<T>` has no `drop` ability, but the friend modules of `moveos_std::object` can still
+//! drop a handle or create a new handle from the ObjectEntity, so the following bugs are possible:
+//!   1. Leaked object: a new object is still `SystemOwned`, not shared nor frozen,
+//!      and no `Object<T>` handle of it is stored, so nobody can access it anymore.
+//!   2. Dangling handle: an `Object<T>` handle is stored, but its object has been removed,
+//!      or transferred to a user in the transaction.
+//! The transaction aborts with `ErrorObjectLeaked` or `ErrorDanglingObjectHandle` at `moveos_std::object`.
+
+use move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMError, VMResult};
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{ModuleId, StructTag, TypeTag},
+    value::{MoveStruct, MoveTypeLayout, MoveValue},
+    vm_status::StatusCode,
+};
+use moveos_stdlib::natives::moveos_stdlib::raw_table::TableData;
+use moveos_types::{
+    addresses::MOVEOS_STD_ADDRESS,
+    moveos_std::object::{self, ObjectID},
+    state_resolver::{StateResolver, GLOBAL_OBJECT_STORAGE_HANDLE},
+};
+use parking_lot::RwLock;
+use std::collections::BTreeSet;
+
+/// Ensure the error codes are consistent with the error codes in object.move
+pub const ERROR_OBJECT_LEAKED: u64 = 4;
+pub const ERROR_DANGLING_OBJECT_HANDLE: u64 = 5;
+
+pub(crate) const SHARED_OBJECT_FLAG_MASK: u8 = 1;
+pub(crate) const FROZEN_OBJECT_FLAG_MASK: u8 = 1 << 1;
+const OWNER_OFFSET: usize = AccountAddress::LENGTH;
+const FLAG_OFFSET: usize = OWNER_OFFSET + AccountAddress::LENGTH;
+
+struct WrittenValue {
+    handle: ObjectID,
+    key: Vec<u8>,
+    value_type: TypeTag,
+    bytes: Vec<u8>,
+}
+
+/// Check the objects in the session, `resolve_layout` returns the fully annotated layout of a type.
+pub fn check_objects<S, F>(
+    remote: &S,
+    table_data: &RwLock<TableData>,
+    resolve_layout: F,
+) -> VMResult<()>
+where
+    S: StateResolver + ?Sized,
+    F: Fn(&TypeTag) -> VMResult<MoveTypeLayout>,
+{
+    // Release the lock before resolving the layouts, the loader may load modules from the table data.
+    let (created, released, values) =
+        collect_objects(remote, &table_data.read()).map_err(|e| e.finish(Location::Undefined))?;
+
+    // A new object is referenced if its ObjectID appears in any value, it is a conservative check,
+    // an ObjectID which is not in an `Object<T>` handle also prevents the object from being reported as leaked.
+    // The check is not metered, every value is scanned once whatever the number of the objects,
+    // so it is linear in the size of the written values.
+    if !created.is_empty() {
+        let mut referenced = BTreeSet::new();
+        for value in values.iter() {
+            referenced.extend(value.references(&created));
+        }
+        if let Some(object_id) = created.difference(&referenced).next() {
+            return Err(object_error(
+                ERROR_OBJECT_LEAKED,
+                format!(
+                    "Object {} is created but no handle of it is stored",
+                    object_id
+                ),
+            ));
+        }
+    }
+
+    if released.is_empty() {
+        return Ok(());
+    }
+    for value in values.iter() {
+        // The bytes search is cheap, only annotate the values that may contain the handles.
+        if value.references(&released).is_empty() {
+            continue;
+        }
+        let layout = match resolve_layout(&value.value_type) {
+            Ok(layout) => layout,
+            Err(e) => {
+                log::warn!(
+                    "Skip the object handle check of type {}: {:?}",
+                    value.value_type,
+                    e
+                );
+                continue;
+            }
+        };
+        let move_value = match MoveValue::simple_deserialize(&value.bytes, &layout) {
+            Ok(move_value) => move_value,
+            Err(e) => {
+                log::warn!(
+                    "Skip the object handle check of type {}: {:?}",
+                    value.value_type,
+                    e
+                );
+                continue;
+            }
+        };
+        let mut handles = BTreeSet::new();
+        collect_object_handles(&move_value, &mut handles);
+        if let Some(object_id) = handles.intersection(&released).next() {
+            return Err(object_error(
+                ERROR_DANGLING_OBJECT_HANDLE,
+                format!(
+                    "The handle of object {} is stored in table {}, but the object is removed or transferred",
+                    object_id, value.handle
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Return the new leaking candidates, the objects removed or transferred in the session, and all the values in the session.
+fn collect_objects<S>(
+    remote: &S,
+    table_data: &TableData,
+) -> PartialVMResult<(BTreeSet<ObjectID>, BTreeSet<ObjectID>, Vec<WrittenValue>)>
+where
+    S: StateResolver + ?Sized,
+{
+    let mut created = BTreeSet::new();
+    let mut released = BTreeSet::new();
+    let mut values = vec![];
+    for table in table_data.tables() {
+        let handle = *table.handle();
+        for (key, runtime_value) in table.content() {
+            let current = runtime_value.serialize_value()?;
+            if handle == GLOBAL_OBJECT_STORAGE_HANDLE {
+                let object_id = ObjectID::from_bytes(key).map_err(storage_error)?;
+                let previous_owner = remote
+                    .resolve_table_item(&handle, key)
+                    .map_err(storage_error)?
+                    .map(|state| parse_owner_and_flag(&state.value).map(|(owner, _)| owner))
+                    .transpose()?;
+                match (previous_owner, current.as_ref()) {
+                    (None, Some(bytes)) => {
+                        let (owner, flag) = parse_owner_and_flag(bytes)?;
+                        if owner != AccountAddress::ZERO {
+                            released.insert(object_id);
+                        } else if flag & (SHARED_OBJECT_FLAG_MASK | FROZEN_OBJECT_FLAG_MASK) == 0 {
+                            created.insert(object_id);
+                        }
+                    }
+                    (Some(previous_owner), Some(bytes)) => {
+                        let (owner, _) = parse_owner_and_flag(bytes)?;
+                        if owner != AccountAddress::ZERO && owner != previous_owner {
+                            released.insert(object_id);
+                        }
+                    }
+                    (Some(_), None) => {
+                        released.insert(object_id);
+                    }
+                    (None, None) => {}
+                }
+            }
+            if let (Some(bytes), Some(value_type)) = (current, runtime_value.value_type()) {
+                values.push(WrittenValue {
+                    handle,
+                    key: key.clone(),
+                    value_type: value_type.clone(),
+                    bytes,
+                });
+            }
+        }
+    }
+    Ok((created, released, values))
+}
+
+impl WrittenValue {
+    /// The objects of `object_ids` whose ObjectID appears in the value, the ObjectEntity of the object itself is excluded.
+    /// Every position of the value is looked up once, so it does not depend on the number of the objects.
+    fn references(&self, object_ids: &BTreeSet<ObjectID>) -> BTreeSet<ObjectID> {
+        if object_ids.is_empty() {
+            return BTreeSet::new();
+        }
+        let own_id = if self.handle == GLOBAL_OBJECT_STORAGE_HANDLE {
+            ObjectID::from_bytes(&self.key).ok()
+        } else {
+            None
+        };
+        self.bytes
+            .windows(AccountAddress::LENGTH)
+            .filter_map(|window| ObjectID::from_bytes(window).ok())
+            .filter(|object_id| object_ids.contains(object_id) && Some(*object_id) != own_id)
+            .collect()
+    }
+}
+
+fn collect_object_handles(value: &MoveValue, handles: &mut BTreeSet<ObjectID>) {
+    match value {
+        MoveValue::Struct(MoveStruct::WithTypes { type_, fields }) => {
+            if is_object_handle(type_) {
+                if let Some((_, MoveValue::Struct(id))) = fields.first() {
+                    if let Some(MoveValue::Address(address)) = struct_fields(id).next() {
+                        handles.insert(ObjectID::from(*address));
+                    }
+                }
+                return;
+            }
+            for (_, field) in fields {
+                collect_object_handles(field, handles);
+            }
+        }
+        MoveValue::Struct(s) => {
+            for field in struct_fields(s) {
+                collect_object_handles(field, handles);
+            }
+        }
+        MoveValue::Vector(elements) => {
+            for element in elements {
+                collect_object_handles(element, handles);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn struct_fields(s: &MoveStruct) -> Box<dyn Iterator<Item = &MoveValue> + '_> {
+    match s {
+        MoveStruct::Runtime(fields) => Box::new(fields.iter()),
+        MoveStruct::WithFields(fields) | MoveStruct::WithTypes { fields, .. } => {
+            Box::new(fields.iter().map(|(_, field)| field))
+        }
+    }
+}
+
+fn is_object_handle(struct_tag: &StructTag) -> bool {
+    struct_tag.address == MOVEOS_STD_ADDRESS
+        && struct_tag.module.as_ident_str() == object::MODULE_NAME
+        && struct_tag.name.as_str() == "Object"
+}
+
+/// The ObjectEntity is serialized as `id || owner || flag || value`
+pub(crate) fn parse_owner_and_flag(bytes: &[u8]) -> PartialVMResult<(AccountAddress, u8)> {
+    if bytes.len() <= FLAG_OFFSET {
+        return Err(
+            PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(format!(
+                "Invalid ObjectEntity bytes, length:{}",
+                bytes.len()
+            )),
+        );
+    }
+    let owner = AccountAddress::from_bytes(&bytes[OWNER_OFFSET..FLAG_OFFSET])
+        .map_err(|e| PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(e.to_string()))?;
+    Ok((owner, bytes[FLAG_OFFSET]))
+}
+
+pub(crate) fn storage_error(e: anyhow::Error) -> PartialVMError {
+    PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(e.to_string())
+}
+
+fn object_error(abort_code: u64, message: String) -> VMError {
+    PartialVMError::new(StatusCode::ABORTED)
+        .with_sub_status(abort_code)
+        .with_message(message)
+        .finish(Location::Module(ModuleId::new(
+            MOVEOS_STD_ADDRESS,
+            object::MODULE_NAME.to_owned(),
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::identifier::Identifier;
+
+    fn object_id_value(object_id: ObjectID) -> MoveValue {
+        MoveValue::Struct(MoveStruct::WithTypes {
+            type_: StructTag {
+                address: MOVEOS_STD_ADDRESS,
+                module: object::MODULE_NAME.to_owned(),
+                name: Identifier::new("ObjectID").unwrap(),
+                type_params: vec![],
+            },
+            fields: vec![(
+                Identifier::new("id").unwrap(),
+                MoveValue::Address(object_id.into()),
+            )],
+        })
+    }
+
+    fn object_handle_value(object_id: ObjectID) -> MoveValue {
+        MoveValue::Struct(MoveStruct::WithTypes {
+            type_: StructTag {
+                address: MOVEOS_STD_ADDRESS,
+                module: object::MODULE_NAME.to_owned(),
+                name: Identifier::new("Object").unwrap(),
+                type_params: vec![TypeTag::U8],
+            },
+            fields: vec![(Identifier::new("id").unwrap(), object_id_value(object_id))],
+        })
+    }
+
+    #[test]
+    fn test_collect_object_handles() {
+        let handle_id = ObjectID::from(AccountAddress::random());
+        let plain_id = ObjectID::from(AccountAddress::random());
+        let value = MoveValue::Struct(MoveStruct::Runtime(vec![
+            MoveValue::Vector(vec![object_handle_value(handle_id)]),
+            object_id_value(plain_id),
+        ]));
+        let mut handles = BTreeSet::new();
+        collect_object_handles(&value, &mut handles);
+        assert_eq!(handles, BTreeSet::from([handle_id]));
+    }
+
+    #[test]
+    fn test_written_value_references() {
+        let own_id = ObjectID::from(AccountAddress::random());
+        let other_id = ObjectID::from(AccountAddress::random());
+        let missing_id = ObjectID::from(AccountAddress::random());
+        let mut bytes = vec![1, 2, 3];
+        bytes.extend(own_id.to_bytes());
+        bytes.extend(other_id.to_bytes());
+        let value = WrittenValue {
+            handle: GLOBAL_OBJECT_STORAGE_HANDLE,
+            key: own_id.to_bytes(),
+            value_type: TypeTag::U8,
+            bytes,
+        };
+        let object_ids = BTreeSet::from([own_id, other_id, missing_id]);
+        assert_eq!(value.references(&object_ids), BTreeSet::from([other_id]));
+        assert!(value.references(&BTreeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_parse_owner_and_flag() {
+        let owner = AccountAddress::random();
+        let mut bytes = ObjectID::from(AccountAddress::random()).to_bytes();
+        bytes.extend(owner.to_vec());
+        assert!(parse_owner_and_flag(&bytes).is_err());
+        bytes.push(SHARED_OBJECT_FLAG_MASK);
+        bytes.push(0);
+        assert_eq!(
+            parse_owner_and_flag(&bytes).unwrap(),
+            (owner, SHARED_OBJECT_FLAG_MASK)
+        );
+    }
+}
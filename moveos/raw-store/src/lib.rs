@@ -106,6 +106,12 @@ impl DBStore for StoreInstance {
             StoreInstance::DB { db } => db.multi_get(prefix_name, keys),
         }
     }
+
+    fn write_cf_batches_sync(&self, batches: Vec<(&str, WriteBatch)>) -> Result<()> {
+        match self {
+            StoreInstance::DB { db } => db.write_cf_batches_sync(batches),
+        }
+    }
 }
 
 pub trait ColumnFamily: Send + Sync {
@@ -269,6 +275,20 @@ where
     }
 }
 
+impl<K, V> CodecWriteBatch<K, V>
+where
+    K: Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    /// Encode the batch of the column family, to write it atomically with the batches of the other column families
+    pub fn into_cf_batch<CF>(self) -> Result<(ColumnFamilyName, WriteBatch)>
+    where
+        CF: ColumnFamily<Key = K, Value = V>,
+    {
+        Ok((CF::name(), self.try_into()?))
+    }
+}
+
 impl<K, V> IntoIterator for CodecWriteBatch<K, V>
 where
     K: Serialize + DeserializeOwned,
@@ -431,6 +431,29 @@ impl DBStore for RocksDB {
         })
     }
 
+    fn write_cf_batches_sync(&self, batches: Vec<(&str, WriteBatch)>) -> Result<()> {
+        record_metrics(
+            "db",
+            "multi_cf",
+            "write_cf_batches_sync",
+            self.metrics.as_ref(),
+        )
+        .call(|| {
+            let mut db_batch = DBWriteBatch::default();
+            for (prefix_name, batch) in &batches {
+                let cf_handle = self.get_cf_handle(prefix_name);
+                for (key, write_op) in &batch.rows {
+                    match write_op {
+                        WriteOp::Value(value) => db_batch.put_cf(&cf_handle, key, value),
+                        WriteOp::Deletion => db_batch.delete_cf(&cf_handle, key),
+                    };
+                }
+            }
+            self.db.write_opt(db_batch, &Self::sync_write_options())?;
+            Ok(())
+        })
+    }
+
     fn multi_get(&self, prefix_name: &str, keys: Vec<Vec<u8>>) -> Result<Vec<Option<Vec<u8>>>> {
         record_metrics("db", prefix_name, "multi_get", self.metrics.as_ref()).call(|| {
             let cf_handle = self.get_cf_handle(prefix_name);
@@ -29,4 +29,6 @@ pub trait DBStore: Send + Sync {
     fn put_sync(&self, prefix_name: &str, key: Vec<u8>, value: Vec<u8>) -> Result<()>;
     fn write_batch_sync(&self, prefix_name: &str, batch: WriteBatch) -> Result<()>;
     fn multi_get(&self, prefix_name: &str, keys: Vec<Vec<u8>>) -> Result<Vec<Option<Vec<u8>>>>;
+    /// Writes the batches of the column families atomically, all of them are written or none of them.
+    fn write_cf_batches_sync(&self, batches: Vec<(&str, WriteBatch)>) -> Result<()>;
 }
@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     marker::PhantomData,
     sync::Arc,
 };
 
-use anyhow::Result;
+use anyhow::{format_err, Result};
 use parking_lot::RwLock;
 use primitive_types::H256;
 
@@ -204,6 +204,39 @@ where
         Ok(new_state_root)
     }
 
+    /// Walk the nodes reachable from the current root, and call `on_leaf` with the key and value of the new walked leaves.
+    /// The nodes are content addressed and shared between the trees of different roots,
+    /// so the subtrees whose root is already in `visited` are skipped, and the new walked nodes are added to `visited`.
+    pub fn walk_nodes<F>(&self, visited: &mut HashSet<H256>, mut on_leaf: F) -> Result<()>
+    where
+        F: FnMut(K, V) -> Result<()>,
+    {
+        let root_hash = self.root_hash();
+        if root_hash == *SPARSE_MERKLE_PLACEHOLDER_HASH {
+            return Ok(());
+        }
+        let mut pending = vec![root_hash];
+        while let Some(hash) = pending.pop() {
+            if !visited.insert(hash) {
+                continue;
+            }
+            let node = self.node_store.get(&hash)?.ok_or_else(|| {
+                format_err!("Missing node {:?} of the tree {:?}", hash, root_hash)
+            })?;
+            match Node::<K, V>::decode(&node)? {
+                Node::Null => {}
+                Node::Internal(internal) => {
+                    pending.extend(internal.all_child().into_iter().map(H256::from));
+                }
+                Node::Leaf(leaf) => {
+                    let (key, value) = leaf.into();
+                    on_leaf(key.origin, value.origin)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn update_state_root(&self, new_state_root: H256) -> Result<()> {
         *self.root_hash.write() = new_state_root;
 
@@ -1,17 +1,19 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-import { DEFAULT_MAX_GAS_AMOUNT } from '../constants'
+import { DEFAULT_MAX_GAS_AMOUNT, VERSIONED_TX_DATA_MARKER } from '../constants'
 import { IAccount, CallOption, ISessionKey } from './interface'
 import { IClient } from '../client'
 import { IAuthorizer, IAuthorization, PrivateKeyAuth } from '../auth'
 import { AccountAddress, FunctionId, TypeTag, Arg, StatePageView, Bytes, IPage } from '../types'
-import { BcsSerializer } from '../types/bcs'
+import { BcsSerializer, Serializable } from '../types/bcs'
 import {
-  RoochTransaction,
   RoochTransactionData,
+  RoochTransactionDataV1,
+  VersionedRoochTransactionDataVariantV1,
   AccountAddress as BCSAccountAddress,
   Authenticator,
+  MoveAction,
 } from '../generated/runtime/rooch_types/mod'
 import {
   encodeArg,
@@ -42,7 +44,7 @@ export class Account implements IAccount {
     this.authorizer = authorizer
   }
 
-  private async makeAuth(tsData: RoochTransactionData): Promise<IAuthorization> {
+  private async makeAuth(tsData: Serializable): Promise<IAuthorization> {
     const payload = (() => {
       const se = new BcsSerializer()
       tsData.serialize(se)
@@ -52,6 +54,39 @@ export class Account implements IAccount {
     return this.authorizer.auth(payload)
   }
 
+  /**
+   * The transaction data carrying the fields added after the first layout is prefixed by
+   * the versioned layout marker, the rest is encoded in the first layout.
+   */
+  private makeTxData(sequenceNumber: number, action: MoveAction, opts: CallOption): Serializable {
+    const sender = new BCSAccountAddress(addressToListTuple(this.address))
+    const chainId = BigInt(this.client.getChainId())
+    const maxGasAmount = BigInt(opts.maxGasAmount ?? DEFAULT_MAX_GAS_AMOUNT)
+    if (opts.maxGasPrice === undefined) {
+      return new RoochTransactionData(sender, BigInt(sequenceNumber), chainId, maxGasAmount, action)
+    }
+
+    const marker = new BCSAccountAddress(addressToListTuple(VERSIONED_TX_DATA_MARKER))
+    const versioned = new VersionedRoochTransactionDataVariantV1(
+      new RoochTransactionDataV1(
+        sender,
+        BigInt(sequenceNumber),
+        chainId,
+        maxGasAmount,
+        action,
+        null,
+        null,
+        BigInt(opts.maxGasPrice),
+      ),
+    )
+    return {
+      serialize(se: BcsSerializer): void {
+        marker.serialize(se)
+        versioned.serialize(se)
+      },
+    }
+  }
+
   private parseStateToSessionKey(data: StatePageView): Array<ISessionKey> {
     const result = new Array<ISessionKey>()
 
@@ -127,13 +162,7 @@ export class Account implements IAccount {
     const number = await this.getSequenceNumber()
     const bcsArgs = args.map((arg) => encodeArg(arg))
     const scriptFunction = encodeFunctionCall(funcId, tyArgs, bcsArgs)
-    const txData = new RoochTransactionData(
-      new BCSAccountAddress(addressToListTuple(this.address)),
-      BigInt(number),
-      BigInt(this.client.getChainId()),
-      BigInt(opts.maxGasAmount ?? DEFAULT_MAX_GAS_AMOUNT),
-      scriptFunction,
-    )
+    const txData = this.makeTxData(number, scriptFunction, opts)
 
     const authResult = await this.makeAuth(txData)
 
@@ -141,11 +170,15 @@ export class Account implements IAccount {
       BigInt(authResult.scheme),
       uint8Array2SeqNumber(authResult.payload),
     )
-    const ts = new RoochTransaction(txData, auth)
-
+    // The transaction is the data and the authenticator, the versioned data is followed by the
+    // RoochTransactionAuthenticators, the sender's authenticator and the absent sponsor authenticator
     const payload = (() => {
       const se = new BcsSerializer()
-      ts.serialize(se)
+      txData.serialize(se)
+      auth.serialize(se)
+      if (opts.maxGasPrice !== undefined) {
+        se.serializeOptionTag(false)
+      }
       return se.getBytes()
     })()
 
@@ -5,6 +5,10 @@ import { AccountAddress, FunctionId, TypeTag, Arg, Bytes, IPage } from '../types
 
 export interface CallOption {
   maxGasAmount?: number
+  /**
+   * The max gas price the sender accepts, the transaction is rejected if the base gas price is higher
+   */
+  maxGasPrice?: number
 }
 
 export interface ISessionKey {
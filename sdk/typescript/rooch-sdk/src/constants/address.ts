@@ -2,3 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 export const ROOCH_ADDRESS_LENGTH = 64
+
+/**
+ * The marker of the versioned layouts of the transaction data,
+ * it takes the place of the sender of the first layout
+ */
+export const VERSIONED_TX_DATA_MARKER =
+  '0xd3a8bf09d91d94de0f338d1577ccf277dd115cedfb4302d238d5aa51c76b8e51'